@@ -79,6 +79,9 @@ func NewISBSvcCreateCommand() *cobra.Command {
 					return err
 				}
 				opts = append(opts, isbsvc.WithConfig(isbSvcConfig.JetStream.StreamConfig))
+			case v1alpha1.ISBSvcTypeRedis:
+				logger.Info("Nothing to create, the streams of the redis ISB Service are created by the vertices on demand")
+				return nil
 			default:
 				cmd.HelpFunc()(cmd, args)
 				return fmt.Errorf("unsupported isb service type %q", isbSvcType)
@@ -64,6 +64,9 @@ func NewISBSvcDeleteCommand() *cobra.Command {
 					logger.Errorw("Failed to get a ISB Service client.", zap.Error(err))
 					return err
 				}
+			case v1alpha1.ISBSvcTypeRedis:
+				logger.Info("Nothing to delete, the streams of the redis ISB Service are not managed by the controller")
+				return nil
 			default:
 				cmd.HelpFunc()(cmd, args)
 				return fmt.Errorf("unsupported isb service type %q", isbSvcType)
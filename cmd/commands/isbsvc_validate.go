@@ -66,6 +66,9 @@ func NewISBSvcValidateCommand() *cobra.Command {
 					logger.Errorw("Failed to get an ISB Service client.", zap.Error(err))
 					return err
 				}
+			case v1alpha1.ISBSvcTypeRedis:
+				logger.Info("Nothing to validate, the streams of the redis ISB Service are created by the vertices on demand")
+				return nil
 			default:
 				cmd.HelpFunc()(cmd, args)
 				return fmt.Errorf("unsupported isb service type")
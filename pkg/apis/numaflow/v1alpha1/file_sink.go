@@ -0,0 +1,35 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+
+// FileSink writes the messages to rotating segment files in a local directory, they can be read back with the file source.
+type FileSink struct {
+	// Path of the directory to write the segments to.
+	Path string `json:"path" protobuf:"bytes,1,opt,name=path"`
+	// Format of the segments, "ndjson" (default) writes one JSON record per line, "proto" writes length-delimited protobuf records.
+	// +kubebuilder:validation:Enum=ndjson;proto
+	// +optional
+	Format string `json:"format,omitempty" protobuf:"bytes,2,opt,name=format"`
+	// MaxSegmentSize is the maximum size of a segment in bytes before it is rotated, defaults to 64MiB.
+	// +optional
+	MaxSegmentSize *int64 `json:"maxSegmentSize,omitempty" protobuf:"varint,3,opt,name=maxSegmentSize"`
+	// MaxSegmentAge is the maximum age of a segment before it is rotated, defaults to 5m.
+	// +optional
+	MaxSegmentAge *metav1.Duration `json:"maxSegmentAge,omitempty" protobuf:"bytes,4,opt,name=maxSegmentAge"`
+}
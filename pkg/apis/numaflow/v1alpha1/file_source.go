@@ -0,0 +1,30 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+// FileSource reads the segment files written by the file sink from a local directory.
+type FileSource struct {
+	// Path of the directory to read the segments from.
+	Path string `json:"path" protobuf:"bytes,1,opt,name=path"`
+	// Format of the segments, "ndjson" (default) or "proto". It has to match the format of the file sink which wrote the segments.
+	// +kubebuilder:validation:Enum=ndjson;proto
+	// +optional
+	Format string `json:"format,omitempty" protobuf:"bytes,2,opt,name=format"`
+	// CheckpointPath is the file where the read progress is persisted, defaults to ".checkpoint.json" in the segment directory.
+	// +optional
+	CheckpointPath string `json:"checkpointPath,omitempty" protobuf:"bytes,3,opt,name=checkpointPath"`
+}
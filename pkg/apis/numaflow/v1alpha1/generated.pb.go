@@ -191,10 +191,38 @@ func (m *AccumulatorWindow) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_AccumulatorWindow proto.InternalMessageInfo
 
+func (m *AdaptiveWatermark) Reset()      { *m = AdaptiveWatermark{} }
+func (*AdaptiveWatermark) ProtoMessage() {}
+func (*AdaptiveWatermark) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{5}
+}
+func (m *AdaptiveWatermark) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *AdaptiveWatermark) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *AdaptiveWatermark) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_AdaptiveWatermark.Merge(m, src)
+}
+func (m *AdaptiveWatermark) XXX_Size() int {
+	return m.Size()
+}
+func (m *AdaptiveWatermark) XXX_DiscardUnknown() {
+	xxx_messageInfo_AdaptiveWatermark.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_AdaptiveWatermark proto.InternalMessageInfo
+
 func (m *Authorization) Reset()      { *m = Authorization{} }
 func (*Authorization) ProtoMessage() {}
 func (*Authorization) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{5}
+	return fileDescriptor_9d0d1b17d3865563, []int{6}
 }
 func (m *Authorization) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -222,7 +250,7 @@ var xxx_messageInfo_Authorization proto.InternalMessageInfo
 func (m *Backoff) Reset()      { *m = Backoff{} }
 func (*Backoff) ProtoMessage() {}
 func (*Backoff) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{6}
+	return fileDescriptor_9d0d1b17d3865563, []int{7}
 }
 func (m *Backoff) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -250,7 +278,7 @@ var xxx_messageInfo_Backoff proto.InternalMessageInfo
 func (m *BasicAuth) Reset()      { *m = BasicAuth{} }
 func (*BasicAuth) ProtoMessage() {}
 func (*BasicAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{7}
+	return fileDescriptor_9d0d1b17d3865563, []int{8}
 }
 func (m *BasicAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -278,7 +306,7 @@ var xxx_messageInfo_BasicAuth proto.InternalMessageInfo
 func (m *Blackhole) Reset()      { *m = Blackhole{} }
 func (*Blackhole) ProtoMessage() {}
 func (*Blackhole) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{8}
+	return fileDescriptor_9d0d1b17d3865563, []int{9}
 }
 func (m *Blackhole) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -303,10 +331,38 @@ func (m *Blackhole) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Blackhole proto.InternalMessageInfo
 
+func (m *BroadcastSink) Reset()      { *m = BroadcastSink{} }
+func (*BroadcastSink) ProtoMessage() {}
+func (*BroadcastSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{10}
+}
+func (m *BroadcastSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *BroadcastSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *BroadcastSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_BroadcastSink.Merge(m, src)
+}
+func (m *BroadcastSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *BroadcastSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_BroadcastSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_BroadcastSink proto.InternalMessageInfo
+
 func (m *BufferServiceConfig) Reset()      { *m = BufferServiceConfig{} }
 func (*BufferServiceConfig) ProtoMessage() {}
 func (*BufferServiceConfig) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{9}
+	return fileDescriptor_9d0d1b17d3865563, []int{11}
 }
 func (m *BufferServiceConfig) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -331,10 +387,66 @@ func (m *BufferServiceConfig) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_BufferServiceConfig proto.InternalMessageInfo
 
+func (m *BuiltinReducer) Reset()      { *m = BuiltinReducer{} }
+func (*BuiltinReducer) ProtoMessage() {}
+func (*BuiltinReducer) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{12}
+}
+func (m *BuiltinReducer) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *BuiltinReducer) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *BuiltinReducer) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_BuiltinReducer.Merge(m, src)
+}
+func (m *BuiltinReducer) XXX_Size() int {
+	return m.Size()
+}
+func (m *BuiltinReducer) XXX_DiscardUnknown() {
+	xxx_messageInfo_BuiltinReducer.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_BuiltinReducer proto.InternalMessageInfo
+
+func (m *CircuitBreaker) Reset()      { *m = CircuitBreaker{} }
+func (*CircuitBreaker) ProtoMessage() {}
+func (*CircuitBreaker) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{13}
+}
+func (m *CircuitBreaker) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *CircuitBreaker) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *CircuitBreaker) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CircuitBreaker.Merge(m, src)
+}
+func (m *CircuitBreaker) XXX_Size() int {
+	return m.Size()
+}
+func (m *CircuitBreaker) XXX_DiscardUnknown() {
+	xxx_messageInfo_CircuitBreaker.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CircuitBreaker proto.InternalMessageInfo
+
 func (m *CombinedEdge) Reset()      { *m = CombinedEdge{} }
 func (*CombinedEdge) ProtoMessage() {}
 func (*CombinedEdge) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{10}
+	return fileDescriptor_9d0d1b17d3865563, []int{14}
 }
 func (m *CombinedEdge) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -362,7 +474,7 @@ var xxx_messageInfo_CombinedEdge proto.InternalMessageInfo
 func (m *Compression) Reset()      { *m = Compression{} }
 func (*Compression) ProtoMessage() {}
 func (*Compression) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{11}
+	return fileDescriptor_9d0d1b17d3865563, []int{15}
 }
 func (m *Compression) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -390,7 +502,7 @@ var xxx_messageInfo_Compression proto.InternalMessageInfo
 func (m *Container) Reset()      { *m = Container{} }
 func (*Container) ProtoMessage() {}
 func (*Container) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{12}
+	return fileDescriptor_9d0d1b17d3865563, []int{16}
 }
 func (m *Container) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -418,7 +530,7 @@ var xxx_messageInfo_Container proto.InternalMessageInfo
 func (m *ContainerTemplate) Reset()      { *m = ContainerTemplate{} }
 func (*ContainerTemplate) ProtoMessage() {}
 func (*ContainerTemplate) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{13}
+	return fileDescriptor_9d0d1b17d3865563, []int{17}
 }
 func (m *ContainerTemplate) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -446,7 +558,7 @@ var xxx_messageInfo_ContainerTemplate proto.InternalMessageInfo
 func (m *DaemonTemplate) Reset()      { *m = DaemonTemplate{} }
 func (*DaemonTemplate) ProtoMessage() {}
 func (*DaemonTemplate) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{14}
+	return fileDescriptor_9d0d1b17d3865563, []int{18}
 }
 func (m *DaemonTemplate) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -474,7 +586,7 @@ var xxx_messageInfo_DaemonTemplate proto.InternalMessageInfo
 func (m *Edge) Reset()      { *m = Edge{} }
 func (*Edge) ProtoMessage() {}
 func (*Edge) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{15}
+	return fileDescriptor_9d0d1b17d3865563, []int{19}
 }
 func (m *Edge) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -499,10 +611,66 @@ func (m *Edge) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Edge proto.InternalMessageInfo
 
+func (m *FileSink) Reset()      { *m = FileSink{} }
+func (*FileSink) ProtoMessage() {}
+func (*FileSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{20}
+}
+func (m *FileSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *FileSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *FileSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FileSink.Merge(m, src)
+}
+func (m *FileSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *FileSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_FileSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FileSink proto.InternalMessageInfo
+
+func (m *FileSource) Reset()      { *m = FileSource{} }
+func (*FileSource) ProtoMessage() {}
+func (*FileSource) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{21}
+}
+func (m *FileSource) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *FileSource) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *FileSource) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FileSource.Merge(m, src)
+}
+func (m *FileSource) XXX_Size() int {
+	return m.Size()
+}
+func (m *FileSource) XXX_DiscardUnknown() {
+	xxx_messageInfo_FileSource.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FileSource proto.InternalMessageInfo
+
 func (m *FixedWindow) Reset()      { *m = FixedWindow{} }
 func (*FixedWindow) ProtoMessage() {}
 func (*FixedWindow) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{16}
+	return fileDescriptor_9d0d1b17d3865563, []int{22}
 }
 func (m *FixedWindow) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -530,7 +698,7 @@ var xxx_messageInfo_FixedWindow proto.InternalMessageInfo
 func (m *ForwardConditions) Reset()      { *m = ForwardConditions{} }
 func (*ForwardConditions) ProtoMessage() {}
 func (*ForwardConditions) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{17}
+	return fileDescriptor_9d0d1b17d3865563, []int{23}
 }
 func (m *ForwardConditions) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -558,7 +726,7 @@ var xxx_messageInfo_ForwardConditions proto.InternalMessageInfo
 func (m *GSSAPI) Reset()      { *m = GSSAPI{} }
 func (*GSSAPI) ProtoMessage() {}
 func (*GSSAPI) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{18}
+	return fileDescriptor_9d0d1b17d3865563, []int{24}
 }
 func (m *GSSAPI) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -586,7 +754,7 @@ var xxx_messageInfo_GSSAPI proto.InternalMessageInfo
 func (m *GeneratorSource) Reset()      { *m = GeneratorSource{} }
 func (*GeneratorSource) ProtoMessage() {}
 func (*GeneratorSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{19}
+	return fileDescriptor_9d0d1b17d3865563, []int{25}
 }
 func (m *GeneratorSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -614,7 +782,7 @@ var xxx_messageInfo_GeneratorSource proto.InternalMessageInfo
 func (m *GetDaemonDeploymentReq) Reset()      { *m = GetDaemonDeploymentReq{} }
 func (*GetDaemonDeploymentReq) ProtoMessage() {}
 func (*GetDaemonDeploymentReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{20}
+	return fileDescriptor_9d0d1b17d3865563, []int{26}
 }
 func (m *GetDaemonDeploymentReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -642,7 +810,7 @@ var xxx_messageInfo_GetDaemonDeploymentReq proto.InternalMessageInfo
 func (m *GetJetStreamServiceSpecReq) Reset()      { *m = GetJetStreamServiceSpecReq{} }
 func (*GetJetStreamServiceSpecReq) ProtoMessage() {}
 func (*GetJetStreamServiceSpecReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{21}
+	return fileDescriptor_9d0d1b17d3865563, []int{27}
 }
 func (m *GetJetStreamServiceSpecReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -670,7 +838,7 @@ var xxx_messageInfo_GetJetStreamServiceSpecReq proto.InternalMessageInfo
 func (m *GetJetStreamStatefulSetSpecReq) Reset()      { *m = GetJetStreamStatefulSetSpecReq{} }
 func (*GetJetStreamStatefulSetSpecReq) ProtoMessage() {}
 func (*GetJetStreamStatefulSetSpecReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{22}
+	return fileDescriptor_9d0d1b17d3865563, []int{28}
 }
 func (m *GetJetStreamStatefulSetSpecReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -698,7 +866,7 @@ var xxx_messageInfo_GetJetStreamStatefulSetSpecReq proto.InternalMessageInfo
 func (m *GetMonoVertexDaemonDeploymentReq) Reset()      { *m = GetMonoVertexDaemonDeploymentReq{} }
 func (*GetMonoVertexDaemonDeploymentReq) ProtoMessage() {}
 func (*GetMonoVertexDaemonDeploymentReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{23}
+	return fileDescriptor_9d0d1b17d3865563, []int{29}
 }
 func (m *GetMonoVertexDaemonDeploymentReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -726,7 +894,7 @@ var xxx_messageInfo_GetMonoVertexDaemonDeploymentReq proto.InternalMessageInfo
 func (m *GetMonoVertexPodSpecReq) Reset()      { *m = GetMonoVertexPodSpecReq{} }
 func (*GetMonoVertexPodSpecReq) ProtoMessage() {}
 func (*GetMonoVertexPodSpecReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{24}
+	return fileDescriptor_9d0d1b17d3865563, []int{30}
 }
 func (m *GetMonoVertexPodSpecReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -754,7 +922,7 @@ var xxx_messageInfo_GetMonoVertexPodSpecReq proto.InternalMessageInfo
 func (m *GetServingPipelineResourceReq) Reset()      { *m = GetServingPipelineResourceReq{} }
 func (*GetServingPipelineResourceReq) ProtoMessage() {}
 func (*GetServingPipelineResourceReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{25}
+	return fileDescriptor_9d0d1b17d3865563, []int{31}
 }
 func (m *GetServingPipelineResourceReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -782,7 +950,7 @@ var xxx_messageInfo_GetServingPipelineResourceReq proto.InternalMessageInfo
 func (m *GetSideInputDeploymentReq) Reset()      { *m = GetSideInputDeploymentReq{} }
 func (*GetSideInputDeploymentReq) ProtoMessage() {}
 func (*GetSideInputDeploymentReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{26}
+	return fileDescriptor_9d0d1b17d3865563, []int{32}
 }
 func (m *GetSideInputDeploymentReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -810,7 +978,7 @@ var xxx_messageInfo_GetSideInputDeploymentReq proto.InternalMessageInfo
 func (m *GetVertexPodSpecReq) Reset()      { *m = GetVertexPodSpecReq{} }
 func (*GetVertexPodSpecReq) ProtoMessage() {}
 func (*GetVertexPodSpecReq) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{27}
+	return fileDescriptor_9d0d1b17d3865563, []int{33}
 }
 func (m *GetVertexPodSpecReq) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -838,7 +1006,7 @@ var xxx_messageInfo_GetVertexPodSpecReq proto.InternalMessageInfo
 func (m *GroupBy) Reset()      { *m = GroupBy{} }
 func (*GroupBy) ProtoMessage() {}
 func (*GroupBy) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{28}
+	return fileDescriptor_9d0d1b17d3865563, []int{34}
 }
 func (m *GroupBy) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -863,10 +1031,66 @@ func (m *GroupBy) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_GroupBy proto.InternalMessageInfo
 
+func (m *HTTPSink) Reset()      { *m = HTTPSink{} }
+func (*HTTPSink) ProtoMessage() {}
+func (*HTTPSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{35}
+}
+func (m *HTTPSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *HTTPSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *HTTPSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_HTTPSink.Merge(m, src)
+}
+func (m *HTTPSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *HTTPSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_HTTPSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_HTTPSink proto.InternalMessageInfo
+
+func (m *HTTPSinkAuth) Reset()      { *m = HTTPSinkAuth{} }
+func (*HTTPSinkAuth) ProtoMessage() {}
+func (*HTTPSinkAuth) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{36}
+}
+func (m *HTTPSinkAuth) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *HTTPSinkAuth) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *HTTPSinkAuth) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_HTTPSinkAuth.Merge(m, src)
+}
+func (m *HTTPSinkAuth) XXX_Size() int {
+	return m.Size()
+}
+func (m *HTTPSinkAuth) XXX_DiscardUnknown() {
+	xxx_messageInfo_HTTPSinkAuth.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_HTTPSinkAuth proto.InternalMessageInfo
+
 func (m *HTTPSource) Reset()      { *m = HTTPSource{} }
 func (*HTTPSource) ProtoMessage() {}
 func (*HTTPSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{29}
+	return fileDescriptor_9d0d1b17d3865563, []int{37}
 }
 func (m *HTTPSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -891,10 +1115,38 @@ func (m *HTTPSource) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_HTTPSource proto.InternalMessageInfo
 
+func (m *ISBBatching) Reset()      { *m = ISBBatching{} }
+func (*ISBBatching) ProtoMessage() {}
+func (*ISBBatching) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{38}
+}
+func (m *ISBBatching) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *ISBBatching) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *ISBBatching) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ISBBatching.Merge(m, src)
+}
+func (m *ISBBatching) XXX_Size() int {
+	return m.Size()
+}
+func (m *ISBBatching) XXX_DiscardUnknown() {
+	xxx_messageInfo_ISBBatching.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ISBBatching proto.InternalMessageInfo
+
 func (m *IdleSource) Reset()      { *m = IdleSource{} }
 func (*IdleSource) ProtoMessage() {}
 func (*IdleSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{30}
+	return fileDescriptor_9d0d1b17d3865563, []int{39}
 }
 func (m *IdleSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -922,7 +1174,7 @@ var xxx_messageInfo_IdleSource proto.InternalMessageInfo
 func (m *InterStepBuffer) Reset()      { *m = InterStepBuffer{} }
 func (*InterStepBuffer) ProtoMessage() {}
 func (*InterStepBuffer) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{31}
+	return fileDescriptor_9d0d1b17d3865563, []int{40}
 }
 func (m *InterStepBuffer) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -950,7 +1202,7 @@ var xxx_messageInfo_InterStepBuffer proto.InternalMessageInfo
 func (m *InterStepBufferService) Reset()      { *m = InterStepBufferService{} }
 func (*InterStepBufferService) ProtoMessage() {}
 func (*InterStepBufferService) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{32}
+	return fileDescriptor_9d0d1b17d3865563, []int{41}
 }
 func (m *InterStepBufferService) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -978,7 +1230,7 @@ var xxx_messageInfo_InterStepBufferService proto.InternalMessageInfo
 func (m *InterStepBufferServiceList) Reset()      { *m = InterStepBufferServiceList{} }
 func (*InterStepBufferServiceList) ProtoMessage() {}
 func (*InterStepBufferServiceList) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{33}
+	return fileDescriptor_9d0d1b17d3865563, []int{42}
 }
 func (m *InterStepBufferServiceList) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1006,7 +1258,7 @@ var xxx_messageInfo_InterStepBufferServiceList proto.InternalMessageInfo
 func (m *InterStepBufferServiceSpec) Reset()      { *m = InterStepBufferServiceSpec{} }
 func (*InterStepBufferServiceSpec) ProtoMessage() {}
 func (*InterStepBufferServiceSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{34}
+	return fileDescriptor_9d0d1b17d3865563, []int{43}
 }
 func (m *InterStepBufferServiceSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1034,7 +1286,7 @@ var xxx_messageInfo_InterStepBufferServiceSpec proto.InternalMessageInfo
 func (m *InterStepBufferServiceStatus) Reset()      { *m = InterStepBufferServiceStatus{} }
 func (*InterStepBufferServiceStatus) ProtoMessage() {}
 func (*InterStepBufferServiceStatus) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{35}
+	return fileDescriptor_9d0d1b17d3865563, []int{44}
 }
 func (m *InterStepBufferServiceStatus) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1062,7 +1314,7 @@ var xxx_messageInfo_InterStepBufferServiceStatus proto.InternalMessageInfo
 func (m *JetStreamBufferService) Reset()      { *m = JetStreamBufferService{} }
 func (*JetStreamBufferService) ProtoMessage() {}
 func (*JetStreamBufferService) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{36}
+	return fileDescriptor_9d0d1b17d3865563, []int{45}
 }
 func (m *JetStreamBufferService) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1090,7 +1342,7 @@ var xxx_messageInfo_JetStreamBufferService proto.InternalMessageInfo
 func (m *JetStreamConfig) Reset()      { *m = JetStreamConfig{} }
 func (*JetStreamConfig) ProtoMessage() {}
 func (*JetStreamConfig) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{37}
+	return fileDescriptor_9d0d1b17d3865563, []int{46}
 }
 func (m *JetStreamConfig) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1115,10 +1367,38 @@ func (m *JetStreamConfig) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_JetStreamConfig proto.InternalMessageInfo
 
+func (m *JetStreamSink) Reset()      { *m = JetStreamSink{} }
+func (*JetStreamSink) ProtoMessage() {}
+func (*JetStreamSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{47}
+}
+func (m *JetStreamSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *JetStreamSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *JetStreamSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_JetStreamSink.Merge(m, src)
+}
+func (m *JetStreamSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *JetStreamSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_JetStreamSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_JetStreamSink proto.InternalMessageInfo
+
 func (m *JetStreamSource) Reset()      { *m = JetStreamSource{} }
 func (*JetStreamSource) ProtoMessage() {}
 func (*JetStreamSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{38}
+	return fileDescriptor_9d0d1b17d3865563, []int{48}
 }
 func (m *JetStreamSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1146,7 +1426,7 @@ var xxx_messageInfo_JetStreamSource proto.InternalMessageInfo
 func (m *JobTemplate) Reset()      { *m = JobTemplate{} }
 func (*JobTemplate) ProtoMessage() {}
 func (*JobTemplate) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{39}
+	return fileDescriptor_9d0d1b17d3865563, []int{49}
 }
 func (m *JobTemplate) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1174,7 +1454,7 @@ var xxx_messageInfo_JobTemplate proto.InternalMessageInfo
 func (m *KafkaSink) Reset()      { *m = KafkaSink{} }
 func (*KafkaSink) ProtoMessage() {}
 func (*KafkaSink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{40}
+	return fileDescriptor_9d0d1b17d3865563, []int{50}
 }
 func (m *KafkaSink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1202,7 +1482,7 @@ var xxx_messageInfo_KafkaSink proto.InternalMessageInfo
 func (m *KafkaSource) Reset()      { *m = KafkaSource{} }
 func (*KafkaSource) ProtoMessage() {}
 func (*KafkaSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{41}
+	return fileDescriptor_9d0d1b17d3865563, []int{51}
 }
 func (m *KafkaSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1227,10 +1507,38 @@ func (m *KafkaSource) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_KafkaSource proto.InternalMessageInfo
 
+func (m *LateData) Reset()      { *m = LateData{} }
+func (*LateData) ProtoMessage() {}
+func (*LateData) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{52}
+}
+func (m *LateData) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *LateData) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *LateData) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_LateData.Merge(m, src)
+}
+func (m *LateData) XXX_Size() int {
+	return m.Size()
+}
+func (m *LateData) XXX_DiscardUnknown() {
+	xxx_messageInfo_LateData.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_LateData proto.InternalMessageInfo
+
 func (m *Lifecycle) Reset()      { *m = Lifecycle{} }
 func (*Lifecycle) ProtoMessage() {}
 func (*Lifecycle) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{42}
+	return fileDescriptor_9d0d1b17d3865563, []int{53}
 }
 func (m *Lifecycle) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1255,10 +1563,38 @@ func (m *Lifecycle) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Lifecycle proto.InternalMessageInfo
 
+func (m *LocalObjectStore) Reset()      { *m = LocalObjectStore{} }
+func (*LocalObjectStore) ProtoMessage() {}
+func (*LocalObjectStore) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{54}
+}
+func (m *LocalObjectStore) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *LocalObjectStore) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *LocalObjectStore) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_LocalObjectStore.Merge(m, src)
+}
+func (m *LocalObjectStore) XXX_Size() int {
+	return m.Size()
+}
+func (m *LocalObjectStore) XXX_DiscardUnknown() {
+	xxx_messageInfo_LocalObjectStore.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_LocalObjectStore proto.InternalMessageInfo
+
 func (m *Log) Reset()      { *m = Log{} }
 func (*Log) ProtoMessage() {}
 func (*Log) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{43}
+	return fileDescriptor_9d0d1b17d3865563, []int{55}
 }
 func (m *Log) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1286,7 +1622,7 @@ var xxx_messageInfo_Log proto.InternalMessageInfo
 func (m *Metadata) Reset()      { *m = Metadata{} }
 func (*Metadata) ProtoMessage() {}
 func (*Metadata) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{44}
+	return fileDescriptor_9d0d1b17d3865563, []int{56}
 }
 func (m *Metadata) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1314,7 +1650,7 @@ var xxx_messageInfo_Metadata proto.InternalMessageInfo
 func (m *MonoVertex) Reset()      { *m = MonoVertex{} }
 func (*MonoVertex) ProtoMessage() {}
 func (*MonoVertex) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{45}
+	return fileDescriptor_9d0d1b17d3865563, []int{57}
 }
 func (m *MonoVertex) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1342,7 +1678,7 @@ var xxx_messageInfo_MonoVertex proto.InternalMessageInfo
 func (m *MonoVertexBypassCondition) Reset()      { *m = MonoVertexBypassCondition{} }
 func (*MonoVertexBypassCondition) ProtoMessage() {}
 func (*MonoVertexBypassCondition) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{46}
+	return fileDescriptor_9d0d1b17d3865563, []int{58}
 }
 func (m *MonoVertexBypassCondition) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1370,7 +1706,7 @@ var xxx_messageInfo_MonoVertexBypassCondition proto.InternalMessageInfo
 func (m *MonoVertexLifecycle) Reset()      { *m = MonoVertexLifecycle{} }
 func (*MonoVertexLifecycle) ProtoMessage() {}
 func (*MonoVertexLifecycle) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{47}
+	return fileDescriptor_9d0d1b17d3865563, []int{59}
 }
 func (m *MonoVertexLifecycle) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1398,7 +1734,7 @@ var xxx_messageInfo_MonoVertexLifecycle proto.InternalMessageInfo
 func (m *MonoVertexLimits) Reset()      { *m = MonoVertexLimits{} }
 func (*MonoVertexLimits) ProtoMessage() {}
 func (*MonoVertexLimits) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{48}
+	return fileDescriptor_9d0d1b17d3865563, []int{60}
 }
 func (m *MonoVertexLimits) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1426,7 +1762,7 @@ var xxx_messageInfo_MonoVertexLimits proto.InternalMessageInfo
 func (m *MonoVertexList) Reset()      { *m = MonoVertexList{} }
 func (*MonoVertexList) ProtoMessage() {}
 func (*MonoVertexList) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{49}
+	return fileDescriptor_9d0d1b17d3865563, []int{61}
 }
 func (m *MonoVertexList) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1454,7 +1790,7 @@ var xxx_messageInfo_MonoVertexList proto.InternalMessageInfo
 func (m *MonoVertexSpec) Reset()      { *m = MonoVertexSpec{} }
 func (*MonoVertexSpec) ProtoMessage() {}
 func (*MonoVertexSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{50}
+	return fileDescriptor_9d0d1b17d3865563, []int{62}
 }
 func (m *MonoVertexSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1482,7 +1818,7 @@ var xxx_messageInfo_MonoVertexSpec proto.InternalMessageInfo
 func (m *MonoVertexStatus) Reset()      { *m = MonoVertexStatus{} }
 func (*MonoVertexStatus) ProtoMessage() {}
 func (*MonoVertexStatus) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{51}
+	return fileDescriptor_9d0d1b17d3865563, []int{63}
 }
 func (m *MonoVertexStatus) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1510,7 +1846,7 @@ var xxx_messageInfo_MonoVertexStatus proto.InternalMessageInfo
 func (m *NatsAuth) Reset()      { *m = NatsAuth{} }
 func (*NatsAuth) ProtoMessage() {}
 func (*NatsAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{52}
+	return fileDescriptor_9d0d1b17d3865563, []int{64}
 }
 func (m *NatsAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1535,10 +1871,38 @@ func (m *NatsAuth) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_NatsAuth proto.InternalMessageInfo
 
+func (m *NatsSink) Reset()      { *m = NatsSink{} }
+func (*NatsSink) ProtoMessage() {}
+func (*NatsSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{65}
+}
+func (m *NatsSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *NatsSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *NatsSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_NatsSink.Merge(m, src)
+}
+func (m *NatsSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *NatsSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_NatsSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_NatsSink proto.InternalMessageInfo
+
 func (m *NatsSource) Reset()      { *m = NatsSource{} }
 func (*NatsSource) ProtoMessage() {}
 func (*NatsSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{53}
+	return fileDescriptor_9d0d1b17d3865563, []int{66}
 }
 func (m *NatsSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1566,7 +1930,7 @@ var xxx_messageInfo_NatsSource proto.InternalMessageInfo
 func (m *NoStore) Reset()      { *m = NoStore{} }
 func (*NoStore) ProtoMessage() {}
 func (*NoStore) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{54}
+	return fileDescriptor_9d0d1b17d3865563, []int{67}
 }
 func (m *NoStore) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1591,10 +1955,66 @@ func (m *NoStore) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_NoStore proto.InternalMessageInfo
 
+func (m *ObjectStoreColumn) Reset()      { *m = ObjectStoreColumn{} }
+func (*ObjectStoreColumn) ProtoMessage() {}
+func (*ObjectStoreColumn) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{68}
+}
+func (m *ObjectStoreColumn) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *ObjectStoreColumn) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *ObjectStoreColumn) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ObjectStoreColumn.Merge(m, src)
+}
+func (m *ObjectStoreColumn) XXX_Size() int {
+	return m.Size()
+}
+func (m *ObjectStoreColumn) XXX_DiscardUnknown() {
+	xxx_messageInfo_ObjectStoreColumn.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ObjectStoreColumn proto.InternalMessageInfo
+
+func (m *ObjectStoreSink) Reset()      { *m = ObjectStoreSink{} }
+func (*ObjectStoreSink) ProtoMessage() {}
+func (*ObjectStoreSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{69}
+}
+func (m *ObjectStoreSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *ObjectStoreSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *ObjectStoreSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ObjectStoreSink.Merge(m, src)
+}
+func (m *ObjectStoreSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *ObjectStoreSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_ObjectStoreSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ObjectStoreSink proto.InternalMessageInfo
+
 func (m *Ordered) Reset()      { *m = Ordered{} }
 func (*Ordered) ProtoMessage() {}
 func (*Ordered) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{55}
+	return fileDescriptor_9d0d1b17d3865563, []int{70}
 }
 func (m *Ordered) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1622,7 +2042,7 @@ var xxx_messageInfo_Ordered proto.InternalMessageInfo
 func (m *PBQStorage) Reset()      { *m = PBQStorage{} }
 func (*PBQStorage) ProtoMessage() {}
 func (*PBQStorage) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{56}
+	return fileDescriptor_9d0d1b17d3865563, []int{71}
 }
 func (m *PBQStorage) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1647,10 +2067,38 @@ func (m *PBQStorage) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_PBQStorage proto.InternalMessageInfo
 
+func (m *PercentileWatermark) Reset()      { *m = PercentileWatermark{} }
+func (*PercentileWatermark) ProtoMessage() {}
+func (*PercentileWatermark) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{72}
+}
+func (m *PercentileWatermark) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PercentileWatermark) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *PercentileWatermark) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PercentileWatermark.Merge(m, src)
+}
+func (m *PercentileWatermark) XXX_Size() int {
+	return m.Size()
+}
+func (m *PercentileWatermark) XXX_DiscardUnknown() {
+	xxx_messageInfo_PercentileWatermark.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PercentileWatermark proto.InternalMessageInfo
+
 func (m *PersistenceStrategy) Reset()      { *m = PersistenceStrategy{} }
 func (*PersistenceStrategy) ProtoMessage() {}
 func (*PersistenceStrategy) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{57}
+	return fileDescriptor_9d0d1b17d3865563, []int{73}
 }
 func (m *PersistenceStrategy) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1678,7 +2126,7 @@ var xxx_messageInfo_PersistenceStrategy proto.InternalMessageInfo
 func (m *Pipeline) Reset()      { *m = Pipeline{} }
 func (*Pipeline) ProtoMessage() {}
 func (*Pipeline) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{58}
+	return fileDescriptor_9d0d1b17d3865563, []int{74}
 }
 func (m *Pipeline) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1706,7 +2154,7 @@ var xxx_messageInfo_Pipeline proto.InternalMessageInfo
 func (m *PipelineLimits) Reset()      { *m = PipelineLimits{} }
 func (*PipelineLimits) ProtoMessage() {}
 func (*PipelineLimits) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{59}
+	return fileDescriptor_9d0d1b17d3865563, []int{75}
 }
 func (m *PipelineLimits) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1734,7 +2182,7 @@ var xxx_messageInfo_PipelineLimits proto.InternalMessageInfo
 func (m *PipelineList) Reset()      { *m = PipelineList{} }
 func (*PipelineList) ProtoMessage() {}
 func (*PipelineList) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{60}
+	return fileDescriptor_9d0d1b17d3865563, []int{76}
 }
 func (m *PipelineList) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1762,7 +2210,7 @@ var xxx_messageInfo_PipelineList proto.InternalMessageInfo
 func (m *PipelineSpec) Reset()      { *m = PipelineSpec{} }
 func (*PipelineSpec) ProtoMessage() {}
 func (*PipelineSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{61}
+	return fileDescriptor_9d0d1b17d3865563, []int{77}
 }
 func (m *PipelineSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1790,7 +2238,7 @@ var xxx_messageInfo_PipelineSpec proto.InternalMessageInfo
 func (m *PipelineStatus) Reset()      { *m = PipelineStatus{} }
 func (*PipelineStatus) ProtoMessage() {}
 func (*PipelineStatus) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{62}
+	return fileDescriptor_9d0d1b17d3865563, []int{78}
 }
 func (m *PipelineStatus) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1818,7 +2266,7 @@ var xxx_messageInfo_PipelineStatus proto.InternalMessageInfo
 func (m *Ports) Reset()      { *m = Ports{} }
 func (*Ports) ProtoMessage() {}
 func (*Ports) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{63}
+	return fileDescriptor_9d0d1b17d3865563, []int{79}
 }
 func (m *Ports) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1843,10 +2291,66 @@ func (m *Ports) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Ports proto.InternalMessageInfo
 
+func (m *PostgresColumn) Reset()      { *m = PostgresColumn{} }
+func (*PostgresColumn) ProtoMessage() {}
+func (*PostgresColumn) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{80}
+}
+func (m *PostgresColumn) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PostgresColumn) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *PostgresColumn) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PostgresColumn.Merge(m, src)
+}
+func (m *PostgresColumn) XXX_Size() int {
+	return m.Size()
+}
+func (m *PostgresColumn) XXX_DiscardUnknown() {
+	xxx_messageInfo_PostgresColumn.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PostgresColumn proto.InternalMessageInfo
+
+func (m *PostgresSink) Reset()      { *m = PostgresSink{} }
+func (*PostgresSink) ProtoMessage() {}
+func (*PostgresSink) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{81}
+}
+func (m *PostgresSink) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PostgresSink) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *PostgresSink) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PostgresSink.Merge(m, src)
+}
+func (m *PostgresSink) XXX_Size() int {
+	return m.Size()
+}
+func (m *PostgresSink) XXX_DiscardUnknown() {
+	xxx_messageInfo_PostgresSink.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PostgresSink proto.InternalMessageInfo
+
 func (m *Probe) Reset()      { *m = Probe{} }
 func (*Probe) ProtoMessage() {}
 func (*Probe) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{64}
+	return fileDescriptor_9d0d1b17d3865563, []int{82}
 }
 func (m *Probe) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1874,7 +2378,7 @@ var xxx_messageInfo_Probe proto.InternalMessageInfo
 func (m *PulsarAuth) Reset()      { *m = PulsarAuth{} }
 func (*PulsarAuth) ProtoMessage() {}
 func (*PulsarAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{65}
+	return fileDescriptor_9d0d1b17d3865563, []int{83}
 }
 func (m *PulsarAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1902,7 +2406,7 @@ var xxx_messageInfo_PulsarAuth proto.InternalMessageInfo
 func (m *PulsarBasicAuth) Reset()      { *m = PulsarBasicAuth{} }
 func (*PulsarBasicAuth) ProtoMessage() {}
 func (*PulsarBasicAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{66}
+	return fileDescriptor_9d0d1b17d3865563, []int{84}
 }
 func (m *PulsarBasicAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1930,7 +2434,7 @@ var xxx_messageInfo_PulsarBasicAuth proto.InternalMessageInfo
 func (m *PulsarSink) Reset()      { *m = PulsarSink{} }
 func (*PulsarSink) ProtoMessage() {}
 func (*PulsarSink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{67}
+	return fileDescriptor_9d0d1b17d3865563, []int{85}
 }
 func (m *PulsarSink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1958,7 +2462,7 @@ var xxx_messageInfo_PulsarSink proto.InternalMessageInfo
 func (m *PulsarSource) Reset()      { *m = PulsarSource{} }
 func (*PulsarSource) ProtoMessage() {}
 func (*PulsarSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{68}
+	return fileDescriptor_9d0d1b17d3865563, []int{86}
 }
 func (m *PulsarSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1983,10 +2487,38 @@ func (m *PulsarSource) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_PulsarSource proto.InternalMessageInfo
 
+func (m *PunctuationWatermark) Reset()      { *m = PunctuationWatermark{} }
+func (*PunctuationWatermark) ProtoMessage() {}
+func (*PunctuationWatermark) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{87}
+}
+func (m *PunctuationWatermark) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PunctuationWatermark) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *PunctuationWatermark) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PunctuationWatermark.Merge(m, src)
+}
+func (m *PunctuationWatermark) XXX_Size() int {
+	return m.Size()
+}
+func (m *PunctuationWatermark) XXX_DiscardUnknown() {
+	xxx_messageInfo_PunctuationWatermark.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PunctuationWatermark proto.InternalMessageInfo
+
 func (m *RateLimit) Reset()      { *m = RateLimit{} }
 func (*RateLimit) ProtoMessage() {}
 func (*RateLimit) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{69}
+	return fileDescriptor_9d0d1b17d3865563, []int{88}
 }
 func (m *RateLimit) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2011,10 +2543,66 @@ func (m *RateLimit) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_RateLimit proto.InternalMessageInfo
 
+func (m *RateLimitKeyOverride) Reset()      { *m = RateLimitKeyOverride{} }
+func (*RateLimitKeyOverride) ProtoMessage() {}
+func (*RateLimitKeyOverride) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{89}
+}
+func (m *RateLimitKeyOverride) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *RateLimitKeyOverride) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *RateLimitKeyOverride) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RateLimitKeyOverride.Merge(m, src)
+}
+func (m *RateLimitKeyOverride) XXX_Size() int {
+	return m.Size()
+}
+func (m *RateLimitKeyOverride) XXX_DiscardUnknown() {
+	xxx_messageInfo_RateLimitKeyOverride.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RateLimitKeyOverride proto.InternalMessageInfo
+
+func (m *RateLimitPerKey) Reset()      { *m = RateLimitPerKey{} }
+func (*RateLimitPerKey) ProtoMessage() {}
+func (*RateLimitPerKey) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{90}
+}
+func (m *RateLimitPerKey) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *RateLimitPerKey) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *RateLimitPerKey) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RateLimitPerKey.Merge(m, src)
+}
+func (m *RateLimitPerKey) XXX_Size() int {
+	return m.Size()
+}
+func (m *RateLimitPerKey) XXX_DiscardUnknown() {
+	xxx_messageInfo_RateLimitPerKey.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RateLimitPerKey proto.InternalMessageInfo
+
 func (m *RateLimiterGoBackN) Reset()      { *m = RateLimiterGoBackN{} }
 func (*RateLimiterGoBackN) ProtoMessage() {}
 func (*RateLimiterGoBackN) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{70}
+	return fileDescriptor_9d0d1b17d3865563, []int{91}
 }
 func (m *RateLimiterGoBackN) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2042,7 +2630,7 @@ var xxx_messageInfo_RateLimiterGoBackN proto.InternalMessageInfo
 func (m *RateLimiterInMemoryStore) Reset()      { *m = RateLimiterInMemoryStore{} }
 func (*RateLimiterInMemoryStore) ProtoMessage() {}
 func (*RateLimiterInMemoryStore) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{71}
+	return fileDescriptor_9d0d1b17d3865563, []int{92}
 }
 func (m *RateLimiterInMemoryStore) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2067,10 +2655,38 @@ func (m *RateLimiterInMemoryStore) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_RateLimiterInMemoryStore proto.InternalMessageInfo
 
+func (m *RateLimiterJetStreamStore) Reset()      { *m = RateLimiterJetStreamStore{} }
+func (*RateLimiterJetStreamStore) ProtoMessage() {}
+func (*RateLimiterJetStreamStore) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{93}
+}
+func (m *RateLimiterJetStreamStore) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *RateLimiterJetStreamStore) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *RateLimiterJetStreamStore) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RateLimiterJetStreamStore.Merge(m, src)
+}
+func (m *RateLimiterJetStreamStore) XXX_Size() int {
+	return m.Size()
+}
+func (m *RateLimiterJetStreamStore) XXX_DiscardUnknown() {
+	xxx_messageInfo_RateLimiterJetStreamStore.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RateLimiterJetStreamStore proto.InternalMessageInfo
+
 func (m *RateLimiterModes) Reset()      { *m = RateLimiterModes{} }
 func (*RateLimiterModes) ProtoMessage() {}
 func (*RateLimiterModes) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{72}
+	return fileDescriptor_9d0d1b17d3865563, []int{94}
 }
 func (m *RateLimiterModes) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2098,7 +2714,7 @@ var xxx_messageInfo_RateLimiterModes proto.InternalMessageInfo
 func (m *RateLimiterOnlyIfUsed) Reset()      { *m = RateLimiterOnlyIfUsed{} }
 func (*RateLimiterOnlyIfUsed) ProtoMessage() {}
 func (*RateLimiterOnlyIfUsed) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{73}
+	return fileDescriptor_9d0d1b17d3865563, []int{95}
 }
 func (m *RateLimiterOnlyIfUsed) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2126,7 +2742,7 @@ var xxx_messageInfo_RateLimiterOnlyIfUsed proto.InternalMessageInfo
 func (m *RateLimiterRedisStore) Reset()      { *m = RateLimiterRedisStore{} }
 func (*RateLimiterRedisStore) ProtoMessage() {}
 func (*RateLimiterRedisStore) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{74}
+	return fileDescriptor_9d0d1b17d3865563, []int{96}
 }
 func (m *RateLimiterRedisStore) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2154,7 +2770,7 @@ var xxx_messageInfo_RateLimiterRedisStore proto.InternalMessageInfo
 func (m *RateLimiterRelaxed) Reset()      { *m = RateLimiterRelaxed{} }
 func (*RateLimiterRelaxed) ProtoMessage() {}
 func (*RateLimiterRelaxed) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{75}
+	return fileDescriptor_9d0d1b17d3865563, []int{97}
 }
 func (m *RateLimiterRelaxed) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2182,7 +2798,7 @@ var xxx_messageInfo_RateLimiterRelaxed proto.InternalMessageInfo
 func (m *RateLimiterScheduled) Reset()      { *m = RateLimiterScheduled{} }
 func (*RateLimiterScheduled) ProtoMessage() {}
 func (*RateLimiterScheduled) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{76}
+	return fileDescriptor_9d0d1b17d3865563, []int{98}
 }
 func (m *RateLimiterScheduled) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2210,7 +2826,7 @@ var xxx_messageInfo_RateLimiterScheduled proto.InternalMessageInfo
 func (m *RateLimiterStore) Reset()      { *m = RateLimiterStore{} }
 func (*RateLimiterStore) ProtoMessage() {}
 func (*RateLimiterStore) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{77}
+	return fileDescriptor_9d0d1b17d3865563, []int{99}
 }
 func (m *RateLimiterStore) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2238,7 +2854,7 @@ var xxx_messageInfo_RateLimiterStore proto.InternalMessageInfo
 func (m *RedisAuth) Reset()      { *m = RedisAuth{} }
 func (*RedisAuth) ProtoMessage() {}
 func (*RedisAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{78}
+	return fileDescriptor_9d0d1b17d3865563, []int{100}
 }
 func (m *RedisAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2263,10 +2879,66 @@ func (m *RedisAuth) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_RedisAuth proto.InternalMessageInfo
 
+func (m *RedisBufferService) Reset()      { *m = RedisBufferService{} }
+func (*RedisBufferService) ProtoMessage() {}
+func (*RedisBufferService) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{101}
+}
+func (m *RedisBufferService) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *RedisBufferService) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *RedisBufferService) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RedisBufferService.Merge(m, src)
+}
+func (m *RedisBufferService) XXX_Size() int {
+	return m.Size()
+}
+func (m *RedisBufferService) XXX_DiscardUnknown() {
+	xxx_messageInfo_RedisBufferService.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RedisBufferService proto.InternalMessageInfo
+
+func (m *RedisConfig) Reset()      { *m = RedisConfig{} }
+func (*RedisConfig) ProtoMessage() {}
+func (*RedisConfig) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{102}
+}
+func (m *RedisConfig) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *RedisConfig) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *RedisConfig) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RedisConfig.Merge(m, src)
+}
+func (m *RedisConfig) XXX_Size() int {
+	return m.Size()
+}
+func (m *RedisConfig) XXX_DiscardUnknown() {
+	xxx_messageInfo_RedisConfig.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RedisConfig proto.InternalMessageInfo
+
 func (m *RedisSentinelConfig) Reset()      { *m = RedisSentinelConfig{} }
 func (*RedisSentinelConfig) ProtoMessage() {}
 func (*RedisSentinelConfig) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{79}
+	return fileDescriptor_9d0d1b17d3865563, []int{103}
 }
 func (m *RedisSentinelConfig) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2294,7 +2966,7 @@ var xxx_messageInfo_RedisSentinelConfig proto.InternalMessageInfo
 func (m *RetryStrategy) Reset()      { *m = RetryStrategy{} }
 func (*RetryStrategy) ProtoMessage() {}
 func (*RetryStrategy) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{80}
+	return fileDescriptor_9d0d1b17d3865563, []int{104}
 }
 func (m *RetryStrategy) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2322,7 +2994,7 @@ var xxx_messageInfo_RetryStrategy proto.InternalMessageInfo
 func (m *RollingUpdateStrategy) Reset()      { *m = RollingUpdateStrategy{} }
 func (*RollingUpdateStrategy) ProtoMessage() {}
 func (*RollingUpdateStrategy) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{81}
+	return fileDescriptor_9d0d1b17d3865563, []int{105}
 }
 func (m *RollingUpdateStrategy) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2347,10 +3019,38 @@ func (m *RollingUpdateStrategy) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_RollingUpdateStrategy proto.InternalMessageInfo
 
+func (m *S3ObjectStore) Reset()      { *m = S3ObjectStore{} }
+func (*S3ObjectStore) ProtoMessage() {}
+func (*S3ObjectStore) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{106}
+}
+func (m *S3ObjectStore) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *S3ObjectStore) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *S3ObjectStore) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_S3ObjectStore.Merge(m, src)
+}
+func (m *S3ObjectStore) XXX_Size() int {
+	return m.Size()
+}
+func (m *S3ObjectStore) XXX_DiscardUnknown() {
+	xxx_messageInfo_S3ObjectStore.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_S3ObjectStore proto.InternalMessageInfo
+
 func (m *SASL) Reset()      { *m = SASL{} }
 func (*SASL) ProtoMessage() {}
 func (*SASL) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{82}
+	return fileDescriptor_9d0d1b17d3865563, []int{107}
 }
 func (m *SASL) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2378,7 +3078,7 @@ var xxx_messageInfo_SASL proto.InternalMessageInfo
 func (m *SASLOAuth) Reset()      { *m = SASLOAuth{} }
 func (*SASLOAuth) ProtoMessage() {}
 func (*SASLOAuth) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{83}
+	return fileDescriptor_9d0d1b17d3865563, []int{108}
 }
 func (m *SASLOAuth) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2406,7 +3106,7 @@ var xxx_messageInfo_SASLOAuth proto.InternalMessageInfo
 func (m *SASLPlain) Reset()      { *m = SASLPlain{} }
 func (*SASLPlain) ProtoMessage() {}
 func (*SASLPlain) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{84}
+	return fileDescriptor_9d0d1b17d3865563, []int{109}
 }
 func (m *SASLPlain) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2434,7 +3134,7 @@ var xxx_messageInfo_SASLPlain proto.InternalMessageInfo
 func (m *Scale) Reset()      { *m = Scale{} }
 func (*Scale) ProtoMessage() {}
 func (*Scale) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{85}
+	return fileDescriptor_9d0d1b17d3865563, []int{110}
 }
 func (m *Scale) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2462,7 +3162,7 @@ var xxx_messageInfo_Scale proto.InternalMessageInfo
 func (m *ServeSink) Reset()      { *m = ServeSink{} }
 func (*ServeSink) ProtoMessage() {}
 func (*ServeSink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{86}
+	return fileDescriptor_9d0d1b17d3865563, []int{111}
 }
 func (m *ServeSink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2490,7 +3190,7 @@ var xxx_messageInfo_ServeSink proto.InternalMessageInfo
 func (m *ServingPipeline) Reset()      { *m = ServingPipeline{} }
 func (*ServingPipeline) ProtoMessage() {}
 func (*ServingPipeline) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{87}
+	return fileDescriptor_9d0d1b17d3865563, []int{112}
 }
 func (m *ServingPipeline) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2518,7 +3218,7 @@ var xxx_messageInfo_ServingPipeline proto.InternalMessageInfo
 func (m *ServingPipelineList) Reset()      { *m = ServingPipelineList{} }
 func (*ServingPipelineList) ProtoMessage() {}
 func (*ServingPipelineList) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{88}
+	return fileDescriptor_9d0d1b17d3865563, []int{113}
 }
 func (m *ServingPipelineList) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2546,7 +3246,7 @@ var xxx_messageInfo_ServingPipelineList proto.InternalMessageInfo
 func (m *ServingPipelineSpec) Reset()      { *m = ServingPipelineSpec{} }
 func (*ServingPipelineSpec) ProtoMessage() {}
 func (*ServingPipelineSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{89}
+	return fileDescriptor_9d0d1b17d3865563, []int{114}
 }
 func (m *ServingPipelineSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2574,7 +3274,7 @@ var xxx_messageInfo_ServingPipelineSpec proto.InternalMessageInfo
 func (m *ServingPipelineStatus) Reset()      { *m = ServingPipelineStatus{} }
 func (*ServingPipelineStatus) ProtoMessage() {}
 func (*ServingPipelineStatus) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{90}
+	return fileDescriptor_9d0d1b17d3865563, []int{115}
 }
 func (m *ServingPipelineStatus) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2602,7 +3302,7 @@ var xxx_messageInfo_ServingPipelineStatus proto.InternalMessageInfo
 func (m *ServingSource) Reset()      { *m = ServingSource{} }
 func (*ServingSource) ProtoMessage() {}
 func (*ServingSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{91}
+	return fileDescriptor_9d0d1b17d3865563, []int{116}
 }
 func (m *ServingSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2630,7 +3330,7 @@ var xxx_messageInfo_ServingSource proto.InternalMessageInfo
 func (m *ServingSpec) Reset()      { *m = ServingSpec{} }
 func (*ServingSpec) ProtoMessage() {}
 func (*ServingSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{92}
+	return fileDescriptor_9d0d1b17d3865563, []int{117}
 }
 func (m *ServingSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2658,7 +3358,7 @@ var xxx_messageInfo_ServingSpec proto.InternalMessageInfo
 func (m *ServingStore) Reset()      { *m = ServingStore{} }
 func (*ServingStore) ProtoMessage() {}
 func (*ServingStore) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{93}
+	return fileDescriptor_9d0d1b17d3865563, []int{118}
 }
 func (m *ServingStore) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2686,7 +3386,7 @@ var xxx_messageInfo_ServingStore proto.InternalMessageInfo
 func (m *SessionWindow) Reset()      { *m = SessionWindow{} }
 func (*SessionWindow) ProtoMessage() {}
 func (*SessionWindow) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{94}
+	return fileDescriptor_9d0d1b17d3865563, []int{119}
 }
 func (m *SessionWindow) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2714,7 +3414,7 @@ var xxx_messageInfo_SessionWindow proto.InternalMessageInfo
 func (m *SideInput) Reset()      { *m = SideInput{} }
 func (*SideInput) ProtoMessage() {}
 func (*SideInput) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{95}
+	return fileDescriptor_9d0d1b17d3865563, []int{120}
 }
 func (m *SideInput) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2742,7 +3442,7 @@ var xxx_messageInfo_SideInput proto.InternalMessageInfo
 func (m *SideInputTrigger) Reset()      { *m = SideInputTrigger{} }
 func (*SideInputTrigger) ProtoMessage() {}
 func (*SideInputTrigger) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{96}
+	return fileDescriptor_9d0d1b17d3865563, []int{121}
 }
 func (m *SideInputTrigger) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2770,7 +3470,7 @@ var xxx_messageInfo_SideInputTrigger proto.InternalMessageInfo
 func (m *SideInputsManagerTemplate) Reset()      { *m = SideInputsManagerTemplate{} }
 func (*SideInputsManagerTemplate) ProtoMessage() {}
 func (*SideInputsManagerTemplate) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{97}
+	return fileDescriptor_9d0d1b17d3865563, []int{122}
 }
 func (m *SideInputsManagerTemplate) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2798,7 +3498,7 @@ var xxx_messageInfo_SideInputsManagerTemplate proto.InternalMessageInfo
 func (m *Sink) Reset()      { *m = Sink{} }
 func (*Sink) ProtoMessage() {}
 func (*Sink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{98}
+	return fileDescriptor_9d0d1b17d3865563, []int{123}
 }
 func (m *Sink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2826,7 +3526,7 @@ var xxx_messageInfo_Sink proto.InternalMessageInfo
 func (m *SlidingWindow) Reset()      { *m = SlidingWindow{} }
 func (*SlidingWindow) ProtoMessage() {}
 func (*SlidingWindow) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{99}
+	return fileDescriptor_9d0d1b17d3865563, []int{124}
 }
 func (m *SlidingWindow) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2854,7 +3554,7 @@ var xxx_messageInfo_SlidingWindow proto.InternalMessageInfo
 func (m *Source) Reset()      { *m = Source{} }
 func (*Source) ProtoMessage() {}
 func (*Source) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{100}
+	return fileDescriptor_9d0d1b17d3865563, []int{125}
 }
 func (m *Source) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2882,7 +3582,7 @@ var xxx_messageInfo_Source proto.InternalMessageInfo
 func (m *SqsSink) Reset()      { *m = SqsSink{} }
 func (*SqsSink) ProtoMessage() {}
 func (*SqsSink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{101}
+	return fileDescriptor_9d0d1b17d3865563, []int{126}
 }
 func (m *SqsSink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2910,7 +3610,7 @@ var xxx_messageInfo_SqsSink proto.InternalMessageInfo
 func (m *SqsSource) Reset()      { *m = SqsSource{} }
 func (*SqsSource) ProtoMessage() {}
 func (*SqsSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{102}
+	return fileDescriptor_9d0d1b17d3865563, []int{127}
 }
 func (m *SqsSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2938,7 +3638,7 @@ var xxx_messageInfo_SqsSource proto.InternalMessageInfo
 func (m *Status) Reset()      { *m = Status{} }
 func (*Status) ProtoMessage() {}
 func (*Status) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{103}
+	return fileDescriptor_9d0d1b17d3865563, []int{128}
 }
 func (m *Status) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2966,7 +3666,7 @@ var xxx_messageInfo_Status proto.InternalMessageInfo
 func (m *TLS) Reset()      { *m = TLS{} }
 func (*TLS) ProtoMessage() {}
 func (*TLS) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{104}
+	return fileDescriptor_9d0d1b17d3865563, []int{129}
 }
 func (m *TLS) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2994,7 +3694,7 @@ var xxx_messageInfo_TLS proto.InternalMessageInfo
 func (m *TagConditions) Reset()      { *m = TagConditions{} }
 func (*TagConditions) ProtoMessage() {}
 func (*TagConditions) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{105}
+	return fileDescriptor_9d0d1b17d3865563, []int{130}
 }
 func (m *TagConditions) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3022,7 +3722,7 @@ var xxx_messageInfo_TagConditions proto.InternalMessageInfo
 func (m *Templates) Reset()      { *m = Templates{} }
 func (*Templates) ProtoMessage() {}
 func (*Templates) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{106}
+	return fileDescriptor_9d0d1b17d3865563, []int{131}
 }
 func (m *Templates) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3050,7 +3750,7 @@ var xxx_messageInfo_Templates proto.InternalMessageInfo
 func (m *UDF) Reset()      { *m = UDF{} }
 func (*UDF) ProtoMessage() {}
 func (*UDF) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{107}
+	return fileDescriptor_9d0d1b17d3865563, []int{132}
 }
 func (m *UDF) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3078,7 +3778,7 @@ var xxx_messageInfo_UDF proto.InternalMessageInfo
 func (m *UDSink) Reset()      { *m = UDSink{} }
 func (*UDSink) ProtoMessage() {}
 func (*UDSink) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{108}
+	return fileDescriptor_9d0d1b17d3865563, []int{133}
 }
 func (m *UDSink) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3106,7 +3806,7 @@ var xxx_messageInfo_UDSink proto.InternalMessageInfo
 func (m *UDSource) Reset()      { *m = UDSource{} }
 func (*UDSource) ProtoMessage() {}
 func (*UDSource) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{109}
+	return fileDescriptor_9d0d1b17d3865563, []int{134}
 }
 func (m *UDSource) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3134,7 +3834,7 @@ var xxx_messageInfo_UDSource proto.InternalMessageInfo
 func (m *UDTransformer) Reset()      { *m = UDTransformer{} }
 func (*UDTransformer) ProtoMessage() {}
 func (*UDTransformer) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{110}
+	return fileDescriptor_9d0d1b17d3865563, []int{135}
 }
 func (m *UDTransformer) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3162,7 +3862,7 @@ var xxx_messageInfo_UDTransformer proto.InternalMessageInfo
 func (m *UpdateStrategy) Reset()      { *m = UpdateStrategy{} }
 func (*UpdateStrategy) ProtoMessage() {}
 func (*UpdateStrategy) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{111}
+	return fileDescriptor_9d0d1b17d3865563, []int{136}
 }
 func (m *UpdateStrategy) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3190,7 +3890,7 @@ var xxx_messageInfo_UpdateStrategy proto.InternalMessageInfo
 func (m *Vertex) Reset()      { *m = Vertex{} }
 func (*Vertex) ProtoMessage() {}
 func (*Vertex) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{112}
+	return fileDescriptor_9d0d1b17d3865563, []int{137}
 }
 func (m *Vertex) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3218,7 +3918,7 @@ var xxx_messageInfo_Vertex proto.InternalMessageInfo
 func (m *VertexInstance) Reset()      { *m = VertexInstance{} }
 func (*VertexInstance) ProtoMessage() {}
 func (*VertexInstance) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{113}
+	return fileDescriptor_9d0d1b17d3865563, []int{138}
 }
 func (m *VertexInstance) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3246,7 +3946,7 @@ var xxx_messageInfo_VertexInstance proto.InternalMessageInfo
 func (m *VertexLifecycle) Reset()      { *m = VertexLifecycle{} }
 func (*VertexLifecycle) ProtoMessage() {}
 func (*VertexLifecycle) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{114}
+	return fileDescriptor_9d0d1b17d3865563, []int{139}
 }
 func (m *VertexLifecycle) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3274,7 +3974,7 @@ var xxx_messageInfo_VertexLifecycle proto.InternalMessageInfo
 func (m *VertexLimits) Reset()      { *m = VertexLimits{} }
 func (*VertexLimits) ProtoMessage() {}
 func (*VertexLimits) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{115}
+	return fileDescriptor_9d0d1b17d3865563, []int{140}
 }
 func (m *VertexLimits) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3302,7 +4002,7 @@ var xxx_messageInfo_VertexLimits proto.InternalMessageInfo
 func (m *VertexList) Reset()      { *m = VertexList{} }
 func (*VertexList) ProtoMessage() {}
 func (*VertexList) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{116}
+	return fileDescriptor_9d0d1b17d3865563, []int{141}
 }
 func (m *VertexList) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3330,7 +4030,7 @@ var xxx_messageInfo_VertexList proto.InternalMessageInfo
 func (m *VertexSpec) Reset()      { *m = VertexSpec{} }
 func (*VertexSpec) ProtoMessage() {}
 func (*VertexSpec) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{117}
+	return fileDescriptor_9d0d1b17d3865563, []int{142}
 }
 func (m *VertexSpec) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3358,7 +4058,7 @@ var xxx_messageInfo_VertexSpec proto.InternalMessageInfo
 func (m *VertexStatus) Reset()      { *m = VertexStatus{} }
 func (*VertexStatus) ProtoMessage() {}
 func (*VertexStatus) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{118}
+	return fileDescriptor_9d0d1b17d3865563, []int{143}
 }
 func (m *VertexStatus) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3386,7 +4086,7 @@ var xxx_messageInfo_VertexStatus proto.InternalMessageInfo
 func (m *VertexTemplate) Reset()      { *m = VertexTemplate{} }
 func (*VertexTemplate) ProtoMessage() {}
 func (*VertexTemplate) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{119}
+	return fileDescriptor_9d0d1b17d3865563, []int{144}
 }
 func (m *VertexTemplate) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3414,7 +4114,7 @@ var xxx_messageInfo_VertexTemplate proto.InternalMessageInfo
 func (m *Watermark) Reset()      { *m = Watermark{} }
 func (*Watermark) ProtoMessage() {}
 func (*Watermark) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{120}
+	return fileDescriptor_9d0d1b17d3865563, []int{145}
 }
 func (m *Watermark) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3439,10 +4139,38 @@ func (m *Watermark) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Watermark proto.InternalMessageInfo
 
+func (m *WatermarkStrategy) Reset()      { *m = WatermarkStrategy{} }
+func (*WatermarkStrategy) ProtoMessage() {}
+func (*WatermarkStrategy) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{146}
+}
+func (m *WatermarkStrategy) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *WatermarkStrategy) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *WatermarkStrategy) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_WatermarkStrategy.Merge(m, src)
+}
+func (m *WatermarkStrategy) XXX_Size() int {
+	return m.Size()
+}
+func (m *WatermarkStrategy) XXX_DiscardUnknown() {
+	xxx_messageInfo_WatermarkStrategy.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_WatermarkStrategy proto.InternalMessageInfo
+
 func (m *Window) Reset()      { *m = Window{} }
 func (*Window) ProtoMessage() {}
 func (*Window) Descriptor() ([]byte, []int) {
-	return fileDescriptor_9d0d1b17d3865563, []int{121}
+	return fileDescriptor_9d0d1b17d3865563, []int{147}
 }
 func (m *Window) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -3467,6 +4195,34 @@ func (m *Window) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Window proto.InternalMessageInfo
 
+func (m *WindowTrigger) Reset()      { *m = WindowTrigger{} }
+func (*WindowTrigger) ProtoMessage() {}
+func (*WindowTrigger) Descriptor() ([]byte, []int) {
+	return fileDescriptor_9d0d1b17d3865563, []int{148}
+}
+func (m *WindowTrigger) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *WindowTrigger) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	b = b[:cap(b)]
+	n, err := m.MarshalToSizedBuffer(b)
+	if err != nil {
+		return nil, err
+	}
+	return b[:n], nil
+}
+func (m *WindowTrigger) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_WindowTrigger.Merge(m, src)
+}
+func (m *WindowTrigger) XXX_Size() int {
+	return m.Size()
+}
+func (m *WindowTrigger) XXX_DiscardUnknown() {
+	xxx_messageInfo_WindowTrigger.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_WindowTrigger proto.InternalMessageInfo
+
 func init() {
 	proto.RegisterType((*AWSAssumeRole)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AWSAssumeRole")
 	proto.RegisterType((*AbstractPodTemplate)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AbstractPodTemplate")
@@ -3474,17 +4230,23 @@ func init() {
 	proto.RegisterType((*AbstractSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AbstractSink")
 	proto.RegisterType((*AbstractVertex)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AbstractVertex")
 	proto.RegisterType((*AccumulatorWindow)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AccumulatorWindow")
+	proto.RegisterType((*AdaptiveWatermark)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.AdaptiveWatermark")
 	proto.RegisterType((*Authorization)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Authorization")
 	proto.RegisterType((*Backoff)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Backoff")
 	proto.RegisterType((*BasicAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.BasicAuth")
 	proto.RegisterType((*Blackhole)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Blackhole")
+	proto.RegisterType((*BroadcastSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.BroadcastSink")
 	proto.RegisterType((*BufferServiceConfig)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.BufferServiceConfig")
+	proto.RegisterType((*BuiltinReducer)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.BuiltinReducer")
+	proto.RegisterType((*CircuitBreaker)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.CircuitBreaker")
 	proto.RegisterType((*CombinedEdge)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.CombinedEdge")
 	proto.RegisterType((*Compression)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Compression")
 	proto.RegisterType((*Container)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Container")
 	proto.RegisterType((*ContainerTemplate)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.ContainerTemplate")
 	proto.RegisterType((*DaemonTemplate)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.DaemonTemplate")
 	proto.RegisterType((*Edge)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Edge")
+	proto.RegisterType((*FileSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.FileSink")
+	proto.RegisterType((*FileSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.FileSource")
 	proto.RegisterType((*FixedWindow)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.FixedWindow")
 	proto.RegisterType((*ForwardConditions)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.ForwardConditions")
 	proto.RegisterType((*GSSAPI)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.GSSAPI")
@@ -3500,7 +4262,12 @@ func init() {
 	proto.RegisterType((*GetSideInputDeploymentReq)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.GetSideInputDeploymentReq")
 	proto.RegisterType((*GetVertexPodSpecReq)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.GetVertexPodSpecReq")
 	proto.RegisterType((*GroupBy)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.GroupBy")
+	proto.RegisterType((*HTTPSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.HTTPSink")
+	proto.RegisterMapType((map[string]string)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.HTTPSink.HeadersEntry")
+	proto.RegisterMapType((map[string]string)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.HTTPSink.HeaderMappingEntry")
+	proto.RegisterType((*HTTPSinkAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.HTTPSinkAuth")
 	proto.RegisterType((*HTTPSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.HTTPSource")
+	proto.RegisterType((*ISBBatching)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.ISBBatching")
 	proto.RegisterType((*IdleSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.IdleSource")
 	proto.RegisterType((*InterStepBuffer)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.InterStepBuffer")
 	proto.RegisterType((*InterStepBufferService)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.InterStepBufferService")
@@ -3509,11 +4276,14 @@ func init() {
 	proto.RegisterType((*InterStepBufferServiceStatus)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.InterStepBufferServiceStatus")
 	proto.RegisterType((*JetStreamBufferService)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.JetStreamBufferService")
 	proto.RegisterType((*JetStreamConfig)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.JetStreamConfig")
+	proto.RegisterType((*JetStreamSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.JetStreamSink")
 	proto.RegisterType((*JetStreamSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.JetStreamSource")
 	proto.RegisterType((*JobTemplate)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.JobTemplate")
 	proto.RegisterType((*KafkaSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.KafkaSink")
 	proto.RegisterType((*KafkaSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.KafkaSource")
+	proto.RegisterType((*LateData)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.LateData")
 	proto.RegisterType((*Lifecycle)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Lifecycle")
+	proto.RegisterType((*LocalObjectStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.LocalObjectStore")
 	proto.RegisterType((*Log)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Log")
 	proto.RegisterType((*Metadata)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Metadata")
 	proto.RegisterMapType((map[string]string)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Metadata.AnnotationsEntry")
@@ -3526,10 +4296,14 @@ func init() {
 	proto.RegisterType((*MonoVertexSpec)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.MonoVertexSpec")
 	proto.RegisterType((*MonoVertexStatus)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.MonoVertexStatus")
 	proto.RegisterType((*NatsAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.NatsAuth")
+	proto.RegisterType((*NatsSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.NatsSink")
 	proto.RegisterType((*NatsSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.NatsSource")
 	proto.RegisterType((*NoStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.NoStore")
+	proto.RegisterType((*ObjectStoreColumn)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.ObjectStoreColumn")
+	proto.RegisterType((*ObjectStoreSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.ObjectStoreSink")
 	proto.RegisterType((*Ordered)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Ordered")
 	proto.RegisterType((*PBQStorage)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PBQStorage")
+	proto.RegisterType((*PercentileWatermark)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PercentileWatermark")
 	proto.RegisterType((*PersistenceStrategy)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PersistenceStrategy")
 	proto.RegisterType((*Pipeline)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Pipeline")
 	proto.RegisterType((*PipelineLimits)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PipelineLimits")
@@ -3537,14 +4311,20 @@ func init() {
 	proto.RegisterType((*PipelineSpec)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PipelineSpec")
 	proto.RegisterType((*PipelineStatus)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PipelineStatus")
 	proto.RegisterType((*Ports)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Ports")
+	proto.RegisterType((*PostgresColumn)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PostgresColumn")
+	proto.RegisterType((*PostgresSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PostgresSink")
 	proto.RegisterType((*Probe)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Probe")
 	proto.RegisterType((*PulsarAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PulsarAuth")
 	proto.RegisterType((*PulsarBasicAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PulsarBasicAuth")
 	proto.RegisterType((*PulsarSink)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PulsarSink")
 	proto.RegisterType((*PulsarSource)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PulsarSource")
+	proto.RegisterType((*PunctuationWatermark)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.PunctuationWatermark")
 	proto.RegisterType((*RateLimit)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimit")
+	proto.RegisterType((*RateLimitKeyOverride)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimitKeyOverride")
+	proto.RegisterType((*RateLimitPerKey)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimitPerKey")
 	proto.RegisterType((*RateLimiterGoBackN)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterGoBackN")
 	proto.RegisterType((*RateLimiterInMemoryStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterInMemoryStore")
+	proto.RegisterType((*RateLimiterJetStreamStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterJetStreamStore")
 	proto.RegisterType((*RateLimiterModes)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterModes")
 	proto.RegisterType((*RateLimiterOnlyIfUsed)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterOnlyIfUsed")
 	proto.RegisterType((*RateLimiterRedisStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterRedisStore")
@@ -3552,9 +4332,12 @@ func init() {
 	proto.RegisterType((*RateLimiterScheduled)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterScheduled")
 	proto.RegisterType((*RateLimiterStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RateLimiterStore")
 	proto.RegisterType((*RedisAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RedisAuth")
+	proto.RegisterType((*RedisBufferService)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RedisBufferService")
+	proto.RegisterType((*RedisConfig)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RedisConfig")
 	proto.RegisterType((*RedisSentinelConfig)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RedisSentinelConfig")
 	proto.RegisterType((*RetryStrategy)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RetryStrategy")
 	proto.RegisterType((*RollingUpdateStrategy)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.RollingUpdateStrategy")
+	proto.RegisterType((*S3ObjectStore)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.S3ObjectStore")
 	proto.RegisterType((*SASL)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.SASL")
 	proto.RegisterType((*SASLOAuth)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.SASLOAuth")
 	proto.RegisterType((*SASLPlain)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.SASLPlain")
@@ -3594,7 +4377,9 @@ func init() {
 	proto.RegisterType((*VertexStatus)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.VertexStatus")
 	proto.RegisterType((*VertexTemplate)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.VertexTemplate")
 	proto.RegisterType((*Watermark)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Watermark")
+	proto.RegisterType((*WatermarkStrategy)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.WatermarkStrategy")
 	proto.RegisterType((*Window)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.Window")
+	proto.RegisterType((*WindowTrigger)(nil), "github.com.numaproj.numaflow.pkg.apis.numaflow.v1alpha1.WindowTrigger")
 }
 
 func init() {
@@ -3602,605 +4387,718 @@ func init() {
 }
 
 var fileDescriptor_9d0d1b17d3865563 = []byte{
-	// 9560 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xec, 0x7d, 0x5b, 0x6c, 0x24, 0x57,
-	0x76, 0x98, 0xfa, 0xdd, 0x7d, 0x9a, 0x8f, 0x99, 0x3b, 0xa3, 0x11, 0x67, 0x76, 0x34, 0x1c, 0x97,
-	0x2c, 0xad, 0x36, 0x2b, 0x93, 0xd1, 0xac, 0xa4, 0xd5, 0xae, 0x56, 0x2b, 0x75, 0x93, 0xc3, 0x19,
-	0x6a, 0xc8, 0x21, 0x75, 0x9a, 0x9c, 0xd1, 0xae, 0xb2, 0xab, 0x14, 0xab, 0x2f, 0x9b, 0x25, 0x56,
-	0x57, 0xf5, 0x54, 0x55, 0x73, 0x86, 0x72, 0x04, 0xad, 0x6d, 0x38, 0x5a, 0x27, 0x06, 0x12, 0x24,
-	0x08, 0x6c, 0x24, 0x71, 0x16, 0x06, 0x0c, 0xf8, 0x23, 0x30, 0x10, 0x24, 0xb1, 0x3f, 0x6c, 0x20,
-	0x8f, 0xfd, 0x70, 0x16, 0xf1, 0x3a, 0x59, 0x38, 0x01, 0xb2, 0x41, 0x12, 0x26, 0xcb, 0x24, 0x1f,
-	0xc9, 0x97, 0x63, 0xe4, 0x39, 0x09, 0xe0, 0xe0, 0xbe, 0xea, 0xd5, 0xd5, 0x23, 0xb2, 0xab, 0x87,
-	0x1a, 0xad, 0xf5, 0xd5, 0x5d, 0xe7, 0x9c, 0x7b, 0xce, 0xad, 0x5b, 0xf7, 0x71, 0xee, 0x39, 0xe7,
-	0x9e, 0x0b, 0xd7, 0x3a, 0xa6, 0xbf, 0xd3, 0xdf, 0x9a, 0x33, 0x9c, 0xee, 0xbc, 0xdd, 0xef, 0xea,
-	0x3d, 0xd7, 0x79, 0x97, 0xff, 0xd9, 0xb6, 0x9c, 0xbb, 0xf3, 0xbd, 0xdd, 0xce, 0xbc, 0xde, 0x33,
-	0xbd, 0x10, 0xb2, 0xf7, 0xbc, 0x6e, 0xf5, 0x76, 0xf4, 0xe7, 0xe7, 0x3b, 0xd4, 0xa6, 0xae, 0xee,
-	0xd3, 0xf6, 0x5c, 0xcf, 0x75, 0x7c, 0x87, 0x7c, 0x31, 0x64, 0x34, 0xa7, 0x18, 0xcd, 0xa9, 0x62,
-	0x73, 0xbd, 0xdd, 0xce, 0x1c, 0x63, 0x14, 0x42, 0x14, 0xa3, 0x0b, 0x3f, 0x15, 0xa9, 0x41, 0xc7,
-	0xe9, 0x38, 0xf3, 0x9c, 0xdf, 0x56, 0x7f, 0x9b, 0x3f, 0xf1, 0x07, 0xfe, 0x4f, 0xc8, 0xb9, 0xa0,
-	0xed, 0xbe, 0xec, 0xcd, 0x99, 0x0e, 0xab, 0xd6, 0xbc, 0xe1, 0xb8, 0x74, 0x7e, 0x6f, 0xa0, 0x2e,
-	0x17, 0x5e, 0x08, 0x69, 0xba, 0xba, 0xb1, 0x63, 0xda, 0xd4, 0xdd, 0x57, 0xef, 0x32, 0xef, 0x52,
-	0xcf, 0xe9, 0xbb, 0x06, 0x3d, 0x56, 0x29, 0x6f, 0xbe, 0x4b, 0x7d, 0x3d, 0x4d, 0xd6, 0xfc, 0xb0,
-	0x52, 0x6e, 0xdf, 0xf6, 0xcd, 0xee, 0xa0, 0x98, 0x97, 0x3e, 0xaa, 0x80, 0x67, 0xec, 0xd0, 0xae,
-	0x3e, 0x50, 0xee, 0x0b, 0xc3, 0xca, 0xf5, 0x7d, 0xd3, 0x9a, 0x37, 0x6d, 0xdf, 0xf3, 0xdd, 0x64,
-	0x21, 0xed, 0xef, 0xe4, 0x61, 0xb2, 0x71, 0xbb, 0xd5, 0xf0, 0xbc, 0x7e, 0x97, 0xa2, 0x63, 0x51,
-	0xf2, 0x39, 0xa8, 0xb8, 0x8e, 0x45, 0x1b, 0xae, 0x3d, 0x93, 0xbb, 0x9c, 0x7b, 0xb6, 0xd6, 0x9c,
-	0xfe, 0xde, 0xc1, 0xec, 0x63, 0x87, 0x07, 0xb3, 0x15, 0x86, 0x6e, 0xe0, 0x4d, 0x54, 0x78, 0xf2,
-	0x3c, 0xd4, 0x3d, 0xea, 0x79, 0xa6, 0x63, 0xdf, 0xd4, 0xbb, 0x74, 0x26, 0x2f, 0xc8, 0x0f, 0x0f,
-	0x66, 0xeb, 0xad, 0x10, 0x8c, 0x51, 0x1a, 0xf2, 0x2a, 0x4c, 0xb7, 0xfb, 0xae, 0xee, 0x9b, 0x8e,
-	0xdd, 0xa2, 0x86, 0x63, 0xb7, 0xbd, 0x99, 0xc2, 0xe5, 0xdc, 0xb3, 0xa5, 0xe6, 0x99, 0xc3, 0x83,
-	0xd9, 0xe9, 0xc5, 0x38, 0x0a, 0x93, 0xb4, 0x64, 0x0e, 0x80, 0xde, 0xf3, 0xa9, 0x6b, 0xeb, 0xd6,
-	0xf2, 0xe2, 0x4c, 0x91, 0x0b, 0x9c, 0x3a, 0x3c, 0x98, 0x85, 0xab, 0x01, 0x14, 0x23, 0x14, 0x44,
-	0x83, 0x72, 0xcf, 0xb1, 0x4c, 0x63, 0x7f, 0xa6, 0xc4, 0x69, 0xe1, 0xf0, 0x60, 0xb6, 0xbc, 0xce,
-	0x21, 0x28, 0x31, 0x8c, 0xa7, 0xf8, 0xd7, 0x70, 0x6d, 0x6f, 0xa6, 0x7c, 0xb9, 0xa0, 0x78, 0x0a,
-	0xba, 0x06, 0xde, 0xf4, 0x30, 0x42, 0xa1, 0xfd, 0x63, 0x80, 0x33, 0x8d, 0x2d, 0xcf, 0x77, 0x75,
-	0xc3, 0x5f, 0x77, 0xda, 0x1b, 0xb4, 0xdb, 0xb3, 0x74, 0x9f, 0x92, 0x5d, 0xa8, 0xb2, 0x3e, 0xd0,
-	0xd6, 0x7d, 0x9d, 0xb7, 0x5c, 0xfd, 0x4a, 0x63, 0x6e, 0xc4, 0x3e, 0x3f, 0xb7, 0x2a, 0x19, 0x35,
-	0x27, 0x0e, 0x0f, 0x66, 0xab, 0xea, 0x09, 0x03, 0x01, 0xe4, 0x97, 0x73, 0x30, 0x61, 0x3b, 0x6d,
-	0xda, 0xa2, 0x16, 0x35, 0x7c, 0xc7, 0x9d, 0xc9, 0x5f, 0x2e, 0x3c, 0x5b, 0xbf, 0xf2, 0xcd, 0x91,
-	0x25, 0xa6, 0xbc, 0xd1, 0xdc, 0xcd, 0x88, 0x80, 0xab, 0xb6, 0xef, 0xee, 0x37, 0xcf, 0xca, 0xbe,
-	0x30, 0x11, 0x45, 0x61, 0xac, 0x26, 0x64, 0x13, 0xea, 0xbe, 0x63, 0x51, 0xf1, 0xe1, 0xd8, 0xe7,
-	0x65, 0x15, 0xbb, 0x34, 0x27, 0x7a, 0x27, 0x13, 0x3f, 0xc7, 0x86, 0xe5, 0xdc, 0xde, 0xf3, 0x73,
-	0x1b, 0x01, 0x59, 0xf3, 0x8c, 0x64, 0x5c, 0x0f, 0x61, 0x1e, 0x46, 0xf9, 0x10, 0x0a, 0xd3, 0x1e,
-	0x35, 0xfa, 0xae, 0xe9, 0xef, 0x2f, 0x38, 0xb6, 0x4f, 0xef, 0xf9, 0xfc, 0xfb, 0xd7, 0xaf, 0x3c,
-	0x93, 0xc6, 0x7a, 0xdd, 0x69, 0xb7, 0xe2, 0xd4, 0xa2, 0x87, 0x25, 0x80, 0x98, 0xe4, 0x49, 0x6c,
-	0x38, 0x65, 0x76, 0xf5, 0x0e, 0x5d, 0xef, 0x5b, 0x56, 0x8b, 0x1a, 0x2e, 0xf5, 0xbd, 0x99, 0x12,
-	0x7f, 0x85, 0x67, 0xd3, 0xe4, 0xac, 0x38, 0x86, 0x6e, 0xad, 0x6d, 0xbd, 0x4b, 0x0d, 0x1f, 0xe9,
-	0x36, 0x75, 0xa9, 0x6d, 0xd0, 0xe6, 0x8c, 0x7c, 0x99, 0x53, 0xcb, 0x09, 0x4e, 0x38, 0xc0, 0x9b,
-	0x5c, 0x83, 0xd3, 0x3d, 0xd7, 0x74, 0x78, 0x15, 0x2c, 0xdd, 0xf3, 0xf8, 0x48, 0x2a, 0xf3, 0xce,
-	0x7a, 0x5e, 0xb2, 0x39, 0xbd, 0x9e, 0x24, 0xc0, 0xc1, 0x32, 0xe4, 0x59, 0xa8, 0x2a, 0xe0, 0x4c,
-	0x85, 0x0f, 0x29, 0xde, 0x77, 0x54, 0x59, 0x0c, 0xb0, 0x64, 0x09, 0xaa, 0xfa, 0xf6, 0xb6, 0x69,
-	0x33, 0xca, 0x2a, 0x6f, 0xc2, 0x8b, 0x69, 0xaf, 0xd6, 0x90, 0x34, 0x82, 0x8f, 0x7a, 0xc2, 0xa0,
-	0x2c, 0x79, 0x03, 0x88, 0x47, 0xdd, 0x3d, 0xd3, 0xa0, 0x0d, 0xc3, 0x70, 0xfa, 0xb6, 0xcf, 0xeb,
-	0x5e, 0xe3, 0x75, 0xbf, 0x20, 0xeb, 0x4e, 0x5a, 0x03, 0x14, 0x98, 0x52, 0x8a, 0xbc, 0x0e, 0xa7,
-	0xe4, 0xf4, 0x16, 0xb6, 0x02, 0x70, 0x4e, 0x67, 0x59, 0x43, 0x62, 0x02, 0x87, 0x03, 0xd4, 0xa4,
-	0x0d, 0x17, 0xf5, 0xbe, 0xef, 0x74, 0x19, 0xcb, 0xb8, 0xd0, 0x0d, 0x67, 0x97, 0xda, 0x33, 0xf5,
-	0xcb, 0xb9, 0x67, 0xab, 0xcd, 0xcb, 0x87, 0x07, 0xb3, 0x17, 0x1b, 0x0f, 0xa0, 0xc3, 0x07, 0x72,
-	0x21, 0x6b, 0x50, 0x6b, 0xdb, 0x9e, 0x98, 0x19, 0x66, 0x26, 0x78, 0x05, 0x9f, 0x97, 0xaf, 0x5a,
-	0x5b, 0xbc, 0xd9, 0x12, 0x88, 0xfb, 0x07, 0xb3, 0x17, 0x07, 0x57, 0xa1, 0xb9, 0x00, 0x8f, 0x21,
-	0x0f, 0xb2, 0xca, 0x19, 0x2e, 0x38, 0xf6, 0xb6, 0xd9, 0x99, 0x99, 0xe4, 0x5f, 0xe3, 0xf2, 0x90,
-	0x0e, 0xbd, 0x78, 0xb3, 0x25, 0xe8, 0x9a, 0x93, 0x52, 0x9c, 0x78, 0xc4, 0x90, 0x03, 0x69, 0xc3,
-	0x94, 0x5a, 0xbf, 0x16, 0x2c, 0xdd, 0xec, 0x7a, 0x33, 0x53, 0xbc, 0xf3, 0xfe, 0xe4, 0x10, 0x9e,
-	0x18, 0x25, 0x6e, 0x9e, 0x93, 0xaf, 0x32, 0x15, 0x03, 0x7b, 0x98, 0xe0, 0x79, 0xe1, 0x35, 0x38,
-	0x3d, 0x30, 0x37, 0x90, 0x53, 0x50, 0xd8, 0xa5, 0xfb, 0x62, 0xd1, 0x40, 0xf6, 0x97, 0x9c, 0x85,
-	0xd2, 0x9e, 0x6e, 0xf5, 0xe5, 0xca, 0x80, 0xe2, 0xe1, 0xcb, 0xf9, 0x97, 0x73, 0xda, 0xef, 0x97,
-	0x60, 0x42, 0xcd, 0x38, 0x2d, 0xd3, 0xde, 0x25, 0xb7, 0xa1, 0x60, 0x39, 0x1d, 0x39, 0x6f, 0x7e,
-	0x65, 0xe4, 0x59, 0x6c, 0xc5, 0xe9, 0x34, 0x2b, 0x87, 0x07, 0xb3, 0x85, 0x15, 0xa7, 0x83, 0x8c,
-	0x23, 0x31, 0xa0, 0xb4, 0xab, 0x6f, 0xef, 0xea, 0xbc, 0x0e, 0xf5, 0x2b, 0xcd, 0x91, 0x59, 0xdf,
-	0x60, 0x5c, 0x58, 0x5d, 0x9b, 0xb5, 0xc3, 0x83, 0xd9, 0x12, 0x7f, 0x44, 0xc1, 0x9b, 0x38, 0x50,
-	0xdb, 0xb2, 0x74, 0x63, 0x77, 0xc7, 0xb1, 0x28, 0x5f, 0xcf, 0xb2, 0x08, 0x6a, 0x2a, 0x4e, 0xe2,
-	0x33, 0x07, 0x8f, 0x18, 0xca, 0x20, 0x06, 0x94, 0xfb, 0x6d, 0xcf, 0xb4, 0x77, 0xe5, 0x1c, 0xf8,
-	0xda, 0xc8, 0xd2, 0x36, 0x17, 0xf9, 0x3b, 0xf1, 0x85, 0x51, 0xfc, 0x47, 0xc9, 0x9a, 0x35, 0x1d,
-	0x1b, 0xa9, 0x94, 0xaf, 0x9d, 0x59, 0xde, 0x88, 0x0d, 0x24, 0x1a, 0x36, 0x1d, 0x7f, 0x44, 0xc1,
-	0x9b, 0xbc, 0x0d, 0x05, 0xef, 0x8e, 0xc7, 0x67, 0xbc, 0xfa, 0x95, 0xd7, 0x47, 0x17, 0x71, 0xc7,
-	0xe3, 0x02, 0xf8, 0xc7, 0x6f, 0xdd, 0xf1, 0x90, 0x71, 0x25, 0x1d, 0x28, 0xf7, 0xfa, 0x96, 0xa7,
-	0xbb, 0x7c, 0x46, 0xac, 0x5f, 0x59, 0x18, 0x99, 0xff, 0x3a, 0x67, 0x13, 0x36, 0x95, 0x78, 0x46,
-	0xc9, 0x5e, 0xfb, 0xd1, 0x24, 0x4c, 0xa9, 0xfe, 0x7c, 0x8b, 0xba, 0x3e, 0xbd, 0x47, 0x2e, 0x43,
-	0xd1, 0x66, 0xb3, 0x98, 0x50, 0xa2, 0x26, 0xe4, 0xc8, 0x2a, 0xf2, 0xd9, 0x8b, 0x63, 0xd8, 0x47,
-	0x14, 0xa3, 0x4a, 0xf6, 0xcd, 0xd1, 0x3f, 0x62, 0x8b, 0xb3, 0x11, 0x35, 0x13, 0xff, 0x51, 0xb2,
-	0x26, 0x6f, 0x43, 0x91, 0xf7, 0x13, 0xd1, 0x2b, 0x5f, 0x1d, 0x5d, 0x04, 0x7b, 0xf5, 0x2a, 0x7b,
-	0x03, 0xde, 0x47, 0x38, 0x53, 0x36, 0x6a, 0xfb, 0xed, 0x6d, 0xd9, 0x07, 0xbf, 0x92, 0xa1, 0x0f,
-	0x2e, 0x89, 0x0f, 0xb7, 0xb9, 0xb8, 0x84, 0x8c, 0x23, 0xf9, 0x4b, 0x39, 0x38, 0x6d, 0x38, 0xb6,
-	0xaf, 0x33, 0x2d, 0x56, 0xe9, 0x23, 0xb2, 0x1f, 0xbe, 0x31, 0xb2, 0x9c, 0x85, 0x24, 0xc7, 0xe6,
-	0xe3, 0x6c, 0x79, 0x1d, 0x00, 0xe3, 0xa0, 0x6c, 0xf2, 0x37, 0x72, 0xf0, 0x38, 0x5b, 0xf6, 0x06,
-	0x88, 0x65, 0xd7, 0x1d, 0x67, 0xad, 0xce, 0x1f, 0x1e, 0xcc, 0x3e, 0xbe, 0x9c, 0x26, 0x0c, 0xd3,
-	0xeb, 0xc0, 0x6a, 0x77, 0x46, 0x1f, 0xd4, 0xe0, 0x64, 0xb7, 0x5f, 0x19, 0xa7, 0x56, 0xd8, 0xfc,
-	0x8c, 0xec, 0xca, 0x69, 0x4a, 0x30, 0xa6, 0xd5, 0x82, 0x5c, 0x85, 0xca, 0x9e, 0x63, 0xf5, 0xbb,
-	0xd4, 0x9b, 0xa9, 0xf2, 0xd5, 0xe8, 0x42, 0xda, 0x6a, 0x74, 0x8b, 0x93, 0x84, 0xdb, 0x0d, 0xf1,
-	0xec, 0xa1, 0x2a, 0x4b, 0x4c, 0x28, 0x5b, 0x66, 0xd7, 0xf4, 0x3d, 0xae, 0x63, 0xd4, 0xaf, 0x5c,
-	0x1d, 0xf9, 0xb5, 0xc4, 0x10, 0x5d, 0xe1, 0xcc, 0xc4, 0xa8, 0x11, 0xff, 0x51, 0x0a, 0xe0, 0x53,
-	0x9f, 0xa1, 0x5b, 0x42, 0x07, 0xa9, 0x5f, 0xf9, 0xea, 0xe8, 0xc3, 0x86, 0x71, 0x69, 0x4e, 0xca,
-	0x77, 0x2a, 0xf1, 0x47, 0x14, 0xbc, 0xc9, 0x37, 0x60, 0x2a, 0xf6, 0x35, 0xbd, 0x99, 0x3a, 0x6f,
-	0x9d, 0x27, 0xd3, 0x5a, 0x27, 0xa0, 0x0a, 0x17, 0xe9, 0x58, 0x0f, 0xf1, 0x30, 0xc1, 0x8c, 0xdc,
-	0x80, 0xaa, 0x67, 0xb6, 0xa9, 0xa1, 0xbb, 0xde, 0xcc, 0xc4, 0x51, 0x18, 0x9f, 0x92, 0x8c, 0xab,
-	0x2d, 0x59, 0x0c, 0x03, 0x06, 0x7c, 0x93, 0xa4, 0xbb, 0xbe, 0x29, 0x74, 0xfa, 0x49, 0xae, 0x5f,
-	0x8a, 0x4d, 0x52, 0x00, 0xc5, 0x08, 0x05, 0xa3, 0x67, 0x65, 0x97, 0xed, 0x5e, 0xdf, 0x17, 0x3a,
-	0x88, 0xdc, 0x54, 0xb5, 0x02, 0x28, 0x46, 0x28, 0xc8, 0x6f, 0xe4, 0xe0, 0x33, 0xe1, 0xe3, 0xe0,
-	0x20, 0x9b, 0x1e, 0xfb, 0x20, 0x9b, 0x3d, 0x3c, 0x98, 0xfd, 0x4c, 0x6b, 0xb8, 0x48, 0x7c, 0x50,
-	0x7d, 0xc8, 0x87, 0x39, 0x98, 0xea, 0xf7, 0xda, 0xba, 0x4f, 0x5b, 0x3e, 0xdb, 0x4f, 0x77, 0xf6,
-	0x67, 0x4e, 0xf1, 0x2a, 0x5e, 0x1b, 0x7d, 0x16, 0x8c, 0xb1, 0x0b, 0x3f, 0x73, 0x1c, 0x8e, 0x09,
-	0xb1, 0xa4, 0x03, 0x15, 0xc7, 0x6d, 0x53, 0x97, 0xb6, 0x67, 0x4e, 0x67, 0x5c, 0x44, 0xd7, 0x04,
-	0x9f, 0x66, 0x9d, 0x0d, 0x3f, 0xf9, 0x80, 0x8a, 0xbb, 0xf6, 0x2e, 0x9c, 0x6e, 0x18, 0x46, 0xbf,
-	0xdb, 0xb7, 0x74, 0xdf, 0x71, 0x6f, 0x9b, 0x76, 0xdb, 0xb9, 0x4b, 0x36, 0xa1, 0xc2, 0xd4, 0x70,
-	0xa7, 0xef, 0x4b, 0xdd, 0x6d, 0x2e, 0xd2, 0xc7, 0x02, 0x33, 0x44, 0x28, 0x94, 0x6d, 0x60, 0x59,
-	0xaf, 0x53, 0x3b, 0x7d, 0x21, 0x6b, 0x43, 0xb0, 0x40, 0xc5, 0x4b, 0xbb, 0x0d, 0x93, 0x8d, 0xbe,
-	0xbf, 0xe3, 0xb8, 0xe6, 0x7b, 0x9c, 0x8c, 0x2c, 0x41, 0xc9, 0xe7, 0x6a, 0xbc, 0x90, 0xf2, 0x74,
-	0x5a, 0x4f, 0x16, 0x5b, 0xaa, 0x1b, 0x74, 0x5f, 0xe9, 0xa5, 0x42, 0xdd, 0x10, 0x6a, 0xbd, 0x28,
-	0xae, 0xfd, 0x52, 0x1e, 0x2a, 0x4d, 0xdd, 0xd8, 0x75, 0xb6, 0xb7, 0xc9, 0x5b, 0x50, 0x35, 0x6d,
-	0x9f, 0xba, 0x7b, 0xba, 0x35, 0x62, 0xe5, 0xf9, 0xce, 0x68, 0x59, 0xf2, 0xc0, 0x80, 0x1b, 0x99,
-	0x85, 0x92, 0xe7, 0xd3, 0x9e, 0xc7, 0x17, 0xf6, 0x49, 0xa9, 0xf5, 0x30, 0x00, 0x0a, 0x38, 0xd1,
-	0xa0, 0xbc, 0xad, 0xf3, 0x7d, 0x3b, 0x5b, 0x97, 0x73, 0x62, 0x0e, 0x5a, 0xe2, 0x10, 0x94, 0x18,
-	0xb2, 0x0c, 0x05, 0x43, 0xef, 0xc9, 0xc5, 0xf5, 0xb8, 0x35, 0xe3, 0xcb, 0xe9, 0x82, 0xde, 0x43,
-	0xc6, 0x83, 0x89, 0x7b, 0xd7, 0xf4, 0x7d, 0xea, 0xf2, 0x25, 0x54, 0x8a, 0x7b, 0x83, 0x43, 0x50,
-	0x62, 0xb4, 0x5f, 0xcd, 0x41, 0xad, 0xa9, 0x7b, 0xa6, 0xc1, 0x1a, 0x9e, 0x2c, 0x40, 0xb1, 0xef,
-	0x51, 0xf7, 0x78, 0xcd, 0xcd, 0xd5, 0x83, 0x4d, 0x8f, 0xba, 0xc8, 0x0b, 0x93, 0x35, 0xa8, 0xf6,
-	0x74, 0xcf, 0xbb, 0xeb, 0xb8, 0x6d, 0xa9, 0xe2, 0x1c, 0x91, 0x91, 0xd8, 0xb9, 0xca, 0xa2, 0x18,
-	0x30, 0xd1, 0xea, 0x10, 0xaa, 0xc3, 0xda, 0x2f, 0xe6, 0xe0, 0x4c, 0xb3, 0xbf, 0xbd, 0x4d, 0x5d,
-	0xb9, 0x51, 0x93, 0x5b, 0xa0, 0x3e, 0xd4, 0xde, 0xa5, 0xbe, 0xe7, 0xbb, 0x54, 0xef, 0x4a, 0xb1,
-	0xd7, 0x47, 0x1e, 0x12, 0x6f, 0x50, 0xbf, 0xc5, 0x39, 0x45, 0x77, 0x5e, 0x01, 0x10, 0x43, 0x49,
-	0xda, 0x77, 0xcb, 0x30, 0xb1, 0xe0, 0x74, 0xb7, 0x4c, 0x9b, 0xb6, 0xaf, 0xb6, 0x3b, 0x94, 0xbc,
-	0x03, 0x45, 0xda, 0xee, 0x50, 0xd9, 0x84, 0xa3, 0x6b, 0x5e, 0x8c, 0x59, 0xa8, 0x3f, 0xb2, 0x27,
-	0xe4, 0x8c, 0xc9, 0x0a, 0x4c, 0x6d, 0xbb, 0x4e, 0x57, 0x2c, 0x66, 0x1b, 0xfb, 0x3d, 0x65, 0x81,
-	0xfb, 0x49, 0x35, 0x73, 0x2c, 0xc5, 0xb0, 0xf7, 0x0f, 0x66, 0x21, 0x7c, 0xc2, 0x44, 0x59, 0xf2,
-	0x16, 0xcc, 0x84, 0x90, 0x60, 0x56, 0x5f, 0x60, 0x5b, 0x5f, 0x69, 0xa2, 0xbb, 0x78, 0x78, 0x30,
-	0x3b, 0xb3, 0x34, 0x84, 0x06, 0x87, 0x96, 0x66, 0x73, 0xe5, 0xa9, 0x10, 0x29, 0x56, 0x5a, 0xd9,
-	0xad, 0xc7, 0xb4, 0x84, 0x73, 0x1b, 0xc1, 0x52, 0x42, 0x04, 0x0e, 0x08, 0x25, 0x4b, 0x30, 0xe1,
-	0x3b, 0x91, 0xf6, 0x12, 0x46, 0x41, 0x4d, 0x19, 0xb5, 0x36, 0x9c, 0xa1, 0xad, 0x15, 0x2b, 0x47,
-	0x10, 0xce, 0xa9, 0xe7, 0x44, 0x4b, 0x95, 0x79, 0x4b, 0x5d, 0x38, 0x3c, 0x98, 0x3d, 0xb7, 0x91,
-	0x4a, 0x81, 0x43, 0x4a, 0x92, 0x9f, 0xc9, 0xc1, 0x94, 0x42, 0xc9, 0x36, 0xaa, 0x8c, 0xb3, 0x8d,
-	0x08, 0xeb, 0x11, 0x1b, 0x31, 0x01, 0x98, 0x10, 0x48, 0x3e, 0x80, 0x69, 0x05, 0x91, 0xd3, 0xbf,
-	0x34, 0x10, 0x65, 0x5f, 0x53, 0xb8, 0xf5, 0x6d, 0x23, 0xce, 0x1c, 0x93, 0xd2, 0xb4, 0x26, 0xd4,
-	0x17, 0x9c, 0x6e, 0xcf, 0x15, 0x16, 0x63, 0xf2, 0x05, 0x28, 0xfa, 0xec, 0x3b, 0x89, 0x3d, 0xd4,
-	0xac, 0x1a, 0x03, 0xf2, 0xfb, 0x4c, 0x47, 0x48, 0xf9, 0x47, 0xe2, 0xc4, 0xda, 0x6f, 0x56, 0xa0,
-	0x16, 0x2c, 0xd8, 0xe4, 0x29, 0x28, 0x71, 0x9b, 0x9b, 0xe4, 0x11, 0x68, 0x62, 0xdc, 0x34, 0x87,
-	0x02, 0x47, 0x9e, 0x86, 0x8a, 0xe1, 0x74, 0xbb, 0xba, 0xdd, 0xe6, 0x76, 0xd4, 0x9a, 0x58, 0x95,
-	0x16, 0x04, 0x08, 0x15, 0x8e, 0x5c, 0x84, 0xa2, 0xee, 0x76, 0x84, 0x49, 0xb3, 0x26, 0x66, 0xbb,
-	0x86, 0xdb, 0xf1, 0x90, 0x43, 0xc9, 0x97, 0xa0, 0x40, 0xed, 0xbd, 0x99, 0xe2, 0x70, 0x0d, 0xf7,
-	0xaa, 0xbd, 0x77, 0x4b, 0x77, 0x9b, 0x75, 0x59, 0x87, 0xc2, 0x55, 0x7b, 0x0f, 0x59, 0x19, 0xb2,
-	0x02, 0x15, 0x6a, 0xef, 0xb1, 0x0e, 0x2c, 0x6d, 0x8d, 0x3f, 0x31, 0xa4, 0x38, 0x23, 0x91, 0x9b,
-	0xbd, 0x40, 0x4f, 0x96, 0x60, 0x54, 0x2c, 0xc8, 0xd7, 0x60, 0x42, 0xa8, 0xcc, 0xab, 0xac, 0x63,
-	0x09, 0x93, 0x76, 0xfd, 0xca, 0xec, 0x70, 0x9d, 0x9b, 0xd3, 0x85, 0xb6, 0xdd, 0x08, 0xd0, 0xc3,
-	0x18, 0x2b, 0xf2, 0x35, 0xa8, 0x29, 0x53, 0x90, 0xea, 0x9e, 0xa9, 0x66, 0x51, 0x65, 0x3f, 0x42,
-	0x7a, 0xa7, 0x6f, 0xba, 0xb4, 0x4b, 0x6d, 0xdf, 0x6b, 0x9e, 0x56, 0x86, 0x32, 0x85, 0xf5, 0x30,
-	0xe4, 0x46, 0xb6, 0x06, 0xed, 0xbb, 0xa2, 0xef, 0x3d, 0x35, 0x64, 0xcd, 0x18, 0xc1, 0xb8, 0xfb,
-	0x4d, 0x98, 0x0e, 0x0c, 0xb0, 0xd2, 0x86, 0x27, 0xcc, 0x95, 0x2f, 0xb0, 0xe2, 0xcb, 0x71, 0xd4,
-	0xfd, 0x83, 0xd9, 0x27, 0x53, 0xac, 0x78, 0x21, 0x01, 0x26, 0x99, 0x91, 0xf7, 0x60, 0xca, 0xa5,
-	0x7a, 0xdb, 0xb4, 0xa9, 0xe7, 0xad, 0xbb, 0xce, 0x56, 0xf6, 0xfd, 0x03, 0xe7, 0x22, 0xc6, 0x2e,
-	0xc6, 0x38, 0x63, 0x42, 0x12, 0xb9, 0x0b, 0x93, 0x96, 0xb9, 0x47, 0x43, 0xd1, 0xf5, 0xb1, 0x88,
-	0x3e, 0x7d, 0x78, 0x30, 0x3b, 0xb9, 0x12, 0x65, 0x8c, 0x71, 0x39, 0x4c, 0x35, 0xeb, 0x39, 0xae,
-	0xaf, 0x36, 0x19, 0x3f, 0xf1, 0xc0, 0x4d, 0xc6, 0xba, 0xe3, 0xfa, 0xe1, 0x20, 0x64, 0x4f, 0x1e,
-	0x8a, 0xe2, 0xda, 0xdf, 0x2f, 0xc1, 0xe0, 0x56, 0x3c, 0xde, 0xe3, 0x72, 0xe3, 0xee, 0x71, 0xc9,
-	0xde, 0x20, 0x16, 0xd0, 0x97, 0x65, 0xb1, 0x31, 0xf4, 0x88, 0x94, 0x5e, 0x5d, 0x18, 0x77, 0xaf,
-	0x7e, 0x64, 0x26, 0x9e, 0xc1, 0xee, 0x5f, 0xfe, 0xf8, 0xba, 0x7f, 0xe5, 0x64, 0xba, 0xbf, 0xf6,
-	0xed, 0x22, 0x4c, 0x2d, 0xea, 0xb4, 0xeb, 0xd8, 0x1f, 0x69, 0x8d, 0xc9, 0x3d, 0x12, 0xd6, 0x98,
-	0x67, 0xa1, 0xea, 0xd2, 0x9e, 0x65, 0x1a, 0xba, 0xd8, 0x9f, 0x48, 0x47, 0x11, 0x4a, 0x18, 0x06,
-	0xd8, 0x21, 0x56, 0xb8, 0xc2, 0x23, 0x69, 0x85, 0x2b, 0x7e, 0xfc, 0x56, 0x38, 0xed, 0x67, 0xf2,
-	0xc0, 0xf5, 0x73, 0x72, 0x19, 0x8a, 0x4c, 0xf7, 0x4c, 0xda, 0x7e, 0xf9, 0x68, 0xe1, 0x18, 0x72,
-	0x01, 0xf2, 0xbe, 0x23, 0xa7, 0x1b, 0x90, 0xf8, 0xfc, 0x86, 0x83, 0x79, 0xdf, 0x21, 0xef, 0x01,
-	0x18, 0x8e, 0xdd, 0x36, 0x95, 0xff, 0x34, 0xdb, 0x8b, 0x2d, 0x39, 0xee, 0x5d, 0xdd, 0x6d, 0x2f,
-	0x04, 0x1c, 0x85, 0x1d, 0x26, 0x7c, 0xc6, 0x88, 0x34, 0xf2, 0x1a, 0x94, 0x1d, 0x7b, 0xa9, 0x6f,
-	0x59, 0xd2, 0xb9, 0xfe, 0x59, 0xb6, 0x53, 0x5c, 0xe3, 0x90, 0xfb, 0x07, 0xb3, 0xe7, 0xc5, 0x7e,
-	0x8b, 0x3d, 0xdd, 0x76, 0x4d, 0xdf, 0xb4, 0x3b, 0x81, 0x59, 0x42, 0x16, 0xd3, 0xfe, 0x4a, 0x0e,
-	0xea, 0x4b, 0xe6, 0x3d, 0xda, 0x96, 0x06, 0x02, 0x84, 0xb2, 0x45, 0xed, 0x8e, 0xbf, 0x33, 0xe2,
-	0x16, 0x5b, 0x58, 0xe7, 0x38, 0x07, 0x94, 0x9c, 0xc8, 0x3c, 0xd4, 0xc4, 0xa6, 0xcb, 0xb4, 0x3b,
-	0xbc, 0x0d, 0xab, 0xe1, 0x4c, 0xdf, 0x52, 0x08, 0x0c, 0x69, 0xb4, 0xf7, 0xe1, 0xf4, 0x40, 0x33,
-	0x90, 0x1d, 0x28, 0xfa, 0x7a, 0x47, 0x2d, 0x2a, 0x4b, 0x23, 0x37, 0xf0, 0x86, 0xde, 0x89, 0x34,
-	0x6e, 0xf0, 0xb1, 0x37, 0x74, 0xa6, 0x19, 0x32, 0x09, 0xda, 0x6f, 0x15, 0xa1, 0x7c, 0xad, 0xd5,
-	0x6a, 0xac, 0x2f, 0x93, 0x17, 0xa1, 0x2e, 0xbd, 0x9f, 0x37, 0x43, 0xe7, 0x40, 0xe0, 0xfc, 0x6e,
-	0x85, 0x28, 0x8c, 0xd2, 0x31, 0x2d, 0xd6, 0xa5, 0xba, 0xd5, 0x95, 0x3d, 0x26, 0x58, 0x40, 0x91,
-	0x01, 0x51, 0xe0, 0x88, 0x0e, 0x53, 0x6c, 0xdb, 0x6d, 0xeb, 0x5d, 0x2a, 0xb6, 0xd4, 0xb2, 0xef,
-	0x1c, 0x71, 0xd3, 0xcd, 0x67, 0xd9, 0xcd, 0x18, 0x03, 0x4c, 0x30, 0x24, 0x2f, 0x43, 0x55, 0xef,
-	0xfb, 0x3b, 0x7c, 0xf3, 0x24, 0x3a, 0xc8, 0x45, 0xee, 0x1c, 0x96, 0xb0, 0xfb, 0x07, 0xb3, 0x13,
-	0x37, 0xb0, 0xf9, 0xa2, 0x7a, 0xc6, 0x80, 0x9a, 0x55, 0x4e, 0x6d, 0xe3, 0x65, 0xe5, 0x4a, 0xc7,
-	0xae, 0xdc, 0x7a, 0x8c, 0x01, 0x26, 0x18, 0x92, 0xb7, 0x61, 0x62, 0x97, 0xee, 0xfb, 0xfa, 0x96,
-	0x14, 0x50, 0x3e, 0x8e, 0x80, 0x53, 0x4c, 0xf3, 0xbd, 0x11, 0x29, 0x8e, 0x31, 0x66, 0xc4, 0x83,
-	0xb3, 0xbb, 0xd4, 0xdd, 0xa2, 0xae, 0x23, 0x5d, 0xad, 0x52, 0x48, 0xe5, 0x38, 0x42, 0x66, 0x0e,
-	0x0f, 0x66, 0xcf, 0xde, 0x48, 0x61, 0x83, 0xa9, 0xcc, 0xb5, 0xff, 0x93, 0x87, 0xe9, 0x6b, 0x22,
-	0x62, 0xc7, 0x71, 0xc5, 0xf2, 0x4b, 0xce, 0x43, 0xc1, 0xed, 0xf5, 0x79, 0xcf, 0x29, 0x08, 0x33,
-	0x0f, 0xae, 0x6f, 0x22, 0x83, 0x91, 0xb7, 0xa0, 0xaa, 0x02, 0x66, 0xa4, 0xe1, 0x63, 0x24, 0x83,
-	0x96, 0x7a, 0xc2, 0x80, 0x1b, 0xdb, 0x20, 0x75, 0xbd, 0x4e, 0xcb, 0x7c, 0x8f, 0x4a, 0x5b, 0x00,
-	0xdf, 0x20, 0xad, 0x0a, 0x10, 0x2a, 0x1c, 0x5b, 0x5a, 0x76, 0xe9, 0xbe, 0xd8, 0x09, 0x17, 0xc3,
-	0xa5, 0xe5, 0x86, 0x84, 0x61, 0x80, 0x25, 0xb3, 0xca, 0x35, 0xcc, 0x7a, 0x41, 0x51, 0x58, 0xc8,
-	0x6e, 0x31, 0x80, 0xf4, 0x12, 0xb3, 0x79, 0x43, 0x9a, 0xac, 0xca, 0xa3, 0xcf, 0x1b, 0x71, 0x13,
-	0x17, 0xf9, 0x3c, 0xd4, 0x38, 0xf3, 0xa6, 0xe5, 0x6c, 0xf1, 0x0f, 0x57, 0x13, 0xf6, 0x9c, 0x5b,
-	0x0a, 0x88, 0x21, 0x5e, 0xfb, 0xe3, 0x3c, 0x9c, 0xbb, 0x46, 0x7d, 0xb1, 0xb4, 0x2f, 0xd2, 0x9e,
-	0xe5, 0xec, 0x33, 0xa5, 0x12, 0xe9, 0x1d, 0xf2, 0x3a, 0x80, 0xe9, 0x6d, 0xb5, 0xf6, 0x8c, 0x8d,
-	0x70, 0x73, 0x7a, 0x59, 0x0e, 0x49, 0x58, 0x6e, 0x35, 0x25, 0xe6, 0x7e, 0xec, 0x09, 0x23, 0x65,
-	0xc2, 0x5d, 0x69, 0xfe, 0x01, 0xbb, 0xd2, 0x16, 0x40, 0x2f, 0x54, 0x4d, 0x0b, 0x9c, 0xf2, 0x0b,
-	0x4a, 0xcc, 0x71, 0xb4, 0xd2, 0x08, 0x9b, 0x2c, 0xca, 0xa2, 0x0d, 0xa7, 0xda, 0x74, 0x5b, 0xef,
-	0x5b, 0x7e, 0xa0, 0x4e, 0xcb, 0x41, 0x7c, 0x74, 0x8d, 0x3c, 0x08, 0x8d, 0x59, 0x4c, 0x70, 0xc2,
-	0x01, 0xde, 0xda, 0x6f, 0x17, 0xe0, 0xc2, 0x35, 0xea, 0x07, 0xd6, 0x36, 0x39, 0x3b, 0xb6, 0x7a,
-	0xd4, 0x60, 0x5f, 0xe1, 0xc3, 0x1c, 0x94, 0x2d, 0x7d, 0x8b, 0x5a, 0x6c, 0x0a, 0x67, 0x6f, 0xf3,
-	0xce, 0xc8, 0x53, 0xf8, 0x70, 0x29, 0x73, 0x2b, 0x5c, 0x82, 0x88, 0x7e, 0x9a, 0x92, 0x95, 0x2f,
-	0x0b, 0x20, 0x4a, 0xf1, 0x6c, 0x52, 0x37, 0xac, 0xbe, 0xe7, 0x8b, 0xed, 0x8d, 0x54, 0xaa, 0x82,
-	0x49, 0x7d, 0x21, 0x44, 0x61, 0x94, 0x8e, 0x5c, 0x01, 0x30, 0x2c, 0x93, 0xda, 0x3e, 0x2f, 0x25,
-	0xc6, 0x15, 0x51, 0xdf, 0x77, 0x21, 0xc0, 0x60, 0x84, 0x8a, 0x89, 0xea, 0x3a, 0xb6, 0xe9, 0x3b,
-	0x42, 0x54, 0x31, 0x2e, 0x6a, 0x35, 0x44, 0x61, 0x94, 0x8e, 0x17, 0xa3, 0xbe, 0x6b, 0x1a, 0x1e,
-	0x2f, 0x56, 0x4a, 0x14, 0x0b, 0x51, 0x18, 0xa5, 0xbb, 0xf0, 0x25, 0xa8, 0x47, 0xde, 0xff, 0x58,
-	0x11, 0x1e, 0x7f, 0xbb, 0x06, 0x97, 0x62, 0xcd, 0xea, 0xeb, 0x3e, 0xdd, 0xee, 0x5b, 0x2d, 0xea,
-	0xab, 0x0f, 0x38, 0xe2, 0x5a, 0xf8, 0x17, 0xc3, 0xef, 0x2e, 0x82, 0xde, 0x8c, 0xf1, 0x7c, 0xf7,
-	0x81, 0x0a, 0x1e, 0xe9, 0xdb, 0xcf, 0x43, 0xcd, 0xd6, 0x7d, 0x8f, 0x0f, 0x5c, 0x39, 0x46, 0x03,
-	0x5d, 0xe4, 0xa6, 0x42, 0x60, 0x48, 0x43, 0xd6, 0xe1, 0xac, 0x6c, 0xe2, 0xab, 0xf7, 0xd8, 0xc6,
-	0x97, 0xba, 0xa2, 0xac, 0x5c, 0x4e, 0x65, 0xd9, 0xb3, 0xab, 0x29, 0x34, 0x98, 0x5a, 0x92, 0xac,
-	0xc2, 0x19, 0x43, 0x04, 0x02, 0x51, 0xcb, 0xd1, 0xdb, 0x8a, 0xa1, 0x30, 0x6e, 0x06, 0xfb, 0x83,
-	0x85, 0x41, 0x12, 0x4c, 0x2b, 0x97, 0xec, 0xcd, 0xe5, 0x91, 0x7a, 0x73, 0x65, 0x94, 0xde, 0x5c,
-	0x1d, 0xad, 0x37, 0xd7, 0x8e, 0xd6, 0x9b, 0x59, 0xcb, 0xf3, 0x98, 0x13, 0x97, 0xa9, 0x27, 0x62,
-	0x85, 0x8d, 0xc4, 0x99, 0x05, 0x2d, 0xdf, 0x4a, 0xa1, 0xc1, 0xd4, 0x92, 0x64, 0x0b, 0x2e, 0x08,
-	0xf8, 0x55, 0xdb, 0x70, 0xf7, 0x7b, 0x32, 0x52, 0x55, 0xf1, 0xad, 0xc7, 0xac, 0xcb, 0x17, 0x5a,
-	0x43, 0x29, 0xf1, 0x01, 0x5c, 0xc8, 0x2b, 0x30, 0x29, 0xbe, 0xd2, 0xaa, 0xde, 0xe3, 0x6c, 0x45,
-	0xd4, 0xd9, 0xe3, 0x92, 0xed, 0xe4, 0x42, 0x14, 0x89, 0x71, 0x5a, 0xd2, 0x80, 0xe9, 0xde, 0x9e,
-	0xc1, 0xfe, 0x2e, 0x6f, 0xdf, 0xa4, 0xb4, 0x4d, 0xdb, 0xdc, 0x77, 0x5b, 0x6b, 0x3e, 0xa1, 0x4c,
-	0x1c, 0xeb, 0x71, 0x34, 0x26, 0xe9, 0xc9, 0xcb, 0x30, 0xe1, 0xf9, 0xba, 0xeb, 0x4b, 0x6b, 0xe8,
-	0xcc, 0x94, 0x88, 0xca, 0x53, 0xc6, 0xc2, 0x56, 0x04, 0x87, 0x31, 0xca, 0xd4, 0xf5, 0x62, 0xfa,
-	0xe1, 0xad, 0x17, 0x59, 0x66, 0xab, 0x7f, 0x92, 0x87, 0xcb, 0xd7, 0xa8, 0xbf, 0xea, 0xd8, 0xd2,
-	0x20, 0x9d, 0xb6, 0xec, 0x1f, 0xc9, 0x94, 0x1c, 0x5f, 0xb4, 0xf3, 0x63, 0x5d, 0xb4, 0x0b, 0x63,
-	0x5a, 0xb4, 0x8b, 0x0f, 0x71, 0xd1, 0xfe, 0x07, 0x79, 0x78, 0x22, 0xd6, 0x92, 0xeb, 0x4e, 0x5b,
-	0x4d, 0xf8, 0x9f, 0x36, 0xe0, 0x11, 0x1a, 0xf0, 0xf7, 0x0b, 0xf0, 0xe4, 0x35, 0x2a, 0x82, 0x4f,
-	0xed, 0xce, 0xba, 0xd9, 0xa3, 0x96, 0x69, 0xd3, 0x08, 0x5b, 0xf2, 0xe7, 0x73, 0x30, 0x21, 0x74,
-	0x49, 0x19, 0x36, 0x9a, 0xd5, 0xae, 0x94, 0xe2, 0x45, 0x0d, 0x07, 0xb8, 0xd0, 0x60, 0xe5, 0xea,
-	0x11, 0x93, 0xfb, 0xa9, 0x16, 0x7b, 0x94, 0xef, 0xf9, 0xb3, 0x05, 0x38, 0xcf, 0xbe, 0xa7, 0x0a,
-	0x26, 0xf9, 0x74, 0x2b, 0xf1, 0x31, 0x7c, 0x84, 0x5f, 0x2f, 0xc1, 0x99, 0x6b, 0xd4, 0x1f, 0x98,
-	0x91, 0xfe, 0x84, 0x36, 0xff, 0x2a, 0x9c, 0x09, 0x83, 0x9b, 0x5a, 0xbe, 0xe3, 0x0a, 0x0d, 0x3c,
-	0xa1, 0x31, 0xb6, 0x06, 0x49, 0x30, 0xad, 0x1c, 0xf9, 0x1a, 0x3c, 0xe1, 0x89, 0xe9, 0x4a, 0xd8,
-	0x28, 0x84, 0x42, 0x1d, 0x39, 0xc9, 0xa0, 0x3c, 0xb7, 0x4f, 0xb4, 0xd2, 0xc9, 0x70, 0x58, 0x79,
-	0xf2, 0x01, 0x4c, 0xf4, 0xe4, 0x14, 0xc8, 0xbe, 0x59, 0x66, 0x97, 0xf8, 0x7a, 0x84, 0x59, 0x38,
-	0xc7, 0x45, 0xa1, 0x18, 0x13, 0x98, 0xda, 0x53, 0xab, 0x0f, 0xb1, 0xa7, 0xfe, 0x51, 0x1e, 0x2a,
-	0xd7, 0x5c, 0xa7, 0xdf, 0x6b, 0xee, 0x93, 0x0e, 0x94, 0xef, 0x72, 0x2b, 0xaa, 0x9c, 0xe1, 0x47,
-	0x0f, 0x10, 0x16, 0xc6, 0xd8, 0x70, 0x13, 0x23, 0x9e, 0x51, 0xb2, 0x67, 0x9d, 0x78, 0x97, 0xee,
-	0xd3, 0xb6, 0x34, 0xa6, 0x06, 0x9d, 0xf8, 0x06, 0x03, 0xa2, 0xc0, 0x91, 0x2e, 0x4c, 0xeb, 0x96,
-	0xe5, 0xdc, 0xa5, 0xed, 0x15, 0xdd, 0xe7, 0x0e, 0x10, 0x69, 0x5f, 0x3c, 0xae, 0x69, 0x86, 0x7b,
-	0xb5, 0x1a, 0x71, 0x56, 0x98, 0xe4, 0x4d, 0xde, 0x85, 0x8a, 0xe7, 0x3b, 0xae, 0xda, 0x1a, 0x65,
-	0x0a, 0xde, 0x6e, 0xbe, 0xd9, 0x12, 0xac, 0x84, 0xdd, 0x4a, 0x3e, 0xa0, 0x12, 0xa0, 0xfd, 0x4a,
-	0x0e, 0xe0, 0xfa, 0xc6, 0xc6, 0xba, 0x34, 0xb1, 0xb5, 0xa1, 0xa8, 0xf7, 0x03, 0x8b, 0xf5, 0xe8,
-	0x96, 0xe1, 0x58, 0x08, 0x9b, 0x8c, 0x17, 0xe8, 0xfb, 0x3b, 0xc8, 0xb9, 0x93, 0xcf, 0x41, 0x45,
-	0x6e, 0x6b, 0x65, 0xb3, 0x07, 0x8e, 0x35, 0xb9, 0x12, 0xa3, 0xc2, 0x6b, 0xbf, 0x5a, 0x00, 0x58,
-	0x6e, 0x5b, 0xb4, 0xa5, 0x62, 0xba, 0x6b, 0xfe, 0x8e, 0x4b, 0xbd, 0x1d, 0xc7, 0x6a, 0x8f, 0x68,
-	0x56, 0xe7, 0x76, 0xaf, 0x0d, 0xc5, 0x04, 0x43, 0x7e, 0xa4, 0xcd, 0xf4, 0x7d, 0xda, 0x53, 0x51,
-	0x6d, 0x23, 0x1a, 0x12, 0x4f, 0x89, 0xbd, 0x41, 0xc8, 0x07, 0x63, 0x5c, 0x89, 0x0e, 0x75, 0xd3,
-	0x36, 0xc4, 0x00, 0x69, 0xee, 0x8f, 0xd8, 0x91, 0xf8, 0x51, 0xc3, 0xe5, 0x90, 0x0d, 0x46, 0x79,
-	0xb2, 0xfe, 0x6a, 0xda, 0xa6, 0x2f, 0xda, 0x6c, 0x91, 0x5a, 0xfa, 0xfe, 0x88, 0xb1, 0x74, 0xbc,
-	0xbf, 0x2e, 0xc7, 0x59, 0x61, 0x92, 0xb7, 0xf6, 0x17, 0x72, 0x30, 0xcd, 0x5f, 0x8f, 0xbd, 0xb5,
-	0xd0, 0xa8, 0xc8, 0x5d, 0xa8, 0x1b, 0x61, 0x8c, 0x8a, 0x6c, 0xca, 0xc5, 0x0c, 0x3e, 0xaa, 0x80,
-	0x97, 0x78, 0xf7, 0x08, 0x00, 0xa3, 0x92, 0xb4, 0x3f, 0xcc, 0xc3, 0xb9, 0x44, 0x65, 0x64, 0xa7,
-	0x22, 0x7f, 0x76, 0xe0, 0x98, 0xe2, 0x9f, 0x3e, 0x5a, 0x7b, 0x88, 0x53, 0x6e, 0xab, 0xd4, 0xd7,
-	0xc3, 0x7d, 0x7d, 0x08, 0x8b, 0x9c, 0x4d, 0xec, 0x43, 0xd1, 0x63, 0x73, 0xb5, 0x78, 0xdd, 0xd6,
-	0xc8, 0xaf, 0x9b, 0xfe, 0x02, 0x7c, 0xe6, 0x0e, 0xbc, 0x2c, 0x7c, 0xc6, 0xe6, 0xe2, 0xc8, 0xfb,
-	0x50, 0xf6, 0x7c, 0xdd, 0xef, 0xab, 0x69, 0x69, 0x73, 0xdc, 0x82, 0x39, 0xf3, 0x70, 0x0e, 0x15,
-	0xcf, 0x28, 0x85, 0x6a, 0x7f, 0x98, 0x83, 0x0b, 0xe9, 0x05, 0x57, 0x4c, 0xcf, 0x27, 0x7f, 0x66,
-	0xa0, 0xd9, 0x8f, 0xd8, 0x0d, 0x59, 0x69, 0xde, 0xe8, 0x41, 0x78, 0xb6, 0x82, 0x44, 0x9a, 0xdc,
-	0x87, 0x92, 0xe9, 0xd3, 0xae, 0xb2, 0x88, 0xad, 0x8d, 0xf9, 0xd5, 0x23, 0x6a, 0x0d, 0x93, 0x82,
-	0x42, 0x98, 0xf6, 0xd7, 0x87, 0xbe, 0x32, 0x5f, 0x3a, 0xff, 0xdc, 0x60, 0x20, 0xe6, 0x5a, 0xf6,
-	0x40, 0xcc, 0x44, 0xc5, 0x86, 0xc6, 0x63, 0xfe, 0xb0, 0x00, 0x17, 0x1f, 0xf4, 0x21, 0xd9, 0xea,
-	0x2a, 0xfb, 0x4b, 0xd6, 0xd5, 0xf5, 0xc1, 0x3d, 0x83, 0x5c, 0x81, 0x52, 0x6f, 0x47, 0xf7, 0x94,
-	0x8a, 0x78, 0x31, 0x88, 0x7e, 0x61, 0xc0, 0xfb, 0x6c, 0x0a, 0xe3, 0xaa, 0x25, 0x7f, 0x44, 0x41,
-	0xca, 0x16, 0x87, 0x2e, 0xf5, 0xbc, 0xd0, 0xa8, 0x18, 0x2c, 0x0e, 0xab, 0x02, 0x8c, 0x0a, 0x4f,
-	0x7c, 0x28, 0x0b, 0xa3, 0x8f, 0x9c, 0xde, 0xc6, 0xbb, 0x0f, 0x0c, 0x5e, 0x4a, 0xee, 0x00, 0xa5,
-	0x2c, 0x32, 0x27, 0x43, 0xf3, 0x4a, 0xb1, 0xe3, 0x9e, 0xc5, 0x14, 0x6d, 0x99, 0xd3, 0x91, 0x37,
-	0x80, 0x38, 0x5b, 0xdc, 0xcc, 0xd5, 0x96, 0x1e, 0x2d, 0x36, 0x23, 0x96, 0xb9, 0x17, 0x2b, 0x38,
-	0x2c, 0xba, 0x36, 0x40, 0x81, 0x29, 0xa5, 0xb4, 0x7f, 0x5e, 0x85, 0x73, 0xe9, 0xfd, 0x81, 0xb5,
-	0xdb, 0x1e, 0x75, 0xf9, 0x6c, 0x9b, 0x38, 0xbd, 0x7e, 0x4b, 0x80, 0x51, 0xe1, 0x3f, 0xd1, 0x71,
-	0x10, 0xbf, 0x9e, 0x83, 0xf3, 0xae, 0xb4, 0xda, 0x9e, 0x44, 0x2c, 0xc4, 0x93, 0x87, 0x07, 0xb3,
-	0xe7, 0x71, 0x98, 0x40, 0x1c, 0x5e, 0x17, 0xf2, 0x6b, 0x39, 0x98, 0x91, 0x46, 0xd8, 0x85, 0x13,
-	0x38, 0xd0, 0xc5, 0x63, 0x94, 0x57, 0x87, 0xc8, 0xc3, 0xa1, 0x35, 0x21, 0x1f, 0x40, 0xbd, 0xc7,
-	0xfa, 0x85, 0xe7, 0x53, 0xdb, 0x50, 0x71, 0x4b, 0xa3, 0x8f, 0xa4, 0xf5, 0x90, 0x57, 0x70, 0xa0,
-	0x83, 0xaf, 0xd8, 0x11, 0x04, 0x46, 0x25, 0x3e, 0xe2, 0x27, 0xb8, 0x9e, 0x85, 0xaa, 0x47, 0x7d,
-	0xdf, 0xb4, 0x3b, 0x62, 0xf7, 0x53, 0x13, 0x63, 0xa5, 0x25, 0x61, 0x18, 0x60, 0xc9, 0xe7, 0xa1,
-	0xc6, 0x8d, 0xc0, 0x0d, 0xb7, 0xe3, 0xcd, 0xd4, 0x78, 0xa0, 0xec, 0xa4, 0x88, 0xcb, 0x90, 0x40,
-	0x0c, 0xf1, 0xe4, 0x05, 0x98, 0xd8, 0xe2, 0xc3, 0x57, 0x1a, 0xb2, 0x84, 0x25, 0x9e, 0xeb, 0x8e,
-	0xcd, 0x08, 0x1c, 0x63, 0x54, 0xe4, 0x0a, 0x00, 0x0d, 0x2c, 0xe5, 0xf2, 0x5c, 0x77, 0xa0, 0x91,
-	0x84, 0x36, 0x74, 0x8c, 0x50, 0x91, 0x27, 0xa1, 0xe0, 0x5b, 0x1e, 0xb7, 0x9d, 0x57, 0xc3, 0x0d,
-	0xf1, 0xc6, 0x4a, 0x0b, 0x19, 0x5c, 0xfb, 0xe3, 0x1c, 0x4c, 0x27, 0x42, 0xfd, 0x59, 0x91, 0xbe,
-	0x6b, 0xc9, 0x69, 0x24, 0x28, 0xb2, 0x89, 0x2b, 0xc8, 0xe0, 0xe4, 0x1d, 0xb9, 0x49, 0xc8, 0x67,
-	0x4c, 0xf5, 0x70, 0x53, 0xf7, 0x3d, 0xb6, 0x2b, 0x18, 0xd8, 0x1f, 0x70, 0xc3, 0x7b, 0x58, 0x1f,
-	0xb9, 0x0e, 0x44, 0x0c, 0xef, 0x21, 0x0e, 0x63, 0x94, 0xac, 0x81, 0x7c, 0xcb, 0xbb, 0x6a, 0xeb,
-	0x5b, 0x16, 0x6d, 0xf3, 0xd9, 0x20, 0xd2, 0x40, 0x1b, 0x2b, 0x2d, 0x89, 0xc1, 0x08, 0x95, 0xf6,
-	0x3b, 0x85, 0x48, 0x0b, 0xc8, 0x7d, 0xc6, 0x47, 0xb4, 0xc0, 0x33, 0x6c, 0x01, 0x0d, 0x16, 0xf7,
-	0x5a, 0x74, 0xfd, 0xe3, 0x8b, 0xb1, 0xc4, 0x92, 0xe7, 0xa0, 0x6a, 0x38, 0xb6, 0xd7, 0xef, 0x52,
-	0x57, 0xbe, 0x44, 0xa0, 0xca, 0x2c, 0x48, 0x38, 0x06, 0x14, 0xe4, 0x2b, 0x30, 0xd5, 0xa6, 0x96,
-	0xb9, 0x47, 0xdd, 0x77, 0x64, 0xea, 0x8e, 0x62, 0xdc, 0xe1, 0xb1, 0x28, 0xb0, 0xd2, 0x22, 0x32,
-	0xd9, 0x8e, 0x3e, 0x92, 0x57, 0x60, 0x7a, 0xdb, 0xb4, 0x7c, 0xea, 0xbe, 0xe3, 0xf5, 0xb9, 0x6e,
-	0x2a, 0xb2, 0x37, 0xd4, 0x44, 0x00, 0xc9, 0x12, 0x47, 0xb5, 0x24, 0x06, 0xa7, 0xb6, 0x63, 0xcf,
-	0xe4, 0xb6, 0xe8, 0x24, 0xe5, 0x8c, 0xc7, 0x59, 0x37, 0x56, 0x5a, 0x22, 0x30, 0x43, 0x75, 0xaf,
-	0xa0, 0xaf, 0x54, 0x1e, 0x52, 0x5f, 0xd1, 0xfe, 0x69, 0x01, 0xea, 0x6f, 0x38, 0x5b, 0x9f, 0x90,
-	0x08, 0xc4, 0xf4, 0xf5, 0x34, 0xff, 0x31, 0xae, 0xa7, 0x9b, 0xf0, 0x84, 0xef, 0x5b, 0x32, 0xcb,
-	0x4c, 0x63, 0xdb, 0xa7, 0xee, 0x92, 0x69, 0x9b, 0xde, 0x0e, 0x6d, 0x4b, 0xbf, 0xfc, 0x67, 0x0e,
-	0x0f, 0x66, 0x9f, 0xd8, 0xd8, 0x58, 0x49, 0x23, 0xc1, 0x61, 0x65, 0xf9, 0xfc, 0x26, 0x0e, 0x9b,
-	0xf1, 0x03, 0x16, 0xd2, 0x5d, 0x2f, 0xe6, 0xb7, 0x08, 0x1c, 0x63, 0x54, 0xda, 0xbf, 0xcb, 0x43,
-	0x2d, 0xc8, 0x36, 0x40, 0x9e, 0x86, 0xca, 0x96, 0xeb, 0xec, 0x52, 0x57, 0x84, 0x39, 0xc8, 0xb3,
-	0x09, 0x4d, 0x01, 0x42, 0x85, 0x23, 0x4f, 0x41, 0xc9, 0x77, 0x7a, 0xa6, 0x91, 0xb4, 0x43, 0x6e,
-	0x30, 0x20, 0x0a, 0x1c, 0x1f, 0xb1, 0x3c, 0x22, 0x89, 0xbf, 0x55, 0x35, 0x32, 0x62, 0x39, 0x14,
-	0x25, 0x56, 0x0d, 0x84, 0xe2, 0xd8, 0x07, 0xc2, 0x33, 0x81, 0xae, 0x5a, 0x8a, 0x4f, 0x19, 0x09,
-	0xed, 0xf2, 0x6d, 0x28, 0x7a, 0xba, 0x67, 0xc9, 0xa1, 0x98, 0xe1, 0xd4, 0x7a, 0xa3, 0xb5, 0x22,
-	0x4f, 0xad, 0x37, 0x5a, 0x2b, 0xc8, 0x99, 0x6a, 0x7f, 0xb7, 0x00, 0x75, 0xd1, 0xbe, 0x62, 0x9a,
-	0x1b, 0x67, 0x0b, 0xbf, 0xc6, 0xbd, 0xb5, 0x7c, 0x26, 0xe3, 0x56, 0x3c, 0x39, 0xe1, 0x05, 0xa9,
-	0x5c, 0x16, 0xa2, 0xc8, 0xc0, 0x63, 0x1b, 0x82, 0x7e, 0xbc, 0x9b, 0x9e, 0xad, 0x69, 0x3c, 0x63,
-	0x86, 0x54, 0xc6, 0x65, 0x10, 0x56, 0xb0, 0xa6, 0xdd, 0x88, 0xe0, 0x30, 0x46, 0xa9, 0xfd, 0xb7,
-	0x3c, 0xd4, 0x56, 0xcc, 0x6d, 0x6a, 0xec, 0x1b, 0x16, 0x25, 0xdf, 0x84, 0x0b, 0x6d, 0x6a, 0x51,
-	0xb6, 0xb4, 0x5f, 0x73, 0x75, 0x83, 0xae, 0x53, 0xd7, 0xe4, 0x19, 0x7f, 0x78, 0x46, 0x29, 0x11,
-	0x1b, 0x77, 0xe9, 0xf0, 0x60, 0xf6, 0xc2, 0xe2, 0x50, 0x2a, 0x7c, 0x00, 0x07, 0xb2, 0x0c, 0x13,
-	0x6d, 0xea, 0x99, 0x2e, 0x6d, 0xaf, 0x47, 0x76, 0x6e, 0x4f, 0xab, 0x7a, 0x2e, 0x46, 0x70, 0xf7,
-	0x0f, 0x66, 0x27, 0x95, 0xfd, 0x58, 0x6c, 0xe1, 0x62, 0x45, 0xd9, 0xd4, 0xd2, 0xd3, 0xfb, 0x1e,
-	0x4d, 0xa9, 0x67, 0x81, 0xd7, 0x93, 0x4f, 0x2d, 0xeb, 0xe9, 0x24, 0x38, 0xac, 0x2c, 0xd9, 0x82,
-	0x19, 0x5e, 0xff, 0x34, 0xbe, 0x45, 0xce, 0xf7, 0x99, 0xc3, 0x83, 0x59, 0x6d, 0x91, 0xf6, 0x5c,
-	0x6a, 0xe8, 0x3e, 0x6d, 0x2f, 0x0e, 0xa1, 0xc6, 0xa1, 0x7c, 0xb4, 0x12, 0x14, 0x56, 0x9c, 0x8e,
-	0xf6, 0xed, 0x02, 0x04, 0x29, 0xa8, 0xc8, 0x2f, 0xe4, 0xa0, 0xae, 0xdb, 0xb6, 0xe3, 0xcb, 0xf4,
-	0x4e, 0x22, 0xf4, 0x0a, 0x33, 0x67, 0xba, 0x9a, 0x6b, 0x84, 0x4c, 0x45, 0xc4, 0x4d, 0x10, 0xd0,
-	0x11, 0xc1, 0x60, 0x54, 0x36, 0xe9, 0x27, 0x02, 0x81, 0x56, 0xb3, 0xd7, 0xe2, 0x08, 0x21, 0x3f,
-	0x17, 0xbe, 0x0a, 0xa7, 0x92, 0x95, 0x3d, 0x4e, 0xb0, 0x41, 0x96, 0x38, 0x85, 0xef, 0xe7, 0x01,
-	0x42, 0xd7, 0xfa, 0x09, 0xd8, 0xf2, 0xcc, 0x98, 0x2d, 0x6f, 0xf4, 0xc3, 0xed, 0x61, 0xa5, 0x87,
-	0xda, 0xef, 0xee, 0x24, 0xec, 0x77, 0xcb, 0xe3, 0x10, 0xf6, 0x60, 0x9b, 0xdd, 0x7f, 0xcf, 0xc3,
-	0xf9, 0x90, 0xb8, 0xb9, 0xdf, 0xd3, 0x3d, 0x2f, 0x88, 0xe5, 0x26, 0x3b, 0x32, 0x75, 0x4a, 0x6e,
-	0xec, 0x11, 0xf8, 0xc9, 0x3c, 0x2a, 0x3e, 0x54, 0xb7, 0x75, 0xcb, 0x62, 0x5a, 0x40, 0x66, 0x35,
-	0x68, 0x50, 0x1a, 0xdf, 0xaa, 0x2d, 0x49, 0xfe, 0x18, 0x48, 0x22, 0x77, 0xa1, 0xe6, 0xd8, 0xad,
-	0xbe, 0x61, 0x84, 0xae, 0x9c, 0x71, 0x8a, 0xe5, 0xdb, 0xbe, 0x35, 0x25, 0x00, 0x43, 0x59, 0xda,
-	0x16, 0x9c, 0x09, 0x5b, 0x3d, 0x9c, 0xd4, 0x6f, 0x24, 0x26, 0x5d, 0xb1, 0xef, 0xf8, 0xec, 0x90,
-	0x49, 0x77, 0x3a, 0x12, 0x62, 0x32, 0x38, 0xed, 0x6a, 0xdf, 0xc9, 0xc3, 0xa9, 0xa8, 0x10, 0x7e,
-	0xbe, 0xf5, 0x8b, 0x30, 0xe9, 0x52, 0xbd, 0xdd, 0xd4, 0x7d, 0x63, 0x87, 0x07, 0x33, 0xe7, 0x78,
-	0xf4, 0x31, 0x3f, 0xe4, 0x83, 0x51, 0x04, 0xc6, 0xe9, 0x88, 0x0e, 0x75, 0x06, 0x90, 0x79, 0x0a,
-	0x46, 0xf4, 0x89, 0x70, 0x03, 0x00, 0x86, 0x6c, 0x30, 0xca, 0x93, 0x38, 0x50, 0x73, 0x75, 0x9f,
-	0x0a, 0x45, 0x31, 0x6b, 0x0e, 0x29, 0x54, 0x9c, 0xc4, 0x57, 0x08, 0x1e, 0x31, 0x94, 0xa1, 0xfd,
-	0x30, 0x07, 0x53, 0xd1, 0x16, 0x7a, 0xe8, 0x46, 0xea, 0x9d, 0xb8, 0x91, 0x7a, 0x61, 0x0c, 0xe3,
-	0x7b, 0x88, 0x61, 0xfa, 0x5f, 0x4c, 0x44, 0x5f, 0x8d, 0x1b, 0xa3, 0xa3, 0xd6, 0xbe, 0xdc, 0x03,
-	0xad, 0x7d, 0x9f, 0xa6, 0x65, 0x1a, 0x9a, 0x96, 0x69, 0xd8, 0xb6, 0xb2, 0xf4, 0x08, 0x6f, 0x2b,
-	0xcb, 0x1f, 0xe3, 0xb6, 0x32, 0x92, 0xf8, 0xa8, 0x92, 0x21, 0xf1, 0x51, 0x37, 0x48, 0x7c, 0x54,
-	0x1d, 0xdb, 0xca, 0x78, 0x94, 0xe4, 0x47, 0xb5, 0x13, 0x4d, 0x7e, 0x04, 0x0f, 0x2b, 0xf9, 0x51,
-	0x3d, 0x6b, 0xf2, 0xa3, 0x9f, 0xcb, 0xc1, 0x54, 0x3b, 0x76, 0xc4, 0x93, 0x1b, 0x12, 0xb3, 0xe8,
-	0x43, 0xf1, 0x13, 0xa3, 0xc2, 0x3a, 0x15, 0x87, 0x61, 0x42, 0x64, 0x5a, 0xca, 0xa1, 0xc9, 0x8f,
-	0x27, 0xe5, 0xd0, 0xfb, 0x50, 0xb3, 0xd4, 0xaa, 0xcd, 0xe3, 0x81, 0xb3, 0x8c, 0xfd, 0x14, 0x4d,
-	0x20, 0x8c, 0xa0, 0x0f, 0x40, 0x18, 0x4a, 0x24, 0x7b, 0x50, 0xde, 0xe2, 0xaa, 0x9a, 0x8c, 0x26,
-	0xc6, 0x71, 0x2c, 0x24, 0x71, 0xdd, 0x4f, 0x8c, 0x0b, 0x01, 0x44, 0x29, 0x4d, 0xfb, 0x5f, 0x95,
-	0xa8, 0x4a, 0x71, 0xd2, 0x5e, 0xc4, 0x97, 0xe2, 0x5e, 0xc4, 0xcb, 0x49, 0x2f, 0xe2, 0x80, 0x3e,
-	0x24, 0x3d, 0x89, 0xcf, 0x45, 0x16, 0xbe, 0x02, 0x4f, 0x47, 0x14, 0x74, 0xf5, 0x94, 0xc5, 0xaf,
-	0x01, 0xd3, 0x52, 0x8d, 0x52, 0x48, 0xbe, 0x8c, 0x4c, 0x86, 0x01, 0xe3, 0x8b, 0x71, 0x34, 0x26,
-	0xe9, 0x99, 0x40, 0x4f, 0x65, 0x25, 0x2e, 0xc5, 0xcd, 0xbd, 0x41, 0xc6, 0xe0, 0x80, 0x82, 0x3c,
-	0x03, 0x65, 0x97, 0xea, 0x9e, 0xf4, 0x05, 0x46, 0xcc, 0x12, 0xc8, 0xa1, 0x28, 0xb1, 0x51, 0x87,
-	0x68, 0xe5, 0x23, 0x1c, 0xa2, 0x3a, 0xd4, 0x2d, 0xdd, 0xf3, 0x45, 0x27, 0x56, 0x19, 0x4c, 0xfe,
-	0xd4, 0xd1, 0x14, 0x19, 0xa6, 0x8d, 0x85, 0x3b, 0xcf, 0x95, 0x90, 0x0d, 0x46, 0x79, 0x92, 0x36,
-	0x4c, 0xb0, 0x47, 0x3e, 0xa3, 0xb5, 0x1b, 0xbe, 0x9c, 0x29, 0x8f, 0x23, 0x23, 0xb0, 0x79, 0xac,
-	0x44, 0xf8, 0x60, 0x8c, 0xeb, 0x10, 0x9f, 0x29, 0x8c, 0xe2, 0x33, 0x25, 0xaf, 0x08, 0xd5, 0x77,
-	0x3f, 0xf8, 0xac, 0x75, 0xfe, 0x59, 0x03, 0xab, 0x3a, 0x46, 0x91, 0x18, 0xa7, 0x65, 0xbd, 0xa2,
-	0x2f, 0x9b, 0x41, 0x15, 0x9f, 0x88, 0xf7, 0x8a, 0xcd, 0x38, 0x1a, 0x93, 0xf4, 0x64, 0x1d, 0xce,
-	0x06, 0xa0, 0x68, 0x35, 0x26, 0x39, 0x9f, 0xe0, 0xf0, 0xc5, 0x66, 0x0a, 0x0d, 0xa6, 0x96, 0xe4,
-	0xe7, 0x54, 0xfa, 0xae, 0x4b, 0x6d, 0xff, 0xba, 0xee, 0xed, 0xc8, 0x73, 0x09, 0xe1, 0x39, 0x95,
-	0x10, 0x85, 0x51, 0x3a, 0x72, 0x05, 0x40, 0xb0, 0xe3, 0xa5, 0xa6, 0x79, 0xa9, 0x60, 0x0f, 0xbc,
-	0x19, 0x60, 0x30, 0x42, 0xa5, 0xfd, 0xbf, 0x1c, 0x54, 0x95, 0xed, 0x9d, 0x2d, 0x8f, 0x5b, 0xba,
-	0x67, 0x1a, 0x72, 0xc0, 0x67, 0x48, 0xf4, 0xaa, 0xb2, 0x6d, 0x89, 0xe3, 0x8f, 0xfc, 0x11, 0x05,
-	0xef, 0x30, 0xdf, 0x59, 0x3e, 0x53, 0xbe, 0x33, 0xb2, 0x00, 0x45, 0x7b, 0x97, 0xee, 0x1f, 0xef,
-	0x24, 0x30, 0xd7, 0x27, 0x6f, 0xde, 0xa0, 0xfb, 0xc8, 0x0b, 0x6b, 0xbf, 0x99, 0x07, 0x60, 0xaf,
-	0x7f, 0x34, 0xb7, 0xd0, 0xe7, 0xa0, 0x22, 0x7d, 0x2f, 0x2a, 0x23, 0x7c, 0x10, 0xd7, 0x26, 0xc0,
-	0xa8, 0xf0, 0xe4, 0x29, 0x28, 0xdd, 0xe9, 0xd3, 0xbe, 0x8a, 0x71, 0x08, 0x34, 0x85, 0x37, 0x19,
-	0x10, 0x05, 0xee, 0xe1, 0x59, 0x44, 0x95, 0x57, 0xa6, 0xf4, 0xb0, 0xbc, 0x32, 0x35, 0xa8, 0xdc,
-	0x74, 0x78, 0x98, 0xac, 0xf6, 0x02, 0xa8, 0x84, 0x7a, 0xac, 0x7d, 0xa8, 0x74, 0xcd, 0xe5, 0xe2,
-	0x71, 0x7f, 0xca, 0x2f, 0xa7, 0xf0, 0xda, 0x7f, 0xcd, 0x03, 0x84, 0xc1, 0x8b, 0xe4, 0x57, 0x72,
-	0xf0, 0x78, 0xe0, 0x33, 0xf6, 0x85, 0x9a, 0xc8, 0x33, 0x32, 0x67, 0xf6, 0xeb, 0xa4, 0xf9, 0xab,
-	0x79, 0xfe, 0x83, 0xf5, 0x34, 0x71, 0x98, 0x5e, 0x0b, 0x82, 0x50, 0xa5, 0xdd, 0x9e, 0xbf, 0xbf,
-	0x68, 0xba, 0xb2, 0xdf, 0xa6, 0xc6, 0xc8, 0x5e, 0x95, 0x34, 0xa2, 0xa8, 0xdc, 0x24, 0xf1, 0xdd,
-	0x98, 0xc2, 0x60, 0xc0, 0x87, 0xec, 0x40, 0xd5, 0x76, 0xde, 0xf1, 0x58, 0x23, 0xca, 0x4e, 0x3c,
-	0x7a, 0x2e, 0x2a, 0xf9, 0x31, 0x84, 0x7d, 0x5f, 0x3e, 0x60, 0xc5, 0x96, 0x9f, 0xe8, 0x97, 0xf3,
-	0x70, 0x26, 0xa5, 0x1d, 0xc8, 0xeb, 0x70, 0x4a, 0xc6, 0x89, 0x86, 0xa9, 0xc9, 0x73, 0x61, 0x6a,
-	0xf2, 0x56, 0x02, 0x87, 0x03, 0xd4, 0xe4, 0x1d, 0x00, 0x9d, 0x5b, 0x3e, 0x56, 0x9d, 0xb6, 0x5a,
-	0xbf, 0x5f, 0x63, 0xd3, 0x4d, 0x23, 0x80, 0xde, 0x3f, 0x98, 0xfd, 0xa9, 0xb4, 0xd0, 0xef, 0x44,
-	0x3b, 0x87, 0x05, 0x30, 0xc2, 0x92, 0x7c, 0x13, 0x40, 0xec, 0x15, 0x82, 0x13, 0xda, 0x1f, 0xb1,
-	0x73, 0x9f, 0x53, 0x59, 0x70, 0xe6, 0xde, 0xec, 0xeb, 0xb6, 0x6f, 0xfa, 0xfb, 0x22, 0x2b, 0xc4,
-	0xad, 0x80, 0x0b, 0x46, 0x38, 0x6a, 0xbf, 0x9b, 0x87, 0xaa, 0xb2, 0x71, 0x9f, 0x80, 0xd1, 0xb1,
-	0x13, 0x33, 0x3a, 0x8e, 0x29, 0xd8, 0x3b, 0xcd, 0xe4, 0xe8, 0x24, 0x4c, 0x8e, 0xd7, 0xb2, 0x8b,
-	0x7a, 0xb0, 0xc1, 0xf1, 0x6f, 0x15, 0x60, 0x4a, 0x91, 0x66, 0xb5, 0x49, 0xbd, 0x0a, 0xd3, 0x22,
-	0x2c, 0x62, 0x55, 0xbf, 0x27, 0x12, 0x64, 0xf0, 0x06, 0x2b, 0x8a, 0x78, 0xd5, 0x66, 0x1c, 0x85,
-	0x49, 0x5a, 0xd6, 0xad, 0x05, 0x68, 0x93, 0x29, 0x4d, 0xa1, 0xd9, 0x69, 0x52, 0x74, 0xeb, 0x66,
-	0x02, 0x87, 0x03, 0xd4, 0x49, 0xa3, 0x58, 0xf1, 0x61, 0x1b, 0xc5, 0x4a, 0x27, 0x60, 0x14, 0xfb,
-	0x97, 0x39, 0x98, 0x08, 0x3f, 0xd0, 0x43, 0x37, 0x89, 0x6d, 0xc7, 0x4d, 0x62, 0x8d, 0xcc, 0xfd,
-	0x6f, 0x88, 0x41, 0xec, 0x5b, 0x35, 0x88, 0x1d, 0x72, 0x20, 0x5b, 0x70, 0xc1, 0x4c, 0x0d, 0x8e,
-	0x8c, 0x4c, 0x6f, 0xc1, 0xc9, 0xd5, 0xe5, 0xa1, 0x94, 0xf8, 0x00, 0x2e, 0xa4, 0x0f, 0xd5, 0x3d,
-	0xea, 0xfa, 0xa6, 0x41, 0xd5, 0xfb, 0x5d, 0xcb, 0x6c, 0x21, 0x92, 0xbb, 0xb5, 0xa0, 0x4d, 0x6f,
-	0x49, 0x01, 0x18, 0x88, 0x22, 0x5b, 0x50, 0xa2, 0xed, 0x0e, 0x55, 0x37, 0x8f, 0x64, 0x4c, 0xbc,
-	0x19, 0xb4, 0x27, 0x7b, 0xf2, 0x50, 0xb0, 0x26, 0x5e, 0x74, 0x07, 0x5c, 0xcc, 0xd8, 0x2f, 0x8f,
-	0xba, 0xef, 0xdd, 0x0d, 0xcc, 0x40, 0xa5, 0x31, 0xcd, 0x56, 0x0f, 0x30, 0x02, 0x79, 0x50, 0xbb,
-	0xab, 0xfb, 0xd4, 0xed, 0xea, 0xee, 0xae, 0xb4, 0xa1, 0x8d, 0xfe, 0x86, 0xb7, 0x15, 0xa7, 0xf0,
-	0x0d, 0x03, 0x10, 0x86, 0x72, 0xd8, 0x70, 0xf7, 0xa5, 0xb9, 0x43, 0xa5, 0x17, 0x1c, 0x5d, 0xa8,
-	0x32, 0x9c, 0x48, 0x4f, 0x44, 0xf0, 0x88, 0xa1, 0x0c, 0xb2, 0x17, 0x4b, 0x53, 0x2d, 0x92, 0x93,
-	0x67, 0xb8, 0xe7, 0x40, 0xb1, 0x0a, 0xd7, 0xb7, 0x21, 0xe9, 0xae, 0x3f, 0xcc, 0xc1, 0x74, 0x62,
-	0xe4, 0xc8, 0x3d, 0xe4, 0xf5, 0x71, 0x85, 0x6e, 0xab, 0x63, 0x0b, 0x31, 0x20, 0x26, 0xa5, 0x46,
-	0xd3, 0x47, 0xc3, 0x43, 0x4d, 0x1f, 0xfd, 0x3f, 0x4a, 0xe1, 0xd2, 0x77, 0xd2, 0xb6, 0x93, 0x17,
-	0xe2, 0xb6, 0x93, 0x4b, 0x49, 0xdb, 0x49, 0xc2, 0x81, 0x7f, 0xfc, 0x18, 0xec, 0x84, 0xc9, 0xa1,
-	0xf8, 0x10, 0x4c, 0x0e, 0xcf, 0x43, 0x7d, 0x8f, 0x4f, 0x7e, 0x22, 0xbd, 0x4e, 0x89, 0x2f, 0xd5,
-	0x7c, 0xf5, 0xbc, 0x15, 0x82, 0x31, 0x4a, 0xc3, 0xef, 0xe7, 0x12, 0xd7, 0xb6, 0x04, 0xb9, 0x69,
-	0x65, 0x91, 0x56, 0x08, 0xc6, 0x28, 0x0d, 0x0f, 0xdf, 0x34, 0xed, 0x5d, 0x51, 0xa0, 0xc2, 0x0b,
-	0x88, 0xf0, 0x4d, 0x05, 0xc4, 0x10, 0x4f, 0x9e, 0x85, 0x6a, 0xbf, 0xbd, 0x2d, 0x68, 0xab, 0x9c,
-	0x96, 0x6b, 0xf1, 0x9b, 0x8b, 0x4b, 0x32, 0xdd, 0x8f, 0xc2, 0xb2, 0x9a, 0x74, 0xf5, 0x9e, 0x42,
-	0xf0, 0xae, 0x2e, 0x6b, 0xb2, 0x1a, 0x82, 0x31, 0x4a, 0x43, 0xbe, 0x0c, 0x53, 0x2e, 0x6d, 0xf7,
-	0x0d, 0x1a, 0x94, 0x02, 0x5e, 0x4a, 0x26, 0x03, 0x8c, 0x62, 0x30, 0x41, 0x39, 0xc4, 0x70, 0x52,
-	0x1f, 0xc9, 0x70, 0xf2, 0x55, 0x98, 0x6a, 0xbb, 0xba, 0x69, 0xd3, 0xf6, 0x9a, 0xcd, 0xa3, 0x34,
-	0x64, 0x10, 0x69, 0x60, 0x2c, 0x5d, 0x8c, 0x61, 0x31, 0x41, 0xad, 0x2d, 0x81, 0x48, 0x73, 0x49,
-	0x66, 0xa1, 0xb4, 0xe3, 0xfb, 0x3d, 0xe5, 0x7e, 0xe2, 0x7b, 0x75, 0x7e, 0xe8, 0x0c, 0x05, 0x9c,
-	0x5c, 0x84, 0x22, 0xfb, 0x23, 0x83, 0xd1, 0xf9, 0x66, 0x92, 0xe1, 0x91, 0x43, 0xb5, 0xdf, 0xcb,
-	0x43, 0x49, 0xa4, 0x3a, 0x5c, 0x86, 0x33, 0xa6, 0x6d, 0xfa, 0xa6, 0x6e, 0xf1, 0x93, 0x47, 0xd1,
-	0xa8, 0x97, 0x52, 0xf3, 0x89, 0xc3, 0x83, 0xd9, 0x33, 0xcb, 0x83, 0x68, 0x4c, 0x2b, 0xc3, 0x1a,
-	0x59, 0xa6, 0x5c, 0x57, 0x5c, 0x84, 0x70, 0x91, 0x2c, 0x38, 0x86, 0xc1, 0x04, 0x25, 0x53, 0x5c,
-	0x7b, 0x03, 0xe1, 0x2c, 0x25, 0xa1, 0xb8, 0xc6, 0x23, 0x4c, 0xe2, 0x74, 0x7c, 0x43, 0x25, 0x3c,
-	0xc1, 0xc1, 0x01, 0x34, 0x19, 0x19, 0x27, 0x36, 0x54, 0x09, 0x1c, 0x0e, 0x50, 0x33, 0x0e, 0xdb,
-	0xba, 0x69, 0xf5, 0x5d, 0x1a, 0x72, 0x28, 0x85, 0x1c, 0x96, 0x12, 0x38, 0x1c, 0xa0, 0xd6, 0x7e,
-	0x2f, 0x07, 0x20, 0xee, 0x70, 0xe1, 0x36, 0x9d, 0x31, 0xa5, 0x97, 0x27, 0x7d, 0xa8, 0x6d, 0x29,
-	0xab, 0x4e, 0xe6, 0xdc, 0xe3, 0xa2, 0x7e, 0xa1, 0x95, 0x48, 0x5c, 0x07, 0xa4, 0x1e, 0x31, 0x94,
-	0xa4, 0xfd, 0xbd, 0x1c, 0x4c, 0x27, 0xa8, 0xc9, 0x1a, 0x54, 0x55, 0xf2, 0xb6, 0xe3, 0xbd, 0x95,
-	0x18, 0xc3, 0xb2, 0x28, 0x06, 0x4c, 0xc6, 0x9f, 0xcd, 0xfd, 0x67, 0xf3, 0xea, 0x1b, 0xf0, 0x40,
-	0xc7, 0x2b, 0x00, 0x32, 0xc9, 0x4a, 0xbb, 0xed, 0x4a, 0x15, 0x34, 0x5c, 0x47, 0x03, 0x0c, 0x46,
-	0xa8, 0x8e, 0x16, 0x93, 0xf7, 0x32, 0x4c, 0xf4, 0x5c, 0x87, 0x4d, 0x10, 0x2e, 0xd7, 0x6e, 0x13,
-	0x81, 0xd4, 0xeb, 0x11, 0x1c, 0xc6, 0x28, 0x89, 0x2e, 0x2d, 0x44, 0xe5, 0xb1, 0xdc, 0x1e, 0x94,
-	0x6a, 0x23, 0xfa, 0xdf, 0x79, 0x98, 0x90, 0x8d, 0x20, 0xac, 0x6b, 0x0f, 0xb3, 0x19, 0x54, 0xa8,
-	0x61, 0x5a, 0x33, 0x2c, 0x44, 0x70, 0x18, 0xa3, 0x24, 0x8b, 0x6c, 0xc0, 0x6e, 0x79, 0x86, 0x6b,
-	0xf2, 0x60, 0x7a, 0x5e, 0x5a, 0x04, 0x65, 0x07, 0x27, 0x9b, 0x5b, 0x09, 0x3c, 0x0e, 0x94, 0x20,
-	0xcf, 0x41, 0xb5, 0xab, 0xdf, 0xdb, 0xb4, 0x75, 0x63, 0x57, 0xae, 0x5e, 0x81, 0x16, 0xbf, 0x2a,
-	0xe1, 0x18, 0x50, 0x9c, 0x44, 0xd3, 0x7f, 0xa7, 0x08, 0xe1, 0x26, 0x90, 0x9c, 0x87, 0x42, 0x57,
-	0xbf, 0x27, 0x77, 0xdf, 0xdc, 0x50, 0xb8, 0xaa, 0xdf, 0x43, 0x06, 0xe3, 0x28, 0xd3, 0x96, 0xbb,
-	0x6b, 0x81, 0x32, 0x6d, 0x64, 0x30, 0xf2, 0x2e, 0x4c, 0xb9, 0x7a, 0xb7, 0xb7, 0xd9, 0x53, 0x1b,
-	0xda, 0x11, 0x8f, 0xb2, 0x8a, 0x55, 0x2d, 0xc6, 0x09, 0x13, 0x9c, 0x49, 0x1f, 0x4a, 0xc2, 0x0e,
-	0x56, 0xcc, 0xe8, 0x05, 0x0e, 0x5e, 0x9a, 0x69, 0x82, 0x8e, 0x4b, 0xe5, 0x1d, 0x7b, 0x09, 0x28,
-	0x0a, 0x69, 0x4c, 0x6c, 0xd7, 0x69, 0x07, 0x09, 0x1a, 0xc6, 0x22, 0x76, 0x95, 0x31, 0x1c, 0x10,
-	0xcb, 0xa1, 0x28, 0xa4, 0x09, 0xbb, 0x08, 0xeb, 0x83, 0x6d, 0xd1, 0x2c, 0xbc, 0x27, 0x54, 0x95,
-	0x5d, 0x24, 0x82, 0xc0, 0x38, 0x1d, 0x59, 0x86, 0x82, 0xef, 0x5b, 0x72, 0xfb, 0x30, 0xd2, 0xbd,
-	0x19, 0x1b, 0x1b, 0x2b, 0xc8, 0x78, 0x68, 0x7f, 0x35, 0x0f, 0x24, 0x52, 0xbf, 0x6b, 0x4e, 0x53,
-	0x37, 0x76, 0x6f, 0xb2, 0x8f, 0x6e, 0x38, 0x8e, 0xb5, 0xe8, 0xdc, 0xb5, 0xc5, 0x42, 0x37, 0xe2,
-	0x21, 0x6c, 0xfe, 0xd1, 0x17, 0x62, 0x9c, 0x30, 0xc1, 0x99, 0x2c, 0x01, 0x61, 0xdd, 0x40, 0x42,
-	0x0c, 0x6a, 0xfb, 0x2a, 0xd9, 0xc4, 0x64, 0xf3, 0x1c, 0x53, 0x63, 0x70, 0x00, 0x8b, 0x29, 0x25,
-	0x98, 0xd2, 0x10, 0x9c, 0xf1, 0x8e, 0x30, 0x12, 0x16, 0x1f, 0xae, 0x34, 0x6c, 0x0c, 0xa2, 0x31,
-	0xad, 0x8c, 0x76, 0x01, 0x66, 0x22, 0x8d, 0xb2, 0x6c, 0xaf, 0xd2, 0xae, 0xe3, 0xee, 0x0b, 0x23,
-	0xea, 0xaf, 0x15, 0x61, 0xe0, 0x8b, 0x92, 0x9f, 0xcf, 0x41, 0xcd, 0x33, 0x76, 0x68, 0xbb, 0x6f,
-	0x51, 0xb5, 0x76, 0xac, 0x8e, 0xa5, 0xf7, 0x2a, 0xa6, 0x22, 0xb3, 0x66, 0x1a, 0x06, 0x43, 0xc9,
-	0xe4, 0x7d, 0xa8, 0xb8, 0xd4, 0xd2, 0xef, 0x51, 0xf5, 0xc1, 0x6e, 0x8c, 0xa3, 0x12, 0x28, 0x58,
-	0xaa, 0xaf, 0x91, 0x84, 0xa3, 0x92, 0x49, 0x7e, 0x21, 0x07, 0xe0, 0xd8, 0xd6, 0xfe, 0xf2, 0xf6,
-	0xa6, 0x27, 0x0f, 0x16, 0xd4, 0xaf, 0xdc, 0x1c, 0x47, 0x15, 0xd6, 0x02, 0xae, 0xc2, 0x6a, 0x9f,
-	0x8a, 0xc2, 0x88, 0x70, 0xd6, 0x14, 0x1d, 0xd1, 0x9b, 0xe5, 0x6c, 0x32, 0x96, 0xa6, 0x90, 0x03,
-	0x64, 0xa0, 0x29, 0x24, 0x1c, 0x95, 0x4c, 0x6d, 0x0b, 0xd2, 0xeb, 0x38, 0xac, 0x9b, 0xe6, 0x46,
-	0xe8, 0xa6, 0xff, 0x29, 0x17, 0x13, 0x82, 0xb4, 0x6d, 0x8a, 0x9c, 0x25, 0xe4, 0x32, 0x14, 0xd9,
-	0x1c, 0x93, 0x4c, 0xcf, 0xcc, 0xcd, 0xea, 0x1c, 0xc3, 0x66, 0xfc, 0xbe, 0x6b, 0xc9, 0xe5, 0xb4,
-	0x12, 0x73, 0x6f, 0xed, 0x41, 0xd5, 0xa3, 0xb6, 0x6f, 0xda, 0xd4, 0x92, 0x9f, 0x70, 0x74, 0xb7,
-	0x8b, 0xa8, 0x93, 0xe4, 0x26, 0x0f, 0xdc, 0xca, 0x83, 0x75, 0x02, 0x86, 0x81, 0x2c, 0x72, 0x0e,
-	0xf2, 0xed, 0x2d, 0xa9, 0x27, 0x97, 0x0f, 0x0f, 0x66, 0xf3, 0x8b, 0x4d, 0xcc, 0xb7, 0xb7, 0xb4,
-	0xb3, 0x90, 0xd2, 0xe9, 0xb4, 0x73, 0x90, 0x3a, 0x1a, 0xb4, 0xdf, 0xca, 0xc3, 0xc0, 0x44, 0xcf,
-	0x3b, 0xa6, 0x1b, 0x34, 0x8f, 0x1c, 0x1b, 0x37, 0xc7, 0x33, 0x36, 0x14, 0xd7, 0x81, 0x8e, 0x19,
-	0xa2, 0x30, 0x22, 0x9c, 0xfc, 0xb5, 0x1c, 0x4c, 0x9a, 0xd1, 0x29, 0x45, 0xce, 0x17, 0x6f, 0x8e,
-	0xa3, 0x3a, 0xb1, 0xb9, 0x4a, 0x9c, 0x15, 0x1d, 0x86, 0xc5, 0x78, 0x2d, 0xb4, 0xdf, 0xc8, 0x41,
-	0x8d, 0x57, 0xf9, 0x13, 0xa2, 0x5c, 0xff, 0x76, 0x11, 0xce, 0xa4, 0x74, 0x2f, 0xa6, 0x5e, 0x76,
-	0x75, 0xcf, 0x97, 0x3a, 0x60, 0x42, 0xbd, 0x5c, 0x0d, 0x30, 0x18, 0xa1, 0x22, 0x9f, 0x87, 0x1a,
-	0xb5, 0xdb, 0x3d, 0xc7, 0xb4, 0x7d, 0x4f, 0x5e, 0x90, 0xc2, 0xf7, 0x22, 0x57, 0x15, 0x10, 0x43,
-	0x3c, 0xb9, 0x07, 0x13, 0xaa, 0xcf, 0xf2, 0x5d, 0x50, 0x56, 0xeb, 0x68, 0xd0, 0xe8, 0x32, 0xa7,
-	0x48, 0x84, 0x37, 0xc6, 0x24, 0x71, 0x67, 0x81, 0x22, 0xce, 0xee, 0x2c, 0x08, 0xc4, 0x0a, 0x67,
-	0x81, 0x7a, 0xc4, 0x50, 0x06, 0x71, 0xa0, 0xae, 0x2a, 0xb0, 0xb1, 0xd2, 0x1a, 0xcb, 0xb9, 0x41,
-	0x79, 0x7b, 0x7a, 0xc0, 0x14, 0xa3, 0x12, 0xc8, 0x36, 0x54, 0xb9, 0x74, 0x26, 0xad, 0x32, 0x06,
-	0x69, 0x32, 0x04, 0x56, 0x70, 0xc4, 0x80, 0xb7, 0xf6, 0x0f, 0x73, 0x30, 0x89, 0xd4, 0x67, 0x7d,
-	0x3f, 0xbc, 0x65, 0x4e, 0x9e, 0x51, 0x93, 0xfd, 0xfd, 0xf5, 0x0c, 0x61, 0x0f, 0x9c, 0x8f, 0x3c,
-	0x65, 0xa5, 0x1b, 0xbb, 0x6b, 0xdb, 0xdb, 0xa8, 0xb8, 0x93, 0xab, 0x50, 0x73, 0x6c, 0xb9, 0x81,
-	0x97, 0xf3, 0xef, 0x67, 0x45, 0x20, 0xb9, 0x04, 0xde, 0x3f, 0x98, 0x3d, 0x17, 0x3c, 0xc4, 0x2a,
-	0x89, 0x61, 0x49, 0xed, 0xe7, 0xd9, 0xe4, 0xef, 0x58, 0x96, 0x69, 0x77, 0xe2, 0xc1, 0x6c, 0xc4,
-	0x82, 0x29, 0xb1, 0xc9, 0xd8, 0xd3, 0x4d, 0x4b, 0xdf, 0xb2, 0xe8, 0x47, 0x3a, 0x31, 0xfb, 0xbe,
-	0x69, 0xcd, 0x89, 0xfb, 0xf3, 0xe7, 0x96, 0x6d, 0x7f, 0xcd, 0x6d, 0xf9, 0xae, 0x69, 0x77, 0x84,
-	0xfa, 0xb6, 0x1a, 0xe3, 0x85, 0x09, 0xde, 0xda, 0xbf, 0x2d, 0x02, 0x3f, 0xff, 0x44, 0xbe, 0x08,
-	0xb5, 0x2e, 0x35, 0x76, 0x74, 0xdb, 0xf4, 0xd4, 0xbd, 0x00, 0x6c, 0x46, 0xac, 0xad, 0x2a, 0xe0,
-	0x7d, 0x36, 0xed, 0x37, 0x5a, 0x2b, 0x3c, 0x67, 0x42, 0x48, 0x4b, 0x0c, 0x28, 0x77, 0x3c, 0x4f,
-	0xef, 0x99, 0x99, 0xc3, 0x91, 0x45, 0x0a, 0x7a, 0x61, 0xed, 0x17, 0xff, 0x51, 0xb2, 0x26, 0x06,
-	0x94, 0x7a, 0x96, 0x6e, 0xda, 0x99, 0x03, 0xcf, 0xd9, 0x1b, 0xac, 0x33, 0x4e, 0xc2, 0x38, 0xc2,
-	0xff, 0xa2, 0xe0, 0x4d, 0xfa, 0x50, 0xf7, 0x0c, 0x57, 0xef, 0x7a, 0x3b, 0xfa, 0x95, 0x17, 0x5f,
-	0xca, 0x3c, 0x31, 0x84, 0xa2, 0xc4, 0xa0, 0x59, 0xc0, 0xc6, 0x6a, 0xeb, 0x7a, 0xe3, 0xca, 0x8b,
-	0x2f, 0x61, 0x54, 0x4e, 0x54, 0xec, 0x8b, 0xcf, 0x5f, 0xc9, 0x7e, 0x99, 0x71, 0xba, 0xd8, 0x17,
-	0x9f, 0xbf, 0x82, 0x51, 0x39, 0xac, 0x49, 0x9d, 0xc8, 0x0e, 0x36, 0x9b, 0xc0, 0xb5, 0x30, 0x4c,
-	0x88, 0xff, 0x45, 0xc1, 0x5b, 0xfb, 0x9f, 0x39, 0xa8, 0x05, 0x78, 0xb6, 0x88, 0x88, 0xe4, 0xba,
-	0xcb, 0x8b, 0x23, 0xac, 0x4a, 0x0b, 0xb2, 0x28, 0x06, 0x4c, 0xc8, 0xdb, 0x30, 0x21, 0xfe, 0xcb,
-	0x64, 0xf7, 0xf9, 0x63, 0x67, 0xd4, 0x5f, 0x88, 0x14, 0xc7, 0x18, 0x33, 0xf2, 0x0a, 0x4c, 0x72,
-	0xa3, 0x99, 0x5a, 0x45, 0xa4, 0x41, 0x22, 0x88, 0x48, 0xdb, 0x88, 0x22, 0x31, 0x4e, 0x1b, 0xbc,
-	0x38, 0xff, 0x12, 0x64, 0x13, 0x80, 0xad, 0xa4, 0xb2, 0x96, 0xc7, 0x7a, 0x75, 0x1e, 0xf6, 0xb0,
-	0x19, 0x14, 0xc6, 0x08, 0xa3, 0x94, 0x3b, 0x0b, 0xf2, 0xe3, 0xbe, 0xb3, 0x60, 0x1e, 0x6a, 0x3b,
-	0xba, 0xdd, 0xf6, 0x76, 0xf4, 0x5d, 0x2a, 0x0f, 0xe5, 0x06, 0x2e, 0xb2, 0xeb, 0x0a, 0x81, 0x21,
-	0x8d, 0xf6, 0xdd, 0x32, 0x88, 0x40, 0x6a, 0xf2, 0x1c, 0x54, 0xdb, 0xa6, 0x17, 0x0d, 0x24, 0x0a,
-	0xec, 0x29, 0x8b, 0x12, 0x8e, 0x01, 0x45, 0xd4, 0x86, 0x51, 0x4a, 0xd8, 0x30, 0xa4, 0xe5, 0xa3,
-	0x10, 0x41, 0x29, 0xcb, 0xc7, 0xab, 0x30, 0x6d, 0x39, 0xce, 0x2e, 0x9b, 0x9c, 0xa3, 0x87, 0x0b,
-	0x27, 0x85, 0x73, 0x69, 0x25, 0x8e, 0xc2, 0x24, 0x2d, 0xd9, 0x84, 0x27, 0xde, 0xa3, 0xae, 0x23,
-	0x43, 0xf6, 0x5a, 0x16, 0xa5, 0x3d, 0xc5, 0x46, 0x58, 0x80, 0xf8, 0xd9, 0xc7, 0xaf, 0xa7, 0x93,
-	0xe0, 0xb0, 0xb2, 0xfc, 0xb4, 0xb6, 0xee, 0x76, 0xa8, 0xbf, 0xee, 0x3a, 0x06, 0xf5, 0x3c, 0xd3,
-	0xee, 0x28, 0xb6, 0xe5, 0x90, 0xed, 0x46, 0x3a, 0x09, 0x0e, 0x2b, 0x4b, 0xde, 0x82, 0x19, 0x81,
-	0x12, 0xae, 0xb1, 0x86, 0x98, 0xc4, 0x4d, 0xcb, 0xf4, 0xf7, 0xa5, 0x2b, 0x84, 0x6b, 0x8c, 0x1b,
-	0x43, 0x68, 0x70, 0x68, 0x69, 0xf2, 0x06, 0x9c, 0x52, 0x11, 0xb6, 0xeb, 0x4c, 0x1b, 0xd7, 0x2d,
-	0x2a, 0x1d, 0x26, 0xf2, 0x90, 0xaa, 0x3a, 0xa4, 0x89, 0x09, 0x2a, 0x1c, 0x28, 0x47, 0x10, 0xce,
-	0xf1, 0x08, 0xfa, 0xcd, 0xde, 0x82, 0xe3, 0x58, 0x6d, 0xe7, 0xae, 0xad, 0xde, 0x5d, 0x78, 0x55,
-	0xf8, 0xdd, 0x83, 0xad, 0x54, 0x0a, 0x1c, 0x52, 0x92, 0xbd, 0x39, 0xc7, 0x2c, 0x3a, 0x77, 0xed,
-	0x24, 0x57, 0x08, 0xdf, 0xbc, 0x35, 0x84, 0x06, 0x87, 0x96, 0xe6, 0xe6, 0x8b, 0xc4, 0x1b, 0x6c,
-	0xf6, 0x64, 0xec, 0xa9, 0xd8, 0x25, 0x0e, 0x60, 0x31, 0xa5, 0x04, 0x59, 0x81, 0xb3, 0x49, 0x28,
-	0x13, 0x27, 0xc3, 0x50, 0xc5, 0xee, 0x3f, 0x05, 0x8f, 0xa9, 0xa5, 0xb4, 0x3a, 0xd4, 0x82, 0x3b,
-	0xe9, 0xb5, 0x7f, 0x93, 0x87, 0xe9, 0x44, 0xb6, 0xdd, 0x13, 0x88, 0x71, 0xb2, 0x63, 0x31, 0x4e,
-	0x2b, 0x99, 0xee, 0xd6, 0x8f, 0xd4, 0x7c, 0x68, 0xa8, 0xd3, 0x5e, 0x22, 0xd4, 0xe9, 0xe6, 0xd8,
-	0x24, 0x3e, 0x38, 0xe2, 0xe9, 0x30, 0x07, 0x67, 0x12, 0x25, 0x4e, 0x20, 0xae, 0xa6, 0x1b, 0x8f,
-	0xab, 0xb9, 0x3e, 0xae, 0x97, 0x1d, 0x12, 0x5e, 0xf3, 0x7f, 0x07, 0x5f, 0xb2, 0x25, 0xe2, 0xcb,
-	0x2a, 0x32, 0xb1, 0xa9, 0x7c, 0xc7, 0xc5, 0xac, 0x15, 0xe1, 0xdf, 0x37, 0x9e, 0x28, 0xd2, 0xee,
-	0xa0, 0x92, 0x42, 0x3c, 0xa8, 0xaa, 0xec, 0xa5, 0xe3, 0x8d, 0x9e, 0x0b, 0x1a, 0x3b, 0x48, 0x48,
-	0x1d, 0x08, 0xd2, 0x7e, 0xa9, 0x00, 0x8f, 0xa7, 0x76, 0x8a, 0x93, 0x73, 0xf0, 0xbf, 0x12, 0x77,
-	0xf0, 0x3f, 0x9d, 0x74, 0xf0, 0x9f, 0x4d, 0xd4, 0xef, 0x11, 0xf6, 0xf3, 0x8f, 0xd1, 0x77, 0xad,
-	0x4d, 0xc3, 0x64, 0x2c, 0xe3, 0xae, 0xf6, 0x07, 0x65, 0xa8, 0x47, 0x7a, 0xd2, 0x23, 0x97, 0xe9,
-	0x94, 0xbc, 0xa3, 0x2e, 0x93, 0x2c, 0x64, 0xbd, 0xbe, 0x8f, 0x71, 0x91, 0x9b, 0x90, 0xc8, 0x2d,
-	0x93, 0xe4, 0xcb, 0x30, 0xd5, 0xf5, 0x3a, 0xcb, 0x8b, 0xd7, 0xa9, 0xde, 0xa6, 0xee, 0x0d, 0xaa,
-	0xd2, 0x0b, 0x89, 0xcd, 0x5c, 0x0c, 0x83, 0x09, 0x4a, 0xb2, 0x02, 0x8f, 0xbb, 0xf4, 0x4e, 0x9f,
-	0x7a, 0x7e, 0xdc, 0x35, 0x2e, 0x95, 0x19, 0xb9, 0x9e, 0x25, 0x08, 0x3c, 0x4c, 0x2f, 0xc4, 0xe6,
-	0x28, 0xe1, 0xce, 0x29, 0x67, 0x1c, 0xa8, 0xea, 0x83, 0x72, 0xa3, 0x96, 0x34, 0x92, 0x84, 0x10,
-	0xe5, 0xc6, 0x49, 0x3f, 0x1d, 0x59, 0xf9, 0x18, 0x4f, 0x47, 0x46, 0x8f, 0xe4, 0x56, 0x1f, 0x78,
-	0x24, 0x77, 0xd8, 0xb9, 0xd3, 0xda, 0xa3, 0x70, 0xee, 0x54, 0xfb, 0x00, 0x62, 0x0d, 0x4e, 0x1c,
-	0xa8, 0x05, 0x2f, 0x9b, 0xf9, 0x90, 0x48, 0x78, 0x42, 0x91, 0xdb, 0xa1, 0x82, 0x47, 0x0c, 0x65,
-	0x68, 0xdb, 0x6c, 0x98, 0xf3, 0xc4, 0xaf, 0x0f, 0xf7, 0x56, 0xfe, 0x7f, 0x95, 0x87, 0x5a, 0x10,
-	0xd0, 0x46, 0x2e, 0x43, 0xd1, 0x0e, 0x6d, 0x88, 0x81, 0xce, 0xc1, 0xad, 0x87, 0x1c, 0x13, 0x6f,
-	0x88, 0xfc, 0xc3, 0x6f, 0x88, 0xe8, 0x79, 0xdb, 0x42, 0x86, 0xf3, 0xb6, 0x3d, 0xa8, 0xf8, 0xae,
-	0xd9, 0xe9, 0x50, 0x37, 0xb3, 0xab, 0x35, 0x68, 0xae, 0x0d, 0xc1, 0x50, 0xb6, 0xac, 0x78, 0x40,
-	0x25, 0x46, 0x7b, 0x17, 0x4e, 0x25, 0x29, 0xf9, 0x39, 0x3a, 0x69, 0xb7, 0x97, 0x6d, 0x1c, 0x9e,
-	0xa3, 0x93, 0x70, 0x0c, 0x28, 0xd8, 0x60, 0x62, 0x9f, 0xe9, 0x3d, 0xc7, 0x56, 0x8b, 0x20, 0x1f,
-	0x4c, 0x1b, 0x12, 0x86, 0x01, 0x56, 0xfb, 0x2f, 0x05, 0x38, 0x1f, 0x86, 0x25, 0xae, 0xea, 0xb6,
-	0xde, 0x89, 0xdf, 0xb0, 0xf9, 0x69, 0xe6, 0xb0, 0xb1, 0xdc, 0x48, 0x5a, 0x78, 0x04, 0x6e, 0x24,
-	0xfd, 0xa3, 0x02, 0xf0, 0xc4, 0x00, 0xe4, 0x03, 0x98, 0x50, 0xed, 0xd9, 0x0a, 0x73, 0x9a, 0x5c,
-	0xcd, 0xfc, 0x39, 0x79, 0xfe, 0x81, 0x20, 0x86, 0x24, 0x0a, 0xc5, 0x98, 0x40, 0xe2, 0x0c, 0xa4,
-	0x38, 0x19, 0x93, 0xf0, 0x61, 0xd9, 0x4d, 0xdc, 0xc1, 0xec, 0x26, 0x63, 0x92, 0x38, 0x34, 0xb1,
-	0x09, 0xf9, 0xb9, 0x1c, 0x4c, 0xba, 0x51, 0x93, 0xb4, 0x9c, 0x3f, 0x96, 0x32, 0xb8, 0x21, 0x22,
-	0xdc, 0xa2, 0xa7, 0x35, 0xa3, 0x76, 0xef, 0xb8, 0x4c, 0xed, 0x3f, 0xe7, 0x60, 0xb2, 0x65, 0x99,
-	0x6d, 0xd3, 0xee, 0x3c, 0xc4, 0x4b, 0x58, 0xd7, 0xa0, 0xe4, 0x59, 0x66, 0x9b, 0x8e, 0x98, 0x0c,
-	0x85, 0x6b, 0x66, 0xac, 0x96, 0x4c, 0x41, 0x61, 0x3f, 0xf1, 0x5b, 0x5d, 0x0b, 0x47, 0xb8, 0xd5,
-	0xf5, 0x77, 0xab, 0x20, 0xd3, 0x6a, 0x90, 0x3e, 0xd4, 0x3a, 0xea, 0x9e, 0x4c, 0xf9, 0x8e, 0xd7,
-	0x33, 0xdc, 0x0a, 0x17, 0xbb, 0x71, 0x53, 0x7c, 0xef, 0x00, 0x88, 0xa1, 0x24, 0x42, 0xa1, 0xc4,
-	0x93, 0x94, 0x65, 0xce, 0xec, 0x1e, 0x49, 0x47, 0x27, 0x5a, 0x86, 0x03, 0x50, 0x70, 0x27, 0xba,
-	0x0c, 0x0c, 0x2d, 0x64, 0x8c, 0x85, 0x0a, 0xaf, 0x38, 0x48, 0x46, 0x97, 0x32, 0x11, 0xb6, 0xee,
-	0x7b, 0x99, 0xaf, 0x5a, 0x08, 0x8f, 0x89, 0xca, 0x53, 0xa4, 0xba, 0xef, 0x21, 0x67, 0x4d, 0x7e,
-	0x1a, 0xea, 0xbe, 0xab, 0xdb, 0xde, 0xb6, 0xe3, 0x76, 0xa9, 0x2b, 0xed, 0xf0, 0x4b, 0x19, 0xb2,
-	0x93, 0x6c, 0x84, 0xdc, 0x44, 0x58, 0x50, 0x0c, 0x84, 0x51, 0x69, 0x64, 0x17, 0xaa, 0xfd, 0xb6,
-	0xa8, 0x98, 0xd4, 0xb7, 0x1b, 0x19, 0x24, 0x47, 0xcf, 0x2c, 0xaa, 0x27, 0x0c, 0x04, 0xb0, 0xde,
-	0x18, 0x26, 0x3e, 0xaf, 0x64, 0xec, 0x8d, 0x89, 0xa4, 0xac, 0xc3, 0x33, 0x9e, 0x93, 0x6e, 0x68,
-	0x6d, 0xa8, 0x66, 0x6c, 0xdc, 0xd8, 0xae, 0x51, 0x5e, 0x9a, 0x91, 0xb4, 0x35, 0x98, 0x50, 0xee,
-	0xf1, 0xe0, 0x3a, 0xa9, 0x86, 0x5f, 0xcd, 0x18, 0xa3, 0x17, 0xcd, 0x96, 0x23, 0x20, 0x28, 0x05,
-	0x90, 0x6f, 0x40, 0xc1, 0xbb, 0xe3, 0xc9, 0x03, 0x0a, 0x19, 0x3c, 0x29, 0x77, 0x54, 0xdf, 0xe4,
-	0x56, 0xee, 0xd6, 0x1d, 0x0f, 0x19, 0x5f, 0xed, 0x77, 0xf2, 0x50, 0x61, 0x38, 0xb6, 0x4e, 0xcd,
-	0x43, 0x4d, 0xbf, 0xeb, 0x21, 0xed, 0x84, 0x29, 0xc4, 0x83, 0x59, 0xa8, 0x71, 0xbb, 0x25, 0x10,
-	0x18, 0xd2, 0xb0, 0x02, 0xfc, 0x9c, 0x32, 0xf7, 0xa7, 0xe7, 0xe3, 0x05, 0xde, 0x54, 0x08, 0x0c,
-	0x69, 0xc8, 0x2d, 0x38, 0xc7, 0x1f, 0xd6, 0xee, 0xda, 0xd4, 0x6d, 0xdc, 0x6e, 0x35, 0x0c, 0xc3,
-	0xe9, 0x73, 0x9f, 0x4d, 0x21, 0x76, 0x3a, 0xe1, 0xdc, 0x9b, 0xa9, 0x54, 0x38, 0xa4, 0x34, 0xd9,
-	0x03, 0xd0, 0x3d, 0xaf, 0xdf, 0xa5, 0xe8, 0x04, 0x87, 0x92, 0x32, 0xec, 0xe8, 0x6f, 0xb7, 0x1a,
-	0x01, 0x37, 0xe1, 0x25, 0x09, 0x9f, 0x31, 0x22, 0x49, 0xfb, 0x7e, 0x09, 0x6a, 0x41, 0xcb, 0x7e,
-	0x82, 0xdb, 0x6f, 0x01, 0x4e, 0xef, 0x99, 0x9e, 0x29, 0x4c, 0xf6, 0xd1, 0x43, 0x8d, 0x25, 0xa1,
-	0x0e, 0xde, 0x4a, 0x22, 0x71, 0x90, 0x9e, 0x2c, 0xc3, 0x99, 0xae, 0x7e, 0xef, 0x66, 0xbf, 0xbb,
-	0x45, 0xdd, 0xb5, 0x6d, 0x69, 0x3f, 0xf2, 0x64, 0x70, 0x3a, 0x8f, 0x5f, 0x5a, 0x1d, 0x44, 0x63,
-	0x5a, 0x19, 0xf2, 0x2a, 0x4c, 0xdf, 0xd5, 0x4d, 0x6e, 0x35, 0x88, 0x7a, 0x37, 0x4a, 0xc2, 0xf7,
-	0x72, 0x3b, 0x8e, 0xc2, 0x24, 0x2d, 0x79, 0x1e, 0xea, 0x2a, 0x28, 0x63, 0xd3, 0xb5, 0x54, 0xd2,
-	0x8c, 0xc3, 0x83, 0xd9, 0xba, 0x72, 0xa2, 0x6d, 0xe2, 0x0a, 0x46, 0x69, 0xc8, 0x97, 0x61, 0x4a,
-	0xf7, 0x7d, 0xd7, 0xdc, 0xea, 0xfb, 0xbc, 0xa9, 0xc5, 0x89, 0x28, 0x69, 0x1b, 0x69, 0xc4, 0x30,
-	0x98, 0xa0, 0x24, 0x6b, 0xf0, 0xb8, 0x34, 0x92, 0xc5, 0x09, 0x65, 0x0e, 0x70, 0xae, 0xba, 0xae,
-	0xa6, 0x11, 0x60, 0x7a, 0xb9, 0x44, 0x77, 0x86, 0x13, 0xeb, 0xce, 0x5d, 0x90, 0xc6, 0x45, 0x62,
-	0xc4, 0xee, 0xe1, 0x17, 0x89, 0x2e, 0xe7, 0x8f, 0xa6, 0xe6, 0x84, 0x89, 0x64, 0xc2, 0x2b, 0x50,
-	0x53, 0x2f, 0xdc, 0xd7, 0xfe, 0x75, 0x1e, 0x0a, 0x1b, 0x2b, 0x2d, 0xf2, 0x06, 0x10, 0xd3, 0xf6,
-	0xa8, 0xd1, 0x77, 0x69, 0x6b, 0xd7, 0xec, 0xdd, 0xa2, 0xae, 0xb9, 0xbd, 0x2f, 0xfd, 0x7a, 0x81,
-	0xd9, 0x6f, 0x79, 0x80, 0x02, 0x53, 0x4a, 0x71, 0xb7, 0xad, 0xbe, 0x40, 0xdd, 0x0c, 0x6e, 0xdb,
-	0x46, 0x58, 0x1c, 0x63, 0xcc, 0xc8, 0x26, 0x80, 0x11, 0xb2, 0x2e, 0x1c, 0xdb, 0xd7, 0x1a, 0x61,
-	0x1c, 0x61, 0x44, 0x10, 0x6a, 0xbb, 0x8c, 0x94, 0x73, 0x2d, 0x1e, 0x87, 0x2b, 0x5f, 0x10, 0x6f,
-	0xa8, 0xb2, 0x18, 0xb2, 0xd1, 0x6c, 0x98, 0x8c, 0x5d, 0xce, 0x4f, 0xbe, 0x04, 0x55, 0xa7, 0x17,
-	0xd1, 0x12, 0x6b, 0xfc, 0x62, 0x83, 0xea, 0x9a, 0x84, 0xdd, 0x3f, 0x98, 0x9d, 0x5c, 0x71, 0x3a,
-	0xa6, 0xa1, 0x00, 0x18, 0x90, 0x13, 0x0d, 0xca, 0x3c, 0x0d, 0xa7, 0x0a, 0x80, 0xe2, 0xcb, 0x14,
-	0xbf, 0x38, 0xdc, 0x43, 0x89, 0xd1, 0xbe, 0x55, 0x84, 0xf0, 0x98, 0x21, 0xf1, 0xa0, 0x2c, 0x32,
-	0x38, 0x49, 0x85, 0xf4, 0xa1, 0x26, 0x8b, 0x92, 0xa2, 0x48, 0x07, 0x0a, 0xef, 0x3a, 0x5b, 0x99,
-	0xf5, 0xd1, 0x48, 0x2e, 0x71, 0x31, 0x67, 0x44, 0x00, 0xc8, 0x24, 0x90, 0xef, 0xe4, 0xe0, 0xb4,
-	0x97, 0xb4, 0x22, 0xc8, 0xee, 0x80, 0xd9, 0xcd, 0x25, 0x49, 0xbb, 0x84, 0xb8, 0x81, 0x62, 0x28,
-	0x1a, 0x07, 0xeb, 0xc2, 0xda, 0x5f, 0x1c, 0x86, 0x93, 0xdd, 0x69, 0xf4, 0xf6, 0x17, 0x07, 0xec,
-	0xe2, 0xed, 0x1f, 0x87, 0xa1, 0x14, 0xa5, 0xfd, 0xfb, 0x1c, 0x14, 0x36, 0x17, 0x97, 0x4e, 0xdc,
-	0x06, 0x48, 0x3a, 0x50, 0xe9, 0x88, 0x6b, 0xe3, 0x32, 0xa7, 0xc9, 0x90, 0xd7, 0xcf, 0x09, 0xb5,
-	0x4f, 0x3e, 0xa0, 0xe2, 0xae, 0xed, 0x43, 0x79, 0x73, 0x51, 0x6e, 0xe9, 0x4f, 0xd8, 0xce, 0xf9,
-	0xd3, 0x10, 0x68, 0xdb, 0x27, 0x2f, 0xfc, 0x5b, 0x39, 0x88, 0x6f, 0x30, 0x4e, 0xbe, 0x0a, 0x7f,
-	0x90, 0x83, 0x44, 0x6a, 0x36, 0xf2, 0x92, 0xbc, 0x86, 0x27, 0x7e, 0x62, 0x5f, 0x5d, 0xc3, 0x43,
-	0xe2, 0xd4, 0x91, 0xeb, 0x78, 0x3e, 0xcc, 0xc1, 0xa4, 0x1b, 0x8d, 0x8f, 0x93, 0x53, 0x46, 0x86,
-	0xa8, 0xdf, 0xb4, 0x68, 0x3b, 0x79, 0x5c, 0x23, 0x8a, 0xc2, 0xb8, 0x5c, 0xed, 0x1f, 0xe5, 0xa1,
-	0x7c, 0x62, 0xe9, 0x8c, 0x69, 0xcc, 0xeb, 0xbe, 0x90, 0x71, 0x46, 0x18, 0xea, 0x6c, 0xef, 0x26,
-	0x9c, 0xed, 0x57, 0xb3, 0x0a, 0x7a, 0xb0, 0x8f, 0xfd, 0x9f, 0xe5, 0x40, 0xce, 0x47, 0xcb, 0xb6,
-	0xe7, 0xeb, 0xb6, 0x41, 0x89, 0x11, 0x4c, 0x7e, 0x59, 0x3d, 0xaf, 0x32, 0xe3, 0x82, 0x58, 0xef,
-	0xf8, 0x7f, 0x35, 0xd9, 0x91, 0xe7, 0xa0, 0xba, 0xe3, 0x78, 0xbe, 0x1d, 0x6a, 0xee, 0x81, 0x85,
-	0xfa, 0xba, 0x84, 0x63, 0x40, 0x41, 0x3e, 0x07, 0x15, 0xe9, 0xd0, 0x91, 0xa1, 0x46, 0x81, 0x01,
-	0x5e, 0x7a, 0x7c, 0x50, 0xe1, 0xb5, 0xaf, 0xc3, 0x74, 0x32, 0x39, 0xf0, 0xb5, 0xd4, 0xe4, 0xc0,
-	0x4f, 0x0d, 0x49, 0x0e, 0x5c, 0x1f, 0x9e, 0x18, 0xf8, 0x6f, 0x16, 0x60, 0xe2, 0x13, 0x93, 0x14,
-	0x38, 0x25, 0xc7, 0x4b, 0x21, 0x63, 0x8e, 0x97, 0xe2, 0xb1, 0x72, 0xbc, 0x9c, 0x78, 0x02, 0x96,
-	0x1f, 0xe4, 0x00, 0x4e, 0x2c, 0x23, 0x71, 0x3b, 0x1e, 0x26, 0x92, 0x79, 0x90, 0xa4, 0x47, 0x87,
-	0x7c, 0xb7, 0xa2, 0x5e, 0x89, 0xfb, 0xdc, 0x3f, 0xcc, 0xc1, 0x94, 0x1e, 0x4b, 0x67, 0x92, 0x59,
-	0x41, 0x4c, 0x64, 0x47, 0x09, 0x8e, 0xa6, 0xc7, 0xe1, 0x98, 0x10, 0xcb, 0x0f, 0xc6, 0xca, 0x80,
-	0x88, 0xc8, 0xee, 0x7b, 0xe0, 0x56, 0x5c, 0x79, 0x30, 0x36, 0xf2, 0xf4, 0x11, 0xe9, 0x63, 0x0a,
-	0x63, 0x49, 0x1f, 0x13, 0x75, 0x0f, 0x17, 0x1f, 0xe8, 0x1e, 0xde, 0x83, 0xda, 0xb6, 0xeb, 0x74,
-	0x79, 0x86, 0x16, 0x7e, 0xdd, 0x4f, 0x96, 0x19, 0x77, 0xc1, 0xe9, 0x6e, 0x99, 0x36, 0x6d, 0xf3,
-	0xec, 0x2f, 0x81, 0x25, 0x62, 0x49, 0xf1, 0xc7, 0x50, 0x14, 0xf7, 0x13, 0x3a, 0x42, 0x6a, 0x79,
-	0x9c, 0x52, 0x83, 0x89, 0x71, 0x43, 0x70, 0x47, 0x25, 0x26, 0x9e, 0x95, 0xa5, 0x72, 0x42, 0x59,
-	0x59, 0xf6, 0xa3, 0xc9, 0x6e, 0xaa, 0x19, 0xcd, 0x99, 0xc7, 0x4b, 0xf5, 0xfa, 0xc8, 0xe4, 0x49,
-	0xd1, 0x7e, 0xb1, 0xa2, 0x96, 0x8d, 0x47, 0xee, 0xfa, 0xc8, 0x4f, 0x93, 0xbe, 0x76, 0xe8, 0x40,
-	0x46, 0xd6, 0xea, 0x09, 0x66, 0x64, 0xad, 0x8d, 0x27, 0x23, 0x2b, 0x64, 0xcb, 0xc8, 0x5a, 0x1f,
-	0x53, 0x46, 0xd6, 0x89, 0x71, 0x65, 0x64, 0x9d, 0x1c, 0x29, 0x23, 0xeb, 0xd4, 0x91, 0x32, 0xb2,
-	0x1e, 0x14, 0x20, 0xb1, 0x05, 0xff, 0x34, 0x72, 0xe1, 0xc7, 0x2a, 0x72, 0xe1, 0xdb, 0x79, 0x08,
-	0x57, 0xa3, 0x63, 0x9e, 0x75, 0x78, 0x8b, 0x67, 0x9a, 0x10, 0x57, 0x7e, 0x8f, 0xa6, 0x95, 0x4f,
-	0xc8, 0xac, 0x14, 0xe2, 0xae, 0xef, 0x80, 0x1b, 0xf1, 0x00, 0xcc, 0xe0, 0x1e, 0xf6, 0xcc, 0xfe,
-	0xd8, 0xf0, 0x4a, 0x77, 0x61, 0x1a, 0x0d, 0x9f, 0x31, 0x22, 0x46, 0xfb, 0x7e, 0x01, 0xe4, 0x85,
-	0xfd, 0x84, 0x42, 0x69, 0xdb, 0x0c, 0xcf, 0xaf, 0x8f, 0x6e, 0xe0, 0x5b, 0x62, 0x5c, 0x04, 0x53,
-	0xe1, 0x70, 0xe6, 0x00, 0x14, 0xdc, 0xb9, 0x27, 0x51, 0x04, 0x10, 0xc8, 0xf6, 0xcb, 0xe0, 0x49,
-	0x8c, 0x06, 0x22, 0x48, 0x4f, 0xa2, 0x00, 0xa1, 0x92, 0x21, 0x1c, 0x97, 0xe2, 0x8a, 0xf4, 0x42,
-	0x66, 0xc7, 0x65, 0x24, 0x0e, 0x4e, 0x39, 0x2e, 0xc5, 0x05, 0xe9, 0x4a, 0x06, 0x79, 0x1f, 0xea,
-	0xba, 0x61, 0xf4, 0xbb, 0x7d, 0x8b, 0x1b, 0x82, 0xb3, 0xde, 0x96, 0xdb, 0x08, 0x79, 0x49, 0xb1,
-	0x7c, 0x4f, 0x17, 0x01, 0x63, 0x54, 0x5e, 0xf3, 0x1b, 0xdf, 0xfb, 0xd1, 0xa5, 0xc7, 0x7e, 0xf0,
-	0xa3, 0x4b, 0x8f, 0xfd, 0xf0, 0x47, 0x97, 0x1e, 0xfb, 0xd6, 0xe1, 0xa5, 0xdc, 0xf7, 0x0e, 0x2f,
-	0xe5, 0x7e, 0x70, 0x78, 0x29, 0xf7, 0xc3, 0xc3, 0x4b, 0xb9, 0xff, 0x70, 0x78, 0x29, 0xf7, 0x97,
-	0xff, 0xe3, 0xa5, 0xc7, 0xbe, 0xfe, 0xc5, 0xb0, 0x3a, 0xf3, 0xaa, 0x3a, 0xf3, 0x4a, 0xf8, 0x7c,
-	0x6f, 0xb7, 0x33, 0xcf, 0xaa, 0x13, 0x42, 0x54, 0x75, 0xfe, 0x7f, 0x00, 0x00, 0x00, 0xff, 0xff,
-	0x2c, 0x62, 0xc4, 0xac, 0xb1, 0xb9, 0x00, 0x00,
+	// 11371 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xed, 0x7d, 0x5b, 0x70, 0x24, 0xc9,
+	0x71, 0x18, 0x67, 0x06, 0x33, 0xc0, 0x14, 0x5e, 0xbb, 0x7d, 0x7b, 0x7b, 0xd8, 0xe5, 0xf1, 0xee,
+	0xd4, 0x14, 0x5f, 0xb2, 0x84, 0x35, 0xf7, 0x8e, 0x6f, 0xf2, 0x48, 0x0c, 0xb0, 0xd8, 0xc3, 0x2d,
+	0xb0, 0x8b, 0xcb, 0x01, 0xf6, 0x28, 0xd2, 0xe4, 0xb9, 0x31, 0xd3, 0x18, 0xf4, 0x62, 0x66, 0x7a,
+	0xae, 0xbb, 0x67, 0x77, 0x71, 0xd4, 0x99, 0x94, 0x64, 0x99, 0xb4, 0x25, 0xcb, 0x6f, 0xeb, 0x65,
+	0x59, 0xa1, 0x08, 0x45, 0xc8, 0x0e, 0x87, 0xc2, 0x0e, 0xdb, 0xf2, 0x87, 0x14, 0xa1, 0xb0, 0x18,
+	0x61, 0x89, 0x61, 0xd1, 0x36, 0x83, 0x72, 0x84, 0xe8, 0xb0, 0x83, 0x96, 0x68, 0xfb, 0xc3, 0xfe,
+	0x92, 0x43, 0x7e, 0xe9, 0xec, 0xb0, 0x5c, 0x99, 0xf5, 0xec, 0x9e, 0x19, 0x2c, 0x30, 0x3d, 0x8b,
+	0xbb, 0x93, 0xef, 0x03, 0xbb, 0xd3, 0x55, 0x59, 0x99, 0xf5, 0xcc, 0xca, 0xca, 0xcc, 0xca, 0x62,
+	0xd7, 0x5b, 0x41, 0x72, 0xd0, 0xdf, 0x5b, 0x6e, 0x84, 0x9d, 0x2b, 0xdd, 0x7e, 0xc7, 0xeb, 0x45,
+	0xe1, 0x1d, 0xfa, 0xb1, 0xdf, 0x0e, 0xef, 0x5d, 0xe9, 0x1d, 0xb6, 0xae, 0x78, 0xbd, 0x20, 0x36,
+	0x29, 0x77, 0xdf, 0xef, 0xb5, 0x7b, 0x07, 0xde, 0xfb, 0xaf, 0xb4, 0xfc, 0xae, 0x1f, 0x79, 0x89,
+	0xdf, 0x5c, 0xe6, 0x45, 0x92, 0xd0, 0xf9, 0x90, 0x41, 0xb4, 0xac, 0x10, 0x2d, 0xab, 0x62, 0xcb,
+	0x1c, 0xd1, 0x32, 0x22, 0x32, 0x29, 0x0a, 0xd1, 0xe5, 0xef, 0xb3, 0x6a, 0xd0, 0x0a, 0x5b, 0xe1,
+	0x15, 0xc2, 0xb7, 0xd7, 0xdf, 0xa7, 0x2f, 0xfa, 0xa0, 0x5f, 0x82, 0xce, 0x65, 0xf7, 0xf0, 0xc3,
+	0xf1, 0x72, 0x10, 0x62, 0xb5, 0xae, 0x34, 0xc2, 0xc8, 0xe7, 0x35, 0xca, 0xd6, 0xe5, 0xf2, 0x33,
+	0x06, 0xa6, 0xe3, 0x35, 0x0e, 0x02, 0x9e, 0x7b, 0xa4, 0xda, 0x72, 0x25, 0xf2, 0xe3, 0xb0, 0x1f,
+	0x35, 0xfc, 0x53, 0x95, 0x8a, 0xaf, 0x74, 0xfc, 0xc4, 0x1b, 0x46, 0xeb, 0xca, 0xa8, 0x52, 0x51,
+	0xbf, 0x9b, 0x04, 0x9d, 0x41, 0x32, 0x1f, 0x7c, 0x50, 0x81, 0xb8, 0x71, 0xe0, 0x77, 0xbc, 0x81,
+	0x72, 0x4f, 0x8f, 0x2a, 0xd7, 0x4f, 0x82, 0xf6, 0x95, 0xa0, 0x9b, 0xc4, 0x49, 0x94, 0x2d, 0xe4,
+	0xfe, 0x83, 0x22, 0x9b, 0x5f, 0x79, 0xb1, 0xbe, 0x12, 0xc7, 0xfd, 0x8e, 0x0f, 0x61, 0xdb, 0x77,
+	0xde, 0xc7, 0xa6, 0x23, 0xfe, 0xff, 0x4a, 0xd4, 0x5d, 0x2a, 0x3c, 0x55, 0x78, 0x6f, 0xb5, 0xb6,
+	0xf8, 0xb5, 0x6f, 0x3f, 0xf9, 0xb6, 0xef, 0x7c, 0xfb, 0xc9, 0x69, 0xcc, 0x5e, 0x81, 0x9b, 0xa0,
+	0xf2, 0x9d, 0xf7, 0xb3, 0xd9, 0xd8, 0x8f, 0xe3, 0x20, 0xec, 0xde, 0xf4, 0x3a, 0xfe, 0x52, 0x51,
+	0x80, 0x73, 0xd0, 0xd9, 0xba, 0x49, 0x06, 0x1b, 0xc6, 0xf9, 0x04, 0x5b, 0x6c, 0xf6, 0x79, 0x05,
+	0xf8, 0x77, 0xdd, 0x6f, 0x84, 0xdd, 0x66, 0xbc, 0x54, 0xe2, 0xc5, 0xca, 0xb5, 0x47, 0x78, 0xb1,
+	0xc5, 0xb5, 0x74, 0x16, 0x64, 0x61, 0x9d, 0x65, 0xc6, 0xfc, 0xfb, 0x89, 0x1f, 0x75, 0xbd, 0xf6,
+	0xc6, 0xda, 0xd2, 0x14, 0x11, 0x5c, 0xe0, 0x25, 0xd9, 0x35, 0x9d, 0x0a, 0x16, 0x84, 0xe3, 0xb2,
+	0x4a, 0x2f, 0x6c, 0x07, 0x8d, 0xa3, 0xa5, 0x32, 0xc1, 0x32, 0x0e, 0x5b, 0xd9, 0xa6, 0x14, 0x90,
+	0x39, 0x88, 0x53, 0xfc, 0xe2, 0x4d, 0x8a, 0x97, 0x2a, 0x4f, 0x95, 0x14, 0x4e, 0x01, 0xc7, 0x5b,
+	0x1c, 0x83, 0x05, 0xe1, 0xfe, 0x3a, 0x63, 0x8f, 0xac, 0xec, 0xf1, 0xce, 0xf4, 0x1a, 0xc9, 0x76,
+	0xd8, 0xdc, 0xf1, 0x3b, 0xbd, 0x36, 0xef, 0x51, 0xe7, 0x90, 0xcd, 0xe0, 0x1c, 0x68, 0x7a, 0x89,
+	0x47, 0x3d, 0x37, 0x7b, 0x75, 0x65, 0x79, 0xcc, 0x39, 0xbf, 0xbc, 0x25, 0x11, 0xd5, 0xe6, 0x78,
+	0x45, 0x66, 0xd4, 0x17, 0x68, 0x02, 0xce, 0x4f, 0x16, 0xd8, 0x5c, 0x37, 0x6c, 0xfa, 0x75, 0xbf,
+	0xed, 0x37, 0x92, 0x30, 0xe2, 0x9d, 0x5f, 0xe2, 0x14, 0x3f, 0x3f, 0x36, 0xc5, 0x21, 0x2d, 0x5a,
+	0xbe, 0x69, 0x11, 0xb8, 0xd6, 0x4d, 0xa2, 0xa3, 0xda, 0x05, 0x39, 0x17, 0xe6, 0xec, 0x2c, 0x48,
+	0xd5, 0xc4, 0xd9, 0x65, 0xb3, 0x09, 0x9f, 0x20, 0x62, 0xe0, 0x70, 0x78, 0xb1, 0x62, 0x4f, 0x2c,
+	0x8b, 0xd9, 0x89, 0xe4, 0x97, 0x71, 0x59, 0x72, 0xca, 0xcb, 0x3b, 0x1a, 0xac, 0xf6, 0x88, 0x44,
+	0x3c, 0x6b, 0xd2, 0x62, 0xb0, 0xf1, 0x38, 0x3e, 0x5b, 0x8c, 0xfd, 0x46, 0x3f, 0x0a, 0x92, 0xa3,
+	0xd5, 0xb0, 0x9b, 0xf0, 0x41, 0xa6, 0xf1, 0x9f, 0xbd, 0xfa, 0xee, 0x61, 0xa8, 0x79, 0x33, 0xea,
+	0x69, 0x68, 0x31, 0xc3, 0x32, 0x89, 0x90, 0xc5, 0xe9, 0x74, 0xd9, 0x39, 0xbe, 0x7c, 0x5a, 0xfe,
+	0x76, 0xbf, 0xdd, 0xe6, 0xc0, 0x91, 0x9f, 0xc4, 0x7c, 0xee, 0x60, 0x13, 0xde, 0x3b, 0x8c, 0xce,
+	0x66, 0xd8, 0xf0, 0xda, 0xb7, 0xf6, 0xee, 0xf0, 0xa6, 0x83, 0xbf, 0xef, 0x47, 0x7e, 0xb7, 0xe1,
+	0xd7, 0x96, 0x64, 0x63, 0xce, 0x6d, 0x64, 0x30, 0xc1, 0x00, 0x6e, 0xe7, 0x3a, 0x3b, 0xdf, 0x8b,
+	0x82, 0x90, 0xaa, 0xd0, 0xf6, 0xe2, 0x98, 0x56, 0x52, 0x85, 0x26, 0xeb, 0x25, 0x89, 0xe6, 0xfc,
+	0x76, 0x16, 0x00, 0x06, 0xcb, 0x38, 0xef, 0x65, 0x33, 0x2a, 0x71, 0x69, 0x9a, 0x96, 0x14, 0xcd,
+	0x1d, 0x55, 0x16, 0x74, 0xae, 0xb3, 0xce, 0x66, 0xbc, 0xfd, 0xfd, 0xa0, 0x8b, 0x90, 0x33, 0xd4,
+	0x85, 0x8f, 0x0f, 0x6b, 0xda, 0x8a, 0x84, 0x11, 0x78, 0xd4, 0x17, 0xe8, 0xb2, 0xce, 0xf3, 0xcc,
+	0x89, 0xfd, 0xe8, 0x6e, 0xd0, 0xf0, 0x57, 0x1a, 0x8d, 0x90, 0x33, 0x27, 0xaa, 0x7b, 0x95, 0xea,
+	0x7e, 0x59, 0xd6, 0xdd, 0xa9, 0x0f, 0x40, 0xc0, 0x90, 0x52, 0xce, 0xa7, 0xd8, 0x39, 0xc9, 0xde,
+	0x4c, 0x2f, 0x30, 0xc2, 0x74, 0x01, 0x3b, 0x12, 0x32, 0x79, 0x30, 0x00, 0xed, 0x34, 0xd9, 0xe3,
+	0x5e, 0x3f, 0x09, 0x3b, 0x88, 0x32, 0x4d, 0x74, 0x27, 0x3c, 0xf4, 0xbb, 0x4b, 0xb3, 0x1c, 0xdb,
+	0x4c, 0xed, 0x29, 0x8e, 0xed, 0xf1, 0x95, 0x63, 0xe0, 0xe0, 0x58, 0x2c, 0xce, 0x2d, 0x56, 0x6d,
+	0x76, 0x63, 0xc1, 0x19, 0x96, 0xe6, 0xa8, 0x82, 0xef, 0x97, 0x4d, 0xad, 0xae, 0xdd, 0xac, 0x8b,
+	0x8c, 0xd7, 0x38, 0x8d, 0xc1, 0x5d, 0x68, 0x59, 0xe7, 0x83, 0xc1, 0xe1, 0x6c, 0x11, 0x42, 0x3e,
+	0xfb, 0xf6, 0x83, 0xd6, 0xd2, 0x3c, 0x8d, 0xc6, 0x53, 0x23, 0x26, 0x34, 0x2f, 0x2f, 0xe0, 0x6a,
+	0xf3, 0x92, 0x9c, 0xf8, 0x04, 0x83, 0x81, 0xf7, 0xc2, 0x82, 0xda, 0xbf, 0x78, 0xd7, 0x04, 0x9d,
+	0x78, 0x69, 0x81, 0x26, 0xef, 0x77, 0x8f, 0xc0, 0x09, 0x36, 0x70, 0xed, 0xa2, 0x6c, 0xca, 0x42,
+	0x2a, 0x39, 0x86, 0x0c, 0xce, 0xcb, 0x9f, 0x64, 0xe7, 0x07, 0x78, 0x83, 0x73, 0x8e, 0x95, 0x0e,
+	0xfd, 0x23, 0xb1, 0x69, 0x00, 0xfe, 0x74, 0x2e, 0xb0, 0xf2, 0x5d, 0xaf, 0xdd, 0x97, 0x3b, 0x03,
+	0x88, 0x8f, 0x8f, 0x16, 0x3f, 0x5c, 0x70, 0x7f, 0x9b, 0xb1, 0x39, 0xc5, 0x71, 0xea, 0x41, 0xf7,
+	0xd0, 0x79, 0x91, 0x95, 0xda, 0x61, 0x4b, 0xf2, 0xcd, 0x8f, 0x8f, 0xcd, 0xc5, 0x36, 0xc3, 0x56,
+	0x6d, 0x9a, 0x37, 0xa0, 0xc4, 0x7f, 0x00, 0x62, 0x74, 0x1a, 0xac, 0x7c, 0xe8, 0xed, 0x1f, 0x7a,
+	0x54, 0x87, 0xd9, 0xab, 0xb5, 0xb1, 0x51, 0xdf, 0x40, 0x2c, 0x58, 0xd7, 0x5a, 0x95, 0x13, 0x28,
+	0xd3, 0x27, 0x08, 0xdc, 0x4e, 0xc8, 0xaa, 0x7b, 0x6d, 0xaf, 0x71, 0x78, 0xc0, 0xf9, 0x15, 0xed,
+	0x67, 0x79, 0x08, 0xd5, 0x14, 0x26, 0x31, 0xcc, 0xfa, 0x13, 0x0c, 0x0d, 0xde, 0xaa, 0x4a, 0xbf,
+	0x19, 0xf3, 0xca, 0x48, 0x1e, 0xf8, 0xc9, 0xb1, 0xa9, 0xed, 0xae, 0x51, 0x9b, 0x68, 0x63, 0x14,
+	0xbf, 0x41, 0xa2, 0xc6, 0xae, 0xc3, 0x95, 0xea, 0xd3, 0xde, 0x99, 0xa7, 0x45, 0xb8, 0x90, 0x7c,
+	0xd3, 0x75, 0xf4, 0x09, 0x02, 0xb7, 0xf3, 0x59, 0x56, 0x8a, 0x5f, 0x8e, 0x89, 0xe3, 0xcd, 0x5e,
+	0xfd, 0xd4, 0xf8, 0x24, 0x5e, 0x8e, 0x89, 0x00, 0x0d, 0x3e, 0xff, 0x00, 0xc4, 0xea, 0xb4, 0xf8,
+	0xf6, 0xdf, 0x6f, 0xc7, 0x5e, 0x44, 0x1c, 0x71, 0xf6, 0xea, 0xea, 0xd8, 0xf8, 0xb7, 0x09, 0x8d,
+	0xe9, 0x2a, 0xf1, 0x0d, 0x12, 0xbd, 0xf3, 0x12, 0x9b, 0xea, 0x7a, 0x7c, 0xa7, 0x98, 0xc9, 0xb9,
+	0xef, 0xdf, 0xe4, 0x48, 0x88, 0xc8, 0x0c, 0x27, 0x32, 0x85, 0x5f, 0x40, 0x88, 0x9d, 0x98, 0x55,
+	0xef, 0xf8, 0x28, 0xc1, 0xf9, 0x5e, 0x87, 0x58, 0xec, 0xec, 0xd5, 0xf5, 0xb1, 0xa9, 0x3c, 0xef,
+	0x27, 0x75, 0xc2, 0x44, 0xa4, 0x68, 0x96, 0xe9, 0x24, 0x30, 0x74, 0xb0, 0x55, 0x07, 0x49, 0xd2,
+	0x23, 0x46, 0x9c, 0xa7, 0x55, 0xcf, 0xed, 0xec, 0x6c, 0x9b, 0x56, 0xe1, 0x17, 0x10, 0x62, 0x24,
+	0xb0, 0x1f, 0xf0, 0x25, 0x33, 0x9b, 0x93, 0xc0, 0x3a, 0x47, 0x62, 0x08, 0xe0, 0x17, 0x10, 0x62,
+	0xe7, 0x0b, 0x6c, 0x36, 0xa4, 0xcd, 0xb9, 0xce, 0xd9, 0x94, 0x4f, 0x0c, 0x7b, 0xf6, 0xea, 0x73,
+	0x63, 0xd3, 0xb9, 0x65, 0x70, 0x11, 0x39, 0x92, 0x75, 0xad, 0x44, 0xb0, 0xa9, 0x71, 0xae, 0x30,
+	0xd3, 0x0b, 0xe3, 0xa4, 0xc5, 0x99, 0xa7, 0xe4, 0xec, 0xd7, 0xc6, 0x9f, 0x7f, 0x12, 0x11, 0x91,
+	0x15, 0x1b, 0xbb, 0x4c, 0x01, 0x4d, 0xc4, 0xfd, 0xbd, 0x79, 0xb6, 0xa0, 0xb8, 0xea, 0x6d, 0x3f,
+	0xe2, 0xf2, 0x8c, 0xf3, 0x14, 0x4e, 0x4c, 0xbe, 0x97, 0x0a, 0x51, 0x7e, 0x4e, 0xf2, 0xf7, 0x29,
+	0xda, 0x43, 0x29, 0x07, 0x59, 0x89, 0xe0, 0xed, 0x92, 0x43, 0x8e, 0xcf, 0x4a, 0xea, 0x84, 0x46,
+	0xac, 0x0f, 0xf1, 0x1b, 0x24, 0x6a, 0xbe, 0xca, 0xa7, 0x88, 0x5b, 0x09, 0xde, 0xf8, 0x89, 0xf1,
+	0x49, 0xe8, 0x41, 0x26, 0x4e, 0x45, 0x48, 0x71, 0xef, 0xe8, 0x37, 0xf7, 0x25, 0x27, 0xfc, 0x78,
+	0x0e, 0x4e, 0xb8, 0x2e, 0xd8, 0x07, 0xff, 0x01, 0x88, 0xd1, 0xf9, 0x4b, 0x05, 0x76, 0x9e, 0x9f,
+	0x3b, 0x12, 0x0f, 0xcf, 0x52, 0x4a, 0x2a, 0x96, 0xdc, 0xf0, 0xf9, 0xb1, 0xe9, 0xac, 0x66, 0x31,
+	0xd6, 0x1e, 0x45, 0x21, 0x6f, 0x20, 0x19, 0x06, 0x69, 0x3b, 0x3f, 0x53, 0x60, 0x8f, 0xa2, 0xf0,
+	0x35, 0x00, 0x2c, 0x19, 0xe8, 0x24, 0x6b, 0x75, 0x89, 0xd7, 0xea, 0xd1, 0x8d, 0x61, 0xc4, 0x60,
+	0x78, 0x1d, 0xb0, 0x76, 0x8f, 0x78, 0x83, 0xe7, 0x08, 0xc9, 0x7c, 0x37, 0x27, 0x79, 0x36, 0xa9,
+	0xbd, 0x5d, 0x4e, 0xe5, 0x61, 0x47, 0x31, 0x18, 0x56, 0x0b, 0xe7, 0x1a, 0x9b, 0xbe, 0x1b, 0xb6,
+	0xf9, 0x31, 0x17, 0xd9, 0x34, 0xca, 0x44, 0x97, 0x87, 0xc9, 0x44, 0xb7, 0x09, 0xc4, 0x1c, 0x7a,
+	0xc5, 0x77, 0x0c, 0xaa, 0xac, 0x13, 0xb0, 0x4a, 0x3b, 0xe8, 0x04, 0x9c, 0xd9, 0x57, 0x73, 0xae,
+	0x69, 0xb1, 0x44, 0x37, 0x09, 0x99, 0x58, 0x35, 0xe2, 0x37, 0x48, 0x02, 0xb4, 0x01, 0xf3, 0xe3,
+	0x85, 0x2f, 0x19, 0xf0, 0xb3, 0xe3, 0x2f, 0x1b, 0xc4, 0x52, 0x9b, 0x97, 0x6d, 0x2a, 0xd3, 0x27,
+	0x08, 0xdc, 0xce, 0xe7, 0xd8, 0x42, 0x6a, 0x34, 0x63, 0xce, 0x8d, 0xb1, 0x77, 0xde, 0x31, 0xac,
+	0x77, 0x34, 0x94, 0x11, 0x15, 0x53, 0x33, 0x84, 0x8b, 0x8a, 0x69, 0x64, 0xce, 0x0d, 0x36, 0x13,
+	0x07, 0x4d, 0xbf, 0xe1, 0x71, 0xc4, 0x73, 0x27, 0x41, 0x7c, 0x4e, 0x22, 0x9e, 0xa9, 0xcb, 0x62,
+	0xa0, 0x11, 0xd0, 0x51, 0xdd, 0x8b, 0x92, 0x40, 0x9c, 0x2c, 0xe7, 0xe9, 0x94, 0x23, 0x8e, 0xea,
+	0x3a, 0x15, 0x2c, 0x08, 0x84, 0xc7, 0xb2, 0x1b, 0xdd, 0x5e, 0x3f, 0x11, 0x92, 0xb0, 0x3c, 0xda,
+	0xd7, 0x75, 0x2a, 0x58, 0x10, 0xce, 0x2f, 0x15, 0xd8, 0xdb, 0xcd, 0xe7, 0xe0, 0x22, 0x5b, 0x9c,
+	0xf8, 0x22, 0x7b, 0x92, 0xd7, 0xe6, 0xed, 0xf5, 0xd1, 0x24, 0xe1, 0xb8, 0xfa, 0x38, 0x5f, 0x2e,
+	0xb0, 0x85, 0x7e, 0xaf, 0xc9, 0x7f, 0xf2, 0xcd, 0x9b, 0xff, 0xdb, 0x3a, 0x5a, 0x3a, 0x47, 0x55,
+	0xbc, 0x3e, 0x3e, 0x17, 0x4c, 0xa1, 0x33, 0xc3, 0x9c, 0x4e, 0x87, 0x0c, 0x59, 0x2e, 0x69, 0x4d,
+	0x87, 0x51, 0x93, 0x9f, 0x7f, 0x9b, 0x4b, 0xe7, 0x73, 0x8a, 0x72, 0xb7, 0x04, 0x9e, 0xda, 0x2c,
+	0x2e, 0x3f, 0xf9, 0x01, 0x0a, 0xbb, 0x7b, 0x87, 0x9d, 0xe7, 0x07, 0xb2, 0x7e, 0xa7, 0xcf, 0x3b,
+	0x20, 0x8c, 0x5e, 0x0c, 0xba, 0xcd, 0xf0, 0x9e, 0xb3, 0xcb, 0xa6, 0xf1, 0x30, 0x18, 0xf6, 0x13,
+	0x79, 0x82, 0x58, 0xb6, 0xe6, 0x98, 0x56, 0x86, 0x19, 0xa2, 0xa8, 0x46, 0xc1, 0x59, 0xa7, 0xf4,
+	0x4d, 0x82, 0xd6, 0x8e, 0x40, 0x01, 0x0a, 0x97, 0xfb, 0x9b, 0x9c, 0xff, 0xaf, 0x34, 0xbd, 0x5e,
+	0x12, 0xdc, 0xf5, 0x5f, 0xe4, 0xed, 0x8c, 0x3a, 0x5e, 0x74, 0xe8, 0x7c, 0x9a, 0xcd, 0x1c, 0x78,
+	0xed, 0xfd, 0xcd, 0x60, 0xdf, 0x1f, 0x93, 0x1a, 0xed, 0xdf, 0xcf, 0x49, 0x1c, 0xa0, 0xb1, 0x21,
+	0xe6, 0x8e, 0x77, 0x7f, 0xcd, 0x6f, 0x7b, 0x47, 0x72, 0x33, 0x1e, 0x0b, 0xf3, 0x96, 0xc4, 0x01,
+	0x1a, 0x9b, 0xfb, 0x22, 0x9b, 0xe7, 0x87, 0xde, 0x03, 0x7e, 0xfe, 0x7f, 0x85, 0x00, 0x9d, 0x75,
+	0x56, 0x4e, 0xe8, 0x58, 0x2c, 0x5a, 0xf0, 0xae, 0x61, 0x6b, 0x52, 0xa8, 0x28, 0x6e, 0xf8, 0x47,
+	0xea, 0x9c, 0x27, 0xc4, 0x77, 0x71, 0x4c, 0x16, 0xc5, 0xdd, 0x9f, 0x28, 0xb2, 0xe9, 0x1a, 0x3f,
+	0x95, 0x84, 0xfb, 0xfb, 0x58, 0xfd, 0xa0, 0xcb, 0x7b, 0x89, 0x1f, 0xf3, 0xf2, 0x74, 0xcc, 0x86,
+	0xc4, 0x01, 0x1a, 0x9b, 0xf3, 0x24, 0x67, 0x84, 0x89, 0xdf, 0x8b, 0xa9, 0x57, 0xe6, 0xe5, 0x29,
+	0x02, 0x13, 0x40, 0xa4, 0xa3, 0x9e, 0x6f, 0xdf, 0x23, 0x3d, 0x18, 0x4a, 0x18, 0x05, 0xc1, 0x4d,
+	0xd7, 0x29, 0x05, 0x64, 0x8e, 0xb3, 0xc1, 0x4a, 0x0d, 0xaf, 0x27, 0xc5, 0x84, 0xd3, 0xd6, 0x8c,
+	0x04, 0x83, 0x55, 0xaf, 0x07, 0x88, 0x03, 0xc9, 0xdd, 0x09, 0x12, 0x5e, 0x39, 0x12, 0x06, 0x24,
+	0xb9, 0xe7, 0x29, 0x05, 0x64, 0x8e, 0xfb, 0xf3, 0x05, 0x56, 0xad, 0x79, 0x71, 0xd0, 0xc0, 0x8e,
+	0x77, 0x56, 0xd9, 0x54, 0x9f, 0x1f, 0x78, 0x4e, 0xd7, 0xdd, 0x24, 0xe8, 0xec, 0xf2, 0x62, 0x40,
+	0x85, 0x9d, 0x5b, 0x5c, 0xa0, 0xf4, 0xe2, 0xf8, 0x1e, 0x5f, 0x0b, 0x72, 0x7e, 0x9c, 0x10, 0x91,
+	0x10, 0x18, 0x65, 0x51, 0xd0, 0x48, 0xdc, 0x59, 0x66, 0x8e, 0x97, 0xee, 0x4f, 0x17, 0xd8, 0x7c,
+	0x2d, 0x0a, 0xbd, 0x66, 0xc3, 0x8b, 0xc5, 0xa1, 0xfc, 0x0e, 0xef, 0x76, 0xfe, 0x7f, 0xcc, 0x6b,
+	0x5d, 0xca, 0xb5, 0xd3, 0xd9, 0x47, 0x7d, 0x6b, 0x1b, 0x42, 0xdc, 0x20, 0x48, 0x38, 0xef, 0x60,
+	0x25, 0x5e, 0x11, 0xa9, 0x43, 0x9e, 0x95, 0x20, 0xa5, 0x95, 0xc6, 0x21, 0x60, 0xba, 0xfb, 0x07,
+	0x5c, 0xb4, 0xa8, 0xf5, 0xf7, 0xf7, 0xfd, 0x48, 0x6a, 0x65, 0xa4, 0xbe, 0xa3, 0x6f, 0x9f, 0x8b,
+	0x8a, 0x39, 0xc5, 0x7b, 0x7d, 0x08, 0xb2, 0xd5, 0x2c, 0x43, 0x4f, 0x46, 0x3e, 0x2b, 0x73, 0x66,
+	0x14, 0xc4, 0x52, 0xa0, 0x5d, 0x1b, 0x9b, 0x24, 0x20, 0x16, 0x49, 0x8e, 0xa6, 0x35, 0x25, 0x80,
+	0xc0, 0xee, 0xfe, 0x06, 0xe7, 0xef, 0xb5, 0x7e, 0xd0, 0x4e, 0x82, 0x2e, 0x4f, 0xef, 0x37, 0xf8,
+	0x1c, 0xf8, 0x00, 0x9b, 0xf5, 0x5a, 0x5c, 0xda, 0x6f, 0xd1, 0xbc, 0x94, 0x72, 0xbd, 0xd6, 0x9e,
+	0xae, 0x98, 0x2c, 0xb0, 0xe1, 0x9c, 0x77, 0xb2, 0xf2, 0x7e, 0xe0, 0xb7, 0x9b, 0xb2, 0x83, 0xf5,
+	0x18, 0xac, 0x63, 0x22, 0x88, 0x3c, 0xe7, 0x4f, 0xb0, 0xea, 0xcb, 0x7d, 0xaf, 0x9b, 0xf0, 0x93,
+	0x93, 0xd0, 0xdb, 0x56, 0x45, 0x17, 0xbc, 0xa0, 0x12, 0xc1, 0xe4, 0x23, 0x70, 0x2f, 0xf2, 0x1b,
+	0x01, 0xaa, 0xf6, 0x69, 0x51, 0x95, 0x05, 0xf0, 0xb6, 0x4a, 0x04, 0x93, 0xef, 0xfe, 0xd7, 0x22,
+	0x5b, 0x58, 0x0d, 0xa2, 0x46, 0x3f, 0x48, 0x6a, 0xbc, 0x03, 0x0f, 0x79, 0x43, 0x9e, 0x61, 0x73,
+	0xfb, 0x5e, 0xd0, 0xee, 0xf3, 0x53, 0x13, 0x56, 0x91, 0x5a, 0x52, 0xa8, 0x9d, 0x43, 0xe5, 0xf2,
+	0xba, 0x95, 0x0e, 0x29, 0x28, 0xa4, 0xda, 0x09, 0xba, 0x2f, 0x46, 0x41, 0xe2, 0x0b, 0x6e, 0x30,
+	0x25, 0xa8, 0x6e, 0xa9, 0x44, 0x30, 0xf9, 0x0e, 0xb0, 0xca, 0x3d, 0xda, 0x20, 0xe4, 0x30, 0x9d,
+	0x76, 0xd1, 0xd3, 0xb2, 0x16, 0x5b, 0x0c, 0x48, 0x4c, 0x4e, 0x93, 0xcd, 0x85, 0x3d, 0xbf, 0xab,
+	0x60, 0xc6, 0x64, 0x27, 0xd4, 0xcc, 0x5b, 0x16, 0x1e, 0x48, 0x61, 0x75, 0x6a, 0xac, 0x12, 0x76,
+	0x31, 0x5f, 0xda, 0x2d, 0xbe, 0x07, 0x6b, 0x72, 0x8b, 0x52, 0x50, 0xb9, 0x28, 0x7e, 0xa5, 0x3b,
+	0x54, 0x6f, 0xcd, 0xb2, 0xa4, 0xfb, 0xd5, 0x0a, 0x9b, 0x5b, 0x0d, 0x3b, 0x7b, 0xbc, 0x2e, 0xcd,
+	0x6b, 0xcd, 0x96, 0x8f, 0xa7, 0x6d, 0x9f, 0xff, 0x2f, 0x79, 0xd0, 0xf8, 0x87, 0x30, 0x44, 0x66,
+	0x8e, 0x92, 0xf8, 0x05, 0x84, 0xd8, 0xd9, 0x64, 0x0b, 0xfb, 0x51, 0xd8, 0x11, 0x72, 0xed, 0xce,
+	0x51, 0x4f, 0x99, 0x84, 0xbe, 0x5b, 0x09, 0x11, 0xeb, 0xa9, 0x5c, 0xde, 0x12, 0x66, 0xbe, 0x20,
+	0x53, 0x96, 0x6f, 0x27, 0x4b, 0x26, 0x45, 0x0b, 0x78, 0xab, 0xa8, 0x8b, 0x95, 0x36, 0xa3, 0xc7,
+	0x39, 0xce, 0xa5, 0xf5, 0x11, 0x30, 0x30, 0xb2, 0x34, 0x8a, 0x4d, 0xe7, 0x4c, 0xa6, 0x10, 0xba,
+	0xe5, 0x40, 0x4e, 0x48, 0x9a, 0x27, 0xa5, 0xf5, 0x7a, 0x86, 0x04, 0x0c, 0x10, 0xe5, 0xdb, 0xf0,
+	0x5c, 0x12, 0x5a, 0xfd, 0x25, 0x46, 0xdb, 0x55, 0x56, 0x96, 0x9d, 0x70, 0x64, 0x6f, 0xa5, 0xca,
+	0xf1, 0x99, 0x7e, 0x51, 0x7d, 0x67, 0x7a, 0xaa, 0x42, 0x3d, 0x75, 0x99, 0x63, 0xbb, 0xb8, 0x33,
+	0x14, 0x02, 0x46, 0x94, 0x74, 0x7e, 0x90, 0x33, 0x1f, 0x95, 0x25, 0xfb, 0x68, 0x7a, 0x92, 0x7d,
+	0xe4, 0xe0, 0x8c, 0xd8, 0x49, 0x11, 0x80, 0x0c, 0x41, 0xe7, 0x8b, 0x6c, 0x51, 0xa5, 0x48, 0x49,
+	0x50, 0xaa, 0xd8, 0xf2, 0x8b, 0x97, 0x64, 0x0e, 0xda, 0x49, 0x23, 0x87, 0x2c, 0x35, 0xf7, 0xef,
+	0x17, 0xd8, 0x2c, 0x5f, 0x44, 0x9c, 0x93, 0x91, 0x0d, 0xd3, 0x79, 0x9a, 0x4d, 0x25, 0x38, 0x50,
+	0x82, 0xef, 0x3e, 0xa9, 0x16, 0x81, 0x1c, 0xa0, 0x45, 0x0b, 0x94, 0x46, 0x89, 0x80, 0x9d, 0x77,
+	0xb1, 0x69, 0xce, 0x94, 0xea, 0xc1, 0x2b, 0x62, 0x41, 0x94, 0x84, 0xb8, 0xb9, 0x25, 0x92, 0x40,
+	0xe5, 0x39, 0x6b, 0xec, 0x5c, 0x33, 0x68, 0xe0, 0x00, 0x78, 0xd1, 0x51, 0xad, 0xdf, 0x38, 0xf4,
+	0xc5, 0x44, 0xaf, 0x1a, 0x83, 0xd2, 0x5a, 0x26, 0x1f, 0x06, 0x4a, 0xb8, 0xbf, 0x3c, 0xcd, 0xaa,
+	0xfa, 0xa4, 0x80, 0x7c, 0x9f, 0x4c, 0x4e, 0xb2, 0xc2, 0x9a, 0xef, 0x93, 0x65, 0x0a, 0x44, 0x1e,
+	0xd6, 0x8f, 0x77, 0x63, 0xc7, 0xeb, 0x36, 0xc9, 0x8c, 0x58, 0x15, 0xf5, 0x5b, 0x15, 0x49, 0xa0,
+	0xf2, 0x9c, 0xc7, 0xd9, 0x94, 0x17, 0xb5, 0xd4, 0xce, 0x40, 0xc2, 0xc9, 0x0a, 0xff, 0x06, 0x4a,
+	0x75, 0x3e, 0xc2, 0x4a, 0x7e, 0xf7, 0x2e, 0x5f, 0x46, 0x23, 0x8f, 0xd6, 0xd7, 0xba, 0x77, 0x6f,
+	0x7b, 0x91, 0xd9, 0xdc, 0xf9, 0x37, 0x60, 0x19, 0xce, 0x37, 0xa6, 0xf9, 0x7f, 0xb8, 0x5c, 0xa4,
+	0xa9, 0xed, 0xbb, 0x46, 0x14, 0x47, 0x10, 0xa9, 0x65, 0xd2, 0x07, 0x74, 0x99, 0x0c, 0x0a, 0x85,
+	0xf3, 0xfd, 0x6c, 0x4e, 0x9c, 0xd5, 0xb7, 0x70, 0x1a, 0x0b, 0x8b, 0xee, 0xec, 0xd5, 0x27, 0x47,
+	0x1f, 0xf6, 0x09, 0xce, 0x98, 0x36, 0xad, 0xc4, 0x18, 0x52, 0xa8, 0x38, 0xea, 0xaa, 0xb2, 0x84,
+	0xa8, 0xc5, 0x30, 0xd4, 0x2a, 0xa8, 0xcc, 0x27, 0xe0, 0xbf, 0xdc, 0x0f, 0x22, 0xbf, 0xe3, 0xf3,
+	0xc2, 0xb5, 0xf3, 0xca, 0x4e, 0xa4, 0x72, 0xf9, 0x5e, 0xa5, 0xb1, 0x39, 0x7b, 0x83, 0xe6, 0x4d,
+	0x31, 0xd3, 0xdf, 0x39, 0x42, 0xc4, 0x1b, 0xc3, 0xb6, 0xf9, 0x79, 0xb6, 0xa8, 0xed, 0x8f, 0xd2,
+	0x84, 0x25, 0xac, 0x75, 0xcf, 0x60, 0xf1, 0x8d, 0x74, 0x16, 0x9f, 0xce, 0xef, 0x18, 0x62, 0xc4,
+	0x32, 0x00, 0x90, 0x45, 0xe6, 0xbc, 0x82, 0xc6, 0x27, 0xaf, 0xc9, 0x27, 0x5e, 0x1c, 0x6f, 0x47,
+	0xe1, 0x5e, 0x7e, 0xc5, 0x05, 0x61, 0x11, 0x9c, 0x02, 0x52, 0x98, 0x21, 0x43, 0xc9, 0xb9, 0xc7,
+	0xe6, 0xdb, 0xfc, 0x98, 0x66, 0x48, 0xcf, 0x4e, 0x84, 0xf4, 0x79, 0x4e, 0x7a, 0x7e, 0xd3, 0x46,
+	0x0c, 0x69, 0x3a, 0x78, 0x92, 0xea, 0x85, 0x51, 0xa2, 0xb4, 0x1b, 0xdf, 0x75, 0xac, 0x76, 0x63,
+	0x9b, 0x43, 0x9a, 0x45, 0x88, 0x5f, 0x5c, 0xd6, 0xa3, 0xe2, 0xee, 0x3f, 0x2e, 0xb3, 0x41, 0x1d,
+	0x60, 0x7a, 0xc6, 0x15, 0x26, 0x3d, 0xe3, 0xb2, 0xb3, 0x41, 0x6c, 0xd7, 0x1f, 0x96, 0xc5, 0x26,
+	0x30, 0x23, 0x86, 0xcc, 0xea, 0xd2, 0xa4, 0x67, 0xf5, 0x1b, 0x86, 0xf1, 0x0c, 0x4e, 0xff, 0xca,
+	0xeb, 0x37, 0xfd, 0xa7, 0xcf, 0x66, 0xfa, 0xbb, 0x5f, 0x99, 0x62, 0x0b, 0x6b, 0x9e, 0xdf, 0xe1,
+	0x1b, 0xde, 0x83, 0xd4, 0xc0, 0x85, 0x37, 0x84, 0x1a, 0xf8, 0xbd, 0x6c, 0x26, 0xf2, 0x7b, 0x7c,
+	0x76, 0x7a, 0xe2, 0x00, 0x21, 0xfd, 0x24, 0x40, 0xa6, 0x81, 0xce, 0x1d, 0xa1, 0xfe, 0x2f, 0xbd,
+	0x21, 0xd5, 0xff, 0x53, 0xaf, 0xbf, 0xfa, 0xdf, 0xfd, 0xc1, 0x22, 0xa3, 0xd3, 0x00, 0x1a, 0x9d,
+	0x50, 0xd2, 0xcd, 0x1a, 0x9d, 0x68, 0xb5, 0x50, 0x8e, 0x73, 0x99, 0x15, 0x93, 0x50, 0xb2, 0x1b,
+	0x26, 0xf3, 0x8b, 0x3b, 0x21, 0xf0, 0x54, 0xbe, 0x8c, 0x18, 0x3a, 0x7b, 0x05, 0xca, 0x7d, 0x28,
+	0x5f, 0xc3, 0xd6, 0xc3, 0xe8, 0x9e, 0x17, 0x35, 0x57, 0x35, 0x46, 0xa1, 0x00, 0x36, 0xdf, 0x60,
+	0x51, 0x73, 0x3e, 0x89, 0xe7, 0xae, 0x75, 0xce, 0xbf, 0xa4, 0x6f, 0xd9, 0x7b, 0xc4, 0xb9, 0x0b,
+	0x53, 0x38, 0xf7, 0xbb, 0x24, 0x34, 0x10, 0xf8, 0x85, 0x27, 0xcc, 0xa0, 0xdb, 0xb2, 0x0f, 0x5d,
+	0x98, 0xec, 0xfe, 0x61, 0x81, 0xcd, 0x28, 0x6b, 0x24, 0xf6, 0x43, 0xcf, 0x4b, 0x0e, 0xb2, 0xfd,
+	0xb0, 0xcd, 0xd3, 0x80, 0x72, 0x9c, 0x77, 0xb3, 0xca, 0x7e, 0x18, 0x75, 0xbc, 0x44, 0xf6, 0xc5,
+	0x82, 0x84, 0xa9, 0xac, 0x53, 0x2a, 0xc8, 0x5c, 0xe7, 0xa3, 0x6c, 0xa1, 0xe3, 0xdd, 0xaf, 0xfb,
+	0x2d, 0x64, 0xec, 0x24, 0x48, 0x96, 0x48, 0x90, 0x24, 0xd6, 0xb0, 0x95, 0xca, 0x81, 0x0c, 0xa4,
+	0xd3, 0x62, 0xf3, 0x26, 0x65, 0xa5, 0xe5, 0x8f, 0x79, 0x64, 0x25, 0x56, 0xb0, 0x65, 0x23, 0x82,
+	0x34, 0x5e, 0xf7, 0x6f, 0x16, 0x18, 0xa3, 0xb6, 0x0b, 0x9b, 0xdf, 0xe4, 0x5a, 0xff, 0x2c, 0x5b,
+	0x68, 0x1c, 0xf8, 0x8d, 0xc3, 0x5e, 0x18, 0x74, 0x13, 0x2c, 0x2f, 0xc5, 0x62, 0xad, 0x9c, 0x5e,
+	0x4d, 0xe5, 0x42, 0x06, 0xda, 0xfd, 0xab, 0x5c, 0x88, 0x5f, 0x0f, 0xee, 0xfb, 0x4d, 0xa9, 0x2e,
+	0x06, 0x56, 0x69, 0xfb, 0xdd, 0x96, 0xac, 0xdb, 0x98, 0x7a, 0x81, 0x4d, 0xc2, 0x00, 0x12, 0x93,
+	0x73, 0x85, 0x55, 0x85, 0x6e, 0x88, 0xcf, 0x0a, 0x6a, 0xce, 0x8c, 0xd9, 0x7e, 0xeb, 0x2a, 0x03,
+	0x0c, 0x8c, 0xfb, 0x2a, 0x3b, 0x3f, 0x30, 0x37, 0x9d, 0x03, 0x7e, 0xbc, 0xf0, 0x5a, 0x6a, 0xa7,
+	0x1f, 0xdf, 0xc2, 0xbf, 0xe3, 0xb5, 0xac, 0x19, 0xaf, 0xfb, 0x9e, 0x27, 0x73, 0x71, 0x1d, 0x29,
+	0xb8, 0xff, 0x64, 0x8a, 0x55, 0xae, 0xd7, 0xeb, 0x2b, 0xdb, 0x1b, 0xa8, 0x52, 0x92, 0x1e, 0x59,
+	0x37, 0x8d, 0xa9, 0x58, 0xab, 0x94, 0xea, 0x26, 0x0b, 0x6c, 0x38, 0x3c, 0x5a, 0xf0, 0xb6, 0xb4,
+	0x3b, 0x59, 0x95, 0x12, 0x60, 0x22, 0x88, 0x3c, 0xc7, 0x63, 0x0b, 0xa8, 0xba, 0x44, 0x4b, 0xb3,
+	0x50, 0x4b, 0xca, 0x05, 0x7d, 0x42, 0xc5, 0x25, 0xcd, 0xef, 0xdd, 0x14, 0x02, 0xc8, 0x20, 0x74,
+	0x3e, 0xcc, 0x66, 0xbc, 0x7e, 0x72, 0x40, 0xe7, 0x67, 0xb1, 0x6a, 0x1f, 0x27, 0x87, 0x35, 0x99,
+	0xc6, 0xd7, 0xed, 0xdc, 0x0d, 0xa8, 0x7d, 0x40, 0x7d, 0x83, 0x86, 0xc6, 0xca, 0x29, 0x55, 0xa8,
+	0xac, 0x5c, 0xf9, 0xd4, 0x95, 0xdb, 0x4e, 0x21, 0x80, 0x0c, 0x42, 0xe7, 0xb3, 0x6c, 0xee, 0xd0,
+	0x3f, 0x4a, 0xbc, 0x3d, 0x49, 0xa0, 0x72, 0x1a, 0x02, 0xa4, 0x25, 0xba, 0x61, 0x15, 0x87, 0x14,
+	0x32, 0x27, 0x66, 0x17, 0x0e, 0xfd, 0x68, 0xcf, 0x8f, 0x42, 0xa9, 0x42, 0x94, 0x44, 0xa6, 0x4f,
+	0x43, 0x64, 0x89, 0x13, 0xb9, 0x70, 0x63, 0x08, 0x1a, 0x18, 0x8a, 0xdc, 0xfd, 0xc3, 0x22, 0x5b,
+	0xbc, 0x2e, 0xbc, 0x88, 0xc3, 0x48, 0x2e, 0xf5, 0x4b, 0xac, 0x14, 0xf5, 0xfa, 0x34, 0x73, 0x4a,
+	0x42, 0x55, 0x0e, 0xdb, 0xbb, 0x80, 0x69, 0x68, 0x14, 0x50, 0x4e, 0xbc, 0x79, 0x6c, 0x1a, 0x5a,
+	0x4f, 0xa6, 0xb1, 0xd1, 0xa9, 0x3a, 0x6e, 0x69, 0x66, 0x58, 0x96, 0xa7, 0x6a, 0x91, 0x04, 0x2a,
+	0x0f, 0xf7, 0x7b, 0xde, 0x69, 0x42, 0x19, 0x32, 0x65, 0xf6, 0xfb, 0x1b, 0x32, 0x0d, 0x74, 0x2e,
+	0x5a, 0x19, 0x84, 0xbb, 0x5a, 0x99, 0xf4, 0x8a, 0xa4, 0x8e, 0xbd, 0x8d, 0x09, 0xd2, 0x73, 0x0d,
+	0xf9, 0x86, 0x54, 0xfb, 0x57, 0xc6, 0xe7, 0x1b, 0x69, 0x33, 0x01, 0x2a, 0x34, 0x09, 0x79, 0xad,
+	0x1d, 0xee, 0xd1, 0xc0, 0x49, 0x9d, 0xeb, 0x6d, 0x95, 0x08, 0x26, 0xdf, 0xfd, 0xa3, 0x22, 0xbb,
+	0x78, 0xdd, 0x4f, 0x84, 0xbc, 0xb5, 0xc6, 0xe5, 0x94, 0xf0, 0x08, 0x99, 0x2f, 0x17, 0xfa, 0x9d,
+	0x4f, 0x31, 0x16, 0xc4, 0x7b, 0xf5, 0xbb, 0x8d, 0x1d, 0xa3, 0x9e, 0x78, 0x4a, 0x2e, 0x49, 0xb6,
+	0x51, 0xaf, 0xc9, 0x9c, 0xd7, 0x52, 0x5f, 0x60, 0x95, 0x31, 0xaa, 0x82, 0xe2, 0x31, 0xaa, 0x82,
+	0x3a, 0x63, 0x3d, 0x73, 0x5e, 0x10, 0x6c, 0xf8, 0x69, 0x45, 0xe6, 0x34, 0x47, 0x05, 0x0b, 0x4d,
+	0x1e, 0x09, 0xbe, 0xcb, 0xce, 0x35, 0xfd, 0x7d, 0xaf, 0xdf, 0x4e, 0xf4, 0x19, 0x47, 0x2e, 0xe2,
+	0x93, 0x1f, 0x93, 0x8c, 0x76, 0x25, 0x83, 0x09, 0x06, 0x70, 0xbb, 0xbf, 0x52, 0x62, 0x97, 0xf9,
+	0x08, 0x18, 0x0f, 0x2a, 0xc1, 0x10, 0xeb, 0x3d, 0xbf, 0x81, 0xa3, 0xf0, 0xe5, 0x02, 0xdf, 0x5a,
+	0xbc, 0x3d, 0xbf, 0xad, 0x6c, 0x26, 0x2f, 0x8d, 0xcd, 0xc2, 0x47, 0x53, 0x59, 0xde, 0x24, 0x0a,
+	0xc2, 0x23, 0x5b, 0xef, 0x99, 0x22, 0x11, 0x24, 0x79, 0x64, 0xea, 0x8d, 0x76, 0x3f, 0x4e, 0xc4,
+	0x99, 0x53, 0x4a, 0xba, 0x9a, 0xa9, 0xaf, 0x9a, 0x2c, 0xb0, 0xe1, 0x9c, 0xab, 0x5c, 0xf8, 0x6a,
+	0x07, 0xbc, 0x5b, 0xa8, 0x94, 0x58, 0x57, 0x8e, 0x1a, 0xdf, 0x55, 0x9d, 0x03, 0x16, 0x14, 0x92,
+	0xe2, 0xd3, 0x31, 0xe0, 0xec, 0x80, 0x0a, 0x4d, 0xa5, 0x49, 0x6d, 0x99, 0x2c, 0xb0, 0xe1, 0xa8,
+	0x98, 0x9f, 0x44, 0x41, 0x23, 0xa6, 0x62, 0xe5, 0x4c, 0x31, 0x93, 0x05, 0x36, 0xdc, 0xe5, 0x8f,
+	0xb0, 0x59, 0xab, 0xfd, 0xa7, 0xf2, 0x3a, 0xfd, 0x7b, 0x55, 0xf6, 0x44, 0xaa, 0x5b, 0x13, 0x2e,
+	0xbc, 0xed, 0xf7, 0xdb, 0x75, 0x9e, 0x20, 0x07, 0x70, 0xcc, 0xbd, 0xf0, 0x47, 0xcd, 0xb8, 0x0b,
+	0x47, 0xfc, 0xc6, 0x64, 0xc6, 0x7d, 0xa0, 0x82, 0x27, 0x1a, 0x7b, 0x2e, 0x8b, 0xa0, 0xd3, 0x20,
+	0x2d, 0x5c, 0xb9, 0x46, 0xb5, 0x2c, 0x72, 0x53, 0x65, 0x80, 0x81, 0x71, 0xb6, 0xd9, 0x05, 0xd9,
+	0xc5, 0xd7, 0xee, 0xa3, 0x36, 0xc2, 0x8f, 0x44, 0x59, 0xb9, 0x9d, 0xca, 0xb2, 0x17, 0xb6, 0x86,
+	0xc0, 0xc0, 0xd0, 0x92, 0xce, 0x16, 0x7b, 0xa4, 0x21, 0x9c, 0x93, 0xfd, 0x76, 0xe8, 0x35, 0x15,
+	0x42, 0xa1, 0xdf, 0xd6, 0x87, 0xb6, 0xd5, 0x41, 0x10, 0x18, 0x56, 0x2e, 0x3b, 0x9b, 0x2b, 0x63,
+	0xcd, 0xe6, 0xe9, 0x71, 0x66, 0xf3, 0xcc, 0x78, 0xb3, 0xb9, 0x7a, 0xb2, 0xd9, 0x8c, 0x3d, 0x4f,
+	0x7e, 0xb0, 0x11, 0x8a, 0x27, 0x62, 0x87, 0xb5, 0x7c, 0xdf, 0x75, 0xcf, 0xd7, 0x87, 0xc0, 0xc0,
+	0xd0, 0x92, 0xce, 0x1e, 0xbb, 0x2c, 0xd2, 0xaf, 0x75, 0x1b, 0xd1, 0x51, 0x4f, 0xde, 0x9e, 0x51,
+	0x78, 0x67, 0x53, 0x06, 0x86, 0xcb, 0xf5, 0x91, 0x90, 0x70, 0x0c, 0x16, 0xe7, 0x63, 0x6c, 0x5e,
+	0x8c, 0xd2, 0x96, 0xd7, 0x23, 0xb4, 0xc2, 0x13, 0xfe, 0x51, 0x89, 0x76, 0x7e, 0xd5, 0xce, 0x84,
+	0x34, 0xac, 0xb3, 0xc2, 0x16, 0x7b, 0x77, 0x1b, 0xf8, 0x73, 0x63, 0xff, 0xa6, 0xef, 0x37, 0xfd,
+	0x26, 0x79, 0xf2, 0x54, 0x6b, 0x8f, 0x29, 0xbd, 0xd3, 0x76, 0x3a, 0x1b, 0xb2, 0xf0, 0x5c, 0xe4,
+	0x9b, 0x8b, 0x13, 0x2f, 0x4a, 0xa4, 0x8a, 0x7a, 0x69, 0x41, 0xdc, 0x14, 0x50, 0x1a, 0xdc, 0xba,
+	0x95, 0x07, 0x29, 0xc8, 0xa1, 0xfb, 0xc5, 0xe2, 0xc3, 0xdb, 0x2f, 0xf2, 0x70, 0xab, 0xdf, 0x2c,
+	0xb2, 0xa7, 0x38, 0x33, 0xe0, 0x33, 0x4e, 0xda, 0x24, 0x86, 0x6d, 0xfb, 0x27, 0xd2, 0xef, 0xa7,
+	0x37, 0xed, 0xe2, 0x44, 0x37, 0xed, 0xd2, 0x84, 0x36, 0xed, 0xa9, 0x87, 0xb8, 0x69, 0xff, 0x5a,
+	0x91, 0x3d, 0x96, 0xea, 0x49, 0xbc, 0x1d, 0x24, 0x19, 0xfe, 0x5b, 0x1d, 0x78, 0x82, 0x0e, 0xfc,
+	0x17, 0x25, 0xf6, 0x0e, 0xde, 0x81, 0xb4, 0xfd, 0x75, 0x5b, 0xdb, 0x41, 0xcf, 0x6f, 0x73, 0x59,
+	0xd7, 0x42, 0xeb, 0xfc, 0xb9, 0x02, 0x9b, 0x13, 0xb2, 0xa4, 0xbc, 0xca, 0x92, 0x57, 0xd9, 0x37,
+	0xc4, 0xd9, 0xc3, 0x2c, 0x70, 0x21, 0xc1, 0xca, 0xdd, 0x23, 0x45, 0xf7, 0x2d, 0x29, 0xf6, 0x24,
+	0xe3, 0xf9, 0x43, 0x25, 0x76, 0x09, 0xc7, 0x53, 0xb9, 0x16, 0xbe, 0x75, 0x94, 0x78, 0x1d, 0x06,
+	0xe1, 0x17, 0xcb, 0xec, 0x11, 0x3e, 0x08, 0x03, 0x1c, 0xe9, 0xff, 0xd3, 0xee, 0xe7, 0x12, 0xa3,
+	0x71, 0x75, 0xa5, 0x0b, 0x14, 0x24, 0x59, 0x64, 0x24, 0xc6, 0xfa, 0x20, 0x08, 0x0c, 0x2b, 0xe7,
+	0x7c, 0x3f, 0x7b, 0x2c, 0x16, 0xec, 0x4a, 0xe8, 0x28, 0x84, 0x40, 0x6d, 0xdd, 0xae, 0x54, 0xb6,
+	0xfb, 0xc7, 0xea, 0xc3, 0xc1, 0x60, 0x54, 0x79, 0xe7, 0x8b, 0x6c, 0xae, 0x27, 0x59, 0x20, 0x8e,
+	0x59, 0x6e, 0xaf, 0x88, 0x6d, 0x0b, 0x99, 0xe1, 0x71, 0x76, 0x2a, 0xa4, 0x08, 0x0e, 0x9d, 0xa9,
+	0x33, 0x0f, 0x73, 0xff, 0x2c, 0xb3, 0xe9, 0xeb, 0x51, 0xd8, 0xef, 0xd5, 0xd0, 0xd1, 0x57, 0xf9,
+	0x54, 0x15, 0x72, 0x5e, 0x17, 0x11, 0xca, 0x58, 0x73, 0x88, 0xc9, 0x38, 0x5a, 0xf1, 0x49, 0xcc,
+	0xe5, 0x23, 0xbf, 0x29, 0x95, 0xa9, 0x7a, 0x12, 0xdf, 0xc0, 0x44, 0x10, 0x79, 0x4e, 0x87, 0x2d,
+	0x7a, 0x6d, 0x8e, 0xd5, 0x6f, 0x6e, 0xf2, 0x93, 0x12, 0x5a, 0xa5, 0xc6, 0x74, 0xf5, 0x22, 0x53,
+	0xe3, 0x4a, 0x1a, 0x15, 0x64, 0x71, 0x3b, 0x77, 0xd8, 0x74, 0xcc, 0x67, 0x98, 0xa7, 0x95, 0xe8,
+	0x39, 0x2e, 0x94, 0xd5, 0x5e, 0xa8, 0x0b, 0x54, 0x42, 0x6f, 0x25, 0x3f, 0x40, 0x11, 0xe0, 0x83,
+	0x3c, 0xbd, 0x27, 0x5c, 0xff, 0x24, 0x17, 0xba, 0x9e, 0x63, 0x2f, 0xb5, 0x5d, 0x08, 0x05, 0x3d,
+	0x95, 0xa6, 0x88, 0xe0, 0xf5, 0x75, 0xb4, 0xe2, 0xac, 0xe1, 0xf5, 0xf5, 0x4a, 0xce, 0xfb, 0x58,
+	0x9b, 0x12, 0x91, 0x50, 0xb5, 0xa9, 0x2f, 0xd0, 0x04, 0xf8, 0xb8, 0x4d, 0xf3, 0x33, 0x50, 0xab,
+	0xe5, 0xab, 0x9b, 0x79, 0xeb, 0x39, 0xa7, 0xd1, 0x8e, 0xc0, 0x26, 0x1d, 0xb9, 0xc5, 0x07, 0x28,
+	0x1a, 0xee, 0x8f, 0x57, 0xd8, 0x8c, 0xba, 0x84, 0x86, 0x9e, 0xa6, 0xfd, 0xa8, 0x2d, 0x19, 0xab,
+	0xe6, 0x43, 0xbb, 0xb0, 0x09, 0x98, 0x8e, 0x46, 0x09, 0x3e, 0x3b, 0x0e, 0xc2, 0x66, 0xd6, 0x28,
+	0xb1, 0x45, 0xa9, 0x20, 0x73, 0x2d, 0xe3, 0x45, 0xe9, 0x58, 0xe3, 0xc5, 0x11, 0x9b, 0x3e, 0xf0,
+	0xf1, 0x24, 0x1b, 0x4b, 0xb6, 0x78, 0x33, 0xf7, 0x3d, 0xba, 0xe5, 0xe7, 0x04, 0x42, 0xa1, 0x05,
+	0xd0, 0x06, 0x69, 0x99, 0x0a, 0x8a, 0x9e, 0xf3, 0x57, 0x0a, 0x6c, 0x5e, 0xfc, 0xe6, 0x67, 0xaf,
+	0x1e, 0x1a, 0x26, 0x84, 0x95, 0x7b, 0x67, 0x52, 0x35, 0x90, 0x68, 0x45, 0x3d, 0xf4, 0xe9, 0x2f,
+	0x95, 0x07, 0xe9, 0x1a, 0xd8, 0xae, 0xfa, 0x95, 0xc9, 0xb9, 0xea, 0x3b, 0x0d, 0x36, 0x85, 0x6a,
+	0xfd, 0xdc, 0xbc, 0x58, 0x35, 0x10, 0x0f, 0xd5, 0xd2, 0xc5, 0xa9, 0x8f, 0xf6, 0x2a, 0x44, 0x8e,
+	0x17, 0xde, 0x83, 0x2e, 0xb9, 0x1f, 0xf8, 0xf5, 0xc3, 0xa0, 0xc7, 0x77, 0xee, 0x60, 0x5f, 0x5c,
+	0xa1, 0x9f, 0x31, 0x17, 0xde, 0x37, 0x06, 0x20, 0x60, 0x48, 0xa9, 0xcb, 0x1f, 0x65, 0x73, 0xf6,
+	0x28, 0x9e, 0xe6, 0x64, 0x78, 0xf9, 0x53, 0xcc, 0x19, 0xec, 0xff, 0x53, 0x9d, 0x2d, 0x7f, 0x8d,
+	0x8b, 0xeb, 0x76, 0x53, 0xf1, 0xaa, 0xd1, 0x1e, 0x3a, 0xab, 0x4b, 0xae, 0x9e, 0xe3, 0xf6, 0xb2,
+	0x72, 0x79, 0x17, 0xfa, 0x73, 0xfa, 0x04, 0x81, 0xdb, 0xd9, 0x60, 0x95, 0x3d, 0xdf, 0x8b, 0xfc,
+	0xe8, 0x74, 0xde, 0xeb, 0xa4, 0x36, 0xaf, 0x51, 0x41, 0x90, 0x08, 0xdc, 0x9f, 0x2d, 0x30, 0x46,
+	0x0d, 0x10, 0x06, 0x88, 0xa6, 0x1c, 0xfe, 0xbc, 0x76, 0xb3, 0xd4, 0x25, 0x89, 0x81, 0xf1, 0x7f,
+	0x1f, 0x67, 0xff, 0xe2, 0x40, 0x22, 0x37, 0x25, 0xbd, 0xf4, 0xe4, 0x39, 0x05, 0x54, 0xbe, 0xfb,
+	0xd7, 0x0b, 0x6c, 0x96, 0x4b, 0x67, 0x35, 0x2f, 0xc1, 0x19, 0xdd, 0xc2, 0x50, 0x29, 0x1d, 0xef,
+	0xfe, 0x16, 0xdf, 0x44, 0x38, 0x6f, 0x17, 0xf6, 0xbd, 0xb2, 0xb8, 0x3e, 0xba, 0x65, 0x92, 0xc1,
+	0x86, 0x21, 0x2b, 0x25, 0x2f, 0xaa, 0x7b, 0x6b, 0x3c, 0x2b, 0x25, 0x61, 0x00, 0x89, 0xc9, 0xfd,
+	0xf9, 0x12, 0x63, 0x1b, 0x4d, 0x6d, 0xa2, 0xfd, 0x2c, 0xab, 0x26, 0x07, 0x91, 0x1f, 0x1f, 0x84,
+	0xed, 0xe6, 0x98, 0xb6, 0x50, 0x32, 0x56, 0xec, 0x28, 0x24, 0x60, 0xf0, 0xa1, 0xa7, 0x34, 0x5e,
+	0xce, 0x50, 0xd7, 0x39, 0xc6, 0x6c, 0xc5, 0x39, 0xa1, 0xd0, 0x31, 0x78, 0x20, 0x85, 0xd5, 0xf1,
+	0xd8, 0x6c, 0xd0, 0x6d, 0x08, 0xa9, 0xa6, 0x76, 0x34, 0xe6, 0xee, 0x4f, 0x03, 0xb1, 0x61, 0xd0,
+	0x80, 0x8d, 0x13, 0x85, 0x0c, 0x74, 0x78, 0x10, 0x7d, 0x26, 0x6e, 0xe7, 0x4c, 0x8d, 0x2f, 0x64,
+	0x6c, 0xa4, 0x51, 0x41, 0x16, 0xb7, 0xfb, 0x5a, 0x81, 0x2d, 0x52, 0xf3, 0xb0, 0xd5, 0xe2, 0x18,
+	0xec, 0xdc, 0x63, 0xb3, 0x0d, 0xe3, 0x5a, 0x2a, 0xbb, 0x72, 0x2d, 0x87, 0xb7, 0x87, 0xc6, 0x25,
+	0xda, 0x6e, 0x25, 0x80, 0x4d, 0x89, 0x4b, 0x21, 0x33, 0x7b, 0x72, 0x0e, 0xe7, 0xbe, 0xeb, 0x60,
+	0xad, 0x07, 0x21, 0x18, 0xa8, 0x2f, 0xd0, 0x34, 0xdc, 0xdf, 0x2f, 0xb2, 0x8b, 0x99, 0xc6, 0xcb,
+	0xb5, 0xe5, 0xfc, 0xe9, 0x81, 0xf8, 0x3a, 0x7f, 0xf2, 0x64, 0xfd, 0x2f, 0x2e, 0x68, 0x63, 0x10,
+	0x1d, 0xa3, 0xfc, 0x35, 0x69, 0x56, 0x50, 0x9d, 0x3e, 0x9b, 0x8a, 0x51, 0xa0, 0x17, 0xdd, 0x5b,
+	0x1f, 0xbf, 0xa1, 0x43, 0x1b, 0x40, 0xe2, 0xbd, 0x36, 0xc5, 0x93, 0x58, 0x4f, 0xe4, 0x9c, 0x57,
+	0x59, 0x25, 0x4e, 0xbc, 0xa4, 0xaf, 0x64, 0xd7, 0xdd, 0x49, 0x13, 0x26, 0xe4, 0x46, 0x40, 0x11,
+	0xdf, 0x20, 0x89, 0xba, 0xbf, 0x5f, 0x60, 0x97, 0x87, 0x17, 0xdc, 0x0c, 0xe2, 0xc4, 0xf9, 0x53,
+	0x03, 0xdd, 0x7e, 0xc2, 0x69, 0x8f, 0xa5, 0xa9, 0xd3, 0xf5, 0x8d, 0x4e, 0x95, 0x62, 0x75, 0x79,
+	0xc2, 0x8f, 0xaa, 0x89, 0xdf, 0x51, 0x66, 0x93, 0x5b, 0x13, 0x6e, 0xba, 0x75, 0xf6, 0x45, 0x2a,
+	0x20, 0x88, 0xb9, 0x5f, 0x29, 0x8e, 0x6a, 0x32, 0x9d, 0xaf, 0x7e, 0x60, 0xf0, 0x52, 0xd1, 0xad,
+	0xfc, 0x97, 0x8a, 0x32, 0x15, 0x1b, 0x79, 0xb7, 0xa8, 0x9d, 0xbe, 0x5b, 0x74, 0x23, 0xdf, 0xdd,
+	0xa2, 0x34, 0xd5, 0xc1, 0x2b, 0x46, 0xdf, 0x2a, 0xb1, 0xc7, 0x8f, 0x9b, 0x36, 0x78, 0xe0, 0x93,
+	0xb3, 0x33, 0xef, 0x81, 0xef, 0xf8, 0x79, 0xe8, 0x5c, 0x65, 0x65, 0x0e, 0x16, 0x2b, 0xad, 0xc5,
+	0xe3, 0xda, 0x4b, 0x16, 0x13, 0x5f, 0x43, 0x06, 0x4d, 0xda, 0x0e, 0xfa, 0x04, 0x01, 0x8a, 0x3b,
+	0x72, 0x47, 0xec, 0x97, 0x52, 0x0a, 0xd7, 0x3b, 0xb2, 0xdc, 0x46, 0x41, 0xe5, 0xf3, 0x99, 0x56,
+	0x11, 0x76, 0x08, 0xc9, 0xbc, 0x27, 0xab, 0x9a, 0xd4, 0x8d, 0x92, 0x4a, 0x49, 0x49, 0xcb, 0x59,
+	0x96, 0xf7, 0x05, 0xca, 0xa9, 0xa8, 0x48, 0x53, 0x43, 0x14, 0x38, 0xe2, 0xaa, 0x00, 0x17, 0x31,
+	0xc3, 0x3d, 0xb2, 0xbc, 0x34, 0xa5, 0x93, 0x05, 0xf2, 0xfb, 0x0a, 0x39, 0x56, 0x68, 0x11, 0xf3,
+	0xd6, 0x00, 0x04, 0x0c, 0x29, 0xe5, 0xfe, 0xab, 0x19, 0x76, 0x71, 0xf8, 0xec, 0xc3, 0x7e, 0xbb,
+	0xcb, 0x45, 0x4f, 0x73, 0x83, 0xcc, 0xdc, 0x77, 0x17, 0xc9, 0xa0, 0xf2, 0xdf, 0xd4, 0xfe, 0x92,
+	0xbf, 0x58, 0x60, 0x97, 0x22, 0x69, 0x48, 0x3c, 0x0b, 0x9f, 0xc9, 0x77, 0xf0, 0x9a, 0x5d, 0x82,
+	0x51, 0x04, 0x61, 0x74, 0x5d, 0x9c, 0x5f, 0x28, 0xb0, 0x25, 0x69, 0x17, 0x5c, 0x3d, 0x83, 0x88,
+	0x13, 0x74, 0x73, 0x6a, 0x6b, 0x04, 0x3d, 0x18, 0x59, 0x13, 0xe7, 0x8b, 0x6c, 0xb6, 0x87, 0xf3,
+	0x82, 0x8b, 0x60, 0xdd, 0x86, 0xf2, 0x6f, 0x1e, 0x7f, 0x25, 0x6d, 0x1b, 0x5c, 0xfa, 0xc6, 0x39,
+	0xc9, 0x23, 0x56, 0x06, 0xd8, 0x14, 0xdf, 0xe0, 0x21, 0x26, 0xf8, 0x5a, 0x89, 0xfd, 0x04, 0x1d,
+	0x43, 0x85, 0x42, 0xae, 0x2a, 0xd6, 0x4a, 0x5d, 0xa6, 0x81, 0xce, 0x45, 0xb7, 0x1f, 0xb2, 0x4b,
+	0xe2, 0x05, 0x9a, 0xa5, 0xaa, 0xb9, 0x6a, 0x59, 0x57, 0x89, 0x60, 0xf2, 0xf1, 0xaa, 0xe4, 0x1e,
+	0x2d, 0x5f, 0x69, 0x5b, 0x11, 0xc6, 0x61, 0x92, 0x8c, 0x6b, 0x56, 0x3a, 0xa4, 0xa0, 0xd0, 0xf8,
+	0xed, 0x6b, 0xe3, 0xad, 0x0c, 0x7f, 0xa6, 0xe5, 0x1f, 0x63, 0xd6, 0x05, 0x0b, 0x0a, 0x75, 0x23,
+	0x49, 0x3b, 0x26, 0x73, 0xee, 0x8c, 0xd1, 0x8d, 0xec, 0x6c, 0xd6, 0x01, 0xd3, 0xdd, 0x3f, 0xe2,
+	0xa2, 0x69, 0xe6, 0x92, 0xec, 0x83, 0xd4, 0x29, 0x2f, 0xc9, 0x93, 0x59, 0x71, 0x02, 0x91, 0x91,
+	0x86, 0x1e, 0xca, 0xc9, 0x16, 0x6c, 0xea, 0x23, 0xf7, 0x01, 0xcb, 0x16, 0x6c, 0xf2, 0x20, 0x05,
+	0x89, 0x1d, 0x94, 0xa0, 0x61, 0xd6, 0xdb, 0x6b, 0xfb, 0x4d, 0xe2, 0x06, 0x56, 0x07, 0xf1, 0x36,
+	0xcb, 0x1c, 0xb0, 0xa0, 0xdc, 0x3f, 0x28, 0xb2, 0xf9, 0x54, 0xf8, 0xa4, 0x07, 0xb5, 0x1f, 0xcf,
+	0x8c, 0x7d, 0x12, 0x36, 0x55, 0x7c, 0x4c, 0x7d, 0x66, 0x14, 0xc9, 0xa0, 0xf2, 0x9d, 0xcf, 0x33,
+	0xe6, 0x35, 0x0e, 0xa5, 0x6a, 0x63, 0xcc, 0x93, 0x0c, 0x39, 0x37, 0xaf, 0x68, 0x2c, 0x60, 0x61,
+	0xc4, 0x38, 0x39, 0x38, 0xb8, 0x79, 0xe3, 0xe4, 0xf0, 0x9e, 0x11, 0x3e, 0x7e, 0x6a, 0x5a, 0xe8,
+	0x31, 0x2e, 0x3f, 0xa4, 0x31, 0x76, 0x7f, 0xb5, 0x64, 0xcd, 0x3b, 0x79, 0x76, 0x7d, 0xb0, 0x1a,
+	0xcf, 0x12, 0xe0, 0xaa, 0xb6, 0xd4, 0x41, 0x02, 0x97, 0xcc, 0x75, 0xbe, 0x97, 0xcd, 0xf0, 0x7d,
+	0x03, 0xa3, 0x9f, 0x46, 0x72, 0xea, 0x68, 0x71, 0x75, 0x55, 0xa6, 0x83, 0x86, 0x70, 0x3e, 0xce,
+	0x16, 0x9a, 0x3e, 0x5e, 0x80, 0x88, 0x5e, 0x92, 0x71, 0x45, 0xa7, 0xd2, 0x9e, 0x0f, 0x6b, 0x22,
+	0x57, 0x9a, 0x46, 0xe6, 0x9b, 0xf6, 0xa7, 0xf3, 0x31, 0xb6, 0xb8, 0x1f, 0xb4, 0xb9, 0xac, 0xf5,
+	0x92, 0x1c, 0x72, 0x11, 0x5a, 0xb2, 0x2a, 0x3c, 0x49, 0xd7, 0x29, 0x4b, 0xce, 0x8a, 0x18, 0x16,
+	0xf6, 0x53, 0xdf, 0x6a, 0xf4, 0x2a, 0x0f, 0x6d, 0xf4, 0xa6, 0x1f, 0xd6, 0xe8, 0xfd, 0xf3, 0x12,
+	0x9b, 0x7d, 0x3e, 0xdc, 0x7b, 0x93, 0xdc, 0x0f, 0x19, 0x2e, 0xc5, 0x14, 0x5f, 0x47, 0x29, 0x66,
+	0x97, 0x3d, 0x96, 0x24, 0x6d, 0x19, 0x02, 0x77, 0x65, 0x9f, 0x4f, 0x8a, 0xf5, 0xa0, 0x1b, 0xc4,
+	0x07, 0x9c, 0x69, 0x09, 0x07, 0xbd, 0xb7, 0xa3, 0x19, 0x6b, 0x87, 0x0f, 0xe8, 0x10, 0x10, 0x18,
+	0x55, 0x96, 0x76, 0x15, 0x11, 0xb9, 0x83, 0x2e, 0xdb, 0x4a, 0xbf, 0x3d, 0xb1, 0xab, 0x58, 0xe9,
+	0x90, 0x82, 0x72, 0x7f, 0xba, 0xc4, 0xaa, 0x3a, 0x14, 0x22, 0xfa, 0xe0, 0xee, 0x45, 0xe1, 0x21,
+	0x2a, 0xb7, 0x0b, 0xe6, 0xe6, 0x68, 0x4d, 0x24, 0x81, 0xca, 0x43, 0x5b, 0x4e, 0x12, 0xf6, 0x82,
+	0x46, 0xd6, 0x20, 0xb9, 0x83, 0x89, 0x20, 0xf2, 0x68, 0xc5, 0x92, 0xe2, 0x8f, 0x5a, 0x35, 0x63,
+	0xad, 0x58, 0x4a, 0x05, 0x99, 0xfb, 0xf0, 0xd8, 0xd8, 0xbb, 0xf5, 0x09, 0xa1, 0x9c, 0x66, 0x19,
+	0x19, 0x99, 0x1e, 0x83, 0x99, 0x79, 0x71, 0x5b, 0x2e, 0xc5, 0x1c, 0xc1, 0xcc, 0x56, 0xea, 0x9b,
+	0x32, 0x98, 0x19, 0xff, 0x05, 0x84, 0x14, 0x5d, 0xab, 0xf8, 0x94, 0xec, 0xc6, 0x9e, 0xb8, 0xd6,
+	0xdb, 0xa6, 0x65, 0x39, 0x63, 0x18, 0xcc, 0x8e, 0x9d, 0x09, 0x69, 0x58, 0xf7, 0x1f, 0xf2, 0x95,
+	0x26, 0x06, 0x47, 0xf0, 0xc8, 0x49, 0x0e, 0xcf, 0x27, 0xc9, 0xe7, 0x8b, 0xd8, 0x20, 0xd9, 0x02,
+	0x25, 0xb7, 0xd4, 0x41, 0x6a, 0x57, 0xed, 0x4c, 0xed, 0xf7, 0x65, 0x92, 0xfe, 0x98, 0x8f, 0x1b,
+	0x17, 0x43, 0x28, 0x16, 0xa8, 0x3c, 0x3f, 0x49, 0x57, 0x6e, 0x2d, 0x86, 0xdc, 0xb0, 0xf2, 0x20,
+	0x05, 0xe9, 0xbe, 0x8f, 0x69, 0x0b, 0x19, 0xc9, 0x5f, 0x5e, 0x2b, 0xbb, 0xa9, 0xed, 0x78, 0x2d,
+	0xc0, 0x74, 0x0c, 0xa3, 0x51, 0xc5, 0x48, 0x41, 0x8d, 0xa3, 0x46, 0xdb, 0xe7, 0xf2, 0xc2, 0x65,
+	0xbe, 0xbf, 0xf8, 0x38, 0xf6, 0xd7, 0x39, 0x17, 0xf3, 0xb9, 0xd0, 0x1c, 0x50, 0xd8, 0x63, 0x0a,
+	0xab, 0x2d, 0x9c, 0xf1, 0x9f, 0x40, 0x2f, 0xbf, 0xb5, 0x91, 0x50, 0x70, 0x0c, 0x06, 0x67, 0x83,
+	0xcd, 0x35, 0xfd, 0x38, 0x88, 0xfc, 0xe6, 0xb6, 0x75, 0x2e, 0x7f, 0x97, 0x6a, 0xd2, 0x9a, 0x95,
+	0xc7, 0xcf, 0xb2, 0xf3, 0xca, 0x60, 0x2d, 0x0e, 0xe8, 0xa9, 0xa2, 0xc8, 0xc2, 0x7a, 0x5e, 0x3f,
+	0xf6, 0x87, 0xd4, 0x53, 0x5c, 0x64, 0x22, 0x16, 0xb6, 0x3d, 0x1c, 0x04, 0x46, 0x95, 0x75, 0xf6,
+	0xd8, 0x12, 0xd5, 0x7f, 0x18, 0xde, 0x29, 0xc2, 0xfb, 0x6e, 0x8e, 0xd7, 0x5d, 0xf3, 0x31, 0x12,
+	0x09, 0x46, 0x3d, 0x5f, 0x1b, 0x01, 0x0d, 0x23, 0xf1, 0xb8, 0xcf, 0xb0, 0x73, 0x56, 0x90, 0x67,
+	0x11, 0xd9, 0xf1, 0x81, 0x57, 0x9b, 0xdc, 0x32, 0xc3, 0x10, 0xb4, 0xee, 0x57, 0x4a, 0x4c, 0x47,
+	0xef, 0x76, 0xfe, 0x7c, 0x81, 0xcd, 0x7a, 0xdd, 0x6e, 0x98, 0xc8, 0xc8, 0xd8, 0xc2, 0x43, 0x1c,
+	0x72, 0x07, 0x09, 0x5f, 0x5e, 0x31, 0x48, 0x85, 0x29, 0xce, 0xc4, 0x83, 0x31, 0x39, 0x60, 0xd3,
+	0x76, 0xfa, 0x19, 0x7f, 0xe5, 0xad, 0xfc, 0xb5, 0x38, 0x81, 0x67, 0xf2, 0xe5, 0x67, 0xd9, 0xb9,
+	0x6c, 0x65, 0x4f, 0x65, 0xf9, 0xca, 0xe1, 0x4e, 0xf9, 0xf5, 0x22, 0x63, 0xc6, 0x03, 0xf0, 0x0c,
+	0xb4, 0xc9, 0x41, 0x4a, 0x9b, 0x3c, 0xbe, 0xf5, 0xde, 0x54, 0x7a, 0xa4, 0x06, 0xf9, 0xe5, 0x8c,
+	0x06, 0x79, 0x63, 0x12, 0xc4, 0x8e, 0xd7, 0x1a, 0xff, 0xb7, 0x22, 0xbb, 0x64, 0x80, 0x6b, 0x47,
+	0x78, 0xef, 0x49, 0x5f, 0x39, 0xc3, 0x7b, 0x6c, 0x14, 0xef, 0xb3, 0x30, 0xf1, 0xdb, 0x9b, 0xd9,
+	0xe0, 0x9f, 0x09, 0x9b, 0xd9, 0xf7, 0xda, 0xed, 0x3d, 0x15, 0x3c, 0x6a, 0xb2, 0xd4, 0xe8, 0xf8,
+	0xbe, 0x2e, 0xf1, 0x83, 0xa6, 0xe4, 0xdc, 0x63, 0xd5, 0xb0, 0x5b, 0xef, 0x37, 0x1a, 0xc6, 0xe3,
+	0x64, 0x92, 0x64, 0x49, 0x15, 0x70, 0x4b, 0x11, 0x00, 0x43, 0xcb, 0xdd, 0x63, 0x8f, 0x98, 0x5e,
+	0x37, 0x5b, 0xc1, 0x8d, 0x0c, 0xab, 0x16, 0x8c, 0xe9, 0x3d, 0x23, 0x58, 0xf5, 0xa2, 0xe5, 0x09,
+	0x3b, 0xc8, 0xac, 0xdd, 0x9f, 0x2b, 0xb2, 0x73, 0x36, 0x11, 0x8a, 0xc4, 0xf2, 0x21, 0x36, 0x8f,
+	0x57, 0xce, 0xc9, 0x64, 0x43, 0x77, 0xae, 0x0a, 0x74, 0x49, 0x8a, 0x6e, 0x85, 0x82, 0x9d, 0x01,
+	0x69, 0x38, 0x34, 0xd0, 0x61, 0x82, 0x3a, 0xd6, 0x16, 0xc7, 0x37, 0xd0, 0x81, 0x41, 0x03, 0x36,
+	0x4e, 0x0c, 0xbf, 0x8d, 0xca, 0x23, 0x21, 0xc6, 0xe6, 0x0d, 0xbf, 0x0d, 0x0a, 0x93, 0x18, 0x05,
+	0xfd, 0x09, 0x86, 0x86, 0xfb, 0xad, 0x02, 0x5b, 0xb0, 0x7b, 0xe8, 0xa1, 0x9b, 0x49, 0x0e, 0xd2,
+	0x66, 0x92, 0xd5, 0x09, 0xac, 0xef, 0x11, 0xa6, 0x91, 0xdf, 0x9e, 0xb3, 0x9b, 0x46, 0xe6, 0x10,
+	0x5b, 0x03, 0x5c, 0x38, 0x56, 0x03, 0xfc, 0x56, 0x2c, 0xe1, 0x91, 0xb1, 0x84, 0x47, 0x1d, 0x7a,
+	0xcb, 0x6f, 0xe0, 0x43, 0x6f, 0xe5, 0x75, 0x3c, 0xf4, 0x5a, 0xd1, 0x7a, 0xa7, 0x73, 0x44, 0xeb,
+	0xed, 0xe8, 0x68, 0xbd, 0x33, 0x13, 0xdb, 0x19, 0x4f, 0x12, 0xb1, 0xb7, 0x7a, 0xa6, 0x11, 0x7b,
+	0xd9, 0xc3, 0x8a, 0xd8, 0x3b, 0x9b, 0x37, 0x62, 0xef, 0x0f, 0x73, 0x4e, 0xd9, 0x4c, 0x85, 0x07,
+	0x91, 0x41, 0xd8, 0xc7, 0x97, 0x87, 0xd2, 0xd1, 0x46, 0x84, 0xee, 0x2c, 0x9d, 0x06, 0x19, 0x92,
+	0xc3, 0xe2, 0xe4, 0xce, 0xbf, 0x3e, 0x71, 0x72, 0x5f, 0x65, 0xd5, 0xb6, 0xda, 0xb5, 0xe9, 0xda,
+	0x52, 0x9e, 0xb5, 0x3f, 0x44, 0x12, 0x30, 0x17, 0xfd, 0x74, 0x12, 0x18, 0x8a, 0xce, 0x5d, 0x56,
+	0xd9, 0x23, 0x51, 0x4d, 0x5e, 0x7a, 0x82, 0x49, 0x6c, 0x24, 0x69, 0xd9, 0x4f, 0xba, 0x6b, 0x51,
+	0x22, 0x48, 0x6a, 0xee, 0xff, 0x9c, 0xb6, 0x45, 0x8a, 0xb3, 0xb6, 0x2c, 0x7f, 0x30, 0x6d, 0x59,
+	0x7e, 0x2a, 0x6b, 0x59, 0x1e, 0x90, 0x87, 0xa4, 0x75, 0xf9, 0x7b, 0xad, 0x8d, 0xaf, 0x44, 0x91,
+	0x67, 0xf5, 0x54, 0x1f, 0xb2, 0xf9, 0xad, 0xb0, 0x45, 0x29, 0x46, 0xa9, 0x4c, 0xda, 0x46, 0xe6,
+	0xcd, 0xbd, 0xb6, 0xb5, 0x74, 0x36, 0x64, 0xe1, 0x91, 0x60, 0xac, 0x1e, 0x74, 0x2a, 0xa7, 0x95,
+	0xd1, 0xfa, 0xb1, 0x25, 0x0d, 0x81, 0x7a, 0x0f, 0x2e, 0x05, 0xc5, 0xd2, 0x3e, 0x6c, 0xe9, 0x3d,
+	0x80, 0x52, 0x41, 0xe6, 0xda, 0x46, 0xf2, 0xe9, 0x07, 0x18, 0xc9, 0xb9, 0xb0, 0xd6, 0xf6, 0xe2,
+	0x44, 0x4c, 0x62, 0x15, 0x6b, 0xef, 0x7b, 0x4e, 0x26, 0xc8, 0xa0, 0x34, 0x66, 0x4e, 0x9e, 0x9b,
+	0x06, 0x0d, 0xd8, 0x38, 0xd1, 0x2d, 0x0c, 0x3f, 0x89, 0xa3, 0x35, 0x57, 0x12, 0xc9, 0x29, 0x4f,
+	0x43, 0x43, 0x2b, 0x55, 0x36, 0x2d, 0x3c, 0x90, 0xc2, 0x3a, 0xc2, 0x8e, 0xce, 0xc6, 0xb1, 0xa3,
+	0xa3, 0x4a, 0x0e, 0xa5, 0xcd, 0x23, 0x3d, 0xac, 0xb3, 0x34, 0xac, 0x5a, 0x25, 0x07, 0x76, 0x26,
+	0xa4, 0x61, 0x71, 0x56, 0xf4, 0x65, 0x37, 0xa8, 0xe2, 0x73, 0xe9, 0x59, 0xb1, 0x9b, 0xce, 0x86,
+	0x2c, 0x3c, 0xde, 0x11, 0xd5, 0x49, 0x76, 0x35, 0xe6, 0x09, 0x8f, 0xbe, 0x23, 0xba, 0x3b, 0x04,
+	0x06, 0x86, 0x96, 0xa4, 0xeb, 0xb4, 0xfd, 0x28, 0xf2, 0xbb, 0xc9, 0x73, 0x5e, 0x7c, 0x20, 0xaf,
+	0x4f, 0x9a, 0xeb, 0xb4, 0x26, 0x0b, 0x6c, 0x38, 0x34, 0x98, 0x09, 0x74, 0x54, 0x6a, 0x91, 0x4a,
+	0xe9, 0x33, 0xf0, 0xae, 0xce, 0x01, 0x0b, 0xca, 0xfd, 0x3f, 0x05, 0x36, 0xa3, 0x2c, 0x03, 0x67,
+	0xe3, 0x65, 0xaa, 0x43, 0x5b, 0x17, 0x73, 0x85, 0xb6, 0xc6, 0x90, 0xcd, 0xdd, 0x43, 0xff, 0xe8,
+	0x74, 0x01, 0x4b, 0xc4, 0xbb, 0x2d, 0xa8, 0xae, 0xa6, 0xc2, 0xee, 0x8f, 0x17, 0x45, 0xf3, 0x27,
+	0x6c, 0x2a, 0x94, 0xba, 0xd4, 0xd2, 0x43, 0x33, 0x06, 0x4d, 0x3d, 0x2c, 0x63, 0xd0, 0x2f, 0x17,
+	0x19, 0xa3, 0x0e, 0x39, 0x91, 0x15, 0xef, 0x14, 0x5d, 0xf2, 0x4e, 0x56, 0x7e, 0xb9, 0xef, 0xf7,
+	0x95, 0x23, 0x90, 0x16, 0x9d, 0x5e, 0xc0, 0x44, 0x10, 0x79, 0x6f, 0x62, 0x13, 0x68, 0x95, 0x4d,
+	0xdf, 0x0c, 0x49, 0xfb, 0xe8, 0x1e, 0xb1, 0xf3, 0x96, 0x32, 0x72, 0x15, 0x25, 0xdd, 0xee, 0x09,
+	0x1e, 0x7a, 0x79, 0x4a, 0xba, 0x22, 0x15, 0xd3, 0x10, 0x96, 0xf3, 0x91, 0x52, 0x6b, 0x96, 0x46,
+	0xaa, 0x35, 0xff, 0x4e, 0x85, 0x2d, 0x66, 0x1e, 0xc1, 0x71, 0x3e, 0xcf, 0x8a, 0xf1, 0xd3, 0xb9,
+	0x3d, 0xaf, 0xeb, 0x4f, 0x5b, 0x78, 0x6b, 0x15, 0x8c, 0x07, 0x56, 0x7f, 0x1a, 0x38, 0x66, 0x8c,
+	0x42, 0xde, 0x46, 0x05, 0xac, 0x5c, 0xcf, 0x1b, 0x39, 0x1e, 0x07, 0x4b, 0xab, 0x71, 0xc5, 0x9a,
+	0xa7, 0x54, 0x10, 0x24, 0x70, 0x4b, 0xed, 0x45, 0xfe, 0x7e, 0x70, 0x3f, 0x7b, 0xa9, 0x63, 0x9b,
+	0x52, 0x41, 0xe6, 0x5a, 0x97, 0x3f, 0xa6, 0x8e, 0xbd, 0xfc, 0xd1, 0xc7, 0xc8, 0xaa, 0x38, 0x3e,
+	0xea, 0x11, 0xc1, 0xe7, 0x27, 0xf1, 0xf6, 0x90, 0x18, 0x72, 0xb3, 0x16, 0xc4, 0x77, 0x0c, 0x8a,
+	0x16, 0x7a, 0x2c, 0xeb, 0x57, 0x30, 0xc4, 0xfd, 0x98, 0x31, 0x2f, 0x5b, 0x90, 0xc7, 0xf2, 0x76,
+	0x1a, 0x15, 0x64, 0x71, 0xd3, 0x8d, 0x7e, 0x95, 0x54, 0x3b, 0xe2, 0xcc, 0x2f, 0x96, 0x56, 0x2b,
+	0x73, 0xa3, 0x3f, 0x9d, 0x0d, 0x59, 0x78, 0xe9, 0x1f, 0x2f, 0x22, 0xa7, 0xbd, 0xe2, 0x93, 0xe0,
+	0x51, 0xd2, 0xfe, 0xf1, 0x2a, 0x19, 0x6c, 0x18, 0x7c, 0x4a, 0x54, 0x7e, 0x6a, 0xb7, 0xfa, 0x2a,
+	0x15, 0xa3, 0x4a, 0x6f, 0xa5, 0xb3, 0x20, 0x0b, 0x8b, 0xde, 0x16, 0x32, 0x09, 0x63, 0xa2, 0xb1,
+	0xf1, 0xbd, 0x2d, 0xb6, 0x34, 0x16, 0xb0, 0x30, 0xba, 0xcf, 0x30, 0xf5, 0x78, 0x05, 0x72, 0x31,
+	0x5f, 0x7a, 0x99, 0x14, 0xd2, 0xf7, 0x06, 0x94, 0x8b, 0x89, 0xca, 0x77, 0xff, 0x0b, 0x67, 0x8f,
+	0xe6, 0x6a, 0x98, 0xf3, 0xb3, 0x05, 0xf6, 0xa8, 0x76, 0x7f, 0x4a, 0xc4, 0xe9, 0x96, 0xde, 0xe0,
+	0xcb, 0x6d, 0x2c, 0x1f, 0xe6, 0x7a, 0x45, 0x21, 0xff, 0xb6, 0x87, 0x91, 0x83, 0xe1, 0xb5, 0x70,
+	0x80, 0xcd, 0xf0, 0x33, 0x56, 0x72, 0xb4, 0x16, 0xa8, 0x5b, 0x0a, 0x43, 0x6f, 0x20, 0x5e, 0x93,
+	0x30, 0xa2, 0xa8, 0xd4, 0xed, 0x90, 0x12, 0x49, 0xe5, 0x80, 0xc6, 0xe3, 0x1c, 0xb0, 0x99, 0x6e,
+	0xf8, 0x52, 0x4c, 0x0f, 0x76, 0x95, 0x72, 0x06, 0x7b, 0x96, 0x2c, 0x53, 0xd8, 0x3d, 0xe5, 0x07,
+	0x4c, 0x77, 0x25, 0x23, 0xfd, 0xa9, 0x02, 0x7b, 0x84, 0x37, 0xb7, 0xe1, 0x53, 0x48, 0x7b, 0xf3,
+	0xc2, 0x07, 0x3e, 0x33, 0xa3, 0x93, 0xa5, 0xca, 0x4b, 0x3c, 0x33, 0xa3, 0x53, 0xc1, 0x82, 0xb0,
+	0xe2, 0xcc, 0x17, 0x27, 0x15, 0x67, 0xde, 0xfd, 0xc9, 0x22, 0xd5, 0x2d, 0x3b, 0x46, 0xf8, 0x50,
+	0xa6, 0xbc, 0x21, 0x68, 0x1e, 0xca, 0x2c, 0x98, 0x87, 0x32, 0xeb, 0x99, 0x3c, 0x18, 0x80, 0xe6,
+	0x5b, 0x15, 0xf3, 0x48, 0x99, 0xbc, 0x15, 0x36, 0xd5, 0x6e, 0xf0, 0x49, 0xe1, 0x36, 0xa4, 0x52,
+	0xf9, 0x99, 0xe8, 0xfb, 0x86, 0x5d, 0xfa, 0xcd, 0xcc, 0x01, 0x53, 0x00, 0x2c, 0x94, 0xb8, 0xb2,
+	0x84, 0xfa, 0x45, 0xc7, 0xe6, 0x7a, 0x40, 0x97, 0x2c, 0xab, 0xa0, 0xb4, 0xcb, 0xe2, 0xa5, 0x81,
+	0xe4, 0x48, 0x74, 0xf7, 0x6d, 0x8d, 0x05, 0x2c, 0x8c, 0xee, 0x6f, 0x70, 0x99, 0x4a, 0x19, 0x1b,
+	0xcf, 0xc0, 0x8e, 0xd3, 0x4a, 0xd9, 0x71, 0x26, 0x74, 0xcd, 0x77, 0x98, 0x15, 0x27, 0xcc, 0x58,
+	0x71, 0xae, 0xe7, 0x27, 0x75, 0xbc, 0x0d, 0xe7, 0x6f, 0x97, 0xd8, 0x82, 0x02, 0xcd, 0xab, 0xe6,
+	0xe7, 0xdc, 0x58, 0x78, 0x1f, 0x72, 0x76, 0x28, 0x42, 0x23, 0xca, 0xe7, 0x19, 0x88, 0x1b, 0xd7,
+	0xd2, 0x59, 0x90, 0x85, 0xc5, 0x69, 0x2d, 0x92, 0x76, 0x91, 0x3b, 0x1b, 0x4d, 0xfe, 0xbc, 0x98,
+	0xd6, 0xb5, 0x4c, 0x1e, 0x0c, 0x40, 0x67, 0xed, 0x0c, 0x53, 0x0f, 0xdb, 0xce, 0x50, 0x3e, 0x03,
+	0x3b, 0xc3, 0xbf, 0x2e, 0xb0, 0x39, 0x33, 0x40, 0x0f, 0xdd, 0xca, 0xb0, 0x9f, 0xb6, 0x32, 0xac,
+	0xe4, 0x9e, 0x7f, 0x23, 0x6c, 0x0c, 0x5f, 0xaa, 0xb2, 0xd4, 0xf5, 0x76, 0x8c, 0x59, 0x14, 0x0c,
+	0xbd, 0x83, 0x60, 0xb1, 0x37, 0x1d, 0xb3, 0x68, 0x63, 0x24, 0x24, 0x1c, 0x83, 0x85, 0x8b, 0x62,
+	0x33, 0x77, 0x7d, 0x2e, 0x74, 0x34, 0x7c, 0xd5, 0xbe, 0xeb, 0xb9, 0x95, 0xee, 0x52, 0x01, 0xa6,
+	0xfb, 0xf4, 0xb6, 0x24, 0x00, 0x9a, 0x14, 0x6f, 0x5a, 0x19, 0xdf, 0xc6, 0x50, 0xef, 0x60, 0xe7,
+	0x7c, 0x75, 0x43, 0xf7, 0x27, 0x7e, 0xf1, 0xfe, 0x24, 0xd4, 0xf8, 0x38, 0xa8, 0x51, 0x2a, 0x4e,
+	0xe5, 0x9c, 0x97, 0x27, 0x55, 0x25, 0x1e, 0x6a, 0xcd, 0x7a, 0x79, 0x42, 0xdc, 0xea, 0x18, 0xbd,
+	0x3a, 0x6f, 0xe1, 0x3d, 0xb5, 0x3d, 0x4b, 0x51, 0x76, 0xfc, 0x16, 0xea, 0x8d, 0xde, 0xb4, 0x50,
+	0x27, 0x81, 0xa1, 0x83, 0xcb, 0x3d, 0x91, 0x1a, 0x64, 0x15, 0xed, 0x7f, 0x7c, 0xa2, 0x4a, 0x17,
+	0x2d, 0x8d, 0xbb, 0xfa, 0x13, 0x0c, 0x0d, 0xe7, 0x6e, 0xea, 0xb9, 0x3a, 0xf1, 0x48, 0x61, 0x8e,
+	0x57, 0x77, 0x15, 0x2a, 0xb3, 0xbf, 0x8d, 0x78, 0xf6, 0xee, 0xcb, 0x05, 0xbc, 0xe1, 0x98, 0x5a,
+	0x39, 0x52, 0x2d, 0xf7, 0xdc, 0xa4, 0xee, 0x63, 0xa9, 0xbb, 0x8f, 0xa9, 0x44, 0xc8, 0x52, 0xb5,
+	0x9f, 0x91, 0x63, 0x0f, 0xf5, 0x19, 0xb9, 0xff, 0x5e, 0x36, 0x5b, 0xdf, 0x59, 0xab, 0xa3, 0x9f,
+	0x49, 0xab, 0xa3, 0x9f, 0xc8, 0xaa, 0xa3, 0x33, 0x9e, 0x54, 0xa7, 0xbf, 0xea, 0x94, 0xd1, 0xe2,
+	0x4e, 0x3d, 0x04, 0x2d, 0x2e, 0x3f, 0xaf, 0xdd, 0x25, 0xe6, 0x27, 0x02, 0xab, 0x96, 0x69, 0xab,
+	0xa6, 0xdd, 0xf3, 0xb6, 0x49, 0x06, 0x1b, 0x06, 0x8b, 0xc8, 0x47, 0xc4, 0xf5, 0xc3, 0x34, 0xb2,
+	0x48, 0xdd, 0x24, 0x83, 0x0d, 0x43, 0xb7, 0x24, 0x82, 0xee, 0xa1, 0x28, 0x30, 0x4d, 0x05, 0xc4,
+	0x2d, 0x09, 0x95, 0x08, 0x26, 0x1f, 0xcd, 0xd4, 0xfd, 0xe6, 0xbe, 0x80, 0x9d, 0x21, 0x58, 0x3a,
+	0x61, 0xec, 0xae, 0xad, 0xcb, 0x40, 0xaf, 0x2a, 0x57, 0x1c, 0x36, 0x7b, 0x2a, 0x83, 0xa6, 0xfa,
+	0xbc, 0x3a, 0x6c, 0xea, 0x64, 0xb0, 0x61, 0x30, 0x00, 0x77, 0x44, 0xe1, 0x33, 0x74, 0x29, 0x46,
+	0xa5, 0x64, 0x6c, 0x7e, 0x3b, 0x07, 0x32, 0x90, 0x23, 0x74, 0xd1, 0xb3, 0x63, 0xe9, 0xa2, 0x9f,
+	0x65, 0x0b, 0xcd, 0x08, 0xcd, 0x6e, 0xcd, 0x5b, 0x5d, 0x72, 0x97, 0x93, 0x77, 0x35, 0xb4, 0xfd,
+	0x69, 0x2d, 0x95, 0x0b, 0x19, 0x68, 0x77, 0x9d, 0x89, 0x57, 0x27, 0x30, 0xd8, 0x2d, 0x3e, 0xc1,
+	0xac, 0x2c, 0xfa, 0xa4, 0x0a, 0xa1, 0x0b, 0xf5, 0x20, 0xd2, 0xf1, 0xb5, 0x17, 0x7a, 0xfc, 0x59,
+	0xdc, 0xf9, 0xca, 0xbc, 0xdc, 0xec, 0x86, 0x7c, 0xf9, 0xc8, 0x67, 0x87, 0x4f, 0xa3, 0x80, 0x22,
+	0xf5, 0x52, 0x71, 0x64, 0x40, 0xf0, 0x4b, 0xc2, 0x9f, 0x4b, 0x78, 0x35, 0x93, 0x82, 0x0d, 0x35,
+	0xa9, 0x98, 0x86, 0xf1, 0xaa, 0xe7, 0xec, 0x47, 0x90, 0x1f, 0xa4, 0x39, 0x1c, 0x8c, 0xed, 0x5c,
+	0x9c, 0x74, 0x6c, 0x67, 0x74, 0x9b, 0xc5, 0x53, 0x7b, 0x56, 0xe3, 0xb8, 0x83, 0x89, 0x20, 0xf2,
+	0x9c, 0xc8, 0x68, 0x80, 0xa6, 0x72, 0x4a, 0x1d, 0xe9, 0x0e, 0x3f, 0x46, 0xfd, 0xc3, 0x65, 0x71,
+	0x74, 0x79, 0x6d, 0x07, 0x8d, 0x64, 0xd5, 0xd2, 0x3e, 0x55, 0x05, 0x13, 0x5e, 0x4d, 0x67, 0x41,
+	0x16, 0x16, 0xd5, 0xfc, 0xf8, 0x06, 0x94, 0x48, 0x94, 0xb6, 0x25, 0x73, 0x44, 0xd2, 0x39, 0x60,
+	0x41, 0xa1, 0xe5, 0x2a, 0x68, 0x0a, 0x04, 0xd2, 0xc8, 0xa4, 0x85, 0xa2, 0x8d, 0x35, 0x91, 0x0e,
+	0x1a, 0xc2, 0xb9, 0xc3, 0x16, 0x38, 0xd1, 0x2e, 0xef, 0x6a, 0x25, 0xae, 0xcf, 0x8c, 0x25, 0xae,
+	0xd3, 0x28, 0xad, 0xa6, 0x30, 0x41, 0x06, 0xb3, 0xfb, 0x5b, 0x45, 0x3e, 0xe5, 0xe9, 0x8d, 0x8c,
+	0x0d, 0xf6, 0x08, 0x9a, 0xba, 0x03, 0xaf, 0x4d, 0x17, 0xed, 0x6d, 0x47, 0xd9, 0x72, 0xed, 0x31,
+	0xf4, 0x97, 0xd8, 0x18, 0xcc, 0x86, 0x61, 0x65, 0x90, 0x1d, 0xc8, 0xc8, 0x23, 0x0a, 0x8b, 0x58,
+	0x26, 0xe2, 0x4d, 0xab, 0x54, 0x0e, 0x64, 0x20, 0xf1, 0x88, 0xd5, 0x1b, 0xf0, 0x80, 0x2d, 0x8b,
+	0x23, 0x56, 0xda, 0x29, 0x35, 0x0d, 0x47, 0x47, 0x7f, 0xe1, 0x06, 0xa6, 0xe3, 0x2d, 0x48, 0xa7,
+	0x7d, 0x71, 0xf4, 0xcf, 0xe4, 0xc1, 0x00, 0x34, 0x62, 0x90, 0xaf, 0xe9, 0x19, 0x0c, 0x65, 0x83,
+	0x61, 0x3d, 0x93, 0x07, 0x03, 0xd0, 0xee, 0x6f, 0x15, 0x18, 0x06, 0x07, 0x8b, 0x3d, 0x0a, 0x3b,
+	0x3a, 0xa9, 0x67, 0x44, 0xf1, 0x19, 0xc7, 0x3d, 0x65, 0xd2, 0xc9, 0xfd, 0x8c, 0xa3, 0xa8, 0x9f,
+	0x31, 0x11, 0xd1, 0x96, 0xa1, 0x3f, 0xc1, 0x50, 0x72, 0xff, 0x11, 0x17, 0x7c, 0x32, 0xd0, 0xf8,
+	0xc8, 0xa6, 0x0a, 0x30, 0x7f, 0xba, 0x56, 0x89, 0xdd, 0x46, 0x16, 0x05, 0x8d, 0x64, 0xf2, 0xaf,
+	0x76, 0xfe, 0x50, 0x51, 0x8d, 0x01, 0x31, 0x42, 0xbe, 0x5c, 0x65, 0x20, 0xd8, 0x66, 0x33, 0x92,
+	0xfc, 0xd0, 0x48, 0x7c, 0x3a, 0x07, 0x2c, 0xa8, 0x93, 0x79, 0xfc, 0x7f, 0x98, 0xcd, 0xf1, 0x3e,
+	0xa7, 0x98, 0x51, 0x74, 0x0e, 0xcb, 0xdc, 0xac, 0xdb, 0xb6, 0xf2, 0x20, 0x05, 0xc9, 0x99, 0xaf,
+	0xb0, 0x86, 0x54, 0xf2, 0x06, 0xc9, 0xd2, 0x33, 0x6d, 0xc0, 0x1e, 0xf2, 0xbf, 0x8a, 0x7c, 0x3f,
+	0x10, 0x9d, 0x20, 0x2c, 0x49, 0x0f, 0xb3, 0x1b, 0xd4, 0x45, 0x86, 0x61, 0xdd, 0xb0, 0x6a, 0xe5,
+	0x41, 0x0a, 0x12, 0x1f, 0x74, 0x8b, 0xfb, 0x7b, 0x71, 0x23, 0x0a, 0xe8, 0x76, 0x25, 0x95, 0x9e,
+	0x4a, 0x3f, 0xe8, 0x56, 0xcf, 0xe4, 0xc3, 0x40, 0x09, 0x64, 0xad, 0x1d, 0xef, 0xfe, 0x6e, 0x17,
+	0x3d, 0x5c, 0xcb, 0x69, 0x2f, 0x84, 0x2d, 0x99, 0x0e, 0x1a, 0xe2, 0x2c, 0xba, 0xfe, 0x59, 0x76,
+	0x61, 0xbb, 0xdf, 0x6d, 0x24, 0x7d, 0x62, 0xc2, 0x46, 0x6d, 0xfa, 0x6e, 0x56, 0x11, 0xb1, 0x9e,
+	0x64, 0xef, 0x6b, 0xf9, 0x57, 0x04, 0x2b, 0x02, 0x99, 0xeb, 0xfe, 0xc5, 0x32, 0x33, 0xea, 0x0e,
+	0xdc, 0xf3, 0x79, 0xe5, 0xa5, 0x9e, 0x89, 0xf6, 0x7c, 0xde, 0x2a, 0xc0, 0x34, 0xca, 0x0a, 0xba,
+	0x52, 0x8f, 0x24, 0xb2, 0x82, 0x2e, 0x60, 0x1a, 0xee, 0x20, 0x91, 0xd7, 0xe9, 0xed, 0xf6, 0xf4,
+	0x43, 0x9c, 0xa5, 0xf1, 0x77, 0x10, 0x48, 0x61, 0x82, 0x0c, 0x66, 0xce, 0x9c, 0xca, 0x42, 0x1b,
+	0x3d, 0x95, 0xd3, 0x00, 0xa5, 0x1b, 0x8d, 0x67, 0x1e, 0x54, 0x4b, 0x13, 0xd7, 0xcd, 0xa6, 0x82,
+	0xa0, 0x86, 0x64, 0x3b, 0x61, 0x53, 0x07, 0xa1, 0x9c, 0x08, 0x59, 0xd4, 0xcf, 0xc6, 0x03, 0x64,
+	0x29, 0x15, 0x04, 0x35, 0xa1, 0x01, 0xc4, 0x39, 0xdc, 0x14, 0xdd, 0x42, 0x33, 0x69, 0x46, 0x69,
+	0x00, 0xad, 0x0c, 0x48, 0xc3, 0xe1, 0xfb, 0xca, 0x49, 0xd2, 0x96, 0x07, 0xe5, 0xb1, 0xde, 0x57,
+	0xde, 0xd9, 0xe1, 0xc2, 0x1b, 0xc7, 0xe1, 0xb4, 0x59, 0x85, 0x6f, 0x7d, 0x78, 0x15, 0x6c, 0x26,
+	0xe7, 0x5e, 0xa0, 0x5b, 0xb9, 0x4d, 0xf8, 0x84, 0x72, 0x41, 0xfc, 0x06, 0x49, 0xc3, 0xfd, 0x9d,
+	0x02, 0xbb, 0xa0, 0xe1, 0x78, 0xc2, 0x2d, 0xce, 0x1e, 0x22, 0x7e, 0x3a, 0x46, 0x11, 0x53, 0x5f,
+	0x2f, 0x30, 0x22, 0xa6, 0x12, 0x49, 0xd5, 0xcc, 0x2d, 0x8e, 0x9e, 0xb9, 0xa5, 0x13, 0xcd, 0xdc,
+	0xa9, 0x87, 0x35, 0x73, 0xdd, 0xdf, 0xe5, 0xfb, 0x5b, 0xa6, 0x07, 0x4e, 0xba, 0x4a, 0xe9, 0x79,
+	0x0d, 0xef, 0x3e, 0x19, 0xf3, 0x44, 0x0b, 0xc5, 0xf3, 0x1a, 0x22, 0x09, 0x54, 0x9e, 0xf3, 0x67,
+	0x58, 0x35, 0x94, 0xfd, 0xa5, 0x74, 0x5c, 0x5b, 0xf9, 0x47, 0xcb, 0x1a, 0x05, 0xa3, 0xa4, 0x51,
+	0x29, 0xe8, 0x10, 0xaf, 0x7e, 0xba, 0x7f, 0xad, 0xc8, 0x1c, 0x6b, 0x2a, 0x5f, 0x0f, 0xf1, 0xee,
+	0xe2, 0x4d, 0x21, 0x61, 0x86, 0xed, 0xb5, 0xf0, 0x5e, 0x57, 0xc8, 0x54, 0x63, 0x86, 0xb7, 0x92,
+	0x12, 0xa6, 0x8d, 0x09, 0x32, 0x98, 0xb9, 0x10, 0xe4, 0x60, 0xbf, 0xcb, 0x14, 0x34, 0x0a, 0xa9,
+	0xd8, 0xab, 0xf3, 0xb5, 0x8b, 0x78, 0xb6, 0x83, 0x81, 0x5c, 0x18, 0x52, 0x02, 0xe5, 0x53, 0x1d,
+	0x3d, 0xcb, 0x42, 0x24, 0xd4, 0xe0, 0x24, 0x9f, 0xee, 0x0c, 0x66, 0xc3, 0xb0, 0x32, 0xee, 0x65,
+	0xb6, 0x64, 0x75, 0xca, 0x46, 0x77, 0xcb, 0xef, 0x84, 0xd1, 0x91, 0xb0, 0x7a, 0xad, 0xb2, 0x4b,
+	0x56, 0x9e, 0xf5, 0xd6, 0x00, 0x32, 0x1d, 0x3e, 0x3b, 0xf6, 0xc4, 0xcb, 0xa3, 0x99, 0xd9, 0x21,
+	0xdf, 0x1b, 0x95, 0xb9, 0xee, 0x2f, 0x4c, 0xb1, 0x01, 0x0e, 0xe2, 0xfc, 0x48, 0x81, 0x9f, 0xd7,
+	0x1b, 0x07, 0xfc, 0xf4, 0xdb, 0xf6, 0x95, 0xac, 0xb3, 0x35, 0x11, 0x6e, 0xa9, 0x90, 0x8a, 0xd7,
+	0x6a, 0x86, 0xe5, 0x80, 0xa1, 0xec, 0xbc, 0xca, 0xa6, 0x23, 0x2e, 0xad, 0xdf, 0xf7, 0xd5, 0xa8,
+	0xdf, 0x98, 0x44, 0x25, 0x40, 0xa0, 0x54, 0x43, 0x9a, 0x4d, 0x07, 0x45, 0x13, 0xef, 0x79, 0xf1,
+	0xa3, 0x51, 0xfb, 0x68, 0x63, 0x9f, 0x4b, 0x83, 0x4d, 0xb9, 0x31, 0xdd, 0x9c, 0x44, 0x15, 0x6e,
+	0x69, 0xac, 0xc2, 0x56, 0x3b, 0x34, 0x0b, 0x2c, 0xe2, 0xd8, 0x15, 0x2d, 0xb1, 0x24, 0x24, 0x9b,
+	0x99, 0x48, 0x57, 0xc8, 0x55, 0x36, 0xd0, 0x15, 0x32, 0x1d, 0x14, 0x4d, 0x77, 0x8f, 0x0d, 0xaf,
+	0xe3, 0xa8, 0xb9, 0x5e, 0x18, 0x63, 0xae, 0xff, 0xc7, 0x42, 0x8a, 0x08, 0x45, 0x37, 0xd2, 0xd7,
+	0xf4, 0x70, 0x4f, 0xcb, 0xaa, 0x24, 0xc8, 0x60, 0x49, 0x39, 0xc8, 0xa7, 0x51, 0x89, 0x20, 0xc4,
+	0xbf, 0xe9, 0x94, 0x02, 0xe1, 0x2e, 0xfa, 0x62, 0x76, 0x13, 0xce, 0x20, 0xda, 0x72, 0x08, 0x37,
+	0xf3, 0x45, 0x62, 0xaa, 0x4b, 0x6c, 0x32, 0x62, 0x90, 0x8c, 0x0c, 0x22, 0xd2, 0x40, 0xd3, 0x72,
+	0x2e, 0xb2, 0x62, 0x73, 0x4f, 0x9e, 0xeb, 0xc8, 0x0d, 0x66, 0xad, 0x06, 0x3c, 0xc5, 0xbd, 0xc0,
+	0x86, 0x4c, 0x3a, 0xf7, 0x22, 0x1b, 0xba, 0x1a, 0xdc, 0x6f, 0x96, 0xd8, 0x80, 0x60, 0x41, 0x13,
+	0x33, 0xd2, 0xdd, 0x23, 0xd7, 0xc6, 0xcd, 0xc9, 0xac, 0x0d, 0x85, 0x75, 0x60, 0x62, 0x9a, 0x2c,
+	0xb0, 0x88, 0x3b, 0x7f, 0xa3, 0xc0, 0xe6, 0x03, 0x9b, 0x2f, 0x49, 0x7e, 0xf1, 0xc2, 0x24, 0xaa,
+	0x93, 0x62, 0x78, 0x22, 0xd8, 0xcd, 0xa8, 0x5c, 0x48, 0xd7, 0xc2, 0xf9, 0xa9, 0x02, 0x5b, 0xb8,
+	0x93, 0xe2, 0x89, 0x72, 0xf4, 0x61, 0x12, 0x15, 0x4b, 0x73, 0x5b, 0x19, 0x2f, 0x68, 0x54, 0x36,
+	0x64, 0x2a, 0xe2, 0xfe, 0x12, 0xe7, 0xaf, 0xd4, 0x9d, 0x6f, 0x92, 0x83, 0xea, 0x9f, 0x2d, 0xf0,
+	0x39, 0x3b, 0x10, 0x84, 0xec, 0x41, 0x9a, 0xbb, 0x89, 0x57, 0xe3, 0x55, 0x36, 0x4b, 0xb5, 0x38,
+	0x59, 0xc0, 0x9a, 0x89, 0x93, 0xff, 0x95, 0x29, 0xf6, 0xc8, 0x10, 0x06, 0x80, 0x07, 0xd6, 0x8e,
+	0x87, 0x4f, 0xd5, 0x58, 0x46, 0x4e, 0x7d, 0x60, 0xdd, 0xd2, 0x39, 0x60, 0x41, 0xa1, 0x42, 0xdc,
+	0xef, 0x36, 0xe9, 0x65, 0xc3, 0x58, 0xbe, 0xd5, 0x4d, 0xda, 0x8d, 0x6b, 0x2a, 0x11, 0x4c, 0xbe,
+	0x73, 0x9f, 0xcd, 0x29, 0xae, 0xb2, 0x62, 0x7c, 0x3a, 0x6b, 0xf9, 0xb8, 0x18, 0x9d, 0x08, 0x45,
+	0x50, 0x4e, 0x0b, 0x37, 0xa4, 0x28, 0x91, 0xa1, 0x5c, 0x01, 0xe7, 0x37, 0x94, 0x6b, 0xb2, 0xc2,
+	0x50, 0xae, 0x3e, 0xc1, 0xd0, 0xe0, 0x04, 0x67, 0x55, 0x05, 0x76, 0x36, 0xeb, 0x13, 0x09, 0x92,
+	0x22, 0x2c, 0x13, 0x06, 0x29, 0xd8, 0x14, 0x9c, 0x7d, 0xbc, 0x1a, 0xc0, 0xa9, 0x23, 0xb5, 0xe9,
+	0x09, 0x50, 0x93, 0x37, 0xea, 0x04, 0x46, 0xd0, 0xb8, 0xdd, 0x7f, 0x56, 0x64, 0xfc, 0xd4, 0x95,
+	0x20, 0x77, 0x92, 0x0e, 0x40, 0x2d, 0x36, 0x2d, 0x03, 0x72, 0xc8, 0x55, 0xff, 0xa9, 0x1c, 0x5e,
+	0xd4, 0x84, 0x47, 0x46, 0x85, 0xe0, 0x1f, 0xb7, 0xf6, 0xf7, 0x41, 0x61, 0x77, 0xae, 0xe1, 0x1d,
+	0x57, 0xa9, 0x12, 0x94, 0x3b, 0xe4, 0x7b, 0xc4, 0xbd, 0x54, 0x99, 0xf8, 0xda, 0xb7, 0x9f, 0xbc,
+	0xa8, 0x3f, 0x52, 0x95, 0x04, 0x53, 0x92, 0x6e, 0x00, 0x35, 0x82, 0xa8, 0xd1, 0x0f, 0x92, 0x1a,
+	0x67, 0x65, 0x87, 0x32, 0xce, 0x4e, 0x1e, 0x45, 0xf8, 0x6a, 0x0a, 0x9d, 0x94, 0xd1, 0x53, 0x69,
+	0x90, 0x21, 0xe9, 0xfe, 0x08, 0x0a, 0x09, 0x61, 0x1b, 0xc3, 0xe0, 0xa6, 0x6f, 0xe8, 0xf0, 0xb3,
+	0xe6, 0x82, 0x50, 0x9e, 0xdc, 0xe5, 0x15, 0x26, 0x75, 0xfe, 0x83, 0xdc, 0x88, 0xfa, 0x49, 0xd0,
+	0x5e, 0xc6, 0x15, 0x96, 0x44, 0x68, 0xe6, 0xbc, 0xc5, 0x77, 0xd7, 0x08, 0x63, 0x9a, 0xaa, 0xc7,
+	0x58, 0x2d, 0x5c, 0x90, 0xc1, 0xed, 0xfe, 0x0e, 0x1f, 0xcf, 0x94, 0xab, 0xeb, 0x49, 0x25, 0x6e,
+	0x7c, 0xd4, 0xcb, 0xbb, 0x17, 0x83, 0xdf, 0x52, 0x01, 0x60, 0xad, 0x47, 0xbd, 0x56, 0x5e, 0xac,
+	0x8b, 0x0c, 0x30, 0x30, 0xe8, 0xe4, 0xaf, 0x78, 0xc1, 0x6e, 0xd4, 0x96, 0x6a, 0x2b, 0x6d, 0xd9,
+	0x53, 0x1c, 0x03, 0xf9, 0x9e, 0x0d, 0x87, 0x16, 0xa6, 0xfd, 0x90, 0x0b, 0x57, 0x68, 0x96, 0xa9,
+	0x27, 0x47, 0xd2, 0xa3, 0xc0, 0xb2, 0x30, 0xad, 0xa7, 0x72, 0x21, 0x03, 0x8d, 0x46, 0x6c, 0xce,
+	0xf9, 0xfa, 0x9c, 0x71, 0x85, 0x6d, 0x75, 0xbd, 0x31, 0x47, 0x40, 0xe6, 0x17, 0xeb, 0x2b, 0x1a,
+	0x9b, 0x8c, 0x6e, 0xa5, 0xbf, 0xc1, 0xa2, 0xe4, 0xfe, 0xbb, 0x29, 0x46, 0xf1, 0x38, 0x9c, 0x0f,
+	0xb1, 0x6a, 0xc7, 0x6f, 0x1c, 0x78, 0xdd, 0x20, 0x56, 0x4f, 0x10, 0xa3, 0x4c, 0x52, 0xdd, 0x52,
+	0x89, 0xaf, 0xa1, 0xe0, 0xc5, 0x21, 0xc9, 0xf3, 0xd9, 0xc0, 0xe2, 0xed, 0xd5, 0x56, 0x1c, 0xf3,
+	0x8a, 0xe4, 0xbe, 0xbd, 0x2a, 0x1e, 0x56, 0x15, 0xca, 0x06, 0xf1, 0x1b, 0x24, 0x6a, 0xbc, 0x02,
+	0xd1, 0x6b, 0x7b, 0x41, 0x37, 0xf7, 0x3d, 0x65, 0x6c, 0xc1, 0x36, 0x62, 0x12, 0xea, 0x74, 0xfa,
+	0x09, 0x02, 0xb7, 0xd3, 0xe7, 0xec, 0xb0, 0xc1, 0x4f, 0x98, 0xf1, 0x81, 0x77, 0xf5, 0x03, 0x1f,
+	0xcc, 0xcd, 0xf8, 0x0d, 0x29, 0xc1, 0x14, 0x57, 0x61, 0x65, 0xab, 0xfe, 0xdc, 0x0a, 0x47, 0x0d,
+	0x36, 0x1d, 0x9b, 0xec, 0x07, 0xde, 0x7f, 0x35, 0x37, 0xe3, 0x1f, 0x41, 0x96, 0xa3, 0x06, 0x9b,
+	0x0e, 0x76, 0x69, 0x68, 0xe9, 0x3c, 0xf3, 0x11, 0xbc, 0x65, 0x6e, 0x95, 0xd0, 0x4f, 0x10, 0xb8,
+	0xdd, 0xff, 0xc1, 0x65, 0x2f, 0x9d, 0x8f, 0x42, 0x82, 0x78, 0x32, 0x6e, 0x63, 0x6d, 0x0c, 0xd9,
+	0x6b, 0x55, 0x16, 0x05, 0x8d, 0x04, 0xdf, 0x89, 0x15, 0xbf, 0xc7, 0x31, 0x56, 0xd2, 0x76, 0xbc,
+	0x6a, 0x15, 0x87, 0x14, 0x32, 0x8a, 0x29, 0x84, 0x66, 0x16, 0xb5, 0xe6, 0x25, 0x2f, 0x30, 0x31,
+	0x85, 0xec, 0x4c, 0x48, 0xc3, 0xea, 0x86, 0xd3, 0x48, 0x38, 0xbb, 0x8c, 0xa1, 0xbc, 0x28, 0x6b,
+	0x79, 0xaa, 0xa6, 0xd3, 0xe2, 0xdd, 0xd5, 0x85, 0xc1, 0x42, 0x74, 0x16, 0xd6, 0x5a, 0xce, 0x3f,
+	0xf9, 0x3a, 0x6f, 0xf2, 0x39, 0x73, 0xe8, 0xcb, 0x08, 0x53, 0x9a, 0x7f, 0x3e, 0xa7, 0x32, 0xc0,
+	0xc0, 0xb8, 0x5f, 0xad, 0x30, 0x71, 0xef, 0x16, 0x35, 0xf0, 0x7c, 0x3b, 0xb6, 0x1d, 0xb8, 0xb5,
+	0x06, 0x7e, 0x4d, 0xa6, 0x83, 0x86, 0xb0, 0xb5, 0xd6, 0xe5, 0x8c, 0xee, 0x4f, 0x6a, 0x0c, 0x2d,
+	0xfb, 0xb6, 0xd6, 0x18, 0x7e, 0x82, 0x2d, 0xb6, 0xc3, 0xf0, 0x10, 0x37, 0x5f, 0x3b, 0x82, 0xcd,
+	0xbc, 0xb0, 0xd9, 0x6e, 0xa6, 0xb3, 0x20, 0x0b, 0x8b, 0x01, 0x76, 0x5e, 0xf1, 0xa3, 0x50, 0xde,
+	0xf0, 0xaa, 0xb7, 0x7d, 0xbf, 0xa7, 0xd0, 0x08, 0x9b, 0x01, 0x05, 0xd8, 0xf9, 0xcc, 0x70, 0x10,
+	0x18, 0x55, 0x96, 0x42, 0x8f, 0x79, 0x51, 0xcb, 0x4f, 0xb6, 0xa3, 0x10, 0x4d, 0x89, 0xf8, 0x1c,
+	0x8e, 0x44, 0x5b, 0x31, 0x68, 0x77, 0x86, 0x83, 0xc0, 0xa8, 0xb2, 0xce, 0xa7, 0xd9, 0x92, 0xc8,
+	0x12, 0x07, 0x83, 0x15, 0xb1, 0x3d, 0x06, 0xed, 0x20, 0x39, 0x92, 0x6e, 0x1e, 0x74, 0x66, 0xdb,
+	0x19, 0x01, 0x03, 0x23, 0x4b, 0x3b, 0xcf, 0xb3, 0x73, 0xea, 0x42, 0xe6, 0x36, 0x9e, 0x87, 0xf1,
+	0x2e, 0xb6, 0x70, 0x06, 0x91, 0x91, 0x90, 0x54, 0x24, 0x20, 0xc8, 0x40, 0xc1, 0x40, 0x39, 0x07,
+	0xd8, 0x45, 0xba, 0x70, 0xbd, 0xdb, 0x43, 0x05, 0x60, 0x33, 0xbc, 0xd7, 0x55, 0x6d, 0x17, 0x1e,
+	0x23, 0x97, 0x39, 0xc6, 0x8b, 0xf5, 0xa1, 0x10, 0x30, 0xa2, 0x24, 0xb6, 0x9c, 0x72, 0x50, 0xf5,
+	0x97, 0xc5, 0xca, 0x4c, 0xcb, 0xeb, 0x23, 0x60, 0x60, 0x64, 0x69, 0xd2, 0x42, 0x66, 0x5a, 0xb0,
+	0xdb, 0x93, 0x57, 0x15, 0x85, 0x9e, 0x66, 0x20, 0x17, 0x86, 0x94, 0x70, 0x36, 0xd9, 0x85, 0x6c,
+	0x2a, 0x92, 0x93, 0xb7, 0x16, 0x85, 0xfe, 0x6d, 0x48, 0x3e, 0x0c, 0x2d, 0xe5, 0xce, 0x72, 0xe6,
+	0x81, 0xf6, 0x36, 0xb4, 0x54, 0xba, 0xff, 0xb6, 0xc8, 0x16, 0x33, 0x6f, 0xc8, 0x9d, 0x81, 0xff,
+	0x76, 0x37, 0xe5, 0xbf, 0x3d, 0xbe, 0x12, 0x27, 0x53, 0xf3, 0x91, 0x6e, 0xdc, 0x77, 0x33, 0x6e,
+	0xdc, 0x37, 0x27, 0x46, 0xf1, 0x78, 0x6f, 0xee, 0xef, 0x14, 0xd8, 0x23, 0x99, 0x12, 0x67, 0xe0,
+	0x33, 0xdc, 0x49, 0xfb, 0x0c, 0x3f, 0x37, 0xa9, 0xc6, 0x8e, 0x70, 0x1d, 0xfe, 0xdf, 0x83, 0x8d,
+	0xac, 0x0b, 0xdf, 0xf9, 0x69, 0xf9, 0x5c, 0x97, 0x6c, 0xe3, 0x5a, 0xde, 0x8a, 0xd0, 0xf8, 0xa6,
+	0x1f, 0xf8, 0xe8, 0xb6, 0x40, 0x51, 0x71, 0x62, 0x36, 0xa3, 0xde, 0xe4, 0x9a, 0xec, 0xcd, 0x00,
+	0xdd, 0xd9, 0xfa, 0x99, 0x45, 0x4d, 0xc8, 0xfd, 0x89, 0x12, 0x7b, 0x74, 0xe8, 0xa4, 0x38, 0x3b,
+	0xe7, 0xc5, 0x8f, 0xa5, 0x9d, 0x17, 0xdf, 0x95, 0x75, 0x5e, 0xbc, 0x90, 0xa9, 0xdf, 0x1b, 0xd8,
+	0x87, 0x71, 0x82, 0x7e, 0x79, 0xee, 0x22, 0x3f, 0xd6, 0xd9, 0xcf, 0xc5, 0xb9, 0xdf, 0xac, 0xb0,
+	0x59, 0x6b, 0x26, 0xbd, 0xe1, 0x5e, 0xa8, 0x71, 0x5e, 0xe2, 0x03, 0x89, 0x9e, 0x80, 0x92, 0x4b,
+	0x3d, 0x9b, 0xc3, 0x2d, 0x8d, 0x63, 0x91, 0x87, 0x10, 0xfc, 0x09, 0x02, 0x2f, 0xfa, 0x48, 0x75,
+	0xe2, 0xd6, 0xc6, 0x9a, 0xb0, 0x2b, 0xa2, 0x31, 0x57, 0xf8, 0x3e, 0x88, 0x63, 0x72, 0x2a, 0x07,
+	0x32, 0x90, 0x7c, 0x13, 0x7a, 0x34, 0xf2, 0x5f, 0xee, 0xfb, 0x71, 0x92, 0x76, 0xa6, 0x92, 0xc2,
+	0x8c, 0xdc, 0xcf, 0x32, 0x00, 0x31, 0x0c, 0x2f, 0x84, 0x3c, 0x4a, 0x18, 0xf0, 0x2b, 0x39, 0x17,
+	0xaa, 0x1a, 0x50, 0x52, 0xde, 0x4a, 0x25, 0x98, 0x49, 0x51, 0x86, 0xfb, 0xe1, 0xc1, 0x74, 0xa6,
+	0x5f, 0xc7, 0x60, 0x3a, 0x76, 0x04, 0xa7, 0x99, 0x63, 0x23, 0x38, 0x8d, 0x0a, 0x53, 0x54, 0x7d,
+	0x23, 0x84, 0x29, 0x72, 0xbf, 0xc8, 0x52, 0x1d, 0x8e, 0x7a, 0x46, 0xdd, 0xd8, 0xdc, 0x31, 0x05,
+	0x4c, 0x40, 0x1b, 0xd2, 0x33, 0xea, 0x4f, 0x30, 0x34, 0xdc, 0x7d, 0x5c, 0xe6, 0xf4, 0x32, 0x8e,
+	0xbc, 0xfa, 0x6a, 0x3d, 0x67, 0x56, 0x98, 0xdc, 0x73, 0x66, 0xa8, 0x26, 0xaa, 0x6a, 0x67, 0xfd,
+	0x13, 0xb8, 0xd6, 0xa6, 0x3a, 0xa2, 0xf8, 0xf0, 0x3b, 0xc2, 0x0e, 0xcf, 0x54, 0xca, 0x11, 0x9e,
+	0xa9, 0x67, 0xde, 0x01, 0xcc, 0xeb, 0x5c, 0xa3, 0xbb, 0xeb, 0xf8, 0xa7, 0x00, 0xef, 0xb0, 0x73,
+	0x59, 0x48, 0x0a, 0xbb, 0x22, 0x2d, 0x67, 0xb2, 0x8f, 0x4d, 0xd8, 0x15, 0x99, 0x0e, 0x1a, 0x02,
+	0x17, 0x13, 0x0e, 0xd3, 0x2b, 0x61, 0x57, 0xdf, 0xa5, 0x47, 0xc8, 0x1d, 0x99, 0x06, 0x3a, 0xd7,
+	0xfd, 0xcf, 0x25, 0x76, 0xc9, 0x5c, 0xb9, 0xd8, 0xf2, 0xba, 0x7c, 0x63, 0x8b, 0xde, 0x0a, 0x83,
+	0x3d, 0x26, 0x13, 0xe3, 0xfd, 0xf5, 0x68, 0x2a, 0x54, 0xd5, 0x43, 0x7c, 0x62, 0x84, 0x8c, 0x98,
+	0x1b, 0xc3, 0x88, 0xc1, 0xf0, 0x3a, 0xb8, 0x3f, 0x53, 0x66, 0x14, 0x47, 0x0e, 0x5f, 0x87, 0x55,
+	0xfd, 0x59, 0x37, 0x21, 0x30, 0xaf, 0xe5, 0x1e, 0x4e, 0x0a, 0x57, 0xa7, 0xbd, 0x0e, 0xed, 0x54,
+	0x48, 0x11, 0xe4, 0xbc, 0x20, 0x1b, 0x11, 0x73, 0x42, 0xc4, 0x47, 0x05, 0xc3, 0x8c, 0x06, 0x83,
+	0x61, 0x4e, 0x88, 0xe2, 0xc8, 0x38, 0x98, 0x68, 0x55, 0x98, 0x8f, 0x6c, 0x93, 0x83, 0xe4, 0x1f,
+	0xeb, 0x39, 0xcc, 0x4c, 0x16, 0x36, 0x3b, 0xb8, 0x8f, 0x6d, 0xd7, 0x48, 0xd3, 0x44, 0x13, 0x5e,
+	0xb3, 0xfd, 0xb2, 0x7c, 0xc7, 0x91, 0x64, 0x13, 0xeb, 0x05, 0x89, 0xb5, 0xcd, 0x17, 0xd4, 0x8b,
+	0x9c, 0x16, 0x14, 0x5e, 0x65, 0xdb, 0x8b, 0x42, 0xaf, 0xc9, 0x77, 0x5b, 0xf5, 0x04, 0xe6, 0xf8,
+	0x95, 0xae, 0x29, 0x4c, 0xa6, 0xbb, 0x74, 0x12, 0x18, 0x3a, 0xee, 0x7f, 0xe2, 0xdd, 0x55, 0x6f,
+	0x07, 0x4d, 0xbe, 0x73, 0xca, 0x8d, 0x0b, 0x5f, 0x17, 0x14, 0xd7, 0x74, 0x0b, 0x39, 0x5e, 0x17,
+	0x14, 0xb7, 0x79, 0x25, 0x26, 0xe7, 0x16, 0x97, 0xb3, 0x38, 0x11, 0x7f, 0xcc, 0x6b, 0xf0, 0x24,
+	0x42, 0x62, 0x2d, 0x51, 0x92, 0xc2, 0xff, 0x50, 0x67, 0x27, 0x9e, 0x69, 0x50, 0xcf, 0xcf, 0x59,
+	0x3a, 0xbb, 0xba, 0xca, 0x00, 0x03, 0xe3, 0xfe, 0xdd, 0x2a, 0x93, 0xe1, 0x22, 0xd1, 0xa5, 0xbc,
+	0x25, 0xe4, 0xf3, 0x50, 0xc9, 0x06, 0xe3, 0x1f, 0x4e, 0xaf, 0x2b, 0x4c, 0x32, 0x76, 0x01, 0xf5,
+	0xb4, 0x4e, 0x04, 0x43, 0xc9, 0xf1, 0x59, 0x99, 0xa2, 0x7b, 0xe7, 0x7e, 0xa3, 0xcf, 0x8a, 0xe3,
+	0x2e, 0x7a, 0x86, 0x12, 0x40, 0x60, 0x47, 0x37, 0x5f, 0xba, 0x9d, 0x53, 0xca, 0xe9, 0xe6, 0x6b,
+	0xde, 0xd0, 0xcc, 0x5e, 0xf1, 0x41, 0x12, 0x5d, 0x2f, 0x89, 0x73, 0xbf, 0x74, 0x6c, 0xa2, 0xfd,
+	0xc8, 0xe8, 0x48, 0xfc, 0x1b, 0x08, 0xb5, 0xf3, 0x05, 0x36, 0x4b, 0x01, 0xec, 0x31, 0x5a, 0x8a,
+	0x1f, 0xe5, 0x36, 0x16, 0xed, 0xae, 0xed, 0x18, 0x6c, 0xc2, 0x63, 0x35, 0x95, 0x04, 0x36, 0x35,
+	0x7c, 0xf0, 0xb8, 0xdf, 0x14, 0x15, 0xcb, 0xfd, 0xe0, 0xf1, 0xee, 0x9a, 0x1d, 0xd4, 0x42, 0x7d,
+	0x81, 0x26, 0x80, 0xb3, 0xd1, 0x3c, 0x29, 0x37, 0x9d, 0x73, 0x36, 0x66, 0x9e, 0x42, 0x39, 0xe6,
+	0x2d, 0xb9, 0x8e, 0x51, 0x8b, 0xcc, 0xe4, 0x0d, 0xd0, 0x63, 0x1f, 0x6f, 0xe5, 0x9b, 0xd5, 0x59,
+	0xa5, 0x48, 0xc0, 0x2a, 0x3d, 0xf2, 0x1b, 0x97, 0xe7, 0x85, 0x6b, 0x39, 0xdd, 0xcf, 0xed, 0x28,
+	0xb0, 0x22, 0x05, 0x24, 0x01, 0xe7, 0x73, 0xac, 0x14, 0xbf, 0x1c, 0xcb, 0x5b, 0xa2, 0x39, 0x4c,
+	0x3e, 0x2f, 0xab, 0xb9, 0x49, 0xea, 0x78, 0xfe, 0x09, 0x88, 0x17, 0x27, 0xff, 0x3e, 0x06, 0xff,
+	0x98, 0xcd, 0x39, 0xf9, 0x29, 0x5a, 0x8d, 0x35, 0xf9, 0xf1, 0x1b, 0x08, 0xb5, 0xfb, 0xab, 0x45,
+	0x36, 0x8d, 0xe4, 0x71, 0xcf, 0x4e, 0x19, 0x77, 0x0b, 0x27, 0x30, 0xee, 0xf2, 0x02, 0x14, 0xd1,
+	0x8a, 0x7c, 0x47, 0x32, 0xd6, 0xe0, 0x17, 0x54, 0x06, 0x18, 0x18, 0xe7, 0x36, 0xbb, 0x48, 0x1f,
+	0xb7, 0xee, 0x71, 0x4e, 0x85, 0x56, 0xd5, 0x46, 0x03, 0x2f, 0x27, 0x6e, 0xac, 0x49, 0x4d, 0x8c,
+	0xba, 0x85, 0x7a, 0xf1, 0x85, 0xa1, 0x50, 0x30, 0xa2, 0x74, 0xc6, 0xdc, 0x3b, 0x75, 0x66, 0xe6,
+	0xde, 0xaf, 0x97, 0xf9, 0x09, 0x49, 0x0d, 0xde, 0x9b, 0xb8, 0xff, 0x56, 0xd9, 0xf9, 0xbb, 0x41,
+	0x1c, 0x08, 0xf3, 0x85, 0x1d, 0xbc, 0xa2, 0x2c, 0x44, 0xe3, 0xdb, 0xd9, 0x4c, 0x18, 0x84, 0x47,
+	0x6f, 0xca, 0x8e, 0x77, 0xff, 0x66, 0xbf, 0xb3, 0xe7, 0x47, 0xb7, 0xf6, 0x75, 0x38, 0xa4, 0xb2,
+	0xb9, 0xd9, 0xb6, 0x35, 0x98, 0x0d, 0xc3, 0xca, 0xa0, 0x1d, 0xea, 0x9e, 0x17, 0x90, 0x06, 0xc5,
+	0xb6, 0xf4, 0x94, 0x85, 0x1d, 0xea, 0xc5, 0x74, 0x16, 0x64, 0x61, 0xf1, 0x6a, 0xad, 0xed, 0x74,
+	0x20, 0xe3, 0x4d, 0x1e, 0xeb, 0x70, 0xf0, 0x51, 0xb6, 0xe0, 0x25, 0xfc, 0x78, 0xb6, 0xd7, 0x4f,
+	0xa8, 0xab, 0xc5, 0xcd, 0x77, 0xa9, 0x27, 0x5a, 0x49, 0xe5, 0x40, 0x06, 0x92, 0x4b, 0x1c, 0x8f,
+	0x4a, 0x85, 0x61, 0x1a, 0x50, 0x3e, 0xa9, 0x46, 0x62, 0xfc, 0xd6, 0x30, 0x00, 0x18, 0x5e, 0x2e,
+	0x33, 0x9d, 0xd9, 0x99, 0x4d, 0xe7, 0x0e, 0x93, 0x8a, 0x56, 0xa7, 0xc1, 0x58, 0x43, 0x47, 0x82,
+	0x97, 0x6f, 0x44, 0x5c, 0x39, 0x99, 0x24, 0x65, 0x62, 0xb0, 0x6a, 0x21, 0xd4, 0x04, 0x95, 0x07,
+	0x0b, 0xad, 0xfb, 0x6f, 0x8a, 0x0c, 0x63, 0xd7, 0x8d, 0x78, 0xd1, 0xbc, 0x30, 0xce, 0x8b, 0xe6,
+	0x64, 0xc2, 0xf6, 0x56, 0xfd, 0x28, 0x87, 0x09, 0x7b, 0xc5, 0x14, 0x87, 0x14, 0x32, 0xb4, 0x3b,
+	0x37, 0x0c, 0xea, 0xd2, 0xa9, 0xed, 0xce, 0x16, 0x62, 0x0b, 0x11, 0x97, 0x82, 0xab, 0x87, 0x08,
+	0x4a, 0x58, 0xa7, 0x4e, 0x83, 0x95, 0xf6, 0xdc, 0x1b, 0xaa, 0x2c, 0x18, 0x34, 0x6e, 0x97, 0xcd,
+	0xef, 0x78, 0x2d, 0xd3, 0xf1, 0xce, 0x47, 0xd8, 0x4c, 0xd8, 0xb3, 0x04, 0xd1, 0x2a, 0xf9, 0x7d,
+	0xce, 0xdc, 0x92, 0x69, 0x78, 0xe3, 0x7f, 0x33, 0x6c, 0x05, 0x0d, 0x95, 0x00, 0x1a, 0xdc, 0x71,
+	0x59, 0x85, 0x5e, 0xb0, 0x50, 0xce, 0x7e, 0xb4, 0x13, 0xde, 0xa6, 0x14, 0x90, 0x39, 0xee, 0x97,
+	0xa6, 0x98, 0x09, 0x27, 0xc1, 0x8f, 0x17, 0x15, 0x11, 0xfc, 0x58, 0xca, 0xbc, 0x0f, 0x35, 0xce,
+	0xb2, 0x24, 0xe5, 0xb4, 0x58, 0xe9, 0x4e, 0xb8, 0x97, 0x5b, 0xe4, 0xb5, 0x1e, 0x09, 0x13, 0x3c,
+	0xc3, 0x4a, 0x00, 0xa4, 0xe0, 0xfc, 0x5c, 0x81, 0x9d, 0x8f, 0xb3, 0x1a, 0x95, 0xdc, 0x0e, 0xba,
+	0x23, 0x75, 0x34, 0xc2, 0x41, 0x77, 0x64, 0x36, 0x0c, 0xd6, 0x05, 0xfb, 0x5f, 0x04, 0x3d, 0x90,
+	0xd3, 0x69, 0xfc, 0xfe, 0x17, 0x81, 0x14, 0xd2, 0xfd, 0x9f, 0x4e, 0x03, 0x49, 0xca, 0xfd, 0xf7,
+	0x05, 0x86, 0x91, 0xe6, 0xcf, 0x5c, 0x1f, 0x8a, 0xce, 0x88, 0x2d, 0x7c, 0xdc, 0x49, 0xbf, 0xbc,
+	0x3e, 0xbe, 0x33, 0xe2, 0x75, 0x81, 0x47, 0x48, 0x96, 0xf2, 0x03, 0x14, 0x76, 0xf7, 0x88, 0x55,
+	0xb8, 0x54, 0x2d, 0xd4, 0x1b, 0x67, 0xac, 0xf3, 0xfd, 0x02, 0xd3, 0x02, 0xfd, 0xd9, 0x13, 0xff,
+	0x12, 0x3f, 0xb8, 0xa7, 0xce, 0x30, 0x67, 0x5f, 0x85, 0x6f, 0x16, 0x58, 0x26, 0xaa, 0xb9, 0xf3,
+	0x41, 0x19, 0x4a, 0x34, 0x1d, 0x99, 0x49, 0xbd, 0x6a, 0xec, 0xa4, 0xa1, 0xad, 0x00, 0xa3, 0x5f,
+	0x46, 0xad, 0x8d, 0xed, 0x85, 0x29, 0x59, 0x46, 0x8e, 0x3b, 0x08, 0xc3, 0x7c, 0x3a, 0xe5, 0x65,
+	0x45, 0x3b, 0x0b, 0xd2, 0x74, 0xdd, 0x7f, 0x5a, 0x64, 0x95, 0x33, 0x7b, 0x09, 0xc8, 0x4f, 0x79,
+	0x20, 0xac, 0xe6, 0xe4, 0x08, 0x23, 0x1d, 0x0f, 0x3a, 0x19, 0xc7, 0x83, 0x6b, 0x79, 0x09, 0x1d,
+	0xef, 0x6f, 0xf0, 0x2f, 0xf9, 0xbc, 0x10, 0x80, 0x5c, 0x40, 0x48, 0x3c, 0x7c, 0x9d, 0xb7, 0xa1,
+	0x99, 0x5f, 0x5e, 0x2b, 0xb4, 0x8c, 0xac, 0x25, 0xf6, 0x3b, 0xfa, 0xad, 0x98, 0x1d, 0x6a, 0xeb,
+	0x0f, 0xc2, 0x38, 0xe9, 0x1a, 0xc9, 0x5d, 0x6b, 0xeb, 0x9f, 0x93, 0xe9, 0xa0, 0x21, 0xd0, 0x22,
+	0x2a, 0x8d, 0x5b, 0xd2, 0xed, 0x4a, 0x1b, 0x23, 0xa4, 0xf5, 0x0b, 0x54, 0xbe, 0xfb, 0x19, 0xb6,
+	0x98, 0x7d, 0x57, 0xe7, 0xfa, 0xd0, 0x77, 0x75, 0xde, 0x39, 0xe2, 0x5d, 0x9d, 0xd9, 0xd1, 0x6f,
+	0xea, 0xfc, 0xad, 0x12, 0x9b, 0x7b, 0xd3, 0xbc, 0xa7, 0x33, 0x24, 0x96, 0x5f, 0x29, 0x67, 0x2c,
+	0xbf, 0xa9, 0x53, 0xc5, 0xf2, 0x3b, 0xf3, 0x40, 0x7b, 0xdf, 0x28, 0x30, 0x76, 0x66, 0x8f, 0xf9,
+	0x34, 0xd3, 0x2e, 0x33, 0xb9, 0x17, 0xc9, 0x70, 0x4f, 0x99, 0xaf, 0x4e, 0xab, 0x26, 0x91, 0xff,
+	0x01, 0x3e, 0x81, 0xe1, 0xa5, 0xc2, 0xd6, 0xe5, 0x16, 0x10, 0x33, 0x51, 0xf0, 0xb4, 0x83, 0x78,
+	0x3a, 0x1d, 0x32, 0x64, 0x29, 0xac, 0x84, 0x74, 0x0e, 0xb1, 0x4e, 0xdf, 0x26, 0xac, 0x84, 0x95,
+	0x07, 0x29, 0xc8, 0x07, 0x84, 0x09, 0x2c, 0x4d, 0x24, 0x4c, 0xa0, 0x6d, 0x2a, 0x9f, 0x3a, 0xd6,
+	0x54, 0x7e, 0x97, 0x55, 0xf7, 0xa3, 0xb0, 0x43, 0x91, 0xf8, 0x64, 0x74, 0xe7, 0x6b, 0x39, 0x36,
+	0xe2, 0xce, 0x1e, 0xc6, 0x69, 0xa2, 0x28, 0x7f, 0x5a, 0x13, 0xb1, 0xae, 0xf0, 0x83, 0x21, 0x45,
+	0x36, 0xd3, 0x50, 0x50, 0xad, 0x4c, 0x92, 0xaa, 0x66, 0x8c, 0x3b, 0x02, 0x3b, 0x28, 0x32, 0xe9,
+	0xe8, 0x7b, 0xd3, 0x67, 0x14, 0x7d, 0xef, 0xc8, 0x0e, 0x6a, 0x98, 0x37, 0x0c, 0xc0, 0xe9, 0x5e,
+	0x49, 0x79, 0xc3, 0xc4, 0xc3, 0x73, 0x7f, 0x6c, 0x5a, 0x6d, 0x1b, 0x67, 0xed, 0xe7, 0x75, 0x35,
+	0xed, 0xe7, 0xf5, 0x78, 0xd6, 0xcf, 0x6b, 0xf6, 0xad, 0xf7, 0x52, 0x5a, 0xfe, 0xc0, 0x63, 0x26,
+	0x33, 0x67, 0xf8, 0x98, 0x49, 0x75, 0x32, 0x8f, 0x99, 0xb0, 0x7c, 0x8f, 0x99, 0xcc, 0x4e, 0xe8,
+	0x31, 0x93, 0xb9, 0x49, 0x3d, 0x66, 0x32, 0x3f, 0xd6, 0x63, 0x26, 0x0b, 0x27, 0x7a, 0xcc, 0xe4,
+	0xdb, 0x25, 0x96, 0x39, 0x82, 0xbf, 0xe5, 0xc5, 0xf1, 0xc7, 0xca, 0x8b, 0xe3, 0xff, 0x16, 0x99,
+	0xd9, 0x8d, 0x4e, 0x79, 0xef, 0xe3, 0xd3, 0x14, 0xa7, 0x89, 0xc2, 0xc4, 0x8d, 0x29, 0x95, 0xcf,
+	0xc9, 0x98, 0x4e, 0x84, 0x03, 0x34, 0x36, 0xbe, 0xff, 0xb2, 0xa0, 0xa9, 0xec, 0x48, 0xb9, 0x4d,
+	0xbe, 0x1b, 0x1a, 0x95, 0x50, 0x8d, 0x9a, 0x6f, 0xb0, 0xc8, 0xe0, 0xc3, 0xaa, 0x71, 0xda, 0xb7,
+	0xe2, 0xf9, 0xfc, 0x7b, 0xbe, 0x3e, 0xa1, 0x8b, 0xe8, 0x07, 0xca, 0xad, 0x42, 0x53, 0x72, 0xff,
+	0x42, 0x89, 0x9d, 0x1f, 0x80, 0x76, 0x7e, 0x60, 0x20, 0x20, 0x7f, 0xce, 0xa7, 0x0f, 0xb2, 0x21,
+	0xff, 0x8f, 0x0d, 0xef, 0xcf, 0x7b, 0x82, 0x0b, 0xee, 0xbd, 0x24, 0xb8, 0x9b, 0x7f, 0xe9, 0xac,
+	0x48, 0x44, 0x86, 0x32, 0xf5, 0x84, 0x4a, 0x06, 0x4d, 0xc9, 0xf9, 0x52, 0x81, 0xcd, 0xf6, 0x4c,
+	0x98, 0x2d, 0x39, 0xec, 0x5b, 0x39, 0x2c, 0xaa, 0x83, 0x21, 0xbb, 0xc4, 0x39, 0xd0, 0xca, 0x01,
+	0x9b, 0xa4, 0xfb, 0xf5, 0x12, 0x93, 0xcf, 0x12, 0xa0, 0x5b, 0xc3, 0x7e, 0x60, 0x02, 0xaa, 0xac,
+	0xe5, 0x30, 0x88, 0x72, 0x2c, 0x02, 0xa9, 0x70, 0x6b, 0xa0, 0x04, 0x10, 0xd8, 0xc9, 0x5e, 0x2d,
+	0xdc, 0x54, 0x64, 0x4f, 0xe7, 0xb0, 0x57, 0xdb, 0xee, 0x2e, 0xd2, 0x5e, 0x2d, 0x92, 0x40, 0xd1,
+	0x10, 0xe6, 0x71, 0x72, 0xe7, 0x94, 0xdd, 0x9b, 0xc7, 0x3c, 0x6e, 0xb9, 0x85, 0x2a, 0xf3, 0x38,
+	0x25, 0x81, 0xa2, 0xe1, 0xbc, 0xca, 0x66, 0xbd, 0x46, 0xa3, 0xdf, 0xe9, 0xb7, 0xc9, 0x16, 0x90,
+	0x77, 0x55, 0xad, 0x18, 0x5c, 0x92, 0x2c, 0x0d, 0xa7, 0x95, 0x0c, 0x36, 0x3d, 0xf7, 0xd7, 0x0b,
+	0x6c, 0x5e, 0x00, 0x2a, 0xc7, 0xc7, 0x27, 0x59, 0x99, 0xec, 0x90, 0x52, 0xf9, 0x40, 0xe3, 0x21,
+	0x62, 0xd8, 0x8a, 0x74, 0xe4, 0x69, 0x24, 0x92, 0xde, 0xd5, 0xcf, 0xef, 0x8c, 0xc5, 0xd3, 0x36,
+	0x24, 0x0e, 0xd0, 0xd8, 0x70, 0xd7, 0x46, 0x8e, 0xbb, 0x1e, 0xe0, 0xd5, 0xe9, 0x58, 0x3a, 0xf7,
+	0x58, 0x3e, 0xfb, 0x3a, 0x0b, 0x6c, 0xb8, 0xda, 0xe7, 0xbe, 0xf6, 0x7b, 0x4f, 0xbc, 0xed, 0x1b,
+	0xfc, 0xef, 0x5b, 0xfc, 0xef, 0x4b, 0xdf, 0x79, 0xa2, 0xf0, 0x35, 0xfe, 0xf7, 0x0d, 0xfe, 0xf7,
+	0x2d, 0xfe, 0xf7, 0xbb, 0xfc, 0xef, 0x2f, 0xff, 0x87, 0x27, 0xde, 0xf6, 0x99, 0x0f, 0x99, 0x2e,
+	0xbd, 0xa2, 0xba, 0xf4, 0x8a, 0xea, 0xc0, 0x2b, 0xbc, 0xb2, 0xf8, 0xac, 0x45, 0x6c, 0x52, 0x54,
+	0x97, 0xfe, 0x3f, 0xbf, 0xad, 0xb1, 0xcc, 0xee, 0xdc, 0x00, 0x00,
 }
 
 func (m *AWSAssumeRole) Marshal() (dAtA []byte, err error) {
@@ -4460,6 +5358,78 @@ func (m *AbstractSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Postgres != nil {
+		{
+			size, err := m.Postgres.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x6a
+	}
+	if m.ObjectStore != nil {
+		{
+			size, err := m.ObjectStore.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x62
+	}
+	if m.File != nil {
+		{
+			size, err := m.File.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x5a
+	}
+	if m.HTTP != nil {
+		{
+			size, err := m.HTTP.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x52
+	}
+	if m.JetStream != nil {
+		{
+			size, err := m.JetStream.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x4a
+	}
+	if m.Nats != nil {
+		{
+			size, err := m.Nats.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x42
+	}
 	if m.Pulsar != nil {
 		{
 			size, err := m.Pulsar.MarshalToSizedBuffer(dAtA[:i])
@@ -4796,6 +5766,53 @@ func (m *AccumulatorWindow) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *AdaptiveWatermark) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *AdaptiveWatermark) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *AdaptiveWatermark) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.MaxDelay != nil {
+		{
+			size, err := m.MaxDelay.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.HalfLife != nil {
+		{
+			size, err := m.HalfLife.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *Authorization) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -4965,6 +5982,48 @@ func (m *Blackhole) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *BroadcastSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *BroadcastSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *BroadcastSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Ack)
+	copy(dAtA[i:], m.Ack)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Ack)))
+	i--
+	dAtA[i] = 0x12
+	if len(m.Sinks) > 0 {
+		for iNdEx := len(m.Sinks) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Sinks[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintGenerated(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0xa
+		}
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *BufferServiceConfig) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -4985,6 +6044,18 @@ func (m *BufferServiceConfig) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Redis != nil {
+		{
+			size, err := m.Redis.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
 	if m.JetStream != nil {
 		{
 			size, err := m.JetStream.MarshalToSizedBuffer(dAtA[:i])
@@ -5000,6 +6071,118 @@ func (m *BufferServiceConfig) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *BuiltinReducer) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *BuiltinReducer) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *BuiltinReducer) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Precision != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Precision))
+		i--
+		dAtA[i] = 0x20
+	}
+	if len(m.Quantiles) > 0 {
+		for iNdEx := len(m.Quantiles) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Quantiles[iNdEx])
+			copy(dAtA[i:], m.Quantiles[iNdEx])
+			i = encodeVarintGenerated(dAtA, i, uint64(len(m.Quantiles[iNdEx])))
+			i--
+			dAtA[i] = 0x1a
+		}
+	}
+	i -= len(m.Field)
+	copy(dAtA[i:], m.Field)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Field)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Aggregation)
+	copy(dAtA[i:], m.Aggregation)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Aggregation)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *CircuitBreaker) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *CircuitBreaker) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *CircuitBreaker) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.OnOpen != nil {
+		i -= len(*m.OnOpen)
+		copy(dAtA[i:], *m.OnOpen)
+		i = encodeVarintGenerated(dAtA, i, uint64(len(*m.OnOpen)))
+		i--
+		dAtA[i] = 0x2a
+	}
+	if m.OpenDuration != nil {
+		{
+			size, err := m.OpenDuration.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
+	if m.Window != nil {
+		{
+			size, err := m.Window.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
+	if m.MinWrites != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MinWrites))
+		i--
+		dAtA[i] = 0x10
+	}
+	if m.FailureRatio != nil {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(*m.FailureRatio))))
+		i--
+		dAtA[i] = 0x9
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *CombinedEdge) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -5109,6 +6292,16 @@ func (m *Compression) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	i -= len(m.DictionaryBucket)
+	copy(dAtA[i:], m.DictionaryBucket)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.DictionaryBucket)))
+	i--
+	dAtA[i] = 0x1a
+	if m.MinSize != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MinSize))
+		i--
+		dAtA[i] = 0x10
+	}
 	i -= len(m.Type)
 	copy(dAtA[i:], m.Type)
 	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Type)))
@@ -5488,6 +6681,94 @@ func (m *Edge) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *FileSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *FileSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *FileSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.MaxSegmentAge != nil {
+		{
+			size, err := m.MaxSegmentAge.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
+	if m.MaxSegmentSize != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MaxSegmentSize))
+		i--
+		dAtA[i] = 0x18
+	}
+	i -= len(m.Format)
+	copy(dAtA[i:], m.Format)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Format)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Path)
+	copy(dAtA[i:], m.Path)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Path)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *FileSource) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *FileSource) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *FileSource) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.CheckpointPath)
+	copy(dAtA[i:], m.CheckpointPath)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.CheckpointPath)))
+	i--
+	dAtA[i] = 0x1a
+	i -= len(m.Format)
+	copy(dAtA[i:], m.Format)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Format)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Path)
+	copy(dAtA[i:], m.Path)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Path)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *FixedWindow) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -6306,6 +7587,42 @@ func (m *GroupBy) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Trigger != nil {
+		{
+			size, err := m.Trigger.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x3a
+	}
+	if m.LateData != nil {
+		{
+			size, err := m.LateData.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x32
+	}
+	if m.Builtin != nil {
+		{
+			size, err := m.Builtin.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x2a
+	}
 	if m.Storage != nil {
 		{
 			size, err := m.Storage.MarshalToSizedBuffer(dAtA[:i])
@@ -6351,6 +7668,171 @@ func (m *GroupBy) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *HTTPSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *HTTPSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *HTTPSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i--
+	if m.InsecureSkipVerify {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x40
+	if m.Auth != nil {
+		{
+			size, err := m.Auth.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x3a
+	}
+	if m.Timeout != nil {
+		{
+			size, err := m.Timeout.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x32
+	}
+	if len(m.HeaderMapping) > 0 {
+		keysForHeaderMapping := make([]string, 0, len(m.HeaderMapping))
+		for k := range m.HeaderMapping {
+			keysForHeaderMapping = append(keysForHeaderMapping, string(k))
+		}
+		github_com_gogo_protobuf_sortkeys.Strings(keysForHeaderMapping)
+		for iNdEx := len(keysForHeaderMapping) - 1; iNdEx >= 0; iNdEx-- {
+			v := m.HeaderMapping[string(keysForHeaderMapping[iNdEx])]
+			baseI := i
+			i -= len(v)
+			copy(dAtA[i:], v)
+			i = encodeVarintGenerated(dAtA, i, uint64(len(v)))
+			i--
+			dAtA[i] = 0x12
+			i -= len(keysForHeaderMapping[iNdEx])
+			copy(dAtA[i:], keysForHeaderMapping[iNdEx])
+			i = encodeVarintGenerated(dAtA, i, uint64(len(keysForHeaderMapping[iNdEx])))
+			i--
+			dAtA[i] = 0xa
+			i = encodeVarintGenerated(dAtA, i, uint64(baseI-i))
+			i--
+			dAtA[i] = 0x2a
+		}
+	}
+	if len(m.Headers) > 0 {
+		keysForHeaders := make([]string, 0, len(m.Headers))
+		for k := range m.Headers {
+			keysForHeaders = append(keysForHeaders, string(k))
+		}
+		github_com_gogo_protobuf_sortkeys.Strings(keysForHeaders)
+		for iNdEx := len(keysForHeaders) - 1; iNdEx >= 0; iNdEx-- {
+			v := m.Headers[string(keysForHeaders[iNdEx])]
+			baseI := i
+			i -= len(v)
+			copy(dAtA[i:], v)
+			i = encodeVarintGenerated(dAtA, i, uint64(len(v)))
+			i--
+			dAtA[i] = 0x12
+			i -= len(keysForHeaders[iNdEx])
+			copy(dAtA[i:], keysForHeaders[iNdEx])
+			i = encodeVarintGenerated(dAtA, i, uint64(len(keysForHeaders[iNdEx])))
+			i--
+			dAtA[i] = 0xa
+			i = encodeVarintGenerated(dAtA, i, uint64(baseI-i))
+			i--
+			dAtA[i] = 0x22
+		}
+	}
+	i -= len(m.Format)
+	copy(dAtA[i:], m.Format)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Format)))
+	i--
+	dAtA[i] = 0x1a
+	i -= len(m.Method)
+	copy(dAtA[i:], m.Method)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Method)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *HTTPSinkAuth) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *HTTPSinkAuth) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *HTTPSinkAuth) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Bearer != nil {
+		{
+			size, err := m.Bearer.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.Basic != nil {
+		{
+			size, err := m.Basic.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *HTTPSource) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -6394,6 +7876,46 @@ func (m *HTTPSource) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *ISBBatching) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *ISBBatching) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *ISBBatching) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Linger != nil {
+		{
+			size, err := m.Linger.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.MaxMessages != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MaxMessages))
+		i--
+		dAtA[i] = 0x8
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *IdleSource) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -6485,6 +8007,18 @@ func (m *InterStepBuffer) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Batching != nil {
+		{
+			size, err := m.Batching.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
 	if m.Compression != nil {
 		{
 			size, err := m.Compression.MarshalToSizedBuffer(dAtA[:i])
@@ -6620,6 +8154,18 @@ func (m *InterStepBufferServiceSpec) MarshalToSizedBuffer(dAtA []byte) (int, err
 	_ = i
 	var l int
 	_ = l
+	if m.Redis != nil {
+		{
+			size, err := m.Redis.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
 	if m.JetStream != nil {
 		{
 			size, err := m.JetStream.MarshalToSizedBuffer(dAtA[:i])
@@ -6879,6 +8425,75 @@ func (m *JetStreamConfig) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *JetStreamSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *JetStreamSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *JetStreamSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Auth != nil {
+		{
+			size, err := m.Auth.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x2a
+	}
+	if m.TLS != nil {
+		{
+			size, err := m.TLS.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
+	if m.AckTimeout != nil {
+		{
+			size, err := m.AckTimeout.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
+	i -= len(m.Subject)
+	copy(dAtA[i:], m.Subject)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Subject)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *JetStreamSource) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -7030,6 +8645,14 @@ func (m *KafkaSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	i--
+	if m.Transactional {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x38
 	if m.SASL != nil {
 		{
 			size, err := m.SASL.MarshalToSizedBuffer(dAtA[:i])
@@ -7160,6 +8783,34 @@ func (m *KafkaSource) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *LateData) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *LateData) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *LateData) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Tag)
+	copy(dAtA[i:], m.Tag)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Tag)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *Lifecycle) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -7203,6 +8854,34 @@ func (m *Lifecycle) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *LocalObjectStore) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *LocalObjectStore) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *LocalObjectStore) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Path)
+	copy(dAtA[i:], m.Path)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Path)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *Log) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -7890,6 +9569,63 @@ func (m *NatsAuth) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *NatsSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *NatsSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *NatsSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Auth != nil {
+		{
+			size, err := m.Auth.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
+	if m.TLS != nil {
+		{
+			size, err := m.TLS.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
+	i -= len(m.Subject)
+	copy(dAtA[i:], m.Subject)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Subject)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *NatsSource) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -7975,6 +9711,157 @@ func (m *NoStore) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *ObjectStoreColumn) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *ObjectStoreColumn) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *ObjectStoreColumn) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Path)
+	copy(dAtA[i:], m.Path)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Path)))
+	i--
+	dAtA[i] = 0x1a
+	i -= len(m.Type)
+	copy(dAtA[i:], m.Type)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Type)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Name)
+	copy(dAtA[i:], m.Name)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Name)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *ObjectStoreSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *ObjectStoreSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *ObjectStoreSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.MaxFileAge != nil {
+		{
+			size, err := m.MaxFileAge.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x52
+	}
+	if m.MaxFileMessages != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MaxFileMessages))
+		i--
+		dAtA[i] = 0x48
+	}
+	if m.MaxFileSize != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MaxFileSize))
+		i--
+		dAtA[i] = 0x40
+	}
+	i--
+	if m.PartitionByKeys {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x38
+	if m.PartitionWindow != nil {
+		{
+			size, err := m.PartitionWindow.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x32
+	}
+	if len(m.Columns) > 0 {
+		for iNdEx := len(m.Columns) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Columns[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintGenerated(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0x2a
+		}
+	}
+	i -= len(m.Format)
+	copy(dAtA[i:], m.Format)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Format)))
+	i--
+	dAtA[i] = 0x22
+	i -= len(m.Prefix)
+	copy(dAtA[i:], m.Prefix)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Prefix)))
+	i--
+	dAtA[i] = 0x1a
+	if m.Local != nil {
+		{
+			size, err := m.Local.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.S3 != nil {
+		{
+			size, err := m.S3.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *Ordered) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -8065,6 +9952,46 @@ func (m *PBQStorage) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *PercentileWatermark) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *PercentileWatermark) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *PercentileWatermark) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Window != nil {
+		{
+			size, err := m.Window.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.Percentile != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Percentile))
+		i--
+		dAtA[i] = 0x8
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *PersistenceStrategy) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -8541,6 +10468,134 @@ func (m *Ports) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *PostgresColumn) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *PostgresColumn) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *PostgresColumn) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Key != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Key))
+		i--
+		dAtA[i] = 0x18
+	}
+	i -= len(m.Path)
+	copy(dAtA[i:], m.Path)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Path)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Name)
+	copy(dAtA[i:], m.Name)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Name)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *PostgresSink) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *PostgresSink) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *PostgresSink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.ConnectTimeout != nil {
+		{
+			size, err := m.ConnectTimeout.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x42
+	}
+	i -= len(m.IDColumn)
+	copy(dAtA[i:], m.IDColumn)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.IDColumn)))
+	i--
+	dAtA[i] = 0x3a
+	i -= len(m.OnConflict)
+	copy(dAtA[i:], m.OnConflict)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.OnConflict)))
+	i--
+	dAtA[i] = 0x32
+	if len(m.ConflictColumns) > 0 {
+		for iNdEx := len(m.ConflictColumns) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.ConflictColumns[iNdEx])
+			copy(dAtA[i:], m.ConflictColumns[iNdEx])
+			i = encodeVarintGenerated(dAtA, i, uint64(len(m.ConflictColumns[iNdEx])))
+			i--
+			dAtA[i] = 0x2a
+		}
+	}
+	if len(m.Columns) > 0 {
+		for iNdEx := len(m.Columns) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Columns[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintGenerated(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0x22
+		}
+	}
+	i -= len(m.Table)
+	copy(dAtA[i:], m.Table)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Table)))
+	i--
+	dAtA[i] = 0x1a
+	if m.PasswordSecret != nil {
+		{
+			size, err := m.PasswordSecret.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *Probe) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -8791,6 +10846,34 @@ func (m *PulsarSource) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *PunctuationWatermark) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *PunctuationWatermark) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *PunctuationWatermark) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Header)
+	copy(dAtA[i:], m.Header)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Header)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *RateLimit) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -8811,6 +10894,18 @@ func (m *RateLimit) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.PerKey != nil {
+		{
+			size, err := m.PerKey.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x42
+	}
 	if m.TTL != nil {
 		{
 			size, err := m.TTL.MarshalToSizedBuffer(dAtA[:i])
@@ -8882,6 +10977,103 @@ func (m *RateLimit) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *RateLimitKeyOverride) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *RateLimitKeyOverride) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *RateLimitKeyOverride) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.RampUpDuration != nil {
+		{
+			size, err := m.RampUpDuration.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
+	if m.Min != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Min))
+		i--
+		dAtA[i] = 0x18
+	}
+	if m.Max != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Max))
+		i--
+		dAtA[i] = 0x10
+	}
+	i -= len(m.Key)
+	copy(dAtA[i:], m.Key)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Key)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *RateLimitPerKey) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *RateLimitPerKey) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *RateLimitPerKey) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if len(m.Overrides) > 0 {
+		for iNdEx := len(m.Overrides) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Overrides[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintGenerated(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0x1a
+		}
+	}
+	if m.MaxKeys != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.MaxKeys))
+		i--
+		dAtA[i] = 0x10
+	}
+	i -= len(m.Header)
+	copy(dAtA[i:], m.Header)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Header)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *RateLimiterGoBackN) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -8950,6 +11142,34 @@ func (m *RateLimiterInMemoryStore) MarshalToSizedBuffer(dAtA []byte) (int, error
 	return len(dAtA) - i, nil
 }
 
+func (m *RateLimiterJetStreamStore) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *RateLimiterJetStreamStore) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *RateLimiterJetStreamStore) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i -= len(m.Bucket)
+	copy(dAtA[i:], m.Bucket)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Bucket)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *RateLimiterModes) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -9167,6 +11387,18 @@ func (m *RateLimiterStore) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.RateLimiterJetStreamStore != nil {
+		{
+			size, err := m.RateLimiterJetStreamStore.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
 	if m.RateLimiterInMemoryStore != nil {
 		{
 			size, err := m.RateLimiterInMemoryStore.MarshalToSizedBuffer(dAtA[:i])
@@ -9241,6 +11473,86 @@ func (m *RedisAuth) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *RedisBufferService) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *RedisBufferService) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *RedisBufferService) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Password != nil {
+		{
+			size, err := m.Password.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
+func (m *RedisConfig) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *RedisConfig) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *RedisConfig) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Password != nil {
+		{
+			size, err := m.Password.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	i -= len(m.URL)
+	copy(dAtA[i:], m.URL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.URL)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *RedisSentinelConfig) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -9346,6 +11658,18 @@ func (m *RetryStrategy) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.CircuitBreaker != nil {
+		{
+			size, err := m.CircuitBreaker.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
 	if m.OnFailure != nil {
 		i -= len(*m.OnFailure)
 		copy(dAtA[i:], *m.OnFailure)
@@ -9403,6 +11727,64 @@ func (m *RollingUpdateStrategy) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *S3ObjectStore) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *S3ObjectStore) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *S3ObjectStore) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.AssumeRole != nil {
+		{
+			size, err := m.AssumeRole.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x2a
+	}
+	i--
+	if m.ForcePathStyle {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x20
+	i -= len(m.EndpointURL)
+	copy(dAtA[i:], m.EndpointURL)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.EndpointURL)))
+	i--
+	dAtA[i] = 0x1a
+	i -= len(m.AWSRegion)
+	copy(dAtA[i:], m.AWSRegion)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.AWSRegion)))
+	i--
+	dAtA[i] = 0x12
+	i -= len(m.Bucket)
+	copy(dAtA[i:], m.Bucket)
+	i = encodeVarintGenerated(dAtA, i, uint64(len(m.Bucket)))
+	i--
+	dAtA[i] = 0xa
+	return len(dAtA) - i, nil
+}
+
 func (m *SASL) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -10285,6 +12667,26 @@ func (m *Sink) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Broadcast != nil {
+		{
+			size, err := m.Broadcast.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x32
+	}
+	i--
+	if m.DLQHeaders {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x28
 	{
 		size, err := m.RetryStrategy.MarshalToSizedBuffer(dAtA[:i])
 		if err != nil {
@@ -10407,6 +12809,18 @@ func (m *Source) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.File != nil {
+		{
+			size, err := m.File.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x5a
+	}
 	if m.Sqs != nil {
 		{
 			size, err := m.Sqs.MarshalToSizedBuffer(dAtA[:i])
@@ -11585,6 +13999,18 @@ func (m *Watermark) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.Strategy != nil {
+		{
+			size, err := m.Strategy.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
 	if m.IdleSource != nil {
 		{
 			size, err := m.IdleSource.MarshalToSizedBuffer(dAtA[:i])
@@ -11620,6 +14046,65 @@ func (m *Watermark) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *WatermarkStrategy) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *WatermarkStrategy) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *WatermarkStrategy) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.Punctuation != nil {
+		{
+			size, err := m.Punctuation.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
+	if m.Adaptive != nil {
+		{
+			size, err := m.Adaptive.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.Percentile != nil {
+		{
+			size, err := m.Percentile.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *Window) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -11691,6 +14176,54 @@ func (m *Window) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *WindowTrigger) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *WindowTrigger) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *WindowTrigger) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	i--
+	if m.LateFirings {
+		dAtA[i] = 1
+	} else {
+		dAtA[i] = 0
+	}
+	i--
+	dAtA[i] = 0x18
+	if m.Interval != nil {
+		{
+			size, err := m.Interval.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintGenerated(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.Count != nil {
+		i = encodeVarintGenerated(dAtA, i, uint64(*m.Count))
+		i--
+		dAtA[i] = 0x8
+	}
+	return len(dAtA) - i, nil
+}
+
 func encodeVarintGenerated(dAtA []byte, offset int, v uint64) int {
 	offset -= sovGenerated(v)
 	base := offset
@@ -11835,6 +14368,30 @@ func (m *AbstractSink) Size() (n int) {
 		l = m.Pulsar.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Nats != nil {
+		l = m.Nats.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.JetStream != nil {
+		l = m.JetStream.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.HTTP != nil {
+		l = m.HTTP.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.File != nil {
+		l = m.File.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.ObjectStore != nil {
+		l = m.ObjectStore.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Postgres != nil {
+		l = m.Postgres.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -11927,6 +14484,23 @@ func (m *AccumulatorWindow) Size() (n int) {
 	return n
 }
 
+func (m *AdaptiveWatermark) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.HalfLife != nil {
+		l = m.HalfLife.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.MaxDelay != nil {
+		l = m.MaxDelay.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *Authorization) Size() (n int) {
 	if m == nil {
 		return 0
@@ -11992,6 +14566,23 @@ func (m *Blackhole) Size() (n int) {
 	return n
 }
 
+func (m *BroadcastSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if len(m.Sinks) > 0 {
+		for _, e := range m.Sinks {
+			l = e.Size()
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
+	l = len(m.Ack)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *BufferServiceConfig) Size() (n int) {
 	if m == nil {
 		return 0
@@ -12002,6 +14593,59 @@ func (m *BufferServiceConfig) Size() (n int) {
 		l = m.JetStream.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Redis != nil {
+		l = m.Redis.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *BuiltinReducer) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Aggregation)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Field)
+	n += 1 + l + sovGenerated(uint64(l))
+	if len(m.Quantiles) > 0 {
+		for _, s := range m.Quantiles {
+			l = len(s)
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
+	if m.Precision != nil {
+		n += 1 + sovGenerated(uint64(*m.Precision))
+	}
+	return n
+}
+
+func (m *CircuitBreaker) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.FailureRatio != nil {
+		n += 9
+	}
+	if m.MinWrites != nil {
+		n += 1 + sovGenerated(uint64(*m.MinWrites))
+	}
+	if m.Window != nil {
+		l = m.Window.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.OpenDuration != nil {
+		l = m.OpenDuration.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.OnOpen != nil {
+		l = len(*m.OnOpen)
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -12046,6 +14690,11 @@ func (m *Compression) Size() (n int) {
 	_ = l
 	l = len(m.Type)
 	n += 1 + l + sovGenerated(uint64(l))
+	if m.MinSize != nil {
+		n += 1 + sovGenerated(uint64(*m.MinSize))
+	}
+	l = len(m.DictionaryBucket)
+	n += 1 + l + sovGenerated(uint64(l))
 	return n
 }
 
@@ -12194,6 +14843,41 @@ func (m *Edge) Size() (n int) {
 	return n
 }
 
+func (m *FileSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Path)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Format)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.MaxSegmentSize != nil {
+		n += 1 + sovGenerated(uint64(*m.MaxSegmentSize))
+	}
+	if m.MaxSegmentAge != nil {
+		l = m.MaxSegmentAge.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *FileSource) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Path)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Format)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.CheckpointPath)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *FixedWindow) Size() (n int) {
 	if m == nil {
 		return 0
@@ -12504,6 +15188,75 @@ func (m *GroupBy) Size() (n int) {
 		l = m.Storage.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Builtin != nil {
+		l = m.Builtin.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.LateData != nil {
+		l = m.LateData.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Trigger != nil {
+		l = m.Trigger.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *HTTPSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Method)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Format)
+	n += 1 + l + sovGenerated(uint64(l))
+	if len(m.Headers) > 0 {
+		for k, v := range m.Headers {
+			_ = k
+			_ = v
+			mapEntrySize := 1 + len(k) + sovGenerated(uint64(len(k))) + 1 + len(v) + sovGenerated(uint64(len(v)))
+			n += mapEntrySize + 1 + sovGenerated(uint64(mapEntrySize))
+		}
+	}
+	if len(m.HeaderMapping) > 0 {
+		for k, v := range m.HeaderMapping {
+			_ = k
+			_ = v
+			mapEntrySize := 1 + len(k) + sovGenerated(uint64(len(k))) + 1 + len(v) + sovGenerated(uint64(len(v)))
+			n += mapEntrySize + 1 + sovGenerated(uint64(mapEntrySize))
+		}
+	}
+	if m.Timeout != nil {
+		l = m.Timeout.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Auth != nil {
+		l = m.Auth.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	n += 2
+	return n
+}
+
+func (m *HTTPSinkAuth) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Basic != nil {
+		l = m.Basic.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Bearer != nil {
+		l = m.Bearer.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -12521,6 +15274,22 @@ func (m *HTTPSource) Size() (n int) {
 	return n
 }
 
+func (m *ISBBatching) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.MaxMessages != nil {
+		n += 1 + sovGenerated(uint64(*m.MaxMessages))
+	}
+	if m.Linger != nil {
+		l = m.Linger.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *IdleSource) Size() (n int) {
 	if m == nil {
 		return 0
@@ -12556,6 +15325,10 @@ func (m *InterStepBuffer) Size() (n int) {
 		l = m.Compression.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Batching != nil {
+		l = m.Batching.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -12601,6 +15374,10 @@ func (m *InterStepBufferServiceSpec) Size() (n int) {
 		l = m.JetStream.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Redis != nil {
+		l = m.Redis.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -12690,6 +15467,31 @@ func (m *JetStreamConfig) Size() (n int) {
 	return n
 }
 
+func (m *JetStreamSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Subject)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.AckTimeout != nil {
+		l = m.AckTimeout.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.TLS != nil {
+		l = m.TLS.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Auth != nil {
+		l = m.Auth.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *JetStreamSource) Size() (n int) {
 	if m == nil {
 		return 0
@@ -12767,6 +15569,7 @@ func (m *KafkaSink) Size() (n int) {
 		l = m.SASL.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	n += 2
 	return n
 }
 
@@ -12801,6 +15604,17 @@ func (m *KafkaSource) Size() (n int) {
 	return n
 }
 
+func (m *LateData) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Tag)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *Lifecycle) Size() (n int) {
 	if m == nil {
 		return 0
@@ -12821,6 +15635,17 @@ func (m *Lifecycle) Size() (n int) {
 	return n
 }
 
+func (m *LocalObjectStore) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Path)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *Log) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13059,6 +15884,27 @@ func (m *NatsAuth) Size() (n int) {
 	return n
 }
 
+func (m *NatsSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Subject)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.TLS != nil {
+		l = m.TLS.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Auth != nil {
+		l = m.Auth.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *NatsSource) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13091,6 +15937,63 @@ func (m *NoStore) Size() (n int) {
 	return n
 }
 
+func (m *ObjectStoreColumn) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Name)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Type)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Path)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
+func (m *ObjectStoreSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.S3 != nil {
+		l = m.S3.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Local != nil {
+		l = m.Local.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	l = len(m.Prefix)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Format)
+	n += 1 + l + sovGenerated(uint64(l))
+	if len(m.Columns) > 0 {
+		for _, e := range m.Columns {
+			l = e.Size()
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
+	if m.PartitionWindow != nil {
+		l = m.PartitionWindow.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	n += 2
+	if m.MaxFileSize != nil {
+		n += 1 + sovGenerated(uint64(*m.MaxFileSize))
+	}
+	if m.MaxFileMessages != nil {
+		n += 1 + sovGenerated(uint64(*m.MaxFileMessages))
+	}
+	if m.MaxFileAge != nil {
+		l = m.MaxFileAge.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *Ordered) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13122,6 +16025,22 @@ func (m *PBQStorage) Size() (n int) {
 	return n
 }
 
+func (m *PercentileWatermark) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Percentile != nil {
+		n += 1 + sovGenerated(uint64(*m.Percentile))
+	}
+	if m.Window != nil {
+		l = m.Window.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *PersistenceStrategy) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13302,6 +16221,59 @@ func (m *Ports) Size() (n int) {
 	return n
 }
 
+func (m *PostgresColumn) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Name)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.Path)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.Key != nil {
+		n += 1 + sovGenerated(uint64(*m.Key))
+	}
+	return n
+}
+
+func (m *PostgresSink) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.PasswordSecret != nil {
+		l = m.PasswordSecret.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	l = len(m.Table)
+	n += 1 + l + sovGenerated(uint64(l))
+	if len(m.Columns) > 0 {
+		for _, e := range m.Columns {
+			l = e.Size()
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
+	if len(m.ConflictColumns) > 0 {
+		for _, s := range m.ConflictColumns {
+			l = len(s)
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
+	l = len(m.OnConflict)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.IDColumn)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.ConnectTimeout != nil {
+		l = m.ConnectTimeout.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *Probe) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13401,6 +16373,17 @@ func (m *PulsarSource) Size() (n int) {
 	return n
 }
 
+func (m *PunctuationWatermark) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Header)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *RateLimit) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13432,6 +16415,51 @@ func (m *RateLimit) Size() (n int) {
 		l = m.TTL.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.PerKey != nil {
+		l = m.PerKey.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *RateLimitKeyOverride) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Key)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.Max != nil {
+		n += 1 + sovGenerated(uint64(*m.Max))
+	}
+	if m.Min != nil {
+		n += 1 + sovGenerated(uint64(*m.Min))
+	}
+	if m.RampUpDuration != nil {
+		l = m.RampUpDuration.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *RateLimitPerKey) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Header)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.MaxKeys != nil {
+		n += 1 + sovGenerated(uint64(*m.MaxKeys))
+	}
+	if len(m.Overrides) > 0 {
+		for _, e := range m.Overrides {
+			l = e.Size()
+			n += 1 + l + sovGenerated(uint64(l))
+		}
+	}
 	return n
 }
 
@@ -13463,6 +16491,17 @@ func (m *RateLimiterInMemoryStore) Size() (n int) {
 	return n
 }
 
+func (m *RateLimiterJetStreamStore) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Bucket)
+	n += 1 + l + sovGenerated(uint64(l))
+	return n
+}
+
 func (m *RateLimiterModes) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13554,6 +16593,10 @@ func (m *RateLimiterStore) Size() (n int) {
 		l = m.RateLimiterInMemoryStore.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.RateLimiterJetStreamStore != nil {
+		l = m.RateLimiterJetStreamStore.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -13574,6 +16617,36 @@ func (m *RedisAuth) Size() (n int) {
 	return n
 }
 
+func (m *RedisBufferService) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.Password != nil {
+		l = m.Password.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *RedisConfig) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.URL)
+	n += 1 + l + sovGenerated(uint64(l))
+	if m.Password != nil {
+		l = m.Password.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *RedisSentinelConfig) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13621,6 +16694,10 @@ func (m *RetryStrategy) Size() (n int) {
 		l = len(*m.OnFailure)
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.CircuitBreaker != nil {
+		l = m.CircuitBreaker.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -13637,6 +16714,26 @@ func (m *RollingUpdateStrategy) Size() (n int) {
 	return n
 }
 
+func (m *S3ObjectStore) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	l = len(m.Bucket)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.AWSRegion)
+	n += 1 + l + sovGenerated(uint64(l))
+	l = len(m.EndpointURL)
+	n += 1 + l + sovGenerated(uint64(l))
+	n += 2
+	if m.AssumeRole != nil {
+		l = m.AssumeRole.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
 func (m *SASL) Size() (n int) {
 	if m == nil {
 		return 0
@@ -13972,6 +17069,11 @@ func (m *Sink) Size() (n int) {
 	}
 	l = m.RetryStrategy.Size()
 	n += 1 + l + sovGenerated(uint64(l))
+	n += 2
+	if m.Broadcast != nil {
+		l = m.Broadcast.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -14039,6 +17141,10 @@ func (m *Source) Size() (n int) {
 		l = m.Sqs.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.File != nil {
+		l = m.File.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -14445,6 +17551,31 @@ func (m *Watermark) Size() (n int) {
 		l = m.IdleSource.Size()
 		n += 1 + l + sovGenerated(uint64(l))
 	}
+	if m.Strategy != nil {
+		l = m.Strategy.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	return n
+}
+
+func (m *WatermarkStrategy) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Percentile != nil {
+		l = m.Percentile.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Adaptive != nil {
+		l = m.Adaptive.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	if m.Punctuation != nil {
+		l = m.Punctuation.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
 	return n
 }
 
@@ -14473,6 +17604,23 @@ func (m *Window) Size() (n int) {
 	return n
 }
 
+func (m *WindowTrigger) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Count != nil {
+		n += 1 + sovGenerated(uint64(*m.Count))
+	}
+	if m.Interval != nil {
+		l = m.Interval.Size()
+		n += 1 + l + sovGenerated(uint64(l))
+	}
+	n += 2
+	return n
+}
+
 func sovGenerated(x uint64) (n int) {
 	return (math_bits.Len64(x|1) + 6) / 7
 }
@@ -14554,6 +17702,12 @@ func (this *AbstractSink) String() string {
 		`Serve:` + strings.Replace(this.Serve.String(), "ServeSink", "ServeSink", 1) + `,`,
 		`Sqs:` + strings.Replace(this.Sqs.String(), "SqsSink", "SqsSink", 1) + `,`,
 		`Pulsar:` + strings.Replace(this.Pulsar.String(), "PulsarSink", "PulsarSink", 1) + `,`,
+		`Nats:` + strings.Replace(this.Nats.String(), "NatsSink", "NatsSink", 1) + `,`,
+		`JetStream:` + strings.Replace(this.JetStream.String(), "JetStreamSink", "JetStreamSink", 1) + `,`,
+		`HTTP:` + strings.Replace(this.HTTP.String(), "HTTPSink", "HTTPSink", 1) + `,`,
+		`File:` + strings.Replace(this.File.String(), "FileSink", "FileSink", 1) + `,`,
+		`ObjectStore:` + strings.Replace(this.ObjectStore.String(), "ObjectStoreSink", "ObjectStoreSink", 1) + `,`,
+		`Postgres:` + strings.Replace(this.Postgres.String(), "PostgresSink", "PostgresSink", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -14609,6 +17763,17 @@ func (this *AccumulatorWindow) String() string {
 	}, "")
 	return s
 }
+func (this *AdaptiveWatermark) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&AdaptiveWatermark{`,
+		`HalfLife:` + strings.Replace(fmt.Sprintf("%v", this.HalfLife), "Duration", "v11.Duration", 1) + `,`,
+		`MaxDelay:` + strings.Replace(fmt.Sprintf("%v", this.MaxDelay), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *Authorization) String() string {
 	if this == nil {
 		return "nil"
@@ -14653,12 +17818,56 @@ func (this *Blackhole) String() string {
 	}, "")
 	return s
 }
+func (this *BroadcastSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	repeatedStringForSinks := "[]AbstractSink{"
+	for _, f := range this.Sinks {
+		repeatedStringForSinks += strings.Replace(strings.Replace(f.String(), "AbstractSink", "AbstractSink", 1), `&`, ``, 1) + ","
+	}
+	repeatedStringForSinks += "}"
+	s := strings.Join([]string{`&BroadcastSink{`,
+		`Sinks:` + repeatedStringForSinks + `,`,
+		`Ack:` + fmt.Sprintf("%v", this.Ack) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *BufferServiceConfig) String() string {
 	if this == nil {
 		return "nil"
 	}
 	s := strings.Join([]string{`&BufferServiceConfig{`,
 		`JetStream:` + strings.Replace(this.JetStream.String(), "JetStreamConfig", "JetStreamConfig", 1) + `,`,
+		`Redis:` + strings.Replace(this.Redis.String(), "RedisConfig", "RedisConfig", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *BuiltinReducer) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&BuiltinReducer{`,
+		`Aggregation:` + fmt.Sprintf("%v", this.Aggregation) + `,`,
+		`Field:` + fmt.Sprintf("%v", this.Field) + `,`,
+		`Quantiles:` + fmt.Sprintf("%v", this.Quantiles) + `,`,
+		`Precision:` + valueToStringGenerated(this.Precision) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *CircuitBreaker) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&CircuitBreaker{`,
+		`FailureRatio:` + valueToStringGenerated(this.FailureRatio) + `,`,
+		`MinWrites:` + valueToStringGenerated(this.MinWrites) + `,`,
+		`Window:` + strings.Replace(fmt.Sprintf("%v", this.Window), "Duration", "v11.Duration", 1) + `,`,
+		`OpenDuration:` + strings.Replace(fmt.Sprintf("%v", this.OpenDuration), "Duration", "v11.Duration", 1) + `,`,
+		`OnOpen:` + valueToStringGenerated(this.OnOpen) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -14686,6 +17895,8 @@ func (this *Compression) String() string {
 	}
 	s := strings.Join([]string{`&Compression{`,
 		`Type:` + fmt.Sprintf("%v", this.Type) + `,`,
+		`MinSize:` + valueToStringGenerated(this.MinSize) + `,`,
+		`DictionaryBucket:` + fmt.Sprintf("%v", this.DictionaryBucket) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -14783,6 +17994,31 @@ func (this *Edge) String() string {
 	}, "")
 	return s
 }
+func (this *FileSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&FileSink{`,
+		`Path:` + fmt.Sprintf("%v", this.Path) + `,`,
+		`Format:` + fmt.Sprintf("%v", this.Format) + `,`,
+		`MaxSegmentSize:` + valueToStringGenerated(this.MaxSegmentSize) + `,`,
+		`MaxSegmentAge:` + strings.Replace(fmt.Sprintf("%v", this.MaxSegmentAge), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *FileSource) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&FileSource{`,
+		`Path:` + fmt.Sprintf("%v", this.Path) + `,`,
+		`Format:` + fmt.Sprintf("%v", this.Format) + `,`,
+		`CheckpointPath:` + fmt.Sprintf("%v", this.CheckpointPath) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *FixedWindow) String() string {
 	if this == nil {
 		return "nil"
@@ -15018,6 +18254,57 @@ func (this *GroupBy) String() string {
 		`Keyed:` + fmt.Sprintf("%v", this.Keyed) + `,`,
 		`AllowedLateness:` + strings.Replace(fmt.Sprintf("%v", this.AllowedLateness), "Duration", "v11.Duration", 1) + `,`,
 		`Storage:` + strings.Replace(this.Storage.String(), "PBQStorage", "PBQStorage", 1) + `,`,
+		`Builtin:` + strings.Replace(this.Builtin.String(), "BuiltinReducer", "BuiltinReducer", 1) + `,`,
+		`LateData:` + strings.Replace(this.LateData.String(), "LateData", "LateData", 1) + `,`,
+		`Trigger:` + strings.Replace(this.Trigger.String(), "WindowTrigger", "WindowTrigger", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *HTTPSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	keysForHeaders := make([]string, 0, len(this.Headers))
+	for k := range this.Headers {
+		keysForHeaders = append(keysForHeaders, k)
+	}
+	github_com_gogo_protobuf_sortkeys.Strings(keysForHeaders)
+	mapStringForHeaders := "map[string]string{"
+	for _, k := range keysForHeaders {
+		mapStringForHeaders += fmt.Sprintf("%v: %v,", k, this.Headers[k])
+	}
+	mapStringForHeaders += "}"
+	keysForHeaderMapping := make([]string, 0, len(this.HeaderMapping))
+	for k := range this.HeaderMapping {
+		keysForHeaderMapping = append(keysForHeaderMapping, k)
+	}
+	github_com_gogo_protobuf_sortkeys.Strings(keysForHeaderMapping)
+	mapStringForHeaderMapping := "map[string]string{"
+	for _, k := range keysForHeaderMapping {
+		mapStringForHeaderMapping += fmt.Sprintf("%v: %v,", k, this.HeaderMapping[k])
+	}
+	mapStringForHeaderMapping += "}"
+	s := strings.Join([]string{`&HTTPSink{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`Method:` + fmt.Sprintf("%v", this.Method) + `,`,
+		`Format:` + fmt.Sprintf("%v", this.Format) + `,`,
+		`Headers:` + mapStringForHeaders + `,`,
+		`HeaderMapping:` + mapStringForHeaderMapping + `,`,
+		`Timeout:` + strings.Replace(fmt.Sprintf("%v", this.Timeout), "Duration", "v11.Duration", 1) + `,`,
+		`Auth:` + strings.Replace(this.Auth.String(), "HTTPSinkAuth", "HTTPSinkAuth", 1) + `,`,
+		`InsecureSkipVerify:` + fmt.Sprintf("%v", this.InsecureSkipVerify) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *HTTPSinkAuth) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&HTTPSinkAuth{`,
+		`Basic:` + strings.Replace(this.Basic.String(), "BasicAuth", "BasicAuth", 1) + `,`,
+		`Bearer:` + strings.Replace(fmt.Sprintf("%v", this.Bearer), "SecretKeySelector", "v1.SecretKeySelector", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15033,6 +18320,17 @@ func (this *HTTPSource) String() string {
 	}, "")
 	return s
 }
+func (this *ISBBatching) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&ISBBatching{`,
+		`MaxMessages:` + valueToStringGenerated(this.MaxMessages) + `,`,
+		`Linger:` + strings.Replace(fmt.Sprintf("%v", this.Linger), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *IdleSource) String() string {
 	if this == nil {
 		return "nil"
@@ -15052,6 +18350,7 @@ func (this *InterStepBuffer) String() string {
 	}
 	s := strings.Join([]string{`&InterStepBuffer{`,
 		`Compression:` + strings.Replace(this.Compression.String(), "Compression", "Compression", 1) + `,`,
+		`Batching:` + strings.Replace(this.Batching.String(), "ISBBatching", "ISBBatching", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15090,6 +18389,7 @@ func (this *InterStepBufferServiceSpec) String() string {
 	}
 	s := strings.Join([]string{`&InterStepBufferServiceSpec{`,
 		`JetStream:` + strings.Replace(this.JetStream.String(), "JetStreamBufferService", "JetStreamBufferService", 1) + `,`,
+		`Redis:` + strings.Replace(this.Redis.String(), "RedisBufferService", "RedisBufferService", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15143,6 +18443,20 @@ func (this *JetStreamConfig) String() string {
 	}, "")
 	return s
 }
+func (this *JetStreamSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&JetStreamSink{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`Subject:` + fmt.Sprintf("%v", this.Subject) + `,`,
+		`AckTimeout:` + strings.Replace(fmt.Sprintf("%v", this.AckTimeout), "Duration", "v11.Duration", 1) + `,`,
+		`TLS:` + strings.Replace(this.TLS.String(), "TLS", "TLS", 1) + `,`,
+		`Auth:` + strings.Replace(this.Auth.String(), "NatsAuth", "NatsAuth", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *JetStreamSource) String() string {
 	if this == nil {
 		return "nil"
@@ -15183,6 +18497,7 @@ func (this *KafkaSink) String() string {
 		`TLS:` + strings.Replace(this.TLS.String(), "TLS", "TLS", 1) + `,`,
 		`Config:` + fmt.Sprintf("%v", this.Config) + `,`,
 		`SASL:` + strings.Replace(this.SASL.String(), "SASL", "SASL", 1) + `,`,
+		`Transactional:` + fmt.Sprintf("%v", this.Transactional) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15203,6 +18518,16 @@ func (this *KafkaSource) String() string {
 	}, "")
 	return s
 }
+func (this *LateData) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&LateData{`,
+		`Tag:` + fmt.Sprintf("%v", this.Tag) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *Lifecycle) String() string {
 	if this == nil {
 		return "nil"
@@ -15216,6 +18541,16 @@ func (this *Lifecycle) String() string {
 	}, "")
 	return s
 }
+func (this *LocalObjectStore) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&LocalObjectStore{`,
+		`Path:` + fmt.Sprintf("%v", this.Path) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *Log) String() string {
 	if this == nil {
 		return "nil"
@@ -15393,6 +18728,19 @@ func (this *NatsAuth) String() string {
 	}, "")
 	return s
 }
+func (this *NatsSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&NatsSink{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`Subject:` + fmt.Sprintf("%v", this.Subject) + `,`,
+		`TLS:` + strings.Replace(this.TLS.String(), "TLS", "TLS", 1) + `,`,
+		`Auth:` + strings.Replace(this.Auth.String(), "NatsAuth", "NatsAuth", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *NatsSource) String() string {
 	if this == nil {
 		return "nil"
@@ -15416,6 +18764,42 @@ func (this *NoStore) String() string {
 	}, "")
 	return s
 }
+func (this *ObjectStoreColumn) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&ObjectStoreColumn{`,
+		`Name:` + fmt.Sprintf("%v", this.Name) + `,`,
+		`Type:` + fmt.Sprintf("%v", this.Type) + `,`,
+		`Path:` + fmt.Sprintf("%v", this.Path) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *ObjectStoreSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	repeatedStringForColumns := "[]ObjectStoreColumn{"
+	for _, f := range this.Columns {
+		repeatedStringForColumns += strings.Replace(strings.Replace(f.String(), "ObjectStoreColumn", "ObjectStoreColumn", 1), `&`, ``, 1) + ","
+	}
+	repeatedStringForColumns += "}"
+	s := strings.Join([]string{`&ObjectStoreSink{`,
+		`S3:` + strings.Replace(this.S3.String(), "S3ObjectStore", "S3ObjectStore", 1) + `,`,
+		`Local:` + strings.Replace(this.Local.String(), "LocalObjectStore", "LocalObjectStore", 1) + `,`,
+		`Prefix:` + fmt.Sprintf("%v", this.Prefix) + `,`,
+		`Format:` + fmt.Sprintf("%v", this.Format) + `,`,
+		`Columns:` + repeatedStringForColumns + `,`,
+		`PartitionWindow:` + strings.Replace(fmt.Sprintf("%v", this.PartitionWindow), "Duration", "v11.Duration", 1) + `,`,
+		`PartitionByKeys:` + fmt.Sprintf("%v", this.PartitionByKeys) + `,`,
+		`MaxFileSize:` + valueToStringGenerated(this.MaxFileSize) + `,`,
+		`MaxFileMessages:` + valueToStringGenerated(this.MaxFileMessages) + `,`,
+		`MaxFileAge:` + strings.Replace(fmt.Sprintf("%v", this.MaxFileAge), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *Ordered) String() string {
 	if this == nil {
 		return "nil"
@@ -15438,6 +18822,17 @@ func (this *PBQStorage) String() string {
 	}, "")
 	return s
 }
+func (this *PercentileWatermark) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&PercentileWatermark{`,
+		`Percentile:` + valueToStringGenerated(this.Percentile) + `,`,
+		`Window:` + strings.Replace(fmt.Sprintf("%v", this.Window), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *PersistenceStrategy) String() string {
 	if this == nil {
 		return "nil"
@@ -15558,6 +18953,40 @@ func (this *Ports) String() string {
 	}, "")
 	return s
 }
+func (this *PostgresColumn) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&PostgresColumn{`,
+		`Name:` + fmt.Sprintf("%v", this.Name) + `,`,
+		`Path:` + fmt.Sprintf("%v", this.Path) + `,`,
+		`Key:` + valueToStringGenerated(this.Key) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *PostgresSink) String() string {
+	if this == nil {
+		return "nil"
+	}
+	repeatedStringForColumns := "[]PostgresColumn{"
+	for _, f := range this.Columns {
+		repeatedStringForColumns += strings.Replace(strings.Replace(f.String(), "PostgresColumn", "PostgresColumn", 1), `&`, ``, 1) + ","
+	}
+	repeatedStringForColumns += "}"
+	s := strings.Join([]string{`&PostgresSink{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`PasswordSecret:` + strings.Replace(fmt.Sprintf("%v", this.PasswordSecret), "SecretKeySelector", "v1.SecretKeySelector", 1) + `,`,
+		`Table:` + fmt.Sprintf("%v", this.Table) + `,`,
+		`Columns:` + repeatedStringForColumns + `,`,
+		`ConflictColumns:` + fmt.Sprintf("%v", this.ConflictColumns) + `,`,
+		`OnConflict:` + fmt.Sprintf("%v", this.OnConflict) + `,`,
+		`IDColumn:` + fmt.Sprintf("%v", this.IDColumn) + `,`,
+		`ConnectTimeout:` + strings.Replace(fmt.Sprintf("%v", this.ConnectTimeout), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *Probe) String() string {
 	if this == nil {
 		return "nil"
@@ -15622,6 +19051,16 @@ func (this *PulsarSource) String() string {
 	}, "")
 	return s
 }
+func (this *PunctuationWatermark) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&PunctuationWatermark{`,
+		`Header:` + fmt.Sprintf("%v", this.Header) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *RateLimit) String() string {
 	if this == nil {
 		return "nil"
@@ -15634,6 +19073,37 @@ func (this *RateLimit) String() string {
 		`RateLimiterModes:` + strings.Replace(this.RateLimiterModes.String(), "RateLimiterModes", "RateLimiterModes", 1) + `,`,
 		`ResumedRampUp:` + valueToStringGenerated(this.ResumedRampUp) + `,`,
 		`TTL:` + strings.Replace(fmt.Sprintf("%v", this.TTL), "Duration", "v11.Duration", 1) + `,`,
+		`PerKey:` + strings.Replace(this.PerKey.String(), "RateLimitPerKey", "RateLimitPerKey", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *RateLimitKeyOverride) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&RateLimitKeyOverride{`,
+		`Key:` + fmt.Sprintf("%v", this.Key) + `,`,
+		`Max:` + valueToStringGenerated(this.Max) + `,`,
+		`Min:` + valueToStringGenerated(this.Min) + `,`,
+		`RampUpDuration:` + strings.Replace(fmt.Sprintf("%v", this.RampUpDuration), "Duration", "v11.Duration", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *RateLimitPerKey) String() string {
+	if this == nil {
+		return "nil"
+	}
+	repeatedStringForOverrides := "[]RateLimitKeyOverride{"
+	for _, f := range this.Overrides {
+		repeatedStringForOverrides += strings.Replace(strings.Replace(f.String(), "RateLimitKeyOverride", "RateLimitKeyOverride", 1), `&`, ``, 1) + ","
+	}
+	repeatedStringForOverrides += "}"
+	s := strings.Join([]string{`&RateLimitPerKey{`,
+		`Header:` + fmt.Sprintf("%v", this.Header) + `,`,
+		`MaxKeys:` + valueToStringGenerated(this.MaxKeys) + `,`,
+		`Overrides:` + repeatedStringForOverrides + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15659,6 +19129,16 @@ func (this *RateLimiterInMemoryStore) String() string {
 	}, "")
 	return s
 }
+func (this *RateLimiterJetStreamStore) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&RateLimiterJetStreamStore{`,
+		`Bucket:` + fmt.Sprintf("%v", this.Bucket) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *RateLimiterModes) String() string {
 	if this == nil {
 		return "nil"
@@ -15720,6 +19200,7 @@ func (this *RateLimiterStore) String() string {
 	s := strings.Join([]string{`&RateLimiterStore{`,
 		`RateLimiterRedisStore:` + strings.Replace(this.RateLimiterRedisStore.String(), "RateLimiterRedisStore", "RateLimiterRedisStore", 1) + `,`,
 		`RateLimiterInMemoryStore:` + strings.Replace(this.RateLimiterInMemoryStore.String(), "RateLimiterInMemoryStore", "RateLimiterInMemoryStore", 1) + `,`,
+		`RateLimiterJetStreamStore:` + strings.Replace(this.RateLimiterJetStreamStore.String(), "RateLimiterJetStreamStore", "RateLimiterJetStreamStore", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15735,6 +19216,28 @@ func (this *RedisAuth) String() string {
 	}, "")
 	return s
 }
+func (this *RedisBufferService) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&RedisBufferService{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`Password:` + strings.Replace(fmt.Sprintf("%v", this.Password), "SecretKeySelector", "v1.SecretKeySelector", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *RedisConfig) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&RedisConfig{`,
+		`URL:` + fmt.Sprintf("%v", this.URL) + `,`,
+		`Password:` + strings.Replace(fmt.Sprintf("%v", this.Password), "SecretKeySelector", "v1.SecretKeySelector", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *RedisSentinelConfig) String() string {
 	if this == nil {
 		return "nil"
@@ -15757,6 +19260,7 @@ func (this *RetryStrategy) String() string {
 	s := strings.Join([]string{`&RetryStrategy{`,
 		`BackOff:` + strings.Replace(this.BackOff.String(), "Backoff", "Backoff", 1) + `,`,
 		`OnFailure:` + valueToStringGenerated(this.OnFailure) + `,`,
+		`CircuitBreaker:` + strings.Replace(this.CircuitBreaker.String(), "CircuitBreaker", "CircuitBreaker", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -15771,6 +19275,20 @@ func (this *RollingUpdateStrategy) String() string {
 	}, "")
 	return s
 }
+func (this *S3ObjectStore) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&S3ObjectStore{`,
+		`Bucket:` + fmt.Sprintf("%v", this.Bucket) + `,`,
+		`AWSRegion:` + fmt.Sprintf("%v", this.AWSRegion) + `,`,
+		`EndpointURL:` + fmt.Sprintf("%v", this.EndpointURL) + `,`,
+		`ForcePathStyle:` + fmt.Sprintf("%v", this.ForcePathStyle) + `,`,
+		`AssumeRole:` + strings.Replace(this.AssumeRole.String(), "AWSAssumeRole", "AWSAssumeRole", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *SASL) String() string {
 	if this == nil {
 		return "nil"
@@ -15990,6 +19508,8 @@ func (this *Sink) String() string {
 		`Fallback:` + strings.Replace(this.Fallback.String(), "AbstractSink", "AbstractSink", 1) + `,`,
 		`OnSuccess:` + strings.Replace(this.OnSuccess.String(), "AbstractSink", "AbstractSink", 1) + `,`,
 		`RetryStrategy:` + strings.Replace(strings.Replace(this.RetryStrategy.String(), "RetryStrategy", "RetryStrategy", 1), `&`, ``, 1) + `,`,
+		`DLQHeaders:` + fmt.Sprintf("%v", this.DLQHeaders) + `,`,
+		`Broadcast:` + strings.Replace(this.Broadcast.String(), "BroadcastSink", "BroadcastSink", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -16021,6 +19541,7 @@ func (this *Source) String() string {
 		`Serving:` + strings.Replace(this.Serving.String(), "ServingSource", "ServingSource", 1) + `,`,
 		`Pulsar:` + strings.Replace(this.Pulsar.String(), "PulsarSource", "PulsarSource", 1) + `,`,
 		`Sqs:` + strings.Replace(this.Sqs.String(), "SqsSource", "SqsSource", 1) + `,`,
+		`File:` + strings.Replace(this.File.String(), "FileSource", "FileSource", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -16296,6 +19817,19 @@ func (this *Watermark) String() string {
 		`Disabled:` + fmt.Sprintf("%v", this.Disabled) + `,`,
 		`MaxDelay:` + strings.Replace(fmt.Sprintf("%v", this.MaxDelay), "Duration", "v11.Duration", 1) + `,`,
 		`IdleSource:` + strings.Replace(this.IdleSource.String(), "IdleSource", "IdleSource", 1) + `,`,
+		`Strategy:` + strings.Replace(this.Strategy.String(), "WatermarkStrategy", "WatermarkStrategy", 1) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *WatermarkStrategy) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&WatermarkStrategy{`,
+		`Percentile:` + strings.Replace(this.Percentile.String(), "PercentileWatermark", "PercentileWatermark", 1) + `,`,
+		`Adaptive:` + strings.Replace(this.Adaptive.String(), "AdaptiveWatermark", "AdaptiveWatermark", 1) + `,`,
+		`Punctuation:` + strings.Replace(this.Punctuation.String(), "PunctuationWatermark", "PunctuationWatermark", 1) + `,`,
 		`}`,
 	}, "")
 	return s
@@ -16313,6 +19847,18 @@ func (this *Window) String() string {
 	}, "")
 	return s
 }
+func (this *WindowTrigger) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&WindowTrigger{`,
+		`Count:` + valueToStringGenerated(this.Count) + `,`,
+		`Interval:` + strings.Replace(fmt.Sprintf("%v", this.Interval), "Duration", "v11.Duration", 1) + `,`,
+		`LateFirings:` + fmt.Sprintf("%v", this.LateFirings) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func valueToStringGenerated(v interface{}) string {
 	rv := reflect.ValueOf(v)
 	if rv.IsNil() {
@@ -17428,61 +20974,47 @@ func (m *AbstractSink) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Nats", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *AbstractVertex) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if iNdEx >= l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			if m.Nats == nil {
+				m.Nats = &NatsSink{}
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: AbstractVertex: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: AbstractVertex: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			if err := m.Nats.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 9:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field JetStream", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -17492,27 +21024,31 @@ func (m *AbstractVertex) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Name = string(dAtA[iNdEx:postIndex])
+			if m.JetStream == nil {
+				m.JetStream = &JetStreamSink{}
+			}
+			if err := m.JetStream.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 2:
+		case 10:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Source", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field HTTP", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -17539,16 +21075,16 @@ func (m *AbstractVertex) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Source == nil {
-				m.Source = &Source{}
+			if m.HTTP == nil {
+				m.HTTP = &HTTPSink{}
 			}
-			if err := m.Source.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.HTTP.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 11:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field File", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -17575,16 +21111,242 @@ func (m *AbstractVertex) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Sink == nil {
-				m.Sink = &Sink{}
+			if m.File == nil {
+				m.File = &FileSink{}
 			}
-			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.File.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 4:
+		case 12:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UDF", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ObjectStore", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ObjectStore == nil {
+				m.ObjectStore = &ObjectStoreSink{}
+			}
+			if err := m.ObjectStore.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 13:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Postgres", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Postgres == nil {
+				m.Postgres = &PostgresSink{}
+			}
+			if err := m.Postgres.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *AbstractVertex) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: AbstractVertex: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: AbstractVertex: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Name = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Source", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Source == nil {
+				m.Source = &Source{}
+			}
+			if err := m.Source.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Sink == nil {
+				m.Sink = &Sink{}
+			}
+			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field UDF", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -18158,7 +21920,7 @@ func (m *AccumulatorWindow) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Authorization) Unmarshal(dAtA []byte) error {
+func (m *AdaptiveWatermark) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -18181,15 +21943,15 @@ func (m *Authorization) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Authorization: wiretype end group for non-group")
+			return fmt.Errorf("proto: AdaptiveWatermark: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Authorization: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: AdaptiveWatermark: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Token", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field HalfLife", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -18216,10 +21978,132 @@ func (m *Authorization) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Token == nil {
-				m.Token = &v1.SecretKeySelector{}
+			if m.HalfLife == nil {
+				m.HalfLife = &v11.Duration{}
 			}
-			if err := m.Token.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.HalfLife.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxDelay", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.MaxDelay == nil {
+				m.MaxDelay = &v11.Duration{}
+			}
+			if err := m.MaxDelay.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *Authorization) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Authorization: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Authorization: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Token", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Token == nil {
+				m.Token = &v1.SecretKeySelector{}
+			}
+			if err := m.Token.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -18582,7 +22466,7 @@ func (m *Blackhole) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *BufferServiceConfig) Unmarshal(dAtA []byte) error {
+func (m *BroadcastSink) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -18605,15 +22489,15 @@ func (m *BufferServiceConfig) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: BufferServiceConfig: wiretype end group for non-group")
+			return fmt.Errorf("proto: BroadcastSink: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: BufferServiceConfig: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: BroadcastSink: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
-		case 2:
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field JetStream", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Sinks", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -18640,13 +22524,43 @@ func (m *BufferServiceConfig) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.JetStream == nil {
-				m.JetStream = &JetStreamConfig{}
-			}
-			if err := m.JetStream.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Sinks = append(m.Sinks, AbstractSink{})
+			if err := m.Sinks[len(m.Sinks)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Ack", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Ack = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -18668,7 +22582,7 @@ func (m *BufferServiceConfig) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *CombinedEdge) Unmarshal(dAtA []byte) error {
+func (m *BufferServiceConfig) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -18691,100 +22605,15 @@ func (m *CombinedEdge) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: CombinedEdge: wiretype end group for non-group")
+			return fmt.Errorf("proto: BufferServiceConfig: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: CombinedEdge: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: BufferServiceConfig: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
-		case 1:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Edge", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if err := m.Edge.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexType", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.FromVertexType = VertexType(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexPartitionCount", wireType)
-			}
-			var v int32
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.FromVertexPartitionCount = &v
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexLimits", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field JetStream", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -18811,68 +22640,16 @@ func (m *CombinedEdge) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.FromVertexLimits == nil {
-				m.FromVertexLimits = &VertexLimits{}
+			if m.JetStream == nil {
+				m.JetStream = &JetStreamConfig{}
 			}
-			if err := m.FromVertexLimits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.JetStream.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 5:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexType", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.ToVertexType = VertexType(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 6:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexPartitionCount", wireType)
-			}
-			var v int32
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.ToVertexPartitionCount = &v
-		case 7:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexLimits", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Redis", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -18899,130 +22676,12 @@ func (m *CombinedEdge) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ToVertexLimits == nil {
-				m.ToVertexLimits = &VertexLimits{}
-			}
-			if err := m.ToVertexLimits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 8:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexOrdered", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			if m.Redis == nil {
+				m.Redis = &RedisConfig{}
 			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.ToVertexOrdered == nil {
-				m.ToVertexOrdered = &Ordered{}
-			}
-			if err := m.ToVertexOrdered.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
+			if err := m.Redis.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *Compression) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: Compression: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Compression: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Type", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Type = CompressionType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -19045,7 +22704,7 @@ func (m *Compression) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Container) Unmarshal(dAtA []byte) error {
+func (m *BuiltinReducer) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -19068,15 +22727,15 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Container: wiretype end group for non-group")
+			return fmt.Errorf("proto: BuiltinReducer: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Container: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: BuiltinReducer: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Aggregation", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -19104,11 +22763,11 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			m.Aggregation = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Command", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Field", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -19136,11 +22795,11 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Command = append(m.Command, string(dAtA[iNdEx:postIndex]))
+			m.Field = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Args", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Quantiles", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -19168,13 +22827,13 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Args = append(m.Args, string(dAtA[iNdEx:postIndex]))
+			m.Quantiles = append(m.Quantiles, string(dAtA[iNdEx:postIndex]))
 			iNdEx = postIndex
 		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Precision", wireType)
 			}
-			var msglen int
+			var v int32
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19184,65 +22843,79 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				v |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
+			m.Precision = &v
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if postIndex > l {
+			if (iNdEx + skippy) > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 5:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field EnvFrom", wireType)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *CircuitBreaker) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
 			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: CircuitBreaker: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: CircuitBreaker: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field FailureRatio", wireType)
 			}
-			if postIndex > l {
+			var v uint64
+			if (iNdEx + 8) > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.EnvFrom = append(m.EnvFrom, v1.EnvFromSource{})
-			if err := m.EnvFrom[len(m.EnvFrom)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 6:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field VolumeMounts", wireType)
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			v2 := float64(math.Float64frombits(v))
+			m.FailureRatio = &v2
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MinWrites", wireType)
 			}
-			var msglen int
+			var v uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19252,29 +22925,15 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				v |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.VolumeMounts = append(m.VolumeMounts, v1.VolumeMount{})
-			if err := m.VolumeMounts[len(m.VolumeMounts)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 7:
+			m.MinWrites = &v
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Resources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Window", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19301,13 +22960,16 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Resources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Window == nil {
+				m.Window = &v11.Duration{}
+			}
+			if err := m.Window.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 8:
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SecurityContext", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field OpenDuration", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19334,16 +22996,16 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.SecurityContext == nil {
-				m.SecurityContext = &v1.SecurityContext{}
+			if m.OpenDuration == nil {
+				m.OpenDuration = &v11.Duration{}
 			}
-			if err := m.SecurityContext.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.OpenDuration.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 9:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ImagePullPolicy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field OnOpen", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -19371,114 +23033,8 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
-			m.ImagePullPolicy = &s
-			iNdEx = postIndex
-		case 10:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReadinessProbe", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.ReadinessProbe == nil {
-				m.ReadinessProbe = &Probe{}
-			}
-			if err := m.ReadinessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 11:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field LivenessProbe", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.LivenessProbe == nil {
-				m.LivenessProbe = &Probe{}
-			}
-			if err := m.LivenessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 12:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Ports", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Ports = append(m.Ports, v1.ContainerPort{})
-			if err := m.Ports[len(m.Ports)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			s := OnOpenCircuitBreakerStrategy(dAtA[iNdEx:postIndex])
+			m.OnOpen = &s
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -19501,7 +23057,7 @@ func (m *Container) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
+func (m *CombinedEdge) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -19524,15 +23080,15 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: ContainerTemplate: wiretype end group for non-group")
+			return fmt.Errorf("proto: CombinedEdge: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: ContainerTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: CombinedEdge: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Resources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Edge", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19559,13 +23115,13 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Resources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Edge.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ImagePullPolicy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexType", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -19593,13 +23149,13 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ImagePullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			m.FromVertexType = VertexType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SecurityContext", wireType)
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexPartitionCount", wireType)
 			}
-			var msglen int
+			var v int32
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19609,31 +23165,15 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				v |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.SecurityContext == nil {
-				m.SecurityContext = &v1.SecurityContext{}
-			}
-			if err := m.SecurityContext.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
+			m.FromVertexPartitionCount = &v
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field FromVertexLimits", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19660,16 +23200,18 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.FromVertexLimits == nil {
+				m.FromVertexLimits = &VertexLimits{}
+			}
+			if err := m.FromVertexLimits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field EnvFrom", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexType", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19679,29 +23221,47 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.EnvFrom = append(m.EnvFrom, v1.EnvFromSource{})
-			if err := m.EnvFrom[len(m.EnvFrom)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ToVertexType = VertexType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexPartitionCount", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ToVertexPartitionCount = &v
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReadinessProbe", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexLimits", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19728,16 +23288,16 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ReadinessProbe == nil {
-				m.ReadinessProbe = &Probe{}
+			if m.ToVertexLimits == nil {
+				m.ToVertexLimits = &VertexLimits{}
 			}
-			if err := m.ReadinessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.ToVertexLimits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 7:
+		case 8:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field LivenessProbe", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ToVertexOrdered", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -19764,10 +23324,10 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.LivenessProbe == nil {
-				m.LivenessProbe = &Probe{}
+			if m.ToVertexOrdered == nil {
+				m.ToVertexOrdered = &Ordered{}
 			}
-			if err := m.LivenessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.ToVertexOrdered.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -19792,7 +23352,7 @@ func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
+func (m *Compression) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -19815,17 +23375,17 @@ func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: DaemonTemplate: wiretype end group for non-group")
+			return fmt.Errorf("proto: Compression: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: DaemonTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: Compression: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Type", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19835,30 +23395,29 @@ func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Type = CompressionType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field MinSize", wireType)
 			}
-			var v int32
+			var v int64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19868,17 +23427,17 @@ func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				v |= int64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.Replicas = &v
+			m.MinSize = &v
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DictionaryBucket", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -19888,63 +23447,23 @@ func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ContainerTemplate == nil {
-				m.ContainerTemplate = &ContainerTemplate{}
-			}
-			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field InitContainerTemplate", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.InitContainerTemplate == nil {
-				m.InitContainerTemplate = &ContainerTemplate{}
-			}
-			if err := m.InitContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.DictionaryBucket = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -19967,7 +23486,7 @@ func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Edge) Unmarshal(dAtA []byte) error {
+func (m *Container) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -19990,15 +23509,15 @@ func (m *Edge) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Edge: wiretype end group for non-group")
+			return fmt.Errorf("proto: Container: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Edge: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: Container: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field From", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -20026,11 +23545,11 @@ func (m *Edge) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.From = string(dAtA[iNdEx:postIndex])
+			m.Image = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field To", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Command", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -20058,11 +23577,43 @@ func (m *Edge) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.To = string(dAtA[iNdEx:postIndex])
+			m.Command = append(m.Command, string(dAtA[iNdEx:postIndex]))
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Conditions", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Args", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Args = append(m.Args, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20089,18 +23640,16 @@ func (m *Edge) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Conditions == nil {
-				m.Conditions = &ForwardConditions{}
-			}
-			if err := m.Conditions.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 4:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field OnFull", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field EnvFrom", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20110,78 +23659,96 @@ func (m *Edge) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := BufferFullWritingStrategy(dAtA[iNdEx:postIndex])
-			m.OnFull = &s
-			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
+			m.EnvFrom = append(m.EnvFrom, v1.EnvFromSource{})
+			if err := m.EnvFrom[len(m.EnvFrom)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field VolumeMounts", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if (iNdEx + skippy) > l {
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *FixedWindow) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			m.VolumeMounts = append(m.VolumeMounts, v1.VolumeMount{})
+			if err := m.VolumeMounts[len(m.VolumeMounts)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-			if iNdEx >= l {
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Resources", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			if err := m.Resources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: FixedWindow: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: FixedWindow: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			iNdEx = postIndex
+		case 8:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Length", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field SecurityContext", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20208,18 +23775,18 @@ func (m *FixedWindow) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Length == nil {
-				m.Length = &v11.Duration{}
+			if m.SecurityContext == nil {
+				m.SecurityContext = &v1.SecurityContext{}
 			}
-			if err := m.Length.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.SecurityContext.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Streaming", wireType)
+		case 9:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ImagePullPolicy", wireType)
 			}
-			var v int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20229,65 +23796,64 @@ func (m *FixedWindow) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.Streaming = bool(v != 0)
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if (iNdEx + skippy) > l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *ForwardConditions) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			s := k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			m.ImagePullPolicy = &s
+			iNdEx = postIndex
+		case 10:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ReadinessProbe", wireType)
 			}
-			if iNdEx >= l {
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			if m.ReadinessProbe == nil {
+				m.ReadinessProbe = &Probe{}
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: ForwardConditions: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: ForwardConditions: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			if err := m.ReadinessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 11:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Tags", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field LivenessProbe", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20314,7 +23880,44 @@ func (m *ForwardConditions) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Tags.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.LivenessProbe == nil {
+				m.LivenessProbe = &Probe{}
+			}
+			if err := m.LivenessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 12:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Ports", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Ports = append(m.Ports, v1.ContainerPort{})
+			if err := m.Ports[len(m.Ports)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -20339,7 +23942,7 @@ func (m *ForwardConditions) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GSSAPI) Unmarshal(dAtA []byte) error {
+func (m *ContainerTemplate) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -20362,17 +23965,17 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GSSAPI: wiretype end group for non-group")
+			return fmt.Errorf("proto: ContainerTemplate: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GSSAPI: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: ContainerTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ServiceName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Resources", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20382,27 +23985,28 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ServiceName = string(dAtA[iNdEx:postIndex])
+			if err := m.Resources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Realm", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ImagePullPolicy", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -20430,11 +24034,11 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Realm = string(dAtA[iNdEx:postIndex])
+			m.ImagePullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UsernameSecret", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field SecurityContext", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20461,18 +24065,18 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.UsernameSecret == nil {
-				m.UsernameSecret = &v1.SecretKeySelector{}
+			if m.SecurityContext == nil {
+				m.SecurityContext = &v1.SecurityContext{}
 			}
-			if err := m.UsernameSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.SecurityContext.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AuthType", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20482,28 +24086,29 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := KRB5AuthType(dAtA[iNdEx:postIndex])
-			m.AuthType = &s
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PasswordSecret", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field EnvFrom", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20530,16 +24135,14 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.PasswordSecret == nil {
-				m.PasswordSecret = &v1.SecretKeySelector{}
-			}
-			if err := m.PasswordSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.EnvFrom = append(m.EnvFrom, v1.EnvFromSource{})
+			if err := m.EnvFrom[len(m.EnvFrom)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field KeytabSecret", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ReadinessProbe", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20566,16 +24169,16 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.KeytabSecret == nil {
-				m.KeytabSecret = &v1.SecretKeySelector{}
+			if m.ReadinessProbe == nil {
+				m.ReadinessProbe = &Probe{}
 			}
-			if err := m.KeytabSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.ReadinessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field KerberosConfigSecret", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field LivenessProbe", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20602,10 +24205,10 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.KerberosConfigSecret == nil {
-				m.KerberosConfigSecret = &v1.SecretKeySelector{}
+			if m.LivenessProbe == nil {
+				m.LivenessProbe = &Probe{}
 			}
-			if err := m.KerberosConfigSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.LivenessProbe.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -20630,7 +24233,7 @@ func (m *GSSAPI) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
+func (m *DaemonTemplate) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -20653,35 +24256,15 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GeneratorSource: wiretype end group for non-group")
+			return fmt.Errorf("proto: DaemonTemplate: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GeneratorSource: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: DaemonTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field RPU", wireType)
-			}
-			var v int64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.RPU = &v
-		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Duration", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -20708,16 +24291,13 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Duration == nil {
-				m.Duration = &v11.Duration{}
-			}
-			if err := m.Duration.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 2:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MsgSize", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
 			}
 			var v int32
 			for shift := uint(0); ; shift += 7 {
@@ -20734,12 +24314,12 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
-			m.MsgSize = &v
-		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field KeyCount", wireType)
+			m.Replicas = &v
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
 			}
-			var v int32
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20749,17 +24329,33 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.KeyCount = &v
-		case 5:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Value", wireType)
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			var v uint64
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ContainerTemplate == nil {
+				m.ContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field InitContainerTemplate", wireType)
+			}
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -20769,27 +24365,7 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.Value = &v
-		case 6:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Jitter", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
@@ -20804,46 +24380,13 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Jitter == nil {
-				m.Jitter = &v11.Duration{}
+			if m.InitContainerTemplate == nil {
+				m.InitContainerTemplate = &ContainerTemplate{}
 			}
-			if err := m.Jitter.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.InitContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 7:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ValueBlob", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			s := string(dAtA[iNdEx:postIndex])
-			m.ValueBlob = &s
-			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -20865,7 +24408,7 @@ func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
+func (m *Edge) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -20888,15 +24431,15 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetDaemonDeploymentReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: Edge: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetDaemonDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: Edge: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field From", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -20924,11 +24467,11 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
+			m.From = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field To", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -20956,43 +24499,11 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			m.To = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Conditions", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -21019,16 +24530,18 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Conditions == nil {
+				m.Conditions = &ForwardConditions{}
+			}
+			if err := m.Conditions.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 5:
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field OnFull", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21038,24 +24551,24 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			s := BufferFullWritingStrategy(dAtA[iNdEx:postIndex])
+			m.OnFull = &s
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -21078,7 +24591,7 @@ func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
+func (m *FileSink) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -21101,17 +24614,17 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetJetStreamServiceSpecReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: FileSink: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetJetStreamServiceSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: FileSink: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Path", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21121,124 +24634,29 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Labels == nil {
-				m.Labels = make(map[string]string)
-			}
-			var mapkey string
-			var mapvalue string
-			for iNdEx < postIndex {
-				entryPreIndex := iNdEx
-				var wire uint64
-				for shift := uint(0); ; shift += 7 {
-					if shift >= 64 {
-						return ErrIntOverflowGenerated
-					}
-					if iNdEx >= l {
-						return io.ErrUnexpectedEOF
-					}
-					b := dAtA[iNdEx]
-					iNdEx++
-					wire |= uint64(b&0x7F) << shift
-					if b < 0x80 {
-						break
-					}
-				}
-				fieldNum := int32(wire >> 3)
-				if fieldNum == 1 {
-					var stringLenmapkey uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapkey |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapkey := int(stringLenmapkey)
-					if intStringLenmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapkey := iNdEx + intStringLenmapkey
-					if postStringIndexmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapkey > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
-					iNdEx = postStringIndexmapkey
-				} else if fieldNum == 2 {
-					var stringLenmapvalue uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapvalue |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapvalue := int(stringLenmapvalue)
-					if intStringLenmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
-					if postStringIndexmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapvalue > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
-					iNdEx = postStringIndexmapvalue
-				} else {
-					iNdEx = entryPreIndex
-					skippy, err := skipGenerated(dAtA[iNdEx:])
-					if err != nil {
-						return err
-					}
-					if (skippy < 0) || (iNdEx+skippy) < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if (iNdEx + skippy) > postIndex {
-						return io.ErrUnexpectedEOF
-					}
-					iNdEx += skippy
-				}
-			}
-			m.Labels[mapkey] = mapvalue
+			m.Path = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ClusterPort", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Format", wireType)
 			}
-			m.ClusterPort = 0
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21248,16 +24666,29 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.ClusterPort |= int32(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Format = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
 		case 3:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ClientPort", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxSegmentSize", wireType)
 			}
-			m.ClientPort = 0
+			var v int64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21267,16 +24698,17 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.ClientPort |= int32(b&0x7F) << shift
+				v |= int64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			m.MaxSegmentSize = &v
 		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MonitorPort", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxSegmentAge", wireType)
 			}
-			m.MonitorPort = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21286,30 +24718,28 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.MonitorPort |= int32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 5:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MetricsPort", wireType)
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			m.MetricsPort = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.MetricsPort |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
 			}
+			if m.MaxSegmentAge == nil {
+				m.MaxSegmentAge = &v11.Duration{}
+			}
+			if err := m.MaxSegmentAge.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -21331,7 +24761,7 @@ func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
+func (m *FileSource) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -21354,15 +24784,15 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetJetStreamStatefulSetSpecReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: FileSource: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetJetStreamStatefulSetSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: FileSource: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ServiceName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Path", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -21390,13 +24820,13 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ServiceName = string(dAtA[iNdEx:postIndex])
+			m.Path = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Format", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21406,122 +24836,27 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Labels == nil {
-				m.Labels = make(map[string]string)
-			}
-			var mapkey string
-			var mapvalue string
-			for iNdEx < postIndex {
-				entryPreIndex := iNdEx
-				var wire uint64
-				for shift := uint(0); ; shift += 7 {
-					if shift >= 64 {
-						return ErrIntOverflowGenerated
-					}
-					if iNdEx >= l {
-						return io.ErrUnexpectedEOF
-					}
-					b := dAtA[iNdEx]
-					iNdEx++
-					wire |= uint64(b&0x7F) << shift
-					if b < 0x80 {
-						break
-					}
-				}
-				fieldNum := int32(wire >> 3)
-				if fieldNum == 1 {
-					var stringLenmapkey uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapkey |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapkey := int(stringLenmapkey)
-					if intStringLenmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapkey := iNdEx + intStringLenmapkey
-					if postStringIndexmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapkey > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
-					iNdEx = postStringIndexmapkey
-				} else if fieldNum == 2 {
-					var stringLenmapvalue uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapvalue |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapvalue := int(stringLenmapvalue)
-					if intStringLenmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
-					if postStringIndexmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapvalue > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
-					iNdEx = postStringIndexmapvalue
-				} else {
-					iNdEx = entryPreIndex
-					skippy, err := skipGenerated(dAtA[iNdEx:])
-					if err != nil {
-						return err
-					}
-					if (skippy < 0) || (iNdEx+skippy) < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if (iNdEx + skippy) > postIndex {
-						return io.ErrUnexpectedEOF
-					}
-					iNdEx += skippy
-				}
-			}
-			m.Labels[mapkey] = mapvalue
+			m.Format = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field NatsImage", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field CheckpointPath", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -21549,45 +24884,63 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.NatsImage = string(dAtA[iNdEx:postIndex])
+			m.CheckpointPath = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MetricsExporterImage", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
 			}
-			if postIndex > l {
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *FixedWindow) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
 				return io.ErrUnexpectedEOF
 			}
-			m.MetricsExporterImage = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 5:
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: FixedWindow: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: FixedWindow: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ConfigReloaderImage", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Length", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21597,29 +24950,33 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ConfigReloaderImage = string(dAtA[iNdEx:postIndex])
+			if m.Length == nil {
+				m.Length = &v11.Duration{}
+			}
+			if err := m.Length.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 6:
+		case 2:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ClusterPort", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Streaming", wireType)
 			}
-			m.ClusterPort = 0
+			var v int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21629,73 +24986,67 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.ClusterPort |= int32(b&0x7F) << shift
+				v |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 7:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ClientPort", wireType)
-			}
-			m.ClientPort = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.ClientPort |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			m.Streaming = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-		case 8:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MonitorPort", wireType)
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			m.MonitorPort = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.MonitorPort |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
 			}
-		case 9:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MetricsPort", wireType)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *ForwardConditions) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-			m.MetricsPort = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.MetricsPort |= int32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
 			}
-		case 10:
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: ForwardConditions: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: ForwardConditions: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ServerAuthSecretName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Tags", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21705,91 +25056,78 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ServerAuthSecretName = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 11:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ServerEncryptionSecretName", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if err := m.Tags.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if postIndex > l {
+			if (iNdEx + skippy) > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ServerEncryptionSecretName = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 12:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ConfigMapName", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *GSSAPI) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-			if postIndex > l {
+			if iNdEx >= l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ConfigMapName = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 13:
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: GSSAPI: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: GSSAPI: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PvcNameIfNeeded", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ServiceName", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -21817,11 +25155,11 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.PvcNameIfNeeded = string(dAtA[iNdEx:postIndex])
+			m.ServiceName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 14:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field StartCommand", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Realm", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -21849,11 +25187,11 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.StartCommand = string(dAtA[iNdEx:postIndex])
+			m.Realm = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 15:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field UsernameSecret", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -21880,63 +25218,16 @@ func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
+			if m.UsernameSecret == nil {
+				m.UsernameSecret = &v1.SecretKeySelector{}
 			}
-			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
+			if err := m.UsernameSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: GetMonoVertexDaemonDeploymentReq: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetMonoVertexDaemonDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			iNdEx = postIndex
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AuthType", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -21964,13 +25255,14 @@ func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			s := KRB5AuthType(dAtA[iNdEx:postIndex])
+			m.AuthType = &s
 			iNdEx = postIndex
-		case 2:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PasswordSecret", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -21980,27 +25272,31 @@ func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			if m.PasswordSecret == nil {
+				m.PasswordSecret = &v1.SecretKeySelector{}
+			}
+			if err := m.PasswordSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 3:
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field KeytabSecret", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -22027,14 +25323,16 @@ func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.KeytabSecret == nil {
+				m.KeytabSecret = &v1.SecretKeySelector{}
+			}
+			if err := m.KeytabSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 4:
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field KerberosConfigSecret", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -22061,7 +25359,10 @@ func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.KerberosConfigSecret == nil {
+				m.KerberosConfigSecret = &v1.SecretKeySelector{}
+			}
+			if err := m.KerberosConfigSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -22086,7 +25387,7 @@ func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
+func (m *GeneratorSource) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -22109,17 +25410,37 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetMonoVertexPodSpecReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: GeneratorSource: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetMonoVertexPodSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GeneratorSource: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RPU", wireType)
+			}
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.RPU = &v
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Duration", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22129,29 +25450,33 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			if m.Duration == nil {
+				m.Duration = &v11.Duration{}
+			}
+			if err := m.Duration.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 2:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MsgSize", wireType)
 			}
-			var stringLen uint64
+			var v int32
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22161,27 +25486,55 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				v |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
+			m.MsgSize = &v
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field KeyCount", wireType)
 			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
+			m.KeyCount = &v
+		case 5:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Value", wireType)
 			}
-			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 3:
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Value = &v
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Jitter", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -22208,16 +25561,18 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Jitter == nil {
+				m.Jitter = &v11.Duration{}
+			}
+			if err := m.Jitter.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 4:
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ValueBlob", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22227,24 +25582,24 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			s := string(dAtA[iNdEx:postIndex])
+			m.ValueBlob = &s
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -22267,7 +25622,7 @@ func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetServingPipelineResourceReq) Unmarshal(dAtA []byte) error {
+func (m *GetDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -22290,17 +25645,17 @@ func (m *GetServingPipelineResourceReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetServingPipelineResourceReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetDaemonDeploymentReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetServingPipelineResourceReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetDaemonDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcConfig", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22310,24 +25665,23 @@ func (m *GetServingPipelineResourceReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.ISBSvcConfig.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
@@ -22481,7 +25835,7 @@ func (m *GetServingPipelineResourceReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
+func (m *GetJetStreamServiceSpecReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -22504,17 +25858,17 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetSideInputDeploymentReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetJetStreamServiceSpecReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetSideInputDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetJetStreamServiceSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22524,61 +25878,124 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 2:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+			if m.Labels == nil {
+				m.Labels = make(map[string]string)
 			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			m.Labels[mapkey] = mapvalue
 			iNdEx = postIndex
-		case 3:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ClusterPort", wireType)
 			}
-			var stringLen uint64
+			m.ClusterPort = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22588,29 +26005,35 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				m.ClusterPort |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ClientPort", wireType)
 			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
+			m.ClientPort = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ClientPort |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
 		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MonitorPort", wireType)
 			}
-			var msglen int
+			m.MonitorPort = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22620,31 +26043,16 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.MonitorPort |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
 		case 5:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MetricsPort", wireType)
 			}
-			var msglen int
+			m.MetricsPort = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22654,25 +26062,11 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.MetricsPort |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -22694,7 +26088,7 @@ func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
+func (m *GetJetStreamStatefulSetSpecReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -22717,15 +26111,15 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: GetVertexPodSpecReq: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetJetStreamStatefulSetSpecReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GetVertexPodSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetJetStreamStatefulSetSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ServiceName", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -22753,13 +26147,13 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
+			m.ServiceName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22769,27 +26163,122 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Image = string(dAtA[iNdEx:postIndex])
+			if m.Labels == nil {
+				m.Labels = make(map[string]string)
+			}
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
+			}
+			m.Labels[mapkey] = mapvalue
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field NatsImage", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -22817,13 +26306,13 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			m.NatsImage = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field MetricsExporterImage", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22833,29 +26322,27 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Env = append(m.Env, v1.EnvVar{})
-			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.MetricsExporterImage = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SideInputsStoreName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ConfigReloaderImage", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -22883,11 +26370,87 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.SideInputsStoreName = string(dAtA[iNdEx:postIndex])
+			m.ConfigReloaderImage = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ClusterPort", wireType)
+			}
+			m.ClusterPort = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ClusterPort |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 7:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ClientPort", wireType)
+			}
+			m.ClientPort = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ClientPort |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 8:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MonitorPort", wireType)
+			}
+			m.MonitorPort = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MonitorPort |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 9:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MetricsPort", wireType)
+			}
+			m.MetricsPort = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MetricsPort |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 10:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ServingSourceStreamName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ServerAuthSecretName", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -22915,13 +26478,13 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ServingSourceStreamName = string(dAtA[iNdEx:postIndex])
+			m.ServerAuthSecretName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 7:
+		case 11:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PipelineSpec", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ServerEncryptionSecretName", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22931,30 +26494,29 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.PipelineSpec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ServerEncryptionSecretName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 8:
+		case 12:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ConfigMapName", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -22964,80 +26526,29 @@ func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ConfigMapName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *GroupBy) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: GroupBy: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: GroupBy: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+		case 13:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Window", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PvcNameIfNeeded", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23047,50 +26558,29 @@ func (m *GroupBy) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Window.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.PvcNameIfNeeded = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Keyed", wireType)
-			}
-			var v int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.Keyed = bool(v != 0)
-		case 3:
+		case 14:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AllowedLateness", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field StartCommand", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23100,31 +26590,27 @@ func (m *GroupBy) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.AllowedLateness == nil {
-				m.AllowedLateness = &v11.Duration{}
-			}
-			if err := m.AllowedLateness.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.StartCommand = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 4:
+		case 15:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Storage", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23151,10 +26637,7 @@ func (m *GroupBy) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Storage == nil {
-				m.Storage = &PBQStorage{}
-			}
-			if err := m.Storage.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -23179,7 +26662,7 @@ func (m *GroupBy) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *HTTPSource) Unmarshal(dAtA []byte) error {
+func (m *GetMonoVertexDaemonDeploymentReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -23202,15 +26685,79 @@ func (m *HTTPSource) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: HTTPSource: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetMonoVertexDaemonDeploymentReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: HTTPSource: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetMonoVertexDaemonDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Image = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23237,18 +26784,16 @@ func (m *HTTPSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Auth == nil {
-				m.Auth = &Authorization{}
-			}
-			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Service", wireType)
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
 			}
-			var v int
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23258,12 +26803,25 @@ func (m *HTTPSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.Service = bool(v != 0)
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -23285,7 +26843,7 @@ func (m *HTTPSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *IdleSource) Unmarshal(dAtA []byte) error {
+func (m *GetMonoVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -23308,17 +26866,17 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: IdleSource: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetMonoVertexPodSpecReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: IdleSource: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetMonoVertexPodSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Threshold", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23328,33 +26886,29 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Threshold == nil {
-				m.Threshold = &v11.Duration{}
-			}
-			if err := m.Threshold.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Image = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field StepInterval", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23364,31 +26918,27 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.StepInterval == nil {
-				m.StepInterval = &v11.Duration{}
-			}
-			if err := m.StepInterval.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field IncrementBy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23415,16 +26965,14 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.IncrementBy == nil {
-				m.IncrementBy = &v11.Duration{}
-			}
-			if err := m.IncrementBy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field InitSourceDelay", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23451,10 +26999,7 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.InitSourceDelay == nil {
-				m.InitSourceDelay = &v11.Duration{}
-			}
-			if err := m.InitSourceDelay.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -23479,7 +27024,7 @@ func (m *IdleSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *InterStepBuffer) Unmarshal(dAtA []byte) error {
+func (m *GetServingPipelineResourceReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -23502,15 +27047,15 @@ func (m *InterStepBuffer) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: InterStepBuffer: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetServingPipelineResourceReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: InterStepBuffer: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetServingPipelineResourceReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
-		case 2:
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Compression", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcConfig", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23537,68 +27082,47 @@ func (m *InterStepBuffer) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Compression == nil {
-				m.Compression = &Compression{}
-			}
-			if err := m.Compression.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.ISBSvcConfig.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if iNdEx >= l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: InterStepBufferService: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: InterStepBufferService: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			m.Image = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ObjectMeta", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23608,28 +27132,27 @@ func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.ObjectMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 2:
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Spec", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23656,13 +27179,14 @@ func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Spec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23689,7 +27213,7 @@ func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -23714,7 +27238,7 @@ func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *InterStepBufferServiceList) Unmarshal(dAtA []byte) error {
+func (m *GetSideInputDeploymentReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -23737,17 +27261,17 @@ func (m *InterStepBufferServiceList) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: InterStepBufferServiceList: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetSideInputDeploymentReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: InterStepBufferServiceList: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetSideInputDeploymentReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ListMeta", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23757,30 +27281,29 @@ func (m *InterStepBufferServiceList) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.ListMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Items", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23790,79 +27313,93 @@ func (m *InterStepBufferServiceList) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Items = append(m.Items, InterStepBufferService{})
-			if err := m.Items[len(m.Items)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Image = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if (iNdEx + skippy) > l {
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *InterStepBufferServiceSpec) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
-			if iNdEx >= l {
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: InterStepBufferServiceSpec: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: InterStepBufferServiceSpec: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 2:
+			iNdEx = postIndex
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field JetStream", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -23889,10 +27426,7 @@ func (m *InterStepBufferServiceSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.JetStream == nil {
-				m.JetStream = &JetStreamBufferService{}
-			}
-			if err := m.JetStream.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -23917,7 +27451,7 @@ func (m *InterStepBufferServiceSpec) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
+func (m *GetVertexPodSpecReq) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -23940,17 +27474,17 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: InterStepBufferServiceStatus: wiretype end group for non-group")
+			return fmt.Errorf("proto: GetVertexPodSpecReq: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: InterStepBufferServiceStatus: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GetVertexPodSpecReq: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ISBSvcType", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -23960,28 +27494,27 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.ISBSvcType = ISBSvcType(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Phase", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Image", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24009,11 +27542,11 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Phase = ISBSvcPhase(dAtA[iNdEx:postIndex])
+			m.Image = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Message", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PullPolicy", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24041,11 +27574,11 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Message = string(dAtA[iNdEx:postIndex])
+			m.PullPolicy = k8s_io_api_core_v1.PullPolicy(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Env", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24072,13 +27605,14 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Config.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Env = append(m.Env, v1.EnvVar{})
+			if err := m.Env[len(m.Env)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Type", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field SideInputsStoreName", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24106,13 +27640,13 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Type = ISBSvcType(dAtA[iNdEx:postIndex])
+			m.SideInputsStoreName = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 6:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ObservedGeneration", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ServingSourceStreamName", wireType)
 			}
-			m.ObservedGeneration = 0
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24122,11 +27656,90 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.ObservedGeneration |= int64(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ServingSourceStreamName = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PipelineSpec", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.PipelineSpec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DefaultResources", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.DefaultResources.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -24148,7 +27761,7 @@ func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
+func (m *GroupBy) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -24171,17 +27784,17 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: JetStreamBufferService: wiretype end group for non-group")
+			return fmt.Errorf("proto: GroupBy: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: JetStreamBufferService: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: GroupBy: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Version", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Window", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24191,29 +27804,30 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Version = string(dAtA[iNdEx:postIndex])
+			if err := m.Window.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Keyed", wireType)
 			}
-			var v int32
+			var v int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24223,15 +27837,15 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				v |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.Replicas = &v
+			m.Keyed = bool(v != 0)
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AllowedLateness", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24258,16 +27872,16 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ContainerTemplate == nil {
-				m.ContainerTemplate = &ContainerTemplate{}
+			if m.AllowedLateness == nil {
+				m.AllowedLateness = &v11.Duration{}
 			}
-			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.AllowedLateness.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReloaderContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Storage", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24294,16 +27908,16 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ReloaderContainerTemplate == nil {
-				m.ReloaderContainerTemplate = &ContainerTemplate{}
+			if m.Storage == nil {
+				m.Storage = &PBQStorage{}
 			}
-			if err := m.ReloaderContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Storage.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MetricsContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Builtin", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24330,16 +27944,16 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.MetricsContainerTemplate == nil {
-				m.MetricsContainerTemplate = &ContainerTemplate{}
+			if m.Builtin == nil {
+				m.Builtin = &BuiltinReducer{}
 			}
-			if err := m.MetricsContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Builtin.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Persistence", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field LateData", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24366,16 +27980,16 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Persistence == nil {
-				m.Persistence = &PersistenceStrategy{}
+			if m.LateData == nil {
+				m.LateData = &LateData{}
 			}
-			if err := m.Persistence.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.LateData.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Trigger", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24402,13 +28016,66 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Trigger == nil {
+				m.Trigger = &WindowTrigger{}
+			}
+			if err := m.Trigger.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 8:
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *HTTPSink) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: HTTPSink: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: HTTPSink: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Settings", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24436,12 +28103,11 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := string(dAtA[iNdEx:postIndex])
-			m.Settings = &s
+			m.URL = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 9:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field StartArgs", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Method", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24469,11 +28135,11 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.StartArgs = append(m.StartArgs, string(dAtA[iNdEx:postIndex]))
+			m.Method = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 10:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field BufferConfig", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Format", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -24501,34 +28167,13 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := string(dAtA[iNdEx:postIndex])
-			m.BufferConfig = &s
+			m.Format = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 11:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Encryption", wireType)
-			}
-			var v int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.Encryption = bool(v != 0)
-		case 12:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Headers", wireType)
 			}
-			var v int
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24538,67 +28183,124 @@ func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.TLS = bool(v != 0)
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if (iNdEx + skippy) > l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
+			if m.Headers == nil {
+				m.Headers = make(map[string]string)
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: JetStreamConfig: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: JetStreamConfig: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			m.Headers[mapkey] = mapvalue
+			iNdEx = postIndex
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field HeaderMapping", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24608,27 +28310,122 @@ func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.URL = string(dAtA[iNdEx:postIndex])
+			if m.HeaderMapping == nil {
+				m.HeaderMapping = make(map[string]string)
+			}
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
+			}
+			m.HeaderMapping[mapkey] = mapvalue
 			iNdEx = postIndex
-		case 2:
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Timeout", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24655,18 +28452,18 @@ func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Auth == nil {
-				m.Auth = &NatsAuth{}
+			if m.Timeout == nil {
+				m.Timeout = &v11.Duration{}
 			}
-			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Timeout.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field StreamConfig", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24676,27 +28473,31 @@ func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.StreamConfig = string(dAtA[iNdEx:postIndex])
+			if m.Auth == nil {
+				m.Auth = &HTTPSinkAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 4:
+		case 8:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLSEnabled", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field InsecureSkipVerify", wireType)
 			}
 			var v int
 			for shift := uint(0); ; shift += 7 {
@@ -24713,7 +28514,7 @@ func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
-			m.TLSEnabled = bool(v != 0)
+			m.InsecureSkipVerify = bool(v != 0)
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -24735,7 +28536,7 @@ func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
+func (m *HTTPSinkAuth) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -24758,17 +28559,17 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: JetStreamSource: wiretype end group for non-group")
+			return fmt.Errorf("proto: HTTPSinkAuth: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: JetStreamSource: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: HTTPSinkAuth: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Basic", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24778,29 +28579,33 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.URL = string(dAtA[iNdEx:postIndex])
+			if m.Basic == nil {
+				m.Basic = &BasicAuth{}
+			}
+			if err := m.Basic.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Stream", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Bearer", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24810,29 +28615,83 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Stream = string(dAtA[iNdEx:postIndex])
+			if m.Bearer == nil {
+				m.Bearer = &v1.SecretKeySelector{}
+			}
+			if err := m.Bearer.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 3:
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *HTTPSource) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: HTTPSource: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: HTTPSource: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Consumer", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24842,29 +28701,33 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Consumer = string(dAtA[iNdEx:postIndex])
+			if m.Auth == nil {
+				m.Auth = &Authorization{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DeliverPolicy", wireType)
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Service", wireType)
 			}
-			var stringLen uint64
+			var v int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24874,61 +28737,67 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				v |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
+			m.Service = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if postIndex > l {
+			if (iNdEx + skippy) > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.DeliverPolicy = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 5:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field FilterSubjects", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *ISBBatching) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-			if postIndex > l {
+			if iNdEx >= l {
 				return io.ErrUnexpectedEOF
 			}
-			m.FilterSubjects = append(m.FilterSubjects, string(dAtA[iNdEx:postIndex]))
-			iNdEx = postIndex
-		case 6:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
 			}
-			var msglen int
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: ISBBatching: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: ISBBatching: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxMessages", wireType)
+			}
+			var v int32
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -24938,31 +28807,15 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				v |= int32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.TLS == nil {
-				m.TLS = &TLS{}
-			}
-			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 7:
+			m.MaxMessages = &v
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Linger", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -24989,10 +28842,10 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Auth == nil {
-				m.Auth = &NatsAuth{}
+			if m.Linger == nil {
+				m.Linger = &v11.Duration{}
 			}
-			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Linger.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -25017,7 +28870,7 @@ func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *JobTemplate) Unmarshal(dAtA []byte) error {
+func (m *IdleSource) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25040,15 +28893,15 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: JobTemplate: wiretype end group for non-group")
+			return fmt.Errorf("proto: IdleSource: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: JobTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: IdleSource: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Threshold", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25075,13 +28928,16 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Threshold == nil {
+				m.Threshold = &v11.Duration{}
+			}
+			if err := m.Threshold.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field StepInterval", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25108,18 +28964,18 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ContainerTemplate == nil {
-				m.ContainerTemplate = &ContainerTemplate{}
+			if m.StepInterval == nil {
+				m.StepInterval = &v11.Duration{}
 			}
-			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.StepInterval.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TTLSecondsAfterFinished", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field IncrementBy", wireType)
 			}
-			var v int32
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25129,17 +28985,33 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.TTLSecondsAfterFinished = &v
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.IncrementBy == nil {
+				m.IncrementBy = &v11.Duration{}
+			}
+			if err := m.IncrementBy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field BackoffLimit", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field InitSourceDelay", wireType)
 			}
-			var v int32
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25149,12 +29021,28 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.BackoffLimit = &v
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.InitSourceDelay == nil {
+				m.InitSourceDelay = &v11.Duration{}
+			}
+			if err := m.InitSourceDelay.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -25176,7 +29064,7 @@ func (m *JobTemplate) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *KafkaSink) Unmarshal(dAtA []byte) error {
+func (m *InterStepBuffer) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25199,17 +29087,17 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: KafkaSink: wiretype end group for non-group")
+			return fmt.Errorf("proto: InterStepBuffer: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: KafkaSink: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: InterStepBuffer: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
-		case 1:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Brokers", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Compression", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25219,29 +29107,33 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Brokers = append(m.Brokers, string(dAtA[iNdEx:postIndex]))
+			if m.Compression == nil {
+				m.Compression = &Compression{}
+			}
+			if err := m.Compression.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 2:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Topic", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Batching", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25251,47 +29143,81 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Topic = string(dAtA[iNdEx:postIndex])
+			if m.Batching == nil {
+				m.Batching = &ISBBatching{}
+			}
+			if err := m.Batching.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SetKey", wireType)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			var v int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			m.SetKey = bool(v != 0)
-		case 4:
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *InterStepBufferService) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: InterStepBufferService: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: InterStepBufferService: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ObjectMeta", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25318,18 +29244,15 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.TLS == nil {
-				m.TLS = &TLS{}
-			}
-			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.ObjectMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 5:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Spec", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25339,27 +29262,28 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Config = string(dAtA[iNdEx:postIndex])
+			if err := m.Spec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 6:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SASL", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25386,10 +29310,7 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.SASL == nil {
-				m.SASL = &SASL{}
-			}
-			if err := m.SASL.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -25414,7 +29335,7 @@ func (m *KafkaSink) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *KafkaSource) Unmarshal(dAtA []byte) error {
+func (m *InterStepBufferServiceList) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25437,17 +29358,17 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: KafkaSource: wiretype end group for non-group")
+			return fmt.Errorf("proto: InterStepBufferServiceList: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: KafkaSource: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: InterStepBufferServiceList: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Brokers", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ListMeta", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25457,29 +29378,30 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Brokers = append(m.Brokers, string(dAtA[iNdEx:postIndex]))
+			if err := m.ListMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Topic", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Items", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25489,29 +29411,81 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Topic = string(dAtA[iNdEx:postIndex])
+			m.Items = append(m.Items, InterStepBufferService{})
+			if err := m.Items[len(m.Items)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 3:
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *InterStepBufferServiceSpec) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: InterStepBufferServiceSpec: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: InterStepBufferServiceSpec: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ConsumerGroupName", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field JetStream", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25521,27 +29495,31 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ConsumerGroupName = string(dAtA[iNdEx:postIndex])
+			if m.JetStream == nil {
+				m.JetStream = &JetStreamBufferService{}
+			}
+			if err := m.JetStream.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 4:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Redis", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25568,18 +29546,68 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.TLS == nil {
-				m.TLS = &TLS{}
+			if m.Redis == nil {
+				m.Redis = &RedisBufferService{}
 			}
-			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Redis.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 5:
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *InterStepBufferServiceStatus) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: InterStepBufferServiceStatus: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: InterStepBufferServiceStatus: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25589,29 +29617,30 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Config = string(dAtA[iNdEx:postIndex])
+			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 6:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SASL", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Phase", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -25621,31 +29650,1901 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.SASL == nil {
-				m.SASL = &SASL{}
-			}
-			if err := m.SASL.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Phase = ISBSvcPhase(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 7:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field KafkaVersion", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Message", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Message = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.Config.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Type", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Type = ISBSvcType(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ObservedGeneration", wireType)
+			}
+			m.ObservedGeneration = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ObservedGeneration |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *JetStreamBufferService) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: JetStreamBufferService: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: JetStreamBufferService: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Version", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Version = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Replicas = &v
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ContainerTemplate == nil {
+				m.ContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ReloaderContainerTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ReloaderContainerTemplate == nil {
+				m.ReloaderContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.ReloaderContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MetricsContainerTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.MetricsContainerTemplate == nil {
+				m.MetricsContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.MetricsContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Persistence", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Persistence == nil {
+				m.Persistence = &PersistenceStrategy{}
+			}
+			if err := m.Persistence.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Settings", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			s := string(dAtA[iNdEx:postIndex])
+			m.Settings = &s
+			iNdEx = postIndex
+		case 9:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field StartArgs", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.StartArgs = append(m.StartArgs, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 10:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field BufferConfig", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			s := string(dAtA[iNdEx:postIndex])
+			m.BufferConfig = &s
+			iNdEx = postIndex
+		case 11:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Encryption", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Encryption = bool(v != 0)
+		case 12:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.TLS = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *JetStreamConfig) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: JetStreamConfig: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: JetStreamConfig: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Auth == nil {
+				m.Auth = &NatsAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field StreamConfig", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.StreamConfig = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLSEnabled", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.TLSEnabled = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *JetStreamSink) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: JetStreamSink: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: JetStreamSink: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Subject", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Subject = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AckTimeout", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.AckTimeout == nil {
+				m.AckTimeout = &v11.Duration{}
+			}
+			if err := m.AckTimeout.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Auth == nil {
+				m.Auth = &NatsAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *JetStreamSource) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: JetStreamSource: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: JetStreamSource: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Stream", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Stream = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Consumer", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Consumer = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DeliverPolicy", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.DeliverPolicy = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field FilterSubjects", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.FilterSubjects = append(m.FilterSubjects, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Auth == nil {
+				m.Auth = &NatsAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *JobTemplate) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: JobTemplate: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: JobTemplate: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ContainerTemplate == nil {
+				m.ContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TTLSecondsAfterFinished", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.TTLSecondsAfterFinished = &v
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field BackoffLimit", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.BackoffLimit = &v
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *KafkaSink) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: KafkaSink: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: KafkaSink: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Brokers", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Brokers = append(m.Brokers, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Topic", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Topic = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SetKey", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.SetKey = bool(v != 0)
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Config = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SASL", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.SASL == nil {
+				m.SASL = &SASL{}
+			}
+			if err := m.SASL.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 7:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Transactional", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Transactional = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *KafkaSource) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: KafkaSource: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: KafkaSource: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Brokers", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Brokers = append(m.Brokers, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Topic", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Topic = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ConsumerGroupName", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ConsumerGroupName = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Config", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Config = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SASL", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.SASL == nil {
+				m.SASL = &SASL{}
+			}
+			if err := m.SASL.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field KafkaVersion", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -25696,7 +31595,974 @@ func (m *KafkaSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Lifecycle) Unmarshal(dAtA []byte) error {
+func (m *LateData) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: LateData: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: LateData: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Tag", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Tag = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *Lifecycle) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Lifecycle: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Lifecycle: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DeletionGracePeriodSeconds", wireType)
+			}
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.DeletionGracePeriodSeconds = &v
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DesiredPhase", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.DesiredPhase = PipelinePhase(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PauseGracePeriodSeconds", wireType)
+			}
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.PauseGracePeriodSeconds = &v
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DeprecatedDeleteGracePeriodSeconds", wireType)
+			}
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.DeprecatedDeleteGracePeriodSeconds = &v
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *LocalObjectStore) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: LocalObjectStore: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: LocalObjectStore: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Path", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Path = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *Log) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Log: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Log: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *Metadata) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Metadata: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Metadata: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Annotations", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Annotations == nil {
+				m.Annotations = make(map[string]string)
+			}
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
+			}
+			m.Annotations[mapkey] = mapvalue
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Labels == nil {
+				m.Labels = make(map[string]string)
+			}
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowGenerated
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowGenerated
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipGenerated(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthGenerated
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
+			}
+			m.Labels[mapkey] = mapvalue
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *MonoVertex) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: MonoVertex: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: MonoVertex: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ObjectMeta", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.ObjectMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Spec", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.Spec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *MonoVertexBypassCondition) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: MonoVertexBypassCondition: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: MonoVertexBypassCondition: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Sink == nil {
+				m.Sink = &ForwardConditions{}
+			}
+			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Fallback", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Fallback == nil {
+				m.Fallback = &ForwardConditions{}
+			}
+			if err := m.Fallback.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field OnSuccess", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.OnSuccess == nil {
+				m.OnSuccess = &ForwardConditions{}
+			}
+			if err := m.OnSuccess.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *MonoVertexLifecycle) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25719,33 +32585,13 @@ func (m *Lifecycle) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Lifecycle: wiretype end group for non-group")
+			return fmt.Errorf("proto: MonoVertexLifecycle: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Lifecycle: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: MonoVertexLifecycle: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DeletionGracePeriodSeconds", wireType)
-			}
-			var v int64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.DeletionGracePeriodSeconds = &v
-		case 2:
 			if wireType != 2 {
 				return fmt.Errorf("proto: wrong wireType = %d for field DesiredPhase", wireType)
 			}
@@ -25775,48 +32621,8 @@ func (m *Lifecycle) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.DesiredPhase = PipelinePhase(dAtA[iNdEx:postIndex])
+			m.DesiredPhase = MonoVertexPhase(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field PauseGracePeriodSeconds", wireType)
-			}
-			var v int64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.PauseGracePeriodSeconds = &v
-		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DeprecatedDeleteGracePeriodSeconds", wireType)
-			}
-			var v int64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.DeprecatedDeleteGracePeriodSeconds = &v
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -25838,7 +32644,7 @@ func (m *Lifecycle) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Log) Unmarshal(dAtA []byte) error {
+func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25861,12 +32667,104 @@ func (m *Log) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Log: wiretype end group for non-group")
+			return fmt.Errorf("proto: MonoVertexLimits: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Log: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: MonoVertexLimits: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ReadBatchSize", wireType)
+			}
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ReadBatchSize = &v
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ReadTimeout", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ReadTimeout == nil {
+				m.ReadTimeout = &v11.Duration{}
+			}
+			if err := m.ReadTimeout.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RateLimit", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RateLimit == nil {
+				m.RateLimit = &RateLimit{}
+			}
+			if err := m.RateLimit.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -25888,7 +32786,7 @@ func (m *Log) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *Metadata) Unmarshal(dAtA []byte) error {
+func (m *MonoVertexList) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -25911,15 +32809,15 @@ func (m *Metadata) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: Metadata: wiretype end group for non-group")
+			return fmt.Errorf("proto: MonoVertexList: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: Metadata: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: MonoVertexList: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Annotations", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ListMeta", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -25946,107 +32844,13 @@ func (m *Metadata) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Annotations == nil {
-				m.Annotations = make(map[string]string)
-			}
-			var mapkey string
-			var mapvalue string
-			for iNdEx < postIndex {
-				entryPreIndex := iNdEx
-				var wire uint64
-				for shift := uint(0); ; shift += 7 {
-					if shift >= 64 {
-						return ErrIntOverflowGenerated
-					}
-					if iNdEx >= l {
-						return io.ErrUnexpectedEOF
-					}
-					b := dAtA[iNdEx]
-					iNdEx++
-					wire |= uint64(b&0x7F) << shift
-					if b < 0x80 {
-						break
-					}
-				}
-				fieldNum := int32(wire >> 3)
-				if fieldNum == 1 {
-					var stringLenmapkey uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapkey |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapkey := int(stringLenmapkey)
-					if intStringLenmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapkey := iNdEx + intStringLenmapkey
-					if postStringIndexmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapkey > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
-					iNdEx = postStringIndexmapkey
-				} else if fieldNum == 2 {
-					var stringLenmapvalue uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapvalue |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapvalue := int(stringLenmapvalue)
-					if intStringLenmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
-					if postStringIndexmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapvalue > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
-					iNdEx = postStringIndexmapvalue
-				} else {
-					iNdEx = entryPreIndex
-					skippy, err := skipGenerated(dAtA[iNdEx:])
-					if err != nil {
-						return err
-					}
-					if (skippy < 0) || (iNdEx+skippy) < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if (iNdEx + skippy) > postIndex {
-						return io.ErrUnexpectedEOF
-					}
-					iNdEx += skippy
-				}
+			if err := m.ListMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-			m.Annotations[mapkey] = mapvalue
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Labels", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Items", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26073,103 +32877,10 @@ func (m *Metadata) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Labels == nil {
-				m.Labels = make(map[string]string)
-			}
-			var mapkey string
-			var mapvalue string
-			for iNdEx < postIndex {
-				entryPreIndex := iNdEx
-				var wire uint64
-				for shift := uint(0); ; shift += 7 {
-					if shift >= 64 {
-						return ErrIntOverflowGenerated
-					}
-					if iNdEx >= l {
-						return io.ErrUnexpectedEOF
-					}
-					b := dAtA[iNdEx]
-					iNdEx++
-					wire |= uint64(b&0x7F) << shift
-					if b < 0x80 {
-						break
-					}
-				}
-				fieldNum := int32(wire >> 3)
-				if fieldNum == 1 {
-					var stringLenmapkey uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapkey |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapkey := int(stringLenmapkey)
-					if intStringLenmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapkey := iNdEx + intStringLenmapkey
-					if postStringIndexmapkey < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapkey > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
-					iNdEx = postStringIndexmapkey
-				} else if fieldNum == 2 {
-					var stringLenmapvalue uint64
-					for shift := uint(0); ; shift += 7 {
-						if shift >= 64 {
-							return ErrIntOverflowGenerated
-						}
-						if iNdEx >= l {
-							return io.ErrUnexpectedEOF
-						}
-						b := dAtA[iNdEx]
-						iNdEx++
-						stringLenmapvalue |= uint64(b&0x7F) << shift
-						if b < 0x80 {
-							break
-						}
-					}
-					intStringLenmapvalue := int(stringLenmapvalue)
-					if intStringLenmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
-					if postStringIndexmapvalue < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if postStringIndexmapvalue > l {
-						return io.ErrUnexpectedEOF
-					}
-					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
-					iNdEx = postStringIndexmapvalue
-				} else {
-					iNdEx = entryPreIndex
-					skippy, err := skipGenerated(dAtA[iNdEx:])
-					if err != nil {
-						return err
-					}
-					if (skippy < 0) || (iNdEx+skippy) < 0 {
-						return ErrInvalidLengthGenerated
-					}
-					if (iNdEx + skippy) > postIndex {
-						return io.ErrUnexpectedEOF
-					}
-					iNdEx += skippy
-				}
+			m.Items = append(m.Items, MonoVertex{})
+			if err := m.Items[len(m.Items)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-			m.Labels[mapkey] = mapvalue
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -26192,7 +32903,7 @@ func (m *Metadata) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *MonoVertex) Unmarshal(dAtA []byte) error {
+func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -26215,15 +32926,143 @@ func (m *MonoVertex) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertex: wiretype end group for non-group")
+			return fmt.Errorf("proto: MonoVertexSpec: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertex: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: MonoVertexSpec: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Replicas = &v
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Source", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Source == nil {
+				m.Source = &Source{}
+			}
+			if err := m.Source.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Sink == nil {
+				m.Sink = &Sink{}
+			}
+			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field UDF", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.UDF == nil {
+				m.UDF = &UDF{}
+			}
+			if err := m.UDF.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ObjectMeta", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26250,13 +33089,13 @@ func (m *MonoVertex) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.ObjectMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 2:
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Spec", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26283,13 +33122,16 @@ func (m *MonoVertex) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Spec.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.ContainerTemplate == nil {
+				m.ContainerTemplate = &ContainerTemplate{}
+			}
+			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Volumes", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26316,63 +33158,14 @@ func (m *MonoVertex) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Volumes = append(m.Volumes, v1.Volume{})
+			if err := m.Volumes[len(m.Volumes)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *MonoVertexBypassCondition) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexBypassCondition: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexBypassCondition: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+		case 8:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Limits", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26399,16 +33192,16 @@ func (m *MonoVertexBypassCondition) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Sink == nil {
-				m.Sink = &ForwardConditions{}
+			if m.Limits == nil {
+				m.Limits = &MonoVertexLimits{}
 			}
-			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Limits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 2:
+		case 9:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Fallback", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Scale", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26435,16 +33228,13 @@ func (m *MonoVertexBypassCondition) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Fallback == nil {
-				m.Fallback = &ForwardConditions{}
-			}
-			if err := m.Fallback.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Scale.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 10:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field OnSuccess", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field InitContainers", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26471,68 +33261,50 @@ func (m *MonoVertexBypassCondition) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.OnSuccess == nil {
-				m.OnSuccess = &ForwardConditions{}
-			}
-			if err := m.OnSuccess.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.InitContainers = append(m.InitContainers, v1.Container{})
+			if err := m.InitContainers[len(m.InitContainers)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 11:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Sidecars", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *MonoVertexLifecycle) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if iNdEx >= l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			m.Sidecars = append(m.Sidecars, v1.Container{})
+			if err := m.Sidecars[len(m.Sidecars)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexLifecycle: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexLifecycle: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			iNdEx = postIndex
+		case 12:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DesiredPhase", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DaemonTemplate", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26542,79 +33314,33 @@ func (m *MonoVertexLifecycle) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.DesiredPhase = MonoVertexPhase(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
+			if m.DaemonTemplate == nil {
+				m.DaemonTemplate = &DaemonTemplate{}
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			if err := m.DaemonTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexLimits: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexLimits: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReadBatchSize", wireType)
+			iNdEx = postIndex
+		case 13:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field UpdateStrategy", wireType)
 			}
-			var v uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26624,15 +33350,28 @@ func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.ReadBatchSize = &v
-		case 2:
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if err := m.UpdateStrategy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 14:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReadTimeout", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Lifecycle", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26659,16 +33398,13 @@ func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ReadTimeout == nil {
-				m.ReadTimeout = &v11.Duration{}
-			}
-			if err := m.ReadTimeout.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Lifecycle.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 3:
+		case 15:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field RateLimit", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Bypass", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26695,10 +33431,10 @@ func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.RateLimit == nil {
-				m.RateLimit = &RateLimit{}
+			if m.Bypass == nil {
+				m.Bypass = &MonoVertexBypassCondition{}
 			}
-			if err := m.RateLimit.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Bypass.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -26723,7 +33459,7 @@ func (m *MonoVertexLimits) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *MonoVertexList) Unmarshal(dAtA []byte) error {
+func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -26746,15 +33482,15 @@ func (m *MonoVertexList) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexList: wiretype end group for non-group")
+			return fmt.Errorf("proto: MonoVertexStatus: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexList: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: MonoVertexStatus: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ListMeta", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -26781,15 +33517,15 @@ func (m *MonoVertexList) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.ListMeta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Items", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Phase", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26799,81 +33535,48 @@ func (m *MonoVertexList) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Items = append(m.Items, MonoVertex{})
-			if err := m.Items[len(m.Items)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Phase = MonoVertexPhase(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			m.Replicas = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Replicas |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexSpec: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexSpec: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+		case 4:
 			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DesiredReplicas", wireType)
 			}
-			var v int32
+			m.DesiredReplicas = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26883,17 +33586,16 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				v |= int32(b&0x7F) << shift
+				m.DesiredReplicas |= uint32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			m.Replicas = &v
-		case 2:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Source", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Selector", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26903,33 +33605,29 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Source == nil {
-				m.Source = &Source{}
-			}
-			if err := m.Source.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Selector = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 3:
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Sink", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Reason", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26939,33 +33637,29 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Sink == nil {
-				m.Sink = &Sink{}
-			}
-			if err := m.Sink.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Reason = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 4:
+		case 7:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UDF", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Message", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -26975,31 +33669,27 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.UDF == nil {
-				m.UDF = &UDF{}
-			}
-			if err := m.UDF.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Message = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 5:
+		case 8:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field AbstractPodTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field LastUpdated", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27026,13 +33716,13 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.AbstractPodTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.LastUpdated.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 6:
+		case 9:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerTemplate", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field LastScaledAt", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27059,18 +33749,15 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.ContainerTemplate == nil {
-				m.ContainerTemplate = &ContainerTemplate{}
-			}
-			if err := m.ContainerTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.LastScaledAt.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 7:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Volumes", wireType)
+		case 10:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ObservedGeneration", wireType)
 			}
-			var msglen int
+			m.ObservedGeneration = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27080,31 +33767,35 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.ObservedGeneration |= int64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
+		case 11:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ReadyReplicas", wireType)
 			}
-			m.Volumes = append(m.Volumes, v1.Volume{})
-			if err := m.Volumes[len(m.Volumes)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
+			m.ReadyReplicas = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ReadyReplicas |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			iNdEx = postIndex
-		case 8:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Limits", wireType)
+		case 12:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field UpdatedReplicas", wireType)
 			}
-			var msglen int
+			m.UpdatedReplicas = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27114,33 +33805,16 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.UpdatedReplicas |= uint32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.Limits == nil {
-				m.Limits = &MonoVertexLimits{}
-			}
-			if err := m.Limits.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 9:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Scale", wireType)
+		case 13:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field UpdatedReadyReplicas", wireType)
 			}
-			var msglen int
+			m.UpdatedReadyReplicas = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27150,30 +33824,16 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.UpdatedReadyReplicas |= uint32(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if err := m.Scale.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 10:
+		case 14:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field InitContainers", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field CurrentHash", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27183,31 +33843,29 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.InitContainers = append(m.InitContainers, v1.Container{})
-			if err := m.InitContainers[len(m.InitContainers)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.CurrentHash = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 11:
+		case 15:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Sidecars", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field UpdateHash", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27217,65 +33875,77 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Sidecars = append(m.Sidecars, v1.Container{})
-			if err := m.Sidecars[len(m.Sidecars)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.UpdateHash = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 12:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DaemonTemplate", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			if postIndex > l {
+			if (iNdEx + skippy) > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.DaemonTemplate == nil {
-				m.DaemonTemplate = &DaemonTemplate{}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *NatsAuth) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-			if err := m.DaemonTemplate.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
 			}
-			iNdEx = postIndex
-		case 13:
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: NatsAuth: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: NatsAuth: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UpdateStrategy", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Basic", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27302,13 +33972,16 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.UpdateStrategy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Basic == nil {
+				m.Basic = &BasicAuth{}
+			}
+			if err := m.Basic.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 14:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Lifecycle", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Token", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27335,13 +34008,16 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Lifecycle.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Token == nil {
+				m.Token = &v1.SecretKeySelector{}
+			}
+			if err := m.Token.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 15:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Bypass", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field NKey", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27368,10 +34044,10 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Bypass == nil {
-				m.Bypass = &MonoVertexBypassCondition{}
+			if m.NKey == nil {
+				m.NKey = &v1.SecretKeySelector{}
 			}
-			if err := m.Bypass.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.NKey.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -27396,7 +34072,7 @@ func (m *MonoVertexSpec) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
+func (m *NatsSink) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -27419,17 +34095,17 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: MonoVertexStatus: wiretype end group for non-group")
+			return fmt.Errorf("proto: NatsSink: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MonoVertexStatus: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: NatsSink: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Status", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27439,28 +34115,27 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.Status.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.URL = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Phase", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Subject", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27488,13 +34163,13 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Phase = MonoVertexPhase(dAtA[iNdEx:postIndex])
+			m.Subject = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Replicas", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
 			}
-			m.Replicas = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27504,16 +34179,33 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.Replicas |= uint32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field DesiredReplicas", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
 			}
-			m.DesiredReplicas = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27523,14 +34215,81 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.DesiredReplicas |= uint32(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 5:
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Auth == nil {
+				m.Auth = &NatsAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *NatsSource) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: NatsSource: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: NatsSource: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Selector", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27558,11 +34317,11 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Selector = string(dAtA[iNdEx:postIndex])
+			m.URL = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 6:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Reason", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Subject", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27590,11 +34349,11 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Reason = string(dAtA[iNdEx:postIndex])
+			m.Subject = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 7:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Message", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Queue", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27622,11 +34381,11 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Message = string(dAtA[iNdEx:postIndex])
+			m.Queue = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 8:
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field LastUpdated", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27653,13 +34412,16 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.LastUpdated.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.TLS == nil {
+				m.TLS = &TLS{}
+			}
+			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 9:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field LastScaledAt", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27686,72 +34448,118 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if err := m.LastScaledAt.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if m.Auth == nil {
+				m.Auth = &NatsAuth{}
+			}
+			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 10:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ObservedGeneration", wireType)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			m.ObservedGeneration = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.ObservedGeneration |= int64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
 			}
-		case 11:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ReadyReplicas", wireType)
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
 			}
-			m.ReadyReplicas = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.ReadyReplicas |= uint32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *NoStore) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
 			}
-		case 12:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UpdatedReplicas", wireType)
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
 			}
-			m.UpdatedReplicas = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowGenerated
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.UpdatedReplicas |= uint32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
 			}
-		case 13:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UpdatedReadyReplicas", wireType)
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: NoStore: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: NoStore: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
 			}
-			m.UpdatedReadyReplicas = 0
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *ObjectStoreColumn) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: ObjectStoreColumn: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: ObjectStoreColumn: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+			}
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27761,14 +34569,27 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.UpdatedReadyReplicas |= uint32(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 14:
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Name = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field CurrentHash", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Type", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27796,11 +34617,11 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.CurrentHash = string(dAtA[iNdEx:postIndex])
+			m.Type = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 15:
+		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field UpdateHash", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Path", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -27828,7 +34649,7 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.UpdateHash = string(dAtA[iNdEx:postIndex])
+			m.Path = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -27851,7 +34672,7 @@ func (m *MonoVertexStatus) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *NatsAuth) Unmarshal(dAtA []byte) error {
+func (m *ObjectStoreSink) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -27874,15 +34695,15 @@ func (m *NatsAuth) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: NatsAuth: wiretype end group for non-group")
+			return fmt.Errorf("proto: ObjectStoreSink: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: NatsAuth: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: ObjectStoreSink: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Basic", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field S3", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27909,16 +34730,16 @@ func (m *NatsAuth) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Basic == nil {
-				m.Basic = &BasicAuth{}
+			if m.S3 == nil {
+				m.S3 = &S3ObjectStore{}
 			}
-			if err := m.Basic.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.S3.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Token", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Local", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -27945,18 +34766,18 @@ func (m *NatsAuth) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Token == nil {
-				m.Token = &v1.SecretKeySelector{}
+			if m.Local == nil {
+				m.Local = &LocalObjectStore{}
 			}
-			if err := m.Token.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.Local.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
 		case 3:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field NKey", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Prefix", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -27966,81 +34787,27 @@ func (m *NatsAuth) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.NKey == nil {
-				m.NKey = &v1.SecretKeySelector{}
-			}
-			if err := m.NKey.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Prefix = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *NatsSource) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: NatsSource: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: NatsSource: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+		case 4:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Format", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -28068,13 +34835,13 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.URL = string(dAtA[iNdEx:postIndex])
+			m.Format = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 2:
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Subject", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Columns", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -28084,29 +34851,31 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Subject = string(dAtA[iNdEx:postIndex])
+			m.Columns = append(m.Columns, ObjectStoreColumn{})
+			if err := m.Columns[len(m.Columns)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 3:
+		case 6:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Queue", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field PartitionWindow", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -28116,29 +34885,33 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Queue = string(dAtA[iNdEx:postIndex])
+			if m.PartitionWindow == nil {
+				m.PartitionWindow = &v11.Duration{}
+			}
+			if err := m.PartitionWindow.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 4:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field TLS", wireType)
+		case 7:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PartitionByKeys", wireType)
 			}
-			var msglen int
+			var v int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -28148,31 +34921,55 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				v |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthGenerated
+			m.PartitionByKeys = bool(v != 0)
+		case 8:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxFileSize", wireType)
 			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if m.TLS == nil {
-				m.TLS = &TLS{}
+			m.MaxFileSize = &v
+		case 9:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxFileMessages", wireType)
 			}
-			if err := m.TLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
+			var v int64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			iNdEx = postIndex
-		case 5:
+			m.MaxFileMessages = &v
+		case 10:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Auth", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxFileAge", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -28199,10 +34996,10 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.Auth == nil {
-				m.Auth = &NatsAuth{}
+			if m.MaxFileAge == nil {
+				m.MaxFileAge = &v11.Duration{}
 			}
-			if err := m.Auth.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.MaxFileAge.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -28227,56 +35024,6 @@ func (m *NatsSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *NoStore) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
-			}
-			if iNdEx >= l {
-				return io.ErrUnexpectedEOF
-			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
-			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: NoStore: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: NoStore: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
-			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
-			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
-			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
 func (m *Ordered) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -28505,6 +35252,112 @@ func (m *PBQStorage) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *PercentileWatermark) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: PercentileWatermark: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: PercentileWatermark: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Percentile", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Percentile = &v
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Window", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Window == nil {
+				m.Window = &v11.Duration{}
+			}
+			if err := m.Window.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *PersistenceStrategy) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -29928,6 +36781,456 @@ func (m *Ports) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *PostgresColumn) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: PostgresColumn: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: PostgresColumn: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Name = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Path", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Path = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Key", wireType)
+			}
+			var v int32
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Key = &v
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *PostgresSink) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: PostgresSink: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: PostgresSink: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PasswordSecret", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.PasswordSecret == nil {
+				m.PasswordSecret = &v1.SecretKeySelector{}
+			}
+			if err := m.PasswordSecret.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Table", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Table = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Columns", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Columns = append(m.Columns, PostgresColumn{})
+			if err := m.Columns[len(m.Columns)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ConflictColumns", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ConflictColumns = append(m.ConflictColumns, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field OnConflict", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.OnConflict = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field IDColumn", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.IDColumn = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ConnectTimeout", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ConnectTimeout == nil {
+				m.ConnectTimeout = &v11.Duration{}
+			}
+			if err := m.ConnectTimeout.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *Probe) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -30737,6 +38040,88 @@ func (m *PulsarSource) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *PunctuationWatermark) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: PunctuationWatermark: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: PunctuationWatermark: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Header", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Header = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *RateLimit) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -30971,6 +38356,336 @@ func (m *RateLimit) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PerKey", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.PerKey == nil {
+				m.PerKey = &RateLimitPerKey{}
+			}
+			if err := m.PerKey.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *RateLimitKeyOverride) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RateLimitKeyOverride: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RateLimitKeyOverride: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Key", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Key = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Max", wireType)
+			}
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Max = &v
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Min", wireType)
+			}
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Min = &v
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RampUpDuration", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RampUpDuration == nil {
+				m.RampUpDuration = &v11.Duration{}
+			}
+			if err := m.RampUpDuration.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *RateLimitPerKey) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RateLimitPerKey: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RateLimitPerKey: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Header", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Header = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxKeys", wireType)
+			}
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.MaxKeys = &v
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Overrides", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Overrides = append(m.Overrides, RateLimitKeyOverride{})
+			if err := m.Overrides[len(m.Overrides)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -31168,6 +38883,88 @@ func (m *RateLimiterInMemoryStore) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *RateLimiterJetStreamStore) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RateLimiterJetStreamStore: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RateLimiterJetStreamStore: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Bucket", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Bucket = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *RateLimiterModes) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -31804,6 +39601,42 @@ func (m *RateLimiterStore) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RateLimiterJetStreamStore", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RateLimiterJetStreamStore == nil {
+				m.RateLimiterJetStreamStore = &RateLimiterJetStreamStore{}
+			}
+			if err := m.RateLimiterJetStreamStore.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -31947,6 +39780,242 @@ func (m *RedisAuth) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *RedisBufferService) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RedisBufferService: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RedisBufferService: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Password", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Password == nil {
+				m.Password = &v1.SecretKeySelector{}
+			}
+			if err := m.Password.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *RedisConfig) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RedisConfig: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RedisConfig: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field URL", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.URL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Password", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Password == nil {
+				m.Password = &v1.SecretKeySelector{}
+			}
+			if err := m.Password.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *RedisSentinelConfig) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
@@ -32141,16 +40210,257 @@ func (m *RedisSentinelConfig) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.SentinelTLS == nil {
-				m.SentinelTLS = &TLS{}
+			if m.SentinelTLS == nil {
+				m.SentinelTLS = &TLS{}
+			}
+			if err := m.SentinelTLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RedisTLS", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RedisTLS == nil {
+				m.RedisTLS = &TLS{}
+			}
+			if err := m.RedisTLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *RetryStrategy) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RetryStrategy: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RetryStrategy: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field BackOff", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.BackOff == nil {
+				m.BackOff = &Backoff{}
+			}
+			if err := m.BackOff.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field OnFailure", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			s := OnFailureRetryStrategy(dAtA[iNdEx:postIndex])
+			m.OnFailure = &s
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field CircuitBreaker", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.CircuitBreaker == nil {
+				m.CircuitBreaker = &CircuitBreaker{}
 			}
-			if err := m.SentinelTLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.CircuitBreaker.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
-		case 7:
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *RollingUpdateStrategy) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: RollingUpdateStrategy: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: RollingUpdateStrategy: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field RedisTLS", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxUnavailable", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -32177,10 +40487,10 @@ func (m *RedisSentinelConfig) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.RedisTLS == nil {
-				m.RedisTLS = &TLS{}
+			if m.MaxUnavailable == nil {
+				m.MaxUnavailable = &intstr.IntOrString{}
 			}
-			if err := m.RedisTLS.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.MaxUnavailable.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -32205,7 +40515,7 @@ func (m *RedisSentinelConfig) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *RetryStrategy) Unmarshal(dAtA []byte) error {
+func (m *S3ObjectStore) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -32228,17 +40538,17 @@ func (m *RetryStrategy) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: RetryStrategy: wiretype end group for non-group")
+			return fmt.Errorf("proto: S3ObjectStore: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: RetryStrategy: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: S3ObjectStore: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field BackOff", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Bucket", wireType)
 			}
-			var msglen int
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowGenerated
@@ -32248,31 +40558,27 @@ func (m *RetryStrategy) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
 				return ErrInvalidLengthGenerated
 			}
-			postIndex := iNdEx + msglen
+			postIndex := iNdEx + intStringLen
 			if postIndex < 0 {
 				return ErrInvalidLengthGenerated
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.BackOff == nil {
-				m.BackOff = &Backoff{}
-			}
-			if err := m.BackOff.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
+			m.Bucket = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field OnFailure", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AWSRegion", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -32300,62 +40606,63 @@ func (m *RetryStrategy) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			s := OnFailureRetryStrategy(dAtA[iNdEx:postIndex])
-			m.OnFailure = &s
+			m.AWSRegion = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipGenerated(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field EndpointURL", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthGenerated
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			iNdEx += skippy
-		}
-	}
-
-	if iNdEx > l {
-		return io.ErrUnexpectedEOF
-	}
-	return nil
-}
-func (m *RollingUpdateStrategy) Unmarshal(dAtA []byte) error {
-	l := len(dAtA)
-	iNdEx := 0
-	for iNdEx < l {
-		preIndex := iNdEx
-		var wire uint64
-		for shift := uint(0); ; shift += 7 {
-			if shift >= 64 {
-				return ErrIntOverflowGenerated
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
 			}
-			if iNdEx >= l {
+			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			b := dAtA[iNdEx]
-			iNdEx++
-			wire |= uint64(b&0x7F) << shift
-			if b < 0x80 {
-				break
+			m.EndpointURL = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ForcePathStyle", wireType)
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: RollingUpdateStrategy: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: RollingUpdateStrategy: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ForcePathStyle = bool(v != 0)
+		case 5:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MaxUnavailable", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field AssumeRole", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -32382,10 +40689,10 @@ func (m *RollingUpdateStrategy) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.MaxUnavailable == nil {
-				m.MaxUnavailable = &intstr.IntOrString{}
+			if m.AssumeRole == nil {
+				m.AssumeRole = &AWSAssumeRole{}
 			}
-			if err := m.MaxUnavailable.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			if err := m.AssumeRole.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
@@ -35057,6 +43364,62 @@ func (m *Sink) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 5:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DLQHeaders", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.DLQHeaders = bool(v != 0)
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Broadcast", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Broadcast == nil {
+				m.Broadcast = &BroadcastSink{}
+			}
+			if err := m.Broadcast.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -35609,6 +43972,42 @@ func (m *Source) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 11:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field File", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.File == nil {
+				m.File = &FileSource{}
+			}
+			if err := m.File.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -38922,6 +47321,200 @@ func (m *Watermark) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Strategy", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Strategy == nil {
+				m.Strategy = &WatermarkStrategy{}
+			}
+			if err := m.Strategy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *WatermarkStrategy) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: WatermarkStrategy: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: WatermarkStrategy: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Percentile", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Percentile == nil {
+				m.Percentile = &PercentileWatermark{}
+			}
+			if err := m.Percentile.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Adaptive", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Adaptive == nil {
+				m.Adaptive = &AdaptiveWatermark{}
+			}
+			if err := m.Adaptive.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Punctuation", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Punctuation == nil {
+				m.Punctuation = &PunctuationWatermark{}
+			}
+			if err := m.Punctuation.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipGenerated(dAtA[iNdEx:])
@@ -39137,6 +47730,132 @@ func (m *Window) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *WindowTrigger) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowGenerated
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: WindowTrigger: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: WindowTrigger: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Count", wireType)
+			}
+			var v uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Count = &v
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Interval", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Interval == nil {
+				m.Interval = &v11.Duration{}
+			}
+			if err := m.Interval.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field LateFirings", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowGenerated
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.LateFirings = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipGenerated(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthGenerated
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func skipGenerated(dAtA []byte) (n int, err error) {
 	l := len(dAtA)
 	iNdEx := 0
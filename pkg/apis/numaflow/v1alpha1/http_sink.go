@@ -0,0 +1,63 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import (
+	corev1 "k8s.io/api/core/v1"
+	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+)
+
+// HTTPSink sends the messages to an HTTP endpoint, either one request per message or as a batch in a single request.
+type HTTPSink struct {
+	// URL of the HTTP endpoint.
+	URL string `json:"url" protobuf:"bytes,1,opt,name=url"`
+	// Method is the HTTP method of the requests, defaults to POST.
+	// +optional
+	Method string `json:"method,omitempty" protobuf:"bytes,2,opt,name=method"`
+	// Format of the request body. "raw" (default) sends one request per message with the payload as the body.
+	// "json" sends the whole batch as a JSON array, and "ndjson" sends the whole batch as newline delimited JSON,
+	// both require the payloads to be valid JSON.
+	// +kubebuilder:validation:Enum=raw;json;ndjson
+	// +optional
+	Format string `json:"format,omitempty" protobuf:"bytes,3,opt,name=format"`
+	// Headers are static headers added to every request.
+	// +optional
+	Headers map[string]string `json:"headers,omitempty" protobuf:"bytes,4,rep,name=headers"`
+	// HeaderMapping maps the message header names to the request header names, only the mapped message headers are sent.
+	// It is only applied to the "raw" format.
+	// +optional
+	HeaderMapping map[string]string `json:"headerMapping,omitempty" protobuf:"bytes,5,rep,name=headerMapping"`
+	// Timeout of a request, defaults to 30s.
+	// +optional
+	Timeout *metav1.Duration `json:"timeout,omitempty" protobuf:"bytes,6,opt,name=timeout"`
+	// Auth information
+	// +optional
+	Auth *HTTPSinkAuth `json:"auth,omitempty" protobuf:"bytes,7,opt,name=auth"`
+	// InsecureSkipVerify skips the verification of the server certificate.
+	// +optional
+	InsecureSkipVerify bool `json:"insecureSkipVerify,omitempty" protobuf:"varint,8,opt,name=insecureSkipVerify"`
+}
+
+// HTTPSinkAuth defines how to authenticate the requests of the HTTP sink, only one of them can be specified.
+type HTTPSinkAuth struct {
+	// Basic auth which contains a username and a password
+	// +optional
+	Basic *BasicAuth `json:"basic,omitempty" protobuf:"bytes,1,opt,name=basic"`
+	// Bearer token auth
+	// +optional
+	Bearer *corev1.SecretKeySelector `json:"bearer,omitempty" protobuf:"bytes,2,opt,name=bearer"`
+}
@@ -46,6 +46,7 @@ type ISBSvcType string
 const (
 	ISBSvcTypeUnknown   ISBSvcType = ""
 	ISBSvcTypeJetStream ISBSvcType = "jetstream"
+	ISBSvcTypeRedis     ISBSvcType = "redis"
 )
 
 // +genclient
@@ -71,6 +72,9 @@ func (isbs InterStepBufferService) GetType() ISBSvcType {
 	if isbs.Spec.JetStream != nil {
 		return ISBSvcTypeJetStream
 	}
+	if isbs.Spec.Redis != nil {
+		return ISBSvcTypeRedis
+	}
 	return ISBSvcTypeUnknown
 }
 
@@ -85,10 +89,14 @@ type InterStepBufferServiceList struct {
 
 type InterStepBufferServiceSpec struct {
 	JetStream *JetStreamBufferService `json:"jetstream,omitempty" protobuf:"bytes,2,opt,name=jetstream"`
+	// +optional
+	Redis *RedisBufferService `json:"redis,omitempty" protobuf:"bytes,3,opt,name=redis"`
 }
 
 type BufferServiceConfig struct {
 	JetStream *JetStreamConfig `json:"jetstream,omitempty" protobuf:"bytes,2,opt,name=jetstream"`
+	// +optional
+	Redis *RedisConfig `json:"redis,omitempty" protobuf:"bytes,3,opt,name=redis"`
 }
 
 type InterStepBufferServiceStatus struct {
@@ -0,0 +1,38 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+
+// JetStreamSink publishes messages to a JetStream stream and waits for the publish acks.
+type JetStreamSink struct {
+	// URL to connect to NATS cluster, multiple urls could be separated by comma.
+	URL string `json:"url" protobuf:"bytes,1,opt,name=url"`
+	// Subject to publish the messages to, it should be bound to a stream. It can be a template rendered per message,
+	// supported placeholders are `{{key.N}}` (N-th key of the message), `{{keys}}` (all the keys joined by ".") and
+	// `{{header.NAME}}` (value of the header NAME).
+	Subject string `json:"subject" protobuf:"bytes,2,opt,name=subject"`
+	// AckTimeout is the maximum time to wait for a publish ack from the JetStream server, defaults to 5s.
+	// +optional
+	AckTimeout *metav1.Duration `json:"ackTimeout,omitempty" protobuf:"bytes,3,opt,name=ackTimeout"`
+	// TLS configuration for the nats client.
+	// +optional
+	TLS *TLS `json:"tls,omitempty" protobuf:"bytes,4,opt,name=tls"`
+	// Auth information
+	// +optional
+	Auth *NatsAuth `json:"auth,omitempty" protobuf:"bytes,5,opt,name=auth"`
+}
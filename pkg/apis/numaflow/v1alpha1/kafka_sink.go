@@ -35,4 +35,10 @@ type KafkaSink struct {
 	// SASL.enable=true default for SASL.
 	// +optional
 	SASL *SASL `json:"sasl" protobuf:"bytes,6,opt,name=sasl"`
+	// Transactional enables the exactly-once mode of the sink. Every batch is written in a Kafka transaction, and the
+	// messages are acknowledged only after the transaction is committed. The transactional.id is derived from the pipeline,
+	// vertex and replica, so that a restarted replica fences off its previous incarnation. Consumers should use
+	// isolation.level=read_committed to not see the records of aborted transactions.
+	// +optional
+	Transactional bool `json:"transactional,omitempty" protobuf:"varint,7,opt,name=transactional"`
 }
@@ -0,0 +1,91 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+
+// ObjectStoreSink buffers the messages into files which are uploaded to an object store, the files are partitioned by
+// the event time window and optionally the keys of the messages.
+type ObjectStoreSink struct {
+	// S3 is an S3 compatible object store.
+	// +optional
+	S3 *S3ObjectStore `json:"s3,omitempty" protobuf:"bytes,1,opt,name=s3"`
+	// Local stores the files in a local directory.
+	// +optional
+	Local *LocalObjectStore `json:"local,omitempty" protobuf:"bytes,2,opt,name=local"`
+	// Prefix of the object keys.
+	// +optional
+	Prefix string `json:"prefix,omitempty" protobuf:"bytes,3,opt,name=prefix"`
+	// Format of the files, "ndjson" (default) or "parquet".
+	// +kubebuilder:validation:Enum=ndjson;parquet
+	// +optional
+	Format string `json:"format,omitempty" protobuf:"bytes,4,opt,name=format"`
+	// Columns of the files, the values are read from the JSON payload of the messages. Required for the parquet format,
+	// for ndjson the payload is written as is if no columns are specified.
+	// +optional
+	Columns []ObjectStoreColumn `json:"columns,omitempty" protobuf:"bytes,5,rep,name=columns"`
+	// PartitionWindow is the length of the event time windows the files are partitioned by, defaults to 1h.
+	// +optional
+	PartitionWindow *metav1.Duration `json:"partitionWindow,omitempty" protobuf:"bytes,6,opt,name=partitionWindow"`
+	// PartitionByKeys adds the keys of the messages to the path of the files.
+	// +optional
+	PartitionByKeys bool `json:"partitionByKeys,omitempty" protobuf:"varint,7,opt,name=partitionByKeys"`
+	// MaxFileSize is the maximum size of the rows of a file in bytes, defaults to 64MiB.
+	// +optional
+	MaxFileSize *int64 `json:"maxFileSize,omitempty" protobuf:"varint,8,opt,name=maxFileSize"`
+	// MaxFileMessages is the maximum number of messages in a file, defaults to 100000.
+	// +optional
+	MaxFileMessages *int64 `json:"maxFileMessages,omitempty" protobuf:"varint,9,opt,name=maxFileMessages"`
+	// MaxFileAge is the maximum time the messages are buffered before the file is uploaded, defaults to 1m.
+	// +optional
+	MaxFileAge *metav1.Duration `json:"maxFileAge,omitempty" protobuf:"bytes,10,opt,name=maxFileAge"`
+}
+
+// ObjectStoreColumn is a column of the files written by the object store sink.
+type ObjectStoreColumn struct {
+	// Name of the column.
+	Name string `json:"name" protobuf:"bytes,1,opt,name=name"`
+	// Type of the column, one of "string", "int64", "double", "boolean", "timestamp" or "json".
+	// +kubebuilder:validation:Enum=string;int64;double;boolean;timestamp;json
+	Type string `json:"type" protobuf:"bytes,2,opt,name=type"`
+	// Path is the JSON pointer of the value in the payload, e.g. "/user/id", defaults to the name of the column.
+	// +optional
+	Path string `json:"path,omitempty" protobuf:"bytes,3,opt,name=path"`
+}
+
+// S3ObjectStore is an S3 compatible object store.
+type S3ObjectStore struct {
+	// Bucket is the name of the bucket.
+	Bucket string `json:"bucket" protobuf:"bytes,1,opt,name=bucket"`
+	// AWSRegion is the AWS Region of the bucket.
+	AWSRegion string `json:"awsRegion" protobuf:"bytes,2,opt,name=awsRegion"`
+	// EndpointURL overrides the endpoint of the store, e.g. for MinIO.
+	// +optional
+	EndpointURL string `json:"endpointUrl,omitempty" protobuf:"bytes,3,opt,name=endpointUrl"`
+	// ForcePathStyle addresses the bucket in the path of the URL instead of the host, required by most S3 compatible stores.
+	// +optional
+	ForcePathStyle bool `json:"forcePathStyle,omitempty" protobuf:"varint,4,opt,name=forcePathStyle"`
+	// AssumeRole contains the configuration for AWS STS assume role.
+	// +optional
+	AssumeRole *AWSAssumeRole `json:"assumeRole,omitempty" protobuf:"bytes,5,opt,name=assumeRole"`
+}
+
+// LocalObjectStore stores the objects in a local directory, usually a mounted volume.
+type LocalObjectStore struct {
+	// Path of the directory to store the objects in.
+	Path string `json:"path" protobuf:"bytes,1,opt,name=path"`
+}
@@ -528,6 +528,19 @@ type InterStepBuffer struct {
 	// Compression is the compression settings for the InterStepBufferService
 	// +optional
 	Compression *Compression `json:"compression,omitempty" protobuf:"bytes,2,opt,name=compression"`
+	// Batching packs multiple messages into a single InterStepBuffer message.
+	// +optional
+	Batching *ISBBatching `json:"batching,omitempty" protobuf:"bytes,3,opt,name=batching"`
+}
+
+// ISBBatching packs multiple messages into a single InterStepBuffer message, which is compressed as a whole.
+type ISBBatching struct {
+	// MaxMessages is the maximum number of messages packed into a single InterStepBuffer message, defaults to 100.
+	// +optional
+	MaxMessages *int32 `json:"maxMessages,omitempty" protobuf:"varint,1,opt,name=maxMessages"`
+	// Linger is the maximum time a message waits for the batch to fill up before the batch is written, defaults to 5ms.
+	// +optional
+	Linger *metav1.Duration `json:"linger,omitempty" protobuf:"bytes,2,opt,name=linger"`
 }
 
 // Ordered defines the ordered processing configuration.
@@ -550,6 +563,13 @@ type Compression struct {
 	// +kubebuilder:default:=None
 	// +optional
 	Type CompressionType `json:"type,omitempty" protobuf:"bytes,1,opt,name=type"`
+	// MinSize is the size in bytes below which the messages are written uncompressed.
+	// +optional
+	MinSize *int64 `json:"minSize,omitempty" protobuf:"varint,2,opt,name=minSize"`
+	// DictionaryBucket is the name of the KV bucket holding the trained zstd dictionaries, keyed by their version.
+	// The dictionary with the highest version is used for compressing. Only supported with zstd.
+	// +optional
+	DictionaryBucket string `json:"dictionaryBucket,omitempty" protobuf:"bytes,3,opt,name=dictionaryBucket"`
 }
 
 // CompressionType is a string enumeration type that enumerates all possible compression types.
@@ -607,6 +627,56 @@ type Watermark struct {
 	// IdleSource defines the idle watermark properties, it could be configured in case source is idling.
 	// +optional
 	IdleSource *IdleSource `json:"idleSource,omitempty" protobuf:"bytes,3,opt,name=idleSource"`
+	// Strategy is the heuristic used by the source to compute the watermark, the max delay is subtracted from the
+	// computed watermark regardless of the strategy.
+	// +optional
+	Strategy *WatermarkStrategy `json:"strategy,omitempty" protobuf:"bytes,4,opt,name=strategy"`
+}
+
+// WatermarkStrategy is the heuristic used by the source to compute the watermark from the event times of the messages.
+// Only one of the strategies can be set, defaults to the minimum event time of the read messages.
+type WatermarkStrategy struct {
+	// Percentile holds back the watermark by a percentile of the observed out-of-orderness.
+	// +optional
+	Percentile *PercentileWatermark `json:"percentile,omitempty" protobuf:"bytes,1,opt,name=percentile"`
+	// Adaptive holds back the watermark of every partition by a delay adapting to its late messages.
+	// +optional
+	Adaptive *AdaptiveWatermark `json:"adaptive,omitempty" protobuf:"bytes,2,opt,name=adaptive"`
+	// Punctuation advances the watermark only on the marked messages.
+	// +optional
+	Punctuation *PunctuationWatermark `json:"punctuation,omitempty" protobuf:"bytes,3,opt,name=punctuation"`
+}
+
+// PercentileWatermark holds back the watermark by a percentile of the out-of-orderness of the messages observed over
+// a sliding window.
+type PercentileWatermark struct {
+	// Percentile of the out-of-orderness used as the delay of the watermark, between 1 and 100, defaults to 99.
+	// +kubebuilder:validation:Minimum=1
+	// +kubebuilder:validation:Maximum=100
+	// +optional
+	Percentile *int32 `json:"percentile,omitempty" protobuf:"varint,1,opt,name=percentile"`
+	// Window is the length of the sliding window the out-of-orderness is observed over, defaults to 5m.
+	// +optional
+	Window *metav1.Duration `json:"window,omitempty" protobuf:"bytes,2,opt,name=window"`
+}
+
+// AdaptiveWatermark holds back the watermark of every partition by a delay which grows to cover the late messages of
+// the partition and decays while messages arrive in order.
+type AdaptiveWatermark struct {
+	// HalfLife is the time it takes for the delay to halve while the messages arrive in order, defaults to 1m.
+	// +optional
+	HalfLife *metav1.Duration `json:"halfLife,omitempty" protobuf:"bytes,1,opt,name=halfLife"`
+	// MaxDelay caps the delay of the watermark, unbounded by default.
+	// +optional
+	MaxDelay *metav1.Duration `json:"maxDelay,omitempty" protobuf:"bytes,2,opt,name=maxDelay"`
+}
+
+// PunctuationWatermark advances the watermark of a partition only when a message with the marker header is read.
+type PunctuationWatermark struct {
+	// Header marking the messages which advance the watermark, its value is the new watermark in epoch milliseconds,
+	// or the event time of the message if empty. Defaults to "x-numaflow-watermark".
+	// +optional
+	Header string `json:"header,omitempty" protobuf:"bytes,1,opt,name=header"`
 }
 
 // GetMaxDelay returns the configured max delay with a default value.
@@ -0,0 +1,66 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import (
+	corev1 "k8s.io/api/core/v1"
+	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+)
+
+// PostgresSink writes the messages to a PostgreSQL table, each batch is written with a single INSERT ... ON CONFLICT
+// statement in one transaction.
+type PostgresSink struct {
+	// URL is the connection string of the database, e.g. "postgres://user@host:5432/db".
+	URL string `json:"url" protobuf:"bytes,1,opt,name=url"`
+	// PasswordSecret refers to the secret that contains the password of the user.
+	// +optional
+	PasswordSecret *corev1.SecretKeySelector `json:"passwordSecret,omitempty" protobuf:"bytes,2,opt,name=passwordSecret"`
+	// Table the rows are written to, optionally qualified by the schema, e.g. "public.events".
+	Table string `json:"table" protobuf:"bytes,3,opt,name=table"`
+	// Columns of the table and where their values are read from.
+	// +kubebuilder:validation:MinItems=1
+	Columns []PostgresColumn `json:"columns" protobuf:"bytes,4,rep,name=columns"`
+	// ConflictColumns are the columns of the unique constraint the rows are upserted on.
+	// +optional
+	ConflictColumns []string `json:"conflictColumns,omitempty" protobuf:"bytes,5,rep,name=conflictColumns"`
+	// OnConflict is what happens to a row whose conflict columns match an existing row, "update" (default) updates the
+	// other columns and "nothing" keeps the existing row.
+	// +kubebuilder:validation:Enum=update;nothing
+	// +optional
+	OnConflict string `json:"onConflict,omitempty" protobuf:"bytes,6,opt,name=onConflict"`
+	// IDColumn is the column the message ID is written to. Without conflict columns, the rows whose ID is already in
+	// the table are skipped, which makes the writes of the redelivered messages idempotent. The column needs a unique
+	// constraint.
+	// +optional
+	IDColumn string `json:"idColumn,omitempty" protobuf:"bytes,7,opt,name=idColumn"`
+	// ConnectTimeout is the timeout of opening a connection, defaults to 10s.
+	// +optional
+	ConnectTimeout *metav1.Duration `json:"connectTimeout,omitempty" protobuf:"bytes,8,opt,name=connectTimeout"`
+}
+
+// PostgresColumn is a column of the table written by the PostgreSQL sink, its value is read from the JSON payload or
+// the keys of the message.
+type PostgresColumn struct {
+	// Name of the column.
+	Name string `json:"name" protobuf:"bytes,1,opt,name=name"`
+	// Path is the JSON pointer of the value in the payload, e.g. "/user/id", defaults to the name of the column.
+	// +optional
+	Path string `json:"path,omitempty" protobuf:"bytes,2,opt,name=path"`
+	// Key is the index of the key of the message the value is read from, instead of the payload.
+	// +optional
+	Key *int32 `json:"key,omitempty" protobuf:"varint,3,opt,name=key"`
+}
@@ -44,6 +44,39 @@ type RateLimit struct {
 	// +optional
 	// +kubebuilder:default="180s"
 	TTL *metav1.Duration `json:"ttl,omitempty" protobuf:"bytes,7,opt,name=ttl"`
+	// PerKey enables a separate rate limit per key instead of one for the whole vertex.
+	// +optional
+	PerKey *RateLimitPerKey `json:"perKey,omitempty" protobuf:"bytes,8,opt,name=perKey"`
+}
+
+// RateLimitPerKey enables a separate rate limit per key (e.g., per tenant) instead of one for the whole vertex.
+type RateLimitPerKey struct {
+	// Header to derive the key from. Defaults to the keys of the message.
+	// +optional
+	Header string `json:"header,omitempty" protobuf:"bytes,1,opt,name=header"`
+	// MaxKeys is the maximum number of keys to keep a rate limit for, the least recently used key is evicted beyond that.
+	// Defaults to 1000.
+	// +optional
+	MaxKeys *uint64 `json:"maxKeys,omitempty" protobuf:"varint,2,opt,name=maxKeys"`
+	// Overrides of the rate limit of specific keys.
+	// +optional
+	Overrides []RateLimitKeyOverride `json:"overrides,omitempty" protobuf:"bytes,3,rep,name=overrides"`
+}
+
+// RateLimitKeyOverride overrides the rate limit of a specific key.
+type RateLimitKeyOverride struct {
+	// Key the override applies to.
+	Key string `json:"key" protobuf:"bytes,1,opt,name=key"`
+	// Max is the maximum TPS of the key. Defaults to the max of the rate limit.
+	// +optional
+	Max *uint64 `json:"max,omitempty" protobuf:"varint,2,opt,name=max"`
+	// Minimum TPS of the key during initial bootup. Defaults to the min of the rate limit.
+	// +optional
+	Min *uint64 `json:"min,omitempty" protobuf:"varint,3,opt,name=min"`
+	// RampUpDuration is the duration to reach the maximum TPS of the key from the minimum TPS.
+	// Defaults to the ramp up duration of the rate limit.
+	// +optional
+	RampUpDuration *metav1.Duration `json:"rampUpDuration,omitempty" protobuf:"bytes,4,opt,name=rampUpDuration"`
 }
 
 // RateLimiterModes defines the modes for rate limiting.
@@ -125,10 +158,21 @@ type RateLimiterStore struct {
 	// InMemoryStore is used to define the in-memory store for the rate limit.
 	// +optional
 	RateLimiterInMemoryStore *RateLimiterInMemoryStore `json:"inMemoryStore,omitempty" protobuf:"bytes,2,opt,name=inMemoryStore"`
+	// JetStreamStore is used to define the JetStream Key-Value store for the rate limit.
+	// +optional
+	RateLimiterJetStreamStore *RateLimiterJetStreamStore `json:"jetStreamStore,omitempty" protobuf:"bytes,3,opt,name=jetStreamStore"`
 }
 
 type RateLimiterInMemoryStore struct{}
 
+// RateLimiterJetStreamStore uses a JetStream Key-Value bucket of the InterStepBufferService as the store.
+type RateLimiterJetStreamStore struct {
+	// Name of the KV bucket to keep the state of the rate limiter in, created if it does not exist.
+	// Defaults to "numaflow-rate-limiter".
+	// +optional
+	Bucket string `json:"bucket,omitempty" protobuf:"bytes,1,opt,name=bucket"`
+}
+
 type RateLimiterRedisStore struct {
 	// Choose how to connect to Redis.
 	// - Single: use a single URL (redis://... or rediss://...)
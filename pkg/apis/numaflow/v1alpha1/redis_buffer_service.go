@@ -0,0 +1,39 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package v1alpha1
+
+import (
+	corev1 "k8s.io/api/core/v1"
+)
+
+// RedisBufferService is an external Redis server used as the inter-step buffer. It is not managed
+// by the controller, the streams are created by the vertices on demand.
+type RedisBufferService struct {
+	// Redis URL, e.g. "redis://redis.default.svc:6379"
+	URL string `json:"url" protobuf:"bytes,1,opt,name=url"`
+	// Secret for the password of the Redis server
+	// +optional
+	Password *corev1.SecretKeySelector `json:"password,omitempty" protobuf:"bytes,2,opt,name=password"`
+}
+
+type RedisConfig struct {
+	// Redis URL
+	URL string `json:"url,omitempty" protobuf:"bytes,1,opt,name=url"`
+	// Secret for the password of the Redis server
+	// +optional
+	Password *corev1.SecretKeySelector `json:"password,omitempty" protobuf:"bytes,2,opt,name=password"`
+}
@@ -45,6 +45,43 @@ type RetryStrategy struct {
 	// +optional
 	// +kubebuilder:default="retry"
 	OnFailure *OnFailureRetryStrategy `json:"onFailure,omitempty" protobuf:"bytes,2,opt,name=onFailure"`
+	// CircuitBreaker stops writing to the sink while it keeps failing.
+	// +optional
+	CircuitBreaker *CircuitBreaker `json:"circuitBreaker,omitempty" protobuf:"bytes,3,opt,name=circuitBreaker"`
+}
+
+type OnOpenCircuitBreakerStrategy string
+
+// Constants representing the possible actions that can be taken while the circuit breaker is open.
+const (
+	OnOpenPause    OnOpenCircuitBreakerStrategy = "pause"    // Stop writing until the circuit is half-open.
+	OnOpenFallback OnOpenCircuitBreakerStrategy = "fallback" // Reroute the messages to the fallback sink.
+)
+
+// CircuitBreaker stops writing to a sink that keeps failing. It opens when the ratio of the failed writes over a sliding
+// window crosses the threshold, and after the open duration a single probe batch is written (half-open) to decide
+// whether to close it again.
+type CircuitBreaker struct {
+	// FailureRatio is the ratio of failed writes over the window (0 < ratio <= 1) that opens the circuit. Defaults to 0.5.
+	// +optional
+	FailureRatio *float64 `json:"failureRatio,omitempty" protobuf:"bytes,1,opt,name=failureRatio"`
+	// MinWrites is the minimum number of writes in the window before the failure ratio is evaluated. Defaults to 20.
+	// +optional
+	MinWrites *uint64 `json:"minWrites,omitempty" protobuf:"varint,2,opt,name=minWrites"`
+	// Window is the length of the sliding window the failure ratio is computed over, defaults to 60s.
+	// +optional
+	Window *metav1.Duration `json:"window,omitempty" protobuf:"bytes,3,opt,name=window"`
+	// OpenDuration is how long the circuit stays open before a probe batch is written, defaults to 30s.
+	// +optional
+	OpenDuration *metav1.Duration `json:"openDuration,omitempty" protobuf:"bytes,4,opt,name=openDuration"`
+	// OnOpen specifies what to do with the messages while the circuit is open.
+	// The possible values are:
+	// 1. "pause": stop writing (and hence reading) until the circuit is half-open and
+	// 2. "fallback": route the messages to the fallback sink.
+	// The default action is to pause.
+	// +kubebuilder:validation:Enum=pause;fallback
+	// +optional
+	OnOpen *OnOpenCircuitBreakerStrategy `json:"onOpen,omitempty" protobuf:"bytes,5,opt,name=onOpen"`
 }
 
 // Backoff defines parameters used to systematically configure the retry strategy.
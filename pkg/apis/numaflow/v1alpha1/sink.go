@@ -35,6 +35,29 @@ type Sink struct {
 	// RetryStrategy struct encapsulates the settings for retrying operations in the event of failures.
 	// +optional
 	RetryStrategy RetryStrategy `json:"retryStrategy,omitempty" protobuf:"bytes,4,opt,name=retryStrategy"`
+	// DLQHeaders mirrors the dead-letter envelope of the messages routed to the fallback sink into their headers.
+	// The envelope is always added to the system metadata of the messages.
+	// +optional
+	DLQHeaders bool `json:"dlqHeaders,omitempty" protobuf:"varint,5,opt,name=dlqHeaders"`
+	// Broadcast sink is used to write the data to multiple sinks, it is used instead of the sinks of the AbstractSink.
+	// +optional
+	Broadcast *BroadcastSink `json:"broadcast,omitempty" protobuf:"bytes,6,opt,name=broadcast"`
+}
+
+// BroadcastSink writes each message to all of its sinks concurrently.
+type BroadcastSink struct {
+	// Sinks to write the messages to, only builtin sinks are supported.
+	// +kubebuilder:validation:MinItems=1
+	Sinks []AbstractSink `json:"sinks" protobuf:"bytes,1,rep,name=sinks"`
+	// Ack specifies when a message is considered written.
+	// The possible values are:
+	// 1. "all": all the sinks have written it,
+	// 2. "quorum": a majority of the sinks have written it and
+	// 3. "bestEffort": the failures of the sinks are ignored.
+	// The default is all.
+	// +kubebuilder:validation:Enum=all;quorum;bestEffort
+	// +optional
+	Ack string `json:"ack,omitempty" protobuf:"bytes,2,opt,name=ack"`
 }
 
 type AbstractSink struct {
@@ -60,6 +83,24 @@ type AbstractSink struct {
 	// Pulsar sink is used to write the data to the Apache Pulsar.
 	// +optional
 	Pulsar *PulsarSink `json:"pulsar,omitempty" protobuf:"bytes,7,opt,name=pulsar"`
+	// Nats sink is used to publish the data to a NATS subject.
+	// +optional
+	Nats *NatsSink `json:"nats,omitempty" protobuf:"bytes,8,opt,name=nats"`
+	// JetStream sink is used to publish the data to a JetStream stream.
+	// +optional
+	JetStream *JetStreamSink `json:"jetstream,omitempty" protobuf:"bytes,9,opt,name=jetstream"`
+	// HTTP sink is used to send the data to an HTTP endpoint.
+	// +optional
+	HTTP *HTTPSink `json:"http,omitempty" protobuf:"bytes,10,opt,name=http"`
+	// File sink is used to write the data to segment files in a local directory.
+	// +optional
+	File *FileSink `json:"file,omitempty" protobuf:"bytes,11,opt,name=file"`
+	// ObjectStore sink is used to write the data as files to an object store.
+	// +optional
+	ObjectStore *ObjectStoreSink `json:"objectStore,omitempty" protobuf:"bytes,12,opt,name=objectStore"`
+	// Postgres sink is used to write the data to a PostgreSQL table.
+	// +optional
+	Postgres *PostgresSink `json:"postgres,omitempty" protobuf:"bytes,13,opt,name=postgres"`
 }
 
 func (s Sink) getContainers(req getContainerReq) ([]corev1.Container, []corev1.Container, error) {
@@ -193,5 +234,6 @@ func (s Sink) getOnSuccessUDSinkContainer(mainContainerReq getContainerReq) core
 
 // IsAnySinkSpecified returns true if any sink is specified.
 func (a *AbstractSink) IsAnySinkSpecified() bool {
-	return a.Log != nil || a.Kafka != nil || a.Blackhole != nil || a.UDSink != nil || a.Sqs != nil || a.Pulsar != nil
+	return a.Log != nil || a.Kafka != nil || a.Blackhole != nil || a.UDSink != nil || a.Sqs != nil || a.Pulsar != nil ||
+		a.Nats != nil || a.JetStream != nil || a.HTTP != nil || a.File != nil || a.ObjectStore != nil || a.Postgres != nil
 }
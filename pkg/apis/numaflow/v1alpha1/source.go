@@ -42,6 +42,8 @@ type Source struct {
 	Pulsar *PulsarSource `json:"pulsar,omitempty" protobuf:"bytes,9,opt,name=pulsar"`
 	// +optional
 	Sqs *SqsSource `json:"sqs,omitempty" protobuf:"bytes,10,opt,name=sqs"`
+	// +optional
+	File *FileSource `json:"file,omitempty" protobuf:"bytes,11,opt,name=file"`
 }
 
 func (s Source) getContainers(req getContainerReq) ([]corev1.Container, []corev1.Container, error) {
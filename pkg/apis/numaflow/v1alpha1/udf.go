@@ -33,7 +33,11 @@ type UDF struct {
 
 func (in UDF) getContainers(req getContainerReq) ([]corev1.Container, []corev1.Container, error) {
 	monitorContainer := buildMonitorContainer(req)
-	sidecarContainers := []corev1.Container{monitorContainer, in.getUDFContainer(req)}
+	sidecarContainers := []corev1.Container{monitorContainer}
+	// A builtin reducer runs within the main container.
+	if in.GroupBy == nil || in.GroupBy.Builtin == nil {
+		sidecarContainers = append(sidecarContainers, in.getUDFContainer(req))
+	}
 	return sidecarContainers, []corev1.Container{in.getMainContainer(req)}, nil
 }
 
@@ -105,6 +109,53 @@ type GroupBy struct {
 	AllowedLateness *metav1.Duration `json:"allowedLateness,omitempty" protobuf:"bytes,3,opt,name=allowedLateness"`
 	// Storage is used to define the PBQ storage for a reduce vertex.
 	Storage *PBQStorage `json:"storage,omitempty" protobuf:"bytes,4,opt,name=storage"`
+	// Builtin is a reducer running within the numa container, it is used instead of a user-defined reduce container.
+	// +optional
+	Builtin *BuiltinReducer `json:"builtin,omitempty" protobuf:"bytes,5,opt,name=builtin"`
+	// LateData routes the messages which are too late for their window to a side output instead of dropping them.
+	// +optional
+	LateData *LateData `json:"lateData,omitempty" protobuf:"bytes,6,opt,name=lateData"`
+	// Trigger fires intermediate results of fixed and sliding windows before they are closed.
+	// +optional
+	Trigger *WindowTrigger `json:"trigger,omitempty" protobuf:"bytes,7,opt,name=trigger"`
+}
+
+// WindowTrigger fires intermediate results (panes) of fixed and sliding windows before they are closed by the watermark.
+type WindowTrigger struct {
+	// Count fires a pane of a key every time the key receives this number of messages.
+	// +optional
+	Count *uint64 `json:"count,omitempty" protobuf:"varint,1,opt,name=count"`
+	// Interval fires a pane of every key with new messages at this processing-time interval.
+	// +optional
+	Interval *metav1.Duration `json:"interval,omitempty" protobuf:"bytes,2,opt,name=interval"`
+	// LateFirings fires a pane of every key once the watermark passes the end of the window, and a pane for every late
+	// message until the window is closed after the allowed lateness. Requires allowedLateness.
+	// +optional
+	LateFirings bool `json:"lateFirings,omitempty" protobuf:"varint,3,opt,name=lateFirings"`
+}
+
+// LateData routes the messages which are too late for their window to the edges with the tag in their conditions,
+// instead of dropping them.
+type LateData struct {
+	// Tag of the late messages, it is used in the conditions of the edges which should receive them. Defaults to "late".
+	// +optional
+	Tag string `json:"tag,omitempty" protobuf:"bytes,1,opt,name=tag"`
+}
+
+// BuiltinReducer is a reducer which runs within the numa container, so that no user-defined reduce container is required.
+type BuiltinReducer struct {
+	// Aggregation of the window, one of count, sum, min, max, distinct or quantiles.
+	// +kubebuilder:validation:Enum=count;sum;min;max;distinct;quantiles
+	Aggregation string `json:"aggregation" protobuf:"bytes,1,opt,name=aggregation"`
+	// Field of the JSON payload to aggregate, nested fields are separated by dots. Defaults to the whole payload.
+	// +optional
+	Field string `json:"field,omitempty" protobuf:"bytes,2,opt,name=field"`
+	// Quantiles computed by quantiles, e.g. "0.5", "0.99".
+	// +optional
+	Quantiles []string `json:"quantiles,omitempty" protobuf:"bytes,3,rep,name=quantiles"`
+	// Precision of the HyperLogLog sketch used by distinct, between 4 and 16. Defaults to 14.
+	// +optional
+	Precision *int32 `json:"precision,omitempty" protobuf:"varint,4,opt,name=precision"`
 }
 
 // Window describes windowing strategy
@@ -90,6 +90,23 @@ func TestUDF_getContainers(t *testing.T) {
 	assert.Equal(t, int32(5), sc[1].LivenessProbe.FailureThreshold)
 }
 
+func TestUDF_getContainers_builtinReducer(t *testing.T) {
+	x := UDF{
+		GroupBy: &GroupBy{
+			Window:  Window{Fixed: &FixedWindow{}},
+			Builtin: &BuiltinReducer{Aggregation: "count"},
+		},
+	}
+	sc, c, err := x.getContainers(getContainerReq{
+		image:           "main-image",
+		imagePullPolicy: corev1.PullAlways,
+	})
+	assert.NoError(t, err)
+	assert.Equal(t, 1, len(c))
+	assert.Equal(t, 1, len(sc))
+	assert.Equal(t, CtrMonitor, sc[0].Name)
+}
+
 func Test_getUDFContainer(t *testing.T) {
 	t.Run("with customized image", func(t *testing.T) {
 		x := UDF{
@@ -184,6 +184,36 @@ func (in *AbstractSink) DeepCopyInto(out *AbstractSink) {
 		*out = new(PulsarSink)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.Nats != nil {
+		in, out := &in.Nats, &out.Nats
+		*out = new(NatsSink)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.JetStream != nil {
+		in, out := &in.JetStream, &out.JetStream
+		*out = new(JetStreamSink)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.HTTP != nil {
+		in, out := &in.HTTP, &out.HTTP
+		*out = new(HTTPSink)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.File != nil {
+		in, out := &in.File, &out.File
+		*out = new(FileSink)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.ObjectStore != nil {
+		in, out := &in.ObjectStore, &out.ObjectStore
+		*out = new(ObjectStoreSink)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Postgres != nil {
+		in, out := &in.Postgres, &out.Postgres
+		*out = new(PostgresSink)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -308,6 +338,32 @@ func (in *AccumulatorWindow) DeepCopy() *AccumulatorWindow {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *AdaptiveWatermark) DeepCopyInto(out *AdaptiveWatermark) {
+	*out = *in
+	if in.HalfLife != nil {
+		in, out := &in.HalfLife, &out.HalfLife
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.MaxDelay != nil {
+		in, out := &in.MaxDelay, &out.MaxDelay
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new AdaptiveWatermark.
+func (in *AdaptiveWatermark) DeepCopy() *AdaptiveWatermark {
+	if in == nil {
+		return nil
+	}
+	out := new(AdaptiveWatermark)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Authorization) DeepCopyInto(out *Authorization) {
 	*out = *in
@@ -412,6 +468,29 @@ func (in *Blackhole) DeepCopy() *Blackhole {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *BroadcastSink) DeepCopyInto(out *BroadcastSink) {
+	*out = *in
+	if in.Sinks != nil {
+		in, out := &in.Sinks, &out.Sinks
+		*out = make([]AbstractSink, len(*in))
+		for i := range *in {
+			(*in)[i].DeepCopyInto(&(*out)[i])
+		}
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new BroadcastSink.
+func (in *BroadcastSink) DeepCopy() *BroadcastSink {
+	if in == nil {
+		return nil
+	}
+	out := new(BroadcastSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *BufferServiceConfig) DeepCopyInto(out *BufferServiceConfig) {
 	*out = *in
@@ -420,6 +499,11 @@ func (in *BufferServiceConfig) DeepCopyInto(out *BufferServiceConfig) {
 		*out = new(JetStreamConfig)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.Redis != nil {
+		in, out := &in.Redis, &out.Redis
+		*out = new(RedisConfig)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -433,6 +517,73 @@ func (in *BufferServiceConfig) DeepCopy() *BufferServiceConfig {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *BuiltinReducer) DeepCopyInto(out *BuiltinReducer) {
+	*out = *in
+	if in.Quantiles != nil {
+		in, out := &in.Quantiles, &out.Quantiles
+		*out = make([]string, len(*in))
+		copy(*out, *in)
+	}
+	if in.Precision != nil {
+		in, out := &in.Precision, &out.Precision
+		*out = new(int32)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new BuiltinReducer.
+func (in *BuiltinReducer) DeepCopy() *BuiltinReducer {
+	if in == nil {
+		return nil
+	}
+	out := new(BuiltinReducer)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *CircuitBreaker) DeepCopyInto(out *CircuitBreaker) {
+	*out = *in
+	if in.FailureRatio != nil {
+		in, out := &in.FailureRatio, &out.FailureRatio
+		*out = new(float64)
+		**out = **in
+	}
+	if in.MinWrites != nil {
+		in, out := &in.MinWrites, &out.MinWrites
+		*out = new(uint64)
+		**out = **in
+	}
+	if in.Window != nil {
+		in, out := &in.Window, &out.Window
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.OpenDuration != nil {
+		in, out := &in.OpenDuration, &out.OpenDuration
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.OnOpen != nil {
+		in, out := &in.OnOpen, &out.OnOpen
+		*out = new(OnOpenCircuitBreakerStrategy)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new CircuitBreaker.
+func (in *CircuitBreaker) DeepCopy() *CircuitBreaker {
+	if in == nil {
+		return nil
+	}
+	out := new(CircuitBreaker)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *CombinedEdge) DeepCopyInto(out *CombinedEdge) {
 	*out = *in
@@ -478,6 +629,11 @@ func (in *CombinedEdge) DeepCopy() *CombinedEdge {
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Compression) DeepCopyInto(out *Compression) {
 	*out = *in
+	if in.MinSize != nil {
+		in, out := &in.MinSize, &out.MinSize
+		*out = new(int64)
+		**out = **in
+	}
 	return
 }
 
@@ -668,6 +824,48 @@ func (in *Edge) DeepCopy() *Edge {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *FileSink) DeepCopyInto(out *FileSink) {
+	*out = *in
+	if in.MaxSegmentSize != nil {
+		in, out := &in.MaxSegmentSize, &out.MaxSegmentSize
+		*out = new(int64)
+		**out = **in
+	}
+	if in.MaxSegmentAge != nil {
+		in, out := &in.MaxSegmentAge, &out.MaxSegmentAge
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new FileSink.
+func (in *FileSink) DeepCopy() *FileSink {
+	if in == nil {
+		return nil
+	}
+	out := new(FileSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *FileSource) DeepCopyInto(out *FileSource) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new FileSource.
+func (in *FileSource) DeepCopy() *FileSource {
+	if in == nil {
+		return nil
+	}
+	out := new(FileSource)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *FixedWindow) DeepCopyInto(out *FixedWindow) {
 	*out = *in
@@ -1005,6 +1203,21 @@ func (in *GroupBy) DeepCopyInto(out *GroupBy) {
 		*out = new(PBQStorage)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.Builtin != nil {
+		in, out := &in.Builtin, &out.Builtin
+		*out = new(BuiltinReducer)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.LateData != nil {
+		in, out := &in.LateData, &out.LateData
+		*out = new(LateData)
+		**out = **in
+	}
+	if in.Trigger != nil {
+		in, out := &in.Trigger, &out.Trigger
+		*out = new(WindowTrigger)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -1018,6 +1231,72 @@ func (in *GroupBy) DeepCopy() *GroupBy {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *HTTPSink) DeepCopyInto(out *HTTPSink) {
+	*out = *in
+	if in.Headers != nil {
+		in, out := &in.Headers, &out.Headers
+		*out = make(map[string]string, len(*in))
+		for key, val := range *in {
+			(*out)[key] = val
+		}
+	}
+	if in.HeaderMapping != nil {
+		in, out := &in.HeaderMapping, &out.HeaderMapping
+		*out = make(map[string]string, len(*in))
+		for key, val := range *in {
+			(*out)[key] = val
+		}
+	}
+	if in.Timeout != nil {
+		in, out := &in.Timeout, &out.Timeout
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.Auth != nil {
+		in, out := &in.Auth, &out.Auth
+		*out = new(HTTPSinkAuth)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new HTTPSink.
+func (in *HTTPSink) DeepCopy() *HTTPSink {
+	if in == nil {
+		return nil
+	}
+	out := new(HTTPSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *HTTPSinkAuth) DeepCopyInto(out *HTTPSinkAuth) {
+	*out = *in
+	if in.Basic != nil {
+		in, out := &in.Basic, &out.Basic
+		*out = new(BasicAuth)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Bearer != nil {
+		in, out := &in.Bearer, &out.Bearer
+		*out = new(v1.SecretKeySelector)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new HTTPSinkAuth.
+func (in *HTTPSinkAuth) DeepCopy() *HTTPSinkAuth {
+	if in == nil {
+		return nil
+	}
+	out := new(HTTPSinkAuth)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *HTTPSource) DeepCopyInto(out *HTTPSource) {
 	*out = *in
@@ -1039,6 +1318,32 @@ func (in *HTTPSource) DeepCopy() *HTTPSource {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *ISBBatching) DeepCopyInto(out *ISBBatching) {
+	*out = *in
+	if in.MaxMessages != nil {
+		in, out := &in.MaxMessages, &out.MaxMessages
+		*out = new(int32)
+		**out = **in
+	}
+	if in.Linger != nil {
+		in, out := &in.Linger, &out.Linger
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new ISBBatching.
+func (in *ISBBatching) DeepCopy() *ISBBatching {
+	if in == nil {
+		return nil
+	}
+	out := new(ISBBatching)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *IdleSource) DeepCopyInto(out *IdleSource) {
 	*out = *in
@@ -1081,7 +1386,12 @@ func (in *InterStepBuffer) DeepCopyInto(out *InterStepBuffer) {
 	if in.Compression != nil {
 		in, out := &in.Compression, &out.Compression
 		*out = new(Compression)
-		**out = **in
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Batching != nil {
+		in, out := &in.Batching, &out.Batching
+		*out = new(ISBBatching)
+		(*in).DeepCopyInto(*out)
 	}
 	return
 }
@@ -1165,6 +1475,11 @@ func (in *InterStepBufferServiceSpec) DeepCopyInto(out *InterStepBufferServiceSp
 		*out = new(JetStreamBufferService)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.Redis != nil {
+		in, out := &in.Redis, &out.Redis
+		*out = new(RedisBufferService)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -1274,6 +1589,37 @@ func (in *JetStreamConfig) DeepCopy() *JetStreamConfig {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *JetStreamSink) DeepCopyInto(out *JetStreamSink) {
+	*out = *in
+	if in.AckTimeout != nil {
+		in, out := &in.AckTimeout, &out.AckTimeout
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.TLS != nil {
+		in, out := &in.TLS, &out.TLS
+		*out = new(TLS)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Auth != nil {
+		in, out := &in.Auth, &out.Auth
+		*out = new(NatsAuth)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new JetStreamSink.
+func (in *JetStreamSink) DeepCopy() *JetStreamSink {
+	if in == nil {
+		return nil
+	}
+	out := new(JetStreamSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *JetStreamSource) DeepCopyInto(out *JetStreamSource) {
 	*out = *in
@@ -1399,6 +1745,22 @@ func (in *KafkaSource) DeepCopy() *KafkaSource {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *LateData) DeepCopyInto(out *LateData) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new LateData.
+func (in *LateData) DeepCopy() *LateData {
+	if in == nil {
+		return nil
+	}
+	out := new(LateData)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Lifecycle) DeepCopyInto(out *Lifecycle) {
 	*out = *in
@@ -1430,6 +1792,22 @@ func (in *Lifecycle) DeepCopy() *Lifecycle {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *LocalObjectStore) DeepCopyInto(out *LocalObjectStore) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new LocalObjectStore.
+func (in *LocalObjectStore) DeepCopy() *LocalObjectStore {
+	if in == nil {
+		return nil
+	}
+	out := new(LocalObjectStore)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Log) DeepCopyInto(out *Log) {
 	*out = *in
@@ -1746,6 +2124,32 @@ func (in *NatsAuth) DeepCopy() *NatsAuth {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *NatsSink) DeepCopyInto(out *NatsSink) {
+	*out = *in
+	if in.TLS != nil {
+		in, out := &in.TLS, &out.TLS
+		*out = new(TLS)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Auth != nil {
+		in, out := &in.Auth, &out.Auth
+		*out = new(NatsAuth)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new NatsSink.
+func (in *NatsSink) DeepCopy() *NatsSink {
+	if in == nil {
+		return nil
+	}
+	out := new(NatsSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *NatsSource) DeepCopyInto(out *NatsSource) {
 	*out = *in
@@ -1762,28 +2166,95 @@ func (in *NatsSource) DeepCopyInto(out *NatsSource) {
 	return
 }
 
-// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new NatsSource.
-func (in *NatsSource) DeepCopy() *NatsSource {
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new NatsSource.
+func (in *NatsSource) DeepCopy() *NatsSource {
+	if in == nil {
+		return nil
+	}
+	out := new(NatsSource)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *NoStore) DeepCopyInto(out *NoStore) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new NoStore.
+func (in *NoStore) DeepCopy() *NoStore {
+	if in == nil {
+		return nil
+	}
+	out := new(NoStore)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *ObjectStoreColumn) DeepCopyInto(out *ObjectStoreColumn) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new ObjectStoreColumn.
+func (in *ObjectStoreColumn) DeepCopy() *ObjectStoreColumn {
 	if in == nil {
 		return nil
 	}
-	out := new(NatsSource)
+	out := new(ObjectStoreColumn)
 	in.DeepCopyInto(out)
 	return out
 }
 
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
-func (in *NoStore) DeepCopyInto(out *NoStore) {
+func (in *ObjectStoreSink) DeepCopyInto(out *ObjectStoreSink) {
 	*out = *in
+	if in.S3 != nil {
+		in, out := &in.S3, &out.S3
+		*out = new(S3ObjectStore)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Local != nil {
+		in, out := &in.Local, &out.Local
+		*out = new(LocalObjectStore)
+		**out = **in
+	}
+	if in.Columns != nil {
+		in, out := &in.Columns, &out.Columns
+		*out = make([]ObjectStoreColumn, len(*in))
+		copy(*out, *in)
+	}
+	if in.PartitionWindow != nil {
+		in, out := &in.PartitionWindow, &out.PartitionWindow
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	if in.MaxFileSize != nil {
+		in, out := &in.MaxFileSize, &out.MaxFileSize
+		*out = new(int64)
+		**out = **in
+	}
+	if in.MaxFileMessages != nil {
+		in, out := &in.MaxFileMessages, &out.MaxFileMessages
+		*out = new(int64)
+		**out = **in
+	}
+	if in.MaxFileAge != nil {
+		in, out := &in.MaxFileAge, &out.MaxFileAge
+		*out = new(metav1.Duration)
+		**out = **in
+	}
 	return
 }
 
-// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new NoStore.
-func (in *NoStore) DeepCopy() *NoStore {
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new ObjectStoreSink.
+func (in *ObjectStoreSink) DeepCopy() *ObjectStoreSink {
 	if in == nil {
 		return nil
 	}
-	out := new(NoStore)
+	out := new(ObjectStoreSink)
 	in.DeepCopyInto(out)
 	return out
 }
@@ -1835,6 +2306,32 @@ func (in *PBQStorage) DeepCopy() *PBQStorage {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *PercentileWatermark) DeepCopyInto(out *PercentileWatermark) {
+	*out = *in
+	if in.Percentile != nil {
+		in, out := &in.Percentile, &out.Percentile
+		*out = new(int32)
+		**out = **in
+	}
+	if in.Window != nil {
+		in, out := &in.Window, &out.Window
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new PercentileWatermark.
+func (in *PercentileWatermark) DeepCopy() *PercentileWatermark {
+	if in == nil {
+		return nil
+	}
+	out := new(PercentileWatermark)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *PersistenceStrategy) DeepCopyInto(out *PersistenceStrategy) {
 	*out = *in
@@ -2101,6 +2598,65 @@ func (in *Ports) DeepCopy() *Ports {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *PostgresColumn) DeepCopyInto(out *PostgresColumn) {
+	*out = *in
+	if in.Key != nil {
+		in, out := &in.Key, &out.Key
+		*out = new(int32)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new PostgresColumn.
+func (in *PostgresColumn) DeepCopy() *PostgresColumn {
+	if in == nil {
+		return nil
+	}
+	out := new(PostgresColumn)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *PostgresSink) DeepCopyInto(out *PostgresSink) {
+	*out = *in
+	if in.PasswordSecret != nil {
+		in, out := &in.PasswordSecret, &out.PasswordSecret
+		*out = new(v1.SecretKeySelector)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Columns != nil {
+		in, out := &in.Columns, &out.Columns
+		*out = make([]PostgresColumn, len(*in))
+		for i := range *in {
+			(*in)[i].DeepCopyInto(&(*out)[i])
+		}
+	}
+	if in.ConflictColumns != nil {
+		in, out := &in.ConflictColumns, &out.ConflictColumns
+		*out = make([]string, len(*in))
+		copy(*out, *in)
+	}
+	if in.ConnectTimeout != nil {
+		in, out := &in.ConnectTimeout, &out.ConnectTimeout
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new PostgresSink.
+func (in *PostgresSink) DeepCopy() *PostgresSink {
+	if in == nil {
+		return nil
+	}
+	out := new(PostgresSink)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Probe) DeepCopyInto(out *Probe) {
 	*out = *in
@@ -2236,6 +2792,22 @@ func (in *PulsarSource) DeepCopy() *PulsarSource {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *PunctuationWatermark) DeepCopyInto(out *PunctuationWatermark) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new PunctuationWatermark.
+func (in *PunctuationWatermark) DeepCopy() *PunctuationWatermark {
+	if in == nil {
+		return nil
+	}
+	out := new(PunctuationWatermark)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *RateLimit) DeepCopyInto(out *RateLimit) {
 	*out = *in
@@ -2274,6 +2846,11 @@ func (in *RateLimit) DeepCopyInto(out *RateLimit) {
 		*out = new(metav1.Duration)
 		**out = **in
 	}
+	if in.PerKey != nil {
+		in, out := &in.PerKey, &out.PerKey
+		*out = new(RateLimitPerKey)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -2287,6 +2864,65 @@ func (in *RateLimit) DeepCopy() *RateLimit {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *RateLimitKeyOverride) DeepCopyInto(out *RateLimitKeyOverride) {
+	*out = *in
+	if in.Max != nil {
+		in, out := &in.Max, &out.Max
+		*out = new(uint64)
+		**out = **in
+	}
+	if in.Min != nil {
+		in, out := &in.Min, &out.Min
+		*out = new(uint64)
+		**out = **in
+	}
+	if in.RampUpDuration != nil {
+		in, out := &in.RampUpDuration, &out.RampUpDuration
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new RateLimitKeyOverride.
+func (in *RateLimitKeyOverride) DeepCopy() *RateLimitKeyOverride {
+	if in == nil {
+		return nil
+	}
+	out := new(RateLimitKeyOverride)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *RateLimitPerKey) DeepCopyInto(out *RateLimitPerKey) {
+	*out = *in
+	if in.MaxKeys != nil {
+		in, out := &in.MaxKeys, &out.MaxKeys
+		*out = new(uint64)
+		**out = **in
+	}
+	if in.Overrides != nil {
+		in, out := &in.Overrides, &out.Overrides
+		*out = make([]RateLimitKeyOverride, len(*in))
+		for i := range *in {
+			(*in)[i].DeepCopyInto(&(*out)[i])
+		}
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new RateLimitPerKey.
+func (in *RateLimitPerKey) DeepCopy() *RateLimitPerKey {
+	if in == nil {
+		return nil
+	}
+	out := new(RateLimitPerKey)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *RateLimiterGoBackN) DeepCopyInto(out *RateLimiterGoBackN) {
 	*out = *in
@@ -2334,6 +2970,22 @@ func (in *RateLimiterInMemoryStore) DeepCopy() *RateLimiterInMemoryStore {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *RateLimiterJetStreamStore) DeepCopyInto(out *RateLimiterJetStreamStore) {
+	*out = *in
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new RateLimiterJetStreamStore.
+func (in *RateLimiterJetStreamStore) DeepCopy() *RateLimiterJetStreamStore {
+	if in == nil {
+		return nil
+	}
+	out := new(RateLimiterJetStreamStore)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *RateLimiterModes) DeepCopyInto(out *RateLimiterModes) {
 	*out = *in
@@ -2467,6 +3119,11 @@ func (in *RateLimiterStore) DeepCopyInto(out *RateLimiterStore) {
 		*out = new(RateLimiterInMemoryStore)
 		**out = **in
 	}
+	if in.RateLimiterJetStreamStore != nil {
+		in, out := &in.RateLimiterJetStreamStore, &out.RateLimiterJetStreamStore
+		*out = new(RateLimiterJetStreamStore)
+		**out = **in
+	}
 	return
 }
 
@@ -2506,6 +3163,48 @@ func (in *RedisAuth) DeepCopy() *RedisAuth {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *RedisBufferService) DeepCopyInto(out *RedisBufferService) {
+	*out = *in
+	if in.Password != nil {
+		in, out := &in.Password, &out.Password
+		*out = new(v1.SecretKeySelector)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new RedisBufferService.
+func (in *RedisBufferService) DeepCopy() *RedisBufferService {
+	if in == nil {
+		return nil
+	}
+	out := new(RedisBufferService)
+	in.DeepCopyInto(out)
+	return out
+}
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *RedisConfig) DeepCopyInto(out *RedisConfig) {
+	*out = *in
+	if in.Password != nil {
+		in, out := &in.Password, &out.Password
+		*out = new(v1.SecretKeySelector)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new RedisConfig.
+func (in *RedisConfig) DeepCopy() *RedisConfig {
+	if in == nil {
+		return nil
+	}
+	out := new(RedisConfig)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *RedisSentinelConfig) DeepCopyInto(out *RedisSentinelConfig) {
 	*out = *in
@@ -2560,6 +3259,11 @@ func (in *RetryStrategy) DeepCopyInto(out *RetryStrategy) {
 		*out = new(OnFailureRetryStrategy)
 		**out = **in
 	}
+	if in.CircuitBreaker != nil {
+		in, out := &in.CircuitBreaker, &out.CircuitBreaker
+		*out = new(CircuitBreaker)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -2594,6 +3298,27 @@ func (in *RollingUpdateStrategy) DeepCopy() *RollingUpdateStrategy {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *S3ObjectStore) DeepCopyInto(out *S3ObjectStore) {
+	*out = *in
+	if in.AssumeRole != nil {
+		in, out := &in.AssumeRole, &out.AssumeRole
+		*out = new(AWSAssumeRole)
+		(*in).DeepCopyInto(*out)
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new S3ObjectStore.
+func (in *S3ObjectStore) DeepCopy() *S3ObjectStore {
+	if in == nil {
+		return nil
+	}
+	out := new(S3ObjectStore)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *SASL) DeepCopyInto(out *SASL) {
 	*out = *in
@@ -3082,6 +3807,11 @@ func (in *Sink) DeepCopyInto(out *Sink) {
 		(*in).DeepCopyInto(*out)
 	}
 	in.RetryStrategy.DeepCopyInto(&out.RetryStrategy)
+	if in.Broadcast != nil {
+		in, out := &in.Broadcast, &out.Broadcast
+		*out = new(BroadcastSink)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -3174,6 +3904,11 @@ func (in *Source) DeepCopyInto(out *Source) {
 		*out = new(SqsSource)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.File != nil {
+		in, out := &in.File, &out.File
+		*out = new(FileSource)
+		**out = **in
+	}
 	return
 }
 
@@ -3725,6 +4460,11 @@ func (in *Watermark) DeepCopyInto(out *Watermark) {
 		*out = new(IdleSource)
 		(*in).DeepCopyInto(*out)
 	}
+	if in.Strategy != nil {
+		in, out := &in.Strategy, &out.Strategy
+		*out = new(WatermarkStrategy)
+		(*in).DeepCopyInto(*out)
+	}
 	return
 }
 
@@ -3738,6 +4478,37 @@ func (in *Watermark) DeepCopy() *Watermark {
 	return out
 }
 
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *WatermarkStrategy) DeepCopyInto(out *WatermarkStrategy) {
+	*out = *in
+	if in.Percentile != nil {
+		in, out := &in.Percentile, &out.Percentile
+		*out = new(PercentileWatermark)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Adaptive != nil {
+		in, out := &in.Adaptive, &out.Adaptive
+		*out = new(AdaptiveWatermark)
+		(*in).DeepCopyInto(*out)
+	}
+	if in.Punctuation != nil {
+		in, out := &in.Punctuation, &out.Punctuation
+		*out = new(PunctuationWatermark)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new WatermarkStrategy.
+func (in *WatermarkStrategy) DeepCopy() *WatermarkStrategy {
+	if in == nil {
+		return nil
+	}
+	out := new(WatermarkStrategy)
+	in.DeepCopyInto(out)
+	return out
+}
+
 // DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
 func (in *Window) DeepCopyInto(out *Window) {
 	*out = *in
@@ -3773,3 +4544,29 @@ func (in *Window) DeepCopy() *Window {
 	in.DeepCopyInto(out)
 	return out
 }
+
+// DeepCopyInto is an autogenerated deepcopy function, copying the receiver, writing into out. in must be non-nil.
+func (in *WindowTrigger) DeepCopyInto(out *WindowTrigger) {
+	*out = *in
+	if in.Count != nil {
+		in, out := &in.Count, &out.Count
+		*out = new(uint64)
+		**out = **in
+	}
+	if in.Interval != nil {
+		in, out := &in.Interval, &out.Interval
+		*out = new(metav1.Duration)
+		**out = **in
+	}
+	return
+}
+
+// DeepCopy is an autogenerated deepcopy function, copying the receiver, creating a new WindowTrigger.
+func (in *WindowTrigger) DeepCopy() *WindowTrigger {
+	if in == nil {
+		return nil
+	}
+	out := new(WindowTrigger)
+	in.DeepCopyInto(out)
+	return out
+}
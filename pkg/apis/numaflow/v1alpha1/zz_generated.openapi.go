@@ -33,17 +33,23 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractSink":                     schema_pkg_apis_numaflow_v1alpha1_AbstractSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractVertex":                   schema_pkg_apis_numaflow_v1alpha1_AbstractVertex(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AccumulatorWindow":                schema_pkg_apis_numaflow_v1alpha1_AccumulatorWindow(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AdaptiveWatermark":                schema_pkg_apis_numaflow_v1alpha1_AdaptiveWatermark(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Authorization":                    schema_pkg_apis_numaflow_v1alpha1_Authorization(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Backoff":                          schema_pkg_apis_numaflow_v1alpha1_Backoff(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BasicAuth":                        schema_pkg_apis_numaflow_v1alpha1_BasicAuth(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Blackhole":                        schema_pkg_apis_numaflow_v1alpha1_Blackhole(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BroadcastSink":                    schema_pkg_apis_numaflow_v1alpha1_BroadcastSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BufferServiceConfig":              schema_pkg_apis_numaflow_v1alpha1_BufferServiceConfig(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BuiltinReducer":                   schema_pkg_apis_numaflow_v1alpha1_BuiltinReducer(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.CircuitBreaker":                   schema_pkg_apis_numaflow_v1alpha1_CircuitBreaker(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.CombinedEdge":                     schema_pkg_apis_numaflow_v1alpha1_CombinedEdge(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Compression":                      schema_pkg_apis_numaflow_v1alpha1_Compression(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Container":                        schema_pkg_apis_numaflow_v1alpha1_Container(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ContainerTemplate":                schema_pkg_apis_numaflow_v1alpha1_ContainerTemplate(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.DaemonTemplate":                   schema_pkg_apis_numaflow_v1alpha1_DaemonTemplate(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Edge":                             schema_pkg_apis_numaflow_v1alpha1_Edge(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSink":                         schema_pkg_apis_numaflow_v1alpha1_FileSink(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSource":                       schema_pkg_apis_numaflow_v1alpha1_FileSource(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FixedWindow":                      schema_pkg_apis_numaflow_v1alpha1_FixedWindow(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ForwardConditions":                schema_pkg_apis_numaflow_v1alpha1_ForwardConditions(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GSSAPI":                           schema_pkg_apis_numaflow_v1alpha1_GSSAPI(ref),
@@ -57,7 +63,10 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GetSideInputDeploymentReq":        schema_pkg_apis_numaflow_v1alpha1_GetSideInputDeploymentReq(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GetVertexPodSpecReq":              schema_pkg_apis_numaflow_v1alpha1_GetVertexPodSpecReq(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GroupBy":                          schema_pkg_apis_numaflow_v1alpha1_GroupBy(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSink":                         schema_pkg_apis_numaflow_v1alpha1_HTTPSink(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSinkAuth":                     schema_pkg_apis_numaflow_v1alpha1_HTTPSinkAuth(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSource":                       schema_pkg_apis_numaflow_v1alpha1_HTTPSource(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ISBBatching":                      schema_pkg_apis_numaflow_v1alpha1_ISBBatching(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.IdleSource":                       schema_pkg_apis_numaflow_v1alpha1_IdleSource(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.InterStepBuffer":                  schema_pkg_apis_numaflow_v1alpha1_InterStepBuffer(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.InterStepBufferService":           schema_pkg_apis_numaflow_v1alpha1_InterStepBufferService(ref),
@@ -66,11 +75,14 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.InterStepBufferServiceStatus":     schema_pkg_apis_numaflow_v1alpha1_InterStepBufferServiceStatus(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamBufferService":           schema_pkg_apis_numaflow_v1alpha1_JetStreamBufferService(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamConfig":                  schema_pkg_apis_numaflow_v1alpha1_JetStreamConfig(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSink":                    schema_pkg_apis_numaflow_v1alpha1_JetStreamSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSource":                  schema_pkg_apis_numaflow_v1alpha1_JetStreamSource(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JobTemplate":                      schema_pkg_apis_numaflow_v1alpha1_JobTemplate(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSink":                        schema_pkg_apis_numaflow_v1alpha1_KafkaSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSource":                      schema_pkg_apis_numaflow_v1alpha1_KafkaSource(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LateData":                         schema_pkg_apis_numaflow_v1alpha1_LateData(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Lifecycle":                        schema_pkg_apis_numaflow_v1alpha1_Lifecycle(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LocalObjectStore":                 schema_pkg_apis_numaflow_v1alpha1_LocalObjectStore(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Log":                              schema_pkg_apis_numaflow_v1alpha1_Log(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Metadata":                         schema_pkg_apis_numaflow_v1alpha1_Metadata(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.MonoVertex":                       schema_pkg_apis_numaflow_v1alpha1_MonoVertex(ref),
@@ -81,10 +93,14 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.MonoVertexSpec":                   schema_pkg_apis_numaflow_v1alpha1_MonoVertexSpec(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.MonoVertexStatus":                 schema_pkg_apis_numaflow_v1alpha1_MonoVertexStatus(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsAuth":                         schema_pkg_apis_numaflow_v1alpha1_NatsAuth(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSink":                         schema_pkg_apis_numaflow_v1alpha1_NatsSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSource":                       schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NoStore":                          schema_pkg_apis_numaflow_v1alpha1_NoStore(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreColumn":                schema_pkg_apis_numaflow_v1alpha1_ObjectStoreColumn(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreSink":                  schema_pkg_apis_numaflow_v1alpha1_ObjectStoreSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Ordered":                          schema_pkg_apis_numaflow_v1alpha1_Ordered(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PBQStorage":                       schema_pkg_apis_numaflow_v1alpha1_PBQStorage(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PercentileWatermark":              schema_pkg_apis_numaflow_v1alpha1_PercentileWatermark(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PersistenceStrategy":              schema_pkg_apis_numaflow_v1alpha1_PersistenceStrategy(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Pipeline":                         schema_pkg_apis_numaflow_v1alpha1_Pipeline(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PipelineLimits":                   schema_pkg_apis_numaflow_v1alpha1_PipelineLimits(ref),
@@ -92,14 +108,20 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PipelineSpec":                     schema_pkg_apis_numaflow_v1alpha1_PipelineSpec(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PipelineStatus":                   schema_pkg_apis_numaflow_v1alpha1_PipelineStatus(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Ports":                            schema_pkg_apis_numaflow_v1alpha1_Ports(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresColumn":                   schema_pkg_apis_numaflow_v1alpha1_PostgresColumn(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresSink":                     schema_pkg_apis_numaflow_v1alpha1_PostgresSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Probe":                            schema_pkg_apis_numaflow_v1alpha1_Probe(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarAuth":                       schema_pkg_apis_numaflow_v1alpha1_PulsarAuth(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarBasicAuth":                  schema_pkg_apis_numaflow_v1alpha1_PulsarBasicAuth(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink":                       schema_pkg_apis_numaflow_v1alpha1_PulsarSink(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSource":                     schema_pkg_apis_numaflow_v1alpha1_PulsarSource(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PunctuationWatermark":             schema_pkg_apis_numaflow_v1alpha1_PunctuationWatermark(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimit":                        schema_pkg_apis_numaflow_v1alpha1_RateLimit(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitKeyOverride":             schema_pkg_apis_numaflow_v1alpha1_RateLimitKeyOverride(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitPerKey":                  schema_pkg_apis_numaflow_v1alpha1_RateLimitPerKey(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterGoBackN":               schema_pkg_apis_numaflow_v1alpha1_RateLimiterGoBackN(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterInMemoryStore":         schema_pkg_apis_numaflow_v1alpha1_RateLimiterInMemoryStore(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterJetStreamStore":        schema_pkg_apis_numaflow_v1alpha1_RateLimiterJetStreamStore(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterModes":                 schema_pkg_apis_numaflow_v1alpha1_RateLimiterModes(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterOnlyIfUsed":            schema_pkg_apis_numaflow_v1alpha1_RateLimiterOnlyIfUsed(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterRedisStore":            schema_pkg_apis_numaflow_v1alpha1_RateLimiterRedisStore(ref),
@@ -107,9 +129,12 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterScheduled":             schema_pkg_apis_numaflow_v1alpha1_RateLimiterScheduled(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterStore":                 schema_pkg_apis_numaflow_v1alpha1_RateLimiterStore(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisAuth":                        schema_pkg_apis_numaflow_v1alpha1_RedisAuth(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisBufferService":               schema_pkg_apis_numaflow_v1alpha1_RedisBufferService(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisConfig":                      schema_pkg_apis_numaflow_v1alpha1_RedisConfig(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisSentinelConfig":              schema_pkg_apis_numaflow_v1alpha1_RedisSentinelConfig(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RetryStrategy":                    schema_pkg_apis_numaflow_v1alpha1_RetryStrategy(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RollingUpdateStrategy":            schema_pkg_apis_numaflow_v1alpha1_RollingUpdateStrategy(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.S3ObjectStore":                    schema_pkg_apis_numaflow_v1alpha1_S3ObjectStore(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SASL":                             schema_pkg_apis_numaflow_v1alpha1_SASL(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SASLOAuth":                        schema_pkg_apis_numaflow_v1alpha1_SASLOAuth(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SASLPlain":                        schema_pkg_apis_numaflow_v1alpha1_SASLPlain(ref),
@@ -149,7 +174,9 @@ func GetOpenAPIDefinitions(ref common.ReferenceCallback) map[string]common.OpenA
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.VertexStatus":                     schema_pkg_apis_numaflow_v1alpha1_VertexStatus(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.VertexTemplate":                   schema_pkg_apis_numaflow_v1alpha1_VertexTemplate(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Watermark":                        schema_pkg_apis_numaflow_v1alpha1_Watermark(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WatermarkStrategy":                schema_pkg_apis_numaflow_v1alpha1_WatermarkStrategy(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Window":                           schema_pkg_apis_numaflow_v1alpha1_Window(ref),
+		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WindowTrigger":                    schema_pkg_apis_numaflow_v1alpha1_WindowTrigger(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.containerBuilder":                 schema_pkg_apis_numaflow_v1alpha1_containerBuilder(ref),
 		"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.getContainerReq":                  schema_pkg_apis_numaflow_v1alpha1_getContainerReq(ref),
 	}
@@ -419,11 +446,47 @@ func schema_pkg_apis_numaflow_v1alpha1_AbstractSink(ref common.ReferenceCallback
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink"),
 						},
 					},
+					"nats": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Nats sink is used to publish the data to a NATS subject.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSink"),
+						},
+					},
+					"jetstream": {
+						SchemaProps: spec.SchemaProps{
+							Description: "JetStream sink is used to publish the data to a JetStream stream.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSink"),
+						},
+					},
+					"http": {
+						SchemaProps: spec.SchemaProps{
+							Description: "HTTP sink is used to send the data to an HTTP endpoint.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSink"),
+						},
+					},
+					"file": {
+						SchemaProps: spec.SchemaProps{
+							Description: "File sink is used to write the data to segment files in a local directory.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSink"),
+						},
+					},
+					"objectStore": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ObjectStore sink is used to write the data as files to an object store.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreSink"),
+						},
+					},
+					"postgres": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Postgres sink is used to write the data to a PostgreSQL table.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresSink"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Blackhole", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Log", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServeSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSink"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Blackhole", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Log", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServeSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSink"},
 	}
 }
 
@@ -734,6 +797,33 @@ func schema_pkg_apis_numaflow_v1alpha1_AccumulatorWindow(ref common.ReferenceCal
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_AdaptiveWatermark(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "AdaptiveWatermark holds back the watermark of every partition by a delay which grows to cover the late messages of the partition and decays while messages arrive in order.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"halfLife": {
+						SchemaProps: spec.SchemaProps{
+							Description: "HalfLife is the time it takes for the delay to halve while the messages arrive in order, defaults to 1m.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"maxDelay": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxDelay caps the delay of the watermark, unbounded by default.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_Authorization(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -840,6 +930,43 @@ func schema_pkg_apis_numaflow_v1alpha1_Blackhole(ref common.ReferenceCallback) c
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_BroadcastSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "BroadcastSink writes each message to all of its sinks concurrently.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"sinks": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Sinks to write the messages to, only builtin sinks are supported.",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: map[string]interface{}{},
+										Ref:     ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractSink"),
+									},
+								},
+							},
+						},
+					},
+					"ack": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Ack specifies when a message is considered written. The possible values are: 1. \"all\": all the sinks have written it, 2. \"quorum\": a majority of the sinks have written it and 3. \"bestEffort\": the failures of the sinks are ignored. The default is all.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+				Required: []string{"sinks"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractSink"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_BufferServiceConfig(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -851,11 +978,115 @@ func schema_pkg_apis_numaflow_v1alpha1_BufferServiceConfig(ref common.ReferenceC
 							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamConfig"),
 						},
 					},
+					"redis": {
+						SchemaProps: spec.SchemaProps{
+							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisConfig"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamConfig", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisConfig"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_BuiltinReducer(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "BuiltinReducer is a reducer which runs within the numa container, so that no user-defined reduce container is required.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"aggregation": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Aggregation of the window, one of count, sum, min, max, distinct or quantiles.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"field": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Field of the JSON payload to aggregate, nested fields are separated by dots. Defaults to the whole payload.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"quantiles": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Quantiles computed by quantiles, e.g. \"0.5\", \"0.99\".",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: "",
+										Type:    []string{"string"},
+										Format:  "",
+									},
+								},
+							},
+						},
+					},
+					"precision": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Precision of the HyperLogLog sketch used by distinct, between 4 and 16. Defaults to 14.",
+							Type:        []string{"integer"},
+							Format:      "int32",
+						},
+					},
+				},
+				Required: []string{"aggregation"},
+			},
+		},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_CircuitBreaker(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "CircuitBreaker stops writing to a sink that keeps failing. It opens when the ratio of the failed writes over a sliding window crosses the threshold, and after the open duration a single probe batch is written (half-open) to decide whether to close it again.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"failureRatio": {
+						SchemaProps: spec.SchemaProps{
+							Description: "FailureRatio is the ratio of failed writes over the window (0 < ratio <= 1) that opens the circuit. Defaults to 0.5.",
+							Type:        []string{"number"},
+							Format:      "double",
+						},
+					},
+					"minWrites": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MinWrites is the minimum number of writes in the window before the failure ratio is evaluated. Defaults to 20.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"window": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Window is the length of the sliding window the failure ratio is computed over, defaults to 60s.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"openDuration": {
+						SchemaProps: spec.SchemaProps{
+							Description: "OpenDuration is how long the circuit stays open before a probe batch is written, defaults to 30s.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"onOpen": {
+						SchemaProps: spec.SchemaProps{
+							Description: "OnOpen specifies what to do with the messages while the circuit is open. The possible values are: 1. \"pause\": stop writing (and hence reading) until the circuit is half-open and 2. \"fallback\": route the messages to the fallback sink. The default action is to pause.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamConfig"},
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
 	}
 }
 
@@ -962,6 +1193,20 @@ func schema_pkg_apis_numaflow_v1alpha1_Compression(ref common.ReferenceCallback)
 							Format:      "",
 						},
 					},
+					"minSize": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MinSize is the size in bytes below which the messages are written uncompressed.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"dictionaryBucket": {
+						SchemaProps: spec.SchemaProps{
+							Description: "DictionaryBucket is the name of the KV bucket holding the trained zstd dictionaries, keyed by their version. The dictionary with the highest version is used for compressing. Only supported with zstd.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
 				},
 			},
 		},
@@ -1385,6 +1630,86 @@ func schema_pkg_apis_numaflow_v1alpha1_Edge(ref common.ReferenceCallback) common
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_FileSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "FileSink writes the messages to rotating segment files in a local directory, they can be read back with the file source.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"path": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Path of the directory to write the segments to.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"format": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Format of the segments, \"ndjson\" (default) writes one JSON record per line, \"proto\" writes length-delimited protobuf records.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"maxSegmentSize": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxSegmentSize is the maximum size of a segment in bytes before it is rotated, defaults to 64MiB.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"maxSegmentAge": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxSegmentAge is the maximum age of a segment before it is rotated, defaults to 5m.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+				Required: []string{"path"},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_FileSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "FileSource reads the segment files written by the file sink from a local directory.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"path": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Path of the directory to read the segments from.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"format": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Format of the segments, \"ndjson\" (default) or \"proto\". It has to match the format of the file sink which wrote the segments.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"checkpointPath": {
+						SchemaProps: spec.SchemaProps{
+							Description: "CheckpointPath is the file where the read progress is persisted, defaults to \".checkpoint.json\" in the segment directory.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+				Required: []string{"path"},
+			},
+		},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_FixedWindow(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -2107,45 +2432,207 @@ func schema_pkg_apis_numaflow_v1alpha1_GroupBy(ref common.ReferenceCallback) com
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PBQStorage"),
 						},
 					},
+					"builtin": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Builtin is a reducer running within the numa container, it is used instead of a user-defined reduce container.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BuiltinReducer"),
+						},
+					},
+					"lateData": {
+						SchemaProps: spec.SchemaProps{
+							Description: "LateData routes the messages which are too late for their window to a side output instead of dropping them.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LateData"),
+						},
+					},
+					"trigger": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Trigger fires intermediate results of fixed and sliding windows before they are closed.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WindowTrigger"),
+						},
+					},
 				},
 				Required: []string{"window"},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PBQStorage", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Window", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BuiltinReducer", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LateData", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PBQStorage", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Window", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WindowTrigger", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
 	}
 }
 
-func schema_pkg_apis_numaflow_v1alpha1_HTTPSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
+func schema_pkg_apis_numaflow_v1alpha1_HTTPSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
 			SchemaProps: spec.SchemaProps{
-				Type: []string{"object"},
+				Description: "HTTPSink sends the messages to an HTTP endpoint, either one request per message or as a batch in a single request.",
+				Type:        []string{"object"},
 				Properties: map[string]spec.Schema{
-					"auth": {
+					"url": {
 						SchemaProps: spec.SchemaProps{
-							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Authorization"),
+							Description: "URL of the HTTP endpoint.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
 						},
 					},
-					"service": {
+					"method": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Whether to create a ClusterIP Service",
-							Default:     false,
-							Type:        []string{"boolean"},
+							Description: "Method is the HTTP method of the requests, defaults to POST.",
+							Type:        []string{"string"},
 							Format:      "",
 						},
 					},
-				},
-			},
-		},
-		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Authorization"},
-	}
-}
-
-func schema_pkg_apis_numaflow_v1alpha1_IdleSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
-	return common.OpenAPIDefinition{
-		Schema: spec.Schema{
+					"format": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Format of the request body. \"raw\" (default) sends one request per message with the payload as the body. \"json\" sends the whole batch as a JSON array, and \"ndjson\" sends the whole batch as newline delimited JSON, both require the payloads to be valid JSON.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"headers": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Headers are static headers added to every request.",
+							Type:        []string{"object"},
+							AdditionalProperties: &spec.SchemaOrBool{
+								Allows: true,
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: "",
+										Type:    []string{"string"},
+										Format:  "",
+									},
+								},
+							},
+						},
+					},
+					"headerMapping": {
+						SchemaProps: spec.SchemaProps{
+							Description: "HeaderMapping maps the message header names to the request header names, only the mapped message headers are sent. It is only applied to the \"raw\" format.",
+							Type:        []string{"object"},
+							AdditionalProperties: &spec.SchemaOrBool{
+								Allows: true,
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: "",
+										Type:    []string{"string"},
+										Format:  "",
+									},
+								},
+							},
+						},
+					},
+					"timeout": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Timeout of a request, defaults to 30s.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"auth": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Auth information",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSinkAuth"),
+						},
+					},
+					"insecureSkipVerify": {
+						SchemaProps: spec.SchemaProps{
+							Description: "InsecureSkipVerify skips the verification of the server certificate.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+				},
+				Required: []string{"url"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSinkAuth", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_HTTPSinkAuth(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "HTTPSinkAuth defines how to authenticate the requests of the HTTP sink, only one of them can be specified.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"basic": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Basic auth which contains a username and a password",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BasicAuth"),
+						},
+					},
+					"bearer": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Bearer token auth",
+							Ref:         ref("k8s.io/api/core/v1.SecretKeySelector"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BasicAuth", "k8s.io/api/core/v1.SecretKeySelector"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_HTTPSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Type: []string{"object"},
+				Properties: map[string]spec.Schema{
+					"auth": {
+						SchemaProps: spec.SchemaProps{
+							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Authorization"),
+						},
+					},
+					"service": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Whether to create a ClusterIP Service",
+							Default:     false,
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Authorization"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_ISBBatching(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "ISBBatching packs multiple messages into a single InterStepBuffer message, which is compressed as a whole.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"maxMessages": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxMessages is the maximum number of messages packed into a single InterStepBuffer message, defaults to 100.",
+							Type:        []string{"integer"},
+							Format:      "int32",
+						},
+					},
+					"linger": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Linger is the maximum time a message waits for the batch to fill up before the batch is written, defaults to 5ms.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_IdleSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
 			SchemaProps: spec.SchemaProps{
 				Type: []string{"object"},
 				Properties: map[string]spec.Schema{
@@ -2194,11 +2681,17 @@ func schema_pkg_apis_numaflow_v1alpha1_InterStepBuffer(ref common.ReferenceCallb
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Compression"),
 						},
 					},
+					"batching": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Batching packs multiple messages into a single InterStepBuffer message.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ISBBatching"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Compression"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Compression", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ISBBatching"},
 	}
 }
 
@@ -2309,11 +2802,16 @@ func schema_pkg_apis_numaflow_v1alpha1_InterStepBufferServiceSpec(ref common.Ref
 							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamBufferService"),
 						},
 					},
+					"redis": {
+						SchemaProps: spec.SchemaProps{
+							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisBufferService"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamBufferService"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamBufferService", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RedisBufferService"},
 	}
 }
 
@@ -2650,6 +3148,56 @@ func schema_pkg_apis_numaflow_v1alpha1_JetStreamConfig(ref common.ReferenceCallb
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_JetStreamSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "JetStreamSink publishes messages to a JetStream stream and waits for the publish acks.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"url": {
+						SchemaProps: spec.SchemaProps{
+							Description: "URL to connect to NATS cluster, multiple urls could be separated by comma.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"subject": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Subject to publish the messages to, it should be bound to a stream. It can be a template rendered per message, supported placeholders are `{{key.N}}` (N-th key of the message), `{{keys}}` (all the keys joined by \".\") and `{{header.NAME}}` (value of the header NAME).",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"ackTimeout": {
+						SchemaProps: spec.SchemaProps{
+							Description: "AckTimeout is the maximum time to wait for a publish ack from the JetStream server, defaults to 5s.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"tls": {
+						SchemaProps: spec.SchemaProps{
+							Description: "TLS configuration for the nats client.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.TLS"),
+						},
+					},
+					"auth": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Auth information",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsAuth"),
+						},
+					},
+				},
+				Required: []string{"url", "subject"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsAuth", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.TLS", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_JetStreamSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -2946,6 +3494,13 @@ func schema_pkg_apis_numaflow_v1alpha1_KafkaSink(ref common.ReferenceCallback) c
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SASL"),
 						},
 					},
+					"transactional": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Transactional enables the exactly-once mode of the sink. Every batch is written in a Kafka transaction, and the messages are acknowledged only after the transaction is committed. The transactional.id is derived from the pipeline, vertex and replica, so that a restarted replica fences off its previous incarnation. Consumers should use isolation.level=read_committed to not see the records of aborted transactions.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
 				},
 				Required: []string{"topic"},
 			},
@@ -3021,6 +3576,26 @@ func schema_pkg_apis_numaflow_v1alpha1_KafkaSource(ref common.ReferenceCallback)
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_LateData(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "LateData routes the messages which are too late for their window to the edges with the tag in their conditions, instead of dropping them.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"tag": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Tag of the late messages, it is used in the conditions of the edges which should receive them. Defaults to \"late\".",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+			},
+		},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_Lifecycle(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -3061,6 +3636,28 @@ func schema_pkg_apis_numaflow_v1alpha1_Lifecycle(ref common.ReferenceCallback) c
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_LocalObjectStore(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "LocalObjectStore stores the objects in a local directory, usually a mounted volume.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"path": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Path of the directory to store the objects in.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+				Required: []string{"path"},
+			},
+		},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_Log(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -3715,11 +4312,12 @@ func schema_pkg_apis_numaflow_v1alpha1_NatsAuth(ref common.ReferenceCallback) co
 	}
 }
 
-func schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
+func schema_pkg_apis_numaflow_v1alpha1_NatsSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
 			SchemaProps: spec.SchemaProps{
-				Type: []string{"object"},
+				Description: "NatsSink publishes messages to a NATS subject.",
+				Type:        []string{"object"},
 				Properties: map[string]spec.Schema{
 					"url": {
 						SchemaProps: spec.SchemaProps{
@@ -3731,15 +4329,7 @@ func schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref common.ReferenceCallback)
 					},
 					"subject": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Subject holds the name of the subject onto which messages are published.",
-							Default:     "",
-							Type:        []string{"string"},
-							Format:      "",
-						},
-					},
-					"queue": {
-						SchemaProps: spec.SchemaProps{
-							Description: "Queue is used for queue subscription.",
+							Description: "Subject to publish the messages to. It can be a template rendered per message, supported placeholders are `{{key.N}}` (N-th key of the message), `{{keys}}` (all the keys joined by \".\") and `{{header.NAME}}` (value of the header NAME).",
 							Default:     "",
 							Type:        []string{"string"},
 							Format:      "",
@@ -3758,7 +4348,7 @@ func schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref common.ReferenceCallback)
 						},
 					},
 				},
-				Required: []string{"url", "subject", "queue"},
+				Required: []string{"url", "subject"},
 			},
 		},
 		Dependencies: []string{
@@ -3766,33 +4356,209 @@ func schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref common.ReferenceCallback)
 	}
 }
 
-func schema_pkg_apis_numaflow_v1alpha1_NoStore(ref common.ReferenceCallback) common.OpenAPIDefinition {
-	return common.OpenAPIDefinition{
-		Schema: spec.Schema{
-			SchemaProps: spec.SchemaProps{
-				Description: "NoStore means there will be no persistence storage and there will be data loss during pod restarts. Use this option only if you do not care about correctness (e.g., approx statistics pipeline like sampling rate, etc.).",
-				Type:        []string{"object"},
-			},
-		},
-	}
-}
-
-func schema_pkg_apis_numaflow_v1alpha1_Ordered(ref common.ReferenceCallback) common.OpenAPIDefinition {
+func schema_pkg_apis_numaflow_v1alpha1_NatsSource(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
 			SchemaProps: spec.SchemaProps{
-				Description: "Ordered defines the ordered processing configuration.",
-				Type:        []string{"object"},
+				Type: []string{"object"},
 				Properties: map[string]spec.Schema{
-					"enabled": {
+					"url": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Enabled toggles ordered processing.",
-							Type:        []string{"boolean"},
+							Description: "URL to connect to NATS cluster, multiple urls could be separated by comma.",
+							Default:     "",
+							Type:        []string{"string"},
 							Format:      "",
 						},
 					},
-				},
-			},
+					"subject": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Subject holds the name of the subject onto which messages are published.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"queue": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Queue is used for queue subscription.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"tls": {
+						SchemaProps: spec.SchemaProps{
+							Description: "TLS configuration for the nats client.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.TLS"),
+						},
+					},
+					"auth": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Auth information",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsAuth"),
+						},
+					},
+				},
+				Required: []string{"url", "subject", "queue"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsAuth", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.TLS"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_NoStore(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "NoStore means there will be no persistence storage and there will be data loss during pod restarts. Use this option only if you do not care about correctness (e.g., approx statistics pipeline like sampling rate, etc.).",
+				Type:        []string{"object"},
+			},
+		},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_ObjectStoreColumn(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "ObjectStoreColumn is a column of the files written by the object store sink.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"name": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Name of the column.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"type": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Type of the column, one of \"string\", \"int64\", \"double\", \"boolean\", \"timestamp\" or \"json\".",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"path": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Path is the JSON pointer of the value in the payload, e.g. \"/user/id\", defaults to the name of the column.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+				Required: []string{"name", "type"},
+			},
+		},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_ObjectStoreSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "ObjectStoreSink buffers the messages into files which are uploaded to an object store, the files are partitioned by the event time window and optionally the keys of the messages.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"s3": {
+						SchemaProps: spec.SchemaProps{
+							Description: "S3 is an S3 compatible object store.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.S3ObjectStore"),
+						},
+					},
+					"local": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Local stores the files in a local directory.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LocalObjectStore"),
+						},
+					},
+					"prefix": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Prefix of the object keys.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"format": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Format of the files, \"ndjson\" (default) or \"parquet\".",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"columns": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Columns of the files, the values are read from the JSON payload of the messages. Required for the parquet format, for ndjson the payload is written as is if no columns are specified.",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: map[string]interface{}{},
+										Ref:     ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreColumn"),
+									},
+								},
+							},
+						},
+					},
+					"partitionWindow": {
+						SchemaProps: spec.SchemaProps{
+							Description: "PartitionWindow is the length of the event time windows the files are partitioned by, defaults to 1h.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"partitionByKeys": {
+						SchemaProps: spec.SchemaProps{
+							Description: "PartitionByKeys adds the keys of the messages to the path of the files.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+					"maxFileSize": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxFileSize is the maximum size of the rows of a file in bytes, defaults to 64MiB.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"maxFileMessages": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxFileMessages is the maximum number of messages in a file, defaults to 100000.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"maxFileAge": {
+						SchemaProps: spec.SchemaProps{
+							Description: "MaxFileAge is the maximum time the messages are buffered before the file is uploaded, defaults to 1m.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.LocalObjectStore", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreColumn", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.S3ObjectStore", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_Ordered(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "Ordered defines the ordered processing configuration.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"enabled": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Enabled toggles ordered processing.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+				},
+			},
 		},
 	}
 }
@@ -3827,6 +4593,34 @@ func schema_pkg_apis_numaflow_v1alpha1_PBQStorage(ref common.ReferenceCallback)
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_PercentileWatermark(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "PercentileWatermark holds back the watermark by a percentile of the out-of-orderness of the messages observed over a sliding window.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"percentile": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Percentile of the out-of-orderness used as the delay of the watermark, between 1 and 100, defaults to 99.",
+							Type:        []string{"integer"},
+							Format:      "int32",
+						},
+					},
+					"window": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Window is the length of the sliding window the out-of-orderness is observed over, defaults to 5m.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_PersistenceStrategy(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -4235,6 +5029,129 @@ func schema_pkg_apis_numaflow_v1alpha1_Ports(ref common.ReferenceCallback) commo
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_PostgresColumn(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "PostgresColumn is a column of the table written by the PostgreSQL sink, its value is read from the JSON payload or the keys of the message.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"name": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Name of the column.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"path": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Path is the JSON pointer of the value in the payload, e.g. \"/user/id\", defaults to the name of the column.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"key": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Key is the index of the key of the message the value is read from, instead of the payload.",
+							Type:        []string{"integer"},
+							Format:      "int32",
+						},
+					},
+				},
+				Required: []string{"name"},
+			},
+		},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_PostgresSink(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "PostgresSink writes the messages to a PostgreSQL table, each batch is written with a single INSERT ... ON CONFLICT statement in one transaction.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"url": {
+						SchemaProps: spec.SchemaProps{
+							Description: "URL is the connection string of the database, e.g. \"postgres://user@host:5432/db\".",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"passwordSecret": {
+						SchemaProps: spec.SchemaProps{
+							Description: "PasswordSecret refers to the secret that contains the password of the user.",
+							Ref:         ref("k8s.io/api/core/v1.SecretKeySelector"),
+						},
+					},
+					"table": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Table the rows are written to, optionally qualified by the schema, e.g. \"public.events\".",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"columns": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Columns of the table and where their values are read from.",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: map[string]interface{}{},
+										Ref:     ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresColumn"),
+									},
+								},
+							},
+						},
+					},
+					"conflictColumns": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ConflictColumns are the columns of the unique constraint the rows are upserted on.",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: "",
+										Type:    []string{"string"},
+										Format:  "",
+									},
+								},
+							},
+						},
+					},
+					"onConflict": {
+						SchemaProps: spec.SchemaProps{
+							Description: "OnConflict is what happens to a row whose conflict columns match an existing row, \"update\" (default) updates the other columns and \"nothing\" keeps the existing row.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"idColumn": {
+						SchemaProps: spec.SchemaProps{
+							Description: "IDColumn is the column the message ID is written to. Without conflict columns, the rows whose ID is already in the table are skipped, which makes the writes of the redelivered messages idempotent. The column needs a unique constraint.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"connectTimeout": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ConnectTimeout is the timeout of opening a connection, defaults to 10s.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+				},
+				Required: []string{"url", "table", "columns"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresColumn", "k8s.io/api/core/v1.SecretKeySelector", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_Probe(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -4417,77 +5334,190 @@ func schema_pkg_apis_numaflow_v1alpha1_PulsarSource(ref common.ReferenceCallback
 							Format:      "int64",
 						},
 					},
-					"auth": {
+					"auth": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Auth information",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarAuth"),
+						},
+					},
+				},
+				Required: []string{"serverAddr", "topic", "consumerName", "subscriptionName"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarAuth"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_PunctuationWatermark(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "PunctuationWatermark advances the watermark of a partition only when a message with the marker header is read.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"header": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Header marking the messages which advance the watermark, its value is the new watermark in epoch milliseconds, or the event time of the message if empty. Defaults to \"x-numaflow-watermark\".",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+			},
+		},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_RateLimit(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Type: []string{"object"},
+				Properties: map[string]spec.Schema{
+					"max": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Max is the maximum TPS that this vertex can process give a distributed `Store` is configured. Otherwise, it will be the maximum TPS for a single replica.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"min": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Minimum TPS allowed during initial bootup. This value will be distributed across all the replicas if a distributed `Store` is configured. Otherwise, it will be the minimum TPS for a single replica.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"rampUpDuration": {
+						SchemaProps: spec.SchemaProps{
+							Description: "RampUpDuration is the duration to reach the maximum TPS from the minimum TPS. The min unit of ramp up is 1 in 1 second.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"store": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Store is used to define the Distributed Store for the rate limiting. We also support in-memory store if no store is configured. This means that every replica will have its own rate limit and the actual TPS will be the sum of all the replicas.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterStore"),
+						},
+					},
+					"modes": {
+						SchemaProps: spec.SchemaProps{
+							Description: "RateLimiterModes is used to define the modes for rate limiting.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterModes"),
+						},
+					},
+					"resumedRampUp": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ResumedRampUp is used to enable the resume mode for rate limiting.\n\nThis, if true, will allow the processor to resume the ramp-up process from the last known state of the rate limiter, i.e., if the processor was allowed X tokens before shutting down, it will be allowed X tokens again after the processor restarts.\n\nThe resumed ramp-up process will be allowed until TTL time after the processor first deregisters with the rate limiter.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+					"ttl": {
+						SchemaProps: spec.SchemaProps{
+							Description: "TTL is used to define the duration after which a pod is considered stale and removed from the pool of pods if it doesn't sync with the rate limiter.\n\nFurthermore, if the ResumedRampUp is true, then TTL also defines the amount of time within which, if a pod re-registers / registers with the same name, with the rate limiter, it will be assigned the same rate limit as the previous pod with that name.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"perKey": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Auth information",
-							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarAuth"),
+							Description: "PerKey enables a separate rate limit per key instead of one for the whole vertex.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitPerKey"),
 						},
 					},
 				},
-				Required: []string{"serverAddr", "topic", "consumerName", "subscriptionName"},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarAuth"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitPerKey", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterModes", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterStore", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
 	}
 }
 
-func schema_pkg_apis_numaflow_v1alpha1_RateLimit(ref common.ReferenceCallback) common.OpenAPIDefinition {
+func schema_pkg_apis_numaflow_v1alpha1_RateLimitKeyOverride(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
 			SchemaProps: spec.SchemaProps{
-				Type: []string{"object"},
+				Description: "RateLimitKeyOverride overrides the rate limit of a specific key.",
+				Type:        []string{"object"},
 				Properties: map[string]spec.Schema{
+					"key": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Key the override applies to.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
 					"max": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Max is the maximum TPS that this vertex can process give a distributed `Store` is configured. Otherwise, it will be the maximum TPS for a single replica.",
+							Description: "Max is the maximum TPS of the key. Defaults to the max of the rate limit.",
 							Type:        []string{"integer"},
 							Format:      "int64",
 						},
 					},
 					"min": {
 						SchemaProps: spec.SchemaProps{
-							Description: "Minimum TPS allowed during initial bootup. This value will be distributed across all the replicas if a distributed `Store` is configured. Otherwise, it will be the minimum TPS for a single replica.",
+							Description: "Minimum TPS of the key during initial bootup. Defaults to the min of the rate limit.",
 							Type:        []string{"integer"},
 							Format:      "int64",
 						},
 					},
 					"rampUpDuration": {
 						SchemaProps: spec.SchemaProps{
-							Description: "RampUpDuration is the duration to reach the maximum TPS from the minimum TPS. The min unit of ramp up is 1 in 1 second.",
+							Description: "RampUpDuration is the duration to reach the maximum TPS of the key from the minimum TPS. Defaults to the ramp up duration of the rate limit.",
 							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
 						},
 					},
-					"store": {
-						SchemaProps: spec.SchemaProps{
-							Description: "Store is used to define the Distributed Store for the rate limiting. We also support in-memory store if no store is configured. This means that every replica will have its own rate limit and the actual TPS will be the sum of all the replicas.",
-							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterStore"),
-						},
-					},
-					"modes": {
+				},
+				Required: []string{"key"},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_RateLimitPerKey(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "RateLimitPerKey enables a separate rate limit per key (e.g., per tenant) instead of one for the whole vertex.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"header": {
 						SchemaProps: spec.SchemaProps{
-							Description: "RateLimiterModes is used to define the modes for rate limiting.",
-							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterModes"),
+							Description: "Header to derive the key from. Defaults to the keys of the message.",
+							Type:        []string{"string"},
+							Format:      "",
 						},
 					},
-					"resumedRampUp": {
+					"maxKeys": {
 						SchemaProps: spec.SchemaProps{
-							Description: "ResumedRampUp is used to enable the resume mode for rate limiting.\n\nThis, if true, will allow the processor to resume the ramp-up process from the last known state of the rate limiter, i.e., if the processor was allowed X tokens before shutting down, it will be allowed X tokens again after the processor restarts.\n\nThe resumed ramp-up process will be allowed until TTL time after the processor first deregisters with the rate limiter.",
-							Type:        []string{"boolean"},
-							Format:      "",
+							Description: "MaxKeys is the maximum number of keys to keep a rate limit for, the least recently used key is evicted beyond that. Defaults to 1000.",
+							Type:        []string{"integer"},
+							Format:      "int64",
 						},
 					},
-					"ttl": {
+					"overrides": {
 						SchemaProps: spec.SchemaProps{
-							Description: "TTL is used to define the duration after which a pod is considered stale and removed from the pool of pods if it doesn't sync with the rate limiter.\n\nFurthermore, if the ResumedRampUp is true, then TTL also defines the amount of time within which, if a pod re-registers / registers with the same name, with the rate limiter, it will be assigned the same rate limit as the previous pod with that name.",
-							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+							Description: "Overrides of the rate limit of specific keys.",
+							Type:        []string{"array"},
+							Items: &spec.SchemaOrArray{
+								Schema: &spec.Schema{
+									SchemaProps: spec.SchemaProps{
+										Default: map[string]interface{}{},
+										Ref:     ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitKeyOverride"),
+									},
+								},
+							},
 						},
 					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterModes", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterStore", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimitKeyOverride"},
 	}
 }
 
@@ -4536,6 +5566,26 @@ func schema_pkg_apis_numaflow_v1alpha1_RateLimiterInMemoryStore(ref common.Refer
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_RateLimiterJetStreamStore(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "RateLimiterJetStreamStore uses a JetStream Key-Value bucket of the InterStepBufferService as the store.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"bucket": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Name of the KV bucket to keep the state of the rate limiter in, created if it does not exist. Defaults to \"numaflow-rate-limiter\".",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+				},
+			},
+		},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_RateLimiterModes(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -4678,11 +5728,17 @@ func schema_pkg_apis_numaflow_v1alpha1_RateLimiterStore(ref common.ReferenceCall
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterInMemoryStore"),
 						},
 					},
+					"jetStreamStore": {
+						SchemaProps: spec.SchemaProps{
+							Description: "JetStreamStore is used to define the JetStream Key-Value store for the rate limit.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterJetStreamStore"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterInMemoryStore", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterRedisStore"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterInMemoryStore", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterJetStreamStore", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RateLimiterRedisStore"},
 	}
 }
 
@@ -4711,6 +5767,63 @@ func schema_pkg_apis_numaflow_v1alpha1_RedisAuth(ref common.ReferenceCallback) c
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_RedisBufferService(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "RedisBufferService is an external Redis server used as the inter-step buffer. It is not managed by the controller, the streams are created by the vertices on demand.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"url": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Redis URL, e.g. \"redis://redis.default.svc:6379\"",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"password": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Secret for the password of the Redis server",
+							Ref:         ref("k8s.io/api/core/v1.SecretKeySelector"),
+						},
+					},
+				},
+				Required: []string{"url"},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/api/core/v1.SecretKeySelector"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_RedisConfig(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Type: []string{"object"},
+				Properties: map[string]spec.Schema{
+					"url": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Redis URL",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"password": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Secret for the password of the Redis server",
+							Ref:         ref("k8s.io/api/core/v1.SecretKeySelector"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/api/core/v1.SecretKeySelector"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_RedisSentinelConfig(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -4793,11 +5906,17 @@ func schema_pkg_apis_numaflow_v1alpha1_RetryStrategy(ref common.ReferenceCallbac
 							Format:      "",
 						},
 					},
+					"circuitBreaker": {
+						SchemaProps: spec.SchemaProps{
+							Description: "CircuitBreaker stops writing to the sink while it keeps failing.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.CircuitBreaker"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Backoff"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Backoff", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.CircuitBreaker"},
 	}
 }
 
@@ -4822,6 +5941,58 @@ func schema_pkg_apis_numaflow_v1alpha1_RollingUpdateStrategy(ref common.Referenc
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_S3ObjectStore(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "S3ObjectStore is an S3 compatible object store.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"bucket": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Bucket is the name of the bucket.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"awsRegion": {
+						SchemaProps: spec.SchemaProps{
+							Description: "AWSRegion is the AWS Region of the bucket.",
+							Default:     "",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"endpointUrl": {
+						SchemaProps: spec.SchemaProps{
+							Description: "EndpointURL overrides the endpoint of the store, e.g. for MinIO.",
+							Type:        []string{"string"},
+							Format:      "",
+						},
+					},
+					"forcePathStyle": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ForcePathStyle addresses the bucket in the path of the URL instead of the host, required by most S3 compatible stores.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+					"assumeRole": {
+						SchemaProps: spec.SchemaProps{
+							Description: "AssumeRole contains the configuration for AWS STS assume role.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AWSAssumeRole"),
+						},
+					},
+				},
+				Required: []string{"bucket", "awsRegion"},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AWSAssumeRole"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_SASL(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -5773,6 +6944,42 @@ func schema_pkg_apis_numaflow_v1alpha1_Sink(ref common.ReferenceCallback) common
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink"),
 						},
 					},
+					"nats": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Nats sink is used to publish the data to a NATS subject.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSink"),
+						},
+					},
+					"jetstream": {
+						SchemaProps: spec.SchemaProps{
+							Description: "JetStream sink is used to publish the data to a JetStream stream.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSink"),
+						},
+					},
+					"http": {
+						SchemaProps: spec.SchemaProps{
+							Description: "HTTP sink is used to send the data to an HTTP endpoint.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSink"),
+						},
+					},
+					"file": {
+						SchemaProps: spec.SchemaProps{
+							Description: "File sink is used to write the data to segment files in a local directory.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSink"),
+						},
+					},
+					"objectStore": {
+						SchemaProps: spec.SchemaProps{
+							Description: "ObjectStore sink is used to write the data as files to an object store.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreSink"),
+						},
+					},
+					"postgres": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Postgres sink is used to write the data to a PostgreSQL table.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresSink"),
+						},
+					},
 					"fallback": {
 						SchemaProps: spec.SchemaProps{
 							Description: "Fallback sink can be imagined as DLQ for primary Sink. The writes to Fallback sink will only be initiated if the ud-sink response field sets it.",
@@ -5792,11 +6999,24 @@ func schema_pkg_apis_numaflow_v1alpha1_Sink(ref common.ReferenceCallback) common
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RetryStrategy"),
 						},
 					},
+					"dlqHeaders": {
+						SchemaProps: spec.SchemaProps{
+							Description: "DLQHeaders mirrors the dead-letter envelope of the messages routed to the fallback sink into their headers. The envelope is always added to the system metadata of the messages.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+					"broadcast": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Broadcast sink is used to write the data to multiple sinks, it is used instead of the sinks of the AbstractSink.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BroadcastSink"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Blackhole", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Log", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RetryStrategy", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServeSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSink"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AbstractSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Blackhole", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.BroadcastSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.Log", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ObjectStoreSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PostgresSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.RetryStrategy", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServeSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSink", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSink"},
 	}
 }
 
@@ -5890,11 +7110,16 @@ func schema_pkg_apis_numaflow_v1alpha1_Source(ref common.ReferenceCallback) comm
 							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSource"),
 						},
 					},
+					"file": {
+						SchemaProps: spec.SchemaProps{
+							Ref: ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSource"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GeneratorSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServingSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDTransformer"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.FileSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.GeneratorSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.HTTPSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.JetStreamSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.KafkaSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.NatsSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PulsarSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.ServingSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.SqsSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.UDTransformer"},
 	}
 }
 
@@ -7160,11 +8385,50 @@ func schema_pkg_apis_numaflow_v1alpha1_Watermark(ref common.ReferenceCallback) c
 							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.IdleSource"),
 						},
 					},
+					"strategy": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Strategy is the heuristic used by the source to compute the watermark, the max delay is subtracted from the computed watermark regardless of the strategy.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WatermarkStrategy"),
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.IdleSource", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.WatermarkStrategy", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
+func schema_pkg_apis_numaflow_v1alpha1_WatermarkStrategy(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "WatermarkStrategy is the heuristic used by the source to compute the watermark from the event times of the messages. Only one of the strategies can be set, defaults to the minimum event time of the read messages.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"percentile": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Percentile holds back the watermark by a percentile of the observed out-of-orderness.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PercentileWatermark"),
+						},
+					},
+					"adaptive": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Adaptive holds back the watermark of every partition by a delay adapting to its late messages.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AdaptiveWatermark"),
+						},
+					},
+					"punctuation": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Punctuation advances the watermark only on the marked messages.",
+							Ref:         ref("github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PunctuationWatermark"),
+						},
+					},
 				},
 			},
 		},
 		Dependencies: []string{
-			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.IdleSource", "k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+			"github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.AdaptiveWatermark", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PercentileWatermark", "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1.PunctuationWatermark"},
 	}
 }
 
@@ -7203,6 +8467,41 @@ func schema_pkg_apis_numaflow_v1alpha1_Window(ref common.ReferenceCallback) comm
 	}
 }
 
+func schema_pkg_apis_numaflow_v1alpha1_WindowTrigger(ref common.ReferenceCallback) common.OpenAPIDefinition {
+	return common.OpenAPIDefinition{
+		Schema: spec.Schema{
+			SchemaProps: spec.SchemaProps{
+				Description: "WindowTrigger fires intermediate results (panes) of fixed and sliding windows before they are closed by the watermark.",
+				Type:        []string{"object"},
+				Properties: map[string]spec.Schema{
+					"count": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Count fires a pane of a key every time the key receives this number of messages.",
+							Type:        []string{"integer"},
+							Format:      "int64",
+						},
+					},
+					"interval": {
+						SchemaProps: spec.SchemaProps{
+							Description: "Interval fires a pane of every key with new messages at this processing-time interval.",
+							Ref:         ref("k8s.io/apimachinery/pkg/apis/meta/v1.Duration"),
+						},
+					},
+					"lateFirings": {
+						SchemaProps: spec.SchemaProps{
+							Description: "LateFirings fires a pane of every key once the watermark passes the end of the window, and a pane for every late message until the window is closed after the allowed lateness. Requires allowedLateness.",
+							Type:        []string{"boolean"},
+							Format:      "",
+						},
+					},
+				},
+			},
+		},
+		Dependencies: []string{
+			"k8s.io/apimachinery/pkg/apis/meta/v1.Duration"},
+	}
+}
+
 func schema_pkg_apis_numaflow_v1alpha1_containerBuilder(ref common.ReferenceCallback) common.OpenAPIDefinition {
 	return common.OpenAPIDefinition{
 		Schema: spec.Schema{
@@ -72,6 +72,9 @@ func getInstaller(isbSvc *dfv1.InterStepBufferService, client client.Client, kub
 		labels[dfv1.KeyISBSvcType] = string(dfv1.ISBSvcTypeJetStream)
 		return NewJetStreamInstaller(client, kubeClient, isbSvc, config, labels, logger, recorder), nil
 	}
+	if isbSvc.Spec.Redis != nil {
+		return NewExternalRedisInstaller(isbSvc, logger), nil
+	}
 	return nil, fmt.Errorf("invalid isb service spec")
 }
 
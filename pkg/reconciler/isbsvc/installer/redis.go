@@ -0,0 +1,67 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package installer
+
+import (
+	"context"
+	"fmt"
+
+	"go.uber.org/zap"
+
+	dfv1 "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1"
+)
+
+// externalRedisInstaller hands the connection settings of an external Redis to the pipelines,
+// nothing is deployed.
+type externalRedisInstaller struct {
+	isbSvc *dfv1.InterStepBufferService
+	logger *zap.SugaredLogger
+}
+
+func NewExternalRedisInstaller(isbSvc *dfv1.InterStepBufferService, logger *zap.SugaredLogger) Installer {
+	return &externalRedisInstaller{
+		isbSvc: isbSvc,
+		logger: logger.With("isbsvc", isbSvc.Name),
+	}
+}
+
+func (r *externalRedisInstaller) Install(_ context.Context) (*dfv1.BufferServiceConfig, error) {
+	redis := r.isbSvc.Spec.Redis
+	if redis == nil || redis.URL == "" {
+		return nil, fmt.Errorf("invalid redis ISB Service spec, url is missing")
+	}
+	r.isbSvc.Status.SetType(dfv1.ISBSvcTypeRedis)
+	r.isbSvc.Status.MarkConfigured()
+	r.isbSvc.Status.MarkDeployed()
+	r.logger.Info("Using external redis config")
+	return &dfv1.BufferServiceConfig{
+		Redis: &dfv1.RedisConfig{
+			URL:      redis.URL,
+			Password: redis.Password,
+		},
+	}, nil
+}
+
+func (r *externalRedisInstaller) Uninstall(_ context.Context) error {
+	r.logger.Info("Nothing to uninstall for the external redis")
+	return nil
+}
+
+func (r *externalRedisInstaller) CheckChildrenResourceStatus(_ context.Context) error {
+	r.isbSvc.Status.MarkChildrenResourceHealthy("External", "The redis server is not managed by the controller")
+	return nil
+}
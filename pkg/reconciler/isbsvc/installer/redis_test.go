@@ -0,0 +1,66 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package installer
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"go.uber.org/zap/zaptest"
+	corev1 "k8s.io/api/core/v1"
+	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+
+	dfv1 "github.com/numaproj/numaflow/pkg/apis/numaflow/v1alpha1"
+)
+
+func testRedisIsbSvc() *dfv1.InterStepBufferService {
+	return &dfv1.InterStepBufferService{
+		ObjectMeta: metav1.ObjectMeta{
+			Namespace: testNamespace,
+			Name:      testISBSName,
+		},
+		Spec: dfv1.InterStepBufferServiceSpec{
+			Redis: &dfv1.RedisBufferService{
+				URL: "redis://redis:6379",
+				Password: &corev1.SecretKeySelector{
+					LocalObjectReference: corev1.LocalObjectReference{Name: "redis"},
+					Key:                  "password",
+				},
+			},
+		},
+	}
+}
+
+func TestExternalRedisInstall(t *testing.T) {
+	t.Run("test install", func(t *testing.T) {
+		installer := NewExternalRedisInstaller(testRedisIsbSvc(), zaptest.NewLogger(t).Sugar())
+		c, err := installer.Install(context.TODO())
+		assert.NoError(t, err)
+		assert.Nil(t, c.JetStream)
+		assert.Equal(t, "redis://redis:6379", c.Redis.URL)
+		assert.Equal(t, "password", c.Redis.Password.Key)
+	})
+
+	t.Run("test install without url", func(t *testing.T) {
+		obj := testRedisIsbSvc()
+		obj.Spec.Redis.URL = ""
+		installer := NewExternalRedisInstaller(obj, zaptest.NewLogger(t).Sugar())
+		_, err := installer.Install(context.TODO())
+		assert.Error(t, err)
+	})
+}
@@ -32,8 +32,11 @@ func ValidateInterStepBufferService(isbsvc *dfv1.InterStepBufferService) error {
 	if errs := k8svalidation.IsDNS1035Label(isbsvc.Name); len(errs) > 0 {
 		return fmt.Errorf("invalid ISB Service name %q, %v", isbsvc.Name, errs)
 	}
-	if isbsvc.Spec.JetStream == nil {
-		return fmt.Errorf(`invalid spec: "spec.jetstream" needs to be specified`)
+	if isbsvc.Spec.JetStream == nil && isbsvc.Spec.Redis == nil {
+		return fmt.Errorf(`invalid spec: either "spec.jetstream" or "spec.redis" needs to be specified`)
+	}
+	if isbsvc.Spec.JetStream != nil && isbsvc.Spec.Redis != nil {
+		return fmt.Errorf(`invalid spec: "spec.jetstream" and "spec.redis" can not be specified together`)
 	}
 	if x := isbsvc.Spec.JetStream; x != nil {
 		if x.Version == "" {
@@ -43,5 +46,8 @@ func ValidateInterStepBufferService(isbsvc *dfv1.InterStepBufferService) error {
 			return fmt.Errorf(`invalid spec: min value for "spec.jetstream.replicas" is 1 and can't be 2`)
 		}
 	}
+	if x := isbsvc.Spec.Redis; x != nil && x.URL == "" {
+		return fmt.Errorf(`invalid spec: "spec.redis.url" is not defined`)
+	}
 	return nil
 }
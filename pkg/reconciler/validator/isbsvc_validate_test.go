@@ -46,7 +46,27 @@ func TestValidateInterStepBuffer(t *testing.T) {
 		isbs.Spec.JetStream = nil
 		err := ValidateInterStepBufferService(isbs)
 		assert.Error(t, err)
-		assert.Contains(t, err.Error(), `"spec.jetstream" needs to be specified`)
+		assert.Contains(t, err.Error(), `either "spec.jetstream" or "spec.redis" needs to be specified`)
+	})
+
+	t.Run("test redis", func(t *testing.T) {
+		isbs := testJetStreamIsbs.DeepCopy()
+		isbs.Spec.JetStream = nil
+		isbs.Spec.Redis = &dfv1.RedisBufferService{URL: "redis://redis:6379"}
+		assert.NoError(t, ValidateInterStepBufferService(isbs))
+
+		isbs.Spec.Redis.URL = ""
+		err := ValidateInterStepBufferService(isbs)
+		assert.Error(t, err)
+		assert.Contains(t, err.Error(), `"spec.redis.url" is not defined`)
+	})
+
+	t.Run("test both jetstream and redis", func(t *testing.T) {
+		isbs := testJetStreamIsbs.DeepCopy()
+		isbs.Spec.Redis = &dfv1.RedisBufferService{URL: "redis://redis:6379"}
+		err := ValidateInterStepBufferService(isbs)
+		assert.Error(t, err)
+		assert.Contains(t, err.Error(), "can not be specified together")
 	})
 
 	t.Run("test missing jetstream version", func(t *testing.T) {
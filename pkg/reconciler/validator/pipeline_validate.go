@@ -349,6 +349,28 @@ func validateReduceUDF(udf dfv1.UDF) error {
 	if storage.EmptyDir != nil && storage.NoStore != nil {
 		return fmt.Errorf(`invalid "groupBy.storage", either none or emptyDir is allowed, not both`)
 	}
+	if t := udf.GroupBy.Trigger; t != nil {
+		if f == nil && s == nil {
+			return fmt.Errorf(`invalid "groupBy.trigger", triggers are only supported for fixed and sliding windows`)
+		}
+		if t.LateFirings && udf.GroupBy.AllowedLateness == nil {
+			return fmt.Errorf(`invalid "groupBy.trigger", "lateFirings" requires "allowedLateness"`)
+		}
+	}
+	if b := udf.GroupBy.Builtin; b != nil {
+		if udf.Container != nil {
+			return fmt.Errorf(`invalid "groupBy.builtin", either builtin or container is allowed, not both`)
+		}
+		if f == nil && s == nil {
+			return fmt.Errorf(`invalid "groupBy.builtin", builtin reducers are only supported for fixed and sliding windows`)
+		}
+		if b.Aggregation == "quantiles" && len(b.Quantiles) == 0 {
+			return fmt.Errorf(`invalid "groupBy.builtin", "quantiles" is missing`)
+		}
+		if b.Precision != nil && (*b.Precision < 4 || *b.Precision > 16) {
+			return fmt.Errorf(`invalid "groupBy.builtin", "precision" should be between 4 and 16`)
+		}
+	}
 
 	return nil
 }
@@ -624,6 +646,26 @@ func validateSink(sink dfv1.Sink) error {
 		}
 	}
 
+	if sink.ObjectStore != nil {
+		if err := validateObjectStoreSink(*sink.ObjectStore); err != nil {
+			return fmt.Errorf("invalid object store sink: %w", err)
+		}
+	}
+
+	if sink.Broadcast != nil {
+		if sink.IsAnySinkSpecified() {
+			return fmt.Errorf("invalid broadcast sink, it can not be specified together with another sink")
+		}
+		if len(sink.Broadcast.Sinks) == 0 {
+			return fmt.Errorf(`invalid broadcast sink, "sinks" is missing`)
+		}
+		for _, child := range sink.Broadcast.Sinks {
+			if child.UDSink != nil || child.Serve != nil {
+				return fmt.Errorf("invalid broadcast sink, udsink and serve sinks cannot be broadcast to")
+			}
+		}
+	}
+
 	return nil
 }
 
@@ -667,6 +709,15 @@ func hasValidSinkRetryStrategy(s dfv1.Sink) error {
 			return fmt.Errorf("jitter in backoff strategy should be between 0 and 1")
 		}
 	}
+	if cb := s.RetryStrategy.CircuitBreaker; cb != nil {
+		// Same as for OnFailure, the messages can only be rerouted if there is a fallback sink.
+		if cb.OnOpen != nil && *cb.OnOpen == dfv1.OnOpenFallback && !hasValidFallbackSink(&s) {
+			return fmt.Errorf("given OnOpen strategy of the circuit breaker is fallback but fallback sink is not provided")
+		}
+		if cb.FailureRatio != nil && (*cb.FailureRatio <= 0 || *cb.FailureRatio > 1) {
+			return fmt.Errorf("failureRatio in circuit breaker should be greater than 0 and less than or equal to 1")
+		}
+	}
 	// If no errors are found, the function returns nil indicating the validation passed.
 	return nil
 }
@@ -772,6 +823,31 @@ func validateSQSSink(sqs dfv1.SqsSink) error {
 	return nil
 }
 
+// validateObjectStoreSink validates the object store sink configuration
+func validateObjectStoreSink(store dfv1.ObjectStoreSink) error {
+	if (store.S3 == nil) == (store.Local == nil) {
+		return fmt.Errorf("either s3 or local store should be specified")
+	}
+	if store.S3 != nil {
+		if store.S3.Bucket == "" {
+			return fmt.Errorf("bucket is required")
+		}
+		if store.S3.AWSRegion == "" {
+			return fmt.Errorf("awsRegion is required")
+		}
+		if err := validateAWSAssumeRole(store.S3.AssumeRole); err != nil {
+			return fmt.Errorf("invalid assume role configuration: %w", err)
+		}
+	}
+	if store.Local != nil && store.Local.Path == "" {
+		return fmt.Errorf("path of the local store is required")
+	}
+	if store.Format == "parquet" && len(store.Columns) == 0 {
+		return fmt.Errorf("columns are required for the parquet format")
+	}
+	return nil
+}
+
 // validateOrderedProcessing validates the ordered processing configuration.
 // For map and sink vertices with ordered processing enabled:
 // - Scale config (min/max) must not be set by user
@@ -630,6 +630,21 @@ func TestValidateReducePipeline(t *testing.T) {
 		assert.Contains(t, err.Error(), `either emptyDir or persistentVolumeClaim is allowed, not both`)
 	})
 
+	t.Run("builtin reducer", func(t *testing.T) {
+		testObj := testReducePipeline.DeepCopy()
+		testObj.Spec.Vertices[1].UDF.GroupBy.Builtin = &dfv1.BuiltinReducer{Aggregation: "count"}
+		err := ValidatePipeline(testObj)
+		assert.Error(t, err)
+		assert.Contains(t, err.Error(), `either builtin or container is allowed, not both`)
+		testObj.Spec.Vertices[1].UDF.Container = nil
+		err = ValidatePipeline(testObj)
+		assert.NoError(t, err)
+		testObj.Spec.Vertices[1].UDF.GroupBy.Builtin = &dfv1.BuiltinReducer{Aggregation: "quantiles"}
+		err = ValidatePipeline(testObj)
+		assert.Error(t, err)
+		assert.Contains(t, err.Error(), `"quantiles" is missing`)
+	})
+
 }
 
 func TestValidateVertex(t *testing.T) {
@@ -1168,6 +1183,45 @@ func TestValidateSink(t *testing.T) {
 			},
 			expectedError: true,
 		},
+		{
+			name: "Invalid object store sink without a store",
+			sink: dfv1.Sink{
+				AbstractSink: dfv1.AbstractSink{
+					ObjectStore: &dfv1.ObjectStoreSink{Format: "ndjson"},
+				},
+			},
+			expectedError: true,
+		},
+		{
+			name: "Invalid parquet object store sink without columns",
+			sink: dfv1.Sink{
+				AbstractSink: dfv1.AbstractSink{
+					ObjectStore: &dfv1.ObjectStoreSink{
+						Local:  &dfv1.LocalObjectStore{Path: "/data"},
+						Format: "parquet",
+					},
+				},
+			},
+			expectedError: true,
+		},
+		{
+			name: "Valid broadcast sink",
+			sink: dfv1.Sink{
+				Broadcast: &dfv1.BroadcastSink{
+					Sinks: []dfv1.AbstractSink{{Log: &dfv1.Log{}}, {Blackhole: &dfv1.Blackhole{}}},
+				},
+			},
+			expectedError: false,
+		},
+		{
+			name: "Invalid broadcast sink to a user-defined sink",
+			sink: dfv1.Sink{
+				Broadcast: &dfv1.BroadcastSink{
+					Sinks: []dfv1.AbstractSink{{Log: &dfv1.Log{}}, {UDSink: &dfv1.UDSink{}}},
+				},
+			},
+			expectedError: true,
+		},
 	}
 
 	for _, tt := range tests {
@@ -1198,6 +1252,26 @@ func TestIsValidSinkRetryStrategy(t *testing.T) {
 		strategy dfv1.RetryStrategy
 		wantErr  bool
 	}{
+		{
+			name: "invalid circuit breaker with fallback on open but no fallback sink",
+			sink: dfv1.Sink{},
+			strategy: dfv1.RetryStrategy{
+				CircuitBreaker: &dfv1.CircuitBreaker{
+					OnOpen: ptr.To(dfv1.OnOpenFallback),
+				},
+			},
+			wantErr: true,
+		},
+		{
+			name: "invalid circuit breaker with failure ratio > 1",
+			sink: dfv1.Sink{},
+			strategy: dfv1.RetryStrategy{
+				CircuitBreaker: &dfv1.CircuitBreaker{
+					FailureRatio: ptr.To(1.5),
+				},
+			},
+			wantErr: true,
+		},
 		{
 			name: "valid strategy with cap >= interval",
 			sink: dfv1.Sink{},
@@ -59,5 +59,14 @@ func GetIsbSvcEnvVars(isbSvcConfig dfv1.BufferServiceConfig) (dfv1.ISBSvcType, [
 		}
 		isbSvcType = dfv1.ISBSvcTypeJetStream
 	}
+	if x := isbSvcConfig.Redis; x != nil {
+		env = append(env, corev1.EnvVar{Name: dfv1.EnvISBSvcRedisURL, Value: x.URL})
+		if x.Password != nil {
+			env = append(env, corev1.EnvVar{Name: dfv1.EnvISBSvcRedisPassword, ValueFrom: &corev1.EnvVarSource{
+				SecretKeyRef: x.Password,
+			}})
+		}
+		isbSvcType = dfv1.ISBSvcTypeRedis
+	}
 	return isbSvcType, env
 }
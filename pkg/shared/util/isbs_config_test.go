@@ -60,3 +60,27 @@ func TestGetJSIsbSvcEnvVars(t *testing.T) {
 	assert.Contains(t, eNames, dfv1.EnvISBSvcJetStreamPassword)
 	assert.Contains(t, eNames, dfv1.EnvISBSvcConfig)
 }
+
+func TestGetRedisIsbSvcEnvVars(t *testing.T) {
+	fakeIsbsConfig := dfv1.BufferServiceConfig{
+		Redis: &dfv1.RedisConfig{
+			URL: "redis://redis:6379",
+			Password: &corev1.SecretKeySelector{
+				LocalObjectReference: corev1.LocalObjectReference{
+					Name: "test-pass",
+				},
+				Key: "test-key",
+			},
+		},
+	}
+	tp, env := GetIsbSvcEnvVars(fakeIsbsConfig)
+	assert.Equal(t, dfv1.ISBSvcTypeRedis, tp)
+	eNames := []string{}
+	for _, e := range env {
+		eNames = append(eNames, e.Name)
+	}
+	assert.Contains(t, eNames, dfv1.EnvISBSvcRedisURL)
+	assert.Contains(t, eNames, dfv1.EnvISBSvcRedisPassword)
+	assert.Contains(t, eNames, dfv1.EnvISBSvcConfig)
+	assert.NotContains(t, eNames, dfv1.EnvISBSvcJetStreamURL)
+}
@@ -35,6 +35,8 @@ use uuid::Uuid;
 
 use tokio_util::sync::CancellationToken;
 
+pub mod sink;
+
 /// Header name for the message ID (lowercase for header lookup)
 const NUMAFLOW_ID_HEADER: &str = "x-numaflow-id";
 /// Header name for the message ID (canonical form for insertion)
@@ -57,8 +59,8 @@ struct HttpSourceActor {
     inflight_requests: InflightRequestsMap,
 }
 
-/// Error types for the HTTP source
-#[derive(thiserror::Error, Debug)]
+/// Error types for the HTTP source and sink
+#[derive(thiserror::Error, Debug, Clone)]
 pub enum Error {
     #[error("Channel send error: {0}")]
     ChannelSend(String),
@@ -68,6 +70,14 @@ pub enum Error {
     ChannelFull(),
     #[error("Server error: {0}")]
     Server(String),
+    #[error("Sink error: {0}")]
+    Sink(String),
+    #[error("Request error: {0}")]
+    Request(String),
+    #[error("Request failed with status {status}: {body}")]
+    Status { status: u16, body: String },
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
 }
 
 type Result<T> = std::result::Result<T, Error>;
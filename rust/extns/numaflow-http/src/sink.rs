@@ -0,0 +1,610 @@
+//! HTTP sink for Numaflow. Messages are sent to an HTTP endpoint either one request per message
+//! ([HttpSinkFormat::Raw]) or the whole batch in a single request as a JSON array
+//! ([HttpSinkFormat::Json]) or as newline delimited JSON ([HttpSinkFormat::Ndjson]).
+//! In the raw format, every request carries the `X-Numaflow-Id`, `X-Numaflow-Event-Time` and
+//! `X-Numaflow-Keys` headers, which are understood by the HTTP source, so that one pipeline can
+//! feed another one.
+//! The status of each message is derived from the response status code, see
+//! [Error::is_retryable].
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Method, RequestBuilder};
+use tracing::info;
+
+use crate::{
+    Error, NUMAFLOW_EVENT_TIME_HEADER, NUMAFLOW_ID_HEADER_KEY, NUMAFLOW_KEYS_HEADER, Result,
+};
+
+/// Maximum length of the response body kept in the error, so that a large error page doesn't end
+/// up in the logs.
+const MAX_ERROR_BODY_LEN: usize = 256;
+
+/// Format of the request body.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HttpSinkFormat {
+    /// One request per message with the payload as the body.
+    #[default]
+    Raw,
+    /// One request per batch, the body is a JSON array of the payloads.
+    Json,
+    /// One request per batch, the body contains one payload per line.
+    Ndjson,
+}
+
+impl TryFrom<&str> for HttpSinkFormat {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "raw" => Ok(HttpSinkFormat::Raw),
+            "json" => Ok(HttpSinkFormat::Json),
+            "ndjson" => Ok(HttpSinkFormat::Ndjson),
+            _ => Err(Error::Sink(format!(
+                "Unsupported format '{value}', supported formats are raw, json and ndjson"
+            ))),
+        }
+    }
+}
+
+/// Authentication used for the requests.
+#[derive(Clone, PartialEq)]
+pub enum HttpSinkAuth {
+    Bearer(String),
+    Basic { username: String, password: String },
+}
+
+impl Debug for HttpSinkAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpSinkAuth::Bearer(_) => f.write_str("Bearer(*****)"),
+            HttpSinkAuth::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"*****")
+                .finish(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpSinkConfig {
+    /// URL of the endpoint.
+    pub url: String,
+    /// HTTP method of the requests.
+    pub method: Method,
+    /// Static headers added to every request.
+    pub headers: HashMap<String, String>,
+    /// Maps the message header names to the request header names. Only the mapped headers are
+    /// sent, and only for [HttpSinkFormat::Raw].
+    pub header_mapping: HashMap<String, String>,
+    pub auth: Option<HttpSinkAuth>,
+    pub format: HttpSinkFormat,
+    /// Timeout of a single request.
+    pub timeout: Duration,
+    /// Skip the verification of the server certificate.
+    pub insecure_skip_verify: bool,
+    /// Maximum number of requests in flight for [HttpSinkFormat::Raw].
+    pub concurrency: usize,
+}
+
+impl Default for HttpSinkConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            method: Method::POST,
+            headers: HashMap::new(),
+            header_mapping: HashMap::new(),
+            auth: None,
+            format: HttpSinkFormat::Raw,
+            timeout: Duration::from_secs(30),
+            insecure_skip_verify: false,
+            concurrency: 50,
+        }
+    }
+}
+
+/// The message to send to the endpoint. Input to [HttpSink::sink_messages].
+#[derive(Debug, Clone)]
+pub struct HttpSinkMessage {
+    /// ID of the message, sent as the `X-Numaflow-Id` header.
+    pub id: String,
+    pub keys: Vec<String>,
+    pub headers: HashMap<String, String>,
+    pub event_time: DateTime<Utc>,
+    pub payload: Bytes,
+}
+
+/// The result of a request corresponding to a [HttpSinkMessage] with same id.
+pub struct HttpSinkResponse {
+    /// ID of the message that was sent.
+    pub id: String,
+    /// Status of the request, [Error::is_retryable] tells whether it is worth retrying.
+    pub status: Result<()>,
+}
+
+impl Error {
+    /// Whether the failed message could succeed if sent again. Transport errors, timeouts,
+    /// `408`, `429` and `5xx` responses are retryable. Other `4xx` responses, payloads which
+    /// can't be encoded in the configured format and requests which can't be built (e.g., a
+    /// message header value which is not a valid HTTP header value) are not, the message will be
+    /// rejected again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Status { status, .. } => *status >= 500 || *status == 408 || *status == 429,
+            Error::InvalidPayload(_) => false,
+            _ => true,
+        }
+    }
+}
+
+/// The HTTP sink client.
+pub struct HttpSink {
+    client: Client,
+    config: HttpSinkConfig,
+    /// [HttpSinkConfig::header_mapping] with the request header names validated.
+    header_mapping: Vec<(String, HeaderName)>,
+}
+
+impl HttpSink {
+    /// Create a new HTTP sink client.
+    pub fn new(config: HttpSinkConfig) -> Result<Self> {
+        let mut default_headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            let name = HeaderName::try_from(name.as_str())
+                .map_err(|e| Error::Sink(format!("Invalid header name {name}: {e}")))?;
+            let value = HeaderValue::try_from(value.as_str())
+                .map_err(|e| Error::Sink(format!("Invalid value for header {name}: {e}")))?;
+            default_headers.insert(name, value);
+        }
+        let header_mapping = config
+            .header_mapping
+            .iter()
+            .map(|(msg_header, request_header)| {
+                HeaderName::try_from(request_header.as_str())
+                    .map(|name| (msg_header.clone(), name))
+                    .map_err(|e| {
+                        Error::Sink(format!(
+                            "Invalid request header name {request_header} mapped from {msg_header}: {e}"
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        reqwest::Url::parse(&config.url)
+            .map_err(|e| Error::Sink(format!("Invalid URL {}: {e}", config.url)))?;
+
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .default_headers(default_headers)
+            .danger_accept_invalid_certs(config.insecure_skip_verify)
+            .build()
+            .map_err(|e| Error::Sink(format!("Creating HTTP client: {e}")))?;
+
+        info!(url = config.url, method = %config.method, format = ?config.format, "HTTP sink created");
+        Ok(Self {
+            client,
+            config,
+            header_mapping,
+        })
+    }
+
+    /// Send the messages to the endpoint.
+    pub async fn sink_messages(
+        &self,
+        messages: Vec<HttpSinkMessage>,
+    ) -> Result<Vec<HttpSinkResponse>> {
+        if messages.is_empty() {
+            return Ok(vec![]);
+        }
+        match self.config.format {
+            HttpSinkFormat::Raw => Ok(stream::iter(messages)
+                .map(|msg| async {
+                    let id = msg.id.clone();
+                    HttpSinkResponse {
+                        id,
+                        status: self.send_message(msg).await,
+                    }
+                })
+                .buffer_unordered(self.config.concurrency.max(1))
+                .collect()
+                .await),
+            HttpSinkFormat::Json | HttpSinkFormat::Ndjson => Ok(self.send_batch(messages).await),
+        }
+    }
+
+    /// Sends a single message, the payload is the body of the request.
+    async fn send_message(&self, msg: HttpSinkMessage) -> Result<()> {
+        let mut request = self
+            .request()
+            .header(NUMAFLOW_ID_HEADER_KEY, &msg.id)
+            .header(
+                NUMAFLOW_EVENT_TIME_HEADER,
+                msg.event_time.timestamp_millis().to_string(),
+            );
+        if !msg.keys.is_empty() {
+            request = request.header(NUMAFLOW_KEYS_HEADER, msg.keys.join(","));
+        }
+        for (msg_header, request_header) in &self.header_mapping {
+            if let Some(value) = msg.headers.get(msg_header) {
+                request = request.header(request_header, value.as_str());
+            }
+        }
+        Self::check_response(request.body(msg.payload).send().await).await
+    }
+
+    /// Sends all the messages with a valid JSON payload in a single request. The status of the
+    /// request is the status of every message in it.
+    async fn send_batch(&self, messages: Vec<HttpSinkMessage>) -> Vec<HttpSinkResponse> {
+        let mut responses = Vec::with_capacity(messages.len());
+        let mut ids = Vec::with_capacity(messages.len());
+        let mut payloads = Vec::with_capacity(messages.len());
+        for msg in messages {
+            match serde_json::from_slice::<serde_json::Value>(&msg.payload) {
+                Ok(payload) => {
+                    ids.push(msg.id);
+                    payloads.push(payload);
+                }
+                Err(e) => responses.push(HttpSinkResponse {
+                    id: msg.id,
+                    status: Err(Error::InvalidPayload(format!(
+                        "Payload is not valid JSON: {e}"
+                    ))),
+                }),
+            }
+        }
+        if payloads.is_empty() {
+            return responses;
+        }
+
+        let status = match self.encode_batch(&payloads) {
+            Ok((content_type, body)) => {
+                let request = self.request().header(CONTENT_TYPE, content_type).body(body);
+                Self::check_response(request.send().await).await
+            }
+            Err(e) => Err(e),
+        };
+        responses.extend(ids.into_iter().map(|id| HttpSinkResponse {
+            id,
+            status: status.clone(),
+        }));
+        responses
+    }
+
+    /// Encodes the payloads in the configured batch format, returns the content type and the body.
+    fn encode_batch(&self, payloads: &[serde_json::Value]) -> Result<(&'static str, Vec<u8>)> {
+        let encode_err = |e: serde_json::Error| Error::Sink(format!("Encoding batch: {e}"));
+        match self.config.format {
+            HttpSinkFormat::Ndjson => {
+                let mut body = Vec::new();
+                for payload in payloads {
+                    serde_json::to_writer(&mut body, payload).map_err(encode_err)?;
+                    body.push(b'\n');
+                }
+                Ok(("application/x-ndjson", body))
+            }
+            _ => Ok((
+                "application/json",
+                serde_json::to_vec(payloads).map_err(encode_err)?,
+            )),
+        }
+    }
+
+    fn request(&self) -> RequestBuilder {
+        let request = self
+            .client
+            .request(self.config.method.clone(), &self.config.url);
+        match &self.config.auth {
+            Some(HttpSinkAuth::Bearer(token)) => request.bearer_auth(token),
+            Some(HttpSinkAuth::Basic { username, password }) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        }
+    }
+
+    async fn check_response(
+        response: std::result::Result<reqwest::Response, reqwest::Error>,
+    ) -> Result<()> {
+        // a request that can't be built will fail the same way every time it is sent.
+        let response = response.map_err(|e| {
+            if e.is_builder() {
+                Error::InvalidPayload(format!("Building request: {e}"))
+            } else {
+                Error::Request(e.to_string())
+            }
+        })?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let mut body = response.text().await.unwrap_or_default();
+        if body.len() > MAX_ERROR_BODY_LEN {
+            let mut end = MAX_ERROR_BODY_LEN;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+        }
+        Err(Error::Status {
+            status: status.as_u16(),
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::extract::{Path, State};
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use std::sync::{Arc, Mutex};
+
+    /// Requests received by the stub server.
+    type Received = Arc<Mutex<Vec<(axum::http::HeaderMap, Bytes)>>>;
+
+    /// Starts a stub server which records the requests and responds with the status code in the
+    /// path, e.g., `POST /200`.
+    async fn start_stub_server() -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new()
+            .route(
+                "/{status}",
+                post(
+                    |State(received): State<Received>,
+                     Path(status): Path<u16>,
+                     headers: axum::http::HeaderMap,
+                     body: Bytes| async move {
+                        received.lock().unwrap().push((headers, body));
+                        (StatusCode::from_u16(status).unwrap(), "stub response")
+                    },
+                ),
+            )
+            .with_state(Arc::clone(&received));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{addr}"), received)
+    }
+
+    fn test_message(id: &str, payload: &str) -> HttpSinkMessage {
+        HttpSinkMessage {
+            id: id.to_string(),
+            keys: vec!["k1".to_string(), "k2".to_string()],
+            headers: HashMap::from([
+                ("trace-id".to_string(), format!("trace-{id}")),
+                ("internal".to_string(), "secret".to_string()),
+            ]),
+            event_time: DateTime::from_timestamp_millis(1_700_000_000_000).unwrap(),
+            payload: Bytes::from(payload.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_format_try_from() {
+        assert_eq!(
+            HttpSinkFormat::try_from("NDJSON").unwrap(),
+            HttpSinkFormat::Ndjson
+        );
+        assert_eq!(
+            HttpSinkFormat::try_from("raw").unwrap(),
+            HttpSinkFormat::Raw
+        );
+        assert!(HttpSinkFormat::try_from("xml").is_err());
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let status = |status| Error::Status {
+            status,
+            body: String::new(),
+        };
+        assert!(status(500).is_retryable());
+        assert!(status(503).is_retryable());
+        assert!(status(408).is_retryable());
+        assert!(status(429).is_retryable());
+        assert!(!status(400).is_retryable());
+        assert!(!status(404).is_retryable());
+        assert!(Error::Request("connection refused".to_string()).is_retryable());
+        assert!(!Error::InvalidPayload("not json".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_raw_format() {
+        let (url, received) = start_stub_server().await;
+        let sink = HttpSink::new(HttpSinkConfig {
+            url: format!("{url}/200"),
+            headers: HashMap::from([("X-Static".to_string(), "static".to_string())]),
+            header_mapping: HashMap::from([("trace-id".to_string(), "X-Trace-Id".to_string())]),
+            auth: Some(HttpSinkAuth::Bearer("my-token".to_string())),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let responses = sink
+            .sink_messages(vec![test_message("1", "one"), test_message("2", "two")])
+            .await
+            .unwrap();
+        assert_eq!(responses.len(), 2);
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        for (headers, body) in received.iter() {
+            let id = headers.get("x-numaflow-id").unwrap().to_str().unwrap();
+            assert_eq!(body, &Bytes::from(if id == "1" { "one" } else { "two" }));
+            assert_eq!(
+                headers.get("x-trace-id").unwrap(),
+                format!("trace-{id}").as_str()
+            );
+            assert_eq!(headers.get("x-numaflow-keys").unwrap(), "k1,k2");
+            assert_eq!(
+                headers.get("x-numaflow-event-time").unwrap(),
+                "1700000000000"
+            );
+            assert_eq!(headers.get("x-static").unwrap(), "static");
+            assert_eq!(headers.get("authorization").unwrap(), "Bearer my-token");
+            // unmapped message headers are not forwarded
+            assert!(headers.get("internal").is_none());
+        }
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = |url: &str, header_mapping: &[(&str, &str)]| HttpSinkConfig {
+            url: url.to_string(),
+            header_mapping: header_mapping
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        assert!(
+            HttpSink::new(config(
+                "http://localhost:8080",
+                &[("trace-id", "X-Trace-Id")]
+            ))
+            .is_ok()
+        );
+        assert!(
+            HttpSink::new(config("http://localhost:8080", &[("trace-id", "X Trace")])).is_err()
+        );
+        assert!(HttpSink::new(config("http://localhost:8080", &[("trace-id", "")])).is_err());
+        assert!(HttpSink::new(config("/relative/path", &[])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_header_value_not_retryable() {
+        let (url, received) = start_stub_server().await;
+        let sink = HttpSink::new(HttpSinkConfig {
+            url: format!("{url}/200"),
+            header_mapping: HashMap::from([("trace-id".to_string(), "X-Trace-Id".to_string())]),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let mut msg = test_message("1", "one");
+        msg.headers
+            .insert("trace-id".to_string(), "line\nbreak".to_string());
+        let responses = sink.sink_messages(vec![msg]).await.unwrap();
+        let err = responses.first().unwrap().status.clone().unwrap_err();
+        assert!(matches!(err, Error::InvalidPayload(_)));
+        assert!(!err.is_retryable());
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_response_status() {
+        let (url, _) = start_stub_server().await;
+        for (status, retryable) in [(400, false), (429, true), (503, true)] {
+            let sink = HttpSink::new(HttpSinkConfig {
+                url: format!("{url}/{status}"),
+                ..Default::default()
+            })
+            .unwrap();
+            let responses = sink
+                .sink_messages(vec![test_message("1", "one")])
+                .await
+                .unwrap();
+            let err = responses.first().unwrap().status.clone().unwrap_err();
+            assert!(
+                matches!(err, Error::Status { status: s, ref body } if s == status && body == "stub response")
+            );
+            assert_eq!(err.is_retryable(), retryable);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_error() {
+        // bind and drop a listener to get a port nobody listens on
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let sink = HttpSink::new(HttpSinkConfig {
+            url: format!("http://{addr}/200"),
+            ..Default::default()
+        })
+        .unwrap();
+        let responses = sink
+            .sink_messages(vec![test_message("1", "one")])
+            .await
+            .unwrap();
+        let err = responses.first().unwrap().status.clone().unwrap_err();
+        assert!(matches!(err, Error::Request(_)));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_format() {
+        let (url, received) = start_stub_server().await;
+        let sink = HttpSink::new(HttpSinkConfig {
+            url: format!("{url}/200"),
+            format: HttpSinkFormat::Ndjson,
+            auth: Some(HttpSinkAuth::Basic {
+                username: "user".to_string(),
+                password: "pass".to_string(),
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let responses = sink
+            .sink_messages(vec![
+                test_message("1", r#"{"a": 1}"#),
+                test_message("2", "not json"),
+                test_message("3", r#"{"b": [1, 2]}"#),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(responses.len(), 3);
+        for resp in responses {
+            match resp.id.as_str() {
+                "2" => assert!(matches!(resp.status, Err(Error::InvalidPayload(_)))),
+                _ => assert!(resp.status.is_ok()),
+            }
+        }
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (headers, body) = received.first().unwrap();
+        assert_eq!(body, &Bytes::from("{\"a\":1}\n{\"b\":[1,2]}\n"));
+        assert_eq!(headers.get("content-type").unwrap(), "application/x-ndjson");
+        // base64 of "user:pass"
+        assert_eq!(headers.get("authorization").unwrap(), "Basic dXNlcjpwYXNz");
+    }
+
+    #[tokio::test]
+    async fn test_json_format_failure() {
+        let (url, received) = start_stub_server().await;
+        let sink = HttpSink::new(HttpSinkConfig {
+            url: format!("{url}/500"),
+            format: HttpSinkFormat::Json,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let responses = sink
+            .sink_messages(vec![test_message("1", "1"), test_message("2", r#""two""#)])
+            .await
+            .unwrap();
+        assert_eq!(responses.len(), 2);
+        assert!(
+            responses
+                .iter()
+                .all(|resp| matches!(resp.status, Err(Error::Status { status: 500, .. })))
+        );
+
+        let received = received.lock().unwrap();
+        let (headers, body) = received.first().unwrap();
+        assert_eq!(body, &Bytes::from(r#"[1,"two"]"#));
+        assert_eq!(headers.get("content-type").unwrap(), "application/json");
+    }
+}
@@ -19,6 +19,9 @@ pub enum Error {
     #[error("{0}")]
     NonRetryable(String),
 
+    #[error("Kafka transaction - {0}")]
+    Transaction(rdkafka::error::KafkaError),
+
     #[error("{0}")]
     Other(String),
 }
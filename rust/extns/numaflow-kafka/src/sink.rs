@@ -5,10 +5,11 @@ use futures::{StreamExt, stream::FuturesUnordered};
 use rdkafka::{
     ClientConfig,
     config::RDKafkaLogLevel,
+    error::KafkaError,
     message::{Header, OwnedHeaders},
-    producer::{FutureProducer, FutureRecord},
+    producer::{FutureProducer, FutureRecord, Producer},
 };
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{KafkaSaslAuth, TlsConfig};
 
@@ -33,13 +34,37 @@ pub struct KafkaSinkConfig {
     /// Any supported kafka client configuration options from
     /// https://docs.confluent.io/platform/current/clients/librdkafka/html/md_CONFIGURATION.html
     pub kafka_raw_config: HashMap<String, String>,
+    /// Enables the transactional mode when set. Every batch passed to [KafkaSink::sink_messages]
+    /// is written in a single transaction, and the messages are reported as written only after the
+    /// transaction is committed. If any message of the batch fails, the transaction is aborted and
+    /// all the messages of the batch are reported as failed.
+    /// The id has to be stable across restarts and unique per producer, so that a restarted producer
+    /// fences off its previous incarnation and aborts its pending transaction.
+    pub transactional_id: Option<String>,
 }
 
+/// Timeout for the transactional operations which block on the broker, i.e., initializing,
+/// committing, and aborting transactions.
+const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of attempts to commit a transaction when the commit fails with a retriable error.
+const COMMIT_ATTEMPTS: usize = 3;
+
 /// The Kafka sink client.
 pub struct KafkaSink {
     topic: String,
     producer: FutureProducer,
     set_partition_key: bool,
+    /// Only set in the transactional mode.
+    transactions: Option<TransactionState>,
+}
+
+/// State of the transactional producer.
+struct TransactionState {
+    /// Used to recreate the producer after a fatal error, e.g., when it has been fenced.
+    client_config: ClientConfig,
+    /// Whether the transactions have been initialized for the current producer.
+    initialized: bool,
 }
 
 /// The result of a sink operation corresponding to a [KafkaSinkMessage] with same id.
@@ -89,6 +114,16 @@ pub fn new_sink(config: KafkaSinkConfig) -> crate::Result<KafkaSink> {
 
     crate::update_auth_config(&mut client_config, config.tls, config.auth);
 
+    if let Some(transactional_id) = &config.transactional_id {
+        info!(
+            transactional_id,
+            "Kafka sink is running in transactional mode"
+        );
+        client_config
+            .set("transactional.id", transactional_id)
+            .set("enable.idempotence", "true");
+    }
+
     let producer: FutureProducer = client_config
         .create()
         .map_err(|e| crate::Error::Kafka(format!("Failed to create producer: {e}")))?;
@@ -97,15 +132,159 @@ pub fn new_sink(config: KafkaSinkConfig) -> crate::Result<KafkaSink> {
         producer,
         topic: config.topic,
         set_partition_key: config.set_partition_key,
+        transactions: config.transactional_id.map(|_| TransactionState {
+            client_config,
+            initialized: false,
+        }),
     })
 }
 
+/// The producer can no longer be used, and has to be recreated.
+fn is_fatal(e: &KafkaError) -> bool {
+    matches!(e, KafkaError::Transaction(e) if e.is_fatal())
+}
+
+/// The operation can be retried.
+fn is_retriable(e: &KafkaError) -> bool {
+    matches!(e, KafkaError::Transaction(e) if e.is_retriable())
+}
+
 impl KafkaSink {
-    /// Send the messages to Kafka.
+    /// Send the messages to Kafka. In the transactional mode, all the messages are written in a
+    /// single transaction, see [KafkaSinkConfig::transactional_id].
     pub async fn sink_messages(
         &mut self,
         messages: Vec<KafkaSinkMessage>,
     ) -> crate::Result<Vec<KafkaSinkResponse>> {
+        if self.transactions.is_none() {
+            return Ok(self.send_messages(messages).await);
+        }
+
+        let ids: Vec<String> = messages.iter().map(|msg| msg.id.clone()).collect();
+        match self.begin_transaction().await {
+            Ok(()) => {}
+            Err(crate::Error::Transaction(e)) => {
+                return self
+                    .fail_transaction(ids, format!("Beginning transaction: {e}"), e)
+                    .await;
+            }
+            Err(e) => return Err(e),
+        }
+
+        let responses = self.send_messages(messages).await;
+        if let Some(err) = responses.iter().find_map(|resp| resp.status.as_ref().err()) {
+            let reason =
+                format!("Aborted transaction, sending a message of the batch failed: {err}");
+            return self.abort_transaction(ids, reason).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            let producer = self.producer.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                producer.commit_transaction(TRANSACTION_TIMEOUT)
+            })
+            .await
+            .map_err(|e| crate::Error::Other(format!("Committing transaction: {e}")))?;
+            match result {
+                Ok(()) => return Ok(responses),
+                Err(e) if is_retriable(&e) && attempt < COMMIT_ATTEMPTS => {
+                    warn!(?e, attempt, "Retrying to commit transaction");
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return self
+                        .fail_transaction(ids, format!("Committing transaction: {e}"), e)
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Initializes the transactions if required, and begins a new transaction. The errors returned
+    /// by Kafka are [crate::Error::Transaction], so that the transaction can be aborted.
+    async fn begin_transaction(&mut self) -> crate::Result<()> {
+        let transactions = self
+            .transactions
+            .as_mut()
+            .expect("only called in transactional mode");
+        if !transactions.initialized {
+            // fences off the previous producer with the same transactional id, and aborts its
+            // pending transaction.
+            let producer = self.producer.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                producer.init_transactions(TRANSACTION_TIMEOUT)
+            })
+            .await
+            .map_err(|e| crate::Error::Other(format!("Initializing transactions: {e}")))?;
+            result.map_err(crate::Error::Transaction)?;
+            transactions.initialized = true;
+        }
+        self.producer
+            .begin_transaction()
+            .map_err(crate::Error::Transaction)
+    }
+
+    /// Handles an error of a transactional operation. The producer is recreated after a fatal
+    /// error, otherwise the transaction is aborted.
+    async fn fail_transaction(
+        &mut self,
+        ids: Vec<String>,
+        reason: String,
+        e: KafkaError,
+    ) -> crate::Result<Vec<KafkaSinkResponse>> {
+        if is_fatal(&e) {
+            self.recreate_producer(&e)?;
+            return Ok(Self::failed(ids, reason));
+        }
+        self.abort_transaction(ids, reason).await
+    }
+
+    /// Aborts the current transaction, all the messages of the batch are reported as failed so that
+    /// the batch is retried, or written to the fallback sink.
+    async fn abort_transaction(
+        &mut self,
+        ids: Vec<String>,
+        reason: String,
+    ) -> crate::Result<Vec<KafkaSinkResponse>> {
+        warn!(reason, "Aborting Kafka transaction");
+        let producer = self.producer.clone();
+        let result =
+            tokio::task::spawn_blocking(move || producer.abort_transaction(TRANSACTION_TIMEOUT))
+                .await
+                .map_err(|e| crate::Error::Other(format!("Aborting transaction: {e}")))?;
+        if let Err(e) = result {
+            // the producer is recreated, initializing its transactions aborts the pending one.
+            self.recreate_producer(&e)?;
+        }
+        Ok(Self::failed(ids, reason))
+    }
+
+    fn recreate_producer(&mut self, e: &KafkaError) -> crate::Result<()> {
+        warn!(?e, "Recreating transactional Kafka producer");
+        let transactions = self
+            .transactions
+            .as_mut()
+            .expect("only called in transactional mode");
+        self.producer = transactions
+            .client_config
+            .create()
+            .map_err(|e| crate::Error::Kafka(format!("Failed to create producer: {e}")))?;
+        transactions.initialized = false;
+        Ok(())
+    }
+
+    fn failed(ids: Vec<String>, reason: String) -> Vec<KafkaSinkResponse> {
+        ids.into_iter()
+            .map(|id| KafkaSinkResponse {
+                id,
+                status: Err(crate::Error::Kafka(reason.clone())),
+            })
+            .collect()
+    }
+
+    /// Sends the messages concurrently, and waits for their delivery.
+    async fn send_messages(&self, messages: Vec<KafkaSinkMessage>) -> Vec<KafkaSinkResponse> {
         // Create futures for all send operations and concurrently await on them
         let mut send_futures = FuturesUnordered::new();
         let message_count = messages.len();
@@ -156,7 +335,7 @@ impl KafkaSink {
         while let Some(status) = send_futures.next().await {
             results.push(status);
         }
-        Ok(results)
+        results
     }
 }
 
@@ -255,6 +434,7 @@ mod tests {
             kafka_raw_config: HashMap::from([
                 ("connections.max.idle.ms".to_string(), "540000".to_string()), // 9 minutes, default value
             ]),
+            transactional_id: None,
         };
         let mut sink = new_sink(config).expect("Failed to create KafkaSink");
         let mut headers = HashMap::new();
@@ -292,6 +472,7 @@ mod tests {
             tls: None,
             set_partition_key: false,
             kafka_raw_config: HashMap::new(),
+            transactional_id: None,
         };
         let mut sink = new_sink(config).expect("Failed to create KafkaSink");
         let mut messages = Vec::new();
@@ -323,4 +504,58 @@ mod tests {
             assert!(seen_payloads.contains(&format!("payload-{}", i)));
         }
     }
+
+    #[cfg(feature = "kafka-tests")]
+    #[tokio::test]
+    async fn test_kafka_sink_transactional() {
+        let (_producer, topic_name) = test_utils::setup_test_topic().await;
+        let config = KafkaSinkConfig {
+            brokers: vec!["localhost:9092".to_string()],
+            topic: topic_name.clone(),
+            auth: None,
+            tls: None,
+            set_partition_key: false,
+            kafka_raw_config: HashMap::new(),
+            transactional_id: Some(format!("{topic_name}-0")),
+        };
+        let mut sink = new_sink(config.clone()).expect("Failed to create KafkaSink");
+        let batch = |start: usize| {
+            (start..start + 3)
+                .map(|i| KafkaSinkMessage {
+                    id: format!("msg{i}"),
+                    partition_key: None,
+                    headers: HashMap::new(),
+                    payload: Bytes::from(format!("payload-{i}")),
+                })
+                .collect::<Vec<_>>()
+        };
+        let responses = sink
+            .sink_messages(batch(0))
+            .await
+            .expect("Failed to send messages");
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+
+        // a new producer with the same transactional id fences off the previous one
+        let mut restarted = new_sink(config).expect("Failed to create KafkaSink");
+        let responses = restarted
+            .sink_messages(batch(3))
+            .await
+            .expect("Failed to send messages");
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+        let responses = sink
+            .sink_messages(batch(6))
+            .await
+            .expect("Failed to send messages");
+        assert_eq!(responses.len(), 3);
+        assert!(responses.iter().all(|resp| resp.status.is_err()));
+
+        // only the committed messages are visible to the read_committed consumer
+        let messages = test_utils::consume_messages_from_topic(&topic_name, 6).await;
+        let payloads: Vec<String> = messages
+            .iter()
+            .map(|msg| String::from_utf8_lossy(&msg.payload).to_string())
+            .collect();
+        let expected: Vec<String> = (0..6).map(|i| format!("payload-{i}")).collect();
+        assert_eq!(payloads, expected);
+    }
 }
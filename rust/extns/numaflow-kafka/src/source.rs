@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::{Arc, Mutex};
 use std::{collections::HashMap, time::Duration};
 
 use bytes::Bytes;
@@ -8,9 +9,9 @@ use rdkafka::config::{ClientConfig, RDKafkaLogLevel};
 use rdkafka::consumer::stream_consumer::StreamConsumer;
 use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer, ConsumerContext, Rebalance};
 use rdkafka::error::KafkaResult;
-use rdkafka::message::{Headers, Message};
+use rdkafka::message::{BorrowedMessage, Headers, Message};
 use rdkafka::topic_partition_list::TopicPartitionList;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, watch};
 use tokio_util::sync::CancellationToken;
 
 use tracing::{error, info, warn};
@@ -45,6 +46,8 @@ pub struct KafkaMessage {
     pub key: Option<String>,
     /// The partition number.
     pub partition: i32,
+    /// Index of the partition across all the topics, see [KafkaSource::assigned_partitions].
+    pub partition_idx: u16,
     /// The offset of the message.
     pub offset: i64,
     /// The headers of the message.
@@ -54,19 +57,122 @@ pub struct KafkaMessage {
     pub timestamp: Option<i64>,
 }
 
+/// Number of partition indexes reserved for every topic of a multi-topic source, the index of a
+/// partition is `topic index * MAX_PARTITIONS_PER_TOPIC + partition`.
+const MAX_PARTITIONS_PER_TOPIC: usize = 1024;
+
+/// Returns the index of the partition of the topic across all the topics of the source, so that the
+/// same partition number of different topics gets different watermarks. The index of a single
+/// topic is the partition number. None if the index does not fit.
+fn partition_idx(topics: &[String], topic: &str, partition: i32) -> Option<u16> {
+    let topic_idx = topics.iter().position(|t| t == topic)?;
+    let partition = usize::try_from(partition).ok()?;
+    if topics.len() == 1 {
+        return u16::try_from(partition).ok();
+    }
+    if partition >= MAX_PARTITIONS_PER_TOPIC {
+        return None;
+    }
+    u16::try_from(topic_idx * MAX_PARTITIONS_PER_TOPIC + partition).ok()
+}
+
+/// Partitions of each topic assigned to this consumer.
+#[derive(Debug)]
+struct Assignment {
+    /// Topics of the source, the order gives the partition indexes.
+    topics: Vec<String>,
+    partitions: HashMap<String, HashSet<i32>>,
+}
+
+impl Assignment {
+    fn new(topics: Vec<String>) -> Self {
+        Self {
+            topics,
+            partitions: HashMap::new(),
+        }
+    }
+
+    fn assign(&mut self, tpl: &TopicPartitionList) {
+        for elem in tpl.elements() {
+            self.partitions
+                .entry(elem.topic().to_string())
+                .or_default()
+                .insert(elem.partition());
+        }
+    }
+
+    fn revoke(&mut self, tpl: &TopicPartitionList) {
+        for elem in tpl.elements() {
+            if let Some(partitions) = self.partitions.get_mut(elem.topic()) {
+                partitions.remove(&elem.partition());
+            }
+        }
+    }
+
+    fn contains(&self, topic: &str, partition: i32) -> bool {
+        self.partitions
+            .get(topic)
+            .is_some_and(|partitions| partitions.contains(&partition))
+    }
+
+    /// Indexes of the partitions assigned across all the topics.
+    fn partition_idxs(&self) -> BTreeSet<u16> {
+        self.partitions
+            .iter()
+            .flat_map(|(topic, partitions)| {
+                partitions.iter().filter_map(|partition| {
+                    let idx = partition_idx(&self.topics, topic, *partition);
+                    if idx.is_none() {
+                        error!(
+                            ?topic,
+                            partition = *partition,
+                            max = MAX_PARTITIONS_PER_TOPIC,
+                            "Partition exceeds the maximum number of partitions per topic of a multi-topic source"
+                        );
+                    }
+                    idx
+                })
+            })
+            .collect()
+    }
+}
+
 // A context can be used to change the behavior of consumers by adding callbacks
 // that will be executed by librdkafka.
-struct KafkaContext;
+struct KafkaContext {
+    /// Updated by the rebalance callbacks, used to fence the acks of the revoked partitions.
+    assignment: Mutex<Assignment>,
+    /// Notifies the partition indexes assigned to this consumer after every rebalance.
+    assigned_tx: watch::Sender<BTreeSet<u16>>,
+}
+
+impl KafkaContext {
+    fn update_assignment(&self, update: impl FnOnce(&mut Assignment)) {
+        let mut assignment = self.assignment.lock().expect("assignment lock poisoned");
+        update(&mut assignment);
+        self.assigned_tx.send_replace(assignment.partition_idxs());
+    }
+}
 
 impl ClientContext for KafkaContext {}
 
 impl ConsumerContext for KafkaContext {
+    // The revoked partitions are fenced before the revocation takes effect, so that none of their
+    // in-flight offsets are committed after another consumer of the group starts reading them.
     fn pre_rebalance(&self, _: &BaseConsumer<Self>, rebalance: &Rebalance<'_>) {
         info!("Pre rebalance {:?}", rebalance);
+        match rebalance {
+            Rebalance::Revoke(tpl) => self.update_assignment(|assignment| assignment.revoke(tpl)),
+            Rebalance::Error(e) => warn!(?e, "Rebalance failed"),
+            Rebalance::Assign(_) => {}
+        }
     }
 
     fn post_rebalance(&self, _: &BaseConsumer<Self>, rebalance: &Rebalance<'_>) {
         info!("Post rebalance {:?}", rebalance);
+        if let Rebalance::Assign(tpl) = rebalance {
+            self.update_assignment(|assignment| assignment.assign(tpl));
+        }
     }
 
     fn commit_callback(&self, result: KafkaResult<()>, _offsets: &TopicPartitionList) {
@@ -119,7 +225,15 @@ impl KafkaActor {
         read_timeout: Duration,
         handler_rx: mpsc::Receiver<KafkaActorMessage>,
         cancel_token: CancellationToken,
-    ) -> Result<()> {
+    ) -> Result<watch::Receiver<BTreeSet<u16>>> {
+        if config.topics.len() > usize::from(u16::MAX) / MAX_PARTITIONS_PER_TOPIC {
+            return Err(Error::Kafka(format!(
+                "Too many topics {}, a source supports at most {} topics",
+                config.topics.len(),
+                usize::from(u16::MAX) / MAX_PARTITIONS_PER_TOPIC
+            )));
+        }
+
         let mut client_config = ClientConfig::new();
         // https://docs.confluent.io/platform/current/clients/librdkafka/html/md_CONFIGURATION.html
         client_config
@@ -149,7 +263,11 @@ impl KafkaActor {
 
         crate::update_auth_config(&mut client_config, config.tls, config.auth);
 
-        let context = KafkaContext;
+        let (assigned_tx, assigned_rx) = watch::channel(BTreeSet::new());
+        let context = KafkaContext {
+            assignment: Mutex::new(Assignment::new(config.topics.clone())),
+            assigned_tx,
+        };
         let consumer: Arc<NumaflowConsumer> =
             Arc::new(client_config.create_with_context(context).map_err(|err| {
                 Error::Connection {
@@ -190,7 +308,7 @@ impl KafkaActor {
             actor.run().await;
         });
 
-        Ok(())
+        Ok(assigned_rx)
     }
 
     // run method will only return when the sender end of the handler_rx is closed.
@@ -253,6 +371,54 @@ impl KafkaActor {
         }
     }
 
+    /// Converts the message read from Kafka.
+    fn kafka_message(&self, message: &BorrowedMessage<'_>) -> Result<KafkaMessage> {
+        let mut headers = match message.headers() {
+            Some(headers) => headers
+                .iter()
+                .map(|header| {
+                    (
+                        header.key.to_string(),
+                        String::from_utf8_lossy(header.value.unwrap_or_default()).to_string(),
+                    )
+                })
+                .collect(),
+            None => HashMap::new(),
+        };
+        headers.insert(
+            crate::KAFKA_TOPIC_HEADER_KEY.to_string(),
+            message.topic().to_string(),
+        );
+
+        let value = match message.payload() {
+            Some(payload) => Bytes::copy_from_slice(payload),
+            // The rdkafka doc says that the payload can be None if there is no payload.
+            None => Bytes::new(),
+        };
+
+        let partition_idx = partition_idx(&self.topics, message.topic(), message.partition())
+            .ok_or_else(|| {
+                Error::Kafka(format!(
+                    "Partition {} of topic {} exceeds the maximum of {MAX_PARTITIONS_PER_TOPIC} partitions per topic of a multi-topic source",
+                    message.partition(),
+                    message.topic()
+                ))
+            })?;
+
+        Ok(KafkaMessage {
+            topic: message.topic().to_string(),
+            value,
+            key: message
+                .key()
+                .map(|k| String::from_utf8_lossy(k).to_string()),
+            partition: message.partition(),
+            partition_idx,
+            offset: message.offset(),
+            headers,
+            timestamp: message.timestamp().to_millis(),
+        })
+    }
+
     async fn read_messages(&mut self) -> Option<Result<Vec<KafkaMessage>>> {
         if self.cancel_token.is_cancelled() {
             return None;
@@ -309,36 +475,9 @@ impl KafkaActor {
                         }
                     };
 
-                    let mut headers = match message.headers() {
-                        Some(headers) => headers
-                            .iter()
-                            .map(|header| {
-                                (
-                                    header.key.to_string(),
-                                    String::from_utf8_lossy(header.value.unwrap_or_default()).to_string(),
-                                )
-                            })
-                            .collect(),
-                        None => HashMap::new(),
-                    };
-                    headers.insert(crate::KAFKA_TOPIC_HEADER_KEY.to_string(), message.topic().to_string());
-
-                    let value = match message.payload() {
-                        Some(payload) => Bytes::copy_from_slice(payload),
-                        // The rdkafka doc says that the payload can be None if there is no payload.
-                        None => Bytes::new(),
-                    };
-
-                    let timestamp = message.timestamp().to_millis();
-
-                    let message = KafkaMessage {
-                        topic: message.topic().to_string(),
-                        value,
-                        key: message.key().map(|k| String::from_utf8_lossy(k).to_string()),
-                        partition: message.partition(),
-                        offset: message.offset(),
-                        headers,
-                        timestamp,
+                    let message = match self.kafka_message(&message) {
+                        Ok(message) => message,
+                        Err(e) => return Some(Err(e)),
                     };
 
                     messages.push(message);
@@ -349,8 +488,31 @@ impl KafkaActor {
         Some(Ok(messages))
     }
 
+    /// Drops the offsets of the partitions which are no longer assigned to this consumer. The
+    /// partition is owned by another consumer of the group, which reads the messages again from the
+    /// last committed offset.
+    fn fence_revoked_offsets(&self, offsets: Vec<KafkaOffset>) -> Vec<KafkaOffset> {
+        let assignment = self
+            .consumer
+            .context()
+            .assignment
+            .lock()
+            .expect("assignment lock poisoned");
+        let (owned, revoked): (Vec<KafkaOffset>, Vec<KafkaOffset>) = offsets
+            .into_iter()
+            .partition(|offset| assignment.contains(&offset.topic, offset.partition));
+        if !revoked.is_empty() {
+            warn!(
+                count = revoked.len(),
+                "Dropping acks of the partitions revoked by a rebalance"
+            );
+        }
+        owned
+    }
+
     async fn ack_messages(&mut self, offsets: Vec<KafkaOffset>) -> Result<()> {
         use std::collections::HashMap;
+        let offsets = self.fence_revoked_offsets(offsets);
         // topic -> partition -> offset
         let mut topic_partition_offsets: HashMap<String, HashMap<i32, i64>> = HashMap::new();
 
@@ -394,15 +556,16 @@ impl KafkaActor {
                 if let Some(code) = commit_error.rdkafka_error_code() {
                     use rdkafka::types::RDKafkaErrorCode::{
                         FencedInstanceId, FencedMemberEpoch, GroupAuthorizationFailed,
-                        IllegalGeneration, RebalanceInProgress, StaleMemberEpoch, UnknownMemberId,
+                        StaleMemberEpoch, UnknownMemberId,
                     };
                     // Potential non-retryable errors that can happen in ACK https://kafka.apache.org/41/design/protocol/#error-codes
+                    // RebalanceInProgress and IllegalGeneration are retried, once the rebalance
+                    // completes the offsets are either committed with the new generation or fenced
+                    // if their partitions were revoked.
                     if matches!(
                         code,
                         // Consumer group membership errors
                         UnknownMemberId
-                            | IllegalGeneration
-                            | RebalanceInProgress
                             | FencedInstanceId
                             | FencedMemberEpoch
                             | StaleMemberEpoch
@@ -539,6 +702,7 @@ impl KafkaActor {
 #[derive(Clone)]
 pub struct KafkaSource {
     actor_tx: mpsc::Sender<KafkaActorMessage>,
+    assigned_rx: watch::Receiver<BTreeSet<u16>>,
 }
 
 impl KafkaSource {
@@ -549,8 +713,23 @@ impl KafkaSource {
         cancel_token: CancellationToken,
     ) -> Result<Self> {
         let (tx, rx) = mpsc::channel(10);
-        KafkaActor::start(config, batch_size, read_timeout, rx, cancel_token).await?;
-        Ok(Self { actor_tx: tx })
+        let assigned_rx =
+            KafkaActor::start(config, batch_size, read_timeout, rx, cancel_token).await?;
+        Ok(Self {
+            actor_tx: tx,
+            assigned_rx,
+        })
+    }
+
+    /// Returns the indexes of the partitions currently assigned to this consumer across all the
+    /// topics, the receiver is notified after every consumer group rebalance. The index of a
+    /// partition is its number with a single topic, and `topic index * 1024 + partition` with
+    /// multiple topics (at most 1024 partitions per topic), so that every partition of every topic
+    /// has its own watermark. The set is empty until the consumer joins the group, which happens
+    /// while reading, the reads return no messages till then. The acks of the offsets of the
+    /// revoked partitions are dropped.
+    pub fn assigned_partitions(&self) -> watch::Receiver<BTreeSet<u16>> {
+        self.assigned_rx.clone()
     }
 
     pub async fn read_messages(&self) -> Option<Result<Vec<KafkaMessage>>> {
@@ -646,6 +825,42 @@ mod tests {
     use rdkafka::producer::FutureRecord;
     use tokio::time::Instant;
 
+    #[test]
+    fn test_assignment() {
+        let mut assigned = TopicPartitionList::new();
+        assigned.add_partition("topic-a", 0);
+        assigned.add_partition("topic-a", 1);
+        assigned.add_partition("topic-b", 1);
+        let mut assignment = Assignment::new(vec!["topic-a".to_string(), "topic-b".to_string()]);
+        assignment.assign(&assigned);
+        assert!(assignment.contains("topic-a", 0));
+        assert!(!assignment.contains("topic-b", 0));
+        assert_eq!(assignment.partition_idxs(), BTreeSet::from([0, 1, 1025]));
+
+        let mut revoked = TopicPartitionList::new();
+        revoked.add_partition("topic-a", 1);
+        assignment.revoke(&revoked);
+        assert!(!assignment.contains("topic-a", 1));
+        // partition 1 of topic-b has its own index
+        assert_eq!(assignment.partition_idxs(), BTreeSet::from([0, 1025]));
+
+        revoked.add_partition("topic-b", 1);
+        assignment.revoke(&revoked);
+        assert_eq!(assignment.partition_idxs(), BTreeSet::from([0]));
+    }
+
+    #[test]
+    fn test_partition_idx() {
+        let single = vec!["topic-a".to_string()];
+        assert_eq!(partition_idx(&single, "topic-a", 2000), Some(2000));
+        assert_eq!(partition_idx(&single, "topic-b", 0), None);
+
+        let multi = vec!["topic-a".to_string(), "topic-b".to_string()];
+        assert_eq!(partition_idx(&multi, "topic-a", 3), Some(3));
+        assert_eq!(partition_idx(&multi, "topic-b", 3), Some(1027));
+        assert_eq!(partition_idx(&multi, "topic-b", 1024), None);
+    }
+
     #[cfg(all(feature = "kafka-tests", feature = "kafka-tests-utils"))]
     #[tokio::test]
     async fn test_kafka_source() {
@@ -899,4 +1114,53 @@ mod tests {
         // Verify that timestamp is present (should be Some since Kafka sets timestamps)
         assert!(message.timestamp.is_some());
     }
+
+    #[cfg(all(feature = "kafka-tests", feature = "kafka-tests-utils"))]
+    #[tokio::test]
+    async fn test_kafka_source_assignment_while_reading() {
+        let (producer, topic_name) = test_utils::setup_test_topic().await;
+        test_utils::produce_test_messages(&producer, &topic_name, 10).await;
+
+        let config = KafkaSourceConfig {
+            brokers: vec!["localhost:9092".to_string()],
+            topics: vec![topic_name.clone()],
+            consumer_group: "test_assignment_consumer_group".to_string(),
+            auth: None,
+            tls: None,
+            kafka_raw_config: HashMap::new(),
+        };
+
+        // connecting does not wait for the consumer to join the group
+        let source = KafkaSource::connect(
+            config,
+            10,
+            Duration::from_millis(500),
+            CancellationToken::new(),
+        )
+        .await
+        .expect("Failed to connect to Kafka");
+
+        // the reads return no messages till the partitions are assigned
+        let mut assigned_rx = source.assigned_partitions();
+        let mut messages = vec![];
+        for _ in 0..60 {
+            messages.extend(
+                source
+                    .read_messages()
+                    .await
+                    .expect("Failed to read messages")
+                    .unwrap(),
+            );
+            if messages.len() >= 10 {
+                break;
+            }
+        }
+        assert_eq!(messages.len(), 10);
+
+        // the partitions of the messages read are assigned to the consumer
+        let assigned = assigned_rx.borrow_and_update().clone();
+        for message in &messages {
+            assert!(assigned.contains(&message.partition_idx));
+        }
+    }
 }
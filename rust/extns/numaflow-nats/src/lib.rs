@@ -1,5 +1,6 @@
 pub mod jetstream;
 pub mod nats;
+pub mod sink;
 
 mod tls;
 
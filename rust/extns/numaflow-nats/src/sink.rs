@@ -0,0 +1,522 @@
+//! NATS sink publishes messages to a NATS subject, either over core NATS or JetStream. The subject
+//! can be a template which is rendered per message from its keys and headers. When publishing to
+//! JetStream, every message carries a `Nats-Msg-Id` header so that the server can de-duplicate
+//! re-deliveries within the stream's duplicate window.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_nats::jetstream::context::PublishAckFuture;
+use async_nats::{ConnectOptions, HeaderMap};
+use bytes::Bytes;
+use tracing::{error, info};
+
+use crate::{Error, NatsAuth, Result, TlsConfig, tls};
+
+/// Header used by JetStream for message de-duplication.
+/// https://docs.nats.io/using-nats/developer/develop_jetstream/model_deep_dive#message-deduplication
+const NATS_MSG_ID_HEADER: &str = "Nats-Msg-Id";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NatsSinkConfig {
+    pub addr: String,
+    /// Subject to publish to. It can contain placeholders which are rendered per message, see
+    /// [SubjectTemplate].
+    pub subject: String,
+    pub auth: Option<NatsAuth>,
+    pub tls: Option<TlsConfig>,
+    /// Publish through JetStream and wait for the publish acks from the server.
+    pub jetstream: bool,
+    /// How long to wait for a JetStream publish ack. Only used when `jetstream` is set.
+    pub ack_timeout: Duration,
+}
+
+/// The message to publish to NATS. Input to [NatsSink::sink_messages].
+#[derive(Debug, Clone)]
+pub struct NatsSinkMessage {
+    /// ID of the message, it is also set as the `Nats-Msg-Id` header for de-duplication.
+    pub id: String,
+    /// Keys of the message, used to render the subject template.
+    pub keys: Vec<String>,
+    /// Headers to publish with the message, also used to render the subject template.
+    pub headers: HashMap<String, String>,
+    /// The message payload.
+    pub payload: Bytes,
+}
+
+/// The result of a publish corresponding to a [NatsSinkMessage] with same id.
+pub struct NatsSinkResponse {
+    /// ID of the message that was published.
+    pub id: String,
+    /// Status of the publish operation.
+    pub status: Result<()>,
+}
+
+/// A segment of a parsed [SubjectTemplate].
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    /// `{{key.N}}`, N-th key of the message.
+    Key(usize),
+    /// `{{keys}}`, all the keys of the message joined by `.`.
+    Keys,
+    /// `{{header.NAME}}`, value of the header NAME.
+    Header(String),
+}
+
+/// SubjectTemplate is a NATS subject with placeholders rendered per message. Supported placeholders
+/// are `{{key.N}}` (N-th key), `{{keys}}` (all keys joined by `.`) and `{{header.NAME}}` (value of
+/// header NAME). E.g., `orders.{{header.region}}.{{key.0}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubjectTemplate {
+    segments: Vec<Segment>,
+}
+
+impl TryFrom<&str> for SubjectTemplate {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut segments = vec![];
+        let mut rest = value;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Literal(rest[..start].to_string()));
+            }
+            let Some(end) = rest[start..].find("}}") else {
+                return Err(Error::Other(format!(
+                    "Unterminated placeholder in subject template '{value}'"
+                )));
+            };
+            let placeholder = rest[start + 2..start + end].trim();
+            let segment = match placeholder.split_once('.') {
+                None if placeholder == "keys" => Segment::Keys,
+                Some(("key", idx)) => Segment::Key(idx.parse::<usize>().map_err(|e| {
+                    Error::Other(format!(
+                        "Invalid key index '{idx}' in subject template '{value}': {e}"
+                    ))
+                })?),
+                Some(("header", name)) if !name.is_empty() => Segment::Header(name.to_string()),
+                _ => {
+                    return Err(Error::Other(format!(
+                        "Unknown placeholder '{placeholder}' in subject template '{value}'"
+                    )));
+                }
+            };
+            segments.push(segment);
+            rest = &rest[start + end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_string()));
+        }
+        if segments.is_empty() {
+            return Err(Error::Other("Subject cannot be empty".to_string()));
+        }
+        Ok(Self { segments })
+    }
+}
+
+impl SubjectTemplate {
+    /// Renders the subject for the given keys and headers. Fails if a referenced key or header is
+    /// missing, or if the rendered value would change the subject hierarchy (contains whitespace, the
+    /// `.` token separator or the `*` and `>` wildcards).
+    pub fn render(&self, keys: &[String], headers: &HashMap<String, String>) -> Result<String> {
+        let mut subject = String::new();
+        for segment in &self.segments {
+            let value = match segment {
+                Segment::Literal(literal) => {
+                    subject.push_str(literal);
+                    continue;
+                }
+                Segment::Key(idx) => keys
+                    .get(*idx)
+                    .cloned()
+                    .ok_or_else(|| Error::Other(format!("Key at index {idx} not found")))?,
+                Segment::Keys => {
+                    if keys.is_empty() {
+                        return Err(Error::Other("Message has no keys".to_string()));
+                    }
+                    keys.join(".")
+                }
+                Segment::Header(name) => headers
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| Error::Other(format!("Header {name} not found")))?,
+            };
+            // `{{keys}}` joins the keys with `.` on purpose, so each key is validated on its own.
+            let tokens: Vec<&str> = match segment {
+                Segment::Keys => keys.iter().map(String::as_str).collect(),
+                _ => vec![value.as_str()],
+            };
+            for token in tokens {
+                if token.is_empty()
+                    || token.contains(|c: char| c.is_whitespace() || matches!(c, '.' | '*' | '>'))
+                {
+                    return Err(Error::Other(format!(
+                        "Invalid subject token '{token}', it should be non-empty without whitespaces, '.', '*' or '>'"
+                    )));
+                }
+            }
+            subject.push_str(&value);
+        }
+        Ok(subject)
+    }
+}
+
+/// Where the messages are published to.
+enum Publisher {
+    Core(async_nats::Client),
+    Jetstream(async_nats::jetstream::Context),
+}
+
+/// The NATS sink client.
+pub struct NatsSink {
+    publisher: Publisher,
+    subject: SubjectTemplate,
+}
+
+impl NatsSink {
+    /// Connects to NATS and creates a new sink.
+    pub async fn connect(config: NatsSinkConfig) -> Result<Self> {
+        let subject = SubjectTemplate::try_from(config.subject.as_str())?;
+
+        let mut conn_opts = ConnectOptions::new()
+            .max_reconnects(None) // unlimited reconnects
+            .reconnect_delay_callback(|attempts| {
+                Duration::from_millis(std::cmp::min((attempts * 10) as u64, 1000))
+            })
+            .ping_interval(Duration::from_secs(3))
+            .retry_on_initial_connect();
+        if let Some(auth) = config.auth {
+            conn_opts = match auth {
+                NatsAuth::Basic { username, password } => {
+                    conn_opts.user_and_password(username, password)
+                }
+                NatsAuth::NKey(nkey) => conn_opts.nkey(nkey),
+                NatsAuth::Token(token) => conn_opts.token(token),
+            };
+        }
+        if let Some(tls_config) = config.tls {
+            conn_opts = tls::configure_tls(conn_opts, tls_config)?;
+        }
+        let client = async_nats::connect_with_options(&config.addr, conn_opts)
+            .await
+            .map_err(|err| Error::Connection {
+                server: config.addr.to_string(),
+                error: err.to_string(),
+            })?;
+
+        let publisher = if config.jetstream {
+            let mut js_ctx = async_nats::jetstream::new(client);
+            js_ctx.set_timeout(config.ack_timeout);
+            Publisher::Jetstream(js_ctx)
+        } else {
+            Publisher::Core(client)
+        };
+        info!(subject = ?config.subject, jetstream = ?config.jetstream, "Created NATS sink");
+
+        Ok(Self { publisher, subject })
+    }
+
+    /// Publishes the messages to NATS. For JetStream, a message is successful only once its
+    /// publish ack is received. For core NATS, the batch is flushed to the server before returning.
+    pub async fn sink_messages(
+        &mut self,
+        messages: Vec<NatsSinkMessage>,
+    ) -> Result<Vec<NatsSinkResponse>> {
+        match &self.publisher {
+            Publisher::Core(client) => Self::publish_core(client, &self.subject, messages).await,
+            Publisher::Jetstream(js_ctx) => {
+                Self::publish_jetstream(js_ctx, &self.subject, messages).await
+            }
+        }
+    }
+
+    async fn publish_core(
+        client: &async_nats::Client,
+        subject: &SubjectTemplate,
+        messages: Vec<NatsSinkMessage>,
+    ) -> Result<Vec<NatsSinkResponse>> {
+        let mut responses = Vec::with_capacity(messages.len());
+        for msg in messages {
+            let status = match subject.render(&msg.keys, &msg.headers) {
+                Ok(subject) => client
+                    .publish_with_headers(subject, to_header_map(&msg), msg.payload)
+                    .await
+                    .map_err(|e| Error::Nats(format!("Publishing message {}: {e}", msg.id))),
+                Err(e) => Err(e),
+            };
+            responses.push(NatsSinkResponse { id: msg.id, status });
+        }
+
+        // publish only buffers the messages on the client, flush makes sure they are written to
+        // the server before we ack them.
+        if let Err(e) = client.flush().await {
+            error!(?e, "Flushing messages to NATS");
+            for resp in responses.iter_mut().filter(|resp| resp.status.is_ok()) {
+                resp.status = Err(Error::Nats(format!("Flushing messages: {e}")));
+            }
+        }
+        Ok(responses)
+    }
+
+    async fn publish_jetstream(
+        js_ctx: &async_nats::jetstream::Context,
+        subject: &SubjectTemplate,
+        messages: Vec<NatsSinkMessage>,
+    ) -> Result<Vec<NatsSinkResponse>> {
+        // publish all the messages first and then wait for the acks.
+        let mut pending: Vec<(String, Result<PublishAckFuture>)> =
+            Vec::with_capacity(messages.len());
+        for msg in messages {
+            let ack_fut = match subject.render(&msg.keys, &msg.headers) {
+                Ok(subject) => js_ctx
+                    .publish_with_headers(subject, to_header_map(&msg), msg.payload)
+                    .await
+                    .map_err(|e| Error::Jetstream(format!("Publishing message {}: {e}", msg.id))),
+                Err(e) => Err(e),
+            };
+            pending.push((msg.id, ack_fut));
+        }
+
+        // the acks arrive asynchronously, so awaiting them in order only waits for the slowest.
+        let mut responses = Vec::with_capacity(pending.len());
+        for (id, ack_fut) in pending {
+            let status = match ack_fut {
+                Ok(ack_fut) => ack_fut
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| Error::Jetstream(format!("Waiting for publish ack of {id}: {e}"))),
+                Err(e) => Err(e),
+            };
+            responses.push(NatsSinkResponse { id, status });
+        }
+        Ok(responses)
+    }
+}
+
+/// Converts the message headers into NATS headers and sets the `Nats-Msg-Id` header.
+fn to_header_map(msg: &NatsSinkMessage) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (k, v) in &msg.headers {
+        headers.insert(k.as_str(), v.as_str());
+    }
+    headers.insert(NATS_MSG_ID_HEADER, msg.id.as_str());
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subject_template_literal() {
+        let template = SubjectTemplate::try_from("orders.created").unwrap();
+        assert_eq!(
+            template.render(&[], &HashMap::new()).unwrap(),
+            "orders.created"
+        );
+    }
+
+    #[test]
+    fn test_subject_template_keys_and_headers() {
+        let template =
+            SubjectTemplate::try_from("orders.{{header.region}}.{{key.1}}.{{ keys }}").unwrap();
+        let keys = vec!["a".to_string(), "b".to_string()];
+        let headers = HashMap::from([("region".to_string(), "us-west".to_string())]);
+        assert_eq!(
+            template.render(&keys, &headers).unwrap(),
+            "orders.us-west.b.a.b"
+        );
+
+        // missing key and header
+        assert!(template.render(&["a".to_string()], &headers).is_err());
+        assert!(template.render(&keys, &HashMap::new()).is_err());
+
+        // whitespace in the rendered token is rejected
+        let keys = vec!["a".to_string(), "b c".to_string()];
+        assert!(template.render(&keys, &headers).is_err());
+    }
+
+    #[test]
+    fn test_subject_template_rejects_separator() {
+        let template = SubjectTemplate::try_from("orders.{{key.0}}").unwrap();
+        assert!(
+            template
+                .render(&["a.b".to_string()], &HashMap::new())
+                .is_err()
+        );
+
+        let template = SubjectTemplate::try_from("orders.{{header.region}}").unwrap();
+        let headers = HashMap::from([("region".to_string(), "us.west".to_string())]);
+        assert!(template.render(&[], &headers).is_err());
+
+        let template = SubjectTemplate::try_from("orders.{{keys}}").unwrap();
+        assert!(
+            template
+                .render(&["a.b".to_string()], &HashMap::new())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_subject_template_rejects_single_token_wildcard() {
+        let template = SubjectTemplate::try_from("orders.{{key.0}}").unwrap();
+        assert!(
+            template
+                .render(&["*".to_string()], &HashMap::new())
+                .is_err()
+        );
+        assert!(
+            template
+                .render(&["a*".to_string()], &HashMap::new())
+                .is_err()
+        );
+
+        let template = SubjectTemplate::try_from("orders.{{keys}}").unwrap();
+        let keys = vec!["a".to_string(), "*".to_string()];
+        assert!(template.render(&keys, &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_subject_template_rejects_multi_token_wildcard() {
+        let template = SubjectTemplate::try_from("orders.{{key.0}}").unwrap();
+        assert!(
+            template
+                .render(&[">".to_string()], &HashMap::new())
+                .is_err()
+        );
+
+        let template = SubjectTemplate::try_from("orders.{{header.region}}").unwrap();
+        let headers = HashMap::from([("region".to_string(), "a>".to_string())]);
+        assert!(template.render(&[], &headers).is_err());
+    }
+
+    #[test]
+    fn test_subject_template_invalid() {
+        assert!(SubjectTemplate::try_from("").is_err());
+        assert!(SubjectTemplate::try_from("orders.{{key.0").is_err());
+        assert!(SubjectTemplate::try_from("orders.{{key.x}}").is_err());
+        assert!(SubjectTemplate::try_from("orders.{{unknown}}").is_err());
+        assert!(SubjectTemplate::try_from("orders.{{header.}}").is_err());
+    }
+
+    #[test]
+    fn test_header_map_sets_msg_id() {
+        let msg = NatsSinkMessage {
+            id: "vertex-0-1".to_string(),
+            keys: vec![],
+            headers: HashMap::from([("foo".to_string(), "bar".to_string())]),
+            payload: Bytes::from_static(b"payload"),
+        };
+        let headers = to_header_map(&msg);
+        assert_eq!(
+            headers.get(NATS_MSG_ID_HEADER).map(|v| v.as_str()),
+            Some("vertex-0-1")
+        );
+        assert_eq!(headers.get("foo").map(|v| v.as_str()), Some("bar"));
+    }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_nats_sink_core() {
+        use tokio_stream::StreamExt;
+
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+        let client = async_nats::connect("localhost").await.unwrap();
+        let mut sub = client.subscribe("nats_sink_test.>").await.unwrap();
+        client.flush().await.unwrap();
+
+        let mut sink = NatsSink::connect(NatsSinkConfig {
+            addr: "localhost".to_string(),
+            subject: "nats_sink_test.{{key.0}}".to_string(),
+            auth: None,
+            tls: None,
+            jetstream: false,
+            ack_timeout: Duration::from_secs(5),
+        })
+        .await
+        .unwrap();
+
+        let messages = (0..3)
+            .map(|i| NatsSinkMessage {
+                id: format!("id-{i}"),
+                keys: vec![format!("key{i}")],
+                headers: HashMap::new(),
+                payload: Bytes::from(format!("message {i}")),
+            })
+            .collect();
+        let responses = sink.sink_messages(messages).await.unwrap();
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+
+        for i in 0..3 {
+            let msg = tokio::time::timeout(Duration::from_secs(2), sub.next())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(msg.subject.as_str(), format!("nats_sink_test.key{i}"));
+            assert_eq!(msg.payload, Bytes::from(format!("message {i}")));
+        }
+    }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_nats_sink_jetstream_dedup() {
+        use async_nats::jetstream::stream;
+
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+        let stream_name = "test_nats_sink_jetstream_dedup";
+        let client = async_nats::connect("localhost").await.unwrap();
+        let js = async_nats::jetstream::new(client);
+        let _ = js.delete_stream(stream_name).await;
+        let mut stream = js
+            .create_stream(stream::Config {
+                name: stream_name.to_string(),
+                subjects: vec![format!("{stream_name}.>")],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut sink = NatsSink::connect(NatsSinkConfig {
+            addr: "localhost".to_string(),
+            subject: format!("{stream_name}.{{{{header.type}}}}"),
+            auth: None,
+            tls: None,
+            jetstream: true,
+            ack_timeout: Duration::from_secs(5),
+        })
+        .await
+        .unwrap();
+
+        let messages: Vec<NatsSinkMessage> = (0..5)
+            .map(|i| NatsSinkMessage {
+                id: format!("id-{i}"),
+                keys: vec![],
+                headers: HashMap::from([("type".to_string(), "created".to_string())]),
+                payload: Bytes::from(format!("message {i}")),
+            })
+            .collect();
+        let responses = sink.sink_messages(messages.clone()).await.unwrap();
+        assert_eq!(responses.len(), 5);
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+
+        // re-publishing the same messages should be de-duplicated by the server
+        let responses = sink.sink_messages(messages).await.unwrap();
+        assert!(responses.iter().all(|resp| resp.status.is_ok()));
+        assert_eq!(stream.info().await.unwrap().state.messages, 5);
+
+        // message without the header used in the subject fails
+        let responses = sink
+            .sink_messages(vec![NatsSinkMessage {
+                id: "id-missing".to_string(),
+                keys: vec![],
+                headers: HashMap::new(),
+                payload: Bytes::from_static(b"missing"),
+            }])
+            .await
+            .unwrap();
+        assert!(responses.first().unwrap().status.is_err());
+
+        js.delete_stream(stream_name).await.unwrap();
+    }
+}
@@ -0,0 +1,364 @@
+//! Encoding of the files. The payloads are parsed as JSON and projected on the configured columns,
+//! which are written as one JSON object per line ([Format::Ndjson]) or as the columns of a
+//! Parquet file ([Format::Parquet]).
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use parquet::basic::{Compression, LogicalType, Repetition, TimeUnit, Type as PhysicalType};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use serde_json::{Map, Value};
+
+use crate::{Error, Result};
+
+/// Format of the files.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    /// One JSON object per line.
+    #[default]
+    Ndjson,
+    /// Parquet file with a single row group, compressed with snappy.
+    Parquet,
+}
+
+impl Format {
+    /// Extension of the object keys.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ndjson => "ndjson",
+            Format::Parquet => "parquet",
+        }
+    }
+}
+
+impl TryFrom<&str> for Format {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "ndjson" => Ok(Format::Ndjson),
+            "parquet" => Ok(Format::Parquet),
+            _ => Err(Error::InvalidConfig(format!(
+                "Unsupported format '{value}', supported formats are ndjson and parquet"
+            ))),
+        }
+    }
+}
+
+/// Type of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnType {
+    String,
+    Int64,
+    Double,
+    Boolean,
+    /// Milliseconds since the epoch, from a JSON number or an RFC 3339 string.
+    Timestamp,
+    /// Any JSON value, stored as a string in Parquet.
+    Json,
+}
+
+impl TryFrom<&str> for ColumnType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "string" => Ok(ColumnType::String),
+            "int64" => Ok(ColumnType::Int64),
+            "double" => Ok(ColumnType::Double),
+            "boolean" => Ok(ColumnType::Boolean),
+            "timestamp" => Ok(ColumnType::Timestamp),
+            "json" => Ok(ColumnType::Json),
+            _ => Err(Error::InvalidConfig(format!(
+                "Unsupported column type '{value}', supported types are string, int64, double, \
+                boolean, timestamp and json"
+            ))),
+        }
+    }
+}
+
+/// A column of the files, read from the JSON payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub column_type: ColumnType,
+    /// JSON pointer of the value in the payload.
+    pub path: String,
+}
+
+impl Column {
+    pub fn new(name: impl Into<String>, column_type: ColumnType) -> Self {
+        let name = name.into();
+        Self {
+            path: format!("/{name}"),
+            name,
+            column_type,
+        }
+    }
+
+    /// Converts the value in the payload to the type of the column, missing values are null.
+    fn value(&self, payload: &Value) -> Result<Value> {
+        let value = match payload.pointer(&self.path) {
+            None | Some(Value::Null) => return Ok(Value::Null),
+            Some(value) => value,
+        };
+        let converted = match self.column_type {
+            ColumnType::String => value.as_str().map(|_| value.clone()),
+            ColumnType::Int64 => value.as_i64().map(Value::from),
+            ColumnType::Double => value.as_f64().map(Value::from),
+            ColumnType::Boolean => value.as_bool().map(Value::from),
+            ColumnType::Timestamp => match value {
+                Value::Number(number) => number.as_i64().map(Value::from),
+                Value::String(time) => DateTime::parse_from_rfc3339(time)
+                    .ok()
+                    .map(|time| Value::from(time.timestamp_millis())),
+                _ => None,
+            },
+            ColumnType::Json => Some(value.clone()),
+        };
+        converted.ok_or_else(|| {
+            Error::InvalidPayload(format!(
+                "value of the column '{}' is not a {:?}: {value}",
+                self.name, self.column_type
+            ))
+        })
+    }
+}
+
+/// A payload projected on the columns, ready to be encoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    value: Value,
+    /// size of the row in the NDJSON format, used to roll the files.
+    pub size: usize,
+}
+
+/// The columns of the files.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    columns: Vec<Column>,
+    parquet: Arc<Type>,
+}
+
+impl Schema {
+    pub fn new(columns: Vec<Column>) -> Result<Self> {
+        let mut fields = Vec::with_capacity(columns.len());
+        for column in &columns {
+            let (physical, logical) = match column.column_type {
+                ColumnType::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+                ColumnType::Json => (PhysicalType::BYTE_ARRAY, Some(LogicalType::Json)),
+                ColumnType::Int64 => (PhysicalType::INT64, None),
+                ColumnType::Double => (PhysicalType::DOUBLE, None),
+                ColumnType::Boolean => (PhysicalType::BOOLEAN, None),
+                ColumnType::Timestamp => (
+                    PhysicalType::INT64,
+                    Some(LogicalType::Timestamp {
+                        is_adjusted_to_u_t_c: true,
+                        unit: TimeUnit::MILLIS,
+                    }),
+                ),
+            };
+            let field = Type::primitive_type_builder(&column.name, physical)
+                .with_repetition(Repetition::OPTIONAL)
+                .with_logical_type(logical)
+                .build()
+                .map_err(|e| Error::InvalidConfig(format!("column '{}': {e}", column.name)))?;
+            fields.push(Arc::new(field));
+        }
+        let parquet = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()
+            .map_err(|e| Error::InvalidConfig(e.to_string()))?;
+        Ok(Self {
+            columns,
+            parquet: Arc::new(parquet),
+        })
+    }
+
+    /// Parses the payload and projects it on the columns, without columns the payload is kept as
+    /// is.
+    pub fn row(&self, payload: &[u8]) -> Result<Row> {
+        let payload: Value = serde_json::from_slice(payload)
+            .map_err(|e| Error::InvalidPayload(format!("payload is not JSON: {e}")))?;
+        let value = if self.columns.is_empty() {
+            payload
+        } else {
+            let mut row = Map::with_capacity(self.columns.len());
+            for column in &self.columns {
+                row.insert(column.name.clone(), column.value(&payload)?);
+            }
+            Value::Object(row)
+        };
+        let size = value.to_string().len() + 1;
+        Ok(Row { value, size })
+    }
+
+    /// Encodes the rows as a file in the given format.
+    pub fn encode(&self, format: Format, rows: &[Row]) -> Result<Bytes> {
+        match format {
+            Format::Ndjson => {
+                let mut file = Vec::with_capacity(rows.iter().map(|row| row.size).sum());
+                for row in rows {
+                    serde_json::to_writer(&mut file, &row.value)
+                        .map_err(|e| Error::Encode(e.to_string()))?;
+                    file.push(b'\n');
+                }
+                Ok(file.into())
+            }
+            Format::Parquet => self
+                .encode_parquet(rows)
+                .map_err(|e| Error::Encode(e.to_string())),
+        }
+    }
+
+    fn encode_parquet(&self, rows: &[Row]) -> parquet::errors::Result<Bytes> {
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer =
+            SerializedFileWriter::new(Vec::new(), Arc::clone(&self.parquet), Arc::new(properties))?;
+        let mut row_group = writer.next_row_group()?;
+        for column in &self.columns {
+            let values = rows
+                .iter()
+                .map(|row| row.value.get(&column.name).unwrap_or(&Value::Null));
+            // definition level 1 is a value, 0 is null
+            let levels: Vec<i16> = values.clone().map(|v| i16::from(!v.is_null())).collect();
+            let values = values.filter(|v| !v.is_null());
+
+            let Some(mut writer) = row_group.next_column()? else {
+                break;
+            };
+            match column.column_type {
+                ColumnType::String => {
+                    let values: Vec<ByteArray> = values
+                        .map(|v| ByteArray::from(v.as_str().unwrap_or_default()))
+                        .collect();
+                    writer
+                        .typed::<ByteArrayType>()
+                        .write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnType::Json => {
+                    let values: Vec<ByteArray> = values
+                        .map(|v| ByteArray::from(v.to_string().into_bytes()))
+                        .collect();
+                    writer
+                        .typed::<ByteArrayType>()
+                        .write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnType::Int64 | ColumnType::Timestamp => {
+                    let values: Vec<i64> = values.map(|v| v.as_i64().unwrap_or_default()).collect();
+                    writer
+                        .typed::<Int64Type>()
+                        .write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnType::Double => {
+                    let values: Vec<f64> = values.map(|v| v.as_f64().unwrap_or_default()).collect();
+                    writer
+                        .typed::<DoubleType>()
+                        .write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnType::Boolean => {
+                    let values: Vec<bool> =
+                        values.map(|v| v.as_bool().unwrap_or_default()).collect();
+                    writer
+                        .typed::<BoolType>()
+                        .write_batch(&values, Some(&levels), None)?;
+                }
+            }
+            writer.close()?;
+        }
+        row_group.close()?;
+        Ok(writer.into_inner()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use serde_json::json;
+
+    use super::*;
+
+    fn schema() -> Schema {
+        let mut user = Column::new("user", ColumnType::String);
+        user.path = "/user/name".to_string();
+        Schema::new(vec![
+            user,
+            Column::new("count", ColumnType::Int64),
+            Column::new("time", ColumnType::Timestamp),
+            Column::new("tags", ColumnType::Json),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_row() {
+        let schema = schema();
+        let row = schema
+            .row(br#"{"user":{"name":"a"},"count":3,"time":"2024-01-02T03:04:05Z","x":1}"#)
+            .unwrap();
+        assert_eq!(
+            row.value,
+            json!({"user": "a", "count": 3, "time": 1704164645000_i64, "tags": null})
+        );
+
+        assert!(matches!(
+            schema.row(br#"{"count":"three"}"#),
+            Err(Error::InvalidPayload(_))
+        ));
+        assert!(matches!(
+            schema.row(b"not json"),
+            Err(Error::InvalidPayload(_))
+        ));
+
+        // without columns the payload is kept as is
+        let schema = Schema::new(vec![]).unwrap();
+        let row = schema.row(br#"{"a": [1, 2]}"#).unwrap();
+        assert_eq!(row.value, json!({"a": [1, 2]}));
+        assert_eq!(row.size, r#"{"a":[1,2]}"#.len() + 1);
+    }
+
+    #[test]
+    fn test_encode() {
+        let schema = schema();
+        let rows = vec![
+            schema
+                .row(br#"{"user":{"name":"a"},"count":1,"tags":["x"]}"#)
+                .unwrap(),
+            schema.row(br#"{"count":2,"time":1000}"#).unwrap(),
+        ];
+
+        let ndjson = schema.encode(Format::Ndjson, &rows).unwrap();
+        let lines: Vec<Value> = ndjson
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines.first(),
+            Some(&json!({"user": "a", "count": 1, "time": null, "tags": ["x"]}))
+        );
+
+        let parquet = schema.encode(Format::Parquet, &rows).unwrap();
+        let reader = SerializedFileReader::new(parquet).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+        let rows: Vec<String> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().to_string())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                r#"{user: "a", count: 1, time: null, tags: "["x"]"}"#,
+                r#"{user: null, count: 2, time: 1970-01-01 00:00:01.000 +00:00, tags: null}"#,
+            ]
+        );
+    }
+}
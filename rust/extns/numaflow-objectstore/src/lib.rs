@@ -0,0 +1,165 @@
+//! Object-storage sink for Numaflow. The messages are buffered into files which are uploaded to an
+//! S3 compatible store (or a local directory), see [store::ObjectStore]. The files are encoded as
+//! NDJSON or Parquet with the columns read from the JSON payload of the messages, see
+//! [format::Format], and they are partitioned by the event time window and optionally the keys of
+//! the messages, see [ObjectStoreSinkConfig::partition].
+//!
+//! The buffering and the rolling of the files is done by the caller, which knows when the messages
+//! can be acknowledged.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+pub mod format;
+pub mod store;
+
+/// Error types of the object store sink.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum Error {
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    /// the payload doesn't match the configured columns, writing it again will not help.
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
+
+    #[error("Failed to encode the file: {0}")]
+    Encode(String),
+
+    #[error("Failed to upload the object: {0}")]
+    Upload(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Configuration of the object store sink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectStoreSinkConfig {
+    pub store: store::StoreConfig,
+    /// Prefix of the object keys.
+    pub prefix: String,
+    pub format: format::Format,
+    /// Columns read from the JSON payload, required for [format::Format::Parquet].
+    pub columns: Vec<format::Column>,
+    /// The file is uploaded once the size of its rows reaches this many bytes.
+    pub max_file_size: usize,
+    /// The file is uploaded once it has this many messages.
+    pub max_file_messages: usize,
+    /// The file is uploaded once its first message has been buffered for this long.
+    pub max_file_age: Duration,
+    /// Length of the event time windows the files are partitioned by.
+    pub partition_window: Duration,
+    /// Add the keys of the messages to the path of the files.
+    pub partition_by_keys: bool,
+}
+
+impl Default for ObjectStoreSinkConfig {
+    fn default() -> Self {
+        Self {
+            store: store::StoreConfig::Local {
+                path: Default::default(),
+            },
+            prefix: String::new(),
+            format: format::Format::Ndjson,
+            columns: vec![],
+            max_file_size: 64 * 1024 * 1024,
+            max_file_messages: 100_000,
+            max_file_age: Duration::from_secs(60),
+            partition_window: Duration::from_secs(60 * 60),
+            partition_by_keys: false,
+        }
+    }
+}
+
+impl ObjectStoreSinkConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.format == format::Format::Parquet && self.columns.is_empty() {
+            return Err(Error::InvalidConfig(
+                "columns are required for the parquet format".to_string(),
+            ));
+        }
+        if self.partition_window.as_millis() == 0 {
+            return Err(Error::InvalidConfig(
+                "partition window must be at least 1ms".to_string(),
+            ));
+        }
+        if self.max_file_messages == 0 || self.max_file_size == 0 {
+            return Err(Error::InvalidConfig(
+                "max file messages and max file size must be greater than 0".to_string(),
+            ));
+        }
+        format::Schema::new(self.columns.clone()).map(|_| ())
+    }
+
+    /// Path of the partition of a message, e.g. `prefix/window=20240102T030000Z/keys=a,b`.
+    pub fn partition(&self, event_time: DateTime<Utc>, keys: &[String]) -> String {
+        let window = self.partition_window.as_millis() as i64;
+        let start = event_time.timestamp_millis().div_euclid(window) * window;
+        let start = DateTime::from_timestamp_millis(start).unwrap_or_default();
+
+        let mut path = self.prefix.trim_matches('/').to_string();
+        if !path.is_empty() {
+            path.push('/');
+        }
+        path.push_str(&format!("window={}", start.format("%Y%m%dT%H%M%SZ")));
+        if self.partition_by_keys {
+            let keys: Vec<String> = keys.iter().map(|key| escape(key)).collect();
+            path.push_str(&format!("/keys={}", keys.join(",")));
+        }
+        path
+    }
+}
+
+/// Percent-encodes everything but the unreserved characters, so that the keys can't add path
+/// segments or clash with the `,` separator.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.') {
+            escaped.push(byte as char);
+        } else {
+            escaped.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition() {
+        let mut config = ObjectStoreSinkConfig {
+            prefix: "/events/".to_string(),
+            partition_window: Duration::from_secs(15 * 60),
+            ..Default::default()
+        };
+        let event_time = DateTime::parse_from_rfc3339("2024-01-02T03:20:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let keys = vec!["us/west".to_string(), "a,b".to_string()];
+        assert_eq!(
+            config.partition(event_time, &keys),
+            "events/window=20240102T031500Z"
+        );
+
+        config.prefix = String::new();
+        config.partition_by_keys = true;
+        assert_eq!(
+            config.partition(event_time, &keys),
+            "window=20240102T031500Z/keys=us%2Fwest,a%2Cb"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = ObjectStoreSinkConfig {
+            format: format::Format::Parquet,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        assert!(ObjectStoreSinkConfig::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,165 @@
+//! The stores the files are uploaded to. An upload returns only once the object is durable, for S3
+//! once the `PutObject` request succeeded and for a local directory once the file and the directory
+//! are synced to the disk.
+
+use std::path::PathBuf;
+
+use aws_config::Region;
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::primitives::ByteStream;
+use bytes::Bytes;
+use numaflow_sqs::{AssumeRoleConfig, aws_behavior_version, create_credentials_provider};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::{Error, Result};
+
+/// Configuration of the S3 compatible store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct S3Config {
+    pub bucket: String,
+    pub region: String,
+    /// Overrides the endpoint, e.g. for MinIO.
+    pub endpoint_url: Option<String>,
+    pub assume_role_config: Option<AssumeRoleConfig>,
+    /// Address the bucket in the path of the URL instead of the host.
+    pub force_path_style: bool,
+}
+
+/// Where the files are uploaded to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreConfig {
+    S3(S3Config),
+    /// A local directory, usually a mounted volume.
+    Local {
+        path: PathBuf,
+    },
+}
+
+/// Client of the store.
+#[derive(Clone, Debug)]
+pub enum ObjectStore {
+    S3 { client: Client, bucket: String },
+    Local { path: PathBuf },
+}
+
+impl ObjectStore {
+    pub async fn connect(config: StoreConfig) -> Result<Self> {
+        match config {
+            StoreConfig::S3(config) => {
+                let region = Region::new(config.region);
+                let region_provider = RegionProviderChain::first_try(region.clone());
+                let credentials_provider = create_credentials_provider(
+                    region,
+                    config.assume_role_config.as_ref(),
+                    config.endpoint_url.as_deref(),
+                )
+                .await
+                .map_err(|e| Error::InvalidConfig(e.to_string()))?;
+
+                let mut config_builder = aws_config::defaults(aws_behavior_version())
+                    .region(region_provider)
+                    .credentials_provider(credentials_provider);
+                if let Some(endpoint_url) = &config.endpoint_url {
+                    config_builder = config_builder.endpoint_url(endpoint_url);
+                }
+                let shared_config = config_builder.load().await;
+                let s3_config = aws_sdk_s3::config::Builder::from(&shared_config)
+                    .force_path_style(config.force_path_style)
+                    .build();
+                Ok(ObjectStore::S3 {
+                    client: Client::from_conf(s3_config),
+                    bucket: config.bucket,
+                })
+            }
+            StoreConfig::Local { path } => {
+                fs::create_dir_all(&path).await.map_err(|e| {
+                    Error::InvalidConfig(format!("failed to create {}: {e}", path.display()))
+                })?;
+                Ok(ObjectStore::Local { path })
+            }
+        }
+    }
+
+    /// Location of the object, used in the logs.
+    pub fn location(&self, key: &str) -> String {
+        match self {
+            ObjectStore::S3 { bucket, .. } => format!("s3://{bucket}/{key}"),
+            ObjectStore::Local { path } => path.join(key).display().to_string(),
+        }
+    }
+
+    /// Uploads the object, it is durable once this returns.
+    pub async fn put(&self, key: &str, body: Bytes) -> Result<()> {
+        match self {
+            ObjectStore::S3 { client, bucket } => {
+                client
+                    .put_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .body(ByteStream::from(body))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        let message = match e.as_service_error() {
+                            Some(se) => format!(
+                                "{}: {}",
+                                se.code().unwrap_or("UnknownError"),
+                                se.message().unwrap_or("No details available")
+                            ),
+                            None => e.to_string(),
+                        };
+                        Error::Upload(message)
+                    })?;
+                Ok(())
+            }
+            ObjectStore::Local { path } => put_local(path.join(key), body)
+                .await
+                .map_err(|e| Error::Upload(format!("{key}: {e}"))),
+        }
+    }
+}
+
+/// Writes the file next to its final path and renames it once it is synced, so that a partially
+/// written file is never visible.
+async fn put_local(path: PathBuf, body: Bytes) -> std::io::Result<()> {
+    let dir = path.parent().map(PathBuf::from).unwrap_or_default();
+    fs::create_dir_all(&dir).await?;
+
+    let mut tmp = path.clone().into_os_string();
+    tmp.push(".tmp");
+    let mut file = fs::File::create(&tmp).await?;
+    file.write_all(&body).await?;
+    file.sync_all().await?;
+    fs::rename(&tmp, &path).await?;
+    fs::File::open(&dir).await?.sync_all().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::connect(StoreConfig::Local {
+            path: dir.path().join("bucket"),
+        })
+        .await
+        .unwrap();
+
+        store
+            .put("a/b/file.ndjson", Bytes::from_static(b"{}\n"))
+            .await
+            .unwrap();
+        let path = dir.path().join("bucket/a/b/file.ndjson");
+        assert_eq!(
+            store.location("a/b/file.ndjson"),
+            path.display().to_string()
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), b"{}\n");
+        assert!(!dir.path().join("bucket/a/b/file.ndjson.tmp").exists());
+    }
+}
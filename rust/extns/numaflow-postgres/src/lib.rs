@@ -0,0 +1,369 @@
+//! PostgreSQL sink for Numaflow. Each batch of messages is written with a single
+//! `INSERT ... ON CONFLICT` statement in one transaction, see [sink::PostgresSink].
+//!
+//! The rows are built from the JSON payload or the keys of the messages, see [Column], and handed
+//! to PostgreSQL as one JSON array which is converted to the row type of the table by
+//! `jsonb_populate_recordset`, so the values are cast to the types of the table columns by the
+//! database.
+
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+
+pub mod sink;
+
+/// Error types of the PostgreSQL sink.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum Error {
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    /// the payload can't be mapped to the columns, writing it again will not help.
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
+
+    /// the row violates a constraint of the table or its values don't fit the columns, writing it
+    /// again will not help.
+    #[error("Row rejected by the database ({code}): {message}")]
+    Rejected { code: String, message: String },
+
+    /// connection and server errors, the write can be retried.
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+impl Error {
+    /// Whether writing the message again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Error::Database(_))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Where the value of a column is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnSource {
+    /// JSON pointer of the value in the payload, e.g. `/user/id`.
+    Payload(String),
+    /// Index of the key of the message.
+    Key(usize),
+}
+
+/// A column of the table, missing values are written as null.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub source: ColumnSource,
+}
+
+impl Column {
+    /// Column read from the top level field of the payload with the same name.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            source: ColumnSource::Payload(format!("/{name}")),
+            name,
+        }
+    }
+}
+
+/// What happens to a row whose conflict columns match an existing row.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OnConflict {
+    /// Update the other columns of the existing row.
+    #[default]
+    Update,
+    /// Keep the existing row.
+    Nothing,
+}
+
+impl TryFrom<&str> for OnConflict {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "update" => Ok(OnConflict::Update),
+            "nothing" => Ok(OnConflict::Nothing),
+            _ => Err(Error::InvalidConfig(format!(
+                "Unsupported onConflict '{value}', supported values are update and nothing"
+            ))),
+        }
+    }
+}
+
+/// Configuration of the PostgreSQL sink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostgresSinkConfig {
+    /// Connection string, e.g. `postgres://user@host:5432/db`.
+    pub url: String,
+    /// Overrides the password of the connection string.
+    pub password: Option<String>,
+    /// Table the rows are written to, optionally qualified by the schema.
+    pub table: String,
+    pub columns: Vec<Column>,
+    /// Columns of the unique constraint the rows are upserted on.
+    pub conflict_columns: Vec<String>,
+    pub on_conflict: OnConflict,
+    /// Column the message ID is written to. Without conflict columns the rows whose ID is already
+    /// in the table are skipped, which makes the redelivered messages idempotent.
+    pub id_column: Option<String>,
+    pub connect_timeout: Duration,
+}
+
+impl Default for PostgresSinkConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            password: None,
+            table: String::new(),
+            columns: vec![],
+            conflict_columns: vec![],
+            on_conflict: OnConflict::default(),
+            id_column: None,
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl PostgresSinkConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.table.is_empty() {
+            return Err(Error::InvalidConfig("table is required".to_string()));
+        }
+        if self.columns.is_empty() {
+            return Err(Error::InvalidConfig(
+                "at least one column is required".to_string(),
+            ));
+        }
+        self.url
+            .parse::<tokio_postgres::Config>()
+            .map_err(|e| Error::InvalidConfig(format!("invalid url: {e}")))?;
+
+        let columns = self.column_names();
+        for (i, column) in columns.iter().enumerate() {
+            if columns.iter().skip(i + 1).any(|other| other == column) {
+                return Err(Error::InvalidConfig(format!(
+                    "column '{column}' is mapped more than once"
+                )));
+            }
+        }
+        if let Some(column) = self
+            .conflict_columns
+            .iter()
+            .find(|column| !columns.contains(column))
+        {
+            return Err(Error::InvalidConfig(format!(
+                "conflict column '{column}' is not one of the columns"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Names of the columns written, the ID column is last.
+    fn column_names(&self) -> Vec<&String> {
+        self.columns
+            .iter()
+            .map(|column| &column.name)
+            .chain(self.id_column.as_ref())
+            .collect()
+    }
+
+    /// The statement writing a batch, its only parameter is the JSON array of the rows.
+    pub fn insert_statement(&self) -> String {
+        let table = self
+            .table
+            .split('.')
+            .map(quote)
+            .collect::<Vec<String>>()
+            .join(".");
+        let columns = self
+            .column_names()
+            .into_iter()
+            .map(|column| quote(column))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let mut statement = format!(
+            "INSERT INTO {table} ({columns}) SELECT {columns} FROM jsonb_populate_recordset(NULL::{table}, $1)"
+        );
+
+        let (target, updated): (Vec<&String>, Vec<&String>) = if self.conflict_columns.is_empty() {
+            // the ID column alone makes the writes idempotent
+            match &self.id_column {
+                Some(id_column) => (vec![id_column], vec![]),
+                None => return statement,
+            }
+        } else {
+            let updated = match self.on_conflict {
+                OnConflict::Update => self
+                    .column_names()
+                    .into_iter()
+                    .filter(|column| !self.conflict_columns.contains(column))
+                    .collect(),
+                OnConflict::Nothing => vec![],
+            };
+            (self.conflict_columns.iter().collect(), updated)
+        };
+
+        let target = target
+            .into_iter()
+            .map(|column| quote(column))
+            .collect::<Vec<String>>()
+            .join(", ");
+        statement.push_str(&format!(" ON CONFLICT ({target}) DO "));
+        if updated.is_empty() {
+            statement.push_str("NOTHING");
+        } else {
+            let updated = updated
+                .into_iter()
+                .map(|column| format!("{} = EXCLUDED.{}", quote(column), quote(column)))
+                .collect::<Vec<String>>()
+                .join(", ");
+            statement.push_str(&format!("UPDATE SET {updated}"));
+        }
+        statement
+    }
+
+    /// The row of a message as a JSON object keyed by the column names.
+    pub fn row(&self, id: &str, keys: &[String], payload: &[u8]) -> Result<Value> {
+        let needs_payload = self
+            .columns
+            .iter()
+            .any(|column| matches!(column.source, ColumnSource::Payload(_)));
+        let payload: Value = if needs_payload {
+            serde_json::from_slice(payload)
+                .map_err(|e| Error::InvalidPayload(format!("payload is not JSON: {e}")))?
+        } else {
+            Value::Null
+        };
+
+        let mut row = Map::with_capacity(self.columns.len() + 1);
+        for column in &self.columns {
+            let value = match &column.source {
+                ColumnSource::Payload(path) => payload.pointer(path).cloned(),
+                ColumnSource::Key(index) => keys.get(*index).cloned().map(Value::String),
+            };
+            row.insert(column.name.clone(), value.unwrap_or(Value::Null));
+        }
+        if let Some(id_column) = &self.id_column {
+            row.insert(id_column.clone(), Value::String(id.to_string()));
+        }
+        Ok(Value::Object(row))
+    }
+}
+
+/// Quotes the identifier, so that it can't inject SQL and keeps its case.
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn test_config() -> PostgresSinkConfig {
+        let mut user = Column::new("user");
+        user.source = ColumnSource::Payload("/user/name".to_string());
+        PostgresSinkConfig {
+            url: "postgres://numaflow@localhost:5432/events".to_string(),
+            table: "public.Events".to_string(),
+            columns: vec![
+                Column {
+                    name: "region".to_string(),
+                    source: ColumnSource::Key(1),
+                },
+                user,
+                Column::new("count"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_insert_statement() {
+        let mut config = test_config();
+        let insert = r#"INSERT INTO "public"."Events" ("region", "user", "count") SELECT "region", "user", "count" FROM jsonb_populate_recordset(NULL::"public"."Events", $1)"#;
+        assert_eq!(config.insert_statement(), insert);
+
+        config.conflict_columns = vec!["region".to_string(), "user".to_string()];
+        assert_eq!(
+            config.insert_statement(),
+            format!(
+                r#"{insert} ON CONFLICT ("region", "user") DO UPDATE SET "count" = EXCLUDED."count""#
+            )
+        );
+
+        config.on_conflict = OnConflict::Nothing;
+        assert_eq!(
+            config.insert_statement(),
+            format!(r#"{insert} ON CONFLICT ("region", "user") DO NOTHING"#)
+        );
+
+        // the ID column is the conflict target if there are no conflict columns
+        config.conflict_columns = vec![];
+        config.id_column = Some("message_id".to_string());
+        assert!(
+            config
+                .insert_statement()
+                .ends_with(r#"SELECT "region", "user", "count", "message_id" FROM jsonb_populate_recordset(NULL::"public"."Events", $1) ON CONFLICT ("message_id") DO NOTHING"#)
+        );
+
+        config.table = r#"a"b"#.to_string();
+        assert!(
+            config
+                .insert_statement()
+                .starts_with(r#"INSERT INTO "a""b" ("#)
+        );
+    }
+
+    #[test]
+    fn test_row() {
+        let mut config = test_config();
+        config.id_column = Some("message_id".to_string());
+        let keys = vec!["a".to_string(), "us-west".to_string()];
+        let row = config
+            .row("v-0-1", &keys, br#"{"user":{"name":"x"},"count":"3"}"#)
+            .unwrap();
+        assert_eq!(
+            row,
+            json!({"region": "us-west", "user": "x", "count": "3", "message_id": "v-0-1"})
+        );
+
+        // missing values are null
+        let row = config.row("v-0-2", &[], b"{}").unwrap();
+        assert_eq!(
+            row,
+            json!({"region": null, "user": null, "count": null, "message_id": "v-0-2"})
+        );
+
+        assert!(matches!(
+            config.row("v-0-3", &keys, b"not json"),
+            Err(Error::InvalidPayload(_))
+        ));
+
+        // the payload isn't parsed if only the keys are mapped
+        config.columns.truncate(1);
+        assert!(config.row("v-0-4", &keys, b"not json").is_ok());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut config = test_config();
+        assert!(config.validate().is_ok());
+
+        config.conflict_columns = vec!["missing".to_string()];
+        assert!(config.validate().is_err());
+
+        let mut config = test_config();
+        config.id_column = Some("user".to_string());
+        assert!(config.validate().is_err());
+
+        let mut config = test_config();
+        config.url = "postgres://localhost:port".to_string();
+        assert!(config.validate().is_err());
+
+        assert!(PostgresSinkConfig::default().validate().is_err());
+    }
+}
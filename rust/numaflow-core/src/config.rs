@@ -7,6 +7,7 @@ use monovertex::MonovertexConfig;
 use crate::Error;
 use crate::Result;
 use crate::config::pipeline::PipelineConfig;
+use crate::config::pipeline::isb::ISBSvcType;
 
 const ENV_MONO_VERTEX_OBJ: &str = "NUMAFLOW_MONO_VERTEX_OBJECT";
 const ENV_VERTEX_OBJ: &str = "NUMAFLOW_VERTEX_OBJECT";
@@ -105,7 +106,10 @@ impl Settings {
     /// load based on the CRD type, either a pipeline or a monovertex.
     /// Settings are populated through reading the env vars set via the controller. The main
     /// CRD is the base64 spec of the CR.
-    pub(crate) fn load(env_vars: HashMap<String, String>) -> Result<Self> {
+    pub(crate) fn load(
+        env_vars: HashMap<String, String>,
+        isb_svc_type: ISBSvcType,
+    ) -> Result<Self> {
         if env_vars.contains_key(ENV_MONO_VERTEX_OBJ) {
             let cfg = MonovertexConfig::load(env_vars)?;
             return Ok(Settings {
@@ -114,7 +118,7 @@ impl Settings {
         }
 
         if let Some(obj) = env_vars.get(ENV_VERTEX_OBJ) {
-            let cfg = PipelineConfig::load(obj.clone(), env::vars())?;
+            let cfg = PipelineConfig::load(obj.clone(), env::vars(), isb_svc_type)?;
             return Ok(Settings {
                 custom_resource_type: CustomResourceType::Pipeline(Box::new(cfg)),
             });
@@ -132,6 +136,7 @@ mod tests {
     use serde_json::json;
 
     use crate::config::components::sink::OnFailureStrategy;
+    use crate::config::pipeline::isb::ISBSvcType;
     use crate::config::{CustomResourceType, ENV_MONO_VERTEX_OBJ, Settings};
 
     #[test]
@@ -187,7 +192,7 @@ mod tests {
             env_vars.insert(ENV_MONO_VERTEX_OBJ.to_string(), encoded_json);
 
             // Execute and verify
-            let settings = Settings::load(env_vars).unwrap();
+            let settings = Settings::load(env_vars, ISBSvcType::default()).unwrap();
             assert!(matches!(
                 settings.custom_resource_type,
                 CustomResourceType::MonoVertex(_)
@@ -238,7 +243,7 @@ mod tests {
             env_vars.insert(ENV_MONO_VERTEX_OBJ.to_string(), encoded_json);
 
             // Execute and verify
-            let settings = Settings::load(env_vars).unwrap();
+            let settings = Settings::load(env_vars, ISBSvcType::default()).unwrap();
 
             let mvtx_cfg = match settings.custom_resource_type {
                 CustomResourceType::MonoVertex(cfg) => cfg,
@@ -335,7 +340,7 @@ mod tests {
             env_vars.insert(ENV_MONO_VERTEX_OBJ.to_string(), encoded_json);
 
             // Execute and verify
-            let settings = Settings::load(env_vars).unwrap();
+            let settings = Settings::load(env_vars, ISBSvcType::default()).unwrap();
 
             let mvtx_cfg = match settings.custom_resource_type {
                 CustomResourceType::MonoVertex(cfg) => cfg,
@@ -440,7 +445,7 @@ mod tests {
             env_vars.insert(ENV_MONO_VERTEX_OBJ.to_string(), encoded_json);
 
             // Execute and verify
-            let settings = Settings::load(env_vars).unwrap();
+            let settings = Settings::load(env_vars, ISBSvcType::default()).unwrap();
             let mvtx_config = match settings.custom_resource_type {
                 CustomResourceType::MonoVertex(cfg) => cfg,
                 CustomResourceType::Pipeline(_) => panic!("Invalid configuration type"),
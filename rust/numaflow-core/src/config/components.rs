@@ -355,6 +355,7 @@ mod kafka_tests {
             sasl: None,
             set_key: Some(true),
             tls: None,
+            transactional: None,
         };
 
         let sink_type = SinkType::try_from(Box::new(kafka_sink)).unwrap();
@@ -1,5 +1,11 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use crate::config::{get_pipeline_name, get_vertex_name, get_vertex_replica, is_mono_vertex};
 
+/// Default maximum number of keys to keep a per-key rate limit for.
+const DEFAULT_MAX_KEYS: usize = 1000;
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct RateLimitConfig {
     /// Key prefix for the rate limiter. This is fixed for a given Vertex/MonoVertex.
@@ -20,6 +26,83 @@ pub(crate) struct RateLimitConfig {
     pub(crate) resume: bool,
     /// TTL for the rate limiter state in seconds.
     pub(crate) ttl: usize,
+    /// Optional per-key rate limiting.
+    pub(crate) per_key: Option<PerKeyConfig>,
+}
+
+/// Configuration for rate limiting every key separately. Unlike the vertex level rate limit, the
+/// per-key limits are always in messages per second, including for sources.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PerKeyConfig {
+    /// Where the key of a message is derived from.
+    pub(crate) selector: KeySelector,
+    /// Maximum number of keys to keep a rate limit for.
+    pub(crate) max_keys: usize,
+    /// Bounds of the keys without an override.
+    pub(crate) bounds: KeyBounds,
+    /// key -> bounds of the key
+    pub(crate) overrides: HashMap<String, KeyBounds>,
+}
+
+/// Where the key of a message is derived from for per-key rate limiting.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum KeySelector {
+    /// The keys of the message, joined with `:`.
+    Keys,
+    /// The value of the header, messages without the header share the empty key.
+    Header(String),
+}
+
+impl KeySelector {
+    pub(crate) fn key(&self, keys: &[String], headers: &HashMap<String, String>) -> String {
+        match self {
+            KeySelector::Keys => keys.join(":"),
+            KeySelector::Header(name) => headers.get(name).cloned().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KeyBounds {
+    pub(crate) max: usize,
+    pub(crate) min: usize,
+    pub(crate) ramp_up_duration: Duration,
+}
+
+impl PerKeyConfig {
+    fn new(per_key: numaflow_models::models::RateLimitPerKey, bounds: KeyBounds) -> Self {
+        let overrides = per_key
+            .overrides
+            .unwrap_or_default()
+            .into_iter()
+            .map(|o| {
+                let max = o.max.map(|x| x as usize).unwrap_or(bounds.max);
+                let key_bounds = KeyBounds {
+                    max,
+                    // the min defaults to the min of the vertex, as long as it is within the max
+                    min: o.min.map(|x| x as usize).unwrap_or(bounds.min.min(max)),
+                    ramp_up_duration: o
+                        .ramp_up_duration
+                        .map(Duration::from)
+                        .unwrap_or(bounds.ramp_up_duration),
+                };
+                (o.key, key_bounds)
+            })
+            .collect();
+
+        Self {
+            selector: per_key
+                .header
+                .map(KeySelector::Header)
+                .unwrap_or(KeySelector::Keys),
+            max_keys: per_key
+                .max_keys
+                .map(|x| x as usize)
+                .unwrap_or(DEFAULT_MAX_KEYS),
+            bounds,
+            overrides,
+        }
+    }
 }
 
 impl Default for RateLimitConfig {
@@ -34,6 +117,7 @@ impl Default for RateLimitConfig {
             modes: None,
             resume: false,
             ttl: 180,
+            per_key: None,
         }
     }
 }
@@ -44,6 +128,20 @@ impl RateLimitConfig {
         is_source: bool,
         rate_limit: numaflow_models::models::RateLimit,
     ) -> Self {
+        let per_key = rate_limit.per_key.clone().map(|per_key| {
+            PerKeyConfig::new(
+                *per_key,
+                KeyBounds {
+                    max: rate_limit.max.map(|x| x as usize).unwrap_or_default(),
+                    min: rate_limit.min.map(|x| x as usize).unwrap_or_default(),
+                    ramp_up_duration: rate_limit
+                        .ramp_up_duration
+                        .map(Duration::from)
+                        .unwrap_or_default(),
+                },
+            )
+        });
+
         Self {
             key_prefix: if is_mono_vertex() {
                 Box::leak(format!("{}-mv", get_vertex_name()).into_boxed_str())
@@ -89,6 +187,59 @@ impl RateLimitConfig {
                 .map(std::time::Duration::from)
                 .unwrap_or(std::time::Duration::from_secs(180))
                 .as_secs() as usize,
+            per_key,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numaflow_models::models::{RateLimit, RateLimitKeyOverride, RateLimitPerKey};
+
+    #[test]
+    fn test_per_key_config() {
+        let mut rate_limit = RateLimit::new();
+        rate_limit.max = Some(100);
+        rate_limit.min = Some(10);
+        rate_limit.ramp_up_duration = Some(Duration::from_secs(10).into());
+        rate_limit.per_key = Some(Box::new(RateLimitPerKey {
+            header: Some("tenant".to_string()),
+            max_keys: None,
+            overrides: Some(vec![RateLimitKeyOverride {
+                max: Some(5),
+                ..RateLimitKeyOverride::new("small".to_string())
+            }]),
+        }));
+
+        // the vertex level limits of a source are divided by the batch size, not the per-key ones
+        let config = RateLimitConfig::new(10, true, rate_limit);
+        assert_eq!(config.max, 10);
+        let per_key = config.per_key.unwrap();
+        assert_eq!(per_key.selector, KeySelector::Header("tenant".to_string()));
+        assert_eq!(per_key.max_keys, DEFAULT_MAX_KEYS);
+        assert_eq!(per_key.bounds.max, 100);
+        assert_eq!(per_key.bounds.min, 10);
+
+        let small = per_key.overrides.get("small").unwrap();
+        assert_eq!(small.max, 5);
+        assert_eq!(small.min, 5);
+        assert_eq!(small.ramp_up_duration, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_key_selector() {
+        let keys = vec!["a".to_string(), "b".to_string()];
+        let headers = HashMap::from([("tenant".to_string(), "t1".to_string())]);
+
+        assert_eq!(KeySelector::Keys.key(&keys, &headers), "a:b");
+        assert_eq!(
+            KeySelector::Header("tenant".to_string()).key(&keys, &headers),
+            "t1"
+        );
+        assert_eq!(
+            KeySelector::Header("missing".to_string()).key(&keys, &headers),
+            ""
+        );
+    }
+}
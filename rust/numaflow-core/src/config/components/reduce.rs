@@ -10,11 +10,14 @@ const DEFAULT_ACCUMULATOR_REDUCER_SERVER_INFO_FILE: &str =
 const DEFAULT_SESSION_REDUCER_SOCKET: &str = "/var/run/numaflow/sessionreduce.sock";
 const DEFAULT_SESSION_REDUCER_SERVER_INFO_FILE: &str =
     "/var/run/numaflow/sessionreducer-server-info";
+const DEFAULT_HLL_PRECISION: u8 = 14;
+const DEFAULT_LATE_DATA_TAG: &str = "late";
 
 use std::time::Duration;
 
 use numaflow_models::models::{
-    AccumulatorWindow, FixedWindow, GroupBy, PbqStorage, SessionWindow, SlidingWindow,
+    AccumulatorWindow, BuiltinReducer, FixedWindow, GroupBy, LateData, PbqStorage, SessionWindow,
+    SlidingWindow, WindowTrigger,
 };
 
 use crate::Result;
@@ -30,6 +33,8 @@ pub(crate) enum ReducerConfig {
 pub(crate) struct AlignedReducerConfig {
     pub(crate) user_defined_config: UserDefinedConfig,
     pub(crate) window_config: AlignedWindowConfig,
+    /// Builtin reducer to use instead of the user-defined reducer.
+    pub(crate) builtin_config: Option<BuiltinReducerConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -80,11 +85,100 @@ impl UserDefinedConfig {
     }
 }
 
+/// Configuration of a builtin reducer, which aggregates the messages of every key of a window
+/// within the numa container.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BuiltinReducerConfig {
+    pub(crate) aggregation: Aggregation,
+    /// Path of the field to aggregate within the JSON payload, the whole payload is aggregated
+    /// if empty.
+    pub(crate) field: Vec<String>,
+}
+
+/// Aggregations supported by the builtin reducers.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Aggregation {
+    Count,
+    Sum,
+    Min,
+    Max,
+    /// Approximate number of distinct values, using a HyperLogLog sketch with 2^precision
+    /// registers.
+    Distinct {
+        precision: u8,
+    },
+    /// Approximate quantiles, using a t-digest.
+    Quantiles(Vec<f64>),
+}
+
+impl TryFrom<&BuiltinReducer> for BuiltinReducerConfig {
+    type Error = Error;
+
+    fn try_from(builtin: &BuiltinReducer) -> Result<Self> {
+        let aggregation = match builtin.aggregation.as_str() {
+            "count" => Aggregation::Count,
+            "sum" => Aggregation::Sum,
+            "min" => Aggregation::Min,
+            "max" => Aggregation::Max,
+            "distinct" => {
+                let precision = builtin.precision.unwrap_or(DEFAULT_HLL_PRECISION as i32);
+                if !(4..=16).contains(&precision) {
+                    return Err(Error::Config(format!(
+                        "Invalid precision {precision} for distinct, must be between 4 and 16"
+                    )));
+                }
+                Aggregation::Distinct {
+                    precision: precision as u8,
+                }
+            }
+            "quantiles" => {
+                let quantiles = builtin
+                    .quantiles
+                    .as_deref()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|q| match q.parse::<f64>() {
+                        Ok(q) if (0.0..=1.0).contains(&q) => Ok(q),
+                        _ => Err(Error::Config(format!(
+                            "Invalid quantile {q}, must be between 0 and 1"
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if quantiles.is_empty() {
+                    return Err(Error::Config(
+                        "At least one quantile is required for quantiles".to_string(),
+                    ));
+                }
+                Aggregation::Quantiles(quantiles)
+            }
+            aggregation => {
+                return Err(Error::Config(format!(
+                    "Unsupported builtin aggregation {aggregation}"
+                )));
+            }
+        };
+
+        Ok(Self {
+            aggregation,
+            field: builtin
+                .field
+                .as_deref()
+                .map(|field| field.split('.').map(str::to_string).collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct AlignedWindowConfig {
     pub(crate) window_type: AlignedWindowType,
     pub(crate) allowed_lateness: Duration,
     pub(crate) is_keyed: bool,
+    /// Side output for the late messages, they are dropped if not set.
+    pub(crate) late_data: Option<LateDataConfig>,
+    /// Triggers of the intermediate results of the windows, the windows are only fired once closed
+    /// if not set.
+    pub(crate) trigger: Option<TriggerConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -92,6 +186,69 @@ pub(crate) struct UnalignedWindowConfig {
     pub(crate) window_type: UnalignedWindowType,
     pub(crate) allowed_lateness: Duration,
     pub(crate) is_keyed: bool,
+    /// Side output for the late messages, they are dropped if not set.
+    pub(crate) late_data: Option<LateDataConfig>,
+}
+
+/// Configuration of the late data side output, the messages which are too late for their window
+/// are written to the edges whose conditions match the tag.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LateDataConfig {
+    pub(crate) tag: String,
+}
+
+impl From<&LateData> for LateDataConfig {
+    fn from(late_data: &LateData) -> Self {
+        Self {
+            tag: late_data
+                .tag
+                .clone()
+                .unwrap_or_else(|| DEFAULT_LATE_DATA_TAG.to_string()),
+        }
+    }
+}
+
+/// Triggers which fire intermediate results (panes) of an aligned window before it is closed. The
+/// window is always fired once it is closed, with the final pane.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TriggerConfig {
+    /// Fires a pane of a key every time it receives this number of messages.
+    pub(crate) count: Option<u64>,
+    /// Fires a pane of every key with new messages at this processing-time interval.
+    pub(crate) interval: Option<Duration>,
+    /// Fires a pane of every key once the watermark passes the end of the window and then a pane
+    /// for every late message until the window is closed.
+    pub(crate) late_firings: bool,
+}
+
+impl TriggerConfig {
+    fn new(trigger: &WindowTrigger, allowed_lateness: Duration) -> Result<Self> {
+        let count = match trigger.count {
+            Some(count) if count <= 0 => {
+                return Err(Error::Config(format!(
+                    "Invalid trigger count {count}, must be greater than 0"
+                )));
+            }
+            count => count.map(|count| count as u64),
+        };
+        let interval = trigger.interval.map(Duration::from);
+        if interval.is_some_and(|interval| interval.is_zero()) {
+            return Err(Error::Config(
+                "Invalid trigger interval, must be greater than 0".to_string(),
+            ));
+        }
+        let late_firings = trigger.late_firings.unwrap_or(false);
+        if late_firings && allowed_lateness.is_zero() {
+            return Err(Error::Config(
+                "Trigger late firings require allowedLateness".to_string(),
+            ));
+        }
+        Ok(Self {
+            count,
+            interval,
+            late_firings,
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -172,12 +329,25 @@ impl TryFrom<&Box<GroupBy>> for ReducerConfig {
             .allowed_lateness
             .map_or(Duration::from_secs(0), Duration::from);
         let is_keyed = group_by.keyed.unwrap_or(false);
+        let late_data = group_by.late_data.as_deref().map(LateDataConfig::from);
+        let trigger = group_by
+            .trigger
+            .as_deref()
+            .map(|trigger| TriggerConfig::new(trigger, allowed_lateness))
+            .transpose()?;
+        let builtin_config = group_by
+            .builtin
+            .as_deref()
+            .map(BuiltinReducerConfig::try_from)
+            .transpose()?;
 
         if let Some(fixed) = &window.fixed {
             let window_config = AlignedWindowConfig {
                 window_type: AlignedWindowType::Fixed(fixed.clone().into()),
                 allowed_lateness,
                 is_keyed,
+                late_data: late_data.clone(),
+                trigger: trigger.clone(),
             };
             let user_defined_config = if fixed.streaming.unwrap_or(false) {
                 UserDefinedConfig::streamer_config()
@@ -187,12 +357,15 @@ impl TryFrom<&Box<GroupBy>> for ReducerConfig {
             Ok(ReducerConfig::Aligned(AlignedReducerConfig {
                 user_defined_config,
                 window_config,
+                builtin_config,
             }))
         } else if let Some(sliding) = &window.sliding {
             let window_config = AlignedWindowConfig {
                 window_type: AlignedWindowType::Sliding(sliding.clone().into()),
                 allowed_lateness,
                 is_keyed,
+                late_data: late_data.clone(),
+                trigger: trigger.clone(),
             };
             let user_defined_config = if sliding.streaming.unwrap_or(false) {
                 UserDefinedConfig::streamer_config()
@@ -202,12 +375,22 @@ impl TryFrom<&Box<GroupBy>> for ReducerConfig {
             Ok(ReducerConfig::Aligned(AlignedReducerConfig {
                 user_defined_config,
                 window_config,
+                builtin_config,
             }))
+        } else if builtin_config.is_some() {
+            Err(Error::Config(
+                "Builtin reducers are only supported for fixed and sliding windows".to_string(),
+            ))
+        } else if trigger.is_some() {
+            Err(Error::Config(
+                "Triggers are only supported for fixed and sliding windows".to_string(),
+            ))
         } else if let Some(session) = &window.session {
             let window_config = UnalignedWindowConfig {
                 window_type: UnalignedWindowType::Session(session.clone().into()),
                 allowed_lateness,
                 is_keyed,
+                late_data: late_data.clone(),
             };
             Ok(ReducerConfig::Unaligned(UnalignedReducerConfig {
                 user_defined_config: UserDefinedConfig::session_config(),
@@ -218,6 +401,7 @@ impl TryFrom<&Box<GroupBy>> for ReducerConfig {
                 window_type: UnalignedWindowType::Accumulator(accumulator.clone().into()),
                 allowed_lateness,
                 is_keyed,
+                late_data: late_data.clone(),
             };
             Ok(ReducerConfig::Unaligned(UnalignedReducerConfig {
                 user_defined_config: UserDefinedConfig::accumulator_config(),
@@ -228,6 +412,33 @@ impl TryFrom<&Box<GroupBy>> for ReducerConfig {
         }
     }
 }
+
+/// What to do when a corrupted or partially written (torn) record is found in a WAL segment during
+/// replay, e.g., after a node crash.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum WalRecoveryPolicy {
+    /// Truncate the segment at the last good record and continue the replay. The records after it
+    /// are lost.
+    #[default]
+    Truncate,
+    /// Fail the replay, the segment has to be fixed or removed manually.
+    Fail,
+}
+
+impl TryFrom<&str> for WalRecoveryPolicy {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "truncate" => Ok(WalRecoveryPolicy::Truncate),
+            "fail" => Ok(WalRecoveryPolicy::Fail),
+            _ => Err(Error::Config(format!(
+                "Unsupported WAL recovery policy '{value}', supported policies are truncate and fail"
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct StorageConfig {
     pub(crate) path: std::path::PathBuf,
@@ -235,6 +446,9 @@ pub(crate) struct StorageConfig {
     pub(crate) flush_interval_ms: u64,
     pub(crate) channel_buffer_size: usize,
     pub(crate) max_segment_age_secs: u64,
+    pub(crate) recovery_policy: WalRecoveryPolicy,
+    /// Interval at which the snapshot of the unaligned windows is saved, 0 disables the snapshots.
+    pub(crate) snapshot_interval_secs: u64,
 }
 
 impl Default for StorageConfig {
@@ -245,6 +459,8 @@ impl Default for StorageConfig {
             flush_interval_ms: 100,
             channel_buffer_size: 500,
             max_segment_age_secs: 120,
+            recovery_policy: WalRecoveryPolicy::default(),
+            snapshot_interval_secs: 30,
         }
     }
 }
@@ -269,7 +485,8 @@ mod tests {
     use std::time::Duration;
 
     use numaflow_models::models::{
-        AccumulatorWindow, FixedWindow, GroupBy, SessionWindow, SlidingWindow, Window,
+        AccumulatorWindow, BuiltinReducer, FixedWindow, GroupBy, SessionWindow, SlidingWindow,
+        Window,
     };
 
     #[test]
@@ -297,8 +514,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: Some(kube::core::Duration::from(Duration::from_secs(10))),
+            builtin: None,
             keyed: Some(true),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -337,8 +557,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -376,8 +599,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: Some(true),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -414,8 +640,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: Some(true),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -450,8 +679,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -463,6 +695,192 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_builtin_reducer_config() {
+        let window = Window {
+            fixed: Some(Box::new(FixedWindow {
+                length: Some(kube::core::Duration::from(Duration::from_secs(60))),
+                streaming: None,
+            })),
+            sliding: None,
+            session: None,
+            accumulator: None,
+        };
+        let mut group_by = Box::new(GroupBy {
+            allowed_lateness: None,
+            builtin: Some(Box::new(BuiltinReducer {
+                field: Some("order.amount".to_string()),
+                quantiles: Some(vec!["0.5".to_string(), "0.99".to_string()]),
+                ..BuiltinReducer::new("quantiles".to_string())
+            })),
+            keyed: None,
+            late_data: None,
+            storage: None,
+            trigger: None,
+            window: Box::new(window),
+        });
+
+        let ReducerConfig::Aligned(aligned_config) = ReducerConfig::try_from(&group_by).unwrap()
+        else {
+            panic!("Expected aligned reducer config");
+        };
+        assert_eq!(
+            aligned_config.builtin_config,
+            Some(BuiltinReducerConfig {
+                aggregation: Aggregation::Quantiles(vec![0.5, 0.99]),
+                field: vec!["order".to_string(), "amount".to_string()],
+            })
+        );
+
+        let distinct = BuiltinReducer::new("distinct".to_string());
+        assert_eq!(
+            BuiltinReducerConfig::try_from(&distinct)
+                .unwrap()
+                .aggregation,
+            Aggregation::Distinct { precision: 14 }
+        );
+
+        // invalid configurations
+        for builtin in [
+            BuiltinReducer::new("avg".to_string()),
+            BuiltinReducer {
+                precision: Some(20),
+                ..BuiltinReducer::new("distinct".to_string())
+            },
+            BuiltinReducer::new("quantiles".to_string()),
+            BuiltinReducer {
+                quantiles: Some(vec!["1.5".to_string()]),
+                ..BuiltinReducer::new("quantiles".to_string())
+            },
+        ] {
+            assert!(BuiltinReducerConfig::try_from(&builtin).is_err());
+        }
+
+        // builtin reducers are not supported for unaligned windows
+        *group_by.window = Window {
+            fixed: None,
+            sliding: None,
+            session: Some(Box::new(SessionWindow {
+                timeout: Some(kube::core::Duration::from(Duration::from_secs(60))),
+            })),
+            accumulator: None,
+        };
+        assert!(ReducerConfig::try_from(&group_by).is_err());
+    }
+
+    #[test]
+    fn test_late_data_config() {
+        let window = Window {
+            fixed: None,
+            sliding: None,
+            session: None,
+            accumulator: Some(Box::new(AccumulatorWindow {
+                timeout: Some(kube::core::Duration::from(Duration::from_secs(60))),
+            })),
+        };
+        let mut group_by = Box::new(GroupBy {
+            allowed_lateness: None,
+            builtin: None,
+            keyed: Some(true),
+            late_data: Some(Box::new(LateData::new())),
+            storage: None,
+            trigger: None,
+            window: Box::new(window),
+        });
+
+        let ReducerConfig::Unaligned(unaligned_config) =
+            ReducerConfig::try_from(&group_by).unwrap()
+        else {
+            panic!("Expected unaligned reducer config");
+        };
+        assert_eq!(
+            unaligned_config.window_config.late_data,
+            Some(LateDataConfig {
+                tag: "late".to_string()
+            })
+        );
+
+        group_by.late_data = Some(Box::new(LateData {
+            tag: Some("reconcile".to_string()),
+        }));
+        let ReducerConfig::Unaligned(unaligned_config) =
+            ReducerConfig::try_from(&group_by).unwrap()
+        else {
+            panic!("Expected unaligned reducer config");
+        };
+        assert_eq!(
+            unaligned_config.window_config.late_data,
+            Some(LateDataConfig {
+                tag: "reconcile".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_trigger_config() {
+        let window = Window {
+            fixed: Some(Box::new(FixedWindow {
+                length: Some(kube::core::Duration::from(Duration::from_secs(3600))),
+                streaming: None,
+            })),
+            sliding: None,
+            session: None,
+            accumulator: None,
+        };
+        let mut group_by = Box::new(GroupBy {
+            allowed_lateness: Some(kube::core::Duration::from(Duration::from_secs(60))),
+            builtin: None,
+            keyed: Some(true),
+            late_data: None,
+            storage: None,
+            trigger: Some(Box::new(WindowTrigger {
+                count: Some(100),
+                interval: Some(kube::core::Duration::from(Duration::from_secs(30))),
+                late_firings: Some(true),
+            })),
+            window: Box::new(window),
+        });
+
+        let ReducerConfig::Aligned(aligned_config) = ReducerConfig::try_from(&group_by).unwrap()
+        else {
+            panic!("Expected aligned reducer config");
+        };
+        assert_eq!(
+            aligned_config.window_config.trigger,
+            Some(TriggerConfig {
+                count: Some(100),
+                interval: Some(Duration::from_secs(30)),
+                late_firings: true,
+            })
+        );
+
+        // late firings require allowed lateness
+        group_by.allowed_lateness = None;
+        assert!(ReducerConfig::try_from(&group_by).is_err());
+
+        // count has to be positive
+        group_by.trigger = Some(Box::new(WindowTrigger {
+            count: Some(0),
+            ..WindowTrigger::new()
+        }));
+        assert!(ReducerConfig::try_from(&group_by).is_err());
+
+        // triggers are not supported for unaligned windows
+        group_by.trigger = Some(Box::new(WindowTrigger {
+            count: Some(10),
+            ..WindowTrigger::new()
+        }));
+        *group_by.window = Window {
+            fixed: None,
+            sliding: None,
+            session: Some(Box::new(SessionWindow {
+                timeout: Some(kube::core::Duration::from(Duration::from_secs(60))),
+            })),
+            accumulator: None,
+        };
+        assert!(ReducerConfig::try_from(&group_by).is_err());
+    }
+
     #[test]
     fn test_session_user_defined_config() {
         let session_config = UserDefinedConfig::session_config();
@@ -518,8 +936,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -559,8 +980,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -612,8 +1036,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -655,8 +1082,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -754,6 +1184,21 @@ mod tests {
         assert_eq!(default_config.flush_interval_ms, 100);
         assert_eq!(default_config.channel_buffer_size, 500);
         assert_eq!(default_config.max_segment_age_secs, 120);
+        assert_eq!(default_config.recovery_policy, WalRecoveryPolicy::Truncate);
+        assert_eq!(default_config.snapshot_interval_secs, 30);
+    }
+
+    #[test]
+    fn test_wal_recovery_policy() {
+        assert_eq!(
+            WalRecoveryPolicy::try_from("truncate").unwrap(),
+            WalRecoveryPolicy::Truncate
+        );
+        assert_eq!(
+            WalRecoveryPolicy::try_from("Fail").unwrap(),
+            WalRecoveryPolicy::Fail
+        );
+        assert!(WalRecoveryPolicy::try_from("skip").is_err());
     }
 
     #[test]
@@ -812,8 +1257,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: Some(kube::core::Duration::from(Duration::from_secs(3600))), // 1 hour
+            builtin: None,
             keyed: Some(false),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -852,8 +1300,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -885,8 +1336,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: Some(kube::core::Duration::from(Duration::from_secs(600))), // 10 minutes
+            builtin: None,
             keyed: Some(true),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -929,8 +1383,11 @@ mod tests {
 
         let group_by = Box::new(GroupBy {
             allowed_lateness: Some(kube::core::Duration::from(Duration::from_secs(300))), // 5 minutes
+            builtin: None,
             keyed: Some(false),
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         });
 
@@ -11,25 +11,53 @@ const DEFAULT_SINK_INITIAL_RETRY_INTERVAL_IN_MS: u32 = 1;
 const DEFAULT_SINK_MAX_RETRY_INTERVAL_IN_MS: u32 = u32::MAX;
 const DEFAULT_SINK_RETRY_FACTOR: f64 = 1.0;
 const DEFAULT_SINK_RETRY_JITTER: f64 = 0.0;
+const DEFAULT_CIRCUIT_BREAKER_FAILURE_RATIO: f64 = 0.5;
+const DEFAULT_CIRCUIT_BREAKER_MIN_WRITES: usize = 20;
+const DEFAULT_CIRCUIT_BREAKER_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+const DEFAULT_CIRCUIT_BREAKER_OPEN_DURATION: std::time::Duration =
+    std::time::Duration::from_secs(30);
+const DEFAULT_JETSTREAM_SINK_ACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const DEFAULT_HTTP_SINK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+const DEFAULT_HTTP_SINK_CONCURRENCY: usize = 50;
+const DEFAULT_FILE_SINK_MAX_SEGMENT_SIZE: u64 = 64 * 1024 * 1024; // 64 MiB
+const DEFAULT_FILE_SINK_MAX_SEGMENT_AGE: std::time::Duration = std::time::Duration::from_secs(300);
 
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::path::PathBuf;
 
+use numaflow_http::sink::{HttpSinkAuth, HttpSinkConfig, HttpSinkFormat};
 use numaflow_kafka::sink::KafkaSinkConfig;
-use numaflow_models::models::{KafkaSink, PulsarSink, RetryStrategy, Sink, SqsSink};
+use numaflow_models::models::{
+    AbstractSink, BroadcastSink, CircuitBreaker, FileSink, HttpSink, JetStreamSink, KafkaSink,
+    NatsSink, ObjectStoreSink, PostgresSink, PulsarSink, RetryStrategy, Sink, SqsSink,
+};
+use numaflow_nats::sink::NatsSinkConfig;
+use numaflow_objectstore::ObjectStoreSinkConfig;
+use numaflow_objectstore::format::{Column, ColumnType, Format};
+use numaflow_objectstore::store::{S3Config, StoreConfig};
+use numaflow_postgres::{
+    Column as PostgresColumnConfig, ColumnSource, OnConflict, PostgresSinkConfig,
+};
 use numaflow_pulsar::PulsarAuth;
 use numaflow_pulsar::sink::Config as PulsarSinkConfig;
 use numaflow_sqs::sink::SqsSinkConfig;
 
 use crate::Result;
 use crate::error::Error;
+use crate::shared::create_components::{parse_nats_auth, parse_tls_config};
+use crate::shared::file_segment::SegmentFormat;
 
-use super::parse_kafka_auth_config;
+use super::{get_secret_from_volume, parse_kafka_auth_config};
+use crate::config::{get_namespace, get_pipeline_name, get_vertex_name, get_vertex_replica};
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct SinkConfig {
     pub(crate) sink_type: SinkType,
     pub(crate) retry_config: Option<RetryConfig>,
+    /// mirror the dead-letter envelope of the messages routed to the fallback sink into their
+    /// headers, only set for the fallback sink.
+    pub(crate) dlq_headers: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -41,6 +69,14 @@ pub(crate) enum SinkType {
     Sqs(SqsSinkConfig),
     Kafka(Box<KafkaSinkConfig>),
     Pulsar(Box<PulsarSinkConfig>),
+    /// Both core NATS and JetStream sinks, [NatsSinkConfig::jetstream] tells them apart.
+    Nats(Box<NatsSinkConfig>),
+    Http(Box<HttpSinkConfig>),
+    File(FileSinkConfig),
+    ObjectStore(Box<ObjectStoreSinkConfig>),
+    Postgres(Box<PostgresSinkConfig>),
+    /// Writes each message to all the child sinks, only for the primary sink.
+    Broadcast(BroadcastSinkConfig),
 }
 
 impl SinkType {
@@ -66,9 +102,106 @@ impl SinkType {
             .or_else(|| sink.sqs.as_ref().map(|sqs| sqs.clone().try_into()))
             .or_else(|| sink.kafka.as_ref().map(|kafka| kafka.clone().try_into()))
             .or_else(|| sink.pulsar.as_ref().map(|pulsar| pulsar.clone().try_into()))
+            .or_else(|| sink.nats.as_ref().map(|nats| nats.clone().try_into()))
+            .or_else(|| {
+                sink.jetstream
+                    .as_ref()
+                    .map(|jetstream| jetstream.clone().try_into())
+            })
+            .or_else(|| sink.http.as_ref().map(|http| http.clone().try_into()))
+            .or_else(|| sink.file.as_ref().map(|file| file.clone().try_into()))
+            .or_else(|| {
+                sink.object_store
+                    .as_ref()
+                    .map(|object_store| object_store.clone().try_into())
+            })
+            .or_else(|| {
+                sink.postgres
+                    .as_ref()
+                    .map(|postgres| postgres.clone().try_into())
+            })
+            .or_else(|| {
+                sink.broadcast
+                    .as_ref()
+                    .map(|broadcast| broadcast.clone().try_into())
+            })
             .ok_or_else(|| Error::Config("Sink type not found".to_string()))?
     }
 
+    /// Sink type of a child of the broadcast sink, only the builtin sinks are supported.
+    fn broadcast_child_sinktype(child: &AbstractSink, index: usize) -> Result<Self> {
+        if child.udsink.is_some() || child.serve.is_some() {
+            return Err(Error::Config(
+                "udsink and serve sinks cannot be broadcast to".to_string(),
+            ));
+        }
+        child
+            .log
+            .as_ref()
+            .map(|_| Ok(SinkType::Log(LogConfig::default())))
+            .or_else(|| {
+                child
+                    .blackhole
+                    .as_ref()
+                    .map(|_| Ok(SinkType::Blackhole(BlackholeConfig::default())))
+            })
+            .or_else(|| child.sqs.as_ref().map(|sqs| sqs.clone().try_into()))
+            .or_else(|| {
+                child.kafka.as_ref().map(|kafka| {
+                    SinkType::try_from(kafka.clone()).map(|sink| {
+                        sink.with_kafka_transactional_id_suffix(&format!("broadcast-{index}"))
+                    })
+                })
+            })
+            .or_else(|| {
+                child
+                    .pulsar
+                    .as_ref()
+                    .map(|pulsar| pulsar.clone().try_into())
+            })
+            .or_else(|| child.nats.as_ref().map(|nats| nats.clone().try_into()))
+            .or_else(|| {
+                child
+                    .jetstream
+                    .as_ref()
+                    .map(|jetstream| jetstream.clone().try_into())
+            })
+            .or_else(|| child.http.as_ref().map(|http| http.clone().try_into()))
+            .or_else(|| child.file.as_ref().map(|file| file.clone().try_into()))
+            .or_else(|| {
+                child
+                    .object_store
+                    .as_ref()
+                    .map(|object_store| object_store.clone().try_into())
+            })
+            .or_else(|| {
+                child
+                    .postgres
+                    .as_ref()
+                    .map(|postgres| postgres.clone().try_into())
+            })
+            .ok_or_else(|| Error::Config("Sink type not found".to_string()))?
+    }
+
+    /// Name of the sink type, used to tell the children of the broadcast sink apart.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            SinkType::Log(_) => "log",
+            SinkType::Blackhole(_) => "blackhole",
+            SinkType::Serve => "serve",
+            SinkType::UserDefined(_) => "udsink",
+            SinkType::Sqs(_) => "sqs",
+            SinkType::Kafka(_) => "kafka",
+            SinkType::Pulsar(_) => "pulsar",
+            SinkType::Nats(_) => "nats",
+            SinkType::Http(_) => "http",
+            SinkType::File(_) => "file",
+            SinkType::ObjectStore(_) => "objectstore",
+            SinkType::Postgres(_) => "postgres",
+            SinkType::Broadcast(_) => "broadcast",
+        }
+    }
+
     pub(crate) fn fallback_sinktype(sink: &Sink) -> Result<Self> {
         if let Some(fallback) = sink.fallback.as_ref() {
             fallback
@@ -90,10 +223,10 @@ impl SinkType {
                 .or_else(|| fallback.serve.as_ref().map(|_| Ok(SinkType::Serve)))
                 .or_else(|| fallback.sqs.as_ref().map(|sqs| sqs.clone().try_into()))
                 .or_else(|| {
-                    fallback
-                        .kafka
-                        .as_ref()
-                        .map(|kafka| kafka.clone().try_into())
+                    fallback.kafka.as_ref().map(|kafka| {
+                        SinkType::try_from(kafka.clone())
+                            .map(|sink| sink.with_kafka_transactional_id_suffix("fallback"))
+                    })
                 })
                 .or_else(|| {
                     fallback
@@ -101,6 +234,27 @@ impl SinkType {
                         .as_ref()
                         .map(|pulsar| pulsar.clone().try_into())
                 })
+                .or_else(|| fallback.nats.as_ref().map(|nats| nats.clone().try_into()))
+                .or_else(|| {
+                    fallback
+                        .jetstream
+                        .as_ref()
+                        .map(|jetstream| jetstream.clone().try_into())
+                })
+                .or_else(|| fallback.http.as_ref().map(|http| http.clone().try_into()))
+                .or_else(|| fallback.file.as_ref().map(|file| file.clone().try_into()))
+                .or_else(|| {
+                    fallback
+                        .object_store
+                        .as_ref()
+                        .map(|object_store| object_store.clone().try_into())
+                })
+                .or_else(|| {
+                    fallback
+                        .postgres
+                        .as_ref()
+                        .map(|postgres| postgres.clone().try_into())
+                })
                 .ok_or_else(|| Error::Config("Sink type not found".to_string()))?
         } else {
             Err(Error::Config("Fallback sink not found".to_string()))
@@ -132,10 +286,10 @@ impl SinkType {
                 .or_else(|| on_success.serve.as_ref().map(|_| Ok(SinkType::Serve)))
                 .or_else(|| on_success.sqs.as_ref().map(|sqs| sqs.clone().try_into()))
                 .or_else(|| {
-                    on_success
-                        .kafka
-                        .as_ref()
-                        .map(|kafka| kafka.clone().try_into())
+                    on_success.kafka.as_ref().map(|kafka| {
+                        SinkType::try_from(kafka.clone())
+                            .map(|sink| sink.with_kafka_transactional_id_suffix("onsuccess"))
+                    })
                 })
                 .or_else(|| {
                     on_success
@@ -143,11 +297,44 @@ impl SinkType {
                         .as_ref()
                         .map(|pulsar| pulsar.clone().try_into())
                 })
+                .or_else(|| on_success.nats.as_ref().map(|nats| nats.clone().try_into()))
+                .or_else(|| {
+                    on_success
+                        .jetstream
+                        .as_ref()
+                        .map(|jetstream| jetstream.clone().try_into())
+                })
+                .or_else(|| on_success.http.as_ref().map(|http| http.clone().try_into()))
+                .or_else(|| on_success.file.as_ref().map(|file| file.clone().try_into()))
+                .or_else(|| {
+                    on_success
+                        .object_store
+                        .as_ref()
+                        .map(|object_store| object_store.clone().try_into())
+                })
+                .or_else(|| {
+                    on_success
+                        .postgres
+                        .as_ref()
+                        .map(|postgres| postgres.clone().try_into())
+                })
                 .ok_or_else(|| Error::Config("Sink type not found".to_string()))?
         } else {
             Err(Error::Config("OnSuccess sink not found".to_string()))
         }
     }
+
+    /// The fallback and on-success Kafka sinks of a vertex need their own transactional ids,
+    /// otherwise they would fence off the primary sink.
+    fn with_kafka_transactional_id_suffix(mut self, suffix: &str) -> Self {
+        if let SinkType::Kafka(config) = &mut self
+            && let Some(transactional_id) = config.transactional_id.as_mut()
+        {
+            transactional_id.push('-');
+            transactional_id.push_str(suffix);
+        }
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -156,6 +343,262 @@ pub(crate) struct LogConfig {}
 #[derive(Debug, Clone, PartialEq, Default)]
 pub(crate) struct BlackholeConfig {}
 
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FileSinkConfig {
+    /// Directory where the segments are written.
+    pub(crate) path: PathBuf,
+    pub(crate) format: SegmentFormat,
+    /// Segments are rotated once they reach this size in bytes.
+    pub(crate) max_segment_size: u64,
+    /// Segments are rotated once they are older than this.
+    pub(crate) max_segment_age: std::time::Duration,
+}
+
+impl Default for FileSinkConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            format: SegmentFormat::default(),
+            max_segment_size: DEFAULT_FILE_SINK_MAX_SEGMENT_SIZE,
+            max_segment_age: DEFAULT_FILE_SINK_MAX_SEGMENT_AGE,
+        }
+    }
+}
+
+impl TryFrom<Box<FileSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<FileSink>) -> Result<Self> {
+        let format = match &sink_config.format {
+            Some(format) => SegmentFormat::try_from(format.as_str())?,
+            None => SegmentFormat::default(),
+        };
+        let max_segment_size = match sink_config.max_segment_size {
+            Some(size) if size <= 0 => {
+                return Err(Error::Config(format!(
+                    "maxSegmentSize of file sink should be positive, got {size}"
+                )));
+            }
+            Some(size) => size as u64,
+            None => DEFAULT_FILE_SINK_MAX_SEGMENT_SIZE,
+        };
+        Ok(SinkType::File(FileSinkConfig {
+            path: PathBuf::from(sink_config.path),
+            format,
+            max_segment_size,
+            max_segment_age: sink_config
+                .max_segment_age
+                .map(std::time::Duration::from)
+                .unwrap_or(DEFAULT_FILE_SINK_MAX_SEGMENT_AGE),
+        }))
+    }
+}
+
+impl TryFrom<Box<ObjectStoreSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<ObjectStoreSink>) -> Result<Self> {
+        let sink_config = *sink_config;
+        let store = match (sink_config.s3, sink_config.local) {
+            (Some(s3), None) => StoreConfig::S3(S3Config {
+                bucket: s3.bucket,
+                region: s3.aws_region,
+                endpoint_url: s3.endpoint_url,
+                assume_role_config: s3.assume_role.map(|ar| numaflow_sqs::AssumeRoleConfig {
+                    role_arn: ar.role_arn,
+                    session_name: ar.session_name,
+                    duration_seconds: ar.duration_seconds,
+                    external_id: ar.external_id,
+                    policy: ar.policy,
+                    policy_arns: ar.policy_arns,
+                }),
+                force_path_style: s3.force_path_style.unwrap_or(false),
+            }),
+            (None, Some(local)) => StoreConfig::Local {
+                path: PathBuf::from(local.path),
+            },
+            _ => {
+                return Err(Error::Config(
+                    "exactly one of s3 or local should be set for the object store sink"
+                        .to_string(),
+                ));
+            }
+        };
+
+        let mut columns = vec![];
+        for column in sink_config.columns.unwrap_or_default() {
+            let column_type = ColumnType::try_from(column.r#type.as_str())
+                .map_err(|e| Error::Config(e.to_string()))?;
+            let mut parsed = Column::new(column.name, column_type);
+            if let Some(path) = column.path {
+                parsed.path = path;
+            }
+            columns.push(parsed);
+        }
+
+        let positive = |name: &str, value: Option<i64>, default: usize| match value {
+            Some(value) if value <= 0 => Err(Error::Config(format!(
+                "{name} of object store sink should be positive, got {value}"
+            ))),
+            Some(value) => Ok(value as usize),
+            None => Ok(default),
+        };
+        let defaults = ObjectStoreSinkConfig::default();
+        let config = ObjectStoreSinkConfig {
+            store,
+            prefix: sink_config.prefix.unwrap_or_default(),
+            format: match sink_config.format {
+                Some(format) => {
+                    Format::try_from(format.as_str()).map_err(|e| Error::Config(e.to_string()))?
+                }
+                None => Format::default(),
+            },
+            columns,
+            max_file_size: positive(
+                "maxFileSize",
+                sink_config.max_file_size,
+                defaults.max_file_size,
+            )?,
+            max_file_messages: positive(
+                "maxFileMessages",
+                sink_config.max_file_messages,
+                defaults.max_file_messages,
+            )?,
+            max_file_age: sink_config
+                .max_file_age
+                .map(std::time::Duration::from)
+                .unwrap_or(defaults.max_file_age),
+            partition_window: sink_config
+                .partition_window
+                .map(std::time::Duration::from)
+                .unwrap_or(defaults.partition_window),
+            partition_by_keys: sink_config.partition_by_keys.unwrap_or(false),
+        };
+        config
+            .validate()
+            .map_err(|e| Error::Config(e.to_string()))?;
+        Ok(SinkType::ObjectStore(Box::new(config)))
+    }
+}
+
+impl TryFrom<Box<PostgresSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<PostgresSink>) -> Result<Self> {
+        let sink_config = *sink_config;
+        let password = sink_config
+            .password_secret
+            .map(|password| {
+                get_secret_from_volume(&password.name, &password.key)
+                    .map_err(|e| Error::Config(format!("Failed to get password secret: {e:?}")))
+            })
+            .transpose()?;
+
+        let mut columns = Vec::with_capacity(sink_config.columns.len());
+        for column in sink_config.columns {
+            let mut parsed = PostgresColumnConfig::new(column.name);
+            match (column.key, column.path) {
+                (Some(_), Some(_)) => {
+                    return Err(Error::Config(format!(
+                        "column '{}' of postgres sink can't have both key and path",
+                        parsed.name
+                    )));
+                }
+                (Some(key), None) => {
+                    let key = usize::try_from(key).map_err(|_| {
+                        Error::Config(format!(
+                            "key of column '{}' of postgres sink should not be negative, got {key}",
+                            parsed.name
+                        ))
+                    })?;
+                    parsed.source = ColumnSource::Key(key);
+                }
+                (None, Some(path)) => parsed.source = ColumnSource::Payload(path),
+                (None, None) => {}
+            }
+            columns.push(parsed);
+        }
+
+        let defaults = PostgresSinkConfig::default();
+        let config = PostgresSinkConfig {
+            url: sink_config.url,
+            password,
+            table: sink_config.table,
+            columns,
+            conflict_columns: sink_config.conflict_columns.unwrap_or_default(),
+            on_conflict: match sink_config.on_conflict {
+                Some(on_conflict) => OnConflict::try_from(on_conflict.as_str())
+                    .map_err(|e| Error::Config(e.to_string()))?,
+                None => OnConflict::default(),
+            },
+            id_column: sink_config.id_column,
+            connect_timeout: sink_config
+                .connect_timeout
+                .map(std::time::Duration::from)
+                .unwrap_or(defaults.connect_timeout),
+        };
+        config
+            .validate()
+            .map_err(|e| Error::Config(e.to_string()))?;
+        Ok(SinkType::Postgres(Box::new(config)))
+    }
+}
+
+/// When a message written to the children of the broadcast sink is considered written.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum BroadcastAck {
+    /// all the children have written the message.
+    #[default]
+    All,
+    /// a majority of the children have written the message.
+    Quorum,
+    /// the failures of the children are only logged and counted.
+    BestEffort,
+}
+
+impl TryFrom<&str> for BroadcastAck {
+    type Error = Error;
+    fn try_from(ack: &str) -> Result<Self> {
+        match ack.to_lowercase().as_str() {
+            "all" => Ok(BroadcastAck::All),
+            "quorum" => Ok(BroadcastAck::Quorum),
+            "besteffort" => Ok(BroadcastAck::BestEffort),
+            _ => Err(Error::Config(format!(
+                "invalid ack {ack} of broadcast sink, should be one of all, quorum or bestEffort"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BroadcastSinkConfig {
+    /// child sinks with their names, the name is the sink type suffixed with its index.
+    pub(crate) sinks: Vec<(String, SinkType)>,
+    pub(crate) ack: BroadcastAck,
+}
+
+impl TryFrom<Box<BroadcastSink>> for SinkType {
+    type Error = Error;
+    fn try_from(broadcast: Box<BroadcastSink>) -> Result<Self> {
+        if broadcast.sinks.is_empty() {
+            return Err(Error::Config(
+                "broadcast sink should have at least one sink".to_string(),
+            ));
+        }
+        let sinks = broadcast
+            .sinks
+            .iter()
+            .enumerate()
+            .map(|(index, child)| {
+                SinkType::broadcast_child_sinktype(child, index)
+                    .map(|sink_type| (format!("{}-{index}", sink_type.name()), sink_type))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let ack = match &broadcast.ack {
+            Some(ack) => BroadcastAck::try_from(ack.as_str())?,
+            None => BroadcastAck::default(),
+        };
+        Ok(SinkType::Broadcast(BroadcastSinkConfig { sinks, ack }))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct UserDefinedConfig {
     pub grpc_max_message_size: usize,
@@ -213,6 +656,17 @@ impl TryFrom<Box<KafkaSink>> for SinkType {
             auth,
             tls,
             set_partition_key: kafka_config.set_key.unwrap_or(false),
+            // stable across restarts of the replica, so that the restarted producer fences off the
+            // previous one and aborts its pending transaction.
+            transactional_id: kafka_config.transactional.unwrap_or(false).then(|| {
+                format!(
+                    "{}-{}-{}-{}",
+                    get_namespace(),
+                    get_pipeline_name(),
+                    get_vertex_name(),
+                    get_vertex_replica()
+                )
+            }),
             // config is multiline string with key: value pairs.
             // Eg:
             //  max.poll.interval.ms: 100
@@ -258,6 +712,103 @@ impl TryFrom<Box<PulsarSink>> for SinkType {
     }
 }
 
+impl TryFrom<Box<NatsSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<NatsSink>) -> Result<Self> {
+        let auth = parse_nats_auth(sink_config.auth)?;
+        let tls = parse_tls_config(sink_config.tls)?;
+        Ok(SinkType::Nats(Box::new(NatsSinkConfig {
+            addr: sink_config.url,
+            subject: sink_config.subject,
+            auth,
+            tls,
+            jetstream: false,
+            ack_timeout: DEFAULT_JETSTREAM_SINK_ACK_TIMEOUT,
+        })))
+    }
+}
+
+impl TryFrom<Box<JetStreamSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<JetStreamSink>) -> Result<Self> {
+        let auth = parse_nats_auth(sink_config.auth)?;
+        let tls = parse_tls_config(sink_config.tls)?;
+        Ok(SinkType::Nats(Box::new(NatsSinkConfig {
+            addr: sink_config.url,
+            subject: sink_config.subject,
+            auth,
+            tls,
+            jetstream: true,
+            ack_timeout: sink_config
+                .ack_timeout
+                .map(std::time::Duration::from)
+                .unwrap_or(DEFAULT_JETSTREAM_SINK_ACK_TIMEOUT),
+        })))
+    }
+}
+
+impl TryFrom<Box<HttpSink>> for SinkType {
+    type Error = Error;
+    fn try_from(sink_config: Box<HttpSink>) -> Result<Self> {
+        let method = match sink_config.method {
+            Some(method) => axum::http::Method::from_bytes(method.to_uppercase().as_bytes())
+                .map_err(|e| Error::Config(format!("Invalid HTTP method {method}: {e}")))?,
+            None => axum::http::Method::POST,
+        };
+        let format = match sink_config.format {
+            Some(format) => HttpSinkFormat::try_from(format.as_str())
+                .map_err(|e| Error::Config(e.to_string()))?,
+            None => HttpSinkFormat::Raw,
+        };
+
+        let auth = match sink_config.auth {
+            Some(auth) => {
+                if let Some(basic) = auth.basic {
+                    let user = basic.user.ok_or_else(|| {
+                        Error::Config("Username can not be empty for basic auth".to_string())
+                    })?;
+                    let username = get_secret_from_volume(&user.name, &user.key).map_err(|e| {
+                        Error::Config(format!("Failed to get username secret: {e:?}"))
+                    })?;
+                    let password = basic.password.ok_or_else(|| {
+                        Error::Config("Password can not be empty for basic auth".to_string())
+                    })?;
+                    let password =
+                        get_secret_from_volume(&password.name, &password.key).map_err(|e| {
+                            Error::Config(format!("Failed to get password secret: {e:?}"))
+                        })?;
+                    Some(HttpSinkAuth::Basic { username, password })
+                } else if let Some(bearer) = auth.bearer {
+                    let token = get_secret_from_volume(&bearer.name, &bearer.key).map_err(|e| {
+                        Error::Config(format!("Failed to get bearer token secret: {e:?}"))
+                    })?;
+                    Some(HttpSinkAuth::Bearer(token))
+                } else {
+                    return Err(Error::Config(
+                        "Authentication is specified, but auth setting is empty".to_string(),
+                    ));
+                }
+            }
+            None => None,
+        };
+
+        Ok(SinkType::Http(Box::new(HttpSinkConfig {
+            url: sink_config.url,
+            method,
+            headers: sink_config.headers.unwrap_or_default(),
+            header_mapping: sink_config.header_mapping.unwrap_or_default(),
+            auth,
+            format,
+            timeout: sink_config
+                .timeout
+                .map(std::time::Duration::from)
+                .unwrap_or(DEFAULT_HTTP_SINK_TIMEOUT),
+            insecure_skip_verify: sink_config.insecure_skip_verify.unwrap_or(false),
+            concurrency: DEFAULT_HTTP_SINK_CONCURRENCY,
+        })))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum OnFailureStrategy {
     Retry,
@@ -303,6 +854,8 @@ pub(crate) struct RetryConfig {
     pub sink_retry_jitter: f64,
     pub sink_max_retry_interval_in_ms: u32,
     pub sink_retry_on_fail_strategy: OnFailureStrategy,
+    /// circuit breaker around the sink, only set for the primary sink.
+    pub sink_circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 impl Default for RetryConfig {
@@ -314,6 +867,7 @@ impl Default for RetryConfig {
             sink_retry_factor: DEFAULT_SINK_RETRY_FACTOR,
             sink_retry_jitter: DEFAULT_SINK_RETRY_JITTER,
             sink_retry_on_fail_strategy: DEFAULT_SINK_RETRY_ON_FAIL_STRATEGY,
+            sink_circuit_breaker: None,
         }
     }
 }
@@ -353,10 +907,90 @@ impl From<Box<RetryStrategy>> for RetryConfig {
             retry_config.sink_max_retry_attempts = DEFAULT_MAX_SINK_RETRY_ATTEMPTS;
         }
 
+        retry_config.sink_circuit_breaker = retry.circuit_breaker.map(|cb| cb.into());
+
         retry_config
     }
 }
 
+/// What to do with the messages while the circuit breaker of the sink is open.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum OnOpenStrategy {
+    /// stop writing to the sink until the circuit is half-open, which in turn stops reading.
+    Pause,
+    /// route the messages to the fallback sink without writing them to the sink.
+    Fallback,
+}
+
+impl OnOpenStrategy {
+    /// Converts a string slice to an `OnOpenStrategy`, defaults to `Pause` for unknown values.
+    pub(crate) fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "fallback" => OnOpenStrategy::Fallback,
+            _ => OnOpenStrategy::Pause,
+        }
+    }
+}
+
+impl Display for OnOpenStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            OnOpenStrategy::Pause => write!(f, "pause"),
+            OnOpenStrategy::Fallback => write!(f, "fallback"),
+        }
+    }
+}
+
+/// Circuit breaker around the sink, it opens when the ratio of the failed writes over the window
+/// crosses `failure_ratio`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CircuitBreakerConfig {
+    pub(crate) failure_ratio: f64,
+    /// minimum number of writes in the window before the failure ratio is evaluated.
+    pub(crate) min_writes: usize,
+    pub(crate) window: std::time::Duration,
+    /// how long the circuit stays open before a probe batch is written.
+    pub(crate) open_duration: std::time::Duration,
+    pub(crate) on_open: OnOpenStrategy,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_ratio: DEFAULT_CIRCUIT_BREAKER_FAILURE_RATIO,
+            min_writes: DEFAULT_CIRCUIT_BREAKER_MIN_WRITES,
+            window: DEFAULT_CIRCUIT_BREAKER_WINDOW,
+            open_duration: DEFAULT_CIRCUIT_BREAKER_OPEN_DURATION,
+            on_open: OnOpenStrategy::Pause,
+        }
+    }
+}
+
+impl From<Box<CircuitBreaker>> for CircuitBreakerConfig {
+    fn from(cb: Box<CircuitBreaker>) -> Self {
+        let mut config = CircuitBreakerConfig::default();
+        if let Some(ratio) = cb.failure_ratio
+            && ratio > 0.0
+            && ratio <= 1.0
+        {
+            config.failure_ratio = ratio;
+        }
+        if let Some(min_writes) = cb.min_writes {
+            config.min_writes = min_writes.max(1) as usize;
+        }
+        if let Some(window) = cb.window {
+            config.window = window.into();
+        }
+        if let Some(open_duration) = cb.open_duration {
+            config.open_duration = open_duration.into();
+        }
+        if let Some(on_open) = &cb.on_open {
+            config.on_open = OnOpenStrategy::from_str(on_open);
+        }
+        config
+    }
+}
+
 impl Default for UserDefinedConfig {
     fn default() -> Self {
         Self {
@@ -459,12 +1093,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_circuit_breaker_config_from_retry_strategy() {
+        let retry = RetryStrategy {
+            circuit_breaker: Some(Box::new(CircuitBreaker {
+                failure_ratio: Some(0.8),
+                min_writes: Some(10),
+                on_open: Some("Fallback".to_string()),
+                open_duration: Some(std::time::Duration::from_secs(10).into()),
+                window: None,
+            })),
+            ..RetryStrategy::new()
+        };
+        let retry_config: RetryConfig = Box::new(retry).into();
+        assert_eq!(
+            retry_config.sink_circuit_breaker,
+            Some(CircuitBreakerConfig {
+                failure_ratio: 0.8,
+                min_writes: 10,
+                window: DEFAULT_CIRCUIT_BREAKER_WINDOW,
+                open_duration: std::time::Duration::from_secs(10),
+                on_open: OnOpenStrategy::Fallback,
+            })
+        );
+
+        // out of range ratios are ignored
+        let cb = CircuitBreaker {
+            failure_ratio: Some(1.5),
+            ..CircuitBreaker::new()
+        };
+        let config: CircuitBreakerConfig = Box::new(cb).into();
+        assert_eq!(config, CircuitBreakerConfig::default());
+
+        assert_eq!(RetryConfig::default().sink_circuit_breaker, None);
+    }
+
     #[test]
     fn test_sink_config_log() {
         let log_config = LogConfig::default();
         let sink_config = SinkConfig {
             sink_type: SinkType::Log(log_config.clone()),
             retry_config: None,
+            dlq_headers: false,
         };
         if let SinkType::Log(config) = sink_config.sink_type {
             assert_eq!(config, log_config);
@@ -479,6 +1149,7 @@ mod tests {
         let sink_config = SinkConfig {
             sink_type: SinkType::Blackhole(blackhole_config.clone()),
             retry_config: None,
+            dlq_headers: false,
         };
         if let SinkType::Blackhole(config) = sink_config.sink_type {
             assert_eq!(config, blackhole_config);
@@ -493,6 +1164,7 @@ mod tests {
         let sink_config = SinkConfig {
             sink_type: SinkType::UserDefined(user_defined_config.clone()),
             retry_config: None,
+            dlq_headers: false,
         };
         if let SinkType::UserDefined(config) = sink_config.sink_type {
             assert_eq!(config, user_defined_config);
@@ -512,6 +1184,7 @@ mod tests {
         let sink_config = SinkConfig {
             sink_type: SinkType::Sqs(sqs_config.clone()),
             retry_config: None,
+            dlq_headers: false,
         };
         if let SinkType::Sqs(config) = sink_config.sink_type {
             assert_eq!(config, sqs_config);
@@ -542,18 +1215,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_broadcast_sink_type() {
+        use numaflow_models::models::{
+            AbstractSink, Blackhole, BroadcastSink, Container, Log, Sink, UdSink,
+        };
+
+        let children = vec![
+            AbstractSink {
+                log: Some(Box::new(Log::new())),
+                ..AbstractSink::new()
+            },
+            AbstractSink {
+                blackhole: Some(Box::new(Blackhole::new())),
+                ..AbstractSink::new()
+            },
+        ];
+        let sink = Sink {
+            broadcast: Some(Box::new(BroadcastSink {
+                ack: Some("quorum".to_string()),
+                sinks: children.clone(),
+            })),
+            ..Sink::new()
+        };
+        assert_eq!(
+            SinkType::primary_sinktype(&sink).unwrap(),
+            SinkType::Broadcast(BroadcastSinkConfig {
+                sinks: vec![
+                    ("log-0".to_string(), SinkType::Log(LogConfig::default())),
+                    (
+                        "blackhole-1".to_string(),
+                        SinkType::Blackhole(BlackholeConfig::default())
+                    ),
+                ],
+                ack: BroadcastAck::Quorum,
+            })
+        );
+
+        // invalid ack
+        let sink = Sink {
+            broadcast: Some(Box::new(BroadcastSink {
+                ack: Some("some".to_string()),
+                sinks: children,
+            })),
+            ..Sink::new()
+        };
+        assert!(SinkType::primary_sinktype(&sink).is_err());
+
+        // user-defined sinks cannot be broadcast to
+        let sink = Sink {
+            broadcast: Some(Box::new(BroadcastSink::new(vec![AbstractSink {
+                udsink: Some(Box::new(UdSink::new(Container::new()))),
+                ..AbstractSink::new()
+            }]))),
+            ..Sink::new()
+        };
+        assert!(SinkType::primary_sinktype(&sink).is_err());
+
+        // no sinks
+        let sink = Sink {
+            broadcast: Some(Box::new(BroadcastSink::new(vec![]))),
+            ..Sink::new()
+        };
+        assert!(SinkType::primary_sinktype(&sink).is_err());
+    }
+
     #[test]
     fn test_sqs_fallback_sink_type() {
         use numaflow_models::models::{AbstractSink, Sink, SqsSink};
 
         // Test case 1: Valid SQS fallback configuration
         let sink = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
@@ -563,10 +1307,16 @@ mod tests {
                     "fallback-queue".to_string(),
                     "123456789012".to_string(),
                 ))),
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: None,
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
@@ -576,11 +1326,19 @@ mod tests {
                     "fallback-queue".to_string(),
                     "123456789012".to_string(),
                 ))),
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: None,
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
 
@@ -608,15 +1366,23 @@ mod tests {
 
         // Test case 2: Missing fallback configuration
         let sink_without_fallback = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: None,
             on_success: None,
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
         let result = SinkType::fallback_sinktype(&sink_without_fallback);
@@ -628,31 +1394,51 @@ mod tests {
 
         // Test case 3: Empty fallback sink configuration
         let sink_empty_fallback = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: None,
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: None,
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
         let result = SinkType::fallback_sinktype(&sink_empty_fallback);
@@ -789,18 +1575,28 @@ mod tests {
 
         // Test case 1: Valid Pulsar fallback configuration without auth
         let sink = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: None,
                     producer_name: "fallback-producer".to_string(),
@@ -809,12 +1605,18 @@ mod tests {
                 })),
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: None,
                     producer_name: "fallback-producer".to_string(),
@@ -823,7 +1625,11 @@ mod tests {
                 })),
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
 
@@ -857,18 +1663,28 @@ mod tests {
         setup_secret(secret_name, token_key, "fallback-jwt-token");
 
         let sink_with_auth = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: Some(SecretKeySelector {
@@ -884,12 +1700,18 @@ mod tests {
                 })),
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: Some(SecretKeySelector {
@@ -905,7 +1727,11 @@ mod tests {
                 })),
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
 
@@ -950,18 +1776,28 @@ mod tests {
 
         // Test case: Fallback Pulsar sink with authentication but missing token
         let sink = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: None,
@@ -973,12 +1809,18 @@ mod tests {
                 })),
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: None,
@@ -990,7 +1832,11 @@ mod tests {
                 })),
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
 
@@ -1016,18 +1862,28 @@ mod tests {
 
         // Test case: Fallback Pulsar sink with authentication but invalid secret
         let sink = Sink {
+            object_store: None,
+            postgres: None,
             udsink: None,
             log: None,
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             serve: None,
             sqs: None,
             fallback: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: Some(SecretKeySelector {
@@ -1043,12 +1899,18 @@ mod tests {
                 })),
             })),
             on_success: Some(Box::new(AbstractSink {
+                object_store: None,
+                postgres: None,
                 udsink: None,
                 log: None,
                 blackhole: None,
                 serve: None,
                 sqs: None,
+                file: None,
+                http: None,
+                jetstream: None,
                 kafka: None,
+                nats: None,
                 pulsar: Some(Box::new(PulsarSink {
                     auth: Some(Box::new(numaflow_models::models::PulsarAuth {
                         token: Some(SecretKeySelector {
@@ -1064,7 +1926,11 @@ mod tests {
                 })),
             })),
             retry_strategy: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
+            nats: None,
             pulsar: None,
         };
 
@@ -1084,4 +1950,256 @@ mod tests {
                 .contains("Failed to get token secret from volume")
         );
     }
+
+    #[test]
+    fn test_nats_sink_type_conversion() {
+        use numaflow_models::models::NatsSink;
+
+        let nats_sink = Box::new(NatsSink::new(
+            "orders.{{key.0}}".to_string(),
+            "nats://localhost:4222".to_string(),
+        ));
+        let SinkType::Nats(config) = SinkType::try_from(nats_sink).unwrap() else {
+            panic!("Expected SinkType::Nats");
+        };
+        assert_eq!(config.addr, "nats://localhost:4222");
+        assert_eq!(config.subject, "orders.{{key.0}}");
+        assert!(!config.jetstream);
+        assert!(config.auth.is_none());
+        assert!(config.tls.is_none());
+    }
+
+    #[test]
+    fn test_jetstream_fallback_sink_type() {
+        use numaflow_models::models::{AbstractSink, JetStreamSink};
+
+        let mut jetstream_sink = JetStreamSink::new(
+            "dlq.{{header.type}}".to_string(),
+            "nats://localhost:4222".to_string(),
+        );
+        jetstream_sink.ack_timeout = Some(std::time::Duration::from_secs(10).into());
+
+        let mut fallback = AbstractSink::new();
+        fallback.jetstream = Some(Box::new(jetstream_sink));
+        let mut sink = Sink::new();
+        sink.log = Some(Box::new(numaflow_models::models::Log::new()));
+        sink.fallback = Some(Box::new(fallback));
+
+        assert!(matches!(
+            SinkType::primary_sinktype(&sink),
+            Ok(SinkType::Log(_))
+        ));
+        let SinkType::Nats(config) = SinkType::fallback_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::Nats for fallback sink");
+        };
+        assert!(config.jetstream);
+        assert_eq!(config.subject, "dlq.{{header.type}}");
+        assert_eq!(config.ack_timeout, std::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_http_sink_type_conversion() {
+        use numaflow_models::models::HttpSink;
+
+        let mut http_sink = HttpSink::new("https://example.com/events".to_string());
+        http_sink.method = Some("put".to_string());
+        http_sink.format = Some("ndjson".to_string());
+        http_sink.header_mapping = Some(HashMap::from([(
+            "trace-id".to_string(),
+            "X-Trace-Id".to_string(),
+        )]));
+        let mut sink = Sink::new();
+        sink.http = Some(Box::new(http_sink.clone()));
+
+        let SinkType::Http(config) = SinkType::primary_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::Http");
+        };
+        assert_eq!(config.url, "https://example.com/events");
+        assert_eq!(config.method, axum::http::Method::PUT);
+        assert_eq!(config.format, HttpSinkFormat::Ndjson);
+        assert_eq!(
+            config.header_mapping.get("trace-id"),
+            Some(&"X-Trace-Id".to_string())
+        );
+        assert_eq!(config.timeout, DEFAULT_HTTP_SINK_TIMEOUT);
+        assert!(config.auth.is_none());
+
+        http_sink.format = Some("xml".to_string());
+        assert!(SinkType::try_from(Box::new(http_sink.clone())).is_err());
+
+        http_sink.format = None;
+        http_sink.auth = Some(Box::new(numaflow_models::models::HttpSinkAuth::new()));
+        assert!(SinkType::try_from(Box::new(http_sink)).is_err());
+    }
+
+    #[test]
+    fn test_file_sink_type_conversion() {
+        use numaflow_models::models::FileSink;
+
+        let mut file_sink = FileSink::new("/var/numaflow/capture".to_string());
+        file_sink.format = Some("proto".to_string());
+        file_sink.max_segment_age = Some(std::time::Duration::from_secs(60).into());
+        let mut sink = Sink::new();
+        sink.file = Some(Box::new(file_sink.clone()));
+
+        let SinkType::File(config) = SinkType::primary_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::File");
+        };
+        assert_eq!(config.path, PathBuf::from("/var/numaflow/capture"));
+        assert_eq!(config.format, SegmentFormat::Proto);
+        assert_eq!(config.max_segment_size, DEFAULT_FILE_SINK_MAX_SEGMENT_SIZE);
+        assert_eq!(config.max_segment_age, std::time::Duration::from_secs(60));
+
+        file_sink.max_segment_size = Some(0);
+        assert!(SinkType::try_from(Box::new(file_sink.clone())).is_err());
+
+        file_sink.max_segment_size = None;
+        file_sink.format = Some("csv".to_string());
+        assert!(SinkType::try_from(Box::new(file_sink)).is_err());
+    }
+
+    #[test]
+    fn test_object_store_sink_type_conversion() {
+        use numaflow_models::models::{ObjectStoreColumn, ObjectStoreSink, S3ObjectStore};
+
+        let mut s3 = S3ObjectStore::new("us-west-2".to_string(), "events".to_string());
+        s3.endpoint_url = Some("http://minio:9000".to_string());
+        s3.force_path_style = Some(true);
+        let mut column = ObjectStoreColumn::new("user".to_string(), "string".to_string());
+        column.path = Some("/user/name".to_string());
+        let mut object_store_sink = ObjectStoreSink::new();
+        object_store_sink.s3 = Some(Box::new(s3));
+        object_store_sink.format = Some("parquet".to_string());
+        object_store_sink.columns = Some(vec![column]);
+        object_store_sink.max_file_age = Some(std::time::Duration::from_secs(30).into());
+        object_store_sink.partition_by_keys = Some(true);
+        let mut sink = Sink::new();
+        sink.object_store = Some(Box::new(object_store_sink.clone()));
+
+        let SinkType::ObjectStore(config) = SinkType::primary_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::ObjectStore");
+        };
+        let StoreConfig::S3(s3) = &config.store else {
+            panic!("Expected S3 store");
+        };
+        assert_eq!(s3.bucket, "events");
+        assert_eq!(s3.endpoint_url.as_deref(), Some("http://minio:9000"));
+        assert!(s3.force_path_style);
+        assert_eq!(config.format, Format::Parquet);
+        assert_eq!(config.columns.first().unwrap().path, "/user/name");
+        assert_eq!(config.max_file_age, std::time::Duration::from_secs(30));
+        assert_eq!(config.max_file_messages, 100_000);
+        assert!(config.partition_by_keys);
+
+        // the parquet format needs columns
+        let mut invalid = object_store_sink.clone();
+        invalid.columns = None;
+        assert!(SinkType::try_from(Box::new(invalid)).is_err());
+
+        // exactly one store
+        let mut invalid = object_store_sink;
+        invalid.s3 = None;
+        assert!(SinkType::try_from(Box::new(invalid)).is_err());
+    }
+
+    #[test]
+    fn test_postgres_sink_type_conversion() {
+        use k8s_openapi::api::core::v1::SecretKeySelector;
+        use numaflow_models::models::PostgresColumn;
+
+        let secret_name = "test_postgres_sink_type_conversion_secret";
+        setup_secret(secret_name, "password", "secret-password");
+
+        let mut region = PostgresColumn::new("region".to_string());
+        region.key = Some(0);
+        let mut user = PostgresColumn::new("user".to_string());
+        user.path = Some("/user/name".to_string());
+        let mut postgres_sink = PostgresSink::new(
+            vec![region, user, PostgresColumn::new("count".to_string())],
+            "public.events".to_string(),
+            "postgres://numaflow@localhost:5432/events".to_string(),
+        );
+        postgres_sink.conflict_columns = Some(vec!["region".to_string(), "user".to_string()]);
+        postgres_sink.on_conflict = Some("nothing".to_string());
+        postgres_sink.id_column = Some("message_id".to_string());
+        postgres_sink.password_secret = Some(SecretKeySelector {
+            name: secret_name.to_string(),
+            key: "password".to_string(),
+            ..Default::default()
+        });
+        let mut sink = Sink::new();
+        sink.postgres = Some(Box::new(postgres_sink.clone()));
+
+        let SinkType::Postgres(config) = SinkType::primary_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::Postgres");
+        };
+        assert_eq!(config.password.as_deref(), Some("secret-password"));
+        assert_eq!(
+            config.columns.iter().map(|c| &c.source).collect::<Vec<_>>(),
+            vec![
+                &ColumnSource::Key(0),
+                &ColumnSource::Payload("/user/name".to_string()),
+                &ColumnSource::Payload("/count".to_string()),
+            ]
+        );
+        assert_eq!(config.on_conflict, OnConflict::Nothing);
+        assert_eq!(config.id_column.as_deref(), Some("message_id"));
+        assert_eq!(config.connect_timeout, std::time::Duration::from_secs(10));
+        cleanup_secret(secret_name);
+
+        // a column reads either a key or the payload
+        let mut invalid = postgres_sink.clone();
+        invalid.password_secret = None;
+        if let Some(column) = invalid.columns.first_mut() {
+            column.path = Some("/region".to_string());
+        }
+        assert!(SinkType::try_from(Box::new(invalid)).is_err());
+
+        // the conflict columns must be mapped
+        let mut invalid = postgres_sink;
+        invalid.password_secret = None;
+        invalid.conflict_columns = Some(vec!["missing".to_string()]);
+        assert!(SinkType::try_from(Box::new(invalid)).is_err());
+    }
+
+    #[test]
+    fn test_kafka_sink_transactional_id() {
+        use numaflow_models::models::AbstractSink;
+
+        let mut kafka_sink = KafkaSink::new("test-topic".to_string());
+        kafka_sink.brokers = Some(vec!["localhost:9092".to_string()]);
+        let SinkType::Kafka(config) = SinkType::try_from(Box::new(kafka_sink.clone())).unwrap()
+        else {
+            panic!("Expected SinkType::Kafka");
+        };
+        assert!(config.transactional_id.is_none());
+
+        kafka_sink.transactional = Some(true);
+        let mut sink = Sink::new();
+        sink.kafka = Some(Box::new(kafka_sink.clone()));
+        sink.fallback = Some(Box::new(AbstractSink {
+            kafka: Some(Box::new(kafka_sink)),
+            ..AbstractSink::new()
+        }));
+
+        let SinkType::Kafka(primary) = SinkType::primary_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::Kafka");
+        };
+        let expected = format!(
+            "{}-{}-{}-{}",
+            get_namespace(),
+            get_pipeline_name(),
+            get_vertex_name(),
+            get_vertex_replica()
+        );
+        assert_eq!(primary.transactional_id, Some(expected.clone()));
+
+        let SinkType::Kafka(fallback) = SinkType::fallback_sinktype(&sink).unwrap() else {
+            panic!("Expected SinkType::Kafka");
+        };
+        assert_eq!(
+            fallback.transactional_id,
+            Some(format!("{expected}-fallback"))
+        );
+    }
 }
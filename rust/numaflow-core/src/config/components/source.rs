@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::{fmt::Debug, time::Duration};
 
 use super::{get_secret_from_volume, parse_kafka_auth_config};
@@ -6,11 +7,12 @@ use crate::Result;
 use crate::config::get_vertex_name;
 use crate::error::Error;
 use crate::shared::create_components::{parse_nats_auth, parse_tls_config};
+use crate::shared::file_segment::SegmentFormat;
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
 use bytes::Bytes;
 use numaflow_kafka::source::KafkaSourceConfig;
-use numaflow_models::models::{GeneratorSource, PulsarSource, SqsSource};
+use numaflow_models::models::{FileSource, GeneratorSource, PulsarSource, SqsSource};
 use numaflow_nats::NatsAuth;
 use numaflow_nats::jetstream::{ConsumerDeliverPolicy, JetstreamSourceConfig};
 use numaflow_nats::nats::NatsSourceConfig;
@@ -21,6 +23,7 @@ use tracing::warn;
 const DEFAULT_GRPC_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024; // 64 MB
 const DEFAULT_SOURCE_SOCKET: &str = "/var/run/numaflow/source.sock";
 const DEFAULT_SOURCE_SERVER_INFO_FILE: &str = "/var/run/numaflow/sourcer-server-info";
+const DEFAULT_FILE_SOURCE_CHECKPOINT_FILE: &str = ".checkpoint.json";
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct SourceConfig {
@@ -90,6 +93,7 @@ pub(crate) enum SourceType {
     Kafka(Box<KafkaSourceConfig>),
     Http(numaflow_http::HttpSourceConfig),
     Nats(NatsSourceConfig),
+    File(FileSourceConfig),
 }
 
 impl TryFrom<Box<GeneratorSource>> for SourceType {
@@ -337,6 +341,10 @@ impl TryFrom<SourceSpec> for SourceType {
             return http.try_into();
         }
 
+        if let Some(file) = source.spec.file.take() {
+            return file.try_into();
+        }
+
         Err(Error::Config(format!("Invalid source type: {source:?}")))
     }
 }
@@ -385,6 +393,36 @@ impl TryFrom<Box<numaflow_models::models::HttpSource>> for SourceType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FileSourceConfig {
+    /// Directory to read the segments from.
+    pub(crate) path: PathBuf,
+    pub(crate) format: SegmentFormat,
+    /// File where the read progress is persisted.
+    pub(crate) checkpoint_path: PathBuf,
+}
+
+impl TryFrom<Box<FileSource>> for SourceType {
+    type Error = Error;
+
+    fn try_from(value: Box<FileSource>) -> Result<Self> {
+        let format = match value.format {
+            Some(format) => SegmentFormat::try_from(format.as_str())?,
+            None => SegmentFormat::default(),
+        };
+        let path = PathBuf::from(value.path);
+        let checkpoint_path = value
+            .checkpoint_path
+            .map(PathBuf::from)
+            .unwrap_or_else(|| path.join(DEFAULT_FILE_SOURCE_CHECKPOINT_FILE));
+        Ok(SourceType::File(FileSourceConfig {
+            path,
+            format,
+            checkpoint_path,
+        }))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct UserDefinedConfig {
     pub grpc_max_message_size: usize,
@@ -1141,4 +1179,35 @@ mod nats_source_tests {
             "Config Error - Authentication is specified, but auth setting is empty"
         );
     }
+
+    #[test]
+    fn test_try_from_file_source() {
+        use numaflow_models::models::FileSource;
+
+        let source_type = SourceType::try_from(Box::new(FileSource::new(
+            "/var/numaflow/capture".to_string(),
+        )))
+        .unwrap();
+        let SourceType::File(config) = source_type else {
+            panic!("Expected SourceType::File");
+        };
+        assert_eq!(config.path, PathBuf::from("/var/numaflow/capture"));
+        assert_eq!(config.format, SegmentFormat::Ndjson);
+        assert_eq!(
+            config.checkpoint_path,
+            PathBuf::from("/var/numaflow/capture/.checkpoint.json")
+        );
+
+        let mut file_source = FileSource::new("/var/numaflow/capture".to_string());
+        file_source.format = Some("proto".to_string());
+        file_source.checkpoint_path = Some("/tmp/checkpoint.json".to_string());
+        let SourceType::File(config) = SourceType::try_from(Box::new(file_source)).unwrap() else {
+            panic!("Expected SourceType::File");
+        };
+        assert_eq!(config.format, SegmentFormat::Proto);
+        assert_eq!(
+            config.checkpoint_path,
+            PathBuf::from("/tmp/checkpoint.json")
+        );
+    }
 }
@@ -64,6 +64,7 @@ impl Default for MonovertexConfig {
             sink_config: SinkConfig {
                 sink_type: SinkType::Log(sink::LogConfig::default()),
                 retry_config: None,
+                dlq_headers: false,
             },
             map_config: None,
             transformer_config: None,
@@ -162,6 +163,7 @@ impl MonovertexConfig {
         let sink_config = SinkConfig {
             sink_type: SinkType::primary_sinktype(&sink)?,
             retry_config: sink.retry_strategy.clone().map(|retry| retry.into()),
+            dlq_headers: false,
         };
 
         // Based on whether UDF config is present or not, obtain a Result<Box<Udf>>
@@ -184,6 +186,7 @@ impl MonovertexConfig {
             Some(SinkConfig {
                 sink_type: SinkType::fallback_sinktype(&sink)?,
                 retry_config: None,
+                dlq_headers: sink.dlq_headers.unwrap_or(false),
             })
         } else {
             None
@@ -193,6 +196,7 @@ impl MonovertexConfig {
             Some(SinkConfig {
                 sink_type: SinkType::on_success_sinktype(&sink)?,
                 retry_config: None,
+                dlq_headers: false,
             })
         } else {
             None
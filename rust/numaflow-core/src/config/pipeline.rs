@@ -18,7 +18,7 @@ use crate::config::ENV_NUMAFLOW_SERVING_CALLBACK_STORE;
 use crate::config::ENV_NUMAFLOW_SERVING_SPEC;
 use crate::config::components::metrics::MetricsConfig;
 use crate::config::components::ratelimit::RateLimitConfig;
-use crate::config::components::reduce::{ReducerConfig, StorageConfig};
+use crate::config::components::reduce::{ReducerConfig, StorageConfig, WalRecoveryPolicy};
 use crate::config::components::sink::SinkConfig;
 use crate::config::components::sink::SinkType;
 use crate::config::components::source::SourceConfig;
@@ -38,10 +38,14 @@ const DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS: u64 = 20; // time we will wait for UD
 const ENV_NUMAFLOW_SERVING_JETSTREAM_URL: &str = "NUMAFLOW_ISBSVC_JETSTREAM_URL";
 const ENV_NUMAFLOW_SERVING_JETSTREAM_USER: &str = "NUMAFLOW_ISBSVC_JETSTREAM_USER";
 const ENV_NUMAFLOW_SERVING_JETSTREAM_PASSWORD: &str = "NUMAFLOW_ISBSVC_JETSTREAM_PASSWORD";
+const ENV_NUMAFLOW_ISBSVC_REDIS_URL: &str = "NUMAFLOW_ISBSVC_REDIS_URL";
+const ENV_NUMAFLOW_ISBSVC_REDIS_PASSWORD: &str = "NUMAFLOW_ISBSVC_REDIS_PASSWORD";
 const ENV_NUMAFLOW_WATERMARK_DELAY: &str = "NUMAFLOW_WATERMARK_DELAY_IN_MS";
 const ENV_WRITE_CONCURRENCY_SIZE: &str = "WRITE_CONCURRENCY_SIZE";
 const ENV_NUMAFLOW_GRACEFUL_TIMEOUT_SECS: &str = "NUMAFLOW_GRACEFUL_TIMEOUT_SECS";
 const ENV_MAX_ACK_PENDING: &str = "MAX_ACK_PENDING";
+const ENV_NUMAFLOW_WAL_RECOVERY_POLICY: &str = "NUMAFLOW_WAL_RECOVERY_POLICY";
+const ENV_NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS: &str = "NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS";
 const DEFAULT_GRPC_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024; // 64 MB
 const DEFAULT_MAP_SOCKET: &str = "/var/run/numaflow/map.sock";
 pub(crate) const DEFAULT_BATCH_MAP_SOCKET: &str = "/var/run/numaflow/batchmap.sock";
@@ -68,7 +72,7 @@ pub(crate) struct PipelineConfig {
     pub(crate) writer_concurrency: usize,
     pub(crate) read_timeout: Duration,
     pub(crate) graceful_shutdown_time: Duration,
-    pub(crate) js_client_config: isb::jetstream::ClientConfig, // TODO: make it enum, since we can have different ISB implementations
+    pub(crate) isb_client_config: isb::ISBClientConfig,
     pub(crate) from_vertex_config: Vec<FromVertexConfig>,
     pub(crate) to_vertex_config: Vec<ToVertexConfig>,
     pub(crate) vertex_config: VertexConfig,
@@ -97,7 +101,7 @@ impl Default for PipelineConfig {
             writer_concurrency: DEFAULT_BATCH_SIZE as usize,
             read_timeout: Duration::from_millis(DEFAULT_TIMEOUT_IN_MS as u64),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig::default(),
+            isb_client_config: isb::ISBClientConfig::default(),
             from_vertex_config: vec![],
             to_vertex_config: vec![],
             vertex_config: VertexConfig::Source(SourceVtxConfig {
@@ -288,6 +292,7 @@ impl PipelineConfig {
     pub(crate) fn load(
         pipeline_spec_obj: String,
         env_vars: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+        isb_svc_type: isb::ISBSvcType,
     ) -> Result<Self> {
         let env_vars: HashMap<String, String> = env_vars
             .into_iter()
@@ -297,6 +302,8 @@ impl PipelineConfig {
                     ENV_NUMAFLOW_SERVING_JETSTREAM_URL,
                     ENV_NUMAFLOW_SERVING_JETSTREAM_USER,
                     ENV_NUMAFLOW_SERVING_JETSTREAM_PASSWORD,
+                    ENV_NUMAFLOW_ISBSVC_REDIS_URL,
+                    ENV_NUMAFLOW_ISBSVC_REDIS_PASSWORD,
                     ENV_WRITE_CONCURRENCY_SIZE,
                     ENV_CALLBACK_ENABLED,
                     ENV_CALLBACK_CONCURRENCY,
@@ -305,6 +312,8 @@ impl PipelineConfig {
                     ENV_NUMAFLOW_SERVING_RESPONSE_STORE,
                     ENV_NUMAFLOW_GRACEFUL_TIMEOUT_SECS,
                     ENV_MAX_ACK_PENDING,
+                    ENV_NUMAFLOW_WAL_RECOVERY_POLICY,
+                    ENV_NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS,
                 ]
                 .contains(&key.as_str())
             })
@@ -388,6 +397,7 @@ impl PipelineConfig {
                 Some(SinkConfig {
                     sink_type: SinkType::fallback_sinktype(&sink)?,
                     retry_config: None,
+                    dlq_headers: sink.dlq_headers.unwrap_or(false),
                 })
             } else {
                 None
@@ -397,6 +407,7 @@ impl PipelineConfig {
                 Some(SinkConfig {
                     sink_type: SinkType::on_success_sinktype(&sink)?,
                     retry_config: None,
+                    dlq_headers: false,
                 })
             } else {
                 None
@@ -442,6 +453,7 @@ impl PipelineConfig {
                     sink_config: SinkConfig {
                         sink_type: SinkType::primary_sinktype(&sink)?,
                         retry_config: sink.retry_strategy.clone().map(|retry| retry.into()),
+                        dlq_headers: false,
                     },
                     fb_sink_config,
                     on_success_sink_config,
@@ -452,11 +464,32 @@ impl PipelineConfig {
         } else if let Some(udf) = vertex_obj.spec.udf {
             if let Some(group_by) = &udf.group_by {
                 // This is a reduce vertex
+                let recovery_policy = env_vars
+                    .get(ENV_NUMAFLOW_WAL_RECOVERY_POLICY)
+                    .map(|policy| WalRecoveryPolicy::try_from(policy.as_str()))
+                    .transpose()?
+                    .unwrap_or_default();
+                let snapshot_interval_secs = env_vars
+                    .get(ENV_NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS)
+                    .map(|interval| {
+                        interval.parse::<u64>().map_err(|e| {
+                            Error::Config(format!(
+                                "Invalid {ENV_NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS}: {e}"
+                            ))
+                        })
+                    })
+                    .transpose()?;
                 let storage_config = group_by.storage.as_ref().and_then(|storage| {
                     if storage.no_store.is_some() {
                         None
                     } else {
-                        Some(Default::default())
+                        let default = StorageConfig::default();
+                        Some(StorageConfig {
+                            recovery_policy,
+                            snapshot_interval_secs: snapshot_interval_secs
+                                .unwrap_or(default.snapshot_interval_secs),
+                            ..default
+                        })
                     }
                 });
 
@@ -501,10 +534,20 @@ impl PipelineConfig {
                 })
         };
 
-        let js_client_config = isb::jetstream::ClientConfig {
-            url: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_URL)?,
-            user: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_USER).ok(),
-            password: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_PASSWORD).ok(),
+        // the controller sets the connection settings of the ISB service as env vars.
+        let isb_client_config = match isb_svc_type {
+            isb::ISBSvcType::Redis => isb::ISBClientConfig::Redis(isb::redis::ClientConfig {
+                url: get_var(ENV_NUMAFLOW_ISBSVC_REDIS_URL)?,
+                password: get_var(ENV_NUMAFLOW_ISBSVC_REDIS_PASSWORD).ok(),
+                ..Default::default()
+            }),
+            isb::ISBSvcType::JetStream => {
+                isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
+                    url: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_URL)?,
+                    user: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_USER).ok(),
+                    password: get_var(ENV_NUMAFLOW_SERVING_JETSTREAM_PASSWORD).ok(),
+                })
+            }
         };
 
         let max_ack_pending: usize = get_var(ENV_MAX_ACK_PENDING)
@@ -572,6 +615,27 @@ impl PipelineConfig {
             });
         }
 
+        let isb_config: Option<isb::ISBConfig> = match vertex_obj.spec.inter_step_buffer.as_ref() {
+            None => None,
+            Some(isb_spec) => {
+                let compression = match isb_spec.compression.clone() {
+                    None => isb::Compression::default(),
+                    Some(compression) => isb::Compression::try_from(*compression)
+                        .map_err(|e| Error::Config(format!("Invalid compression setting: {e}")))?,
+                };
+                let batching = isb_spec
+                    .batching
+                    .clone()
+                    .map(|batching| isb::Batching::try_from(*batching))
+                    .transpose()
+                    .map_err(|e| Error::Config(format!("Invalid batching setting: {e}")))?;
+                Some(isb::ISBConfig {
+                    compression,
+                    batching,
+                })
+            }
+        };
+
         let mut to_vertex_config = vec![];
         for edge in to_edges {
             let partition_count = edge.to_vertex_partition_count.unwrap_or_default() as u16;
@@ -610,6 +674,7 @@ impl PipelineConfig {
                         .on_full
                         .and_then(|s| s.clone().try_into().ok())
                         .unwrap_or(default_writer_config.buffer_full_strategy),
+                    batching: isb_config.as_ref().and_then(|c| c.batching.clone()),
                 },
                 conditions: edge.conditions,
                 to_vertex_type: VertexType::from_str(&edge.to_vertex_type)?,
@@ -642,7 +707,7 @@ impl PipelineConfig {
                 &to_vertex_config,
                 delay_in_millis,
                 ordered_processing_enabled,
-            )
+            )?
         } else {
             None
         };
@@ -677,30 +742,13 @@ impl PipelineConfig {
             });
         }
 
-        let isb_config: Option<isb::ISBConfig> = match vertex_obj.spec.inter_step_buffer.as_ref() {
-            None => None,
-            Some(isb_spec) => {
-                let compress_type = match isb_spec.compression.as_ref() {
-                    None => isb::CompressionType::None,
-                    Some(t) => match &t.r#type {
-                        None => isb::CompressionType::None,
-                        Some(t) => match t.as_str() {
-                            "gzip" => isb::CompressionType::Gzip,
-                            "zstd" => isb::CompressionType::Zstd,
-                            "lz4" => isb::CompressionType::LZ4,
-                            _ => {
-                                return Err(Error::Config(format!(
-                                    "Invalid compression type setting: {t}"
-                                )));
-                            }
-                        },
-                    },
-                };
-                Some(isb::ISBConfig {
-                    compression: isb::Compression { compress_type },
-                })
-            }
-        };
+        // the callback store is a JetStream KV bucket
+        if callback_config.is_some() && matches!(isb_client_config, isb::ISBClientConfig::Redis(_))
+        {
+            return Err(Error::Config(
+                "Serving is not supported with the Redis ISB".to_string(),
+            ));
+        }
 
         let graceful_shutdown_time_secs = env_vars
             .get(ENV_NUMAFLOW_GRACEFUL_TIMEOUT_SECS)
@@ -723,7 +771,7 @@ impl PipelineConfig {
             pipeline_name: Box::leak(pipeline_name.into_boxed_str()),
             vertex_name: Box::leak(vertex_name.into_boxed_str()),
             replica: *replica,
-            js_client_config,
+            isb_client_config,
             from_vertex_config,
             to_vertex_config,
             vertex_type,
@@ -762,7 +810,7 @@ mod tests {
             writer_concurrency: DEFAULT_BATCH_SIZE as usize,
             read_timeout: Duration::from_millis(DEFAULT_TIMEOUT_IN_MS as u64),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig::default(),
+            isb_client_config: isb::ISBClientConfig::default(),
             from_vertex_config: vec![],
             to_vertex_config: vec![],
             vertex_type: VertexType::Source,
@@ -794,6 +842,7 @@ mod tests {
             sink_config: SinkConfig {
                 sink_type: SinkType::Log(LogConfig {}),
                 retry_config: None,
+                dlq_headers: false,
             },
             fb_sink_config: None,
             on_success_sink_config: None,
@@ -836,7 +885,9 @@ mod tests {
             ("NUMAFLOW_SERVING_CALLBACK_STORE", "test-kv-store"),
             ("NUMAFLOW_SERVING_RESPONSE_STORE", "test-kv-store"),
         ];
-        let pipeline_config = PipelineConfig::load(pipeline_cfg_base64, env_vars).unwrap();
+        let pipeline_config =
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .unwrap();
 
         let expected = PipelineConfig {
             pipeline_name: "simple-pipeline",
@@ -846,11 +897,11 @@ mod tests {
             writer_concurrency: 500,
             read_timeout: Duration::from_secs(1),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             from_vertex_config: vec![FromVertexConfig {
                 name: "in",
                 reader_config: BufferReaderConfig {
@@ -865,6 +916,7 @@ mod tests {
                 sink_config: SinkConfig {
                     sink_type: SinkType::Blackhole(BlackholeConfig {}),
                     retry_config: Some(RetryConfig::default()),
+                    dlq_headers: false,
                 },
                 fb_sink_config: None,
                 on_success_sink_config: None,
@@ -977,6 +1029,7 @@ mod tests {
         let pipeline_config = PipelineConfig::load(
             pipeline_cfg_base64.to_string(),
             vec![("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")],
+            isb::ISBSvcType::JetStream,
         )
         .unwrap();
 
@@ -1007,8 +1060,12 @@ mod tests {
         let pipeline_cfg_base64 = "eyJtZXRhZGF0YSI6eyJuYW1lIjoic2ltcGxlLXBpcGVsaW5lLWluIiwibmFtZXNwYWNlIjoiZGVmYXVsdCIsImNyZWF0aW9uVGltZXN0YW1wIjpudWxsfSwic3BlYyI6eyJuYW1lIjoiaW4iLCJzb3VyY2UiOnsiZ2VuZXJhdG9yIjp7InJwdSI6MTAwMDAwLCJkdXJhdGlvbiI6IjFzIiwibXNnU2l6ZSI6OCwiaml0dGVyIjoiMHMifX0sImNvbnRhaW5lclRlbXBsYXRlIjp7InJlc291cmNlcyI6e30sImVudiI6W3sibmFtZSI6IlBBRl9CQVRDSF9TSVpFIiwidmFsdWUiOiIxMDAwMDAifV19LCJsaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6MTAwMCwicmVhZFRpbWVvdXQiOiIxcyIsImJ1ZmZlck1heExlbmd0aCI6MTUwMDAwLCJidWZmZXJVc2FnZUxpbWl0Ijo4NX0sInNjYWxlIjp7Im1pbiI6MX0sInVwZGF0ZVN0cmF0ZWd5Ijp7InR5cGUiOiJSb2xsaW5nVXBkYXRlIiwicm9sbGluZ1VwZGF0ZSI6eyJtYXhVbmF2YWlsYWJsZSI6IjI1JSJ9fSwicGlwZWxpbmVOYW1lIjoic2ltcGxlLXBpcGVsaW5lIiwiaW50ZXJTdGVwQnVmZmVyU2VydmljZU5hbWUiOiIiLCJyZXBsaWNhcyI6MCwidG9FZGdlcyI6W3siZnJvbSI6ImluIiwidG8iOiJvdXQiLCJjb25kaXRpb25zIjpudWxsLCJmcm9tVmVydGV4VHlwZSI6IlNvdXJjZSIsImZyb21WZXJ0ZXhQYXJ0aXRpb25Db3VudCI6MSwiZnJvbVZlcnRleExpbWl0cyI6eyJyZWFkQmF0Y2hTaXplIjoxMDAwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjoxNTAwMDAsImJ1ZmZlclVzYWdlTGltaXQiOjg1fSwidG9WZXJ0ZXhUeXBlIjoiU2luayIsInRvVmVydGV4UGFydGl0aW9uQ291bnQiOjEsInRvVmVydGV4TGltaXRzIjp7InJlYWRCYXRjaFNpemUiOjEwMDAsInJlYWRUaW1lb3V0IjoiMXMiLCJidWZmZXJNYXhMZW5ndGgiOjE1MDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODV9fV0sIndhdGVybWFyayI6eyJkaXNhYmxlZCI6dHJ1ZSwibWF4RGVsYXkiOiIwcyJ9fSwic3RhdHVzIjp7InBoYXNlIjoiIiwicmVwbGljYXMiOjAsImRlc2lyZWRSZXBsaWNhcyI6MCwibGFzdFNjYWxlZEF0IjpudWxsfX0=";
 
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let pipeline_config =
-            PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::JetStream,
+        )
+        .unwrap();
 
         let expected = PipelineConfig {
             pipeline_name: "simple-pipeline",
@@ -1018,11 +1075,11 @@ mod tests {
             writer_concurrency: 1000,
             read_timeout: Duration::from_secs(1),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             from_vertex_config: vec![],
             to_vertex_config: vec![ToVertexConfig {
                 name: "out",
@@ -1065,8 +1122,12 @@ mod tests {
         let pipeline_cfg_base64 = "eyJtZXRhZGF0YSI6eyJuYW1lIjoic2ltcGxlLXBpcGVsaW5lLWluIiwibmFtZXNwYWNlIjoiZGVmYXVsdCIsImNyZWF0aW9uVGltZXN0YW1wIjpudWxsfSwic3BlYyI6eyJuYW1lIjoiaW4iLCJzb3VyY2UiOnsicHVsc2FyIjp7InNlcnZlckFkZHIiOiJwdWxzYXI6Ly9wdWxzYXItc2VydmljZTo2NjUwIiwidG9waWMiOiJ0ZXN0X3BlcnNpc3RlbnQiLCJjb25zdW1lck5hbWUiOiJteV9wZXJzaXN0ZW50X2NvbnN1bWVyIiwic3Vic2NyaXB0aW9uTmFtZSI6Im15X3BlcnNpc3RlbnRfc3Vic2NyaXB0aW9uIn19LCJsaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAsInJlYWRUaW1lb3V0IjoiMXMiLCJidWZmZXJNYXhMZW5ndGgiOjMwMDAwLCJidWZmZXJVc2FnZUxpbWl0Ijo4MH0sInNjYWxlIjp7Im1pbiI6MSwibWF4IjoxfSwidXBkYXRlU3RyYXRlZ3kiOnsidHlwZSI6IlJvbGxpbmdVcGRhdGUiLCJyb2xsaW5nVXBkYXRlIjp7Im1heFVuYXZhaWxhYmxlIjoiMjUlIn19LCJwaXBlbGluZU5hbWUiOiJzaW1wbGUtcGlwZWxpbmUiLCJpbnRlclN0ZXBCdWZmZXJTZXJ2aWNlTmFtZSI6IiIsInJlcGxpY2FzIjowLCJ0b0VkZ2VzIjpbeyJmcm9tIjoiaW4iLCJ0byI6Im91dCIsImNvbmRpdGlvbnMiOm51bGwsImZyb21WZXJ0ZXhUeXBlIjoiU291cmNlIiwiZnJvbVZlcnRleFBhcnRpdGlvbkNvdW50IjoxLCJmcm9tVmVydGV4TGltaXRzIjp7InJlYWRCYXRjaFNpemUiOjUwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjozMDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODB9LCJ0b1ZlcnRleFR5cGUiOiJTaW5rIiwidG9WZXJ0ZXhQYXJ0aXRpb25Db3VudCI6MSwidG9WZXJ0ZXhMaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAsInJlYWRUaW1lb3V0IjoiMXMiLCJidWZmZXJNYXhMZW5ndGgiOjMwMDAwLCJidWZmZXJVc2FnZUxpbWl0Ijo4MH19XSwid2F0ZXJtYXJrIjp7Im1heERlbGF5IjoiMHMifX0sInN0YXR1cyI6eyJwaGFzZSI6IiIsInJlcGxpY2FzIjowLCJkZXNpcmVkUmVwbGljYXMiOjAsImxhc3RTY2FsZWRBdCI6bnVsbH19";
 
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let pipeline_config =
-            PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::JetStream,
+        )
+        .unwrap();
 
         let expected = PipelineConfig {
             pipeline_name: "simple-pipeline",
@@ -1076,11 +1137,11 @@ mod tests {
             writer_concurrency: 50,
             read_timeout: Duration::from_secs(1),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             from_vertex_config: vec![],
             to_vertex_config: vec![ToVertexConfig {
                 name: "out",
@@ -1112,6 +1173,7 @@ mod tests {
             metrics_config: Default::default(),
             watermark_config: Some(WatermarkConfig::Source(SourceWatermarkConfig {
                 max_delay: Default::default(),
+                strategy: Default::default(),
                 source_bucket_config: BucketConfig {
                     vertex: "in",
                     partitions: vec![0],
@@ -1174,8 +1236,12 @@ mod tests {
         let pipeline_cfg_base64 = "eyJtZXRhZGF0YSI6eyJuYW1lIjoic2ltcGxlLXBpcGVsaW5lLW1hcCIsIm5hbWVzcGFjZSI6ImRlZmF1bHQiLCJjcmVhdGlvblRpbWVzdGFtcCI6bnVsbH0sInNwZWMiOnsibmFtZSI6Im1hcCIsInVkZiI6eyJjb250YWluZXIiOnsidGVtcGxhdGUiOiJkZWZhdWx0In19LCJsaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjozMDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODB9LCJzY2FsZSI6eyJtaW4iOjF9LCJwaXBlbGluZU5hbWUiOiJzaW1wbGUtcGlwZWxpbmUiLCJpbnRlclN0ZXBCdWZmZXJTZXJ2aWNlTmFtZSI6IiIsInJlcGxpY2FzIjowLCJmcm9tRWRnZXMiOlt7ImZyb20iOiJpbiIsInRvIjoibWFwIiwiY29uZGl0aW9ucyI6bnVsbCwiZnJvbVZlcnRleFR5cGUiOiJTb3VyY2UiLCJmcm9tVmVydGV4UGFydGl0aW9uQ291bnQiOjEsImZyb21WZXJ0ZXhMaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjozMDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODB9LCJ0b1ZlcnRleFR5cGUiOiJNYXAiLCJ0b1ZlcnRleFBhcnRpdGlvbkNvdW50IjoxLCJ0b1ZlcnRleExpbWl0cyI6eyJyZWFkQmF0Y2hTaXplIjo1MDAsInJlYWRUaW1lb3V0IjoiMXMiLCJidWZmZXJNYXhMZW5ndGgiOjMwMDAwLCJidWZmZXJVc2FnZUxpbWl0Ijo4MH19XSwid2F0ZXJtYXJrIjp7Im1heERlbGF5IjoiMHMifX0sInN0YXR1cyI6eyJwaGFzZSI6IiIsInJlcGxpY2FzIjowLCJkZXNpcmVkUmVwbGljYXMiOjAsImxhc3RTY2FsZWRBdCI6bnVsbH19";
 
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let pipeline_config =
-            PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::JetStream,
+        )
+        .unwrap();
 
         let expected = PipelineConfig {
             pipeline_name: "simple-pipeline",
@@ -1185,11 +1251,11 @@ mod tests {
             writer_concurrency: 500,
             read_timeout: Duration::from_secs(1),
             graceful_shutdown_time: Duration::from_secs(DEFAULT_GRACEFUL_SHUTDOWN_TIME_SECS),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             from_vertex_config: vec![FromVertexConfig {
                 name: "in",
                 reader_config: BufferReaderConfig {
@@ -1235,8 +1301,12 @@ mod tests {
             ("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222"),
             ("NUMAFLOW_GRACEFUL_TIMEOUT_SECS", "30"),
         ];
-        let pipeline_config =
-            PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::JetStream,
+        )
+        .unwrap();
 
         assert_eq!(
             pipeline_config.graceful_shutdown_time,
@@ -1245,8 +1315,12 @@ mod tests {
 
         // Test with default graceful timeout (no env var)
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let pipeline_config =
-            PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::JetStream,
+        )
+        .unwrap();
 
         assert_eq!(
             pipeline_config.graceful_shutdown_time,
@@ -1254,6 +1328,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redis_isb_client_config() {
+        let pipeline_cfg_base64 = "eyJtZXRhZGF0YSI6eyJuYW1lIjoic2ltcGxlLXBpcGVsaW5lLW1hcCIsIm5hbWVzcGFjZSI6ImRlZmF1bHQiLCJjcmVhdGlvblRpbWVzdGFtcCI6bnVsbH0sInNwZWMiOnsibmFtZSI6Im1hcCIsInVkZiI6eyJjb250YWluZXIiOnsidGVtcGxhdGUiOiJkZWZhdWx0In19LCJsaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjozMDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODB9LCJzY2FsZSI6eyJtaW4iOjF9LCJwaXBlbGluZU5hbWUiOiJzaW1wbGUtcGlwZWxpbmUiLCJpbnRlclN0ZXBCdWZmZXJTZXJ2aWNlTmFtZSI6IiIsInJlcGxpY2FzIjowLCJmcm9tRWRnZXMiOlt7ImZyb20iOiJpbiIsInRvIjoibWFwIiwiY29uZGl0aW9ucyI6bnVsbCwiZnJvbVZlcnRleFR5cGUiOiJTb3VyY2UiLCJmcm9tVmVydGV4UGFydGl0aW9uQ291bnQiOjEsImZyb21WZXJ0ZXhMaW1pdHMiOnsicmVhZEJhdGNoU2l6ZSI6NTAwLCJyZWFkVGltZW91dCI6IjFzIiwiYnVmZmVyTWF4TGVuZ3RoIjozMDAwMCwiYnVmZmVyVXNhZ2VMaW1pdCI6ODB9LCJ0b1ZlcnRleFR5cGUiOiJNYXAiLCJ0b1ZlcnRleFBhcnRpdGlvbkNvdW50IjoxLCJ0b1ZlcnRleExpbWl0cyI6eyJyZWFkQmF0Y2hTaXplIjo1MDAsInJlYWRUaW1lb3V0IjoiMXMiLCJidWZmZXJNYXhMZW5ndGgiOjMwMDAwLCJidWZmZXJVc2FnZUxpbWl0Ijo4MH19XSwid2F0ZXJtYXJrIjp7Im1heERlbGF5IjoiMHMifX0sInN0YXR1cyI6eyJwaGFzZSI6IiIsInJlcGxpY2FzIjowLCJkZXNpcmVkUmVwbGljYXMiOjAsImxhc3RTY2FsZWRBdCI6bnVsbH19";
+
+        let env_vars = [
+            ("NUMAFLOW_ISBSVC_REDIS_URL", "redis://redis:6379"),
+            ("NUMAFLOW_ISBSVC_REDIS_PASSWORD", "secret"),
+        ];
+        let pipeline_config = PipelineConfig::load(
+            pipeline_cfg_base64.to_string(),
+            env_vars,
+            isb::ISBSvcType::Redis,
+        )
+        .unwrap();
+
+        assert_eq!(
+            pipeline_config.isb_client_config,
+            isb::ISBClientConfig::Redis(isb::redis::ClientConfig {
+                url: "redis://redis:6379".to_string(),
+                password: Some("secret".to_string()),
+                ..Default::default()
+            })
+        );
+
+        // the Redis settings are required for the Redis ISB service
+        assert!(
+            PipelineConfig::load(
+                pipeline_cfg_base64.to_string(),
+                [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")],
+                isb::ISBSvcType::Redis,
+            )
+            .is_err()
+        );
+        assert_eq!(
+            isb::ISBSvcType::try_from("redis").unwrap(),
+            isb::ISBSvcType::Redis
+        );
+        assert!(isb::ISBSvcType::try_from("kafka").is_err());
+    }
+
+    #[test]
+    fn test_wal_recovery_policy_env_var() {
+        let pipeline_cfg = r#"{
+            "metadata": {"name": "test-reduce", "namespace": "default", "creationTimestamp": null},
+            "spec": {
+                "name": "reduce-vertex",
+                "udf": {
+                    "container": {"image": "test-image"},
+                    "groupBy": {
+                        "window": {"fixed": {"length": "60s"}},
+                        "keyed": true,
+                        "storage": {"emptyDir": {}}
+                    }
+                },
+                "pipelineName": "test-pipeline",
+                "interStepBufferServiceName": "",
+                "replicas": 0,
+                "fromEdges": [{
+                    "from": "in", "to": "reduce-vertex",
+                    "fromVertexType": "Source", "fromVertexPartitionCount": 1,
+                    "toVertexType": "ReduceUDF", "toVertexPartitionCount": 1
+                }],
+                "toEdges": []
+            },
+            "status": {"phase": "", "replicas": 0, "desiredReplicas": 0, "lastScaledAt": null}
+        }"#;
+        let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
+
+        let recovery_policy = |env_vars: Vec<(&str, &str)>| {
+            let pipeline_config = PipelineConfig::load(
+                pipeline_cfg_base64.clone(),
+                env_vars,
+                isb::ISBSvcType::JetStream,
+            )
+            .unwrap();
+            let VertexConfig::Reduce(reduce_config) = pipeline_config.vertex_config else {
+                panic!("expected a reduce vertex");
+            };
+            reduce_config.wal_storage_config.unwrap().recovery_policy
+        };
+
+        assert_eq!(
+            recovery_policy(vec![("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")]),
+            WalRecoveryPolicy::Truncate
+        );
+        assert_eq!(
+            recovery_policy(vec![
+                ("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222"),
+                ("NUMAFLOW_WAL_RECOVERY_POLICY", "fail"),
+            ]),
+            WalRecoveryPolicy::Fail
+        );
+
+        let env_vars = [
+            ("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222"),
+            ("NUMAFLOW_WAL_RECOVERY_POLICY", "skip"),
+        ];
+        assert!(
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_wal_snapshot_interval_env_var() {
+        let pipeline_cfg = r#"{
+            "metadata": {"name": "test-reduce", "namespace": "default", "creationTimestamp": null},
+            "spec": {
+                "name": "reduce-vertex",
+                "udf": {
+                    "container": {"image": "test-image"},
+                    "groupBy": {
+                        "window": {"session": {"timeout": "60s"}},
+                        "keyed": true,
+                        "storage": {"emptyDir": {}}
+                    }
+                },
+                "pipelineName": "test-pipeline",
+                "interStepBufferServiceName": "",
+                "replicas": 0,
+                "fromEdges": [{
+                    "from": "in", "to": "reduce-vertex",
+                    "fromVertexType": "Source", "fromVertexPartitionCount": 1,
+                    "toVertexType": "ReduceUDF", "toVertexPartitionCount": 1
+                }],
+                "toEdges": []
+            },
+            "status": {"phase": "", "replicas": 0, "desiredReplicas": 0, "lastScaledAt": null}
+        }"#;
+        let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
+
+        let snapshot_interval = |env_vars: Vec<(&str, &str)>| {
+            let pipeline_config = PipelineConfig::load(
+                pipeline_cfg_base64.clone(),
+                env_vars,
+                isb::ISBSvcType::JetStream,
+            )
+            .unwrap();
+            let VertexConfig::Reduce(reduce_config) = pipeline_config.vertex_config else {
+                panic!("expected a reduce vertex");
+            };
+            reduce_config
+                .wal_storage_config
+                .unwrap()
+                .snapshot_interval_secs
+        };
+
+        assert_eq!(
+            snapshot_interval(vec![("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")]),
+            30
+        );
+        assert_eq!(
+            snapshot_interval(vec![
+                ("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222"),
+                ("NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS", "0"),
+            ]),
+            0
+        );
+
+        let env_vars = [
+            ("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222"),
+            ("NUMAFLOW_WAL_SNAPSHOT_INTERVAL_SECS", "soon"),
+        ];
+        assert!(
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_ordered_processing_always_disabled_for_source_and_reduce() {
         // Test that Source and Reduce vertices always have ordered_processing_enabled = false
@@ -1310,8 +1553,12 @@ mod tests {
         for (name, pipeline_cfg, expected_vertex_type) in test_cases {
             let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
             let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-            let pipeline_config =
-                PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+            let pipeline_config = PipelineConfig::load(
+                pipeline_cfg_base64.to_string(),
+                env_vars,
+                isb::ISBSvcType::JetStream,
+            )
+            .unwrap();
 
             assert!(
                 !pipeline_config.ordered_processing_enabled,
@@ -1368,8 +1615,12 @@ mod tests {
 
             let pipeline_cfg_base64 = BASE64_STANDARD.encode(&pipeline_cfg);
             let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-            let pipeline_config =
-                PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+            let pipeline_config = PipelineConfig::load(
+                pipeline_cfg_base64.to_string(),
+                env_vars,
+                isb::ISBSvcType::JetStream,
+            )
+            .unwrap();
 
             assert_eq!(
                 pipeline_config.ordered_processing_enabled, expected_enabled,
@@ -1429,8 +1680,12 @@ mod tests {
 
             let pipeline_cfg_base64 = BASE64_STANDARD.encode(&pipeline_cfg);
             let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-            let pipeline_config =
-                PipelineConfig::load(pipeline_cfg_base64.to_string(), env_vars).unwrap();
+            let pipeline_config = PipelineConfig::load(
+                pipeline_cfg_base64.to_string(),
+                env_vars,
+                isb::ISBSvcType::JetStream,
+            )
+            .unwrap();
 
             assert_eq!(
                 pipeline_config.ordered_processing_enabled, expected_enabled,
@@ -1487,7 +1742,9 @@ mod tests {
 
         let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let config = PipelineConfig::load(pipeline_cfg_base64, env_vars).unwrap();
+        let config =
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .unwrap();
 
         assert!(config.ordered_processing_enabled);
         assert_eq!(config.from_vertex_config.len(), 1);
@@ -1533,7 +1790,9 @@ mod tests {
 
         let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let config = PipelineConfig::load(pipeline_cfg_base64, env_vars).unwrap();
+        let config =
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .unwrap();
 
         assert!(!config.ordered_processing_enabled);
         assert_eq!(config.from_vertex_config.len(), 1);
@@ -1582,7 +1841,9 @@ mod tests {
 
         let pipeline_cfg_base64 = BASE64_STANDARD.encode(pipeline_cfg);
         let env_vars = [("NUMAFLOW_ISBSVC_JETSTREAM_URL", "localhost:4222")];
-        let config = PipelineConfig::load(pipeline_cfg_base64, env_vars).unwrap();
+        let config =
+            PipelineConfig::load(pipeline_cfg_base64, env_vars, isb::ISBSvcType::JetStream)
+                .unwrap();
 
         assert!(config.ordered_processing_enabled);
         assert_eq!(config.to_vertex_config.len(), 1);
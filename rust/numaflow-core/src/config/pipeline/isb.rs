@@ -9,6 +9,11 @@ const DEFAULT_MAX_LENGTH: usize = 30000;
 const DEFAULT_USAGE_LIMIT: f64 = 0.8;
 const DEFAULT_BUFFER_FULL_STRATEGY: BufferFullStrategy = BufferFullStrategy::RetryUntilSuccess;
 const DEFAULT_WIP_ACK_INTERVAL_MILLIS: u64 = 1000;
+const DEFAULT_BATCH_MAX_MESSAGES: usize = 100;
+const DEFAULT_BATCH_LINGER_MILLIS: u64 = 5;
+/// Upper bound of the batch size, since the index of a message within its batch is packed into
+/// the lower bits of its offset.
+pub(crate) const MAX_BATCH_MESSAGES: usize = 1 << 16;
 
 pub(crate) mod jetstream {
     const DEFAULT_URL: &str = "localhost:4222";
@@ -40,6 +45,83 @@ pub(crate) mod jetstream {
     }
 }
 
+/// Redis Streams ISB related configurations.
+pub(crate) mod redis {
+    use std::time::Duration;
+
+    const DEFAULT_URL: &str = "redis://localhost:6379";
+    const DEFAULT_ACK_WAIT: Duration = Duration::from_secs(60);
+    const DEFAULT_DUPLICATE_WINDOW: Duration = Duration::from_secs(120);
+
+    #[derive(Clone, PartialEq)]
+    pub(crate) struct ClientConfig {
+        pub url: String,
+        pub password: Option<String>,
+        /// How long a delivered message can stay unacknowledged before it is redelivered.
+        pub ack_wait: Duration,
+        /// How long the message ids are remembered for deduplicating the writes.
+        pub duplicate_window: Duration,
+    }
+
+    impl std::fmt::Debug for ClientConfig {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ClientConfig")
+                .field("url", &self.url)
+                .field("password", &self.password.as_ref().map(|_| "[REDACTED]"))
+                .field("ack_wait", &self.ack_wait)
+                .field("duplicate_window", &self.duplicate_window)
+                .finish()
+        }
+    }
+
+    impl Default for ClientConfig {
+        fn default() -> Self {
+            ClientConfig {
+                url: DEFAULT_URL.to_string(),
+                password: None,
+                ack_wait: DEFAULT_ACK_WAIT,
+                duplicate_window: DEFAULT_DUPLICATE_WINDOW,
+            }
+        }
+    }
+}
+
+/// Type of the ISB service the pipeline runs on, passed by the controller with `--isbsvc-type`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum ISBSvcType {
+    #[default]
+    JetStream,
+    Redis,
+}
+
+impl TryFrom<&str> for ISBSvcType {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            // the controller always sets it, empty is kept for running the binary by hand
+            "jetstream" | "" => Ok(ISBSvcType::JetStream),
+            "redis" => Ok(ISBSvcType::Redis),
+            _ => Err(crate::Error::Config(format!(
+                "Unsupported ISB service type: {value}"
+            ))),
+        }
+    }
+}
+
+/// Client configuration of the ISB service the pipeline runs on.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ISBClientConfig {
+    JetStream(jetstream::ClientConfig),
+    Redis(redis::ClientConfig),
+}
+
+impl Default for ISBClientConfig {
+    fn default() -> Self {
+        ISBClientConfig::JetStream(jetstream::ClientConfig::default())
+    }
+}
+
 /// Stream is a one of the partition of the ISB between two vertices.
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Stream {
@@ -80,6 +162,8 @@ pub(crate) struct BufferWriterConfig {
     pub(crate) max_length: usize,
     pub(crate) usage_limit: f64,
     pub(crate) buffer_full_strategy: BufferFullStrategy,
+    /// Packs multiple messages into a single ISB message, disabled if None.
+    pub(crate) batching: Option<Batching>,
 }
 
 impl Default for BufferWriterConfig {
@@ -89,6 +173,7 @@ impl Default for BufferWriterConfig {
             max_length: DEFAULT_MAX_LENGTH,
             usage_limit: DEFAULT_USAGE_LIMIT,
             buffer_full_strategy: DEFAULT_BUFFER_FULL_STRATEGY,
+            batching: None,
         }
     }
 }
@@ -154,6 +239,31 @@ mod jetstream_client_config {
     }
 }
 
+#[cfg(test)]
+mod redis_client_config {
+    use super::redis::*;
+
+    #[test]
+    fn test_default_client_config() {
+        let config = ClientConfig::default();
+        assert_eq!(config.url, "redis://localhost:6379");
+        assert_eq!(config.password, None);
+        assert_eq!(config.ack_wait, std::time::Duration::from_secs(60));
+        assert_eq!(config.duplicate_window, std::time::Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_client_config_debug_redacts_password() {
+        let config = ClientConfig {
+            password: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let debug = format!("{config:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("secret"));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +275,7 @@ mod tests {
             max_length: DEFAULT_MAX_LENGTH,
             usage_limit: DEFAULT_USAGE_LIMIT,
             buffer_full_strategy: DEFAULT_BUFFER_FULL_STRATEGY,
+            batching: None,
         };
         let config = BufferWriterConfig::default();
 
@@ -208,11 +319,68 @@ mod tests {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ISBConfig {
     pub(crate) compression: Compression,
+    /// Packs multiple messages into a single ISB message, disabled if None.
+    pub(crate) batching: Option<Batching>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Batching {
+    /// Maximum number of messages packed into a single ISB message.
+    pub(crate) max_messages: usize,
+    /// Maximum time a message waits for the batch to fill up.
+    pub(crate) linger: Duration,
+}
+
+impl Default for Batching {
+    fn default() -> Self {
+        Batching {
+            max_messages: DEFAULT_BATCH_MAX_MESSAGES,
+            linger: Duration::from_millis(DEFAULT_BATCH_LINGER_MILLIS),
+        }
+    }
+}
+
+impl TryFrom<numaflow_models::models::IsbBatching> for Batching {
+    type Error = String;
+    fn try_from(value: numaflow_models::models::IsbBatching) -> Result<Self, Self::Error> {
+        let max_messages = match value.max_messages {
+            None => DEFAULT_BATCH_MAX_MESSAGES,
+            Some(n) if n > 0 && n as usize <= MAX_BATCH_MESSAGES => n as usize,
+            Some(n) => {
+                return Err(format!(
+                    "Invalid batching maxMessages: {n}, should be between 1 and {}",
+                    MAX_BATCH_MESSAGES
+                ));
+            }
+        };
+
+        Ok(Batching {
+            max_messages,
+            linger: value
+                .linger
+                .map(Duration::from)
+                .unwrap_or(Duration::from_millis(DEFAULT_BATCH_LINGER_MILLIS)),
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Compression {
     pub(crate) compress_type: CompressionType,
+    /// Messages smaller than this many bytes are written uncompressed (adaptive compression).
+    pub(crate) min_size: usize,
+    /// KV bucket holding the trained zstd dictionaries, keyed by their version.
+    pub(crate) dictionary_bucket: Option<&'static str>,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression {
+            compress_type: CompressionType::None,
+            min_size: 0,
+            dictionary_bucket: None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -226,25 +394,98 @@ pub(crate) enum CompressionType {
 impl TryFrom<numaflow_models::models::Compression> for Compression {
     type Error = String;
     fn try_from(value: numaflow_models::models::Compression) -> Result<Self, Self::Error> {
-        match value.r#type {
-            None => Ok(Compression {
-                compress_type: CompressionType::None,
-            }),
-            Some(t) => match t.as_str() {
-                "gzip" => Ok(Compression {
-                    compress_type: CompressionType::Gzip,
-                }),
-                "zstd" => Ok(Compression {
-                    compress_type: CompressionType::Zstd,
-                }),
-                "lz4" => Ok(Compression {
-                    compress_type: CompressionType::LZ4,
-                }),
-                "none" => Ok(Compression {
-                    compress_type: CompressionType::None,
-                }),
-                _ => Err(format!("Invalid compression type: {t}")),
-            },
-        }
+        let compress_type = match value.r#type.as_deref() {
+            None | Some("none") => CompressionType::None,
+            Some("gzip") => CompressionType::Gzip,
+            Some("zstd") => CompressionType::Zstd,
+            Some("lz4") => CompressionType::LZ4,
+            Some(t) => return Err(format!("Invalid compression type: {t}")),
+        };
+
+        let min_size = match value.min_size {
+            None => 0,
+            Some(size) => {
+                usize::try_from(size).map_err(|_| format!("Invalid compression minSize: {size}"))?
+            }
+        };
+
+        let dictionary_bucket = match value.dictionary_bucket {
+            None => None,
+            Some(_) if compress_type != CompressionType::Zstd => {
+                return Err("Compression dictionaryBucket is only supported with zstd".to_string());
+            }
+            Some(bucket) => Some(bucket.leak() as &'static str),
+        };
+
+        Ok(Compression {
+            compress_type,
+            min_size,
+            dictionary_bucket,
+        })
+    }
+}
+
+#[cfg(test)]
+mod compression_tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_try_from() {
+        let mut model = numaflow_models::models::Compression::new();
+        assert_eq!(
+            Compression::try_from(model.clone()),
+            Ok(Compression::default())
+        );
+
+        model.r#type = Some("zstd".to_string());
+        model.min_size = Some(256);
+        model.dictionary_bucket = Some("zstd-dictionaries".to_string());
+        assert_eq!(
+            Compression::try_from(model.clone()),
+            Ok(Compression {
+                compress_type: CompressionType::Zstd,
+                min_size: 256,
+                dictionary_bucket: Some("zstd-dictionaries"),
+            })
+        );
+
+        // dictionaries are only supported with zstd
+        model.r#type = Some("gzip".to_string());
+        assert!(Compression::try_from(model.clone()).is_err());
+
+        model.dictionary_bucket = None;
+        model.min_size = Some(-1);
+        assert!(Compression::try_from(model.clone()).is_err());
+
+        model.min_size = None;
+        model.r#type = Some("brotli".to_string());
+        assert!(Compression::try_from(model).is_err());
+    }
+}
+
+#[cfg(test)]
+mod batching_tests {
+    use super::*;
+
+    #[test]
+    fn test_batching_try_from() {
+        let mut model = numaflow_models::models::IsbBatching::new();
+        assert_eq!(Batching::try_from(model.clone()), Ok(Batching::default()));
+
+        model.max_messages = Some(500);
+        model.linger = Some(kube::core::Duration::from(Duration::from_millis(20)));
+        assert_eq!(
+            Batching::try_from(model.clone()),
+            Ok(Batching {
+                max_messages: 500,
+                linger: Duration::from_millis(20),
+            })
+        );
+
+        model.max_messages = Some(0);
+        assert!(Batching::try_from(model.clone()).is_err());
+
+        model.max_messages = Some(MAX_BATCH_MESSAGES as i32 + 1);
+        assert!(Batching::try_from(model).is_err());
     }
 }
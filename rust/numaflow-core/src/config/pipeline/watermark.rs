@@ -1,8 +1,15 @@
+use crate::Error;
 use crate::config::get_vertex_replica;
 use crate::config::pipeline::{FromVertexConfig, ToVertexConfig, VertexConfig};
-use numaflow_models::models::Watermark;
+use crate::error::Result;
+use numaflow_models::models::{Watermark, WatermarkStrategy};
 use std::time::Duration;
 
+const DEFAULT_WATERMARK_PERCENTILE: i32 = 99;
+const DEFAULT_PERCENTILE_WINDOW: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_ADAPTIVE_HALF_LIFE: Duration = Duration::from_secs(60);
+const DEFAULT_PUNCTUATION_HEADER: &str = "x-numaflow-watermark";
+
 /// Watermark config for different types of Vertex.
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum WatermarkConfig {
@@ -22,7 +29,7 @@ impl WatermarkConfig {
         to_vertex_config: &[ToVertexConfig],
         delay_in_millis: u64,
         ordered_processing_enabled: bool,
-    ) -> Option<WatermarkConfig> {
+    ) -> Result<Option<WatermarkConfig>> {
         let max_delay = watermark_spec
             .as_ref()
             .and_then(|w| w.max_delay.map(|x| Duration::from(x).as_millis() as u64))
@@ -81,9 +88,12 @@ impl WatermarkConfig {
                 delay: Some(Duration::from_millis(delay_in_millis)),
             };
 
-        match vertex {
+        let config = match vertex {
             VertexConfig::Source(_) => Some(WatermarkConfig::Source(SourceWatermarkConfig {
                 max_delay: Duration::from_millis(max_delay),
+                strategy: SourceWatermarkStrategy::new(
+                    watermark_spec.as_ref().and_then(|w| w.strategy.as_deref()),
+                )?,
                 source_bucket_config: BucketConfig {
                     vertex: Box::leak(vertex_name.to_string().into_boxed_str()),
                     partitions: vec![0], // source will have only one partition
@@ -138,7 +148,8 @@ impl WatermarkConfig {
                         .collect(),
                 }))
             }
-        }
+        };
+        Ok(config)
     }
 }
 
@@ -147,11 +158,99 @@ impl WatermarkConfig {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct SourceWatermarkConfig {
     pub(crate) max_delay: Duration,
+    pub(crate) strategy: SourceWatermarkStrategy,
     pub(crate) source_bucket_config: BucketConfig,
     pub(crate) to_vertex_bucket_config: Vec<BucketConfig>,
     pub(crate) idle_config: Option<IdleConfig>,
 }
 
+/// Heuristic used by the source to compute the watermark of a partition from the event times of
+/// the messages read from it. The max delay is subtracted from the computed watermark regardless of
+/// the strategy.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) enum SourceWatermarkStrategy {
+    /// Minimum event time of the read messages.
+    #[default]
+    MinEventTime,
+    /// Holds back the watermark by the percentile of the out-of-orderness of the messages observed
+    /// in the sliding window.
+    Percentile { percentile: u8, window: Duration },
+    /// Holds back the watermark of each partition by a delay which grows to cover the late messages
+    /// and halves every `half_life` while the messages arrive in order.
+    Adaptive {
+        half_life: Duration,
+        max_delay: Option<Duration>,
+    },
+    /// Advances the watermark only on the messages with the marker header.
+    Punctuation { header: String },
+}
+
+impl SourceWatermarkStrategy {
+    fn new(spec: Option<&WatermarkStrategy>) -> Result<Self> {
+        let Some(spec) = spec else {
+            return Ok(Self::MinEventTime);
+        };
+
+        let configured = [
+            spec.percentile.is_some(),
+            spec.adaptive.is_some(),
+            spec.punctuation.is_some(),
+        ];
+        if configured.iter().filter(|c| **c).count() > 1 {
+            return Err(Error::Config(
+                "only one of percentile, adaptive and punctuation watermark strategies can be set"
+                    .to_string(),
+            ));
+        }
+
+        if let Some(percentile) = &spec.percentile {
+            let value = percentile
+                .percentile
+                .unwrap_or(DEFAULT_WATERMARK_PERCENTILE);
+            if !(1..=100).contains(&value) {
+                return Err(Error::Config(format!(
+                    "watermark percentile must be between 1 and 100, got {value}"
+                )));
+            }
+            return Ok(Self::Percentile {
+                percentile: value as u8,
+                window: percentile
+                    .window
+                    .map(Duration::from)
+                    .unwrap_or(DEFAULT_PERCENTILE_WINDOW),
+            });
+        }
+
+        if let Some(adaptive) = &spec.adaptive {
+            let half_life = adaptive
+                .half_life
+                .map(Duration::from)
+                .unwrap_or(DEFAULT_ADAPTIVE_HALF_LIFE);
+            if half_life.is_zero() {
+                return Err(Error::Config(
+                    "half life of the adaptive watermark must be greater than 0".to_string(),
+                ));
+            }
+            return Ok(Self::Adaptive {
+                half_life,
+                max_delay: adaptive.max_delay.map(Duration::from),
+            });
+        }
+
+        if let Some(punctuation) = &spec.punctuation {
+            return Ok(Self::Punctuation {
+                header: punctuation
+                    .header
+                    .clone()
+                    .filter(|header| !header.is_empty())
+                    .unwrap_or(DEFAULT_PUNCTUATION_HEADER.to_string()),
+            });
+        }
+
+        Ok(Self::MinEventTime)
+    }
+}
+
 /// Idle configuration for detecting idleness when there is no data
 /// from source and publish the Watermark.
 #[derive(Debug, Clone, PartialEq)]
@@ -193,3 +292,66 @@ pub(crate) struct EdgeWatermarkConfig {
     pub(crate) from_vertex_config: Vec<BucketConfig>,
     pub(crate) to_vertex_config: Vec<BucketConfig>,
 }
+
+#[cfg(test)]
+mod tests {
+    use numaflow_models::models::{AdaptiveWatermark, PercentileWatermark, PunctuationWatermark};
+
+    use super::*;
+
+    #[test]
+    fn test_source_watermark_strategy() {
+        assert_eq!(
+            SourceWatermarkStrategy::new(None).unwrap(),
+            SourceWatermarkStrategy::MinEventTime
+        );
+        assert_eq!(
+            SourceWatermarkStrategy::new(Some(&WatermarkStrategy::new())).unwrap(),
+            SourceWatermarkStrategy::MinEventTime
+        );
+
+        let mut spec = WatermarkStrategy::new();
+        spec.percentile = Some(Box::new(PercentileWatermark::new()));
+        assert_eq!(
+            SourceWatermarkStrategy::new(Some(&spec)).unwrap(),
+            SourceWatermarkStrategy::Percentile {
+                percentile: 99,
+                window: Duration::from_secs(300),
+            }
+        );
+
+        spec.percentile = Some(Box::new(PercentileWatermark {
+            percentile: Some(101),
+            window: None,
+        }));
+        assert!(SourceWatermarkStrategy::new(Some(&spec)).is_err());
+
+        // only one strategy can be set
+        spec.punctuation = Some(Box::new(PunctuationWatermark::new()));
+        assert!(SourceWatermarkStrategy::new(Some(&spec)).is_err());
+
+        spec.percentile = None;
+        assert_eq!(
+            SourceWatermarkStrategy::new(Some(&spec)).unwrap(),
+            SourceWatermarkStrategy::Punctuation {
+                header: "x-numaflow-watermark".to_string(),
+            }
+        );
+
+        let spec = WatermarkStrategy {
+            adaptive: Some(Box::new(AdaptiveWatermark {
+                half_life: Some(Duration::from_secs(10).into()),
+                max_delay: Some(Duration::from_secs(60).into()),
+            })),
+            percentile: None,
+            punctuation: None,
+        };
+        assert_eq!(
+            SourceWatermarkStrategy::new(Some(&spec)).unwrap(),
+            SourceWatermarkStrategy::Adaptive {
+                half_life: Duration::from_secs(10),
+                max_delay: Some(Duration::from_secs(60)),
+            }
+        );
+    }
+}
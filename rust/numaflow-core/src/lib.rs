@@ -117,7 +117,14 @@ pub(crate) mod typ;
 /// [Reduce]:https://numaflow.numaproj.io/user-guide/user-defined-functions/reduce/reduce/
 mod reduce;
 
-pub async fn run() -> Result<()> {
+/// Offline inspection and repair of the [Reduce] WAL, used by the `numaflow wal` subcommand.
+///
+/// [Reduce]:https://numaflow.numaproj.io/user-guide/user-defined-functions/reduce/reduce/
+pub use reduce::wal::inspect as wal;
+
+/// Runs the MonoVertex or the pipeline vertex configured by the controller. `isbsvc_type` is the
+/// type of the ISB service the pipeline runs on, i.e. `--isbsvc-type` of the `processor` command.
+pub async fn run(isbsvc_type: &str) -> Result<()> {
     let cln_token = CancellationToken::new();
     let shutdown_cln_token = cln_token.clone();
 
@@ -129,7 +136,7 @@ pub async fn run() -> Result<()> {
     });
 
     let env_vars: HashMap<String, String> = std::env::vars().collect();
-    let settings = Settings::load(env_vars)?;
+    let settings = Settings::load(env_vars, isbsvc_type.try_into()?)?;
     let crd_type = settings.custom_resource_type.clone();
 
     match crd_type {
@@ -28,7 +28,8 @@ pub(super) mod unary;
 
 use crate::config::pipeline::VERTEX_TYPE_MAP_UDF;
 use crate::metrics::{
-    monovertex_metrics, mvtx_forward_metric_labels, pipeline_metric_labels, pipeline_metrics,
+    monovertex_metrics, mvtx_forward_metric_labels, pipeline_drop_metric_labels,
+    pipeline_metric_labels, pipeline_metrics,
 };
 use crate::monovertex::bypass_router::MvtxBypassRouter;
 use batch::{MapBatchTask, UserDefinedBatchMap};
@@ -254,6 +255,8 @@ impl MapHandle {
                     if self.shutting_down_on_err {
                         warn!(offset = ?read_msg.offset, error = ?self.final_result, "Map component is shutting down because of an error, not accepting the message");
                         read_msg.ack_handle.as_ref().expect("ack handle should be present").is_failed.store(true, Ordering::Relaxed);
+                    } else if read_msg.deadline_exceeded() {
+                        drop_expired_message(read_msg, &self.tracker, ctx.shared_ctx.is_mono_vertex).await;
                     } else {
                         let permit = Arc::clone(&ctx.semaphore).acquire_owned()
                             .await.map_err(|e| Error::Mapper(format!("failed to acquire semaphore: {e}")))?;
@@ -300,7 +303,7 @@ impl MapHandle {
         let is_mono_vertex = is_mono_vertex();
         // we don't need to tokio spawn here because, unlike unary and stream, batch is
         // a blocking operation, and we process one batch at a time.
-        while let Some(batch) = chunked_stream.next().await {
+        while let Some(mut batch) = chunked_stream.next().await {
             // if there are errors then we need to drain the stream and nack
             if self.shutting_down_on_err {
                 for msg in batch {
@@ -314,6 +317,10 @@ impl MapHandle {
                 continue;
             }
 
+            for msg in batch.extract_if(.., |msg| msg.deadline_exceeded()) {
+                drop_expired_message(msg, &self.tracker, is_mono_vertex).await;
+            }
+
             let ack_handles: Vec<Option<Arc<AckHandle>>> =
                 batch.iter().map(|msg| msg.ack_handle.clone()).collect();
 
@@ -375,6 +382,34 @@ struct BatchMapContext {
     batch_mapper: UserDefinedBatchMap,
 }
 
+/// Drops the message without invoking the UDF, because the deadline of the serving request has
+/// passed. The message is acked once dropped, and its serving callback marks it as dropped so that
+/// the request is complete.
+async fn drop_expired_message(message: Message, tracker: &Tracker, is_mono_vertex: bool) {
+    warn!(offset = ?message.offset, "Deadline of the request has passed, dropping the message");
+    tracker
+        .serving_update(&message.offset, vec![Some(Message::drop_tags())])
+        .await
+        .expect("failed to update tracker");
+
+    if is_mono_vertex {
+        monovertex_metrics()
+            .dropped_total
+            .get_or_create(mvtx_forward_metric_labels())
+            .inc();
+    } else {
+        pipeline_metrics()
+            .forwarder
+            .drop_total
+            .get_or_create(&pipeline_drop_metric_labels(
+                VERTEX_TYPE_MAP_UDF,
+                get_vertex_name(),
+                "Deadline exceeded",
+            ))
+            .inc();
+    }
+}
+
 fn update_udf_error_metric(is_mono_vertex: bool) {
     if is_mono_vertex {
         monovertex_metrics()
@@ -829,6 +864,96 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_map_drops_expired_messages() -> Result<()> {
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let tmp_dir = TempDir::new().unwrap();
+        let sock_file = tmp_dir.path().join("batch_map.sock");
+        let server_info_file = tmp_dir.path().join("batch_map-server-info");
+
+        let server_info = server_info_file.clone();
+        let server_socket = sock_file.clone();
+        let handle = tokio::spawn(async move {
+            batchmap::Server::new(SimpleBatchMap)
+                .with_socket_file(server_socket)
+                .with_server_info_file(server_info)
+                .start_with_shutdown(shutdown_rx)
+                .await
+                .expect("server failed");
+        });
+
+        // wait for the server to start
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let tracker = Tracker::new(None, CancellationToken::new());
+
+        let client = MapClient::new(create_rpc_channel(sock_file).await?);
+        let mapper = MapHandle::new(
+            MapMode::Batch,
+            500,
+            Duration::from_millis(1000),
+            Duration::from_secs(10),
+            10,
+            client,
+            tracker.clone(),
+        )
+        .await?;
+
+        let message = |value: &'static str, index: i32, deadline: i64| Message {
+            keys: Arc::from(vec![value.into()]),
+            value: value.into(),
+            offset: Offset::String(StringOffset::new(index.to_string(), 0)),
+            event_time: Utc::now(),
+            headers: Arc::new(HashMap::from([(
+                serving::DEFAULT_DEADLINE_HEADER.to_string(),
+                deadline.to_string(),
+            )])),
+            id: MessageID {
+                vertex_name: "vertex_name".to_string().into(),
+                offset: index.to_string().into(),
+                index,
+            },
+            ..Default::default()
+        };
+
+        let now = Utc::now().timestamp_millis();
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let mut expired = message("expired", 0, now - 1000);
+        expired.ack_handle = Some(Arc::new(AckHandle::new(ack_tx)));
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        input_tx.send(expired).await.unwrap();
+        input_tx
+            .send(message("in-time", 1, now + 60_000))
+            .await
+            .unwrap();
+        drop(input_tx);
+
+        let (output_stream, map_handle) = mapper
+            .streaming_map(
+                ReceiverStream::new(input_rx),
+                CancellationToken::new(),
+                None,
+            )
+            .await?;
+        let mut output_rx = output_stream.into_inner();
+
+        // the expired message is acked without invoking the UDF
+        assert_eq!(ack_rx.await.unwrap(), ReadAck::Ack);
+        assert_eq!(output_rx.recv().await.unwrap().value, "in-time");
+        assert!(output_rx.recv().await.is_none());
+
+        shutdown_tx
+            .send(())
+            .expect("failed to send shutdown signal");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(
+            handle.is_finished(),
+            "Expected gRPC server to have shut down"
+        );
+        map_handle.await.unwrap()?;
+        Ok(())
+    }
+
     struct FlatmapStream;
 
     #[tonic::async_trait]
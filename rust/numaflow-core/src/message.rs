@@ -20,6 +20,7 @@ use bytes::{Bytes, BytesMut};
 use chrono::{DateTime, Utc};
 use prost::Message as ProtoMessage;
 use serde::{Deserialize, Serialize};
+use serving::DEFAULT_DEADLINE_HEADER;
 use tokio::sync::oneshot;
 
 const DROP: &str = "U+005C__DROP__";
@@ -200,6 +201,20 @@ impl Message {
             .as_ref()
             .is_some_and(|tags| tags.contains(&DROP.to_string()))
     }
+
+    /// Tags of a message dropped by the platform rather than the UDF.
+    pub(crate) fn drop_tags() -> Arc<[String]> {
+        Arc::from([DROP.to_string()])
+    }
+
+    /// Check if the deadline set by the serving source has passed, i.e., nobody is waiting for the
+    /// response of the sync request anymore.
+    pub(crate) fn deadline_exceeded(&self) -> bool {
+        self.headers
+            .get(DEFAULT_DEADLINE_HEADER)
+            .and_then(|deadline| deadline.parse::<i64>().ok())
+            .is_some_and(|deadline| deadline <= Utc::now().timestamp_millis())
+    }
 }
 
 /// IntOffset is integer based offset enum type.
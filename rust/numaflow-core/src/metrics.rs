@@ -91,6 +91,16 @@ const FALLBACK_SINK_WRITE_ERRORS_TOTAL: &str = "fbsink_write_errors";
 const ON_SUCCESS_SINK_WRITE_ERRORS_TOTAL: &str = "onsuccess_sink_write_errors";
 
 const SINK_DROPPED_TOTAL: &str = "dropped";
+const SINK_CIRCUIT_BREAKER_STATE: &str = "circuit_breaker_state";
+const SINK_CIRCUIT_BREAKER_TRANSITIONS_TOTAL: &str = "circuit_breaker_transitions";
+const PIPELINE_SINK_CIRCUIT_BREAKER_STATE: &str = "sink_circuit_breaker_state";
+const PIPELINE_SINK_CIRCUIT_BREAKER_TRANSITIONS_TOTAL: &str = "sink_circuit_breaker_transitions";
+const SINK_BROADCAST_WRITE_TOTAL: &str = "broadcast_write";
+const SINK_BROADCAST_WRITE_ERRORS_TOTAL: &str = "broadcast_write_errors";
+const SINK_BROADCAST_TIME: &str = "broadcast_time";
+const PIPELINE_SINK_BROADCAST_WRITE_TOTAL: &str = "sink_broadcast_write";
+const PIPELINE_SINK_BROADCAST_WRITE_ERRORS_TOTAL: &str = "sink_broadcast_write_errors";
+const PIPELINE_SINK_BROADCAST_TIME: &str = "sink_broadcast_time";
 const DROPPED_TOTAL: &str = "dropped";
 const PIPELINE_FORWARDER_DROP_TOTAL: &str = "drop";
 const PIPELINE_FORWARDER_DROP_BYTES_TOTAL: &str = "drop_bytes";
@@ -151,6 +161,22 @@ const JETSTREAM_ISB_READ_TIME_TOTAL: &str = "read_time_total";
 const JETSTREAM_ISB_WRITE_TIME_TOTAL: &str = "write_time_total";
 const JETSTREAM_ISB_ACK_TIME_TOTAL: &str = "ack_time_total";
 
+// isb compression metrics
+const ISB_COMPRESSION_RATIO: &str = "ratio";
+const ISB_COMPRESSION_SKIPPED_TOTAL: &str = "skipped";
+const ISB_COMPRESSION_COMPRESS_TIME: &str = "compress_time";
+const ISB_COMPRESSION_DECOMPRESS_TIME: &str = "decompress_time";
+
+// reduce wal metrics
+const WAL_CORRUPTED_RECORDS_TOTAL: &str = "corrupted_records";
+const WAL_TRUNCATED_BYTES_TOTAL: &str = "truncated_bytes";
+
+// watermark metrics
+const WATERMARK_LAG: &str = "lag";
+const WATERMARK_LAG_MS: &str = "lag_ms";
+const WATERMARK_PROCESSOR_SKEW_MS: &str = "processor_skew_ms";
+const WATERMARK_SINCE_LAST_ADVANCE_MS: &str = "since_last_advance_ms";
+
 /// A deep healthcheck for components. Each component should implement IsReady for both builtins and
 /// user-defined containers.
 #[derive(Clone)]
@@ -175,6 +201,8 @@ pub(crate) enum PipelineComponents<C: crate::typ::NumaflowTypeConfig> {
     Sink(Box<SinkWriter>),
     Map(MapHandle),
     Reduce(UserDefinedReduce),
+    /// Builtin reducers run within the numa container, there is nothing else to check.
+    BuiltinReduce,
 }
 
 /// WatermarkFetcherState holds watermark handle and partition count for fetching watermarks
@@ -197,6 +225,10 @@ pub(crate) struct MetricsState<C: crate::typ::NumaflowTypeConfig> {
 pub(crate) struct WatermarkQueryParams {
     /// Optional from vertex name to filter watermarks by edge
     pub(crate) from: Option<String>,
+    /// Returns the processors of the incoming edges and the watermarks published to the outgoing
+    /// edges instead of the watermark of each partition.
+    #[serde(default)]
+    pub(crate) detail: bool,
 }
 
 /// WatermarkResponse represents the response structure for the /watermark endpoint
@@ -302,6 +334,9 @@ pub(crate) struct PipelineMetrics {
     // sink forwarder specific metrics
     pub(crate) sink_forwarder: SinkForwarderMetrics,
     pub(crate) jetstream_isb: JetStreamISBMetrics,
+    pub(crate) isb_compression: ISBCompressionMetrics,
+    pub(crate) wal: WalMetrics,
+    pub(crate) watermark: WatermarkMetrics,
     pub(crate) pending_raw: Family<Vec<(String, String)>, Gauge>,
 }
 
@@ -311,6 +346,30 @@ pub(crate) struct SinkMetrics {
     pub(crate) time: Family<Vec<(String, String)>, Histogram>,
     pub(crate) dropped_total: Family<Vec<(String, String)>, Counter>,
     pub(crate) write_errors_total: Family<Vec<(String, String)>, Counter>,
+    /// state of the circuit breaker, 0 is closed, 1 is half-open and 2 is open
+    pub(crate) circuit_breaker_state: Family<Vec<(String, String)>, Gauge>,
+    pub(crate) circuit_breaker_transitions_total: Family<Vec<(String, String)>, Counter>,
+    /// writes to the child sinks of a broadcast sink, labeled with the child sink
+    pub(crate) broadcast: BroadcastSinkMetrics,
+}
+
+/// Family of metrics for the child sinks of a broadcast sink
+pub(crate) struct BroadcastSinkMetrics {
+    pub(crate) write_total: Family<Vec<(String, String)>, Counter>,
+    pub(crate) write_errors_total: Family<Vec<(String, String)>, Counter>,
+    pub(crate) time: Family<Vec<(String, String)>, Histogram>,
+}
+
+impl BroadcastSinkMetrics {
+    fn new() -> Self {
+        Self {
+            write_total: Family::<Vec<(String, String)>, Counter>::default(),
+            write_errors_total: Family::<Vec<(String, String)>, Counter>::default(),
+            time: Family::<Vec<(String, String)>, Histogram>::new_with_constructor(|| {
+                Histogram::new(exponential_buckets_range(100.0, 60000000.0 * 15.0, 10))
+            }),
+        }
+    }
 }
 
 /// Family of metrics for the Fallback Sink
@@ -466,6 +525,12 @@ pub(crate) struct SinkForwarderMetrics {
 
     // on-success sink histograms
     pub(crate) onsuccess_sink_write_processing_time: Family<Vec<(String, String)>, Histogram>,
+
+    // circuit breaker of the sink, the state is 0 for closed, 1 for half-open and 2 for open
+    pub(crate) circuit_breaker_state: Family<Vec<(String, String)>, Gauge>,
+    pub(crate) circuit_breaker_transitions_total: Family<Vec<(String, String)>, Counter>,
+    /// writes to the child sinks of a broadcast sink, labeled with the child sink
+    pub(crate) broadcast: BroadcastSinkMetrics,
 }
 
 impl SinkForwarderMetrics {
@@ -485,6 +550,9 @@ impl SinkForwarderMetrics {
                 Family::<Vec<(String, String)>, Histogram>::new_with_constructor(|| {
                     Histogram::new(exponential_buckets_range(100.0, 60000000.0 * 20.0, 10))
                 }),
+            circuit_breaker_state: Family::<Vec<(String, String)>, Gauge>::default(),
+            circuit_breaker_transitions_total: Family::<Vec<(String, String)>, Counter>::default(),
+            broadcast: BroadcastSinkMetrics::new(),
         }
     }
 }
@@ -533,6 +601,75 @@ impl JetStreamISBMetrics {
     }
 }
 
+/// Integrity metrics of the reduce WAL segments, updated during replay.
+pub(crate) struct WalMetrics {
+    /// corrupted or partially written (torn) records found in the segments
+    pub(crate) corrupted_records_total: Family<Vec<(String, String)>, Counter>,
+    /// bytes truncated from the segments at the corrupted records
+    pub(crate) truncated_bytes_total: Family<Vec<(String, String)>, Counter>,
+}
+
+impl WalMetrics {
+    pub(crate) fn new() -> Self {
+        Self {
+            corrupted_records_total: Family::<Vec<(String, String)>, Counter>::default(),
+            truncated_bytes_total: Family::<Vec<(String, String)>, Counter>::default(),
+        }
+    }
+}
+
+/// Progress of the watermark fetched for each partition read from the ISB, updated periodically.
+pub(crate) struct WatermarkMetrics {
+    /// wall-clock time minus the fetched watermark, in milliseconds
+    pub(crate) lag: Family<Vec<(String, String)>, Histogram>,
+    pub(crate) lag_ms: Family<Vec<(String, String)>, Gauge>,
+    /// spread between the head watermarks of the fastest and the slowest upstream processors
+    pub(crate) processor_skew_ms: Family<Vec<(String, String)>, Gauge>,
+    /// time since the fetched watermark last advanced
+    pub(crate) since_last_advance_ms: Family<Vec<(String, String)>, Gauge>,
+}
+
+impl WatermarkMetrics {
+    pub(crate) fn new() -> Self {
+        Self {
+            // exponential buckets in the range 10 milliseconds to 1 day
+            lag: Family::<Vec<(String, String)>, Histogram>::new_with_constructor(|| {
+                Histogram::new(exponential_buckets_range(10.0, 86400000.0, 12))
+            }),
+            lag_ms: Family::<Vec<(String, String)>, Gauge>::default(),
+            processor_skew_ms: Family::<Vec<(String, String)>, Gauge>::default(),
+            since_last_advance_ms: Family::<Vec<(String, String)>, Gauge>::default(),
+        }
+    }
+}
+
+/// Per-edge (buffer) compression metrics of the ISB.
+pub(crate) struct ISBCompressionMetrics {
+    /// compressed size / original size of the compressed messages
+    pub(crate) ratio: Family<Vec<(String, String)>, Histogram>,
+    /// messages written uncompressed since they are smaller than the minimum size
+    pub(crate) skipped_total: Family<Vec<(String, String)>, Counter>,
+    pub(crate) compress_time: Family<Vec<(String, String)>, Histogram>,
+    pub(crate) decompress_time: Family<Vec<(String, String)>, Histogram>,
+}
+
+impl ISBCompressionMetrics {
+    pub(crate) fn new() -> Self {
+        Self {
+            ratio: Family::<Vec<(String, String)>, Histogram>::new_with_constructor(|| {
+                Histogram::new(exponential_buckets_range(0.01, 2.0, 10))
+            }),
+            skipped_total: Family::<Vec<(String, String)>, Counter>::default(),
+            compress_time: Family::<Vec<(String, String)>, Histogram>::new_with_constructor(|| {
+                Histogram::new(exponential_buckets_range(1.0, 1000000.0, 10))
+            }),
+            decompress_time: Family::<Vec<(String, String)>, Histogram>::new_with_constructor(
+                || Histogram::new(exponential_buckets_range(1.0, 1000000.0, 10)),
+            ),
+        }
+    }
+}
+
 /// Exponential bucket distribution with range.
 /// Creates `length` buckets, where the lowest bucket is `min` and the highest bucket is `max`.
 /// The final +Inf bucket is not counted and not included in the returned iterator.
@@ -599,6 +736,10 @@ impl MonoVtxMetrics {
                 }),
                 dropped_total: Family::<Vec<(String, String)>, Counter>::default(),
                 write_errors_total: Family::<Vec<(String, String)>, Counter>::default(),
+                circuit_breaker_state: Family::<Vec<(String, String)>, Gauge>::default(),
+                circuit_breaker_transitions_total:
+                    Family::<Vec<(String, String)>, Counter>::default(),
+                broadcast: BroadcastSinkMetrics::new(),
             },
 
             fb_sink: FallbackSinkMetrics {
@@ -722,6 +863,31 @@ impl MonoVtxMetrics {
             "A counter to keep track of the total number of messages dropped by sink",
             metrics.sink.dropped_total.clone(),
         );
+        sink_registry.register(
+            SINK_CIRCUIT_BREAKER_STATE,
+            "State of the circuit breaker of the sink, 0 is closed, 1 is half-open and 2 is open",
+            metrics.sink.circuit_breaker_state.clone(),
+        );
+        sink_registry.register(
+            SINK_CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+            "A counter to keep track of the state transitions of the circuit breaker of the sink",
+            metrics.sink.circuit_breaker_transitions_total.clone(),
+        );
+        sink_registry.register(
+            SINK_BROADCAST_WRITE_TOTAL,
+            "A Counter to keep track of the total number of messages written to each child sink of the broadcast sink",
+            metrics.sink.broadcast.write_total.clone(),
+        );
+        sink_registry.register(
+            SINK_BROADCAST_WRITE_ERRORS_TOTAL,
+            "A counter to keep track of the total number of write errors for each child sink of the broadcast sink",
+            metrics.sink.broadcast.write_errors_total.clone(),
+        );
+        sink_registry.register(
+            SINK_BROADCAST_TIME,
+            "A Histogram to keep track of the time taken to write to each child sink of the broadcast sink, in microseconds",
+            metrics.sink.broadcast.time.clone(),
+        );
 
         // Fallback Sink metrics
         let fb_sink_registry = registry.sub_registry_with_prefix(FALLBACK_SINK_REGISTRY_PREFIX);
@@ -758,6 +924,9 @@ impl PipelineMetrics {
             source_forwarder: SourceForwarderMetrics::new(),
             sink_forwarder: SinkForwarderMetrics::new(),
             jetstream_isb: JetStreamISBMetrics::new(),
+            isb_compression: ISBCompressionMetrics::new(),
+            wal: WalMetrics::new(),
+            watermark: WatermarkMetrics::new(),
             pending_raw: Family::<Vec<(String, String)>, Gauge>::default(),
         };
         let mut registry = global_registry().registry.lock();
@@ -765,6 +934,9 @@ impl PipelineMetrics {
         Self::register_source_forwarder_metrics(&metrics, &mut registry);
         Self::register_sink_forwarder_metrics(&metrics, &mut registry);
         Self::register_jetstream_isb_metrics(&metrics, &mut registry);
+        Self::register_isb_compression_metrics(&metrics, &mut registry);
+        Self::register_wal_metrics(&metrics, &mut registry);
+        Self::register_watermark_metrics(&metrics, &mut registry);
         Self::register_vertex_metrics(&metrics, &mut registry);
         metrics
     }
@@ -918,6 +1090,35 @@ impl PipelineMetrics {
         // Pipeline sink forwarder sub-registry
         let sink_forwarder_registry = registry.sub_registry_with_prefix("forwarder");
 
+        sink_forwarder_registry.register(
+            PIPELINE_SINK_CIRCUIT_BREAKER_STATE,
+            "State of the circuit breaker of the sink, 0 is closed, 1 is half-open and 2 is open",
+            metrics.sink_forwarder.circuit_breaker_state.clone(),
+        );
+        sink_forwarder_registry.register(
+            PIPELINE_SINK_CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+            "Total number of state transitions of the circuit breaker of the sink",
+            metrics
+                .sink_forwarder
+                .circuit_breaker_transitions_total
+                .clone(),
+        );
+        sink_forwarder_registry.register(
+            PIPELINE_SINK_BROADCAST_WRITE_TOTAL,
+            "Total number of Messages Written to each child sink of the broadcast sink",
+            metrics.sink_forwarder.broadcast.write_total.clone(),
+        );
+        sink_forwarder_registry.register(
+            PIPELINE_SINK_BROADCAST_WRITE_ERRORS_TOTAL,
+            "Total number of Write Errors of each child sink of the broadcast sink",
+            metrics.sink_forwarder.broadcast.write_errors_total.clone(),
+        );
+        sink_forwarder_registry.register(
+            PIPELINE_SINK_BROADCAST_TIME,
+            "Processing times of the writes to each child sink of the broadcast sink (100 microseconds to 15 minutes)",
+            metrics.sink_forwarder.broadcast.time.clone(),
+        );
+
         sink_forwarder_registry.register(
             FALLBACK_SINK_WRITE_ERRORS_TOTAL,
             "Total number of Write Errors while writing to a fallback sink",
@@ -1034,6 +1235,71 @@ impl PipelineMetrics {
         );
     }
 
+    fn register_isb_compression_metrics(metrics: &Self, registry: &mut Registry) {
+        // Pipeline ISB compression sub-registry
+        let compression_registry = registry.sub_registry_with_prefix("isb_compression");
+        compression_registry.register(
+            ISB_COMPRESSION_RATIO,
+            "Ratio of the compressed size to the original size of the messages",
+            metrics.isb_compression.ratio.clone(),
+        );
+        compression_registry.register(
+            ISB_COMPRESSION_SKIPPED_TOTAL,
+            "Total number of messages written uncompressed since they are smaller than the minimum size",
+            metrics.isb_compression.skipped_total.clone(),
+        );
+        compression_registry.register(
+            ISB_COMPRESSION_COMPRESS_TIME,
+            "Time taken to compress a message, in microseconds",
+            metrics.isb_compression.compress_time.clone(),
+        );
+        compression_registry.register(
+            ISB_COMPRESSION_DECOMPRESS_TIME,
+            "Time taken to decompress a message, in microseconds",
+            metrics.isb_compression.decompress_time.clone(),
+        );
+    }
+
+    fn register_wal_metrics(metrics: &Self, registry: &mut Registry) {
+        // Pipeline reduce WAL sub-registry
+        let wal_registry = registry.sub_registry_with_prefix("wal");
+        wal_registry.register(
+            WAL_CORRUPTED_RECORDS_TOTAL,
+            "Total number of corrupted or partially written records found while replaying the WAL",
+            metrics.wal.corrupted_records_total.clone(),
+        );
+        wal_registry.register(
+            WAL_TRUNCATED_BYTES_TOTAL,
+            "Total number of bytes truncated from the WAL segments at the corrupted records",
+            metrics.wal.truncated_bytes_total.clone(),
+        );
+    }
+
+    fn register_watermark_metrics(metrics: &Self, registry: &mut Registry) {
+        // Pipeline watermark sub-registry
+        let watermark_registry = registry.sub_registry_with_prefix("watermark");
+        watermark_registry.register(
+            WATERMARK_LAG,
+            "A Histogram to keep track of the wall-clock time minus the fetched watermark of the partition, in milliseconds",
+            metrics.watermark.lag.clone(),
+        );
+        watermark_registry.register(
+            WATERMARK_LAG_MS,
+            "Wall-clock time minus the fetched watermark of the partition, in milliseconds",
+            metrics.watermark.lag_ms.clone(),
+        );
+        watermark_registry.register(
+            WATERMARK_PROCESSOR_SKEW_MS,
+            "Difference between the head watermarks of the fastest and the slowest upstream processors of the partition, in milliseconds",
+            metrics.watermark.processor_skew_ms.clone(),
+        );
+        watermark_registry.register(
+            WATERMARK_SINCE_LAST_ADVANCE_MS,
+            "Time since the fetched watermark of the partition last advanced, in milliseconds",
+            metrics.watermark.since_last_advance_ms.clone(),
+        );
+    }
+
     fn register_vertex_metrics(metrics: &Self, registry: &mut Registry) {
         // Pipeline vertex sub-registry
         let vertex_registry = registry.sub_registry_with_prefix("vertex");
@@ -1168,6 +1434,46 @@ pub(crate) fn pipeline_critical_error_metric_labels(
     labels
 }
 
+/// labels of the WAL metrics, `reason` is set only for the corrupted records.
+pub(crate) fn wal_metrics_labels(wal_type: &str, reason: Option<&str>) -> Vec<(String, String)> {
+    let mut labels = vec![("wal".to_string(), wal_type.to_string())];
+    if let Some(reason) = reason {
+        labels.push(("reason".to_string(), reason.to_string()));
+    }
+    labels
+}
+
+/// labels of the watermark metrics of a partition of the incoming edge from `from_vertex`.
+pub(crate) fn watermark_metrics_labels(from_vertex: &str, partition: u16) -> Vec<(String, String)> {
+    vec![
+        (
+            PIPELINE_NAME_LABEL.to_string(),
+            get_pipeline_name().to_string(),
+        ),
+        (
+            PIPELINE_VERTEX_LABEL.to_string(),
+            get_vertex_name().to_string(),
+        ),
+        ("from_vertex".to_string(), from_vertex.to_string()),
+        ("partition".to_string(), partition.to_string()),
+    ]
+}
+
+/// labels of the compression metrics of a buffer, for every ISB service type.
+pub(crate) fn isb_compression_metrics_labels(buffer_name: &str) -> Vec<(String, String)> {
+    vec![
+        (
+            PIPELINE_NAME_LABEL.to_string(),
+            get_pipeline_name().to_string(),
+        ),
+        (
+            PIPELINE_VERTEX_LABEL.to_string(),
+            get_vertex_name().to_string(),
+        ),
+        ("buffer".to_string(), buffer_name.to_string()),
+    ]
+}
+
 pub(crate) fn jetstream_isb_metrics_labels(buffer_name: &str) -> Vec<(String, String)> {
     vec![("buffer".to_string(), buffer_name.to_string())]
 }
@@ -1200,7 +1506,8 @@ pub async fn metrics_handler() -> impl IntoResponse {
 
 // watermark_handler is used to fetch and return watermark information
 // for all partitions based on available watermark handles.
-// Optionally accepts a 'from' query parameter to filter watermarks by edge (from vertex).
+// Optionally accepts a 'from' query parameter to filter watermarks by edge (from vertex), and a
+// 'detail' query parameter to return the processors of every partition.
 pub async fn watermark_handler<C: crate::typ::NumaflowTypeConfig>(
     State(state): State<MetricsState<C>>,
     Query(params): Query<WatermarkQueryParams>,
@@ -1215,6 +1522,10 @@ pub async fn watermark_handler<C: crate::typ::NumaflowTypeConfig>(
             .unwrap();
     };
 
+    if params.detail {
+        return watermark_details(watermark_fetcher_state, params.from.as_deref()).await;
+    }
+
     let mut partitions = HashMap::new();
     match &watermark_fetcher_state.watermark_handle {
         // For source watermark handle, always fetch only partition 0
@@ -1254,6 +1565,36 @@ pub async fn watermark_handler<C: crate::typ::NumaflowTypeConfig>(
         .unwrap()
 }
 
+/// Returns the detailed view of the watermark, which is only available for vertices reading from
+/// the ISB.
+async fn watermark_details(
+    watermark_fetcher_state: &WatermarkFetcherState,
+    from: Option<&str>,
+) -> Response<Body> {
+    let WatermarkHandle::ISB(isb_handle) = &watermark_fetcher_state.watermark_handle else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"error": "Watermark details are only available for vertices reading from the ISB"}"#,
+            ))
+            .unwrap();
+    };
+
+    let details = isb_handle
+        .fetch_watermark_details(from, &watermark_fetcher_state.partitions)
+        .await;
+    let json_response = serde_json::to_string(&details)
+        .unwrap_or_else(|_| r#"{"error": "Failed to serialize watermark details"}"#.to_string());
+
+    debug!(?json_response, ?from, "Watermark details response");
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(json_response))
+        .unwrap()
+}
+
 pub(crate) async fn start_metrics_https_server<C: crate::typ::NumaflowTypeConfig>(
     addr: SocketAddr,
     metrics_state: MetricsState<C>,
@@ -1358,6 +1699,7 @@ async fn sidecar_livez<C: crate::typ::NumaflowTypeConfig>(
                     }
                 },
             },
+            PipelineComponents::BuiltinReduce => {}
         },
     }
     StatusCode::NO_CONTENT
@@ -13,7 +13,7 @@ use crate::source::Source;
 use crate::tracker::Tracker;
 use crate::typ::{
     build_in_memory_rate_limiter_config, build_redis_rate_limiter_config,
-    should_use_redis_rate_limiter,
+    should_use_jetstream_rate_limiter, should_use_redis_rate_limiter,
 };
 use crate::{metrics, shared};
 
@@ -32,6 +32,12 @@ pub(crate) async fn start_forwarder(
     config: &MonovertexConfig,
 ) -> error::Result<()> {
     if let Some(rate_limit_config) = &config.rate_limit {
+        // MonoVertex has no ISB to keep the state of the rate limiter in.
+        if should_use_jetstream_rate_limiter(rate_limit_config) {
+            return Err(error::Error::Config(
+                "jetStreamStore rate limiter store is not supported for MonoVertex".to_string(),
+            ));
+        }
         if should_use_redis_rate_limiter(rate_limit_config) {
             let redis_config =
                 build_redis_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
@@ -103,7 +109,8 @@ async fn run_monovertex_forwarder<C: crate::typ::NumaflowTypeConfig>(
         cln_token.clone(),
         rate_limiter,
     )
-    .await?;
+    .await?
+    .with_keyed_rate_limiter(config.rate_limit.as_ref());
 
     let mapper = if let Some(map_config) = &config.map_config {
         create_components::create_mapper(
@@ -296,6 +303,7 @@ mod tests {
                     },
                 ),
                 retry_config: Default::default(),
+                dlq_headers: false,
             },
             ..Default::default()
         };
@@ -38,8 +38,9 @@
 
 use crate::config::pipeline::PipelineConfig;
 use crate::config::pipeline::watermark::WatermarkConfig;
+use crate::pipeline::isb::ISBService;
 use crate::watermark::source::SourceWatermarkHandle;
-use crate::{config, error, pipeline};
+use crate::{config, error};
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
@@ -61,7 +62,7 @@ pub(crate) async fn start_forwarder(
     cln_token: CancellationToken,
     config: PipelineConfig,
 ) -> error::Result<()> {
-    let js_context = pipeline::create_js_context(config.js_client_config.clone()).await?;
+    let isb = ISBService::connect(&config.isb_client_config).await?;
 
     match &config.vertex_config {
         config::pipeline::VertexConfig::Source(source) => {
@@ -72,7 +73,7 @@ pub(crate) async fn start_forwarder(
                 Some(WatermarkConfig::Source(source_config)) => Some(
                     SourceWatermarkHandle::new(
                         config.read_timeout,
-                        js_context.clone(),
+                        isb.clone(),
                         &config.to_vertex_config,
                         source_config,
                         cln_token.clone(),
@@ -84,7 +85,7 @@ pub(crate) async fn start_forwarder(
 
             source_forwarder::start_source_forwarder(
                 cln_token,
-                js_context,
+                isb,
                 config.clone(),
                 source.clone(),
                 source_watermark_handle,
@@ -93,24 +94,18 @@ pub(crate) async fn start_forwarder(
         }
         config::pipeline::VertexConfig::Sink(sink) => {
             info!("Starting sink forwarder");
-            sink_forwarder::start_sink_forwarder(
-                cln_token,
-                js_context,
-                config.clone(),
-                (**sink).clone(),
-            )
-            .await?;
+            sink_forwarder::start_sink_forwarder(cln_token, isb, config.clone(), (**sink).clone())
+                .await?;
         }
         config::pipeline::VertexConfig::Map(map) => {
             info!("Starting map forwarder");
-            map_forwarder::start_map_forwarder(cln_token, js_context, config.clone(), map.clone())
-                .await?;
+            map_forwarder::start_map_forwarder(cln_token, isb, config.clone(), map.clone()).await?;
         }
         config::pipeline::VertexConfig::Reduce(reduce) => {
             info!("Starting reduce forwarder");
             reduce_forwarder::start_reduce_forwarder(
                 cln_token,
-                js_context,
+                isb,
                 config.clone(),
                 reduce.clone(),
             )
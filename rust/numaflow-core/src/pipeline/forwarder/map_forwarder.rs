@@ -9,21 +9,21 @@ use crate::metrics::{
     WatermarkFetcherState,
 };
 use crate::pipeline::PipelineContext;
-
-use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
+use crate::pipeline::isb::jetstream::JetStreamFactory;
 use crate::pipeline::isb::reader::{ISBReaderComponents, ISBReaderOrchestrator};
 use crate::pipeline::isb::writer::{ISBWriterOrchestrator, ISBWriterOrchestratorComponents};
+use crate::pipeline::isb::{ISBFactory, ISBService};
 use crate::shared::create_components;
 use crate::shared::metrics::start_metrics_server;
 use crate::tracker::Tracker;
 use crate::typ::{
-    NumaflowTypeConfig, WithInMemoryRateLimiter, WithRedisRateLimiter, WithoutRateLimiter,
-    build_in_memory_rate_limiter_config, build_redis_rate_limiter_config,
+    NumaflowTypeConfig, WithInMemoryRateLimiter, WithJetStreamRateLimiter, WithRedisRateLimiter,
+    WithoutRateLimiter, build_in_memory_rate_limiter_config, build_jetstream_rate_limiter_config,
+    build_redis_rate_limiter_config, should_use_jetstream_rate_limiter,
     should_use_redis_rate_limiter,
 };
 use crate::watermark::WatermarkHandle;
 use crate::{Result, shared};
-use async_nats::jetstream::Context;
 use futures::future::try_join_all;
 use serving::callback::CallbackHandler;
 use tokio_util::sync::CancellationToken;
@@ -95,7 +95,7 @@ impl<C: crate::typ::NumaflowTypeConfig> MapForwarder<C> {
 
 pub async fn start_map_forwarder(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: ISBService,
     config: PipelineConfig,
     map_vtx_config: MapVtxConfig,
 ) -> Result<()> {
@@ -103,7 +103,7 @@ pub async fn start_map_forwarder(
         Some(
             CallbackHandler::new(
                 config.vertex_name,
-                js_context.clone(),
+                isb.jetstream("Serving")?.clone(),
                 cb_cfg.callback_store,
                 cb_cfg.callback_concurrency,
             )
@@ -128,7 +128,7 @@ pub async fn start_map_forwarder(
     let tracker = Tracker::new(serving_callback_handler.clone(), cln_token.clone());
     let watermark_handle = create_components::create_edge_watermark_handle(
         &config,
-        &js_context,
+        &isb,
         &cln_token,
         None,
         tracker.clone(),
@@ -136,11 +136,84 @@ pub async fn start_map_forwarder(
     )
     .await?;
 
-    // Create the ISB factory from the JetStream context
-    use crate::pipeline::isb::ISBFactory;
-    use crate::pipeline::isb::jetstream::JetStreamFactory;
-    let isb_factory = JetStreamFactory::new(js_context.clone());
+    let (forwarder_tasks, mapper_handle, _pending_reader_task) = match &isb {
+        ISBService::JetStream(js_context) => {
+            let isb_factory = JetStreamFactory::new(js_context.clone());
+            run_map_forwarders(
+                &isb_factory,
+                &isb,
+                &cln_token,
+                &config,
+                &tracker,
+                &map_vtx_config,
+                reader_config,
+                watermark_handle.clone(),
+            )
+            .await?
+        }
+        ISBService::Redis(isb_factory) => {
+            run_map_forwarders(
+                isb_factory,
+                &isb,
+                &cln_token,
+                &config,
+                &tracker,
+                &map_vtx_config,
+                reader_config,
+                watermark_handle.clone(),
+            )
+            .await?
+        }
+    };
+
+    let metrics_server_handle = start_metrics_server::<WithoutRateLimiter>(
+        config.metrics_config.clone(),
+        MetricsState {
+            health_checks: ComponentHealthChecks::Pipeline(Box::new(PipelineComponents::Map(
+                mapper_handle,
+            ))),
+            watermark_fetcher_state: watermark_handle.map(|handle| WatermarkFetcherState {
+                watermark_handle: WatermarkHandle::ISB(handle),
+                partitions: from_partitions,
+            }),
+        },
+    )
+    .await;
+
+    let results = try_join_all(forwarder_tasks)
+        .await
+        .map_err(|e| Error::Forwarder(e.to_string()))?;
+
+    for result in results {
+        info!(?result, "Forwarder task completed");
+        result?;
+    }
+
+    metrics_server_handle.abort();
+
+    info!("All forwarders have stopped successfully");
+    Ok(())
+}
 
+/// Starts the map forwarders on the ISB of the factory with the configured rate limiter.
+#[allow(clippy::too_many_arguments)]
+async fn run_map_forwarders<F>(
+    isb_factory: &F,
+    isb: &ISBService,
+    cln_token: &CancellationToken,
+    config: &PipelineConfig,
+    tracker: &Tracker,
+    map_vtx_config: &MapVtxConfig,
+    reader_config: &BufferReaderConfig,
+    watermark_handle: Option<crate::watermark::isb::ISBWatermarkHandle>,
+) -> Result<(
+    Vec<tokio::task::JoinHandle<Result<()>>>,
+    MapHandle,
+    PendingReaderTasks,
+)>
+where
+    F: ISBFactory + Clone + 'static,
+{
     let writers = isb_factory
         .create_writers(
             &config.to_vertex_config,
@@ -164,100 +237,95 @@ pub async fn start_map_forwarder(
         }};
     }
 
-    let (forwarder_tasks, mapper_handle, _pending_reader_task) = if let Some(rate_limit_config) =
-        &config.rate_limit
-    {
+    if let Some(rate_limit_config) = &config.rate_limit {
         if should_use_redis_rate_limiter(rate_limit_config) {
             let redis_config =
                 build_redis_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
-            let buffer_writer = create_writer!(WithRedisRateLimiter);
+            let buffer_writer = create_writer!(WithRedisRateLimiter<F>);
 
-            let context = PipelineContext::<WithRedisRateLimiter, _>::new(
+            let context = PipelineContext::<WithRedisRateLimiter<F>, _>::new(
                 cln_token.clone(),
-                &isb_factory,
-                &config,
+                isb_factory,
+                config,
                 tracker.clone(),
             );
 
-            run_all_map_forwarders::<WithRedisRateLimiter, _>(
+            run_all_map_forwarders::<WithRedisRateLimiter<F>, _>(
                 &context,
-                &map_vtx_config,
+                map_vtx_config,
                 reader_config,
                 buffer_writer,
                 watermark_handle.clone(),
                 Some(redis_config.throttling_config),
             )
-            .await?
+            .await
+        } else if should_use_jetstream_rate_limiter(rate_limit_config) {
+            let js_config = build_jetstream_rate_limiter_config(
+                rate_limit_config,
+                isb.jetstream("The JetStream rate limiter")?,
+                cln_token.clone(),
+            )
+            .await?;
+            let buffer_writer = create_writer!(WithJetStreamRateLimiter<F>);
+
+            let context = PipelineContext::<WithJetStreamRateLimiter<F>, _>::new(
+                cln_token.clone(),
+                isb_factory,
+                config,
+                tracker.clone(),
+            );
+
+            run_all_map_forwarders::<WithJetStreamRateLimiter<F>, _>(
+                &context,
+                map_vtx_config,
+                reader_config,
+                buffer_writer,
+                watermark_handle.clone(),
+                Some(js_config.throttling_config),
+            )
+            .await
         } else {
             let in_mem_config =
                 build_in_memory_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
-            let buffer_writer = create_writer!(WithInMemoryRateLimiter);
+            let buffer_writer = create_writer!(WithInMemoryRateLimiter<F>);
 
-            let context = PipelineContext::<WithInMemoryRateLimiter, _>::new(
+            let context = PipelineContext::<WithInMemoryRateLimiter<F>, _>::new(
                 cln_token.clone(),
-                &isb_factory,
-                &config,
+                isb_factory,
+                config,
                 tracker.clone(),
             );
 
-            run_all_map_forwarders::<WithInMemoryRateLimiter, _>(
+            run_all_map_forwarders::<WithInMemoryRateLimiter<F>, _>(
                 &context,
-                &map_vtx_config,
+                map_vtx_config,
                 reader_config,
                 buffer_writer,
                 watermark_handle.clone(),
                 Some(in_mem_config.throttling_config),
             )
-            .await?
+            .await
         }
     } else {
-        let buffer_writer = create_writer!(WithoutRateLimiter);
+        let buffer_writer = create_writer!(WithoutRateLimiter<F>);
 
-        let context = PipelineContext::<WithoutRateLimiter, _>::new(
+        let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
             cln_token.clone(),
-            &isb_factory,
-            &config,
+            isb_factory,
+            config,
             tracker.clone(),
         );
 
-        run_all_map_forwarders::<WithoutRateLimiter, _>(
+        run_all_map_forwarders::<WithoutRateLimiter<F>, _>(
             &context,
-            &map_vtx_config,
+            map_vtx_config,
             reader_config,
             buffer_writer,
             watermark_handle.clone(),
             None,
         )
-        .await?
-    };
-
-    let metrics_server_handle = start_metrics_server::<WithoutRateLimiter>(
-        config.metrics_config.clone(),
-        MetricsState {
-            health_checks: ComponentHealthChecks::Pipeline(Box::new(PipelineComponents::Map(
-                mapper_handle,
-            ))),
-            watermark_fetcher_state: watermark_handle.map(|handle| WatermarkFetcherState {
-                watermark_handle: WatermarkHandle::ISB(handle),
-                partitions: from_partitions,
-            }),
-        },
-    )
-    .await;
-
-    let results = try_join_all(forwarder_tasks)
         .await
-        .map_err(|e| Error::Forwarder(e.to_string()))?;
-
-    for result in results {
-        info!(?result, "Forwarder task completed");
-        result?;
     }
-
-    metrics_server_handle.abort();
-
-    info!("All forwarders have stopped successfully");
-    Ok(())
 }
 
 /// Starts map forwarder for all the streams.
@@ -274,7 +342,7 @@ async fn run_all_map_forwarders<C, F>(
     PendingReaderTasks,
 )>
 where
-    C: NumaflowTypeConfig<ISBReader = JetStreamReader>,
+    C: NumaflowTypeConfig,
     F: crate::pipeline::isb::ISBFactory<Reader = C::ISBReader, Writer = C::ISBWriter>,
 {
     let mut forwarder_tasks = vec![];
@@ -343,7 +411,7 @@ async fn run_map_forwarder_for_stream<C, F>(
     ISBReaderOrchestrator<C>,
 )>
 where
-    C: NumaflowTypeConfig<ISBReader = JetStreamReader>,
+    C: NumaflowTypeConfig,
     F: crate::pipeline::isb::ISBFactory<Reader = C::ISBReader, Writer = C::ISBWriter>,
 {
     let cln_token = reader_components.cln_token.clone();
@@ -537,11 +605,11 @@ mod tests {
             batch_size: 1000,
             writer_concurrency: 1000,
             read_timeout: Duration::from_secs(1),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             to_vertex_config: vec![ToVertexConfig {
                 name: "map-out",
                 partitions: 5,
@@ -550,6 +618,7 @@ mod tests {
                     max_length: 30000,
                     usage_limit: 0.8,
                     buffer_full_strategy: RetryUntilSuccess,
+                    batching: None,
                 },
                 conditions: None,
                 to_vertex_type: VertexType::Sink,
@@ -595,9 +664,14 @@ mod tests {
             let cancellation_token = cancellation_token.clone();
             let context = context.clone();
             async move {
-                start_map_forwarder(cancellation_token, context, pipeline_config, map_vtx_config)
-                    .await
-                    .unwrap();
+                start_map_forwarder(
+                    cancellation_token,
+                    ISBService::JetStream(context),
+                    pipeline_config,
+                    map_vtx_config,
+                )
+                .await
+                .unwrap();
             }
         });
 
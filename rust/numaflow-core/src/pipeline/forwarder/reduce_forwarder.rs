@@ -8,11 +8,13 @@ use crate::metrics::{
     ComponentHealthChecks, LagReader, MetricsState, PipelineComponents, WatermarkFetcherState,
 };
 use crate::pipeline::PipelineContext;
-use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
+use crate::pipeline::isb::jetstream::JetStreamFactory;
 use crate::pipeline::isb::reader::{ISBReaderComponents, ISBReaderOrchestrator};
 use crate::pipeline::isb::writer::{ISBWriterOrchestrator, ISBWriterOrchestratorComponents};
+use crate::pipeline::isb::{ISBFactory, ISBService};
 use crate::reduce::pbq::{PBQ, PBQBuilder, WAL};
-use crate::reduce::reducer::aligned::reducer::AlignedReducer;
+use crate::reduce::reducer::aligned::builtin::BuiltinAlignedReduce;
+use crate::reduce::reducer::aligned::reducer::{AlignedReduceFn, AlignedReducer};
 use crate::reduce::reducer::aligned::windower::AlignedWindowManager;
 use crate::reduce::reducer::aligned::windower::fixed::FixedWindowManager;
 use crate::reduce::reducer::aligned::windower::sliding::SlidingWindowManager;
@@ -24,13 +26,13 @@ use crate::reduce::reducer::user_defined::UserDefinedReduce;
 use crate::reduce::reducer::{Reducer, WindowManager};
 use crate::reduce::wal::create_wal_components;
 use crate::reduce::wal::segment::compactor::WindowKind;
+use crate::reduce::wal::snapshot::{SnapshotConfig, WINDOW_SNAPSHOT_FILE};
 use crate::shared::create_components;
 use crate::shared::metrics::start_metrics_server;
 use crate::tracker::Tracker;
 use crate::typ::{NumaflowTypeConfig, WithoutRateLimiter};
 use crate::watermark::WatermarkHandle;
 use crate::{Result, shared};
-use async_nats::jetstream::Context;
 use std::path::PathBuf;
 use std::time::Duration;
 use tokio::fs;
@@ -90,13 +92,17 @@ impl<C: NumaflowTypeConfig> ReduceForwarder<C> {
     }
 }
 
-pub(crate) async fn start_aligned_reduce_forwarder(
+pub(crate) async fn start_aligned_reduce_forwarder<F>(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: &ISBService,
+    isb_factory: F,
     config: PipelineConfig,
     reduce_vtx_config: ReduceVtxConfig,
     aligned_config: AlignedReducerConfig,
-) -> Result<()> {
+) -> Result<()>
+where
+    F: ISBFactory + Clone + 'static,
+{
     // for reduce we do not pass serving callback handler to tracker.
     let tracker = Tracker::new(None, cln_token.clone());
 
@@ -128,7 +134,7 @@ pub(crate) async fn start_aligned_reduce_forwarder(
     // create watermark handle, if watermark is enabled
     let watermark_handle = create_components::create_edge_watermark_handle(
         &config,
-        &js_context,
+        isb,
         &cln_token,
         Some(WindowManager::Aligned(window_manager.clone())),
         tracker.clone(),
@@ -151,25 +157,20 @@ pub(crate) async fn start_aligned_reduce_forwarder(
             crate::error::Error::Config("No stream found for reduce vertex".to_string())
         })?;
 
-    // Create the ISB factory from the JetStream context
-    use crate::pipeline::isb::jetstream::JetStreamFactory;
-    let isb_factory = JetStreamFactory::new(js_context.clone());
-
-    let context = PipelineContext::<WithoutRateLimiter, _>::new(
+    let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
         cln_token.clone(),
         &isb_factory,
         &config,
         tracker.clone(),
     );
 
-    let reader_components = ISBReaderComponents::new::<WithoutRateLimiter, _>(
+    let reader_components = ISBReaderComponents::new::<WithoutRateLimiter<F>, _>(
         stream,
         reader_config.clone(),
         watermark_handle.clone(),
         &context,
     );
 
-    use crate::pipeline::isb::ISBFactory;
     let writers = isb_factory
         .create_writers(
             &config.to_vertex_config,
@@ -178,7 +179,7 @@ pub(crate) async fn start_aligned_reduce_forwarder(
         )
         .await?;
 
-    let writer_components: ISBWriterOrchestratorComponents<WithoutRateLimiter> =
+    let writer_components: ISBWriterOrchestratorComponents<WithoutRateLimiter<F>> =
         ISBWriterOrchestratorComponents {
             config: config.to_vertex_config.clone(),
             writers,
@@ -187,7 +188,7 @@ pub(crate) async fn start_aligned_reduce_forwarder(
             vertex_type: config.vertex_type,
         };
 
-    let buffer_writer = ISBWriterOrchestrator::<WithoutRateLimiter>::new(writer_components);
+    let buffer_writer = ISBWriterOrchestrator::<WithoutRateLimiter<F>>::new(writer_components);
 
     // Create WAL if configured
     let (wal, gc_wal) = create_wal_components(
@@ -197,18 +198,30 @@ pub(crate) async fn start_aligned_reduce_forwarder(
     .await?;
 
     // Create PBQ
-    // Create user-defined aligned reducer client
-    let reducer_client =
-        create_components::create_aligned_reducer(aligned_config.clone(), cln_token.clone())
+    // Create the builtin reducer if configured, else the user-defined aligned reducer client
+    let (reduce_fn, reduce_component) = match &aligned_config.builtin_config {
+        Some(builtin_config) => (
+            AlignedReduceFn::Builtin(BuiltinAlignedReduce::new(builtin_config.clone())),
+            PipelineComponents::BuiltinReduce,
+        ),
+        None => {
+            let reducer_client = create_components::create_aligned_reducer(
+                aligned_config.clone(),
+                cln_token.clone(),
+            )
             .await?;
+            (
+                AlignedReduceFn::UserDefined(reducer_client.clone()),
+                PipelineComponents::Reduce(UserDefinedReduce::Aligned(reducer_client)),
+            )
+        }
+    };
 
     // Start the metrics server with one of the clients
     start_metrics_server::<WithoutRateLimiter>(
         config.metrics_config.clone(),
         MetricsState {
-            health_checks: ComponentHealthChecks::Pipeline(Box::new(PipelineComponents::Reduce(
-                UserDefinedReduce::Aligned(reducer_client.clone()),
-            ))),
+            health_checks: ComponentHealthChecks::Pipeline(Box::new(reduce_component)),
             watermark_fetcher_state: watermark_handle
                 .clone()
                 .map(|handle| WatermarkFetcherState {
@@ -221,18 +234,20 @@ pub(crate) async fn start_aligned_reduce_forwarder(
 
     let reducer = Reducer::Aligned(
         AlignedReducer::new(
-            reducer_client,
+            reduce_fn,
             window_manager,
             buffer_writer,
             gc_wal,
             aligned_config.window_config.allowed_lateness,
+            aligned_config.window_config.late_data,
+            aligned_config.window_config.trigger,
             config.graceful_shutdown_time,
             reduce_vtx_config.keyed,
         )
         .await,
     );
 
-    let context = PipelineContext::<WithoutRateLimiter, _>::new(
+    let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
         cln_token.clone(),
         &isb_factory,
         &config,
@@ -240,20 +255,30 @@ pub(crate) async fn start_aligned_reduce_forwarder(
     );
 
     // rate limit is not applicable for reduce
-    run_reduce_forwarder::<WithoutRateLimiter, _>(&context, reader_components, reducer, wal, None)
-        .await?;
+    run_reduce_forwarder::<WithoutRateLimiter<F>, _>(
+        &context,
+        reader_components,
+        reducer,
+        wal,
+        None,
+    )
+    .await?;
 
     info!("Aligned reduce forwarder has stopped successfully");
     Ok(())
 }
 
-pub(crate) async fn start_unaligned_reduce_forwarder(
+pub(crate) async fn start_unaligned_reduce_forwarder<F>(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: &ISBService,
+    isb_factory: F,
     config: PipelineConfig,
     reduce_vtx_config: ReduceVtxConfig,
     unaligned_config: UnalignedReducerConfig,
-) -> Result<()> {
+) -> Result<()>
+where
+    F: ISBFactory + Clone + 'static,
+{
     // for reduce we do not pass serving callback handler to tracker.
     let tracker = Tracker::new(None, cln_token.clone());
 
@@ -272,7 +297,7 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
     // create watermark handle, if watermark is enabled
     let watermark_handle = create_components::create_edge_watermark_handle(
         &config,
-        &js_context,
+        isb,
         &cln_token,
         Some(WindowManager::Unaligned(window_manager.clone())),
         tracker.clone(),
@@ -295,25 +320,20 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
             crate::error::Error::Config("No stream found for reduce vertex".to_string())
         })?;
 
-    // Create the ISB factory from the JetStream context
-    use crate::pipeline::isb::jetstream::JetStreamFactory;
-    let isb_factory = JetStreamFactory::new(js_context.clone());
-
-    let context = PipelineContext::<WithoutRateLimiter, _>::new(
+    let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
         cln_token.clone(),
         &isb_factory,
         &config,
         tracker.clone(),
     );
 
-    let reader_components = ISBReaderComponents::new::<WithoutRateLimiter, _>(
+    let reader_components = ISBReaderComponents::new::<WithoutRateLimiter<F>, _>(
         stream,
         reader_config.clone(),
         watermark_handle.clone(),
         &context,
     );
 
-    use crate::pipeline::isb::ISBFactory;
     let writers = isb_factory
         .create_writers(
             &config.to_vertex_config,
@@ -322,7 +342,7 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
         )
         .await?;
 
-    let writer_components: ISBWriterOrchestratorComponents<WithoutRateLimiter> =
+    let writer_components: ISBWriterOrchestratorComponents<WithoutRateLimiter<F>> =
         ISBWriterOrchestratorComponents {
             config: config.to_vertex_config.clone(),
             writers,
@@ -331,7 +351,7 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
             vertex_type: config.vertex_type,
         };
 
-    let buffer_writer = ISBWriterOrchestrator::<WithoutRateLimiter>::new(writer_components);
+    let buffer_writer = ISBWriterOrchestrator::<WithoutRateLimiter<F>>::new(writer_components);
 
     // Create WAL if configured (use Unaligned WindowKind for unaligned reducers)
     let (wal, gc_wal) = create_wal_components(
@@ -340,6 +360,16 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
     )
     .await?;
 
+    // Save the snapshots of the windows next to the WAL segments, if enabled
+    let snapshot_config = reduce_vtx_config
+        .wal_storage_config
+        .as_ref()
+        .filter(|storage_config| storage_config.snapshot_interval_secs > 0)
+        .map(|storage_config| SnapshotConfig {
+            path: storage_config.path.join(WINDOW_SNAPSHOT_FILE),
+            interval: Duration::from_secs(storage_config.snapshot_interval_secs),
+        });
+
     // Create user-defined unaligned reducer client
     let reducer_client =
         create_components::create_unaligned_reducer(unaligned_config.clone(), cln_token.clone())
@@ -367,14 +397,16 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
             window_manager,
             buffer_writer,
             unaligned_config.window_config.allowed_lateness,
+            unaligned_config.window_config.late_data,
             gc_wal,
+            snapshot_config,
             config.graceful_shutdown_time,
             reduce_vtx_config.keyed,
         )
         .await,
     );
 
-    let context = PipelineContext::<WithoutRateLimiter, _>::new(
+    let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
         cln_token.clone(),
         &isb_factory,
         &config,
@@ -382,8 +414,14 @@ pub(crate) async fn start_unaligned_reduce_forwarder(
     );
 
     // rate limit is not applicable for reduce
-    run_reduce_forwarder::<WithoutRateLimiter, _>(&context, reader_components, reducer, wal, None)
-        .await?;
+    run_reduce_forwarder::<WithoutRateLimiter<F>, _>(
+        &context,
+        reader_components,
+        reducer,
+        wal,
+        None,
+    )
+    .await?;
 
     info!("Unaligned reduce forwarder has stopped successfully");
     Ok(())
@@ -398,7 +436,7 @@ async fn run_reduce_forwarder<C, F>(
     rate_limiter: Option<C::RateLimiter>,
 ) -> Result<()>
 where
-    C: NumaflowTypeConfig<ISBReader = JetStreamReader>,
+    C: NumaflowTypeConfig,
     F: crate::pipeline::isb::ISBFactory<Reader = C::ISBReader, Writer = C::ISBWriter>,
 {
     let isb_reader_impl = context
@@ -498,7 +536,7 @@ pub async fn wait_for_fence_availability(
 
 pub(crate) async fn start_reduce_forwarder(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: ISBService,
     config: PipelineConfig,
     reduce_vtx_config: ReduceVtxConfig,
 ) -> crate::error::Result<()> {
@@ -520,11 +558,41 @@ pub(crate) async fn start_reduce_forwarder(
         None
     };
 
+    match &isb {
+        ISBService::JetStream(js_context) => {
+            let isb_factory = JetStreamFactory::new(js_context.clone());
+            start_reducer(cln_token, &isb, isb_factory, config, reduce_vtx_config).await
+        }
+        ISBService::Redis(isb_factory) => {
+            start_reducer(
+                cln_token,
+                &isb,
+                isb_factory.clone(),
+                config,
+                reduce_vtx_config,
+            )
+            .await
+        }
+    }
+}
+
+/// Starts the aligned or unaligned reduce forwarder on the ISB of the factory.
+async fn start_reducer<F>(
+    cln_token: CancellationToken,
+    isb: &ISBService,
+    isb_factory: F,
+    config: PipelineConfig,
+    reduce_vtx_config: ReduceVtxConfig,
+) -> crate::error::Result<()>
+where
+    F: ISBFactory + Clone + 'static,
+{
     match &reduce_vtx_config.reducer_config {
         ReducerConfig::Aligned(aligned_config) => {
             start_aligned_reduce_forwarder(
                 cln_token,
-                js_context,
+                isb,
+                isb_factory,
                 config,
                 reduce_vtx_config.clone(),
                 aligned_config.clone(),
@@ -534,7 +602,8 @@ pub(crate) async fn start_reduce_forwarder(
         ReducerConfig::Unaligned(unaligned_config) => {
             start_unaligned_reduce_forwarder(
                 cln_token,
-                js_context,
+                isb,
+                isb_factory,
                 config,
                 reduce_vtx_config.clone(),
                 unaligned_config.clone(),
@@ -560,6 +629,8 @@ mod tests {
         FenceGuard, start_aligned_reduce_forwarder, start_unaligned_reduce_forwarder,
         wait_for_fence_availability,
     };
+    use crate::pipeline::isb::ISBService;
+    use crate::pipeline::isb::jetstream::JetStreamFactory;
     use async_nats::jetstream::consumer::PullConsumer;
     use async_nats::jetstream::kv::Config;
     use async_nats::jetstream::{self, consumer, stream};
@@ -730,12 +801,15 @@ mod tests {
                             }),
                             allowed_lateness: Duration::from_secs(0),
                             is_keyed: true,
+                            late_data: None,
+                            trigger: None,
                         },
                         user_defined_config: UserDefinedConfig {
                             grpc_max_message_size: 5 * 1024 * 1024,
                             socket_path: Box::leak(sock_file_str.into_boxed_str()),
                             server_info_path: Box::leak(server_info_file_str.into_boxed_str()),
                         },
+                        builtin_config: None,
                     },
                 ),
             }),
@@ -852,7 +926,8 @@ mod tests {
             async move {
                 start_aligned_reduce_forwarder(
                     cancellation_token,
-                    js_context,
+                    &ISBService::JetStream(js_context.clone()),
+                    JetStreamFactory::new(js_context),
                     pipeline_config,
                     reduce_vtx_config,
                     aligned_config,
@@ -1102,6 +1177,7 @@ mod tests {
                                 ),
                             allowed_lateness: Duration::from_secs(0),
                             is_keyed: true,
+                            late_data: None,
                         },
                     },
                 ),
@@ -1180,7 +1256,8 @@ mod tests {
             async move {
                 if let Err(e) = start_unaligned_reduce_forwarder(
                     cancellation_token,
-                    js_context,
+                    &ISBService::JetStream(js_context.clone()),
+                    JetStreamFactory::new(js_context),
                     pipeline_config,
                     reduce_vtx_config,
                     unaligned_config,
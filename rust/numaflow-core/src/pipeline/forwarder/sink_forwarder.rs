@@ -7,8 +7,9 @@ use crate::metrics::{
     WatermarkFetcherState,
 };
 use crate::pipeline::PipelineContext;
-use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
+use crate::pipeline::isb::jetstream::JetStreamFactory;
 use crate::pipeline::isb::reader::{ISBReaderComponents, ISBReaderOrchestrator};
+use crate::pipeline::isb::{ISBFactory, ISBService};
 use crate::shared::create_components;
 use crate::shared::metrics::start_metrics_server;
 use crate::sinker::sink::SinkWriter;
@@ -17,13 +18,13 @@ use crate::sinker::sink::serve::nats::NatsServingStore;
 use crate::sinker::sink::serve::user_defined::UserDefinedStore;
 use crate::tracker::Tracker;
 use crate::typ::{
-    NumaflowTypeConfig, WithInMemoryRateLimiter, WithRedisRateLimiter, WithoutRateLimiter,
-    build_in_memory_rate_limiter_config, build_redis_rate_limiter_config,
+    NumaflowTypeConfig, WithInMemoryRateLimiter, WithJetStreamRateLimiter, WithRedisRateLimiter,
+    WithoutRateLimiter, build_in_memory_rate_limiter_config, build_jetstream_rate_limiter_config,
+    build_redis_rate_limiter_config, should_use_jetstream_rate_limiter,
     should_use_redis_rate_limiter,
 };
 use crate::watermark::WatermarkHandle;
 use crate::{Result, shared};
-use async_nats::jetstream::Context;
 use futures::future::try_join_all;
 use serving::callback::CallbackHandler;
 use tokio_util::sync::CancellationToken;
@@ -81,7 +82,7 @@ impl<C: crate::typ::NumaflowTypeConfig> SinkForwarder<C> {
 
 pub async fn start_sink_forwarder(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: ISBService,
     config: PipelineConfig,
     sink: SinkVtxConfig,
 ) -> Result<()> {
@@ -90,7 +91,7 @@ pub async fn start_sink_forwarder(
         Some(
             CallbackHandler::new(
                 config.vertex_name,
-                js_context.clone(),
+                isb.jetstream("Serving")?.clone(),
                 cb_cfg.callback_store,
                 cb_cfg.callback_concurrency,
             )
@@ -115,7 +116,7 @@ pub async fn start_sink_forwarder(
     let tracker = Tracker::new(serving_callback_handler.clone(), cln_token.clone());
     let watermark_handle = create_components::create_edge_watermark_handle(
         &config,
-        &js_context,
+        &isb,
         &cln_token,
         None,
         tracker.clone(),
@@ -131,80 +132,45 @@ pub async fn start_sink_forwarder(
             }
             ServingStoreType::Nats(config) => {
                 let serving_store =
-                    NatsServingStore::new(js_context.clone(), config.clone()).await?;
+                    NatsServingStore::new(isb.jetstream("Serving")?.clone(), config.clone())
+                        .await?;
                 Some(ServingStore::Nats(Box::new(serving_store)))
             }
         },
         None => None,
     };
 
-    // Create the ISB factory from the JetStream context
-    use crate::pipeline::isb::jetstream::JetStreamFactory;
-    let isb_factory = JetStreamFactory::new(js_context);
-
-    // 2. Clean dispatch logic
-    let (forwarder_tasks, first_sink_writer, _pending_reader_task) =
-        if let Some(rate_limit_config) = &config.rate_limit {
-            if should_use_redis_rate_limiter(rate_limit_config) {
-                let redis_config =
-                    build_redis_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
-
-                let context = PipelineContext::<WithRedisRateLimiter, _>::new(
-                    cln_token.clone(),
-                    &isb_factory,
-                    &config,
-                    tracker.clone(),
-                );
-
-                run_all_sink_forwarders::<WithRedisRateLimiter, _>(
-                    &context,
-                    &sink,
-                    reader_config,
-                    watermark_handle.clone(),
-                    serving_store,
-                    Some(redis_config.throttling_config),
-                )
-                .await?
-            } else {
-                let in_mem_config =
-                    build_in_memory_rate_limiter_config(rate_limit_config, cln_token.clone())
-                        .await?;
-
-                let context = PipelineContext::<WithInMemoryRateLimiter, _>::new(
-                    cln_token.clone(),
-                    &isb_factory,
-                    &config,
-                    tracker.clone(),
-                );
-
-                run_all_sink_forwarders::<WithInMemoryRateLimiter, _>(
-                    &context,
-                    &sink,
-                    reader_config,
-                    watermark_handle.clone(),
-                    serving_store,
-                    Some(in_mem_config.throttling_config),
-                )
-                .await?
-            }
-        } else {
-            let context = PipelineContext::<WithoutRateLimiter, _>::new(
-                cln_token.clone(),
+    let (forwarder_tasks, first_sink_writer, _pending_reader_task) = match &isb {
+        ISBService::JetStream(js_context) => {
+            let isb_factory = JetStreamFactory::new(js_context.clone());
+            run_sink_forwarders(
                 &isb_factory,
+                &isb,
+                &cln_token,
                 &config,
-                tracker.clone(),
-            );
-
-            run_all_sink_forwarders::<WithoutRateLimiter, _>(
-                &context,
+                &tracker,
                 &sink,
                 reader_config,
                 watermark_handle.clone(),
                 serving_store,
-                None,
             )
             .await?
-        };
+        }
+        ISBService::Redis(isb_factory) => {
+            run_sink_forwarders(
+                isb_factory,
+                &isb,
+                &cln_token,
+                &config,
+                &tracker,
+                &sink,
+                reader_config,
+                watermark_handle.clone(),
+                serving_store,
+            )
+            .await?
+        }
+    };
 
     start_metrics_server::<WithoutRateLimiter>(
         config.metrics_config.clone(),
@@ -233,6 +199,112 @@ pub async fn start_sink_forwarder(
     Ok(())
 }
 
+/// Starts the sink forwarders on the ISB of the factory with the configured rate limiter.
+#[allow(clippy::too_many_arguments)]
+async fn run_sink_forwarders<F>(
+    isb_factory: &F,
+    isb: &ISBService,
+    cln_token: &CancellationToken,
+    config: &PipelineConfig,
+    tracker: &Tracker,
+    sink: &SinkVtxConfig,
+    reader_config: &BufferReaderConfig,
+    watermark_handle: Option<crate::watermark::isb::ISBWatermarkHandle>,
+    serving_store: Option<ServingStore>,
+) -> Result<(
+    Vec<tokio::task::JoinHandle<Result<()>>>,
+    SinkWriter,
+    PendingReaderTasks,
+)>
+where
+    F: ISBFactory + Clone + 'static,
+{
+    if let Some(rate_limit_config) = &config.rate_limit {
+        if should_use_redis_rate_limiter(rate_limit_config) {
+            let redis_config =
+                build_redis_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
+
+            let context = PipelineContext::<WithRedisRateLimiter<F>, _>::new(
+                cln_token.clone(),
+                isb_factory,
+                config,
+                tracker.clone(),
+            );
+
+            run_all_sink_forwarders::<WithRedisRateLimiter<F>, _>(
+                &context,
+                sink,
+                reader_config,
+                watermark_handle.clone(),
+                serving_store,
+                Some(redis_config.throttling_config),
+            )
+            .await
+        } else if should_use_jetstream_rate_limiter(rate_limit_config) {
+            let js_config = build_jetstream_rate_limiter_config(
+                rate_limit_config,
+                isb.jetstream("The JetStream rate limiter")?,
+                cln_token.clone(),
+            )
+            .await?;
+
+            let context = PipelineContext::<WithJetStreamRateLimiter<F>, _>::new(
+                cln_token.clone(),
+                isb_factory,
+                config,
+                tracker.clone(),
+            );
+
+            run_all_sink_forwarders::<WithJetStreamRateLimiter<F>, _>(
+                &context,
+                sink,
+                reader_config,
+                watermark_handle.clone(),
+                serving_store,
+                Some(js_config.throttling_config),
+            )
+            .await
+        } else {
+            let in_mem_config =
+                build_in_memory_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
+
+            let context = PipelineContext::<WithInMemoryRateLimiter<F>, _>::new(
+                cln_token.clone(),
+                isb_factory,
+                config,
+                tracker.clone(),
+            );
+
+            run_all_sink_forwarders::<WithInMemoryRateLimiter<F>, _>(
+                &context,
+                sink,
+                reader_config,
+                watermark_handle.clone(),
+                serving_store,
+                Some(in_mem_config.throttling_config),
+            )
+            .await
+        }
+    } else {
+        let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
+            cln_token.clone(),
+            isb_factory,
+            config,
+            tracker.clone(),
+        );
+
+        run_all_sink_forwarders::<WithoutRateLimiter<F>, _>(
+            &context,
+            sink,
+            reader_config,
+            watermark_handle.clone(),
+            serving_store,
+            None,
+        )
+        .await
+    }
+}
+
 /// Starts sink forwarder for all the streams.
 async fn run_all_sink_forwarders<C, F>(
     context: &PipelineContext<'_, C, F>,
@@ -247,7 +319,7 @@ async fn run_all_sink_forwarders<C, F>(
     PendingReaderTasks,
 )>
 where
-    C: NumaflowTypeConfig<ISBReader = JetStreamReader>,
+    C: NumaflowTypeConfig,
     F: crate::pipeline::isb::ISBFactory<Reader = C::ISBReader, Writer = C::ISBWriter>,
 {
     let mut forwarder_tasks = vec![];
@@ -321,7 +393,7 @@ async fn run_sink_forwarder_for_stream<C, F>(
     ISBReaderOrchestrator<C>,
 )>
 where
-    C: NumaflowTypeConfig<ISBReader = JetStreamReader>,
+    C: NumaflowTypeConfig,
     F: crate::pipeline::isb::ISBFactory<Reader = C::ISBReader, Writer = C::ISBWriter>,
 {
     let cln_token = reader_components.cln_token.clone();
@@ -445,11 +517,11 @@ mod tests {
             batch_size: 1000,
             writer_concurrency: 1000,
             read_timeout: Duration::from_secs(1),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             to_vertex_config: vec![],
             from_vertex_config: vec![FromVertexConfig {
                 name: "in",
@@ -465,6 +537,7 @@ mod tests {
                 sink_config: SinkConfig {
                     sink_type: SinkType::Blackhole(BlackholeConfig::default()),
                     retry_config: None,
+                    dlq_headers: false,
                 },
                 fb_sink_config: None,
                 on_success_sink_config: None,
@@ -494,7 +567,7 @@ mod tests {
             async move {
                 start_sink_forwarder(
                     cancellation_token,
-                    context,
+                    ISBService::JetStream(context),
                     pipeline_config,
                     sink_vtx_config,
                 )
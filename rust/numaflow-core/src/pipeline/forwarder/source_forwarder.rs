@@ -6,22 +6,23 @@ use crate::metrics::{
     WatermarkFetcherState,
 };
 use crate::pipeline::PipelineContext;
-
+use crate::pipeline::isb::jetstream::JetStreamFactory;
 use crate::pipeline::isb::writer::{ISBWriterOrchestrator, ISBWriterOrchestratorComponents};
+use crate::pipeline::isb::{ISBFactory, ISBService};
 use crate::shared::create_components;
 use crate::shared::metrics::start_metrics_server;
 use crate::source::Source;
 use crate::tracker::Tracker;
 use crate::transformer::Transformer;
 use crate::typ::{
-    WithInMemoryRateLimiter, WithRedisRateLimiter, WithoutRateLimiter,
-    build_in_memory_rate_limiter_config, build_redis_rate_limiter_config,
+    WithInMemoryRateLimiter, WithJetStreamRateLimiter, WithRedisRateLimiter, WithoutRateLimiter,
+    build_in_memory_rate_limiter_config, build_jetstream_rate_limiter_config,
+    build_redis_rate_limiter_config, should_use_jetstream_rate_limiter,
     should_use_redis_rate_limiter,
 };
 use crate::watermark::WatermarkHandle;
 use crate::watermark::source::SourceWatermarkHandle;
 use crate::{error, shared};
-use async_nats::jetstream::Context;
 use serving::callback::CallbackHandler;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
@@ -68,7 +69,7 @@ impl<C: crate::typ::NumaflowTypeConfig> SourceForwarder<C> {
 
 pub(crate) async fn start_source_forwarder(
     cln_token: CancellationToken,
-    js_context: Context,
+    isb: ISBService,
     config: PipelineConfig,
     source_config: SourceVtxConfig,
     source_watermark_handle: Option<SourceWatermarkHandle>,
@@ -77,7 +78,7 @@ pub(crate) async fn start_source_forwarder(
         Some(
             CallbackHandler::new(
                 config.vertex_name,
-                js_context.clone(),
+                isb.jetstream("Serving")?.clone(),
                 cb_cfg.callback_store,
                 cb_cfg.callback_concurrency,
             )
@@ -89,11 +90,61 @@ pub(crate) async fn start_source_forwarder(
 
     let tracker = Tracker::new(serving_callback_handler, cln_token.clone());
 
-    // Create the ISB factory from the JetStream context
-    use crate::pipeline::isb::ISBFactory;
-    use crate::pipeline::isb::jetstream::JetStreamFactory;
-    let isb_factory = JetStreamFactory::new(js_context.clone());
+    let transformer = create_components::create_transformer(
+        config.batch_size,
+        config.graceful_shutdown_time,
+        source_config.transformer_config.clone(),
+        tracker.clone(),
+        cln_token.clone(),
+    )
+    .await?;
+
+    match &isb {
+        ISBService::JetStream(js_context) => {
+            let isb_factory = JetStreamFactory::new(js_context.clone());
+            run_source_forwarders(
+                &isb_factory,
+                &isb,
+                &cln_token,
+                &config,
+                &tracker,
+                &source_config,
+                transformer,
+                source_watermark_handle,
+            )
+            .await
+        }
+        ISBService::Redis(isb_factory) => {
+            run_source_forwarders(
+                isb_factory,
+                &isb,
+                &cln_token,
+                &config,
+                &tracker,
+                &source_config,
+                transformer,
+                source_watermark_handle,
+            )
+            .await
+        }
+    }
+}
 
+/// Starts the source forwarder on the ISB of the factory with the configured rate limiter.
+#[allow(clippy::too_many_arguments)]
+async fn run_source_forwarders<F>(
+    isb_factory: &F,
+    isb: &ISBService,
+    cln_token: &CancellationToken,
+    config: &PipelineConfig,
+    tracker: &Tracker,
+    source_config: &SourceVtxConfig,
+    transformer: Option<Transformer>,
+    source_watermark_handle: Option<SourceWatermarkHandle>,
+) -> error::Result<()>
+where
+    F: ISBFactory + Clone + 'static,
+{
     let writers = isb_factory
         .create_writers(
             &config.to_vertex_config,
@@ -117,82 +168,96 @@ pub(crate) async fn start_source_forwarder(
         }};
     }
 
-    let transformer = create_components::create_transformer(
-        config.batch_size,
-        config.graceful_shutdown_time,
-        source_config.transformer_config.clone(),
-        tracker.clone(),
-        cln_token.clone(),
-    )
-    .await?;
-
     // Apply rate limiting dispatch pattern similar to other forwarders
     if let Some(rate_limit_config) = &config.rate_limit {
         if should_use_redis_rate_limiter(rate_limit_config) {
             let redis_config =
                 build_redis_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
-            let buffer_writer = create_writer!(WithRedisRateLimiter);
+            let buffer_writer = create_writer!(WithRedisRateLimiter<F>);
 
-            let context = PipelineContext::<WithRedisRateLimiter, _>::new(
+            let context = PipelineContext::<WithRedisRateLimiter<F>, _>::new(
                 cln_token.clone(),
-                &isb_factory,
-                &config,
+                isb_factory,
+                config,
                 tracker.clone(),
             );
 
-            run_source_forwarder::<WithRedisRateLimiter, _>(
+            run_source_forwarder::<WithRedisRateLimiter<F>, _>(
                 &context,
-                &source_config,
+                source_config,
                 transformer,
                 source_watermark_handle,
                 buffer_writer,
                 Some(redis_config.throttling_config),
             )
-            .await?
+            .await
+        } else if should_use_jetstream_rate_limiter(rate_limit_config) {
+            let js_config = build_jetstream_rate_limiter_config(
+                rate_limit_config,
+                isb.jetstream("The JetStream rate limiter")?,
+                cln_token.clone(),
+            )
+            .await?;
+            let buffer_writer = create_writer!(WithJetStreamRateLimiter<F>);
+
+            let context = PipelineContext::<WithJetStreamRateLimiter<F>, _>::new(
+                cln_token.clone(),
+                isb_factory,
+                config,
+                tracker.clone(),
+            );
+
+            run_source_forwarder::<WithJetStreamRateLimiter<F>, _>(
+                &context,
+                source_config,
+                transformer,
+                source_watermark_handle,
+                buffer_writer,
+                Some(js_config.throttling_config),
+            )
+            .await
         } else {
             let in_mem_config =
                 build_in_memory_rate_limiter_config(rate_limit_config, cln_token.clone()).await?;
-            let buffer_writer = create_writer!(WithInMemoryRateLimiter);
+            let buffer_writer = create_writer!(WithInMemoryRateLimiter<F>);
 
-            let context = PipelineContext::<WithInMemoryRateLimiter, _>::new(
+            let context = PipelineContext::<WithInMemoryRateLimiter<F>, _>::new(
                 cln_token.clone(),
-                &isb_factory,
-                &config,
+                isb_factory,
+                config,
                 tracker.clone(),
             );
 
-            run_source_forwarder::<WithInMemoryRateLimiter, _>(
+            run_source_forwarder::<WithInMemoryRateLimiter<F>, _>(
                 &context,
-                &source_config,
+                source_config,
                 transformer,
                 source_watermark_handle,
                 buffer_writer,
                 Some(in_mem_config.throttling_config),
             )
-            .await?
+            .await
         }
     } else {
-        let buffer_writer = create_writer!(WithoutRateLimiter);
+        let buffer_writer = create_writer!(WithoutRateLimiter<F>);
 
-        let context = PipelineContext::<WithoutRateLimiter, _>::new(
+        let context = PipelineContext::<WithoutRateLimiter<F>, _>::new(
             cln_token.clone(),
-            &isb_factory,
-            &config,
+            isb_factory,
+            config,
             tracker.clone(),
         );
 
-        run_source_forwarder::<WithoutRateLimiter, _>(
+        run_source_forwarder::<WithoutRateLimiter<F>, _>(
             &context,
-            &source_config,
+            source_config,
             transformer,
             source_watermark_handle,
             buffer_writer,
             None,
         )
-        .await?
-    };
-
-    Ok(())
+        .await
+    }
 }
 
 /// Starts source forwarder.
@@ -218,7 +283,8 @@ where
         context.cln_token.clone(),
         rate_limiter,
     )
-    .await?;
+    .await?
+    .with_keyed_rate_limiter(context.config.rate_limit.as_ref());
 
     // only check the pending and lag for source for pod_id = 0
     let _pending_reader_handle: Option<PendingReaderTasks> = if context.config.replica == 0 {
@@ -1060,11 +1126,11 @@ mod tests {
             batch_size: 1000,
             writer_concurrency: 30000,
             read_timeout: Duration::from_secs(1),
-            js_client_config: isb::jetstream::ClientConfig {
+            isb_client_config: isb::ISBClientConfig::JetStream(isb::jetstream::ClientConfig {
                 url: "localhost:4222".to_string(),
                 user: None,
                 password: None,
-            },
+            }),
             from_vertex_config: vec![],
             to_vertex_config: vec![ToVertexConfig {
                 name: "out",
@@ -1074,6 +1140,7 @@ mod tests {
                     max_length: 30000,
                     usage_limit: 0.8,
                     buffer_full_strategy: RetryUntilSuccess,
+                    batching: None,
                 },
                 conditions: None,
                 to_vertex_type: VertexType::Sink,
@@ -1124,7 +1191,7 @@ mod tests {
             async move {
                 start_source_forwarder(
                     cancellation_token,
-                    context,
+                    ISBService::JetStream(context),
                     pipeline_config,
                     source_vtx_config,
                     source_watermark_handle,
@@ -5,10 +5,18 @@
 
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::error::Result;
+use async_nats::jetstream::Context;
+use bytes::Bytes;
+use numaflow_shared::kv::KVStore;
+use numaflow_shared::kv::jetstream::JetstreamKVStore;
+
+use crate::config::pipeline::isb::{ISBClientConfig, Stream};
+use crate::error::{Error, Result};
 use crate::message::{Message, Offset};
+use crate::pipeline::isb::redis::factory::RedisStreamsFactory;
 
 /// A boxed future representing a pending write operation.
 /// This is returned by `ISBWriter::async_write()` and can be awaited
@@ -21,6 +29,7 @@ pub(crate) mod error;
 pub(crate) mod factory;
 pub(crate) mod jetstream;
 pub(crate) mod reader;
+pub(crate) mod redis;
 pub(crate) mod writer;
 // SimpleBuffer for integration tests
 #[cfg(test)]
@@ -28,6 +37,79 @@ pub(crate) mod simplebuffer;
 
 pub(crate) use factory::ISBFactory;
 
+/// The ISB service the pipeline runs on. Besides the streams, it holds the KV buckets of the
+/// watermarks and takes the control messages of the idle watermarks.
+#[derive(Clone)]
+pub(crate) enum ISBService {
+    JetStream(Context),
+    Redis(RedisStreamsFactory),
+}
+
+impl ISBService {
+    /// Connects to the ISB service of the client configuration.
+    pub(crate) async fn connect(config: &ISBClientConfig) -> Result<Self> {
+        match config {
+            ISBClientConfig::JetStream(config) => Ok(ISBService::JetStream(
+                crate::pipeline::create_js_context(config.clone()).await?,
+            )),
+            ISBClientConfig::Redis(config) => {
+                Ok(ISBService::Redis(RedisStreamsFactory::new(config.clone())?))
+            }
+        }
+    }
+
+    /// Returns the JetStream context for the features which are only available with the JetStream
+    /// ISB, e.g., serving.
+    pub(crate) fn jetstream(&self, feature: &str) -> Result<&Context> {
+        match self {
+            ISBService::JetStream(js_context) => Ok(js_context),
+            ISBService::Redis(_) => Err(Error::Config(format!(
+                "{feature} is not supported with the Redis ISB"
+            ))),
+        }
+    }
+
+    /// Returns the KV store of the bucket, the bucket is created by the controller.
+    pub(crate) async fn kv_store(&self, bucket: &'static str) -> Result<Arc<dyn KVStore>> {
+        match self {
+            ISBService::JetStream(js_context) => {
+                let kv = js_context.get_key_value(bucket).await.map_err(|e| {
+                    Error::Connection(format!("Failed to get bucket {bucket}: {e}"))
+                })?;
+                Ok(Arc::new(JetstreamKVStore::new(kv, bucket)))
+            }
+            ISBService::Redis(factory) => factory.kv_store(bucket).await,
+        }
+    }
+
+    /// Writes the encoded control message to the stream and returns its offset. `batched` tells
+    /// whether the messages of the stream are batched, which changes how the offsets are derived
+    /// from the JetStream sequence.
+    pub(crate) async fn publish_ctrl_message(
+        &self,
+        stream: &Stream,
+        payload: Bytes,
+        batched: bool,
+    ) -> Result<i64> {
+        match self {
+            ISBService::JetStream(js_context) => {
+                let sequence = js_context
+                    .publish(stream.name, payload)
+                    .await
+                    .map_err(|e| Error::Watermark(e.to_string()))?
+                    .await
+                    .map_err(|e| Error::Watermark(e.to_string()))?
+                    .sequence;
+                if batched {
+                    return Ok(jetstream::batch::offset(sequence, 0));
+                }
+                Ok(sequence as i64)
+            }
+            ISBService::Redis(factory) => factory.publish_ctrl_message(stream, payload).await,
+        }
+    }
+}
+
 /// Trait for reading messages from an Inter Step Buffer (ISB).
 ///
 /// Implementations handle the low-level details of fetching messages,
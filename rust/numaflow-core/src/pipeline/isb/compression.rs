@@ -1,12 +1,41 @@
 //!  Compression and Decompression before writing or after reading from ISB.
-
-use std::io::Read;
+//!
+//! Besides the fixed compression of every message with the configured [CompressionType], the
+//! [Codec] of an edge supports
+//! - adaptive compression, where the messages smaller than a minimum size are written uncompressed,
+//!   since they barely shrink and cost the CPU all the same.
+//! - zstd with trained dictionaries, which are read from a KV bucket. Small messages of a similar
+//!   shape (e.g., JSON events) compress much better with a dictionary trained on them.
+//!
+//! The writer records how a message deviates from the configured compression in the
+//! [COMPRESSION_HEADER] header, i.e., whether it is uncompressed or which dictionary version it is
+//! compressed with, and the reader removes the header after decompressing the message.
+
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::{Arc, RwLock, Weak};
+
+use bytes::Bytes;
+use numaflow_shared::kv::{KVStore, KVWatchOp};
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
+use tracing::{info, warn};
+use zstd::dict::{DecoderDictionary, EncoderDictionary};
 
 use crate::Result;
-use crate::config::pipeline::isb::CompressionType;
+use crate::config::pipeline::isb::{Compression, CompressionType};
 use crate::error::Error;
+use crate::message::Message;
+use crate::metrics::{isb_compression_metrics_labels, pipeline_metrics};
 use crate::pipeline::isb::error::ISBError;
 
+/// Header which records how the payload deviates from the configured compression, its value is
+/// either [UNCOMPRESSED] or [ZSTD_DICTIONARY_PREFIX] followed by the dictionary version.
+pub(crate) const COMPRESSION_HEADER: &str = "x-numaflow-compression";
+const UNCOMPRESSED: &str = "none";
+const ZSTD_DICTIONARY_PREFIX: &str = "zstd:";
+const ZSTD_LEVEL: i32 = 3;
+
 /// Compress data based on the compression type.
 pub(super) fn compress(compression_type: CompressionType, data: &[u8]) -> Result<Vec<u8>> {
     match compression_type {
@@ -31,7 +60,7 @@ pub(super) fn compress(compression_type: CompressionType, data: &[u8]) -> Result
             use std::io::Write;
             use zstd::Encoder;
 
-            let mut encoder = Encoder::new(Vec::new(), 3).map_err(|e| {
+            let mut encoder = Encoder::new(Vec::new(), ZSTD_LEVEL).map_err(|e| {
                 Error::ISB(ISBError::Encode(format!(
                     "Failed to create zstd encoder: {e:?}"
                 )))
@@ -116,6 +145,296 @@ pub(super) fn decompress(compression_type: CompressionType, data: &[u8]) -> Resu
     }
 }
 
+/// Compress data with zstd using the given dictionary.
+fn compress_with_dictionary(
+    dictionary: &EncoderDictionary<'static>,
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut encoder =
+        zstd::Encoder::with_prepared_dictionary(Vec::new(), dictionary).map_err(|e| {
+            Error::ISB(ISBError::Encode(format!(
+                "Failed to create zstd encoder with dictionary: {e:?}"
+            )))
+        })?;
+    encoder.write_all(data).map_err(|e| {
+        Error::ISB(ISBError::Encode(format!(
+            "Failed to compress message with zstd dictionary: {e}"
+        )))
+    })?;
+    encoder.finish().map_err(|e| {
+        Error::ISB(ISBError::Encode(format!(
+            "Failed to finish zstd compression: {e}"
+        )))
+    })
+}
+
+/// Decompress zstd data using the given dictionary.
+fn decompress_with_dictionary(
+    dictionary: &DecoderDictionary<'static>,
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    let mut decoder = zstd::Decoder::with_prepared_dictionary(BufReader::new(data), dictionary)
+        .map_err(|e| {
+            Error::ISB(ISBError::Decode(format!(
+                "Failed to create zstd decoder with dictionary: {e:?}"
+            )))
+        })?;
+    let mut decompressed = vec![];
+    decoder.read_to_end(&mut decompressed).map_err(|e| {
+        Error::ISB(ISBError::Decode(format!(
+            "Failed to decompress message: {e}"
+        )))
+    })?;
+    Ok(decompressed)
+}
+
+/// Codec compresses the messages written to an edge and decompresses the ones read from it, and
+/// records the compression metrics of the edge.
+#[derive(Debug, Clone)]
+pub(crate) struct Codec {
+    compression_type: CompressionType,
+    min_size: usize,
+    dictionaries: Option<ZstdDictionaries>,
+    labels: Arc<Vec<(String, String)>>,
+}
+
+impl Codec {
+    pub(crate) fn new(
+        buffer_name: &str,
+        compression: &Compression,
+        dictionaries: Option<ZstdDictionaries>,
+    ) -> Self {
+        Self {
+            compression_type: compression.compress_type,
+            min_size: compression.min_size,
+            dictionaries,
+            labels: Arc::new(isb_compression_metrics_labels(buffer_name)),
+        }
+    }
+
+    /// Compresses the value of the message in place. Messages smaller than the minimum size are
+    /// left uncompressed, and zstd uses the latest dictionary if there is one.
+    pub(crate) fn encode(&self, message: &mut Message) -> Result<()> {
+        if self.compression_type == CompressionType::None {
+            return Ok(());
+        }
+
+        let (header, value) = self.compress(&message.value)?;
+        if let Some(header) = header {
+            Arc::make_mut(&mut message.headers).insert(COMPRESSION_HEADER.to_string(), header);
+        }
+        message.value = value;
+        Ok(())
+    }
+
+    /// Compresses the given value, returns the value of the compression header (if it has to be
+    /// set) along with the compressed value.
+    pub(crate) fn compress(&self, value: &Bytes) -> Result<(Option<String>, Bytes)> {
+        if self.compression_type == CompressionType::None {
+            return Ok((None, value.clone()));
+        }
+
+        if value.len() < self.min_size {
+            pipeline_metrics()
+                .isb_compression
+                .skipped_total
+                .get_or_create(&self.labels)
+                .inc();
+            return Ok((Some(UNCOMPRESSED.to_string()), value.clone()));
+        }
+
+        let start = Instant::now();
+        let dictionary = match self.compression_type {
+            CompressionType::Zstd => self.dictionaries.as_ref().and_then(|d| d.latest()),
+            _ => None,
+        };
+        let (header, compressed) = match dictionary {
+            Some((version, dictionary)) => (
+                Some(format!("{ZSTD_DICTIONARY_PREFIX}{version}")),
+                compress_with_dictionary(&dictionary, value)?,
+            ),
+            None => (None, compress(self.compression_type, value)?),
+        };
+
+        let metrics = &pipeline_metrics().isb_compression;
+        metrics
+            .compress_time
+            .get_or_create(&self.labels)
+            .observe(start.elapsed().as_micros() as f64);
+        if !value.is_empty() {
+            metrics
+                .ratio
+                .get_or_create(&self.labels)
+                .observe(compressed.len() as f64 / value.len() as f64);
+        }
+
+        Ok((header, Bytes::from(compressed)))
+    }
+
+    /// Decompresses the payload of a message read from the edge, the compression header is
+    /// removed from the headers of the message.
+    pub(crate) async fn decode(
+        &self,
+        headers: &mut HashMap<String, String>,
+        payload: Vec<u8>,
+    ) -> Result<Bytes> {
+        let start = Instant::now();
+        let decompressed = match headers.remove(COMPRESSION_HEADER) {
+            Some(value) if value == UNCOMPRESSED => return Ok(payload.into()),
+            Some(value) => {
+                let version = value.strip_prefix(ZSTD_DICTIONARY_PREFIX).ok_or_else(|| {
+                    Error::ISB(ISBError::Decode(format!("Unknown compression {value}")))
+                })?;
+                let dictionary = self
+                    .dictionaries
+                    .as_ref()
+                    .ok_or_else(|| {
+                        Error::ISB(ISBError::Decode(format!(
+                            "Message is compressed with zstd dictionary {version}, but no dictionary bucket is configured"
+                        )))
+                    })?
+                    .decoder(version)
+                    .await?;
+                decompress_with_dictionary(&dictionary, &payload)?
+            }
+            None if self.compression_type == CompressionType::None => return Ok(payload.into()),
+            None => decompress(self.compression_type, &payload)?,
+        };
+
+        pipeline_metrics()
+            .isb_compression
+            .decompress_time
+            .get_or_create(&self.labels)
+            .observe(start.elapsed().as_micros() as f64);
+        Ok(decompressed.into())
+    }
+}
+
+/// Trained zstd dictionaries stored in a KV bucket, keyed by their version (a positive integer).
+/// The dictionary with the highest version is used for compressing, and every version seen is
+/// kept for decompressing the messages written with it.
+#[derive(Clone)]
+pub(crate) struct ZstdDictionaries {
+    store: Arc<dyn KVStore>,
+    state: Arc<RwLock<DictionaryState>>,
+}
+
+impl std::fmt::Debug for ZstdDictionaries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.read().expect("poisoned lock");
+        f.debug_struct("ZstdDictionaries")
+            .field("bucket", &self.store.name())
+            .field("latest", &state.latest.as_ref().map(|(version, _)| version))
+            .finish()
+    }
+}
+
+#[derive(Default)]
+struct DictionaryState {
+    latest: Option<(u64, Arc<EncoderDictionary<'static>>)>,
+    decoders: HashMap<u64, Arc<DecoderDictionary<'static>>>,
+}
+
+impl DictionaryState {
+    fn insert(&mut self, key: &str, dictionary: &[u8]) -> Option<Arc<DecoderDictionary<'static>>> {
+        let Ok(version) = key.parse::<u64>() else {
+            warn!(key, "Ignoring zstd dictionary with a non-numeric version");
+            return None;
+        };
+
+        let decoder = Arc::new(DecoderDictionary::copy(dictionary));
+        self.decoders.insert(version, Arc::clone(&decoder));
+        if self
+            .latest
+            .as_ref()
+            .is_none_or(|(latest, _)| version >= *latest)
+        {
+            info!(version, "Compressing with zstd dictionary");
+            self.latest = Some((
+                version,
+                Arc::new(EncoderDictionary::copy(dictionary, ZSTD_LEVEL)),
+            ));
+        }
+        Some(decoder)
+    }
+}
+
+impl ZstdDictionaries {
+    /// Loads the dictionaries in the store and keeps watching it for new versions.
+    pub(crate) async fn new(store: Arc<dyn KVStore>) -> Result<Self> {
+        let kv_error = |e| Error::ISB(ISBError::Other(format!("zstd dictionary store: {e}")));
+
+        let mut state = DictionaryState::default();
+        for key in store.keys().await.map_err(kv_error)? {
+            if let Some(dictionary) = store.get(&key).await.map_err(kv_error)? {
+                state.insert(&key, &dictionary);
+            }
+        }
+        let state = Arc::new(RwLock::new(state));
+
+        let mut watcher = store.watch(None).await.map_err(kv_error)?;
+        let weak_state: Weak<RwLock<DictionaryState>> = Arc::downgrade(&state);
+        tokio::spawn(async move {
+            while let Some(entry) = watcher.next().await {
+                // stop watching once the dictionaries are no longer used
+                let Some(state) = weak_state.upgrade() else {
+                    return;
+                };
+                if entry.operation == KVWatchOp::Put {
+                    state
+                        .write()
+                        .expect("poisoned lock")
+                        .insert(&entry.key, &entry.value);
+                }
+            }
+        });
+
+        Ok(Self { store, state })
+    }
+
+    /// Returns the latest version of the dictionary for compressing.
+    fn latest(&self) -> Option<(u64, Arc<EncoderDictionary<'static>>)> {
+        self.state.read().expect("poisoned lock").latest.clone()
+    }
+
+    /// Returns the given version of the dictionary for decompressing, it is fetched from the store
+    /// if this version hasn't been seen yet.
+    async fn decoder(&self, version: &str) -> Result<Arc<DecoderDictionary<'static>>> {
+        let decode_error = |msg: String| {
+            Error::ISB(ISBError::Decode(format!(
+                "zstd dictionary {version}: {msg}"
+            )))
+        };
+
+        let parsed: u64 = version
+            .parse()
+            .map_err(|_| decode_error("invalid version".to_string()))?;
+        if let Some(decoder) = self
+            .state
+            .read()
+            .expect("poisoned lock")
+            .decoders
+            .get(&parsed)
+        {
+            return Ok(Arc::clone(decoder));
+        }
+
+        let dictionary = self
+            .store
+            .get(version)
+            .await
+            .map_err(|e| decode_error(e.to_string()))?
+            .ok_or_else(|| decode_error("not found".to_string()))?;
+        self.state
+            .write()
+            .expect("poisoned lock")
+            .insert(version, &dictionary)
+            .ok_or_else(|| decode_error("invalid version".to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,4 +672,154 @@ mod tests {
         let decompressed = decompress(CompressionType::LZ4, &compressed).unwrap();
         assert_eq!(decompressed, test_data);
     }
+
+    fn codec(
+        compress_type: CompressionType,
+        min_size: usize,
+        dictionaries: Option<ZstdDictionaries>,
+    ) -> Codec {
+        let compression = Compression {
+            compress_type,
+            min_size,
+            ..Default::default()
+        };
+        Codec::new("test-buffer", &compression, dictionaries)
+    }
+
+    fn message(value: &[u8]) -> Message {
+        Message {
+            value: Bytes::copy_from_slice(value),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_codec_skips_small_messages() {
+        let codec = codec(CompressionType::Gzip, 64, None);
+
+        let mut small = message(b"tiny");
+        codec.encode(&mut small).unwrap();
+        assert_eq!(small.value.as_ref(), b"tiny");
+        assert_eq!(
+            small.headers.get(COMPRESSION_HEADER).map(String::as_str),
+            Some(UNCOMPRESSED)
+        );
+
+        let mut headers = small.headers.as_ref().clone();
+        let decoded = codec
+            .decode(&mut headers, small.value.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(decoded.as_ref(), b"tiny");
+        assert!(headers.is_empty());
+
+        let large_value = vec![b'A'; 128];
+        let mut large = message(&large_value);
+        codec.encode(&mut large).unwrap();
+        assert!(large.value.len() < large_value.len());
+        assert!(!large.headers.contains_key(COMPRESSION_HEADER));
+
+        let mut headers = large.headers.as_ref().clone();
+        let decoded = codec
+            .decode(&mut headers, large.value.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(decoded.as_ref(), large_value.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_codec_zstd_dictionary() {
+        use numaflow_testing::simplekvstore::SimpleKVStore;
+
+        let event = br#"{"user_id":"u-1","event":"page_view","page":"/checkout"}"#;
+        // any content works as a raw zstd dictionary, a trained one does better
+        let store = Arc::new(SimpleKVStore::new("zstd-dictionaries"));
+        store
+            .put("1", Bytes::from_static(b"page_view"))
+            .await
+            .unwrap();
+        store.put("2", Bytes::copy_from_slice(event)).await.unwrap();
+        store
+            .put("not-a-version", Bytes::from_static(b"ignored"))
+            .await
+            .unwrap();
+
+        let dictionaries = ZstdDictionaries::new(Arc::clone(&store) as Arc<dyn KVStore>)
+            .await
+            .unwrap();
+        let codec = codec(CompressionType::Zstd, 0, Some(dictionaries));
+
+        let mut with_dictionary = message(event);
+        codec.encode(&mut with_dictionary).unwrap();
+        assert_eq!(
+            with_dictionary
+                .headers
+                .get(COMPRESSION_HEADER)
+                .map(String::as_str),
+            Some("zstd:2")
+        );
+        let without_dictionary = compress(CompressionType::Zstd, event).unwrap();
+        assert!(with_dictionary.value.len() < without_dictionary.len());
+
+        let mut headers = with_dictionary.headers.as_ref().clone();
+        let decoded = codec
+            .decode(&mut headers, with_dictionary.value.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(decoded.as_ref(), event);
+        assert!(headers.is_empty());
+
+        // a reader without the dictionary can't decode the message
+        let plain = codec_without_dictionaries();
+        let mut headers = with_dictionary.headers.as_ref().clone();
+        assert!(
+            plain
+                .decode(&mut headers, with_dictionary.value.to_vec())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zstd_dictionary_versions() {
+        use numaflow_testing::simplekvstore::SimpleKVStore;
+
+        let store = Arc::new(SimpleKVStore::new("zstd-dictionary-versions"));
+        store
+            .put("1", Bytes::from_static(b"version one"))
+            .await
+            .unwrap();
+        let dictionaries = ZstdDictionaries::new(Arc::clone(&store) as Arc<dyn KVStore>)
+            .await
+            .unwrap();
+        assert_eq!(dictionaries.latest().map(|(v, _)| v), Some(1));
+
+        // a new version put after start up is picked up by the watcher
+        store
+            .put("3", Bytes::from_static(b"version three"))
+            .await
+            .unwrap();
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while dictionaries.latest().map(|(v, _)| v) != Some(3) {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("version 3 should be the latest");
+
+        // an older version is fetched on demand, but doesn't become the latest
+        store
+            .put("2", Bytes::from_static(b"version two"))
+            .await
+            .unwrap();
+        assert!(dictionaries.decoder("2").await.is_ok());
+        assert_eq!(dictionaries.latest().map(|(v, _)| v), Some(3));
+
+        assert!(dictionaries.decoder("4").await.is_err());
+        assert!(dictionaries.decoder("latest").await.is_err());
+    }
+
+    fn codec_without_dictionaries() -> Codec {
+        codec(CompressionType::Zstd, 0, None)
+    }
 }
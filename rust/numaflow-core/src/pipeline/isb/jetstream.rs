@@ -8,6 +8,9 @@ pub(crate) mod js_writer;
 /// the messages.
 pub(crate) mod js_reader;
 
+/// Batching packs multiple messages into a single JetStream message.
+pub(crate) mod batch;
+
 /// JetStream ISB Factory for creating readers and writers.
 pub(crate) mod factory;
 
@@ -0,0 +1,442 @@
+//! Batching packs multiple messages into a single JetStream message, to cut the number of publish
+//! RPCs and the per-message storage overhead of the stream for tiny messages. The batch is
+//! compressed as a whole, which also compresses much better than the individual messages.
+//!
+//! Every message of a batch gets its own offset, the stream sequence of the batch shifted left by
+//! [INDEX_BITS] plus the index of the message within the batch, so that the offsets stay unique
+//! and ordered across batches. The same offsets are used for publishing the watermarks, and the
+//! (unbatched) control messages are treated as a batch of one. The JetStream message is settled
+//! only once every message of the batch is settled: it is acked if all of them are acked, and
+//! nacked otherwise. The reader remembers which messages of a nacked batch were already acked, and
+//! skips them when the batch is redelivered.
+//!
+//! The batch is published with a message id derived from the ids of its messages, so that the
+//! JetStream deduplication drops a batch which is written again with the same messages.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_nats::jetstream::Context;
+use async_nats::jetstream::Message as JetstreamMessage;
+use async_nats::jetstream::message::PublishMessage;
+use bytes::{Buf, Bytes, BytesMut};
+use prost::encoding::{decode_varint, encode_varint};
+use sha2::{Digest, Sha256};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tracing::error;
+
+use crate::Result;
+use crate::config::pipeline::isb::{Batching, MAX_BATCH_MESSAGES, Stream};
+use crate::error::Error;
+use crate::message::{IntOffset, Offset};
+use crate::metrics::{jetstream_isb_error_metrics_labels, pipeline_metrics};
+use crate::pipeline::isb::compression::{COMPRESSION_HEADER, Codec};
+use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::{WriteError, WriteResult};
+
+/// JetStream header holding the number of messages in the batch.
+pub(crate) const BATCH_HEADER: &str = "x-numaflow-batch";
+
+/// Number of lower bits of the offset used for the index of the message within its batch.
+const INDEX_BITS: u32 = MAX_BATCH_MESSAGES.trailing_zeros();
+
+/// Returns the offset of the message at `index` within the batch at the stream `sequence`.
+pub(crate) fn offset(sequence: u64, index: usize) -> i64 {
+    ((sequence as i64) << INDEX_BITS) | index as i64
+}
+
+/// Encodes the serialized messages into a batch, every message is prefixed with its length.
+pub(crate) fn encode(messages: &[Bytes]) -> Bytes {
+    let size = messages.iter().map(|m| m.len() + 4).sum();
+    let mut buf = BytesMut::with_capacity(size);
+    for message in messages {
+        encode_varint(message.len() as u64, &mut buf);
+        buf.extend_from_slice(message);
+    }
+    buf.freeze()
+}
+
+/// Decodes a batch into the serialized messages.
+pub(crate) fn decode(mut payload: Bytes) -> Result<Vec<Bytes>> {
+    let mut messages = vec![];
+    while payload.has_remaining() {
+        let len = decode_varint(&mut payload)
+            .map_err(|e| Error::ISB(ISBError::Decode(format!("Invalid batch: {e}"))))?
+            as usize;
+        if len > payload.remaining() {
+            return Err(Error::ISB(ISBError::Decode(format!(
+                "Invalid batch: message of {len} bytes, only {} bytes left",
+                payload.remaining()
+            ))));
+        }
+        messages.push(payload.split_to(len));
+    }
+    Ok(messages)
+}
+
+/// Returns the serialized messages of a JetStream message. Batches are decompressed with the
+/// codec and unpacked, a message without the batch header (e.g., control messages) is returned as
+/// is, along with whether it was a batch.
+pub(crate) async fn unpack(
+    message: &JetstreamMessage,
+    codec: Option<&Codec>,
+) -> Result<(Vec<Bytes>, bool)> {
+    let Some(headers) = message
+        .headers
+        .as_ref()
+        .filter(|h| h.get(BATCH_HEADER).is_some())
+    else {
+        return Ok((vec![message.payload.clone()], false));
+    };
+
+    let payload = match codec {
+        None => message.payload.clone(),
+        Some(codec) => {
+            let mut compression = HashMap::new();
+            if let Some(value) = headers.get(COMPRESSION_HEADER) {
+                compression.insert(COMPRESSION_HEADER.to_string(), value.as_str().to_string());
+            }
+            codec
+                .decode(&mut compression, message.payload.to_vec())
+                .await?
+        }
+    };
+    Ok((decode(payload)?, true))
+}
+
+/// How the JetStream message of a batch is settled once all of its messages are settled.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Settlement {
+    Ack,
+    /// the batch is redelivered, along with which of its messages were already acked.
+    Nak(Vec<bool>),
+}
+
+/// Tracks the acks of the messages of a batch. The JetStream message is settled once all of them
+/// are acked or nacked, so that a nack does not redeliver the batch while its other messages are
+/// still in flight.
+#[derive(Debug)]
+pub(crate) struct BatchAcks {
+    state: Mutex<AckState>,
+}
+
+#[derive(Debug)]
+struct AckState {
+    /// acked messages of the batch, including the ones acked in a previous delivery.
+    acked: Vec<bool>,
+    pending: usize,
+    nacked: bool,
+}
+
+impl BatchAcks {
+    pub(crate) fn new(size: usize) -> Self {
+        Self::with_acked(vec![false; size])
+    }
+
+    /// Tracks a redelivered batch whose `acked` messages were already acked.
+    pub(crate) fn with_acked(acked: Vec<bool>) -> Self {
+        let pending = acked.iter().filter(|acked| !**acked).count();
+        Self {
+            state: Mutex::new(AckState {
+                acked,
+                pending,
+                nacked: false,
+            }),
+        }
+    }
+
+    /// Records the ack of the message at `index`, returns how to settle the JetStream message if
+    /// it was the last message of the batch to be settled.
+    pub(crate) fn ack(&self, index: usize) -> Option<Settlement> {
+        let mut state = self.state.lock().expect("batch acks mutex poisoned");
+        state.acked[index] = true;
+        Self::settle(&mut state)
+    }
+
+    /// Records the nack of the message at `index`, returns how to settle the JetStream message if
+    /// it was the last message of the batch to be settled.
+    pub(crate) fn nack(&self, _index: usize) -> Option<Settlement> {
+        let mut state = self.state.lock().expect("batch acks mutex poisoned");
+        state.nacked = true;
+        Self::settle(&mut state)
+    }
+
+    fn settle(state: &mut AckState) -> Option<Settlement> {
+        state.pending = state.pending.checked_sub(1)?;
+        if state.pending > 0 {
+            return None;
+        }
+        if state.nacked {
+            Some(Settlement::Nak(state.acked.clone()))
+        } else {
+            Some(Settlement::Ack)
+        }
+    }
+}
+
+/// Returns the message id of a batch, the hex encoded SHA-256 of the ids of its messages.
+pub(crate) fn batch_id<'a>(ids: impl IntoIterator<Item = &'a str>) -> String {
+    let mut hasher = Sha256::new();
+    for id in ids {
+        // length prefixed, so that the ids can not run into each other
+        hasher.update((id.len() as u64).to_be_bytes());
+        hasher.update(id.as_bytes());
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Number of the messages and of the batches written by a [Batcher], used to convert the length
+/// of the stream from batches to messages.
+#[derive(Debug, Default)]
+pub(crate) struct BatchStats {
+    messages: AtomicU64,
+    batches: AtomicU64,
+}
+
+impl BatchStats {
+    pub(crate) fn record(&self, messages: usize) {
+        self.messages.fetch_add(messages as u64, Ordering::Relaxed);
+        self.batches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Average number of messages per batch, 1 until a batch is written.
+    pub(crate) fn messages_per_batch(&self) -> f64 {
+        let batches = self.batches.load(Ordering::Relaxed);
+        if batches == 0 {
+            return 1.0;
+        }
+        (self.messages.load(Ordering::Relaxed) as f64 / batches as f64).max(1.0)
+    }
+}
+
+type WriteResponse = std::result::Result<WriteResult, WriteError>;
+
+/// A serialized message waiting for its batch to be written.
+struct PendingMessage {
+    id: String,
+    payload: Bytes,
+    done: oneshot::Sender<WriteResponse>,
+}
+
+/// Batcher collects the messages written to a stream into batches of up to `max_messages`, or
+/// whatever is collected within `linger` of the first message, and publishes every batch as a
+/// single JetStream message.
+#[derive(Clone)]
+pub(crate) struct Batcher {
+    tx: mpsc::Sender<PendingMessage>,
+    stats: Arc<BatchStats>,
+}
+
+impl Batcher {
+    /// Creates a new Batcher and spawns the task writing the batches. The task stops once all the
+    /// clones of the Batcher are dropped, after writing the last batch.
+    pub(crate) fn new(
+        stream: Stream,
+        js_ctx: Context,
+        codec: Option<Codec>,
+        config: Batching,
+    ) -> Self {
+        let (tx, rx) = mpsc::channel(config.max_messages);
+        let stats = Arc::new(BatchStats::default());
+        tokio::spawn(
+            BatchWriter {
+                stream,
+                js_ctx,
+                codec,
+                config,
+                stats: Arc::clone(&stats),
+            }
+            .run(rx),
+        );
+        Self { tx, stats }
+    }
+
+    /// Returns the statistics of the written batches.
+    pub(crate) fn stats(&self) -> Arc<BatchStats> {
+        Arc::clone(&self.stats)
+    }
+
+    /// Adds the serialized message to the current batch, the returned receiver resolves once the
+    /// batch is written.
+    pub(crate) async fn write(
+        &self,
+        id: String,
+        payload: Bytes,
+    ) -> std::result::Result<oneshot::Receiver<WriteResponse>, WriteError> {
+        let (done, rx) = oneshot::channel();
+        self.tx
+            .send(PendingMessage { id, payload, done })
+            .await
+            .map_err(|_| WriteError::WriteFailed("batcher stopped".to_string()))?;
+        Ok(rx)
+    }
+}
+
+struct BatchWriter {
+    stream: Stream,
+    js_ctx: Context,
+    codec: Option<Codec>,
+    config: Batching,
+    stats: Arc<BatchStats>,
+}
+
+impl BatchWriter {
+    async fn run(self, mut rx: mpsc::Receiver<PendingMessage>) {
+        while let Some(first) = rx.recv().await {
+            let deadline = Instant::now() + self.config.linger;
+            let mut batch = Vec::with_capacity(self.config.max_messages);
+            batch.push(first);
+            while batch.len() < self.config.max_messages {
+                match tokio::time::timeout_at(deadline, rx.recv()).await {
+                    Ok(Some(message)) => batch.push(message),
+                    Ok(None) | Err(_) => break,
+                }
+            }
+            self.publish(batch).await;
+        }
+    }
+
+    /// Publishes the batch and spawns a task to resolve the publish ack, so that the next batch
+    /// can be collected in the meantime.
+    async fn publish(&self, batch: Vec<PendingMessage>) {
+        let id = batch_id(batch.iter().map(|m| m.id.as_str()));
+        let (payloads, done): (Vec<_>, Vec<_>) =
+            batch.into_iter().map(|m| (m.payload, m.done)).unzip();
+
+        let mut message = PublishMessage::build()
+            .header(BATCH_HEADER, payloads.len().to_string())
+            .message_id(&id);
+        let payload = encode(&payloads);
+        let payload = match &self.codec {
+            None => payload,
+            Some(codec) => match codec.compress(&payload) {
+                Ok((header, compressed)) => {
+                    if let Some(header) = header {
+                        message = message.header(COMPRESSION_HEADER, header);
+                    }
+                    compressed
+                }
+                Err(e) => {
+                    return Self::respond(
+                        done,
+                        Err(WriteError::WriteFailed(format!("Compression failed: {e}"))),
+                    );
+                }
+            },
+        };
+
+        let paf = match self
+            .js_ctx
+            .send_publish(self.stream.name, message.payload(payload))
+            .await
+        {
+            Ok(paf) => paf,
+            Err(e) => {
+                pipeline_metrics()
+                    .jetstream_isb
+                    .write_error_total
+                    .get_or_create(&jetstream_isb_error_metrics_labels(
+                        self.stream.name,
+                        e.kind().to_string(),
+                    ))
+                    .inc();
+                return Self::respond(done, Err(WriteError::WriteFailed(e.to_string())));
+            }
+        };
+
+        self.stats.record(done.len());
+        let partition = self.stream.partition;
+        tokio::spawn(async move {
+            let ack = match paf.await {
+                Ok(ack) => ack,
+                Err(e) => {
+                    error!(?e, "awaiting publish ack of the batch failed");
+                    return Self::respond(done, Err(WriteError::WriteFailed(e.to_string())));
+                }
+            };
+            for (index, done) in done.into_iter().enumerate() {
+                let offset = Offset::Int(IntOffset::new(offset(ack.sequence, index), partition));
+                let result = if ack.duplicate {
+                    WriteResult::duplicate(offset)
+                } else {
+                    WriteResult::new(offset)
+                };
+                let _ = done.send(Ok(result));
+            }
+        });
+    }
+
+    fn respond(done: Vec<oneshot::Sender<WriteResponse>>, response: WriteResponse) {
+        for done in done {
+            let _ = done.send(response.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let messages = vec![Bytes::from("a"), Bytes::new(), Bytes::from(vec![7u8; 300])];
+        let decoded = decode(encode(&messages)).unwrap();
+        assert_eq!(decoded, messages);
+
+        assert!(decode(Bytes::new()).unwrap().is_empty());
+
+        // truncated batch
+        let encoded = encode(&messages);
+        assert!(decode(encoded.slice(..encoded.len() - 1)).is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(offset(1, 0), 1 << 16);
+        assert!(offset(1, MAX_BATCH_MESSAGES - 1) < offset(2, 0));
+        assert!(offset(2, 0) < offset(2, 1));
+    }
+
+    #[test]
+    fn test_batch_acks() {
+        let acks = BatchAcks::new(3);
+        assert_eq!(acks.ack(0), None);
+        assert_eq!(acks.ack(2), None);
+        assert_eq!(acks.ack(1), Some(Settlement::Ack));
+
+        // nacked only once all the messages are settled, along with the acked ones
+        let acks = BatchAcks::new(3);
+        assert_eq!(acks.nack(1), None);
+        assert_eq!(acks.ack(0), None);
+        assert_eq!(acks.ack(2), Some(Settlement::Nak(vec![true, false, true])));
+
+        // redelivered batch, only the message which was not acked is pending
+        let acks = BatchAcks::with_acked(vec![true, false, true]);
+        assert_eq!(acks.ack(1), Some(Settlement::Ack));
+    }
+
+    #[test]
+    fn test_batch_id() {
+        let id = batch_id(["a", "bc"]);
+        assert_eq!(id.len(), 64);
+        assert_eq!(id, batch_id(["a", "bc"]));
+        assert_ne!(id, batch_id(["ab", "c"]));
+        assert_ne!(id, batch_id(["bc", "a"]));
+    }
+
+    #[test]
+    fn test_batch_stats() {
+        let stats = BatchStats::default();
+        assert_eq!(stats.messages_per_batch(), 1.0);
+        stats.record(10);
+        stats.record(20);
+        assert_eq!(stats.messages_per_batch(), 15.0);
+    }
+}
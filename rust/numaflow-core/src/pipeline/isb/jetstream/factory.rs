@@ -2,13 +2,20 @@
 //!
 //! This module provides a factory for creating JetStream-based ISB readers and writers.
 
+use std::sync::Arc;
+
 use async_nats::jetstream::Context;
 use async_trait::async_trait;
+use numaflow_shared::kv::jetstream::JetstreamKVStore;
+use tokio::sync::OnceCell;
 use tokio_util::sync::CancellationToken;
 
 use crate::Result;
 use crate::config::pipeline::isb::{BufferWriterConfig, ISBConfig, Stream};
+use crate::error::Error;
 use crate::pipeline::isb::ISBFactory;
+use crate::pipeline::isb::compression::{Codec, ZstdDictionaries};
+use crate::pipeline::isb::error::ISBError;
 use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
 use crate::pipeline::isb::jetstream::js_writer::JetStreamWriter;
 
@@ -20,6 +27,8 @@ use crate::pipeline::isb::jetstream::js_writer::JetStreamWriter;
 pub struct JetStreamFactory {
     /// The JetStream context used to create readers and writers
     context: Context,
+    /// zstd dictionaries shared by all the readers and writers, loaded on first use
+    dictionaries: Arc<OnceCell<ZstdDictionaries>>,
 }
 
 impl JetStreamFactory {
@@ -28,7 +37,10 @@ impl JetStreamFactory {
     /// # Arguments
     /// * `context` - The JetStream context to use for creating readers and writers
     pub fn new(context: Context) -> Self {
-        Self { context }
+        Self {
+            context,
+            dictionaries: Arc::new(OnceCell::new()),
+        }
     }
 
     /// Returns a reference to the underlying JetStream context.
@@ -39,6 +51,36 @@ impl JetStreamFactory {
     pub fn context(&self) -> &Context {
         &self.context
     }
+
+    /// Creates the codec of the stream for the configured compression.
+    async fn codec(
+        &self,
+        stream: &Stream,
+        isb_config: Option<&ISBConfig>,
+    ) -> Result<Option<Codec>> {
+        let Some(compression) = isb_config.map(|c| &c.compression) else {
+            return Ok(None);
+        };
+
+        let dictionaries = match compression.dictionary_bucket {
+            None => None,
+            Some(bucket) => Some(
+                self.dictionaries
+                    .get_or_try_init(|| async {
+                        let store = self.context.get_key_value(bucket).await.map_err(|e| {
+                            Error::ISB(ISBError::Other(format!(
+                                "Failed to get zstd dictionary bucket {bucket}: {e}"
+                            )))
+                        })?;
+                        ZstdDictionaries::new(Arc::new(JetstreamKVStore::new(store, bucket))).await
+                    })
+                    .await?
+                    .clone(),
+            ),
+        };
+
+        Ok(Some(Codec::new(stream.name, compression, dictionaries)))
+    }
 }
 
 #[async_trait]
@@ -51,7 +93,9 @@ impl ISBFactory for JetStreamFactory {
         stream: Stream,
         isb_config: Option<&ISBConfig>,
     ) -> Result<Self::Reader> {
-        JetStreamReader::new(stream, self.context.clone(), isb_config.cloned()).await
+        let codec = self.codec(&stream, isb_config).await?;
+        let batched = isb_config.is_some_and(|c| c.batching.is_some());
+        JetStreamReader::new(stream, self.context.clone(), codec, batched).await
     }
 
     async fn create_writer(
@@ -61,12 +105,12 @@ impl ISBFactory for JetStreamFactory {
         isb_config: Option<&ISBConfig>,
         cln_token: CancellationToken,
     ) -> Result<Self::Writer> {
-        let compression_type = isb_config.map(|c| c.compression.compress_type);
+        let codec = self.codec(&stream, isb_config).await?;
         JetStreamWriter::new(
             stream,
             self.context.clone(),
             writer_config,
-            compression_type,
+            codec,
             cln_token,
         )
         .await
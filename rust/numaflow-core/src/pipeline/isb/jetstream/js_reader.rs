@@ -1,15 +1,16 @@
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
 
 use crate::Result;
 use crate::config::get_vertex_name;
-use crate::config::pipeline::isb::{CompressionType, ISBConfig, Stream};
+use crate::config::pipeline::isb::Stream;
 use crate::error::Error;
 use crate::message::{IntOffset, Message, MessageID, MessageType, Offset};
 use crate::metadata::Metadata;
-use crate::pipeline::isb::compression;
+use crate::pipeline::isb::compression::Codec;
 use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::jetstream::batch::{self, BatchAcks, BatchStats, Settlement};
 use crate::shared::grpc::utc_from_timestamp;
 use async_nats::jetstream::{
     AckKind, Context, Message as JetstreamMessage, consumer, consumer::PullConsumer,
@@ -20,21 +21,20 @@ use serde_json::json;
 use tokio_stream::StreamExt;
 use tracing::warn;
 
-/// JSWrappedMessage is a wrapper around the JetStream message that includes the
-/// partition index and the vertex name.
+/// JSWrappedMessage is a wrapper around the payload of a JetStream message (or of a message within
+/// a batch) that includes its offset and the vertex name.
 #[derive(Debug)]
 struct JSWrappedMessage {
-    partition_idx: u16,
-    message: async_nats::jetstream::Message,
+    payload: Bytes,
+    offset: Offset,
     vertex_name: &'static str,
-    compression_type: Option<CompressionType>,
+    codec: Option<Codec>,
 }
 
 impl JSWrappedMessage {
     async fn into_message(self) -> Result<Message> {
-        let proto_message =
-            numaflow_pb::objects::isb::Message::decode(self.message.payload.clone())
-                .map_err(|e| Error::Proto(e.to_string()))?;
+        let proto_message = numaflow_pb::objects::isb::Message::decode(self.payload)
+            .map_err(|e| Error::Proto(e.to_string()))?;
 
         let header = proto_message
             .header
@@ -53,28 +53,19 @@ impl JSWrappedMessage {
         let message_info = header
             .message_info
             .ok_or(Error::Proto("Missing message_info".to_string()))?;
+        let mut headers = header.headers;
+        let value = match &self.codec {
+            None => body.payload.into(),
+            Some(codec) => codec.decode(&mut headers, body.payload).await?,
+        };
 
-        let msg_info = self.message.info().map_err(|e| {
-            Error::ISB(ISBError::Decode(format!(
-                "Failed to get message info from JetStream: {e}"
-            )))
-        })?;
-
-        let offset = Offset::Int(IntOffset::new(
-            msg_info.stream_sequence as i64,
-            self.partition_idx,
-        ));
+        let offset = self.offset;
 
         Ok(Message {
             typ: header.kind.into(),
             keys: Arc::from(header.keys.into_boxed_slice()),
             tags: None,
-            value: match self.compression_type {
-                None => body.payload.into(),
-                Some(compression_type) => {
-                    Bytes::from(compression::decompress(compression_type, &body.payload)?)
-                }
-            },
+            value,
             offset: offset.clone(),
             event_time: message_info
                 .event_time
@@ -85,7 +76,7 @@ impl JSWrappedMessage {
                 offset: offset.to_string().into(),
                 index: 0,
             },
-            headers: Arc::new(header.headers),
+            headers: Arc::new(headers),
             watermark: None,
             metadata: header.metadata.map(|m| Arc::new(Metadata::from(m))),
             is_late: message_info.is_late,
@@ -103,20 +94,40 @@ pub(crate) struct JetStreamReader {
     read_consumer: Arc<PullConsumer>,
     /// js context to fetch pending messages from the stream
     js_context: Arc<Context>,
-    /// compression_type is used to decompress the message body
-    compression_type: Option<CompressionType>,
+    /// codec is used to decompress the message body
+    codec: Option<Codec>,
     /// jetstream needs complete message to ack/nack, so we need to keep track of them using the offset
     /// so that we can ack/nack them later using the offset.
-    offset2jsmsg: Arc<RwLock<HashMap<Offset, JetstreamMessage>>>,
+    offset2jsmsg: Arc<RwLock<HashMap<Offset, TrackedMessage>>>,
+    /// whether the messages are written in batches, see [batch].
+    batched: bool,
+    /// messages unpacked from the batches beyond the requested count, returned by the next fetch.
+    overflow: Arc<Mutex<VecDeque<Message>>>,
+    /// acked messages of the nacked batches by their stream sequence, they are skipped when the
+    /// batch is redelivered.
+    redelivered: Arc<Mutex<HashMap<u64, Vec<bool>>>>,
+    /// size of the batches read, used to convert the pending count from batches to messages.
+    batch_stats: Arc<BatchStats>,
     /// interval at which we should send wip ack to avoid redelivery.
     wip_ack_interval: Duration,
 }
 
+/// The JetStream message of a read message, along with the acks of its batch. Messages which are
+/// not batched are a batch of one.
+#[derive(Debug, Clone)]
+struct TrackedMessage {
+    message: JetstreamMessage,
+    acks: Arc<BatchAcks>,
+    /// index of the message within its batch.
+    index: usize,
+}
+
 impl JetStreamReader {
     pub(crate) async fn new(
         stream: Stream,
         js_ctx: Context,
-        isb_config: Option<ISBConfig>,
+        codec: Option<Codec>,
+        batched: bool,
     ) -> Result<Self> {
         let mut consumer: PullConsumer = js_ctx
             .get_consumer_from_stream(&stream.name, &stream.name)
@@ -137,8 +148,12 @@ impl JetStreamReader {
             stream,
             read_consumer: Arc::new(consumer.clone()),
             js_context: Arc::new(js_ctx),
-            compression_type: isb_config.map(|c| c.compression.compress_type),
+            codec,
             offset2jsmsg: Arc::new(RwLock::new(HashMap::new())),
+            batched,
+            overflow: Arc::new(Mutex::new(VecDeque::new())),
+            redelivered: Arc::new(Mutex::new(HashMap::new())),
+            batch_stats: Arc::new(BatchStats::default()),
             wip_ack_interval: Duration::from_secs(ack_wait_seconds / 3), // give 2 chances
         })
     }
@@ -149,7 +164,12 @@ impl JetStreamReader {
 
     /// Fetches messages from JetStream ISB in batches, it honors the batch size and timeout.
     pub(crate) async fn fetch(&self, max: usize, timeout: Duration) -> Result<Vec<Message>> {
-        let mut out = Vec::with_capacity(max);
+        // return the leftovers of the previous batches first
+        let mut out = self.take_overflow(max);
+        if !out.is_empty() {
+            return Ok(out);
+        }
+
         let messages = match self
             .read_consumer
             .batch()
@@ -182,27 +202,44 @@ impl JetStreamReader {
                     "Failed to get message info from JetStream: {e}"
                 )))
             })?;
-            let offset = Offset::Int(IntOffset::new(
-                info.stream_sequence as i64,
-                self.stream.partition,
-            ));
+            let sequence = info.stream_sequence;
+
+            if self.batched {
+                let messages = self.unpack(js_msg, sequence).await?;
+                let mut overflow = self.overflow.lock().expect("overflow mutex poisoned");
+                for message in messages {
+                    if out.len() < max {
+                        out.push(message);
+                    } else {
+                        overflow.push_back(message);
+                    }
+                }
+                continue;
+            }
+
+            let offset = Offset::Int(IntOffset::new(sequence as i64, self.stream.partition));
 
             // Convert to core Message (including decompression) using existing wrapper
-            let mut message = JSWrappedMessage {
-                partition_idx: self.stream.partition,
-                message: js_msg.clone(),
+            let message = JSWrappedMessage {
+                payload: js_msg.payload.clone(),
+                offset: offset.clone(),
                 vertex_name: get_vertex_name(),
-                compression_type: self.compression_type,
+                codec: self.codec.clone(),
             }
             .into_message()
             .await?;
 
-            message.offset = offset.clone();
-
             // Track the actual message for doing ack/nack/wip by offset
             {
                 let mut map = self.offset2jsmsg.write().expect("handles mutex poisoned");
-                map.insert(offset, js_msg);
+                map.insert(
+                    offset,
+                    TrackedMessage {
+                        message: js_msg,
+                        acks: Arc::new(BatchAcks::new(1)),
+                        index: 0,
+                    },
+                );
             }
 
             out.push(message);
@@ -211,12 +248,83 @@ impl JetStreamReader {
         Ok(out)
     }
 
+    /// Unpacks the messages of a batch, every message is tracked by its own offset. Messages
+    /// without the batch header (e.g., control messages) are unpacked as a batch of one. The
+    /// messages of a redelivered batch which were already acked are skipped.
+    async fn unpack(&self, js_msg: JetstreamMessage, sequence: u64) -> Result<Vec<Message>> {
+        let (payloads, is_batch) = batch::unpack(&js_msg, self.codec.as_ref()).await?;
+        self.batch_stats.record(payloads.len());
+        // the messages of a batch are compressed as a whole, not individually
+        let codec = if is_batch { None } else { self.codec.clone() };
+
+        let acked = self
+            .redelivered
+            .lock()
+            .expect("redelivered mutex poisoned")
+            .remove(&sequence)
+            .filter(|acked| acked.len() == payloads.len())
+            .unwrap_or_else(|| vec![false; payloads.len()]);
+
+        let mut messages = Vec::with_capacity(payloads.len());
+        let mut indices = Vec::with_capacity(payloads.len());
+        for (index, payload) in payloads.into_iter().enumerate() {
+            if acked[index] {
+                continue;
+            }
+            let message = JSWrappedMessage {
+                payload,
+                offset: Offset::Int(IntOffset::new(
+                    batch::offset(sequence, index),
+                    self.stream.partition,
+                )),
+                vertex_name: get_vertex_name(),
+                codec: codec.clone(),
+            }
+            .into_message()
+            .await?;
+            messages.push(message);
+            indices.push(index);
+        }
+
+        if messages.is_empty() {
+            // every message was acked before, only the JetStream message is left to ack
+            js_msg
+                .double_ack()
+                .await
+                .map_err(|e| Error::ISB(ISBError::Ack(format!("sequence {sequence}: {e}"))))?;
+            return Ok(messages);
+        }
+
+        let acks = Arc::new(BatchAcks::with_acked(acked));
+        let mut map = self.offset2jsmsg.write().expect("handles mutex poisoned");
+        for (message, index) in messages.iter().zip(indices) {
+            map.insert(
+                message.offset.clone(),
+                TrackedMessage {
+                    message: js_msg.clone(),
+                    acks: Arc::clone(&acks),
+                    index,
+                },
+            );
+        }
+        Ok(messages)
+    }
+
+    /// Takes up to `max` messages left over from the previous batches.
+    fn take_overflow(&self, max: usize) -> Vec<Message> {
+        let mut overflow = self.overflow.lock().expect("overflow mutex poisoned");
+        let n = max.min(overflow.len());
+        overflow.drain(..n).collect()
+    }
+
     /// Mark message as in progress by sending work in progress ack.
     pub(crate) async fn mark_wip(&self, offset: &Offset) -> Result<()> {
-        let msg = self
+        let tracked = self
             .get_js_message(offset, false)
             .ok_or_else(|| Error::ISB(ISBError::OffsetNotFound(offset.to_string())))?;
-        msg.ack_with(AckKind::Progress)
+        tracked
+            .message
+            .ack_with(AckKind::Progress)
             .await
             .map_err(|e| Error::ISB(ISBError::WipAck(format!("offset {}: {}", offset, e))))?;
         Ok(())
@@ -224,28 +332,51 @@ impl JetStreamReader {
 
     /// Acknowledge the offset
     pub(crate) async fn ack(&self, offset: &Offset) -> Result<()> {
-        let msg = self
+        let tracked = self
             .get_js_message(offset, true)
             .ok_or_else(|| Error::ISB(ISBError::OffsetNotFound(offset.to_string())))?;
-        msg.double_ack()
+        let settlement = tracked.acks.ack(tracked.index);
+        self.settle(&tracked, settlement)
             .await
-            .map_err(|e| Error::ISB(ISBError::Ack(format!("offset {}: {}", offset, e))))?;
-        Ok(())
+            .map_err(|e| Error::ISB(ISBError::Ack(format!("offset {}: {}", offset, e))))
     }
 
     /// Negatively acknowledge the offset
     pub(crate) async fn nack(&self, offset: &Offset) -> Result<()> {
-        let msg = self
+        let tracked = self
             .get_js_message(offset, true)
             .ok_or_else(|| Error::ISB(ISBError::OffsetNotFound(offset.to_string())))?;
-        msg.ack_with(AckKind::Nak(None))
+        let settlement = tracked.acks.nack(tracked.index);
+        self.settle(&tracked, settlement)
             .await
-            .map_err(|e| Error::ISB(ISBError::Nack(format!("offset {}: {}", offset, e))))?;
-        Ok(())
+            .map_err(|e| Error::ISB(ISBError::Nack(format!("offset {}: {}", offset, e))))
+    }
+
+    /// Settles the JetStream message once all the messages of its batch are settled. The acked
+    /// messages of a nacked batch are remembered, so that only the rest is processed again.
+    async fn settle(
+        &self,
+        tracked: &TrackedMessage,
+        settlement: Option<Settlement>,
+    ) -> std::result::Result<(), async_nats::Error> {
+        match settlement {
+            None => Ok(()),
+            Some(Settlement::Ack) => tracked.message.double_ack().await,
+            Some(Settlement::Nak(acked)) => {
+                if acked.iter().any(|acked| *acked) {
+                    let sequence = tracked.message.info()?.stream_sequence;
+                    self.redelivered
+                        .lock()
+                        .expect("redelivered mutex poisoned")
+                        .insert(sequence, acked);
+                }
+                tracked.message.ack_with(AckKind::Nak(None)).await
+            }
+        }
     }
 
     /// Helper method to get the JetStream message for a given offset, optionally removing it from the map
-    fn get_js_message(&self, offset: &Offset, remove: bool) -> Option<JetstreamMessage> {
+    fn get_js_message(&self, offset: &Offset, remove: bool) -> Option<TrackedMessage> {
         if remove {
             let mut map = self.offset2jsmsg.write().expect("handles mutex poisoned");
             map.remove(offset)
@@ -255,7 +386,9 @@ impl JetStreamReader {
         }
     }
 
-    /// Returns the number of pending messages in the stream.
+    /// Returns the number of pending messages in the stream. With batching, the consumer counts
+    /// batches, hence the count is multiplied by the average number of messages per batch read so
+    /// far, like the writer does for the buffer usage.
     pub(crate) async fn pending(&self) -> Result<Option<usize>> {
         let subject = format!("CONSUMER.INFO.{}.{}", self.stream.name, self.stream.name);
         let info: consumer::Info =
@@ -269,7 +402,13 @@ impl JetStreamReader {
                     )))
                 })?;
 
-        Ok(Some(info.num_pending as usize + info.num_ack_pending))
+        let pending = info.num_pending as usize + info.num_ack_pending;
+        if !self.batched {
+            return Ok(Some(pending));
+        }
+        Ok(Some(
+            (pending as f64 * self.batch_stats.messages_per_batch()).round() as usize,
+        ))
     }
 }
 
@@ -355,10 +494,13 @@ mod tests {
         let isb_config = ISBConfig {
             compression: Compression {
                 compress_type: CompressionType::Gzip,
+                ..Default::default()
             },
+            batching: None,
         };
+        let codec = Codec::new(stream.name, &isb_config.compression, None);
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), Some(isb_config))
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), Some(codec), false)
             .await
             .unwrap();
 
@@ -487,7 +629,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -536,7 +678,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -585,7 +727,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -602,4 +744,138 @@ mod tests {
 
         context.delete_stream(stream.name).await.unwrap();
     }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_jetstream_batched_read_write() {
+        use crate::config::pipeline::isb::{Batching, BufferWriterConfig};
+        use crate::pipeline::isb::ISBWriter;
+        use crate::pipeline::isb::jetstream::js_writer::JetStreamWriter;
+        use tokio_util::sync::CancellationToken;
+
+        let js_url = "localhost:4222";
+        let client = async_nats::connect(js_url).await.unwrap();
+        let context = jetstream::new(client);
+
+        let stream = Stream::new("test_batched_read_write", "test", 0);
+        let _ = context.delete_stream(stream.name).await;
+        context
+            .get_or_create_stream(stream::Config {
+                name: stream.name.to_string(),
+                subjects: vec![stream.name.to_string()],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let _consumer = context
+            .create_consumer_on_stream(
+                consumer::Config {
+                    name: Some(stream.name.to_string()),
+                    ack_policy: consumer::AckPolicy::Explicit,
+                    ..Default::default()
+                },
+                stream.name,
+            )
+            .await
+            .unwrap();
+
+        let compression = Compression {
+            compress_type: CompressionType::Zstd,
+            ..Default::default()
+        };
+        let cln_token = CancellationToken::new();
+        let writer = JetStreamWriter::new(
+            stream.clone(),
+            context.clone(),
+            BufferWriterConfig {
+                streams: vec![stream.clone()],
+                batching: Some(Batching {
+                    max_messages: 10,
+                    linger: Duration::from_millis(50),
+                }),
+                ..Default::default()
+            },
+            Some(Codec::new(stream.name, &compression, None)),
+            cln_token.clone(),
+        )
+        .await
+        .unwrap();
+
+        let mut pending_writes = vec![];
+        for i in 0..25 {
+            let message = Message {
+                keys: Arc::from(vec![format!("key_{i}")]),
+                value: format!("message {i}").into(),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                event_time: Utc::now(),
+                id: MessageID {
+                    vertex_name: "vertex".to_string().into(),
+                    offset: format!("offset_{i}").into(),
+                    index: i as i32,
+                },
+                ..Default::default()
+            };
+            pending_writes.push(ISBWriter::async_write(&writer, message).await.unwrap());
+        }
+        let mut written = vec![];
+        for pending_write in pending_writes {
+            written.push(pending_write.await.unwrap().offset);
+        }
+        // 25 messages in 3 batches
+        let info = context
+            .get_stream(stream.name)
+            .await
+            .unwrap()
+            .info()
+            .await
+            .unwrap()
+            .clone();
+        assert_eq!(info.state.messages, 3);
+
+        let reader = JetStreamReader::new(
+            stream.clone(),
+            context.clone(),
+            Some(Codec::new(stream.name, &compression, None)),
+            true,
+        )
+        .await
+        .unwrap();
+
+        // the messages beyond the requested count are returned by the next fetch
+        let mut messages = reader.fetch(8, Duration::from_millis(1000)).await.unwrap();
+        assert_eq!(messages.len(), 8);
+        messages.extend(
+            reader
+                .fetch(100, Duration::from_millis(1000))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(messages.len(), 25);
+
+        let offsets: Vec<Offset> = messages.iter().map(|m| m.offset.clone()).collect();
+        assert_eq!(offsets, written);
+        for (i, message) in messages.iter().enumerate() {
+            assert_eq!(message.value, Bytes::from(format!("message {i}")));
+        }
+
+        // the pending count is in messages, estimated from the 3 batches of 25 messages read
+        assert_eq!(reader.pending().await.unwrap(), Some(25));
+
+        // the batch is acked only once all of its messages are acked
+        for offset in offsets.iter().take(9) {
+            reader.ack(offset).await.unwrap();
+        }
+        assert_eq!(reader.pending().await.unwrap(), Some(25));
+        reader.ack(&offsets[9]).await.unwrap();
+        // only the last 2 batches are left, 2 * 25 / 3 messages
+        assert_eq!(reader.pending().await.unwrap(), Some(17));
+        for offset in offsets.iter().skip(10) {
+            reader.ack(offset).await.unwrap();
+        }
+        assert_eq!(reader.pending().await.unwrap(), Some(0));
+
+        cln_token.cancel();
+        context.delete_stream(stream.name).await.unwrap();
+    }
 }
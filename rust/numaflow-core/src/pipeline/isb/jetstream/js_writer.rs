@@ -13,14 +13,15 @@ use tokio_util::sync::CancellationToken;
 use tracing::{debug, error};
 
 use crate::Result;
-use crate::config::pipeline::isb::{BufferWriterConfig, CompressionType, Stream};
+use crate::config::pipeline::isb::{BufferWriterConfig, Stream};
 use crate::error::Error;
 use crate::message::Message;
 use crate::metrics::{
     jetstream_isb_error_metrics_labels, jetstream_isb_metrics_labels, pipeline_metrics,
 };
-use crate::pipeline::isb::compression;
+use crate::pipeline::isb::compression::Codec;
 use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::jetstream::batch::{BatchStats, Batcher};
 
 /// Type alias for metric labels
 type MetricLabels = Arc<Vec<(String, String)>>;
@@ -55,7 +56,9 @@ const DEFAULT_REFRESH_INTERVAL_SECS: u64 = 1;
 pub(crate) struct JetStreamWriter {
     stream: Stream,
     js_ctx: Context,
-    compression_type: Option<CompressionType>,
+    codec: Option<Codec>,
+    /// packs the messages into batches, if batching is enabled for the stream
+    batcher: Option<Batcher>,
     is_full: Arc<AtomicBool>,
     writer_config: BufferWriterConfig,
     /// Cached metric labels to avoid repeated allocations
@@ -69,7 +72,7 @@ impl JetStreamWriter {
         stream: Stream,
         js_ctx: Context,
         writer_config: BufferWriterConfig,
-        compression_type: Option<CompressionType>,
+        codec: Option<Codec>,
         cln_token: CancellationToken,
     ) -> Result<Self> {
         let is_full = Arc::new(AtomicBool::new(false));
@@ -77,10 +80,25 @@ impl JetStreamWriter {
         // Build metric labels once during initialization
         let buffer_labels = Arc::new(jetstream_isb_metrics_labels(stream.name));
 
+        // the batches are compressed as a whole instead of the individual messages
+        let (codec, batcher) = match writer_config.batching.clone() {
+            None => (codec, None),
+            Some(batching) => (
+                None,
+                Some(Batcher::new(
+                    stream.clone(),
+                    js_ctx.clone(),
+                    codec,
+                    batching,
+                )),
+            ),
+        };
+
         let js_writer = Self {
             stream,
             js_ctx,
-            compression_type,
+            codec,
+            batcher,
             is_full: Arc::clone(&is_full),
             writer_config,
             buffer_labels,
@@ -108,6 +126,7 @@ impl JetStreamWriter {
                 })?;
 
             let is_full = Arc::clone(&js_writer.is_full);
+            let batch_stats = js_writer.batcher.as_ref().map(Batcher::stats);
             let writer_config = js_writer.writer_config.clone();
             let buffer_labels = Arc::clone(&js_writer.buffer_labels);
             let stream_name = js_writer.stream.name;
@@ -117,6 +136,7 @@ impl JetStreamWriter {
                     stream,
                     consumer,
                     is_full,
+                    batch_stats,
                     writer_config,
                     buffer_labels,
                     stream_name,
@@ -162,11 +182,10 @@ impl JetStreamWriter {
 
         // Compress the message value if compression is enabled
         let mut message = message;
-        if let Some(compression_type) = self.compression_type {
-            message.value = bytes::Bytes::from(
-                compression::compress(compression_type, &message.value)
-                    .map_err(|e| WriteError::WriteFailed(format!("Compression failed: {}", e)))?,
-            );
+        if let Some(codec) = &self.codec {
+            codec
+                .encode(&mut message)
+                .map_err(|e| WriteError::WriteFailed(format!("Compression failed: {}", e)))?;
         }
 
         let payload: BytesMut = message
@@ -235,11 +254,10 @@ impl JetStreamWriter {
 
         // Compress the message value if compression is enabled
         let mut message = message;
-        if let Some(compression_type) = self.compression_type {
-            message.value = bytes::Bytes::from(
-                compression::compress(compression_type, &message.value)
-                    .map_err(|e| WriteError::WriteFailed(format!("Compression failed: {}", e)))?,
-            );
+        if let Some(codec) = &self.codec {
+            codec
+                .encode(&mut message)
+                .map_err(|e| WriteError::WriteFailed(format!("Compression failed: {}", e)))?;
         }
 
         let payload: Bytes = message
@@ -290,12 +308,44 @@ impl JetStreamWriter {
         }
     }
 
+    /// Adds the message to the current batch of the stream and returns a future which resolves
+    /// once the batch is written.
+    async fn batch_write(
+        &self,
+        batcher: &Batcher,
+        message: Message,
+    ) -> std::result::Result<crate::pipeline::isb::PendingWrite, crate::pipeline::isb::WriteError>
+    {
+        if self.is_full() {
+            pipeline_metrics()
+                .jetstream_isb
+                .isfull_total
+                .get_or_create(&self.buffer_labels)
+                .inc();
+            return Err(crate::pipeline::isb::WriteError::BufferFull);
+        }
+
+        // message id will be used for deduplicating the batch
+        let id = message.id.to_string();
+        let payload: Bytes = message
+            .try_into()
+            .expect("message serialization should not fail");
+        let done = batcher.write(id, payload).await?;
+
+        Ok(Box::pin(async move {
+            done.await.map_err(|_| {
+                crate::pipeline::isb::WriteError::WriteFailed("batch was dropped".to_string())
+            })?
+        }))
+    }
+
     /// Checks the buffer usage metrics (soft and solid usage) and pending metrics for a stream.
     /// If the usage is greater than the bufferUsageLimit, it sets the is_full flag to true.
     async fn check_stream_status(
         mut stream: async_nats::jetstream::stream::Stream,
         mut consumer: PullConsumer,
         is_full: Arc<AtomicBool>,
+        batch_stats: Option<Arc<BatchStats>>,
         writer_config: BufferWriterConfig,
         buffer_labels: MetricLabels,
         stream_name: &'static str,
@@ -306,10 +356,15 @@ impl JetStreamWriter {
         loop {
             tokio::select! {
                 _ = interval.tick() => {
+                    // the stream holds batches, while the max length is in messages
+                    let messages_per_entry = batch_stats
+                        .as_ref()
+                        .map_or(1.0, |stats| stats.messages_per_batch());
                     match Self::fetch_buffer_info(
                         &mut stream,
                         &mut consumer,
-                        writer_config.max_length
+                        writer_config.max_length,
+                        messages_per_entry,
                     ).await {
                         Ok(buffer_info) => {
                             if buffer_info.solid_usage >= writer_config.usage_limit
@@ -357,10 +412,15 @@ impl JetStreamWriter {
     /// - Otherwise: solidUsage = State.Msgs / maxLength
     /// - State.Msgs: The total number of messages in the stream.
     /// - maxLength: The maximum length of the buffer.
+    ///
+    /// With batching, every JetStream message is a batch, hence the counts are multiplied by
+    /// `messages_per_entry`, the average number of messages per batch written by this writer, to
+    /// estimate the number of messages.
     async fn fetch_buffer_info(
         stream: &mut async_nats::jetstream::stream::Stream,
         consumer: &mut PullConsumer,
         max_length: usize,
+        messages_per_entry: f64,
     ) -> Result<BufferInfo> {
         let stream_info = stream.info().await.map_err(|e| {
             Error::ISB(ISBError::BufferInfo(format!(
@@ -376,19 +436,20 @@ impl JetStreamWriter {
             )))
         })?;
 
-        let soft_usage = (consumer_info.num_pending as f64 + consumer_info.num_ack_pending as f64)
-            / max_length as f64;
+        let num_pending = (consumer_info.num_pending as f64 * messages_per_entry) as u64;
+        let num_ack_pending = (consumer_info.num_ack_pending as f64 * messages_per_entry) as usize;
+        let soft_usage = (num_pending as f64 + num_ack_pending as f64) / max_length as f64;
         let solid_usage = if stream_info.config.retention == Limits {
             soft_usage
         } else {
-            stream_info.state.messages as f64 / max_length as f64
+            stream_info.state.messages as f64 * messages_per_entry / max_length as f64
         };
 
         Ok(BufferInfo {
             soft_usage,
             solid_usage,
-            num_pending: consumer_info.num_pending,
-            num_ack_pending: consumer_info.num_ack_pending,
+            num_pending,
+            num_ack_pending,
         })
     }
 }
@@ -399,6 +460,10 @@ impl crate::pipeline::isb::ISBWriter for JetStreamWriter {
         message: Message,
     ) -> std::result::Result<crate::pipeline::isb::PendingWrite, crate::pipeline::isb::WriteError>
     {
+        if let Some(batcher) = &self.batcher {
+            return self.batch_write(batcher, message).await;
+        }
+
         // Get the PAF from the inherent async_write method
         let paf = self.async_write(message).await.map_err(|e| match e {
             WriteError::BufferFull => crate::pipeline::isb::WriteError::BufferFull,
@@ -435,6 +500,10 @@ impl crate::pipeline::isb::ISBWriter for JetStreamWriter {
         message: Message,
     ) -> std::result::Result<crate::pipeline::isb::WriteResult, crate::pipeline::isb::WriteError>
     {
+        if let Some(batcher) = &self.batcher {
+            return self.batch_write(batcher, message).await?.await;
+        }
+
         // Delegate to the inherent write method
         self.write(message).await.map_err(|e| match e {
             WriteError::BufferFull => crate::pipeline::isb::WriteError::BufferFull,
@@ -454,6 +523,7 @@ impl crate::pipeline::isb::ISBWriter for JetStreamWriter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::pipeline::isb::{Compression, CompressionType};
     use crate::message::{IntOffset, MessageID, Offset};
     use async_nats::jetstream;
     use async_nats::jetstream::consumer::{self, Config};
@@ -504,9 +574,10 @@ mod tests {
             })
             .expect("failed to create consumer");
 
-        let buffer_info = JetStreamWriter::fetch_buffer_info(&mut js_stream, &mut consumer, 100)
-            .await
-            .unwrap();
+        let buffer_info =
+            JetStreamWriter::fetch_buffer_info(&mut js_stream, &mut consumer, 100, 1.0)
+                .await
+                .unwrap();
 
         assert_eq!(buffer_info.num_pending, 0);
         assert_eq!(buffer_info.num_ack_pending, 0);
@@ -559,6 +630,7 @@ mod tests {
             usage_limit: 0.5,
             buffer_full_strategy:
                 crate::config::pipeline::isb::BufferFullStrategy::RetryUntilSuccess,
+            batching: None,
         };
 
         let writer = JetStreamWriter::new(
@@ -663,7 +735,14 @@ mod tests {
             stream.clone(),
             context.clone(),
             writer_config,
-            Some(CompressionType::Gzip),
+            Some(Codec::new(
+                stream.name,
+                &Compression {
+                    compress_type: CompressionType::Gzip,
+                    ..Default::default()
+                },
+                None,
+            )),
             cln_token.clone(),
         )
         .await
@@ -735,7 +814,14 @@ mod tests {
             stream.clone(),
             context.clone(),
             writer_config,
-            Some(CompressionType::Zstd),
+            Some(Codec::new(
+                stream.name,
+                &Compression {
+                    compress_type: CompressionType::Zstd,
+                    ..Default::default()
+                },
+                None,
+            )),
             cln_token.clone(),
         )
         .await
@@ -639,6 +639,7 @@ mod tests {
     use super::*;
     use crate::config::pipeline::isb::{BufferReaderConfig, CompressionType};
     use crate::message::{Message, MessageID};
+    use crate::pipeline::isb::compression::Codec;
     use crate::pipeline::isb::error::ISBError;
     use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
     use crate::pipeline::isb::reader::{ISBReaderComponents, ISBReaderOrchestrator};
@@ -723,7 +724,7 @@ mod tests {
         };
         let tracker = Tracker::new(None, CancellationToken::new());
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -836,7 +837,7 @@ mod tests {
             ..Default::default()
         };
 
-        let js_reader = JetStreamReader::new(js_stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(js_stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -957,7 +958,9 @@ mod tests {
         let isb_config = ISBConfig {
             compression: crate::config::pipeline::isb::Compression {
                 compress_type: CompressionType::Gzip,
+                ..Default::default()
             },
+            batching: None,
         };
 
         let buf_reader_config = BufferReaderConfig {
@@ -967,10 +970,14 @@ mod tests {
         };
         let tracker = Tracker::new(None, CancellationToken::new());
 
-        let js_reader =
-            JetStreamReader::new(stream.clone(), context.clone(), Some(isb_config.clone()))
-                .await
-                .unwrap();
+        let js_reader = JetStreamReader::new(
+            stream.clone(),
+            context.clone(),
+            Some(Codec::new(stream.name, &isb_config.compression, None)),
+            false,
+        )
+        .await
+        .unwrap();
 
         let isb_reader_components = ISBReaderComponents {
             vertex_type: "Map".to_string(),
@@ -1093,7 +1100,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -1179,7 +1186,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
         let js_reader = Arc::new(js_reader);
@@ -1240,7 +1247,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
 
@@ -1326,7 +1333,7 @@ mod tests {
             .await
             .unwrap();
 
-        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None)
+        let js_reader = JetStreamReader::new(stream.clone(), context.clone(), None, false)
             .await
             .unwrap();
         let js_reader = Arc::new(js_reader);
@@ -0,0 +1,94 @@
+//! Redis Streams implementation of the ISB.
+//!
+//! Every ISB stream (partition) is a Redis Stream with a single consumer group named after the
+//! stream, and every replica of the reading vertex is a consumer of that group. Acknowledged
+//! entries are deleted from the stream, so the length of the stream is the number of messages
+//! that are yet to be processed, which is what the writer uses to decide whether the buffer is full.
+//!
+//! The entry ids (`<millis>-<sequence>`) are packed into integer offsets, see [offset], so that the
+//! watermarks can be tracked on them like on the JetStream sequence numbers.
+
+/// Redis Streams Writer for a single stream, handles writes and buffer fullness tracking.
+pub(crate) mod redis_writer;
+
+/// Redis Streams Reader reads batches of messages using the consumer group of the stream and
+/// exposes methods to ack/nack them and to mark them as work in progress.
+pub(crate) mod redis_reader;
+
+/// Redis Streams ISB Factory for creating readers and writers.
+pub(crate) mod factory;
+
+use crate::error::Error;
+use crate::pipeline::isb::error::ISBError;
+
+/// Name of the field of the stream entry which holds the encoded message.
+const PAYLOAD_FIELD: &str = "payload";
+
+/// Number of the lower bits of the offset holding the sequence number of the entry id.
+const SEQUENCE_BITS: u32 = 20;
+
+/// Returns the integer offset of the stream entry id, the milliseconds part is shifted above the
+/// sequence number so that the offsets are ordered like the entries.
+pub(crate) fn offset(id: &str) -> crate::Result<i64> {
+    let invalid = || Error::ISB(ISBError::Decode(format!("Invalid stream entry id {id}")));
+    let (millis, sequence) = id.split_once('-').ok_or_else(invalid)?;
+    let millis: i64 = millis.parse().map_err(|_| invalid())?;
+    let sequence: i64 = sequence.parse().map_err(|_| invalid())?;
+    if sequence >= 1 << SEQUENCE_BITS || millis >= 1 << (63 - SEQUENCE_BITS) {
+        return Err(invalid());
+    }
+    Ok(millis << SEQUENCE_BITS | sequence)
+}
+
+/// Returns the stream entry id of the integer offset.
+fn entry_id(offset: i64) -> String {
+    format!(
+        "{}-{}",
+        offset >> SEQUENCE_BITS,
+        offset & ((1 << SEQUENCE_BITS) - 1)
+    )
+}
+
+/// Creates the consumer group of the stream (and the stream itself) if it doesn't exist yet. The
+/// group starts from the beginning of the stream, so the messages written before the first reader
+/// came up are not skipped.
+async fn ensure_consumer_group(
+    conn: &mut redis::aio::ConnectionManager,
+    stream: &str,
+) -> crate::Result<()> {
+    let created: redis::RedisResult<()> = redis::cmd("XGROUP")
+        .arg("CREATE")
+        .arg(stream)
+        .arg(stream)
+        .arg("0")
+        .arg("MKSTREAM")
+        .query_async(conn)
+        .await;
+
+    match created {
+        Ok(()) => Ok(()),
+        Err(e) if e.code() == Some("BUSYGROUP") => Ok(()),
+        Err(e) => Err(Error::ISB(ISBError::Other(format!(
+            "Failed to create consumer group for stream {stream}: {e}"
+        )))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset() {
+        let first = offset("1700000000000-0").unwrap();
+        let second = offset("1700000000000-1").unwrap();
+        let third = offset("1700000000001-0").unwrap();
+        assert!(first < second && second < third);
+        assert_eq!(entry_id(second), "1700000000000-1");
+        assert_eq!(entry_id(third), "1700000000001-0");
+
+        assert!(offset("1700000000000").is_err());
+        assert!(offset("a-0").is_err());
+        assert!(offset(&format!("1700000000000-{}", 1 << SEQUENCE_BITS)).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Redis Streams ISB Factory implementation.
+//!
+//! This module provides a factory for creating Redis Streams based ISB readers and writers.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use numaflow_shared::kv::KVStore;
+use numaflow_shared::kv::redis::RedisKVStore;
+use redis::IntoConnectionInfo;
+use redis::aio::ConnectionManager;
+use tokio::sync::OnceCell;
+use tokio_util::sync::CancellationToken;
+
+use crate::Result;
+use crate::config::pipeline::isb::redis::ClientConfig;
+use crate::config::pipeline::isb::{BufferWriterConfig, ISBConfig, Stream};
+use crate::error::Error;
+use crate::pipeline::isb::ISBFactory;
+use crate::pipeline::isb::compression::Codec;
+use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::redis::redis_reader::RedisStreamsReader;
+use crate::pipeline::isb::redis::redis_writer::RedisStreamsWriter;
+use crate::pipeline::isb::redis::{PAYLOAD_FIELD, offset};
+
+/// Factory for creating Redis Streams based ISB readers and writers.
+///
+/// Every reader and writer gets its own connection, since the blocking reads of a reader would
+/// otherwise hold up the commands of everyone else sharing the (multiplexed) connection. The
+/// control messages are written on a connection shared by the whole factory.
+#[derive(Clone)]
+pub(crate) struct RedisStreamsFactory {
+    client: redis::Client,
+    config: ClientConfig,
+    shared_conn: Arc<OnceCell<ConnectionManager>>,
+}
+
+impl RedisStreamsFactory {
+    /// Creates a new RedisStreamsFactory for the given client configuration. No connection is made
+    /// until a reader or a writer is created.
+    pub(crate) fn new(config: ClientConfig) -> Result<Self> {
+        let mut connection_info = config
+            .url
+            .as_str()
+            .into_connection_info()
+            .map_err(|e| Error::Connection(format!("Invalid redis url: {e}")))?;
+        if let Some(password) = &config.password {
+            connection_info.redis.password = Some(password.clone());
+        }
+
+        let client = redis::Client::open(connection_info)
+            .map_err(|e| Error::Connection(format!("Failed to create redis client: {e}")))?;
+        Ok(Self {
+            client,
+            config,
+            shared_conn: Arc::new(OnceCell::new()),
+        })
+    }
+
+    /// Creates the codec of the stream for the configured compression. zstd dictionaries are not
+    /// supported, since they are distributed through a JetStream KV bucket, and neither is
+    /// batching, since every entry of the stream holds a single message.
+    fn codec(stream: &Stream, isb_config: Option<&ISBConfig>) -> Result<Option<Codec>> {
+        let Some(isb_config) = isb_config else {
+            return Ok(None);
+        };
+        if isb_config.batching.is_some() {
+            return Err(Error::Config(
+                "batching is not supported with the Redis ISB".to_string(),
+            ));
+        }
+        if isb_config.compression.dictionary_bucket.is_some() {
+            return Err(Error::Config(
+                "zstd dictionaries are not supported with the Redis ISB".to_string(),
+            ));
+        }
+        Ok(Some(Codec::new(stream.name, &isb_config.compression, None)))
+    }
+
+    /// Returns the KV store of the bucket, e.g., for the watermarks.
+    pub(crate) async fn kv_store(&self, bucket: &'static str) -> Result<Arc<dyn KVStore>> {
+        let store = RedisKVStore::new(self.client.clone(), bucket)
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get bucket {bucket}: {e}")))?;
+        Ok(Arc::new(store))
+    }
+
+    /// Appends the encoded control message to the stream and returns its offset. Control messages
+    /// are not deduplicated and are written even if the buffer is full.
+    pub(crate) async fn publish_ctrl_message(
+        &self,
+        stream: &Stream,
+        payload: Bytes,
+    ) -> Result<i64> {
+        let mut conn = self
+            .shared_conn
+            .get_or_try_init(|| self.connection())
+            .await?
+            .clone();
+        let id: String = redis::cmd("XADD")
+            .arg(stream.name)
+            .arg("*")
+            .arg(PAYLOAD_FIELD)
+            .arg(payload.as_ref())
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| {
+                Error::ISB(ISBError::Other(format!(
+                    "Failed to write control message to stream {}: {e}",
+                    stream.name
+                )))
+            })?;
+        offset(&id)
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager> {
+        self.client
+            .get_connection_manager()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to connect to redis: {e}")))
+    }
+}
+
+#[async_trait]
+impl ISBFactory for RedisStreamsFactory {
+    type Reader = RedisStreamsReader;
+    type Writer = RedisStreamsWriter;
+
+    async fn create_reader(
+        &self,
+        stream: Stream,
+        isb_config: Option<&ISBConfig>,
+    ) -> Result<Self::Reader> {
+        let codec = Self::codec(&stream, isb_config)?;
+        RedisStreamsReader::new(
+            stream,
+            self.connection().await?,
+            self.connection().await?,
+            self.config.ack_wait,
+            codec,
+        )
+        .await
+    }
+
+    async fn create_writer(
+        &self,
+        stream: Stream,
+        writer_config: BufferWriterConfig,
+        isb_config: Option<&ISBConfig>,
+        cln_token: CancellationToken,
+    ) -> Result<Self::Writer> {
+        let codec = Self::codec(&stream, isb_config)?;
+        RedisStreamsWriter::new(
+            stream,
+            self.connection().await?,
+            writer_config,
+            self.config.duplicate_window,
+            codec,
+            cln_token,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_url() {
+        let config = ClientConfig {
+            url: "not a url".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            RedisStreamsFactory::new(config),
+            Err(Error::Connection(_))
+        ));
+    }
+}
@@ -0,0 +1,462 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use prost::Message as ProtoMessage;
+use redis::aio::ConnectionManager;
+use redis::streams::{
+    StreamClaimReply, StreamId, StreamPendingCountReply, StreamReadOptions, StreamReadReply,
+};
+use redis::{AsyncCommands, Script};
+
+use crate::Result;
+use crate::config::get_vertex_name;
+use crate::config::get_vertex_replica;
+use crate::config::pipeline::isb::Stream;
+use crate::error::Error;
+use crate::message::{IntOffset, Message, MessageID, MessageType, Offset};
+use crate::metadata::Metadata;
+use crate::pipeline::isb::compression::Codec;
+use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::redis::{PAYLOAD_FIELD, ensure_consumer_group};
+use crate::pipeline::isb::redis::{entry_id, offset};
+use crate::shared::grpc::utc_from_timestamp;
+
+/// Sets the idle time of a pending entry only if it is still owned by this consumer. XCLAIM alone
+/// does not check the owner, with a min-idle of 0 it would take back an entry which another
+/// consumer has claimed for redelivery in the meantime. JUSTID keeps the delivery count as is.
+///
+/// KEYS[1] is the stream, ARGV is the group, the consumer, the entry id and the idle time in ms.
+/// Returns 1 if the idle time has been set, 0 if the entry is not pending for this consumer.
+const RESET_IDLE_SCRIPT: &str = r#"
+local pending = redis.call('XPENDING', KEYS[1], ARGV[1], ARGV[3], ARGV[3], 1, ARGV[2])
+if #pending == 0 then
+    return 0
+end
+redis.call('XCLAIM', KEYS[1], ARGV[1], ARGV[2], 0, ARGV[3], 'IDLE', ARGV[4], 'JUSTID')
+return 1
+"#;
+
+/// RedisStreamsReader reads from a Redis Stream as a consumer of the stream's consumer group.
+///
+/// Delivered messages stay in the Pending Entries List (PEL) of the group until they are acked.
+/// A message which has been idle in the PEL for longer than `ack_wait` (the consumer crashed, or
+/// the message was nacked) is found with XPENDING and claimed again with XCLAIM by the next fetch
+/// of any consumer of the group.
+#[derive(Clone)]
+pub(crate) struct RedisStreamsReader {
+    /// redis stream from which we are reading
+    stream: Stream,
+    /// name of this consumer within the consumer group
+    consumer: String,
+    /// connection used for the blocking reads
+    read_conn: ConnectionManager,
+    /// connection used for ack/nack/wip, so they are not held up by a blocking read
+    conn: ConnectionManager,
+    /// idle time after which an unacknowledged message is redelivered
+    ack_wait: Duration,
+    /// codec is used to decompress the message body
+    codec: Option<Codec>,
+    /// resets the idle time of the entries owned by this consumer
+    reset_idle_script: Script,
+}
+
+impl RedisStreamsReader {
+    pub(crate) async fn new(
+        stream: Stream,
+        read_conn: ConnectionManager,
+        mut conn: ConnectionManager,
+        ack_wait: Duration,
+        codec: Option<Codec>,
+    ) -> Result<Self> {
+        ensure_consumer_group(&mut conn, stream.name).await?;
+
+        Ok(Self {
+            stream,
+            consumer: format!("{}-{}", get_vertex_name(), get_vertex_replica()),
+            read_conn,
+            conn,
+            ack_wait,
+            codec,
+            reset_idle_script: Script::new(RESET_IDLE_SCRIPT),
+        })
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        self.stream.name
+    }
+
+    /// Fetches messages from the stream in batches, it honors the batch size and timeout. The
+    /// messages due for redelivery are claimed first, and only the rest of the batch is read from
+    /// the new messages.
+    pub(crate) async fn fetch(&self, max: usize, timeout: Duration) -> Result<Vec<Message>> {
+        let mut conn = self.read_conn.clone();
+
+        let mut entries = self.claim_idle(&mut conn, max).await?;
+        if entries.len() < max {
+            let mut opts = StreamReadOptions::default()
+                .group(self.stream.name, &self.consumer)
+                .count(max - entries.len());
+            // don't wait for new messages if we already have something to return
+            if entries.is_empty() {
+                // BLOCK 0 means wait forever
+                opts = opts.block((timeout.as_millis() as usize).max(1));
+            }
+
+            let reply: Option<StreamReadReply> = conn
+                .xread_options(&[self.stream.name], &[">"], &opts)
+                .await
+                .map_err(|e| Error::ISB(ISBError::Fetch(format!("XREADGROUP failed: {e}"))))?;
+
+            if let Some(reply) = reply {
+                entries.extend(reply.keys.into_iter().flat_map(|key| key.ids));
+            }
+        }
+
+        let mut messages = Vec::with_capacity(entries.len());
+        for entry in entries {
+            messages.push(self.decode(entry).await?);
+        }
+        Ok(messages)
+    }
+
+    /// Claims the messages which have been idle in the PEL for longer than `ack_wait`. XCLAIM
+    /// checks the idle time again, so a message claimed by another consumer in the meantime is
+    /// skipped.
+    async fn claim_idle(&self, conn: &mut ConnectionManager, max: usize) -> Result<Vec<StreamId>> {
+        let ack_wait = self.ack_wait.as_millis() as usize;
+        let pending: StreamPendingCountReply = redis::cmd("XPENDING")
+            .arg(self.stream.name)
+            .arg(self.stream.name)
+            .arg("IDLE")
+            .arg(ack_wait)
+            .arg("-")
+            .arg("+")
+            .arg(max)
+            .query_async(conn)
+            .await
+            .map_err(|e| Error::ISB(ISBError::Fetch(format!("XPENDING failed: {e}"))))?;
+        if pending.ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids: Vec<String> = pending.ids.into_iter().map(|pending| pending.id).collect();
+        let claimed: StreamClaimReply = conn
+            .xclaim(
+                self.stream.name,
+                self.stream.name,
+                &self.consumer,
+                ack_wait,
+                &ids,
+            )
+            .await
+            .map_err(|e| Error::ISB(ISBError::Fetch(format!("XCLAIM failed: {e}"))))?;
+        Ok(claimed.ids)
+    }
+
+    /// Converts a stream entry to a [Message], the entry id packed into an integer is the offset
+    /// of the message.
+    async fn decode(&self, entry: StreamId) -> Result<Message> {
+        let payload: Vec<u8> = entry.get(PAYLOAD_FIELD).ok_or_else(|| {
+            Error::ISB(ISBError::Decode(format!(
+                "Missing {PAYLOAD_FIELD} in stream entry {}",
+                entry.id
+            )))
+        })?;
+
+        let proto_message = numaflow_pb::objects::isb::Message::decode(payload.as_slice())
+            .map_err(|e| Error::Proto(e.to_string()))?;
+
+        let header = proto_message
+            .header
+            .ok_or(Error::Proto("Missing header".to_string()))?;
+        let offset = Offset::Int(IntOffset::new(offset(&entry.id)?, self.stream.partition));
+        let kind: MessageType = header.kind.into();
+        if kind == MessageType::WMB {
+            return Ok(Message {
+                typ: kind,
+                offset,
+                ..Default::default()
+            });
+        }
+
+        let body = proto_message
+            .body
+            .ok_or(Error::Proto("Missing body".to_string()))?;
+        let message_info = header
+            .message_info
+            .ok_or(Error::Proto("Missing message_info".to_string()))?;
+        let event_time = message_info
+            .event_time
+            .map(utc_from_timestamp)
+            .ok_or(Error::Proto("Missing event time".to_string()))?;
+        let mut headers = header.headers;
+        let value = match &self.codec {
+            None => body.payload.into(),
+            Some(codec) => codec.decode(&mut headers, body.payload).await?,
+        };
+
+        Ok(Message {
+            typ: kind,
+            keys: Arc::from(header.keys.into_boxed_slice()),
+            tags: None,
+            value,
+            offset: offset.clone(),
+            event_time,
+            id: MessageID {
+                vertex_name: get_vertex_name().into(),
+                offset: offset.to_string().into(),
+                index: 0,
+            },
+            headers: Arc::new(headers),
+            watermark: None,
+            metadata: header.metadata.map(|m| Arc::new(Metadata::from(m))),
+            is_late: message_info.is_late,
+            ack_handle: None,
+        })
+    }
+
+    /// Mark message as in progress by resetting its idle time in the PEL, so it is not claimed
+    /// for redelivery while it is being processed.
+    pub(crate) async fn mark_wip(&self, offset: &Offset) -> Result<()> {
+        self.reset_idle(&stream_entry_id(offset)?, 0)
+            .await
+            .map_err(|e| Error::ISB(ISBError::WipAck(format!("offset {}: {}", offset, e))))?
+            .then_some(())
+            .ok_or_else(|| Error::ISB(ISBError::OffsetNotFound(offset.to_string())))
+    }
+
+    /// Acknowledge the offset and delete the entry from the stream.
+    pub(crate) async fn ack(&self, offset: &Offset) -> Result<()> {
+        let id = stream_entry_id(offset)?;
+        let (acked, _deleted): (usize, usize) = redis::pipe()
+            .atomic()
+            .xack(self.stream.name, self.stream.name, &[&id])
+            .xdel(self.stream.name, &[&id])
+            .query_async(&mut self.conn.clone())
+            .await
+            .map_err(|e| Error::ISB(ISBError::Ack(format!("offset {}: {}", offset, e))))?;
+
+        if acked == 0 {
+            return Err(Error::ISB(ISBError::OffsetNotFound(offset.to_string())));
+        }
+        Ok(())
+    }
+
+    /// Negatively acknowledge the offset, by making the message look like it has been idle for
+    /// `ack_wait` so that it is claimed by the next fetch.
+    pub(crate) async fn nack(&self, offset: &Offset) -> Result<()> {
+        self.reset_idle(
+            &stream_entry_id(offset)?,
+            self.ack_wait.as_millis() as usize,
+        )
+        .await
+        .map_err(|e| Error::ISB(ISBError::Nack(format!("offset {}: {}", offset, e))))?
+        .then_some(())
+        .ok_or_else(|| Error::ISB(ISBError::OffsetNotFound(offset.to_string())))
+    }
+
+    /// Sets the idle time of the pending entry, see [RESET_IDLE_SCRIPT]. Returns false if the
+    /// entry is not pending for this consumer, i.e., it has been acked or claimed by another
+    /// consumer of the group.
+    async fn reset_idle(&self, id: &str, idle_ms: usize) -> redis::RedisResult<bool> {
+        let reset: i64 = self
+            .reset_idle_script
+            .key(self.stream.name)
+            .arg(self.stream.name)
+            .arg(&self.consumer)
+            .arg(id)
+            .arg(idle_ms)
+            .invoke_async(&mut self.conn.clone())
+            .await?;
+        Ok(reset == 1)
+    }
+
+    /// Returns the number of pending messages in the stream, i.e., the messages which are yet to
+    /// be delivered plus the ones which are delivered but not yet acked, since acked entries are
+    /// deleted from the stream.
+    pub(crate) async fn pending(&self) -> Result<Option<usize>> {
+        let len: usize = self
+            .conn
+            .clone()
+            .xlen(self.stream.name)
+            .await
+            .map_err(|e| {
+                Error::ISB(ISBError::Pending(format!(
+                    "Failed to get length of stream {}: {}",
+                    self.stream.name, e
+                )))
+            })?;
+        Ok(Some(len))
+    }
+}
+
+/// Returns the stream entry id of the offset.
+fn stream_entry_id(offset: &Offset) -> Result<String> {
+    match offset {
+        Offset::Int(offset) => Ok(entry_id(offset.offset)),
+        Offset::String(offset) => Err(Error::ISB(ISBError::OffsetNotFound(offset.to_string()))),
+    }
+}
+
+impl crate::pipeline::isb::ISBReader for RedisStreamsReader {
+    async fn fetch(&self, max: usize, timeout: Duration) -> Result<Vec<Message>> {
+        RedisStreamsReader::fetch(self, max, timeout).await
+    }
+
+    async fn ack(&self, offset: &Offset) -> Result<()> {
+        RedisStreamsReader::ack(self, offset).await
+    }
+
+    async fn nack(&self, offset: &Offset) -> Result<()> {
+        RedisStreamsReader::nack(self, offset).await
+    }
+
+    async fn pending(&self) -> Result<Option<usize>> {
+        RedisStreamsReader::pending(self).await
+    }
+
+    fn name(&self) -> &'static str {
+        RedisStreamsReader::name(self)
+    }
+
+    async fn mark_wip(&self, offset: &Offset) -> Result<()> {
+        RedisStreamsReader::mark_wip(self, offset).await
+    }
+
+    fn wip_ack_interval(&self) -> Option<Duration> {
+        Some(self.ack_wait / 3) // give 2 chances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::StringOffset;
+
+    #[test]
+    fn test_stream_entry_id() {
+        let offset = Offset::Int(IntOffset::new(offset("1700000000000-3").unwrap(), 0));
+        assert_eq!(stream_entry_id(&offset).unwrap(), "1700000000000-3");
+
+        let offset = Offset::String(StringOffset::new("1700000000000-0".to_string(), 0));
+        assert!(matches!(
+            stream_entry_id(&offset),
+            Err(Error::ISB(ISBError::OffsetNotFound(_)))
+        ));
+    }
+
+    #[cfg(feature = "redis-tests")]
+    #[tokio::test]
+    async fn test_redis_streams_read_write() {
+        use chrono::Utc;
+        use tokio_util::sync::CancellationToken;
+
+        use crate::config::pipeline::isb::BufferWriterConfig;
+        use crate::config::pipeline::isb::redis::ClientConfig;
+        use crate::pipeline::isb::ISBFactory;
+        use crate::pipeline::isb::redis::factory::RedisStreamsFactory;
+
+        let stream = Stream::new("test_redis_streams_read_write", "test", 0);
+        let client = redis::Client::open("redis://localhost:6379").unwrap();
+        let mut conn = client.get_connection_manager().await.unwrap();
+        let _: () = redis::cmd("DEL")
+            .arg(stream.name)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+
+        let factory = RedisStreamsFactory::new(ClientConfig {
+            ack_wait: Duration::from_secs(5),
+            ..Default::default()
+        })
+        .unwrap();
+        let cln_token = CancellationToken::new();
+        let writer = factory
+            .create_writer(
+                stream.clone(),
+                BufferWriterConfig::default(),
+                None,
+                cln_token.clone(),
+            )
+            .await
+            .unwrap();
+        let reader = factory.create_reader(stream.clone(), None).await.unwrap();
+
+        for i in 0..2 {
+            let message = Message {
+                keys: Arc::from(vec![format!("key-{i}")]),
+                value: format!("message-{i}").into(),
+                offset: Offset::String(StringOffset::new(format!("src-{i}"), 0)),
+                event_time: Utc::now(),
+                id: MessageID {
+                    vertex_name: "vertex".to_string().into(),
+                    offset: format!("src-{i}").into(),
+                    index: 0,
+                },
+                ..Default::default()
+            };
+            let result = writer.write(message.clone()).await.unwrap();
+            assert!(!result.is_duplicate);
+
+            // writing the same message again is deduplicated
+            let duplicate = writer.write(message).await.unwrap();
+            assert!(duplicate.is_duplicate);
+            assert_eq!(duplicate.offset, result.offset);
+        }
+        assert_eq!(reader.pending().await.unwrap(), Some(2));
+
+        let messages = reader.fetch(10, Duration::from_millis(100)).await.unwrap();
+        assert_eq!(messages.len(), 2);
+        let first = messages.first().unwrap();
+        assert_eq!(first.value.as_ref(), b"message-0");
+        assert_eq!(first.keys.as_ref(), &["key-0".to_string()]);
+
+        // nothing new to read
+        let messages_again = reader.fetch(10, Duration::from_millis(100)).await.unwrap();
+        assert!(messages_again.is_empty());
+
+        // wip and nack the first message, it should be redelivered by the next fetch
+        reader.mark_wip(&first.offset).await.unwrap();
+        reader.nack(&first.offset).await.unwrap();
+        let redelivered = reader.fetch(10, Duration::from_millis(100)).await.unwrap();
+        assert_eq!(redelivered.len(), 1);
+        assert_eq!(redelivered.first().unwrap().offset, first.offset);
+
+        // once another consumer of the group has claimed the nacked message, this consumer can
+        // neither mark it as in progress nor nack it
+        reader.nack(&first.offset).await.unwrap();
+        let other = RedisStreamsReader {
+            consumer: "other-consumer".to_string(),
+            ..reader.clone()
+        };
+        let claimed = other.fetch(10, Duration::from_millis(100)).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert!(matches!(
+            reader.mark_wip(&first.offset).await,
+            Err(Error::ISB(ISBError::OffsetNotFound(_)))
+        ));
+        assert!(matches!(
+            reader.nack(&first.offset).await,
+            Err(Error::ISB(ISBError::OffsetNotFound(_)))
+        ));
+        other.mark_wip(&first.offset).await.unwrap();
+
+        for message in &messages {
+            reader.ack(&message.offset).await.unwrap();
+        }
+        assert_eq!(reader.pending().await.unwrap(), Some(0));
+
+        // acking again fails since the message is no longer pending
+        assert!(matches!(
+            reader.ack(&first.offset).await,
+            Err(Error::ISB(ISBError::OffsetNotFound(_)))
+        ));
+
+        cln_token.cancel();
+        let _: () = redis::cmd("DEL")
+            .arg(stream.name)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,317 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use bytes::Bytes;
+use redis::Script;
+use redis::aio::ConnectionManager;
+use redis::streams::StreamPendingReply;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error};
+
+use crate::Result;
+use crate::config::pipeline::isb::{BufferWriterConfig, Stream};
+use crate::error::Error;
+use crate::message::{IntOffset, Message, Offset};
+use crate::pipeline::isb::compression::Codec;
+use crate::pipeline::isb::error::ISBError;
+use crate::pipeline::isb::redis::{PAYLOAD_FIELD, ensure_consumer_group, offset};
+use crate::pipeline::isb::{PendingWrite, WriteError, WriteResult};
+
+/// Appends the payload (ARGV[1]) to the stream (KEYS[1]) unless the message id has been written
+/// within the duplicate window, in which case the id of the earlier entry is returned. The entry
+/// id is remembered in KEYS[2] for ARGV[2] milliseconds.
+const WRITE_SCRIPT: &str = r#"
+local existing = redis.call('GET', KEYS[2])
+if existing then
+    return {existing, 1}
+end
+local id = redis.call('XADD', KEYS[1], '*', ARGV[3], ARGV[1])
+redis.call('SET', KEYS[2], id, 'PX', ARGV[2])
+return {id, 0}
+"#;
+
+const DEFAULT_REFRESH_INTERVAL_SECS: u64 = 1;
+
+/// Buffer information for a Redis Stream.
+#[derive(Debug)]
+struct BufferInfo {
+    usage: f64,
+    num_pending: usize,
+    num_ack_pending: usize,
+}
+
+/// Redis Streams Writer for a single stream.
+///
+/// Writes are deduplicated on the message id within the duplicate window, like the JetStream
+/// writer does. Since the readers delete the entries they ack, the length of the stream is the
+/// number of messages yet to be processed, and the buffer is full once it reaches the usage limit.
+#[derive(Clone)]
+pub(crate) struct RedisStreamsWriter {
+    stream: Stream,
+    conn: ConnectionManager,
+    write_script: Script,
+    duplicate_window: Duration,
+    codec: Option<Codec>,
+    is_full: Arc<AtomicBool>,
+}
+
+impl RedisStreamsWriter {
+    /// Creates a new RedisStreamsWriter for a single stream and spawns a background task to
+    /// monitor buffer fullness.
+    pub(crate) async fn new(
+        stream: Stream,
+        mut conn: ConnectionManager,
+        writer_config: BufferWriterConfig,
+        duplicate_window: Duration,
+        codec: Option<Codec>,
+        cln_token: CancellationToken,
+    ) -> Result<Self> {
+        // the fullness check needs the group to exist, and the group has to exist before the
+        // first write so that no message is missed by the readers.
+        ensure_consumer_group(&mut conn, stream.name).await?;
+
+        let writer = Self {
+            stream,
+            conn,
+            write_script: Script::new(WRITE_SCRIPT),
+            duplicate_window,
+            codec,
+            is_full: Arc::new(AtomicBool::new(false)),
+        };
+
+        tokio::spawn(Self::check_stream_status(
+            writer.conn.clone(),
+            Arc::clone(&writer.is_full),
+            writer_config,
+            writer.stream.name,
+            cln_token,
+        ));
+
+        Ok(writer)
+    }
+
+    /// Returns whether this stream is full.
+    pub(crate) fn is_full(&self) -> bool {
+        self.is_full.load(Ordering::Relaxed)
+    }
+
+    /// Writes a message to the stream and waits for the entry id.
+    ///
+    /// Returns `Err(WriteError::BufferFull)` if the buffer is full.
+    /// Returns `Err(WriteError::WriteFailed)` if the write operation fails.
+    pub(crate) async fn write(
+        &self,
+        message: Message,
+    ) -> std::result::Result<WriteResult, WriteError> {
+        if self.is_full() {
+            return Err(WriteError::BufferFull);
+        }
+
+        // message id will be used for deduplication
+        let dedup_key = format!("{}:dedup:{}", self.stream.name, message.id);
+
+        let mut message = message;
+        if let Some(codec) = &self.codec {
+            codec
+                .encode(&mut message)
+                .map_err(|e| WriteError::WriteFailed(format!("Compression failed: {}", e)))?;
+        }
+
+        let payload: Bytes = message
+            .try_into()
+            .expect("message serialization should not fail");
+
+        let (id, duplicate): (String, i64) = self
+            .write_script
+            .key(self.stream.name)
+            .key(dedup_key)
+            .arg(payload.as_ref())
+            .arg(self.duplicate_window.as_millis() as u64)
+            .arg(PAYLOAD_FIELD)
+            .invoke_async(&mut self.conn.clone())
+            .await
+            .map_err(|e| {
+                error!(
+                    ?e,
+                    stream = self.stream.name,
+                    "writing to redis stream failed"
+                );
+                WriteError::WriteFailed(e.to_string())
+            })?;
+
+        let offset = offset(&id).map_err(|e| WriteError::WriteFailed(e.to_string()))?;
+        let offset = Offset::Int(IntOffset::new(offset, self.stream.partition));
+        if duplicate == 1 {
+            Ok(WriteResult::duplicate(offset))
+        } else {
+            Ok(WriteResult::new(offset))
+        }
+    }
+
+    /// Refreshes the is_full flag of the stream periodically, the buffer is considered full if the
+    /// usage is greater than the usage limit. The buffer is considered full if its usage can't be
+    /// fetched.
+    async fn check_stream_status(
+        mut conn: ConnectionManager,
+        is_full: Arc<AtomicBool>,
+        writer_config: BufferWriterConfig,
+        stream_name: &'static str,
+        cln_token: CancellationToken,
+    ) {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(DEFAULT_REFRESH_INTERVAL_SECS));
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    match Self::fetch_buffer_info(&mut conn, stream_name, writer_config.max_length).await {
+                        Ok(buffer_info) => {
+                            debug!(?buffer_info, stream_name, "Fetched buffer info");
+                            is_full.store(buffer_info.usage >= writer_config.usage_limit, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            error!(?e, "Failed to fetch buffer info for stream {}, updating isFull to true", stream_name);
+                            is_full.store(true, Ordering::Relaxed);
+                        }
+                    }
+                }
+                _ = cln_token.cancelled() => {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Fetches the buffer usage of the stream.
+    ///
+    /// Formula: XLEN / maxLength
+    /// - XLEN: The number of entries in the stream, i.e., the messages that are yet to be
+    ///   delivered (pending) plus the ones that are delivered but not yet acked (ack pending),
+    ///   since acked entries are deleted.
+    /// - maxLength: The maximum length of the buffer.
+    async fn fetch_buffer_info(
+        conn: &mut ConnectionManager,
+        stream_name: &str,
+        max_length: usize,
+    ) -> Result<BufferInfo> {
+        let (len, ack_pending): (usize, StreamPendingReply) = redis::pipe()
+            .xlen(stream_name)
+            .xpending(stream_name, stream_name)
+            .query_async(conn)
+            .await
+            .map_err(|e| {
+                Error::ISB(ISBError::BufferInfo(format!(
+                    "Failed to get the stream info: {}",
+                    e
+                )))
+            })?;
+
+        Ok(BufferInfo {
+            usage: len as f64 / max_length as f64,
+            num_pending: len.saturating_sub(ack_pending.count()),
+            num_ack_pending: ack_pending.count(),
+        })
+    }
+}
+
+impl crate::pipeline::isb::ISBWriter for RedisStreamsWriter {
+    /// Redis has no asynchronous acknowledgement of the writes, so the write is done before the
+    /// pending write is returned.
+    async fn async_write(&self, message: Message) -> std::result::Result<PendingWrite, WriteError> {
+        let result = self.write(message).await?;
+        Ok(Box::pin(async move { Ok(result) }))
+    }
+
+    async fn write(&self, message: Message) -> std::result::Result<WriteResult, WriteError> {
+        RedisStreamsWriter::write(self, message).await
+    }
+
+    fn name(&self) -> &'static str {
+        self.stream.name
+    }
+
+    fn is_full(&self) -> bool {
+        RedisStreamsWriter::is_full(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "redis-tests")]
+    #[tokio::test]
+    async fn test_fetch_buffer_info() {
+        use crate::pipeline::isb::ISBWriter;
+
+        let stream = Stream::new("test_redis_buffer_info", "test", 0);
+        let client = redis::Client::open("redis://localhost:6379").unwrap();
+        let mut conn = client.get_connection_manager().await.unwrap();
+        let _: () = redis::cmd("DEL")
+            .arg(stream.name)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+
+        let cln_token = CancellationToken::new();
+        let writer_config = BufferWriterConfig {
+            max_length: 2,
+            usage_limit: 0.5,
+            ..Default::default()
+        };
+        let writer = RedisStreamsWriter::new(
+            stream.clone(),
+            conn.clone(),
+            writer_config,
+            Duration::from_secs(120),
+            None,
+            cln_token.clone(),
+        )
+        .await
+        .unwrap();
+
+        let buffer_info = RedisStreamsWriter::fetch_buffer_info(&mut conn, stream.name, 2)
+            .await
+            .unwrap();
+        assert_eq!(buffer_info.num_pending, 0);
+        assert_eq!(buffer_info.num_ack_pending, 0);
+        assert_eq!(buffer_info.usage, 0.0);
+
+        let message = Message {
+            value: "hello".into(),
+            ..Default::default()
+        };
+        writer.async_write(message).await.unwrap().await.unwrap();
+
+        let buffer_info = RedisStreamsWriter::fetch_buffer_info(&mut conn, stream.name, 2)
+            .await
+            .unwrap();
+        assert_eq!(buffer_info.num_pending, 1);
+        assert_eq!(buffer_info.usage, 0.5);
+
+        // the background task marks the buffer full once the usage limit is reached
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !ISBWriter::is_full(&writer) {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("buffer should be full");
+        assert!(matches!(
+            writer
+                .write(Message {
+                    value: "world".into(),
+                    ..Default::default()
+                })
+                .await,
+            Err(WriteError::BufferFull)
+        ));
+
+        cln_token.cancel();
+        let _: () = redis::cmd("DEL")
+            .arg(stream.name)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+    }
+}
@@ -185,6 +185,7 @@ impl<C: NumaflowTypeConfig> PBQ<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::components::reduce::WalRecoveryPolicy;
     use crate::config::pipeline::isb::{BufferReaderConfig, Stream};
     use crate::message::{IntOffset, MessageID, Offset};
     use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
@@ -255,7 +256,8 @@ mod tests {
         let js_reader = JetStreamReader::new(
             reader_components.stream.clone(),
             context.clone(),
-            reader_components.isb_config.clone(),
+            None,
+            false,
         )
         .await
         .unwrap();
@@ -387,7 +389,8 @@ mod tests {
         let js_reader = JetStreamReader::new(
             reader_components.stream.clone(),
             context.clone(),
-            reader_components.isb_config.clone(),
+            None,
+            false,
         )
         .await
         .unwrap();
@@ -408,9 +411,16 @@ mod tests {
         .await
         .unwrap();
 
-        let compactor = Compactor::new(wal_path.clone(), WindowKind::Aligned, 10, 100, 300)
-            .await
-            .unwrap();
+        let compactor = Compactor::new(
+            wal_path.clone(),
+            WindowKind::Aligned,
+            10,
+            100,
+            300,
+            WalRecoveryPolicy::default(),
+        )
+        .await
+        .unwrap();
 
         let wal = WAL {
             append_only_wal,
@@ -486,7 +496,11 @@ mod tests {
         writer_handle.await.unwrap().unwrap();
 
         // Now use ReplayWal to verify the messages are persisted
-        let replay_wal = ReplayWal::new(WalType::Data, wal_path.clone());
+        let replay_wal = ReplayWal::new(
+            WalType::Data,
+            wal_path.clone(),
+            WalRecoveryPolicy::default(),
+        );
         let (mut replay_stream, replay_handle) = replay_wal.streaming_read().unwrap();
 
         let mut persisted_messages = vec![];
@@ -620,7 +634,8 @@ mod tests {
         let js_reader = JetStreamReader::new(
             reader_components.stream.clone(),
             context.clone(),
-            reader_components.isb_config.clone(),
+            None,
+            false,
         )
         .await
         .unwrap();
@@ -635,9 +650,16 @@ mod tests {
             .await
             .unwrap();
 
-        let compactor = Compactor::new(wal_path.clone(), WindowKind::Aligned, 10, 100, 300)
-            .await
-            .unwrap();
+        let compactor = Compactor::new(
+            wal_path.clone(),
+            WindowKind::Aligned,
+            10,
+            100,
+            300,
+            WalRecoveryPolicy::default(),
+        )
+        .await
+        .unwrap();
 
         let wal = WAL {
             append_only_wal,
@@ -746,7 +768,11 @@ mod tests {
         handle.await.unwrap().unwrap();
 
         // Check WAL again - should now have all 10 messages
-        let data_replay_wal = ReplayWal::new(WalType::Data, wal_path.clone());
+        let data_replay_wal = ReplayWal::new(
+            WalType::Data,
+            wal_path.clone(),
+            WalRecoveryPolicy::default(),
+        );
         let (mut replay_stream, replay_handle) = data_replay_wal.streaming_read().unwrap();
 
         let mut all_wal_messages: Vec<Message> = vec![];
@@ -758,7 +784,11 @@ mod tests {
         }
         replay_handle.await.unwrap().unwrap();
 
-        let compact_wal = ReplayWal::new(WalType::Compact, wal_path.clone());
+        let compact_wal = ReplayWal::new(
+            WalType::Compact,
+            wal_path.clone(),
+            WalRecoveryPolicy::default(),
+        );
         let (mut replay_stream, replay_handle) = compact_wal.streaming_read().unwrap();
         while let Some(entry) = replay_stream.next().await {
             if let SegmentEntry::DataEntry { data, .. } = entry {
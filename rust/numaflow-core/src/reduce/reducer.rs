@@ -60,6 +60,12 @@ pub(crate) mod unaligned;
 /// User-defined Reduce for Aligned and Unaligned Windows.
 pub(crate) mod user_defined;
 
+/// Builtin aggregations which run within the numa container.
+pub(crate) mod builtin;
+
+/// Side output for the late messages of Aligned and Unaligned Windows.
+pub(crate) mod late_data;
+
 #[derive(Debug, Clone)]
 pub(crate) enum WindowManager {
     /// Aligned window manager.
@@ -12,8 +12,12 @@
 //! more fine-grained, continuous analysis. Both types ensure deterministic and predictable windowing
 //! based on time.
 
+/// Builtin Reduce for Aligned Windows.
+pub(crate) mod builtin;
 /// Aligned Reduce for Fixed and Sliding Windows.
 pub(crate) mod reducer;
+/// Triggers for the intermediate results of Aligned Windows.
+pub(crate) mod trigger;
 /// User-defined Reduce for Aligned and Unaligned Windows.
 pub(crate) mod user_defined;
 /// Windower for Aligned Windows.
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::ops::Sub;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::Result;
+use crate::config::components::reduce::BuiltinReducerConfig;
+use crate::config::{get_vertex_name, get_vertex_replica};
+use crate::error::Error;
+use crate::message::{IntOffset, Message, MessageID, Offset};
+use crate::reduce::reducer::aligned::windower::{
+    AlignedWindowMessage, AlignedWindowOperation, Window,
+};
+use crate::reduce::reducer::builtin::Aggregator;
+
+/// Builtin aligned reduce, it aggregates the messages of every key of the window as they arrive
+/// and writes a message with the result of every key once the window is closed.
+#[derive(Clone)]
+pub(crate) struct BuiltinAlignedReduce {
+    config: Arc<BuiltinReducerConfig>,
+}
+
+impl BuiltinAlignedReduce {
+    pub(crate) fn new(config: BuiltinReducerConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+
+    /// Returns the aggregation state of a new key.
+    pub(super) fn aggregator(&self) -> Aggregator {
+        Aggregator::new(&self.config.aggregation)
+    }
+
+    /// Adds the message to the aggregation state of its key, invalid messages are skipped.
+    pub(super) fn aggregate(&self, aggregator: &mut Aggregator, message: &Message) {
+        if let Err(e) = aggregator.add(&message.value, &self.config.field) {
+            warn!(?e, offset = %message.offset, "Skipping message in builtin reduce");
+        }
+    }
+
+    /// Aggregates the window stream and sends the results to `result_tx` once the window is
+    /// closed, the end of the stream is the close of the window. If cancelled before (shutdown),
+    /// nothing is written and [Error::Cancelled] is returned, so that the window is not GC'ed and
+    /// gets replayed.
+    pub(crate) async fn reduce_fn(
+        &mut self,
+        mut stream: ReceiverStream<AlignedWindowMessage>,
+        result_tx: mpsc::Sender<Message>,
+        cln_token: CancellationToken,
+    ) -> Result<()> {
+        // ordered by keys, so that the results of a window get the same ids after a replay and
+        // can be deduplicated downstream.
+        let mut aggregators: BTreeMap<Arc<[String]>, Aggregator> = BTreeMap::new();
+        let mut current_window = None;
+
+        let window = loop {
+            let window_msg = tokio::select! {
+                _ = cln_token.cancelled() => {
+                    info!("Cancellation detected while doing builtin reduce");
+                    return Err(Error::Cancelled());
+                }
+                window_msg = stream.next() => window_msg,
+            };
+
+            // the stream is closed once the window is closed
+            let Some(window_msg) = window_msg else {
+                match current_window {
+                    Some(window) => break window,
+                    None => return Ok(()),
+                }
+            };
+
+            match window_msg.operation {
+                AlignedWindowOperation::Open { message, window }
+                | AlignedWindowOperation::Append { message, window } => {
+                    current_window = Some(window);
+                    let aggregator = aggregators
+                        .entry(Arc::clone(&message.keys))
+                        .or_insert_with(|| self.aggregator());
+                    self.aggregate(aggregator, &message);
+                }
+                AlignedWindowOperation::Close { window } => break window,
+            }
+        };
+
+        let mut index = 0;
+        for (keys, mut aggregator) in aggregators {
+            let Some(value) = aggregator.result() else {
+                continue;
+            };
+            result_tx
+                .send(result_message(&window, keys, value, index))
+                .await
+                .expect("failed to send response");
+            index += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Creates the message holding the result of a key of the window, the same way as the results of
+/// the user-defined reducers.
+pub(super) fn result_message(
+    window: &Window,
+    keys: Arc<[String]>,
+    value: bytes::Bytes,
+    index: i32,
+) -> Message {
+    let event_time = window.end_time.sub(chrono::Duration::milliseconds(1));
+    Message {
+        keys,
+        value,
+        offset: Offset::Int(IntOffset::new(0, 0)),
+        event_time,
+        watermark: Some(event_time),
+        id: MessageID {
+            vertex_name: format!("{}-{}", get_vertex_name(), get_vertex_replica()).into(),
+            offset: format!(
+                "{}-{}",
+                window.start_time.timestamp_millis(),
+                window.end_time.timestamp_millis()
+            )
+            .into(),
+            index,
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::config::components::reduce::Aggregation;
+    use crate::reduce::reducer::aligned::windower::window_to_pnf_slot;
+
+    fn window_message(operation: AlignedWindowOperation, window: &Window) -> AlignedWindowMessage {
+        AlignedWindowMessage {
+            operation,
+            pnf_slot: window_to_pnf_slot(window),
+        }
+    }
+
+    fn message(key: &str, value: &str) -> Message {
+        Message {
+            keys: Arc::from(vec![key.to_string()]),
+            value: value.to_string().into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builtin_reduce() {
+        let window = Window::new(
+            Utc.timestamp_millis_opt(60000).unwrap(),
+            Utc.timestamp_millis_opt(120000).unwrap(),
+        );
+        let mut reducer = BuiltinAlignedReduce::new(BuiltinReducerConfig {
+            aggregation: Aggregation::Sum,
+            field: vec![],
+        });
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        let operations = [
+            AlignedWindowOperation::Open {
+                message: message("b", "1"),
+                window: window.clone(),
+            },
+            AlignedWindowOperation::Append {
+                message: message("a", "2"),
+                window: window.clone(),
+            },
+            AlignedWindowOperation::Append {
+                message: message("b", "invalid"),
+                window: window.clone(),
+            },
+            AlignedWindowOperation::Append {
+                message: message("b", "3"),
+                window: window.clone(),
+            },
+        ];
+        for operation in operations {
+            input_tx
+                .send(window_message(operation, &window))
+                .await
+                .unwrap();
+        }
+        // the window is closed by closing the stream
+        drop(input_tx);
+
+        reducer
+            .reduce_fn(
+                ReceiverStream::new(input_rx),
+                result_tx,
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        let a = result_rx.recv().await.unwrap();
+        assert_eq!(a.keys.to_vec(), vec!["a"]);
+        assert_eq!(a.value, "2");
+        assert_eq!(a.id.index, 0);
+        assert_eq!(a.id.offset, "60000-120000");
+        assert_eq!(a.event_time, Utc.timestamp_millis_opt(119999).unwrap());
+
+        let b = result_rx.recv().await.unwrap();
+        assert_eq!(b.keys.to_vec(), vec!["b"]);
+        assert_eq!(b.value, "4");
+        assert_eq!(b.id.index, 1);
+        assert!(result_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_builtin_reduce_cancelled() {
+        let window = Window::new(
+            Utc.timestamp_millis_opt(60000).unwrap(),
+            Utc.timestamp_millis_opt(120000).unwrap(),
+        );
+        let mut reducer = BuiltinAlignedReduce::new(BuiltinReducerConfig {
+            aggregation: Aggregation::Count,
+            field: vec![],
+        });
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        input_tx
+            .send(window_message(
+                AlignedWindowOperation::Open {
+                    message: message("a", "1"),
+                    window: window.clone(),
+                },
+                &window,
+            ))
+            .await
+            .unwrap();
+
+        // the stream is still open (window not closed) when the reduce is cancelled
+        let cln_token = CancellationToken::new();
+        cln_token.cancel();
+        let result = reducer
+            .reduce_fn(ReceiverStream::new(input_rx), result_tx, cln_token)
+            .await;
+        assert!(matches!(result, Err(Error::Cancelled())));
+        assert!(result_rx.recv().await.is_none());
+        drop(input_tx);
+    }
+}
@@ -1,12 +1,16 @@
+use crate::config::components::reduce::{LateDataConfig, TriggerConfig};
 use crate::config::{get_vertex_name, get_vertex_replica};
 use crate::error::Error;
 use crate::message::{Message, MessageType};
 use crate::metrics::{pipeline_drop_metric_labels, pipeline_metrics};
 use crate::pipeline::isb::writer::ISBWriterOrchestrator;
+use crate::reduce::reducer::aligned::builtin::BuiltinAlignedReduce;
+use crate::reduce::reducer::aligned::trigger::TriggeredReduce;
 use crate::reduce::reducer::aligned::user_defined::UserDefinedAlignedReduce;
 use crate::reduce::reducer::aligned::windower::{
     AlignedWindowManager, AlignedWindowMessage, AlignedWindowOperation, Window,
 };
+use crate::reduce::reducer::late_data::LateDataWriter;
 use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
 use crate::typ::NumaflowTypeConfig;
 use bytes::Bytes;
@@ -17,7 +21,7 @@ use std::marker::PhantomData;
 use std::ops::Sub;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tokio::task::JoinHandle;
 use tokio_stream::StreamExt;
 use tokio_stream::wrappers::ReceiverStream;
@@ -26,6 +30,32 @@ use tracing::{debug, error, info, warn};
 
 const DEFAULT_KEY_FOR_NON_KEYED_STREAM: &str = "NON_KEYED_STREAM";
 
+/// Reduce function applied to the messages of a window, either by the user-defined reducer or by
+/// a builtin one.
+#[derive(Clone)]
+pub(crate) enum AlignedReduceFn {
+    UserDefined(UserDefinedAlignedReduce),
+    Builtin(BuiltinAlignedReduce),
+}
+
+impl AlignedReduceFn {
+    pub(crate) async fn reduce_fn(
+        &mut self,
+        stream: ReceiverStream<AlignedWindowMessage>,
+        result_tx: mpsc::Sender<Message>,
+        cln_token: CancellationToken,
+    ) -> crate::Result<()> {
+        match self {
+            AlignedReduceFn::UserDefined(client) => {
+                client.reduce_fn(stream, result_tx, cln_token).await
+            }
+            AlignedReduceFn::Builtin(reducer) => {
+                reducer.reduce_fn(stream, result_tx, cln_token).await
+            }
+        }
+    }
+}
+
 /// Represents an active reduce stream for a window.
 struct ActiveStream {
     /// Sender for window messages. Messages are sent to this channel is received by the unique reduce
@@ -39,24 +69,31 @@ struct ActiveStream {
 /// function for the given window and writing the output to JetStream and publishing the watermark.
 /// Also writes the GC events to the WAL if configured.
 struct ReduceTask<C: NumaflowTypeConfig> {
-    client: UserDefinedAlignedReduce,
+    client: AlignedReduceFn,
     isb_writer: ISBWriterOrchestrator<C>,
     gc_wal_tx: Option<mpsc::Sender<SegmentWriteMessage>>,
     error_tx: mpsc::Sender<Error>,
     window: Window,
     window_manager: AlignedWindowManager,
+    /// Triggers of the intermediate results, the window is only fired once closed if not set.
+    trigger: Option<TriggerConfig>,
+    /// Watermark of the reducer, used by the triggers.
+    watermark_rx: watch::Receiver<DateTime<Utc>>,
     _marker: PhantomData<C>,
 }
 
 impl<C: NumaflowTypeConfig> ReduceTask<C> {
     /// Creates a new ReduceTask with the given configuration
+    #[allow(clippy::too_many_arguments)]
     fn new(
-        client: UserDefinedAlignedReduce,
+        client: AlignedReduceFn,
         isb_writer: ISBWriterOrchestrator<C>,
         gc_wal_tx: Option<mpsc::Sender<SegmentWriteMessage>>,
         error_tx: mpsc::Sender<Error>,
         window: Window,
         window_manager: AlignedWindowManager,
+        trigger: Option<TriggerConfig>,
+        watermark_rx: watch::Receiver<DateTime<Utc>>,
     ) -> Self {
         Self {
             client,
@@ -65,6 +102,8 @@ impl<C: NumaflowTypeConfig> ReduceTask<C> {
             error_tx,
             window,
             window_manager,
+            trigger,
+            watermark_rx,
             _marker: PhantomData,
         }
     }
@@ -94,11 +133,25 @@ impl<C: NumaflowTypeConfig> ReduceTask<C> {
 
             // Call the reduce function. This is a blocking call and will return only once the window
             // is closed, cancellation is detected, or on error. The output is sent to the result_tx
-            // channel which is consumed by the writer task and published to JetStream.
-            let result = self
-                .client
-                .reduce_fn(message_stream, result_tx, cln_token)
-                .await;
+            // channel which is consumed by the writer task and published to JetStream. With triggers,
+            // the intermediate results are also written before the window is closed.
+            let result = match self.trigger.take() {
+                Some(trigger) => {
+                    TriggeredReduce::new(
+                        self.client.clone(),
+                        trigger,
+                        self.window.clone(),
+                        self.watermark_rx.clone(),
+                    )
+                    .reduce_fn(message_stream, result_tx, cln_token)
+                    .await
+                }
+                None => {
+                    self.client
+                        .reduce_fn(message_stream, result_tx, cln_token)
+                        .await
+                }
+            };
 
             if let Err(e) = result {
                 // Check if this is a cancellation error
@@ -171,8 +224,8 @@ struct AlignedReduceActor<C: NumaflowTypeConfig> {
     /// It multiplexes the messages to the receiver to the reduce tasks through the corresponding
     /// tx in [ActiveStream].
     receiver: mpsc::Receiver<AlignedWindowMessage>,
-    /// Client for user-defined reduce operations.
-    client: UserDefinedAlignedReduce,
+    /// Reduce function, user-defined or builtin.
+    client: AlignedReduceFn,
     /// Map of [ActiveStream]s keyed by window ID (pnf_slot).
     active_streams: HashMap<Bytes, ActiveStream>,
     /// ISB writer for writing results of reduce operation.
@@ -183,6 +236,10 @@ struct AlignedReduceActor<C: NumaflowTypeConfig> {
     gc_wal_tx: Option<mpsc::Sender<SegmentWriteMessage>>,
     /// WindowManager for assigning windows to messages and closing windows.
     window_manager: AlignedWindowManager,
+    /// Triggers of the intermediate results of the windows.
+    trigger: Option<TriggerConfig>,
+    /// Watermark of the reducer, used by the triggers.
+    watermark_rx: watch::Receiver<DateTime<Utc>>,
     /// Cancellation token to signal tasks to stop
     cln_token: CancellationToken,
     _marker: PhantomData<C>,
@@ -210,13 +267,16 @@ impl<C: NumaflowTypeConfig> AlignedReduceActor<C> {
         info!("All reduce tasks completed");
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn new(
-        client: UserDefinedAlignedReduce,
+        client: AlignedReduceFn,
         receiver: mpsc::Receiver<AlignedWindowMessage>,
         isb_writer: ISBWriterOrchestrator<C>,
         error_tx: mpsc::Sender<Error>,
         gc_wal_tx: Option<mpsc::Sender<SegmentWriteMessage>>,
         window_manager: AlignedWindowManager,
+        trigger: Option<TriggerConfig>,
+        watermark_rx: watch::Receiver<DateTime<Utc>>,
         cln_token: CancellationToken,
     ) -> Self {
         Self {
@@ -227,6 +287,8 @@ impl<C: NumaflowTypeConfig> AlignedReduceActor<C> {
             error_tx,
             gc_wal_tx,
             window_manager,
+            trigger,
+            watermark_rx,
             cln_token,
             _marker: PhantomData,
         }
@@ -270,6 +332,8 @@ impl<C: NumaflowTypeConfig> AlignedReduceActor<C> {
             self.error_tx.clone(),
             window.clone(),
             self.window_manager.clone(),
+            self.trigger.clone(),
+            self.watermark_rx.clone(),
         );
 
         // start the reduce task and store the handle and the sender so that we can send messages
@@ -359,7 +423,7 @@ impl<C: NumaflowTypeConfig> AlignedReduceActor<C> {
 
 /// Processes messages and forwards results to the next stage.
 pub(crate) struct AlignedReducer<C: NumaflowTypeConfig> {
-    client: UserDefinedAlignedReduce,
+    client: AlignedReduceFn,
     /// Window manager for assigning windows to messages and closing windows.
     window_manager: AlignedWindowManager,
     /// Writer for writing results to JetStream
@@ -372,8 +436,16 @@ pub(crate) struct AlignedReducer<C: NumaflowTypeConfig> {
     gc_wal: Option<AppendOnlyWal>,
     /// Allowed lateness for the messages to be accepted and delay the close of book.
     allowed_lateness: Duration,
+    /// Side output for the late messages, they are dropped if not configured.
+    late_data: Option<LateDataConfig>,
+    /// Writer of the late messages, set once the component is started with late data configured.
+    late_data_writer: Option<LateDataWriter>,
+    /// Triggers of the intermediate results of the windows.
+    trigger: Option<TriggerConfig>,
     /// current watermark for the reduce vertex.
     current_watermark: DateTime<Utc>,
+    /// Publishes the current watermark to the reduce tasks for the triggers.
+    watermark_tx: watch::Sender<DateTime<Utc>>,
     /// Whether the reduce is keyed or not.
     keyed: bool,
     /// Graceful shutdown timeout duration.
@@ -384,21 +456,28 @@ pub(crate) struct AlignedReducer<C: NumaflowTypeConfig> {
 impl<C: NumaflowTypeConfig> AlignedReducer<C> {
     #[allow(clippy::too_many_arguments)]
     pub(crate) async fn new(
-        client: UserDefinedAlignedReduce,
+        client: AlignedReduceFn,
         window_manager: AlignedWindowManager,
         isb_writer: ISBWriterOrchestrator<C>,
         gc_wal: Option<AppendOnlyWal>,
         allowed_lateness: Duration,
+        late_data: Option<LateDataConfig>,
+        trigger: Option<TriggerConfig>,
         graceful_timeout: Duration,
         keyed: bool,
     ) -> Self {
+        let current_watermark = DateTime::from_timestamp_millis(-1).expect("Invalid timestamp");
         Self {
             client,
             window_manager,
             isb_writer,
             gc_wal,
             allowed_lateness,
-            current_watermark: DateTime::from_timestamp_millis(-1).expect("Invalid timestamp"),
+            late_data,
+            late_data_writer: None,
+            trigger,
+            current_watermark,
+            watermark_tx: watch::channel(current_watermark).0,
             keyed,
             graceful_timeout,
             shutting_down_on_err: false,
@@ -424,7 +503,6 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
         // the one that calls shutdown
         let hard_shutdown_token_owner = hard_shutdown_token.clone();
         let graceful_timeout = self.graceful_timeout;
-        let keyed = self.keyed;
 
         // spawn a task to cancel the token after graceful timeout when the main token is cancelled
         let shutdown_handle = tokio::spawn(async move {
@@ -445,6 +523,8 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
             error_tx.clone(),
             gc_wal_handle,
             self.window_manager.clone(),
+            self.trigger.clone(),
+            self.watermark_tx.subscribe(),
             hard_shutdown_token.clone(),
         )
         .await;
@@ -454,6 +534,11 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
             actor.run().await;
         });
 
+        // start writing the late messages to the side output if configured
+        let late_data_handle = self
+            .setup_late_data_writer(hard_shutdown_token.clone())
+            .await?;
+
         // Start the main task
         let handle = tokio::spawn(async move {
             let mut input_stream = input_stream;
@@ -468,7 +553,7 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
                     // read input messages
                     read_msg = input_stream.next() => {
                         match read_msg {
-                            Some(mut msg) => {
+                            Some(msg) => {
                                 // If shutting down, drain the stream
                                 if self.shutting_down_on_err {
                                     info!("Reduce component is shutting down due to an error, not accepting the message");
@@ -482,17 +567,13 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
                                         // Only close windows if the idle watermark is greater than current watermark
                                         if idle_watermark > self.current_watermark {
                                             self.current_watermark = idle_watermark;
+                                            self.watermark_tx.send_replace(idle_watermark);
                                             self.close_windows_with_watermark(idle_watermark, &actor_tx).await;
                                         }
                                     }
                                     continue; // Skip further processing for WMB messages
                                 }
 
-                                // If the stream is not keyed, set all messages to have the same key
-                                if !keyed {
-                                    msg.keys = Arc::new([DEFAULT_KEY_FOR_NON_KEYED_STREAM.to_string()]);
-                                }
-
                                 self.assign_and_close_windows(msg, &actor_tx).await;
                             }
                             None => {
@@ -515,6 +596,16 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
                 error!("Error waiting for actor to complete: {:?}", e);
             }
 
+            // Drop the late data writer and wait for the pending late messages to be written
+            drop(self.late_data_writer.take());
+            if let Some(late_data_handle) = late_data_handle
+                && let Err(e) = late_data_handle
+                    .await
+                    .expect("late data writer task failed")
+            {
+                error!(?e, "Error while writing late messages");
+            }
+
             // For sliding: we need to make sure to store the window manager state before exiting
             // from the reducer component
             if let AlignedWindowManager::Sliding(manager) = self.window_manager
@@ -545,6 +636,20 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
         }
     }
 
+    /// Starts the late data writer if the late data side output is configured.
+    async fn setup_late_data_writer(
+        &mut self,
+        cln_token: CancellationToken,
+    ) -> crate::Result<Option<JoinHandle<crate::Result<()>>>> {
+        let Some(late_data) = self.late_data.take() else {
+            return Ok(None);
+        };
+        let (writer, handle) =
+            LateDataWriter::start(late_data, self.isb_writer.clone(), cln_token).await?;
+        self.late_data_writer = Some(writer);
+        Ok(Some(handle))
+    }
+
     /// Handles errors from reduce tasks, cancels the token to signal the upstream to stop sending
     /// new messages and updates the final result and shutting_down_on_err flags so that we can
     /// go to shut down mode by draining the input stream and exit.
@@ -576,7 +681,7 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
     /// that can be closed based on the current watermark.
     async fn assign_and_close_windows(
         &mut self,
-        msg: Message,
+        mut msg: Message,
         actor_tx: &mpsc::Sender<AlignedWindowMessage>,
     ) {
         // Update the watermark - use max to ensure it never regresses (only after accepting the message)
@@ -585,6 +690,11 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
             msg.watermark
                 .unwrap_or(DateTime::from_timestamp_millis(-1).expect("Invalid timestamp")),
         );
+        self.watermark_tx.send_if_modified(|watermark| {
+            let modified = *watermark != self.current_watermark;
+            *watermark = self.current_watermark;
+            modified
+        });
 
         // Handle late messages
         if msg.is_late {
@@ -592,14 +702,15 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
                 // this can happen when the pipeline is just started, and we receive a late message
                 // before we receive any watermark.
                 warn!(event_time = ?msg.event_time.timestamp_millis(), watermark = ?self.current_watermark.timestamp_millis(), "Late message detected, but watermark is -1, dropping message");
-                increment_late_message_drop_metric("no-valid-wm-late-message");
+                self.reject_late_message(msg, "no-valid-wm-late-message")
+                    .await;
                 return;
             }
 
             // Drop the late message if it is outside the allowed lateness window.
             if msg.event_time < self.current_watermark.sub(self.allowed_lateness) {
                 debug!(event_time = ?msg.event_time.timestamp_millis(), watermark = ?self.current_watermark.timestamp_millis(), "Late message detected, dropping");
-                increment_late_message_drop_metric("late-message");
+                self.reject_late_message(msg, "late-message").await;
                 return;
             }
         }
@@ -615,7 +726,7 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
                 "Old message popped up, Watermark has progressed past event time"
             );
 
-            increment_late_message_drop_metric("old-message-popped-up");
+            self.reject_late_message(msg, "old-message-popped-up").await;
             return;
         }
 
@@ -623,6 +734,11 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
         self.close_windows_with_watermark(self.current_watermark, actor_tx)
             .await;
 
+        // If the stream is not keyed, set all messages to have the same key
+        if !self.keyed {
+            msg.keys = Arc::new([DEFAULT_KEY_FOR_NON_KEYED_STREAM.to_string()]);
+        }
+
         // Assign windows to the message
         let window_messages = self.window_manager.assign_windows(msg);
 
@@ -631,6 +747,25 @@ impl<C: NumaflowTypeConfig> AlignedReducer<C> {
             actor_tx.send(window_msg).await.expect("Receiver dropped");
         }
     }
+
+    /// Writes the late message to the late data side output along with the window it missed, or
+    /// drops it if the side output is not configured.
+    async fn reject_late_message(&self, msg: Message, reason: &str) {
+        let Some(late_data_writer) = &self.late_data_writer else {
+            increment_late_message_drop_metric(reason);
+            return;
+        };
+        let window = self.window_manager.message_window(&msg);
+        late_data_writer
+            .write(
+                msg,
+                window.start_time,
+                window.end_time,
+                self.current_watermark,
+                reason,
+            )
+            .await;
+    }
 }
 
 /// Increment the late message drop metric counter.
@@ -812,11 +947,13 @@ mod tests {
 
         // Create the AlignedReducer
         let reducer = AlignedReducer::new(
-            client,
+            AlignedReduceFn::UserDefined(client),
             AlignedWindowManager::Fixed(windower),
             isb_writer,
             None, // No GC WAL for testing
             Duration::from_secs(0),
+            None,
+            None,
             Duration::from_millis(50),
             true,
         )
@@ -1072,11 +1209,13 @@ mod tests {
 
         // Create the AlignedReducer
         let reducer = AlignedReducer::new(
-            client,
+            AlignedReduceFn::UserDefined(client),
             AlignedWindowManager::Sliding(windower),
             isb_writer,
             None, // No GC WAL for testing
             Duration::from_secs(0),
+            None,
+            None,
             Duration::from_millis(50),
             true,
         )
@@ -1336,11 +1475,13 @@ mod tests {
 
         // Create the AlignedReducer
         let reducer = AlignedReducer::new(
-            client,
+            AlignedReduceFn::UserDefined(client),
             AlignedWindowManager::Fixed(windower),
             isb_writer,
             None, // No GC WAL for testing
             Duration::from_secs(0),
+            None,
+            None,
             Duration::from_millis(50),
             true,
         )
@@ -0,0 +1,754 @@
+//! Triggers fire intermediate results (panes) of a window before it is closed by the watermark,
+//! which is useful for long windows. A pane is fired per key, either when the key receives a number
+//! of messages, at a processing-time interval, or once the watermark passes the end of the window
+//! followed by a pane for every late message (late firings). The window is always fired once it is
+//! closed with the final pane of every key.
+//!
+//! Panes are accumulating, every pane is the result over all the messages of the key received so
+//! far. The cost of a firing depends on the reducer:
+//!  - builtin reducers keep the aggregation state of every key and update it as the messages
+//!    arrive, so a firing only reads the state of the key, and nothing but the state is kept in
+//!    memory.
+//!  - user-defined reducers only return results once the window is closed. The window is streamed
+//!    to the reducer as the messages arrive, the same way as without triggers, and the final panes
+//!    are its results. Every early firing invokes the reduce function again with the messages of
+//!    the key received so far, which are kept in memory for it. A key stops firing early panes once
+//!    it has more than [MAX_PANE_MESSAGES] messages, so the memory and the work of a firing are
+//!    bounded per key, it is only fired once the window is closed after that.
+//!
+//! The WAL is not affected, the window is GC'ed only after the final panes are written, and the
+//! panes are fired again on replay.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::Result;
+use crate::config::components::reduce::TriggerConfig;
+use crate::error::Error;
+use crate::message::Message;
+use crate::reduce::reducer::aligned::builtin::result_message;
+use crate::reduce::reducer::aligned::reducer::AlignedReduceFn;
+use crate::reduce::reducer::aligned::windower::{
+    AlignedWindowMessage, AlignedWindowOperation, Window, window_to_pnf_slot,
+};
+use crate::reduce::reducer::builtin::Aggregator;
+
+/// Index of the pane of the key within the window, starting from 0.
+pub(crate) const PANE_INDEX_HEADER: &str = "x-numaflow-pane-index";
+
+/// Whether the pane is the final one of the key within the window ("true" or "false").
+pub(crate) const PANE_FINAL_HEADER: &str = "x-numaflow-pane-final";
+
+/// Maximum number of messages of a key kept in memory for the early panes of a user-defined
+/// reducer, the key is only fired once the window is closed past it.
+const MAX_PANE_MESSAGES: usize = 10_000;
+
+/// State of a key of the window and its firings so far.
+struct KeyPanes {
+    /// Aggregation state of the key, only with builtin reducers.
+    aggregator: Option<Aggregator>,
+    /// Messages of the key for the early panes, only with user-defined reducers. None once the key
+    /// has more than [MAX_PANE_MESSAGES] messages.
+    messages: Option<Vec<Message>>,
+    /// Number of messages received since the last firing.
+    pending: u64,
+    /// Index of the next pane.
+    next_pane: u32,
+}
+
+/// Reduce of the whole window by the user-defined reducer, its results are the final panes.
+struct WindowReduce {
+    /// Stream of the window, dropped to close the window.
+    window_tx: Option<mpsc::Sender<AlignedWindowMessage>>,
+    results_rx: mpsc::Receiver<Message>,
+    handle: JoinHandle<Result<()>>,
+    opened: bool,
+}
+
+impl WindowReduce {
+    fn new(mut client: AlignedReduceFn, cln_token: CancellationToken) -> Self {
+        let (window_tx, window_rx) = mpsc::channel(500);
+        let (results_tx, results_rx) = mpsc::channel(100);
+        let handle = tokio::spawn(async move {
+            client
+                .reduce_fn(ReceiverStream::new(window_rx), results_tx, cln_token)
+                .await
+        });
+        Self {
+            window_tx: Some(window_tx),
+            results_rx,
+            handle,
+            opened: false,
+        }
+    }
+
+    /// Streams the message to the reducer, returns the error of the reduce if it has exited.
+    async fn send(&mut self, message: Message, window: &Window) -> Result<()> {
+        let operation = if self.opened {
+            AlignedWindowOperation::Append {
+                message,
+                window: window.clone(),
+            }
+        } else {
+            AlignedWindowOperation::Open {
+                message,
+                window: window.clone(),
+            }
+        };
+        self.opened = true;
+        let window_tx = self.window_tx.as_ref().expect("window is not closed");
+        let window_msg = AlignedWindowMessage {
+            operation,
+            pnf_slot: window_to_pnf_slot(window),
+        };
+        if window_tx.send(window_msg).await.is_err() {
+            return Err(self.exited().await);
+        }
+        Ok(())
+    }
+
+    /// Returns the error of the reduce, which exited before the window is closed.
+    async fn exited(&mut self) -> Error {
+        match (&mut self.handle).await {
+            Ok(Err(e)) => e,
+            Ok(Ok(())) => Error::Reduce("Reduce exited before the window is closed".to_string()),
+            Err(e) => Error::Reduce(format!("Reduce task failed: {e}")),
+        }
+    }
+}
+
+impl Drop for WindowReduce {
+    fn drop(&mut self) {
+        // the window is not closed if the triggered reduce exits early (cancellation or error), the
+        // reduce should not write its results.
+        self.handle.abort();
+    }
+}
+
+/// Reduce of a window with triggers, it fires a pane for every trigger and the final panes once
+/// the window is closed.
+pub(crate) struct TriggeredReduce {
+    client: AlignedReduceFn,
+    trigger: TriggerConfig,
+    window: Window,
+    watermark_rx: watch::Receiver<DateTime<Utc>>,
+    /// Ordered by keys, so that the final panes are written in the same order after a replay.
+    panes: BTreeMap<Arc<[String]>, KeyPanes>,
+    /// Set once the watermark passed the end of the window (only with late firings), every
+    /// message is late after that.
+    on_time_fired: bool,
+}
+
+impl TriggeredReduce {
+    pub(crate) fn new(
+        client: AlignedReduceFn,
+        trigger: TriggerConfig,
+        window: Window,
+        watermark_rx: watch::Receiver<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            client,
+            trigger,
+            window,
+            watermark_rx,
+            panes: BTreeMap::new(),
+            on_time_fired: false,
+        }
+    }
+
+    /// Fires the panes of the window stream as the triggers fire, and the final panes once the
+    /// window is closed (end of the stream). If cancelled before (shutdown), [Error::Cancelled] is
+    /// returned so that the window is not GC'ed and gets replayed.
+    pub(crate) async fn reduce_fn(
+        mut self,
+        mut stream: ReceiverStream<AlignedWindowMessage>,
+        result_tx: mpsc::Sender<Message>,
+        cln_token: CancellationToken,
+    ) -> Result<()> {
+        let mut window_reduce = match &self.client {
+            AlignedReduceFn::UserDefined(_) => {
+                Some(WindowReduce::new(self.client.clone(), cln_token.clone()))
+            }
+            AlignedReduceFn::Builtin(_) => None,
+        };
+        let mut interval = self.trigger.interval.map(|period| {
+            let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+        let mut watch_watermark = self.trigger.late_firings;
+
+        loop {
+            tokio::select! {
+                _ = cln_token.cancelled() => {
+                    info!("Cancellation detected while doing triggered reduce");
+                    return Err(Error::Cancelled());
+                }
+
+                window_msg = stream.next() => {
+                    let Some(window_msg) = window_msg else {
+                        break;
+                    };
+                    match window_msg.operation {
+                        AlignedWindowOperation::Open { mut message, .. }
+                        | AlignedWindowOperation::Append { mut message, .. } => {
+                            // the message is persisted in the WAL, it should not hold back the ack
+                            // till the window is closed.
+                            message.ack_handle = None;
+                            if let Some(window_reduce) = window_reduce.as_mut() {
+                                window_reduce.send(message.clone(), &self.window).await?;
+                            }
+                            self.add(message, &result_tx, &cln_token).await?;
+                        }
+                        AlignedWindowOperation::Close { .. } => break,
+                    }
+                }
+
+                _ = tick(&mut interval) => {
+                    self.fire_pending(&result_tx, &cln_token).await?;
+                }
+
+                changed = self.watermark_rx.changed(), if watch_watermark && !self.on_time_fired => {
+                    if changed.is_err() {
+                        watch_watermark = false;
+                        continue;
+                    }
+                    if *self.watermark_rx.borrow_and_update() >= self.window.end_time {
+                        self.on_time_fired = true;
+                        self.fire_pending(&result_tx, &cln_token).await?;
+                    }
+                }
+            }
+        }
+
+        match window_reduce {
+            Some(window_reduce) => self.fire_window(window_reduce, &result_tx).await,
+            None => {
+                let keys: Vec<Arc<[String]>> = self.panes.keys().cloned().collect();
+                for keys in keys {
+                    self.fire(&keys, true, &result_tx, &cln_token).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds the message to the panes of its key, and fires the key if the count is reached or if
+    /// the message is late.
+    async fn add(
+        &mut self,
+        message: Message,
+        result_tx: &mpsc::Sender<Message>,
+        cln_token: &CancellationToken,
+    ) -> Result<()> {
+        let keys = Arc::clone(&message.keys);
+        let panes = self
+            .panes
+            .entry(Arc::clone(&keys))
+            .or_insert_with(|| match &self.client {
+                AlignedReduceFn::Builtin(reducer) => KeyPanes {
+                    aggregator: Some(reducer.aggregator()),
+                    messages: None,
+                    pending: 0,
+                    next_pane: 0,
+                },
+                AlignedReduceFn::UserDefined(_) => KeyPanes {
+                    aggregator: None,
+                    messages: Some(Vec::new()),
+                    pending: 0,
+                    next_pane: 0,
+                },
+            });
+        panes.pending += 1;
+        if let (AlignedReduceFn::Builtin(reducer), Some(aggregator)) =
+            (&self.client, panes.aggregator.as_mut())
+        {
+            reducer.aggregate(aggregator, &message);
+        }
+        if let Some(messages) = panes.messages.as_mut() {
+            if messages.len() < MAX_PANE_MESSAGES {
+                messages.push(message);
+            } else {
+                warn!(
+                    ?keys,
+                    max = MAX_PANE_MESSAGES,
+                    "Too many messages of the key for the early panes, it is fired only once the window is closed"
+                );
+                panes.messages = None;
+            }
+        }
+
+        let count_reached = self
+            .trigger
+            .count
+            .is_some_and(|count| panes.pending >= count);
+        if count_reached || self.on_time_fired {
+            self.fire(&keys, false, result_tx, cln_token).await?;
+        }
+        Ok(())
+    }
+
+    /// Fires the keys which received messages since their last firing.
+    async fn fire_pending(
+        &mut self,
+        result_tx: &mpsc::Sender<Message>,
+        cln_token: &CancellationToken,
+    ) -> Result<()> {
+        let keys: Vec<Arc<[String]>> = self
+            .panes
+            .iter()
+            .filter(|(_, panes)| panes.pending > 0)
+            .map(|(keys, _)| Arc::clone(keys))
+            .collect();
+        for keys in keys {
+            self.fire(&keys, false, result_tx, cln_token).await?;
+        }
+        Ok(())
+    }
+
+    /// Fires a pane of the key and writes the results with the pane headers. With builtin reducers
+    /// the result is the aggregation state of the key, with user-defined reducers the reduce
+    /// function is invoked with all the messages of the key.
+    async fn fire(
+        &mut self,
+        keys: &Arc<[String]>,
+        is_final: bool,
+        result_tx: &mpsc::Sender<Message>,
+        cln_token: &CancellationToken,
+    ) -> Result<()> {
+        let Some(panes) = self.panes.get_mut(keys) else {
+            return Ok(());
+        };
+        panes.pending = 0;
+
+        if let Some(aggregator) = panes.aggregator.as_mut() {
+            let pane = panes.next_pane;
+            panes.next_pane += 1;
+            if let Some(value) = aggregator.result() {
+                let message = result_message(&self.window, Arc::clone(keys), value, 0);
+                result_tx
+                    .send(pane_message(message, &self.window, keys, pane, is_final))
+                    .await
+                    .expect("failed to send response");
+            }
+            return Ok(());
+        }
+
+        // keys past the maximum number of messages are only fired once the window is closed.
+        let Some(messages) = panes.messages.as_ref() else {
+            return Ok(());
+        };
+        let pane = panes.next_pane;
+        panes.next_pane += 1;
+
+        // the whole window stream is sent upfront, the end of the stream closes the window.
+        let (window_tx, window_rx) = mpsc::channel(messages.len().max(1));
+        let pnf_slot = window_to_pnf_slot(&self.window);
+        for (i, message) in messages.iter().enumerate() {
+            let operation = if i == 0 {
+                AlignedWindowOperation::Open {
+                    message: message.clone(),
+                    window: self.window.clone(),
+                }
+            } else {
+                AlignedWindowOperation::Append {
+                    message: message.clone(),
+                    window: self.window.clone(),
+                }
+            };
+            window_tx
+                .try_send(AlignedWindowMessage {
+                    operation,
+                    pnf_slot: pnf_slot.clone(),
+                })
+                .expect("window channel has capacity for all the messages");
+        }
+        drop(window_tx);
+
+        let (pane_tx, mut pane_rx) = mpsc::channel(100);
+        let window = &self.window;
+        let forward = async {
+            while let Some(message) = pane_rx.recv().await {
+                result_tx
+                    .send(pane_message(message, window, keys, pane, is_final))
+                    .await
+                    .expect("failed to send response");
+            }
+        };
+        let (result, _) = tokio::join!(
+            self.client
+                .reduce_fn(ReceiverStream::new(window_rx), pane_tx, cln_token.clone()),
+            forward
+        );
+        result
+    }
+
+    /// Closes the window streamed to the user-defined reducer and writes its results as the final
+    /// panes of their keys.
+    async fn fire_window(
+        &self,
+        mut window_reduce: WindowReduce,
+        result_tx: &mpsc::Sender<Message>,
+    ) -> Result<()> {
+        window_reduce.window_tx = None;
+        while let Some(message) = window_reduce.results_rx.recv().await {
+            let keys = Arc::clone(&message.keys);
+            let pane = self.panes.get(&keys).map_or(0, |panes| panes.next_pane);
+            result_tx
+                .send(pane_message(message, &self.window, &keys, pane, true))
+                .await
+                .expect("failed to send response");
+        }
+        match (&mut window_reduce.handle).await {
+            Ok(result) => result,
+            Err(e) => Err(Error::Reduce(format!("Reduce task failed: {e}"))),
+        }
+    }
+}
+
+/// Waits for the next tick of the interval, forever if there is no interval.
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Adds the pane headers to the result, and makes its id unique across the panes of the window.
+fn pane_message(
+    mut message: Message,
+    window: &Window,
+    keys: &[String],
+    pane: u32,
+    is_final: bool,
+) -> Message {
+    let mut headers = Arc::unwrap_or_clone(message.headers);
+    headers.insert(PANE_INDEX_HEADER.to_string(), pane.to_string());
+    headers.insert(PANE_FINAL_HEADER.to_string(), is_final.to_string());
+    message.headers = Arc::new(headers);
+    message.id.offset = format!(
+        "{}-{}-{}-{}",
+        window.start_time.timestamp_millis(),
+        window.end_time.timestamp_millis(),
+        keys.join(":"),
+        pane
+    )
+    .into();
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+    use numaflow::reduce;
+    use numaflow::shared::ServerExtras;
+    use numaflow_pb::clients::reduce::reduce_client::ReduceClient;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::config::components::reduce::{Aggregation, BuiltinReducerConfig};
+    use crate::reduce::reducer::aligned::builtin::BuiltinAlignedReduce;
+    use crate::reduce::reducer::aligned::user_defined::UserDefinedAlignedReduce;
+    use crate::shared::grpc::create_rpc_channel;
+
+    struct Counter {}
+
+    struct CounterCreator {}
+
+    impl reduce::ReducerCreator for CounterCreator {
+        type R = Counter;
+
+        fn create(&self) -> Self::R {
+            Counter {}
+        }
+    }
+
+    #[tonic::async_trait]
+    impl reduce::Reducer for Counter {
+        async fn reduce(
+            &self,
+            keys: Vec<String>,
+            mut input: mpsc::Receiver<reduce::ReduceRequest>,
+            _md: &reduce::Metadata,
+        ) -> Vec<reduce::Message> {
+            let mut counter = 0;
+            while input.recv().await.is_some() {
+                counter += 1;
+            }
+            vec![reduce::Message::new(counter.to_string().into_bytes()).with_keys(keys)]
+        }
+    }
+
+    fn window() -> Window {
+        Window::new(
+            Utc.timestamp_millis_opt(0).unwrap(),
+            Utc.timestamp_millis_opt(3_600_000).unwrap(),
+        )
+    }
+
+    fn append(key: &str) -> AlignedWindowMessage {
+        let window = window();
+        AlignedWindowMessage {
+            pnf_slot: window_to_pnf_slot(&window),
+            operation: AlignedWindowOperation::Append {
+                message: Message {
+                    keys: Arc::from(vec![key.to_string()]),
+                    value: "1".into(),
+                    ..Default::default()
+                },
+                window,
+            },
+        }
+    }
+
+    fn triggered_reduce(
+        trigger: TriggerConfig,
+        watermark_rx: watch::Receiver<DateTime<Utc>>,
+    ) -> TriggeredReduce {
+        let client = AlignedReduceFn::Builtin(BuiltinAlignedReduce::new(BuiltinReducerConfig {
+            aggregation: Aggregation::Count,
+            field: vec![],
+        }));
+        TriggeredReduce::new(client, trigger, window(), watermark_rx)
+    }
+
+    /// Returns the keys, value, pane index and final marker of the result.
+    fn pane(message: &Message) -> (String, &str, &str, &str) {
+        (
+            message.keys.join(":"),
+            std::str::from_utf8(&message.value).unwrap(),
+            message.headers.get(PANE_INDEX_HEADER).unwrap().as_str(),
+            message.headers.get(PANE_FINAL_HEADER).unwrap().as_str(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_count_trigger() {
+        let (_watermark_tx, watermark_rx) = watch::channel(Utc.timestamp_millis_opt(-1).unwrap());
+        let reduce = triggered_reduce(
+            TriggerConfig {
+                count: Some(2),
+                interval: None,
+                late_firings: false,
+            },
+            watermark_rx,
+        );
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        for key in ["a", "b", "a", "a", "a"] {
+            input_tx.send(append(key)).await.unwrap();
+        }
+        drop(input_tx);
+
+        reduce
+            .reduce_fn(
+                ReceiverStream::new(input_rx),
+                result_tx,
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        let mut results = vec![];
+        while let Some(message) = result_rx.recv().await {
+            results.push(message);
+        }
+        let panes: Vec<_> = results.iter().map(pane).collect();
+        assert_eq!(
+            panes,
+            vec![
+                ("a".to_string(), "2", "0", "false"),
+                ("a".to_string(), "4", "1", "false"),
+                ("a".to_string(), "4", "2", "true"),
+                ("b".to_string(), "1", "0", "true"),
+            ]
+        );
+
+        // every pane gets a unique id
+        let ids: Vec<_> = results.iter().map(|m| m.id.offset.clone()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "0-3600000-a-0",
+                "0-3600000-a-1",
+                "0-3600000-a-2",
+                "0-3600000-b-0"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_late_firings() {
+        let (watermark_tx, watermark_rx) = watch::channel(Utc.timestamp_millis_opt(-1).unwrap());
+        let reduce = triggered_reduce(
+            TriggerConfig {
+                count: None,
+                interval: None,
+                late_firings: true,
+            },
+            watermark_rx,
+        );
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        let handle = tokio::spawn(reduce.reduce_fn(
+            ReceiverStream::new(input_rx),
+            result_tx,
+            CancellationToken::new(),
+        ));
+
+        input_tx.send(append("a")).await.unwrap();
+        input_tx.send(append("a")).await.unwrap();
+        // let the messages be added before the watermark progresses
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // on-time pane once the watermark passes the end of the window
+        watermark_tx.send_replace(Utc.timestamp_millis_opt(3_600_000).unwrap());
+        let on_time = result_rx.recv().await.unwrap();
+        assert_eq!(pane(&on_time), ("a".to_string(), "2", "0", "false"));
+
+        // late message is fired right away
+        input_tx.send(append("a")).await.unwrap();
+        let late = result_rx.recv().await.unwrap();
+        assert_eq!(pane(&late), ("a".to_string(), "3", "1", "false"));
+
+        drop(input_tx);
+        let last = result_rx.recv().await.unwrap();
+        assert_eq!(pane(&last), ("a".to_string(), "3", "2", "true"));
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_interval_trigger() {
+        let (_watermark_tx, watermark_rx) = watch::channel(Utc.timestamp_millis_opt(-1).unwrap());
+        let reduce = triggered_reduce(
+            TriggerConfig {
+                count: None,
+                interval: Some(Duration::from_secs(10)),
+                late_firings: false,
+            },
+            watermark_rx,
+        );
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        let handle = tokio::spawn(reduce.reduce_fn(
+            ReceiverStream::new(input_rx),
+            result_tx,
+            CancellationToken::new(),
+        ));
+
+        input_tx.send(append("a")).await.unwrap();
+        let early = result_rx.recv().await.unwrap();
+        assert_eq!(pane(&early), ("a".to_string(), "1", "0", "false"));
+
+        // keys without new messages are not fired again
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert!(result_rx.try_recv().is_err());
+
+        drop(input_tx);
+        let last = result_rx.recv().await.unwrap();
+        assert_eq!(pane(&last), ("a".to_string(), "1", "1", "true"));
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_user_defined_trigger() {
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+        let tmp_dir = TempDir::new().unwrap();
+        let sock_file = tmp_dir.path().join("reduce.sock");
+        let server_info_file = tmp_dir.path().join("reduce-server-info");
+
+        let server_socket = sock_file.clone();
+        let server_handle = tokio::spawn(async move {
+            reduce::Server::new(CounterCreator {})
+                .with_socket_file(server_socket)
+                .with_server_info_file(server_info_file)
+                .start_with_shutdown(shutdown_rx)
+                .await
+                .expect("server failed");
+        });
+
+        // wait for the server to start
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let client = UserDefinedAlignedReduce::new(ReduceClient::new(
+            create_rpc_channel(sock_file).await.unwrap(),
+        ))
+        .await;
+        let (_watermark_tx, watermark_rx) = watch::channel(Utc.timestamp_millis_opt(-1).unwrap());
+        let reduce = TriggeredReduce::new(
+            AlignedReduceFn::UserDefined(client),
+            TriggerConfig {
+                count: Some(2),
+                interval: None,
+                late_firings: false,
+            },
+            window(),
+            watermark_rx,
+        );
+
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (result_tx, mut result_rx) = mpsc::channel(10);
+        for key in ["a", "a", "a"] {
+            input_tx.send(append(key)).await.unwrap();
+        }
+        drop(input_tx);
+
+        reduce
+            .reduce_fn(
+                ReceiverStream::new(input_rx),
+                result_tx,
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        // the early pane re-reduces the messages of the key, the final pane is the result of the
+        // window streamed to the reducer.
+        let mut panes = vec![];
+        while let Some(message) = result_rx.recv().await {
+            let (keys, value, index, is_final) = pane(&message);
+            panes.push((
+                keys,
+                value.to_string(),
+                index.to_string(),
+                is_final.to_string(),
+            ));
+        }
+        assert_eq!(
+            panes,
+            vec![
+                (
+                    "a".to_string(),
+                    "2".to_string(),
+                    "0".to_string(),
+                    "false".to_string()
+                ),
+                (
+                    "a".to_string(),
+                    "3".to_string(),
+                    "1".to_string(),
+                    "true".to_string()
+                ),
+            ]
+        );
+
+        shutdown_tx.send(()).unwrap();
+        server_handle.await.unwrap();
+    }
+}
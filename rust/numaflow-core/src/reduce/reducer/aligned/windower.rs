@@ -38,6 +38,15 @@ impl AlignedWindowManager {
         }
     }
 
+    /// Returns the window the message belongs to without assigning it, for sliding windows the span
+    /// of all the windows it belongs to.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        match self {
+            AlignedWindowManager::Fixed(manager) => manager.message_window(msg),
+            AlignedWindowManager::Sliding(manager) => manager.message_window(msg),
+        }
+    }
+
     /// Closes any windows that can be closed because the Watermark has advanced beyond the window
     /// end time.
     pub(crate) fn close_windows(&self, watermark: DateTime<Utc>) -> Vec<AlignedWindowMessage> {
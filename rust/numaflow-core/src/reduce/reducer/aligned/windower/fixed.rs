@@ -56,6 +56,11 @@ impl FixedWindowManager {
         Window::new(start_time, end_time)
     }
 
+    /// Returns the window of the message without assigning it.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        self.create_window(msg)
+    }
+
     /// Assigns windows to a message
     pub(crate) fn assign_windows(&self, msg: Message) -> Vec<AlignedWindowMessage> {
         let window = self.create_window(&msg);
@@ -121,7 +121,7 @@ impl SlidingWindowManager {
     }
 
     /// Creates windows for the given message
-    fn create_windows(&self, msg: &Message) -> Vec<Window> {
+    pub(crate) fn create_windows(&self, msg: &Message) -> Vec<Window> {
         let mut windows = Vec::new();
         let window_length_millis = self.window_length.as_millis() as i64;
         let slide_millis = self.slide.as_millis() as i64;
@@ -330,6 +330,17 @@ impl SlidingWindowManager {
         Ok(())
     }
 
+    /// Returns the span of all the windows of the message without assigning it, i.e., from the
+    /// start of the earliest window to the end of the latest window.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        let windows = self.create_windows(msg);
+        // windows are created from the latest to the earliest
+        match (windows.first(), windows.last()) {
+            (Some(latest), Some(earliest)) => Window::new(earliest.start_time, latest.end_time),
+            _ => Window::new(msg.event_time, msg.event_time),
+        }
+    }
+
     /// Assigns windows to a message. It first figures all the windows that the message belongs to and
     /// then checks if the window already exists. If it does, it appends the message to the window,
     /// else it creates a new window and adds the message to it.
@@ -441,6 +452,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_message_window() {
+        // Create a sliding windower with 60s window length and 20s slide
+        let windower =
+            SlidingWindowManager::new(Duration::from_secs(60), Duration::from_secs(20), None);
+
+        // the message belongs to [20000, 80000), [40000, 100000) and [60000, 120000)
+        let msg = Message {
+            keys: Arc::from(vec!["test_key".to_string()]),
+            event_time: Utc.timestamp_millis_opt(70000).unwrap(),
+            ..Default::default()
+        };
+
+        let window = windower.message_window(&msg);
+        assert_eq!(window.start_time.timestamp_millis(), 20000);
+        assert_eq!(window.end_time.timestamp_millis(), 120000);
+
+        // the message is not assigned to any window
+        assert!(windower.oldest_window().is_none());
+    }
+
     #[tokio::test]
     async fn test_assign_windows_length_not_divisible_by_slide() {
         // Create a sliding windower with 60s window length and 40s slide
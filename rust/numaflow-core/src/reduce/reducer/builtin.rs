@@ -0,0 +1,217 @@
+//! Builtin reducers aggregate the messages of every key of a window within the numa container, which
+//! saves a user-defined reduce container and a gRPC round trip per message for the common
+//! aggregations. They plug into the same windowers and PBQ as the user-defined reducers, and the WAL
+//! is GC'ed and compacted the same way once the window is closed.
+//!
+//! The aggregation state is not persisted. Like the state of the user-defined reducers, it is
+//! rebuilt on restart by replaying the messages of the open windows from the WAL, which only holds
+//! the messages of the windows which are not closed yet.
+//!
+//! The aggregated value is either the whole payload or a (nested) field of a JSON payload. Numbers
+//! can be JSON numbers or strings holding a number.
+
+use bytes::Bytes;
+use serde_json::Value;
+
+use crate::config::components::reduce::Aggregation;
+use crate::error::{Error, Result};
+
+/// HyperLogLog sketch for the distinct aggregation.
+mod hyperloglog;
+
+/// t-digest for the quantiles aggregation.
+mod tdigest;
+
+use hyperloglog::HyperLogLog;
+use tdigest::TDigest;
+
+/// Aggregation state of a key within a window.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Aggregator {
+    Count(u64),
+    Sum(f64),
+    Min(Option<f64>),
+    Max(Option<f64>),
+    Distinct(HyperLogLog),
+    Quantiles {
+        quantiles: Vec<f64>,
+        digest: TDigest,
+    },
+}
+
+impl Aggregator {
+    pub(crate) fn new(aggregation: &Aggregation) -> Self {
+        match aggregation {
+            Aggregation::Count => Aggregator::Count(0),
+            Aggregation::Sum => Aggregator::Sum(0.0),
+            Aggregation::Min => Aggregator::Min(None),
+            Aggregation::Max => Aggregator::Max(None),
+            Aggregation::Distinct { precision } => {
+                Aggregator::Distinct(HyperLogLog::new(*precision))
+            }
+            Aggregation::Quantiles(quantiles) => Aggregator::Quantiles {
+                quantiles: quantiles.clone(),
+                digest: TDigest::default(),
+            },
+        }
+    }
+
+    /// Adds the `field` of the payload to the aggregation, the whole payload if `field` is empty.
+    pub(crate) fn add(&mut self, payload: &[u8], field: &[String]) -> Result<()> {
+        match self {
+            Aggregator::Count(count) => *count += 1,
+            Aggregator::Sum(sum) => *sum += number(payload, field)?,
+            Aggregator::Min(min) => {
+                let value = number(payload, field)?;
+                *min = Some(min.map_or(value, |min| min.min(value)));
+            }
+            Aggregator::Max(max) => {
+                let value = number(payload, field)?;
+                *max = Some(max.map_or(value, |max| max.max(value)));
+            }
+            Aggregator::Distinct(hll) => {
+                if field.is_empty() {
+                    hll.add(payload);
+                } else {
+                    hll.add(&text(lookup(payload, field)?));
+                }
+            }
+            Aggregator::Quantiles { digest, .. } => digest.add(number(payload, field)?),
+        }
+        Ok(())
+    }
+
+    /// Returns the result of the aggregation, None if no value was aggregated (e.g., min of a key
+    /// whose payloads were all invalid). Scalars are written as text, quantiles as a JSON object
+    /// of the quantile to its value.
+    pub(crate) fn result(&mut self) -> Option<Bytes> {
+        let result = match self {
+            Aggregator::Count(count) => count.to_string(),
+            Aggregator::Sum(sum) => sum.to_string(),
+            Aggregator::Min(min) => min.as_ref()?.to_string(),
+            Aggregator::Max(max) => max.as_ref()?.to_string(),
+            Aggregator::Distinct(hll) => (hll.estimate().round() as u64).to_string(),
+            Aggregator::Quantiles { quantiles, digest } => {
+                if digest.is_empty() {
+                    return None;
+                }
+                let values: serde_json::Map<String, Value> = quantiles
+                    .iter()
+                    .map(|q| (q.to_string(), digest.quantile(*q).into()))
+                    .collect();
+                Value::Object(values).to_string()
+            }
+        };
+        Some(Bytes::from(result))
+    }
+}
+
+/// Returns the field of the JSON payload.
+fn lookup(payload: &[u8], field: &[String]) -> Result<Value> {
+    let mut value: Value = serde_json::from_slice(payload)
+        .map_err(|e| Error::Reduce(format!("Payload is not a JSON: {e}")))?;
+    for name in field {
+        value = match value {
+            Value::Object(mut object) => object.remove(name),
+            _ => None,
+        }
+        .ok_or_else(|| Error::Reduce(format!("Field {} not found", field.join("."))))?;
+    }
+    Ok(value)
+}
+
+/// Returns the bytes of a JSON value, strings without the quotes.
+fn text(value: Value) -> Vec<u8> {
+    match value {
+        Value::String(s) => s.into_bytes(),
+        value => value.to_string().into_bytes(),
+    }
+}
+
+/// Returns the number in the field of the payload, or the whole payload if `field` is empty.
+fn number(payload: &[u8], field: &[String]) -> Result<f64> {
+    let number = if field.is_empty() {
+        std::str::from_utf8(payload)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+    } else {
+        match lookup(payload, field)? {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    };
+    number.ok_or_else(|| Error::Reduce("Value is not a number".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate(aggregation: Aggregation, payloads: &[&str], field: &[&str]) -> Option<Bytes> {
+        let field: Vec<String> = field.iter().map(|f| f.to_string()).collect();
+        let mut aggregator = Aggregator::new(&aggregation);
+        for payload in payloads {
+            let _ = aggregator.add(payload.as_bytes(), &field);
+        }
+        aggregator.result()
+    }
+
+    #[test]
+    fn test_scalar_aggregations() {
+        let payloads = ["3", " 1.5 ", "x", "-2"];
+        assert_eq!(
+            aggregate(Aggregation::Count, &payloads, &[]),
+            Some("4".into())
+        );
+        assert_eq!(
+            aggregate(Aggregation::Sum, &payloads, &[]),
+            Some("2.5".into())
+        );
+        assert_eq!(
+            aggregate(Aggregation::Min, &payloads, &[]),
+            Some("-2".into())
+        );
+        assert_eq!(
+            aggregate(Aggregation::Max, &payloads, &[]),
+            Some("3".into())
+        );
+        assert_eq!(aggregate(Aggregation::Max, &["x"], &[]), None);
+    }
+
+    #[test]
+    fn test_json_field() {
+        let payloads = [
+            r#"{"order": {"amount": 10, "user": "a"}}"#,
+            r#"{"order": {"amount": "2.5", "user": "b"}}"#,
+            r#"{"order": {"amount": 1, "user": "a"}}"#,
+            r#"{"order": {}}"#,
+            "not json",
+        ];
+        assert_eq!(
+            aggregate(Aggregation::Sum, &payloads, &["order", "amount"]),
+            Some("13.5".into())
+        );
+        assert_eq!(
+            aggregate(
+                Aggregation::Distinct { precision: 14 },
+                &payloads,
+                &["order", "user"]
+            ),
+            Some("2".into())
+        );
+
+        let mut aggregator = Aggregator::new(&Aggregation::Sum);
+        assert!(aggregator.add(b"{}", &["amount".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_quantiles() {
+        let payloads: Vec<String> = (1..=100).map(|i| i.to_string()).collect();
+        let payloads: Vec<&str> = payloads.iter().map(String::as_str).collect();
+        let result = aggregate(Aggregation::Quantiles(vec![0.0, 1.0]), &payloads, &[]).unwrap();
+        assert_eq!(result, Bytes::from(r#"{"0":1.0,"1":100.0}"#));
+
+        assert_eq!(aggregate(Aggregation::Quantiles(vec![0.5]), &[], &[]), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! [HyperLogLog] estimates the number of distinct values with a fixed amount of memory, 2^precision
+//! one byte registers, with a standard error of about `1.04 / sqrt(2^precision)`.
+//!
+//! [HyperLogLog]: https://algo.inria.fr/flajolet/Publications/FlFuGaMe07.pdf
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch, `precision` has to be between 4 and 16.
+    pub(crate) fn new(precision: u8) -> Self {
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    pub(crate) fn add(&mut self, value: &[u8]) {
+        let hash = hash64(value);
+        // the first `precision` bits select the register, the register keeps the highest rank (the
+        // position of the first set bit) of the remaining bits seen so far.
+        let index = (hash >> (64 - self.precision)) as usize;
+        let rank =
+            ((hash << self.precision) | (1 << (self.precision - 1))).leading_zeros() as u8 + 1;
+        if let Some(register) = self.registers.get_mut(index) {
+            *register = (*register).max(rank);
+        }
+    }
+
+    /// Returns the estimated number of distinct values added.
+    pub(crate) fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // the raw estimate is biased for small cardinalities, use linear counting instead as long
+        // as there are empty registers.
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+}
+
+/// FNV-1a followed by the murmur3 finalizer, so that the bits of the hash are well distributed even
+/// for short values. The sketches are not persisted, on restart they are rebuilt by replaying the
+/// messages of the open windows from the WAL, and a fixed hash gives the same estimate again.
+fn hash64(value: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in value {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate() {
+        let mut hll = HyperLogLog::new(14);
+        assert_eq!(hll.estimate(), 0.0);
+
+        for i in 0..100_000 {
+            hll.add(format!("user-{i}").as_bytes());
+            // duplicates do not change the estimate
+            hll.add(format!("user-{i}").as_bytes());
+        }
+        let error = (hll.estimate() - 100_000.0).abs() / 100_000.0;
+        assert!(error < 0.03, "error {error} is too large");
+    }
+
+    #[test]
+    fn test_small_cardinality() {
+        let mut hll = HyperLogLog::new(10);
+        for i in 0..10 {
+            hll.add(&[i]);
+        }
+        assert_eq!(hll.estimate().round(), 10.0);
+    }
+}
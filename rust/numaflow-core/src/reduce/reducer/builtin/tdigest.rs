@@ -0,0 +1,179 @@
+//! [t-digest] estimates quantiles with a bounded number of centroids (clusters of values), which are
+//! kept small near the tails so that the extreme quantiles (e.g., p99) stay accurate.
+//!
+//! This is the merging variant, values are buffered and merged into the centroids in batches.
+//!
+//! [t-digest]: https://arxiv.org/abs/1902.04023
+
+use std::f64::consts::PI;
+
+/// Compression of the digest, the number of centroids is bounded by about this number.
+const COMPRESSION: f64 = 100.0;
+
+/// Number of values buffered before they are merged into the centroids.
+const BUFFER_SIZE: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct TDigest {
+    /// centroids sorted by mean.
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub(crate) fn add(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        if self.is_empty() {
+            self.min = value;
+            self.max = value;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+
+        self.buffer.push(value);
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.merge();
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Returns the estimated value at quantile `q` (between 0 and 1), None if the digest is empty.
+    pub(crate) fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.merge();
+        let first = self.centroids.first()?;
+        let last = self.centroids.last()?;
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = q.clamp(0.0, 1.0) * total;
+
+        // every centroid is assumed to be centered around its mean, the value at the target rank
+        // is interpolated between the two closest centroid centers (or the min/max at the tails).
+        if target <= first.weight / 2.0 {
+            return Some(interpolate(
+                self.min,
+                first.mean,
+                target / (first.weight / 2.0),
+            ));
+        }
+
+        let mut rank = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let [left, right] = pair else { continue };
+            let next_rank = rank + (left.weight + right.weight) / 2.0;
+            if target <= next_rank {
+                return Some(interpolate(
+                    left.mean,
+                    right.mean,
+                    (target - rank) / (next_rank - rank),
+                ));
+            }
+            rank = next_rank;
+        }
+
+        Some(interpolate(
+            last.mean,
+            self.max,
+            ((target - rank) / (last.weight / 2.0)).min(1.0),
+        ))
+    }
+
+    /// Merges the buffered values into the centroids. Adjacent centroids are combined as long as
+    /// the combined weight stays within the limit of the k1 scale function at their quantile.
+    fn merge(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut centroids: Vec<Centroid> = std::mem::take(&mut self.centroids);
+        centroids.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(COMPRESSION as usize);
+        let mut centroids = centroids.into_iter();
+        let Some(mut current) = centroids.next() else {
+            return;
+        };
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = total * k_inverse(k(0.0) + 1.0);
+
+        for centroid in centroids {
+            if weight_so_far + current.weight + centroid.weight <= weight_limit {
+                let weight = current.weight + centroid.weight;
+                current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                weight_limit = total * k_inverse(k(weight_so_far / total) + 1.0);
+                merged.push(current);
+                current = centroid;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+}
+
+/// k1 scale function, maps a quantile to an index such that every centroid spans at most one
+/// index.
+fn k(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin()
+}
+
+fn k_inverse(k: f64) -> f64 {
+    ((k * 2.0 * PI / COMPRESSION).sin() + 1.0) / 2.0
+}
+
+fn interpolate(from: f64, to: f64, fraction: f64) -> f64 {
+    from + (to - from) * fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantiles() {
+        let mut digest = TDigest::default();
+        assert_eq!(digest.quantile(0.5), None);
+
+        // add 1..=10000 out of order
+        for i in 0..10_000 {
+            digest.add(((i * 7919) % 10_000 + 1) as f64);
+        }
+        assert!(digest.centroids.len() <= 2 * COMPRESSION as usize);
+
+        for (q, expected) in [(0.5, 5000.0), (0.9, 9000.0), (0.99, 9900.0)] {
+            let actual = digest.quantile(q).unwrap();
+            let error = (actual - expected).abs() / expected;
+            assert!(error < 0.01, "q{q} is {actual}, expected {expected}");
+        }
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(1.0), Some(10_000.0));
+    }
+
+    #[test]
+    fn test_single_value() {
+        let mut digest = TDigest::default();
+        digest.add(42.0);
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), Some(42.0));
+        assert_eq!(digest.quantile(0.99), Some(42.0));
+    }
+}
@@ -0,0 +1,154 @@
+//! Late messages are the messages whose event time is behind the watermark (after the allowed
+//! lateness), so the windows they belong to could already be closed. The reducers drop them unless
+//! the late data side output is configured, in which case they are written to the ISB as-is and
+//! tagged with the configured tag, so that only the edges with the tag in their conditions receive
+//! them. The headers of the message carry the window it missed and the watermark at the time, so
+//! that it can be reconciled or reprocessed downstream.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+use tracing::error;
+
+use crate::Result;
+use crate::config::components::reduce::LateDataConfig;
+use crate::message::Message;
+use crate::pipeline::isb::writer::ISBWriterOrchestrator;
+use crate::typ::NumaflowTypeConfig;
+
+/// Start time (epoch millis) of the window the late message missed. For sliding windows, it is the
+/// start of the earliest window the message belongs to.
+pub(crate) const LATE_WINDOW_START_HEADER: &str = "x-numaflow-late-window-start";
+
+/// End time (epoch millis) of the window the late message missed. For sliding windows, it is the
+/// end of the latest window the message belongs to.
+pub(crate) const LATE_WINDOW_END_HEADER: &str = "x-numaflow-late-window-end";
+
+/// Watermark (epoch millis) of the reducer when the late message was rejected.
+pub(crate) const LATE_WATERMARK_HEADER: &str = "x-numaflow-late-watermark";
+
+/// Why the message was rejected, same as the reason of the drop metric.
+pub(crate) const LATE_REASON_HEADER: &str = "x-numaflow-late-reason";
+
+/// Writes the late messages to the edges with the late data tag.
+pub(crate) struct LateDataWriter {
+    tag: String,
+    tx: mpsc::Sender<Message>,
+}
+
+impl LateDataWriter {
+    /// Starts writing the late messages to the ISB, the returned write task completes once the
+    /// [LateDataWriter] is dropped and all the pending writes are done.
+    pub(crate) async fn start<C: NumaflowTypeConfig>(
+        config: LateDataConfig,
+        isb_writer: ISBWriterOrchestrator<C>,
+        cln_token: CancellationToken,
+    ) -> Result<(Self, JoinHandle<Result<()>>)> {
+        let (tx, rx) = mpsc::channel(100);
+        let handle = isb_writer
+            .streaming_write(ReceiverStream::new(rx), cln_token)
+            .await?;
+        Ok((
+            Self {
+                tag: config.tag,
+                tx,
+            },
+            handle,
+        ))
+    }
+
+    /// Writes the late message, which missed the window from `start_time` to `end_time` because
+    /// the reducer was at `watermark`.
+    pub(crate) async fn write(
+        &self,
+        msg: Message,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        watermark: DateTime<Utc>,
+        reason: &str,
+    ) {
+        let msg = late_message(msg, &self.tag, start_time, end_time, watermark, reason);
+        if self.tx.send(msg).await.is_err() {
+            error!(
+                reason,
+                "Late data writer has stopped, dropping the late message"
+            );
+        }
+    }
+}
+
+/// Tags the late message and adds the headers of the window it missed.
+fn late_message(
+    mut msg: Message,
+    tag: &str,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    watermark: DateTime<Utc>,
+    reason: &str,
+) -> Message {
+    let mut headers = Arc::unwrap_or_clone(msg.headers);
+    headers.insert(
+        LATE_WINDOW_START_HEADER.to_string(),
+        start_time.timestamp_millis().to_string(),
+    );
+    headers.insert(
+        LATE_WINDOW_END_HEADER.to_string(),
+        end_time.timestamp_millis().to_string(),
+    );
+    headers.insert(
+        LATE_WATERMARK_HEADER.to_string(),
+        watermark.timestamp_millis().to_string(),
+    );
+    headers.insert(LATE_REASON_HEADER.to_string(), reason.to_string());
+    msg.headers = Arc::new(headers);
+    msg.tags = Some(Arc::from(vec![tag.to_string()]));
+    msg
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_late_message() {
+        let msg = Message {
+            keys: Arc::from(vec!["key".to_string()]),
+            value: "value".into(),
+            event_time: Utc.timestamp_millis_opt(61000).unwrap(),
+            is_late: true,
+            headers: Arc::new(HashMap::from([("k".to_string(), "v".to_string())])),
+            ..Default::default()
+        };
+
+        let late = late_message(
+            msg,
+            "late",
+            Utc.timestamp_millis_opt(60000).unwrap(),
+            Utc.timestamp_millis_opt(120000).unwrap(),
+            Utc.timestamp_millis_opt(180000).unwrap(),
+            "late-message",
+        );
+
+        assert_eq!(late.tags.as_deref(), Some(&["late".to_string()][..]));
+        assert_eq!(late.value, "value");
+        assert_eq!(late.keys.to_vec(), vec!["key"]);
+        assert_eq!(
+            *late.headers,
+            HashMap::from([
+                ("k".to_string(), "v".to_string()),
+                (LATE_WINDOW_START_HEADER.to_string(), "60000".to_string()),
+                (LATE_WINDOW_END_HEADER.to_string(), "120000".to_string()),
+                (LATE_WATERMARK_HEADER.to_string(), "180000".to_string()),
+                (LATE_REASON_HEADER.to_string(), "late-message".to_string()),
+            ])
+        );
+    }
+}
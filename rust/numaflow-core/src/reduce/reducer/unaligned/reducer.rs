@@ -1,6 +1,8 @@
+use crate::config::components::reduce::LateDataConfig;
 use crate::error::Error;
 use crate::message::{Message, MessageType};
 use crate::pipeline::isb::writer::ISBWriterOrchestrator;
+use crate::reduce::reducer::late_data::LateDataWriter;
 use crate::reduce::reducer::unaligned::user_defined::UserDefinedUnalignedReduce;
 use crate::reduce::reducer::unaligned::user_defined::accumulator::UserDefinedAccumulator;
 use crate::reduce::reducer::unaligned::user_defined::session::UserDefinedSessionReduce;
@@ -8,6 +10,7 @@ use crate::reduce::reducer::unaligned::windower::{
     UnalignedWindowManager, UnalignedWindowMessage, Window,
 };
 use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
+use crate::reduce::wal::snapshot::{SnapshotConfig, load_snapshot, write_snapshot};
 use crate::typ::NumaflowTypeConfig;
 
 use crate::config::{get_vertex_name, get_vertex_replica};
@@ -517,8 +520,14 @@ pub(crate) struct UnalignedReducer<C: NumaflowTypeConfig> {
     shutting_down_on_err: bool,
     /// WAL for writing GC events
     gc_wal: Option<AppendOnlyWal>,
+    /// Snapshot of the windows saved periodically, used as GC boundary of the WAL.
+    snapshot: Option<SnapshotConfig>,
     /// Allowed lateness for the messages to be accepted and delay the close of book.
     allowed_lateness: Duration,
+    /// Side output for the late messages, they are dropped if not configured.
+    late_data: Option<LateDataConfig>,
+    /// Writer of the late messages, set once the component is started with late data configured.
+    late_data_writer: Option<LateDataWriter>,
     /// current watermark for the reduce vertex.
     current_watermark: DateTime<Utc>,
     /// Whether the reduce is keyed or not.
@@ -535,7 +544,9 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
         window_manager: UnalignedWindowManager,
         isb_writer: ISBWriterOrchestrator<C>,
         allowed_lateness: Duration,
+        late_data: Option<LateDataConfig>,
         gc_wal: Option<AppendOnlyWal>,
+        snapshot: Option<SnapshotConfig>,
         graceful_timeout: Duration,
         keyed: bool,
     ) -> Self {
@@ -546,7 +557,10 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
             final_result: Ok(()),
             shutting_down_on_err: false,
             gc_wal,
+            snapshot,
             allowed_lateness,
+            late_data,
+            late_data_writer: None,
             current_watermark: DateTime::from_timestamp_millis(-1).expect("Invalid timestamp"),
             keyed,
             graceful_timeout,
@@ -571,7 +585,6 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
         // the one that calls shutdown
         let hard_shutdown_token_owner = hard_shutdown_token.clone();
         let graceful_timeout = self.graceful_timeout;
-        let keyed = self.keyed;
 
         // spawn a task to cancel the token after graceful timeout when the main token is cancelled
         let shutdown_handle = tokio::spawn(async move {
@@ -601,6 +614,18 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
             actor.run().await;
         });
 
+        // start writing the late messages to the side output if configured
+        let late_data_handle = self
+            .setup_late_data_writer(hard_shutdown_token.clone())
+            .await?;
+
+        // restore the windows from the last snapshot before the messages are replayed (the
+        // compactor only replays the messages which the snapshot does not cover), and start saving
+        // the snapshots of the windows if configured
+        self.restore_snapshot().await;
+        let snapshot_token = CancellationToken::new();
+        let snapshot_handle = self.setup_snapshot_writer(snapshot_token.clone());
+
         // Start the main task
         let handle = tokio::spawn(async move {
             let mut input_stream = input_stream;
@@ -615,7 +640,7 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                     // Process input messages with timeout
                     read_msg = input_stream.next() => {
                         match read_msg {
-                            Some(mut msg) => {
+                            Some(msg) => {
                                 // If shutting down, drain the stream
                                 if self.shutting_down_on_err {
                                     info!("Unaligned reducer is in shutdown mode, ignoring the message");
@@ -634,12 +659,6 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                                     continue; // Skip further processing for WMB messages
                                 }
 
-
-                                // If the stream is not keyed, set all messages to have the same key
-                                if !keyed {
-                                    msg.keys = Arc::new([DEFAULT_KEY_FOR_NON_KEYED_STREAM.to_string()]);
-                                }
-
                                 self.assign_and_close_windows(msg, &actor_tx).await;
                             }
                             None => {
@@ -662,6 +681,24 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                 error!("Error waiting for actor to complete: {:?}", e);
             }
 
+            // Stop the snapshot writer, it saves the final snapshot of the windows before exiting
+            snapshot_token.cancel();
+            if let Some(snapshot_handle) = snapshot_handle
+                && let Err(e) = snapshot_handle.await
+            {
+                error!(?e, "Error waiting for the snapshot writer to complete");
+            }
+
+            // Drop the late data writer and wait for the pending late messages to be written
+            drop(self.late_data_writer.take());
+            if let Some(late_data_handle) = late_data_handle
+                && let Err(e) = late_data_handle
+                    .await
+                    .expect("late data writer task failed")
+            {
+                error!(?e, "Error while writing late messages");
+            }
+
             // abort the shutdown handle since we are done processing, no need to wait for the
             // hard shutdown.
             shutdown_handle.abort();
@@ -684,6 +721,78 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
         }
     }
 
+    /// Restores the window manager from the last snapshot of the windows if there is one. The WAL
+    /// replay skips the messages which the snapshot has marked as processed (see
+    /// [WindowSnapshot::is_processed]), so the GC end times of the keys are restored from the
+    /// snapshot while the pending messages are replayed to rebuild the windows. A snapshot which
+    /// cannot be read is ignored, the windows are then rebuilt from the replay alone.
+    ///
+    /// [WindowSnapshot::is_processed]: crate::reduce::wal::snapshot::WindowSnapshot::is_processed
+    async fn restore_snapshot(&self) {
+        let Some(snapshot) = &self.snapshot else {
+            return;
+        };
+        let path = snapshot.path.clone();
+        match tokio::task::spawn_blocking(move || load_snapshot(&path)).await {
+            Ok(Ok(Some(state))) => {
+                info!(
+                    offset = state.offset,
+                    keys = state.states.len(),
+                    "Restoring window snapshot"
+                );
+                self.window_manager.restore(state);
+            }
+            Ok(Ok(None)) => {}
+            Ok(Err(e)) => warn!(?e, "Failed to load the snapshot of the windows"),
+            Err(e) => error!(?e, "Snapshot loader task failed"),
+        }
+    }
+
+    /// Starts saving the snapshot of the windows periodically if configured. A final snapshot is
+    /// saved when the token is cancelled.
+    fn setup_snapshot_writer(&mut self, cln_token: CancellationToken) -> Option<JoinHandle<()>> {
+        let snapshot = self.snapshot.take()?;
+        let window_manager = self.window_manager.clone();
+
+        Some(tokio::spawn(async move {
+            let mut tick = tokio::time::interval(snapshot.interval);
+            // skip the first tick, which completes immediately
+            tick.tick().await;
+            loop {
+                let stop = tokio::select! {
+                    _ = tick.tick() => false,
+                    _ = cln_token.cancelled() => true,
+                };
+
+                let state = window_manager.snapshot();
+                let path = snapshot.path.clone();
+                match tokio::task::spawn_blocking(move || write_snapshot(&path, &state)).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => warn!(?e, "Failed to save the snapshot of the windows"),
+                    Err(e) => error!(?e, "Snapshot writer task failed"),
+                }
+
+                if stop {
+                    break;
+                }
+            }
+        }))
+    }
+
+    /// Starts the late data writer if the late data side output is configured.
+    async fn setup_late_data_writer(
+        &mut self,
+        cln_token: CancellationToken,
+    ) -> crate::Result<Option<JoinHandle<crate::Result<()>>>> {
+        let Some(late_data) = self.late_data.take() else {
+            return Ok(None);
+        };
+        let (writer, handle) =
+            LateDataWriter::start(late_data, self.isb_writer.clone(), cln_token).await?;
+        self.late_data_writer = Some(writer);
+        Ok(Some(handle))
+    }
+
     /// handle errors from the reduce tasks by cancelling token so that upstream knows there is an
     /// issue and stops sending new messages.
     fn handle_error(&mut self, error: Error, cln_token: &CancellationToken) {
@@ -717,7 +826,7 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
     /// that can be closed based on the current watermark.
     async fn assign_and_close_windows(
         &mut self,
-        msg: Message,
+        mut msg: Message,
         actor_tx: &mpsc::Sender<UnalignedWindowMessage>,
     ) {
         // Update the watermark - use max to ensure it never regresses (only after accepting the message)
@@ -733,14 +842,15 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                 // this can happen when the pipeline is just started, and we receive a late message
                 // before we receive any watermark.
                 warn!(event_time = ?msg.event_time.timestamp_millis(), watermark = ?self.current_watermark.timestamp_millis(), "Late message detected, but watermark is -1, dropping message");
-                increment_late_message_drop_metric("no-valid-wm-late-message");
+                self.reject_late_message(msg, "no-valid-wm-late-message")
+                    .await;
                 return;
             }
 
             // Drop the late message if it is outside the allowed lateness window.
             if msg.event_time < self.current_watermark.sub(self.allowed_lateness) {
                 debug!(event_time = ?msg.event_time.timestamp_millis(), watermark = ?self.current_watermark.timestamp_millis(), "Late message detected, dropping");
-                increment_late_message_drop_metric("late-message");
+                self.reject_late_message(msg, "late-message").await;
                 return;
             }
         }
@@ -754,7 +864,7 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                 offset = ?msg.offset,
                 "Old message popped up, Watermark has progressed past event time"
             );
-            increment_late_message_drop_metric("old-message-popped-up");
+            self.reject_late_message(msg, "old-message-popped-up").await;
             return;
         }
 
@@ -762,6 +872,11 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
         self.close_windows_by_wm(self.current_watermark, actor_tx)
             .await;
 
+        // If the stream is not keyed, set all messages to have the same key
+        if !self.keyed {
+            msg.keys = Arc::new([DEFAULT_KEY_FOR_NON_KEYED_STREAM.to_string()]);
+        }
+
         // Assign windows to the message
         let window_messages = self.window_manager.assign_windows(msg);
 
@@ -773,6 +888,25 @@ impl<C: NumaflowTypeConfig> UnalignedReducer<C> {
                 .expect("Failed to send window message");
         }
     }
+
+    /// Writes the late message to the late data side output along with the window it missed, or
+    /// drops it if the side output is not configured.
+    async fn reject_late_message(&self, msg: Message, reason: &str) {
+        let Some(late_data_writer) = &self.late_data_writer else {
+            increment_late_message_drop_metric(reason);
+            return;
+        };
+        let window = self.window_manager.message_window(&msg);
+        late_data_writer
+            .write(
+                msg,
+                window.start_time,
+                window.end_time,
+                self.current_watermark,
+                reason,
+            )
+            .await;
+    }
 }
 
 /// Increment the late message drop metric counter.
@@ -1039,7 +1173,9 @@ mod tests {
             window_manager,
             isb_writer,
             Duration::from_secs(0), // No allowed lateness for testing
-            None,                   // No GC WAL for testing
+            None,
+            None, // No GC WAL for testing
+            None, // No snapshot for testing
             Duration::from_millis(50),
             true, // No watermark handle for testing
         )
@@ -1276,7 +1412,9 @@ mod tests {
             window_manager,
             isb_writer,
             Duration::from_secs(0), // No allowed lateness for testing
-            None,                   // No GC WAL for testing
+            None,
+            None, // No GC WAL for testing
+            None, // No snapshot for testing
             Duration::from_millis(50),
             true, // No watermark handle for testing
         )
@@ -1464,6 +1602,8 @@ mod tests {
     #[cfg(feature = "nats-tests")]
     #[tokio::test]
     async fn test_unaligned_accumulator_reducer_basic() -> crate::Result<()> {
+        use crate::reduce::wal::snapshot::{WINDOW_SNAPSHOT_FILE, WindowSnapshot};
+
         // Set up the accumulator reducer server
         let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
         let tmp_dir = TempDir::new().unwrap();
@@ -1576,7 +1716,12 @@ mod tests {
             window_manager,
             isb_writer,
             Duration::from_secs(0), // No allowed lateness for testing
-            None,                   // No GC WAL for testing
+            None,
+            None, // No GC WAL for testing
+            Some(SnapshotConfig {
+                path: tmp_dir.path().join(WINDOW_SNAPSHOT_FILE),
+                interval: Duration::from_secs(60),
+            }),
             Duration::from_millis(50),
             true,
         )
@@ -1664,6 +1809,13 @@ mod tests {
         // Wait for the reducer to complete
         reducer_handle.await.expect("reducer handle failed")?;
 
+        // the final snapshot of the windows is saved on shutdown
+        assert!(
+            WindowSnapshot::load(&tmp_dir.path().join(WINDOW_SNAPSHOT_FILE))
+                .unwrap()
+                .is_some()
+        );
+
         // Shutdown the server
         shutdown_tx
             .send(())
@@ -1794,7 +1946,9 @@ mod tests {
             window_manager,
             isb_writer,
             Duration::from_secs(0), // No allowed lateness for testing
-            None,                   // No GC WAL for testing
+            None,
+            None, // No GC WAL for testing
+            None, // No snapshot for testing
             Duration::from_millis(50),
             true,
         )
@@ -2046,7 +2200,9 @@ mod tests {
             window_manager,
             isb_writer,
             Duration::from_secs(0), // No allowed lateness for testing
-            None,                   // No GC WAL for testing
+            None,
+            None, // No GC WAL for testing
+            None, // No snapshot for testing
             Duration::from_millis(50),
             true,
         )
@@ -2,17 +2,20 @@
 //! messages and closing windows when there are no messages for a timeout duration. In case of
 //! unaligned, windows are tracked at the key level and assignment happens based on the event-time
 //! of the message.
-use crate::message::Message;
+use crate::message::{Message, Offset};
 use crate::reduce::reducer::unaligned::windower::accumulator::AccumulatorWindowManager;
 use crate::reduce::reducer::unaligned::windower::session::SessionWindowManager;
 use crate::shared::grpc::{prost_timestamp_from_utc, utc_from_timestamp};
 use chrono::{DateTime, Utc};
 use numaflow_pb::clients::accumulator::KeyedWindow;
 use numaflow_pb::clients::sessionreduce;
-use numaflow_pb::objects::wal::GcEvent;
+use numaflow_pb::objects::wal::{GcEvent, KeyedWindowState, UnalignedWindowManagerState};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::sync::Arc;
+use std::sync::atomic::{self, AtomicI64};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 pub(crate) mod accumulator;
 pub(crate) mod session;
@@ -105,6 +108,15 @@ impl From<&Window> for GcEvent {
     }
 }
 
+impl From<&Window> for numaflow_pb::objects::wal::Window {
+    fn from(value: &Window) -> Self {
+        Self {
+            start_time: Some(prost_timestamp_from_utc(value.start_time)),
+            end_time: Some(prost_timestamp_from_utc(value.end_time)),
+        }
+    }
+}
+
 impl Window {
     /// Creates a new Window.
     pub(crate) fn new(
@@ -170,6 +182,14 @@ impl UnalignedWindowManager {
         }
     }
 
+    /// Returns the window the message would open without assigning it.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        match self {
+            UnalignedWindowManager::Accumulator(manager) => manager.message_window(msg),
+            UnalignedWindowManager::Session(manager) => manager.message_window(msg),
+        }
+    }
+
     /// Closes any windows that can be closed because the Watermark has advanced beyond the window
     /// end time.
     pub(crate) fn close_windows(&self, watermark: DateTime<Utc>) -> Vec<UnalignedWindowMessage> {
@@ -194,6 +214,134 @@ impl UnalignedWindowManager {
             UnalignedWindowManager::Session(manager) => manager.oldest_window_end_time(),
         }
     }
+
+    /// Returns the snapshot of the windows, which is used as GC boundary of the [WAL].
+    ///
+    /// [WAL]: crate::reduce::wal::snapshot
+    pub(crate) fn snapshot(&self) -> UnalignedWindowManagerState {
+        match self {
+            UnalignedWindowManager::Accumulator(manager) => manager.snapshot(),
+            UnalignedWindowManager::Session(manager) => manager.snapshot(),
+        }
+    }
+
+    /// Restores the state of the windows from the snapshot saved before the restart. The windows
+    /// are rebuilt by replaying the pending messages from the WAL, since the state of the reduce
+    /// function is not part of the snapshot.
+    pub(crate) fn restore(&self, state: UnalignedWindowManagerState) {
+        match self {
+            UnalignedWindowManager::Accumulator(manager) => manager.restore(state),
+            UnalignedWindowManager::Session(manager) => manager.restore(state),
+        }
+    }
+}
+
+/// GC end time of every key (combinedKey -> (keys, end time)).
+type GcEndTimeStore = Arc<RwLock<HashMap<String, (Arc<[String]>, DateTime<Utc>)>>>;
+
+/// Tracks the position of the windows in the WAL and the end times till which the messages of every
+/// key have been garbage collected, for the snapshot of the windows.
+#[derive(Debug, Clone)]
+pub(crate) struct SnapshotTracker {
+    /// Offset of the latest message assigned to the windows.
+    offset: Arc<AtomicI64>,
+    /// GC end time of every key. The end times are kept after the windows of the key are closed,
+    /// till the watermark is past them by the timeout.
+    gc_end_times: GcEndTimeStore,
+}
+
+impl SnapshotTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            offset: Arc::new(AtomicI64::new(-1)),
+            gc_end_times: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Tracks the offset of the message assigned to the windows. It has to be called while holding
+    /// the lock of the windows, so that the snapshot is consistent with the offset.
+    pub(crate) fn assigned(&self, msg: &Message) {
+        if let Offset::Int(offset) = &msg.offset {
+            self.offset
+                .fetch_max(offset.offset, atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Tracks the end time of the garbage collected window.
+    pub(crate) fn garbage_collected(&self, window: &Window) {
+        let mut gc_end_times = self.gc_end_times.write().expect("Poisoned lock");
+        let entry = gc_end_times
+            .entry(combine_keys(&window.keys))
+            .or_insert_with(|| (Arc::clone(&window.keys), window.end_time));
+        entry.1 = entry.1.max(window.end_time);
+    }
+
+    /// Forgets the GC end time of the key.
+    pub(crate) fn forget(&self, key: &str) {
+        self.gc_end_times
+            .write()
+            .expect("Poisoned lock")
+            .remove(key);
+    }
+
+    /// Removes the GC end times of the keys without windows once the watermark is past them by the
+    /// timeout.
+    pub(crate) fn prune(
+        &self,
+        watermark: DateTime<Utc>,
+        timeout: Duration,
+        has_windows: impl Fn(&str) -> bool,
+    ) {
+        let timeout = chrono::Duration::from_std(timeout).unwrap_or(chrono::Duration::MAX);
+        self.gc_end_times
+            .write()
+            .expect("Poisoned lock")
+            .retain(|key, (_, end_time)| {
+                has_windows(key) || end_time.checked_add_signed(timeout) > Some(watermark)
+            });
+    }
+
+    /// Restores the GC end times of the keys from the snapshot saved before the restart. The offset
+    /// is not restored, it catches up as the pending messages are replayed from the WAL, so that a
+    /// snapshot saved during the replay never covers messages which are not in the windows yet.
+    pub(crate) fn restore(&self, state: UnalignedWindowManagerState) {
+        let mut gc_end_times = self.gc_end_times.write().expect("Poisoned lock");
+        for key_state in state.states {
+            if let Some(end_time) = key_state.gc_end_time {
+                gc_end_times.insert(
+                    combine_keys(&key_state.keys),
+                    (Arc::from(key_state.keys), utc_from_timestamp(end_time)),
+                );
+            }
+        }
+    }
+
+    /// Offset of the latest message assigned to the windows.
+    pub(crate) fn offset(&self) -> i64 {
+        self.offset.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Creates the snapshot from the states of the keys with windows, adding the GC end times.
+    pub(crate) fn snapshot(
+        &self,
+        offset: i64,
+        mut states: HashMap<String, KeyedWindowState>,
+    ) -> UnalignedWindowManagerState {
+        for (key, (keys, end_time)) in self.gc_end_times.read().expect("Poisoned lock").iter() {
+            states
+                .entry(key.clone())
+                .or_insert_with(|| KeyedWindowState {
+                    keys: keys.to_vec(),
+                    ..Default::default()
+                })
+                .gc_end_time = Some(prost_timestamp_from_utc(*end_time));
+        }
+
+        UnalignedWindowManagerState {
+            offset,
+            states: states.into_values().collect(),
+        }
+    }
 }
 
 /// Combines keys into a single string for use as a map key
@@ -30,9 +30,12 @@ use std::time::Duration;
 
 use crate::message::Message;
 use crate::reduce::reducer::unaligned::windower::{
-    SHARED_PNF_SLOT, UnalignedWindowMessage, UnalignedWindowOperation, Window, combine_keys,
+    SHARED_PNF_SLOT, SnapshotTracker, UnalignedWindowMessage, UnalignedWindowOperation, Window,
+    combine_keys,
 };
+use crate::shared::grpc::prost_timestamp_from_utc;
 use chrono::{DateTime, Utc};
+use numaflow_pb::objects::wal::{KeyedWindowState, UnalignedWindowManagerState};
 
 /// Represents the key global state of an accumulator window.
 #[derive(Debug, Clone)]
@@ -116,6 +119,8 @@ pub(crate) struct AccumulatorWindowManager {
     timeout: Duration,
     /// Active windows mapped by combined key.
     active_windows: Arc<RwLock<HashMap<String, WindowState>>>,
+    /// Tracks the offset and the GC end times for the snapshot.
+    snapshot_tracker: SnapshotTracker,
 }
 
 impl AccumulatorWindowManager {
@@ -124,9 +129,19 @@ impl AccumulatorWindowManager {
         Self {
             timeout,
             active_windows: Arc::new(RwLock::new(HashMap::new())),
+            snapshot_tracker: SnapshotTracker::new(),
         }
     }
 
+    /// Returns the window the message would open for a new key, without assigning it.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        Window::new(
+            msg.event_time,
+            msg.event_time + chrono::Duration::from_std(self.timeout).unwrap(),
+            Arc::clone(&msg.keys),
+        )
+    }
+
     /// Assigns windows to a message. For accumulator windows, each key gets exactly one window.
     pub(crate) fn assign_windows(&self, msg: Message) -> Vec<UnalignedWindowMessage> {
         let combined_key = combine_keys(&msg.keys);
@@ -134,6 +149,7 @@ impl AccumulatorWindowManager {
         let keys = Arc::clone(&msg.keys);
 
         let mut active_windows = self.active_windows.write().expect("Poisoned lock");
+        self.snapshot_tracker.assigned(&msg);
         // Check if a window already exists for the key, if exits we can do append else we will have
         // to create a new window for the key
         if let Some(window_state) = active_windows.get(&combined_key) {
@@ -176,13 +192,18 @@ impl AccumulatorWindowManager {
         let mut active_windows = self.active_windows.write().expect("Poisoned lock");
 
         // Iterate and remove inactive windows
-        active_windows.retain(|_, window_state| {
+        active_windows.retain(|key, window_state| {
             let last_seen = *window_state
                 .last_seen_event_time
                 .read()
                 .expect("Poisoned lock");
 
             if watermark > last_seen + self.timeout {
+                // the pending messages are no longer tracked once the window is closed, forget the
+                // GC end time so that the snapshot does not consider them processed.
+                if window_state.oldest_timestamp().is_some() {
+                    self.snapshot_tracker.forget(key);
+                }
                 result.push(UnalignedWindowMessage {
                     operation: UnalignedWindowOperation::Close {
                         window: window_state.window.clone(),
@@ -195,6 +216,10 @@ impl AccumulatorWindowManager {
             }
         });
 
+        self.snapshot_tracker.prune(watermark, self.timeout, |key| {
+            active_windows.contains_key(key)
+        });
+
         result
     }
 
@@ -207,6 +232,41 @@ impl AccumulatorWindowManager {
         if let Some(window_state) = active_windows.get(&combined_key) {
             window_state.delete_timestamps_before(window.end_time);
         }
+        self.snapshot_tracker.garbage_collected(&window);
+    }
+
+    /// Returns the snapshot of the windows with the timestamps of the pending messages.
+    pub(crate) fn snapshot(&self) -> UnalignedWindowManagerState {
+        // the offset is read while holding the lock, so that every message till the offset is either
+        // pending in the windows or has been garbage collected.
+        let active_windows = self.active_windows.read().expect("Poisoned lock");
+        let offset = self.snapshot_tracker.offset();
+        let states = active_windows
+            .iter()
+            .map(|(key, window_state)| {
+                let message_timestamps = window_state
+                    .message_timestamps
+                    .read()
+                    .expect("Poisoned lock")
+                    .iter()
+                    .map(|timestamp| prost_timestamp_from_utc(*timestamp))
+                    .collect();
+                let state = KeyedWindowState {
+                    keys: window_state.window.keys.to_vec(),
+                    message_timestamps,
+                    ..Default::default()
+                };
+                (key.clone(), state)
+            })
+            .collect();
+        drop(active_windows);
+
+        self.snapshot_tracker.snapshot(offset, states)
+    }
+
+    /// Restores the GC end times of the keys from the snapshot saved before the restart.
+    pub(crate) fn restore(&self, state: UnalignedWindowManagerState) {
+        self.snapshot_tracker.restore(state);
     }
 
     /// Returns the oldest event time across all windows.
@@ -223,6 +283,9 @@ impl AccumulatorWindowManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message::{IntOffset, Offset};
+    use crate::reduce::wal::snapshot::WindowSnapshot;
+    use chrono::TimeZone;
     use std::sync::Arc;
 
     #[test]
@@ -406,4 +469,86 @@ mod tests {
         let oldest_time = windower.oldest_window_end_time().unwrap();
         assert_eq!(oldest_time, msg1.event_time);
     }
+
+    #[test]
+    fn test_snapshot() {
+        let windower = AccumulatorWindowManager::new(Duration::from_secs(60));
+        let base_time = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let keys: Arc<[String]> = Arc::from(vec!["test_key".to_string()]);
+
+        let messages: Vec<Message> = (1..=3)
+            .map(|i| Message {
+                keys: Arc::clone(&keys),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                event_time: base_time + chrono::Duration::seconds(i * 10),
+                ..Default::default()
+            })
+            .collect();
+        for msg in &messages {
+            windower.assign_windows(msg.clone());
+        }
+
+        // nothing has been garbage collected yet
+        let snapshot = WindowSnapshot::from(windower.snapshot());
+        assert!(!snapshot.is_processed(1, &keys, base_time + chrono::Duration::seconds(10)));
+
+        // the first two messages are processed
+        let window = Window::new(
+            base_time,
+            base_time + chrono::Duration::seconds(25),
+            Arc::clone(&keys),
+        );
+        windower.delete_window(window);
+
+        let state = windower.snapshot();
+        assert_eq!(state.offset, 3);
+        let snapshot = WindowSnapshot::from(state);
+        assert!(snapshot.is_processed(1, &keys, base_time + chrono::Duration::seconds(10)));
+        assert!(snapshot.is_processed(2, &keys, base_time + chrono::Duration::seconds(20)));
+        assert!(!snapshot.is_processed(3, &keys, base_time + chrono::Duration::seconds(30)));
+
+        // the window is closed with a pending message, the snapshot does not know about the key
+        // anymore
+        windower.close_windows(base_time + chrono::Duration::seconds(100));
+        let snapshot = WindowSnapshot::from(windower.snapshot());
+        assert!(!snapshot.is_processed(1, &keys, base_time + chrono::Duration::seconds(10)));
+    }
+
+    #[test]
+    fn test_restore() {
+        let windower = AccumulatorWindowManager::new(Duration::from_secs(60));
+        let base_time = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let keys: Arc<[String]> = Arc::from(vec!["test_key".to_string()]);
+
+        let msg = Message {
+            keys: Arc::clone(&keys),
+            offset: Offset::Int(IntOffset::new(1, 0)),
+            event_time: base_time + chrono::Duration::seconds(10),
+            ..Default::default()
+        };
+        windower.assign_windows(msg);
+        windower.delete_window(Window::new(
+            base_time,
+            base_time + chrono::Duration::seconds(25),
+            Arc::clone(&keys),
+        ));
+
+        // the restarted windower knows which messages of the key have been processed, the offset
+        // catches up when the messages are replayed
+        let restored = AccumulatorWindowManager::new(Duration::from_secs(60));
+        restored.restore(windower.snapshot());
+        let state = restored.snapshot();
+        assert_eq!(state.offset, -1);
+        assert_eq!(state.states.len(), 1);
+
+        restored.assign_windows(Message {
+            keys: Arc::clone(&keys),
+            offset: Offset::Int(IntOffset::new(2, 0)),
+            event_time: base_time + chrono::Duration::seconds(30),
+            ..Default::default()
+        });
+        let snapshot = WindowSnapshot::from(restored.snapshot());
+        assert!(snapshot.is_processed(1, &keys, base_time + chrono::Duration::seconds(10)));
+        assert!(!snapshot.is_processed(2, &keys, base_time + chrono::Duration::seconds(30)));
+    }
 }
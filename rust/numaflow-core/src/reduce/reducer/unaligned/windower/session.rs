@@ -29,9 +29,10 @@ use std::time::Duration;
 use crate::message::Message;
 use crate::reduce::reducer::unaligned::windower;
 use crate::reduce::reducer::unaligned::windower::{
-    SHARED_PNF_SLOT, UnalignedWindowMessage, UnalignedWindowOperation, Window,
+    SHARED_PNF_SLOT, SnapshotTracker, UnalignedWindowMessage, UnalignedWindowOperation, Window,
 };
 use chrono::{DateTime, Utc};
+use numaflow_pb::objects::wal::{KeyedWindowState, UnalignedWindowManagerState};
 
 /// Active session windows for every key combination (combinedKey -> Sorted window set)
 type ActiveWindowStore = Arc<RwLock<HashMap<String, BTreeSet<Window>>>>;
@@ -52,6 +53,8 @@ pub(crate) struct SessionWindowManager {
     active_windows: ActiveWindowStore,
     /// Closed windows sorted by end time. These windows have been closed but not yet garbage collected.
     closed_windows: ClosedWindowStore,
+    /// Tracks the offset and the GC end times for the snapshot.
+    snapshot_tracker: SnapshotTracker,
 }
 
 impl SessionWindowManager {
@@ -60,9 +63,24 @@ impl SessionWindowManager {
             timeout,
             active_windows: Arc::new(RwLock::new(HashMap::new())),
             closed_windows: Arc::new(RwLock::new(BTreeSet::new())),
+            snapshot_tracker: SnapshotTracker::new(),
         }
     }
 
+    /// Returns the window the message would open without assigning it, before it is merged with the
+    /// existing windows of the key.
+    pub(crate) fn message_window(&self, msg: &Message) -> Window {
+        let start_time_millis = msg.event_time.timestamp_millis();
+        Window::new(
+            chrono::DateTime::from_timestamp_millis(start_time_millis).unwrap(),
+            chrono::DateTime::from_timestamp_millis(
+                start_time_millis + self.timeout.as_millis() as i64,
+            )
+            .unwrap(),
+            Arc::clone(&msg.keys),
+        )
+    }
+
     /// Assigns windows to a message, we create a new window for the key with start time as the event time
     /// and end time as the event time + timeout.
     /// * If the start and end time of the existing key is same - append operation
@@ -85,6 +103,7 @@ impl SessionWindowManager {
         let new_window = Window::new(start_time, end_time, keys);
 
         let mut active_windows = self.active_windows.write().expect("Poisoned lock");
+        self.snapshot_tracker.assigned(&msg);
         let window_set = active_windows.entry(combined_key).or_default();
 
         if let Some(existing_window) = Self::find_window_to_merge(window_set, &new_window) {
@@ -175,17 +194,26 @@ impl SessionWindowManager {
     /// window.end_time might grow overtime and overlap. At the time of close, we merge these windows
     /// those have overlapping end times.
     pub(crate) fn close_windows(&self, watermark: DateTime<Utc>) -> Vec<UnalignedWindowMessage> {
+        // the lock is held till the windows are moved to the closed windows, so that the snapshot
+        // always finds the windows either in the active or in the closed windows.
+        let mut active_windows = self.active_windows.write().expect("Poisoned lock");
+
         // Extract and remove expired windows from active windows
-        let closed_windows_by_key = {
-            let mut active_windows = self.active_windows.write().expect("Poisoned lock");
-            Self::extract_expired_windows(&mut active_windows, watermark)
-        };
+        let closed_windows_by_key = Self::extract_expired_windows(&mut active_windows, watermark);
 
         // Process each key's closed windows
-        closed_windows_by_key
+        let messages = closed_windows_by_key
             .into_iter()
-            .flat_map(|(key, windows)| self.process_closed_windows(&key, windows))
-            .collect()
+            .flat_map(|(key, windows)| {
+                self.process_closed_windows(&mut active_windows, &key, windows)
+            })
+            .collect();
+
+        self.snapshot_tracker.prune(watermark, self.timeout, |key| {
+            active_windows.contains_key(key)
+        });
+
+        messages
     }
 
     /// Extract expired windows from the window set. These are the windows that have not received any
@@ -223,12 +251,13 @@ impl SessionWindowManager {
     /// and then close the windows that cannot be merged.
     fn process_closed_windows(
         &self,
+        active_windows: &mut HashMap<String, BTreeSet<Window>>,
         key: &str,
         closed_windows: Vec<Window>,
     ) -> Vec<UnalignedWindowMessage> {
         Self::windows_that_can_be_merged(&closed_windows)
             .into_iter()
-            .filter_map(|group| self.process_close_window_group(key, group))
+            .filter_map(|group| self.process_close_window_group(active_windows, key, group))
             .flatten()
             .collect()
     }
@@ -236,6 +265,7 @@ impl SessionWindowManager {
     /// Process multiple closing windows by merging them first, then attempting to merge with active windows
     fn process_close_window_group(
         &self,
+        active_windows: &mut HashMap<String, BTreeSet<Window>>,
         key: &str,
         closing_group: Vec<Window>,
     ) -> Option<Vec<UnalignedWindowMessage>> {
@@ -246,17 +276,15 @@ impl SessionWindowManager {
         let mut messages = Vec::new();
         let (merge_result, window) = if closing_group.len() == 1 {
             let window = closing_group.into_iter().next().unwrap();
-            let mut active_windows = self.active_windows.write().expect("Poisoned lock");
             (
-                Self::try_merge_with_active(&mut active_windows, key, &window),
+                Self::try_merge_with_active(active_windows, key, &window),
                 window,
             )
         } else {
             let (merged_window, initial_merge_msg) = Self::merge_windows(&closing_group);
             messages.push(initial_merge_msg);
-            let mut active_windows = self.active_windows.write().expect("Poisoned lock");
             (
-                Self::try_merge_with_active(&mut active_windows, key, &merged_window),
+                Self::try_merge_with_active(active_windows, key, &merged_window),
                 merged_window,
             )
         };
@@ -404,6 +432,46 @@ impl SessionWindowManager {
             .write()
             .expect("Poisoned lock")
             .remove(&window);
+        self.snapshot_tracker.garbage_collected(&window);
+    }
+
+    /// Returns the snapshot of the active and closed windows which are not garbage collected yet.
+    pub(crate) fn snapshot(&self) -> UnalignedWindowManagerState {
+        let mut states: HashMap<String, KeyedWindowState> = HashMap::new();
+
+        // the offset is read while holding the lock, so that every message till the offset is either
+        // in the windows or has been garbage collected.
+        let active_windows = self.active_windows.read().expect("Poisoned lock");
+        let offset = self.snapshot_tracker.offset();
+        let closed_windows = self.closed_windows.read().expect("Poisoned lock");
+
+        let windows = active_windows
+            .iter()
+            .flat_map(|(key, windows)| windows.iter().map(move |window| (key.clone(), window)))
+            .chain(
+                closed_windows
+                    .iter()
+                    .map(|window| (windower::combine_keys(&window.keys), window)),
+            );
+        for (key, window) in windows {
+            states
+                .entry(key)
+                .or_insert_with(|| KeyedWindowState {
+                    keys: window.keys.to_vec(),
+                    ..Default::default()
+                })
+                .windows
+                .push(window.into());
+        }
+        drop(closed_windows);
+        drop(active_windows);
+
+        self.snapshot_tracker.snapshot(offset, states)
+    }
+
+    /// Restores the GC end times of the keys from the snapshot saved before the restart.
+    pub(crate) fn restore(&self, state: UnalignedWindowManagerState) {
+        self.snapshot_tracker.restore(state);
     }
 
     /// Returns the end time of the oldest window among both active and closed windows
@@ -431,6 +499,9 @@ impl SessionWindowManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message::{IntOffset, Offset};
+    use crate::reduce::wal::snapshot::WindowSnapshot;
+    use chrono::TimeZone;
     use std::sync::Arc;
 
     #[test]
@@ -465,6 +536,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_message_window() {
+        // Create session windower with 60s timeout
+        let windower = SessionWindowManager::new(Duration::from_secs(60));
+
+        let msg = Message {
+            keys: Arc::from(vec!["test_key".to_string()]),
+            event_time: chrono::DateTime::from_timestamp_millis(60000).unwrap(),
+            ..Default::default()
+        };
+
+        let window = windower.message_window(&msg);
+        assert_eq!(window.start_time.timestamp_millis(), 60000);
+        assert_eq!(window.end_time.timestamp_millis(), 120000);
+        assert_eq!(window.keys, msg.keys);
+
+        // the message is not assigned to any window
+        assert!(windower.oldest_window_end_time().is_none());
+    }
+
     #[test]
     fn test_assign_windows_existing_key() {
         // Create session windower with 60s timeout
@@ -1095,4 +1186,45 @@ mod tests {
             _ => panic!("Expected Open operation for different key"),
         }
     }
+
+    #[test]
+    fn test_snapshot() {
+        let windower = SessionWindowManager::new(Duration::from_secs(10));
+        let base_time = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let keys: Arc<[String]> = Arc::from(vec!["test_key".to_string()]);
+        let message = |offset: i64, secs: i64| Message {
+            keys: Arc::clone(&keys),
+            offset: Offset::Int(IntOffset::new(offset, 0)),
+            event_time: base_time + chrono::Duration::seconds(secs),
+            ..Default::default()
+        };
+
+        // window [0, 10) for the key
+        windower.assign_windows(message(1, 0));
+        windower.assign_windows(message(2, 5));
+
+        // the closed window is part of the snapshot till it is garbage collected
+        let closed = windower.close_windows(base_time + chrono::Duration::seconds(20));
+        let Some(UnalignedWindowMessage {
+            operation: UnalignedWindowOperation::Close { window },
+            ..
+        }) = closed.into_iter().next()
+        else {
+            panic!("Expected Close operation");
+        };
+        let state = windower.snapshot();
+        assert_eq!(state.offset, 2);
+        assert_eq!(state.states.first().unwrap().windows.len(), 1);
+        let snapshot = WindowSnapshot::from(state);
+        assert!(!snapshot.is_processed(2, &keys, base_time + chrono::Duration::seconds(5)));
+
+        windower.delete_window(window);
+        // a new window [30, 40) for the key
+        windower.assign_windows(message(3, 30));
+
+        let snapshot = WindowSnapshot::from(windower.snapshot());
+        assert!(snapshot.is_processed(1, &keys, base_time));
+        assert!(snapshot.is_processed(2, &keys, base_time + chrono::Duration::seconds(5)));
+        assert!(!snapshot.is_processed(3, &keys, base_time + chrono::Duration::seconds(30)));
+    }
 }
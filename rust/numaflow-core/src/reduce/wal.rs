@@ -8,6 +8,7 @@ use crate::reduce::pbq::WAL;
 use crate::reduce::wal::segment::WalType;
 use crate::reduce::wal::segment::append::AppendOnlyWal;
 use crate::reduce::wal::segment::compactor::{Compactor, WindowKind};
+use crate::reduce::wal::snapshot::WINDOW_SNAPSHOT_FILE;
 use crate::shared::grpc::{prost_timestamp_from_utc, utc_from_timestamp};
 use bytes::Bytes;
 use std::sync::Arc;
@@ -18,6 +19,12 @@ pub(crate) mod segment;
 /// All the errors WAL could face.
 pub(crate) mod error;
 
+/// Snapshot of the unaligned window manager, used as GC boundary.
+pub(crate) mod snapshot;
+
+/// Offline inspection and repair of the WAL.
+pub mod inspect;
+
 #[derive(Debug, Clone)]
 pub(crate) struct WalMessage {
     pub(crate) message: Message,
@@ -138,8 +145,10 @@ pub(crate) async fn create_wal_components(
             storage_config.max_file_size_mb,
             storage_config.flush_interval_ms,
             storage_config.max_segment_age_secs,
+            storage_config.recovery_policy,
         )
-        .await?;
+        .await?
+        .with_snapshot(wal_path.join(WINDOW_SNAPSHOT_FILE));
 
         let gc_wal = AppendOnlyWal::new(
             WalType::Gc,
@@ -161,3 +170,104 @@ pub(crate) async fn create_wal_components(
         Ok((None, None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessageID;
+    use crate::reduce::wal::segment::append::SegmentWriteMessage;
+    use crate::reduce::wal::snapshot::write_snapshot;
+    use chrono::{TimeZone, Utc};
+    use numaflow_pb::objects::wal::{KeyedWindowState, UnalignedWindowManagerState};
+    use std::time::Duration;
+    use tempfile::tempdir;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tokio_util::sync::CancellationToken;
+
+    #[tokio::test]
+    async fn test_unaligned_replay_skips_snapshot_processed_messages() {
+        let temp_dir = tempdir().unwrap();
+        let storage_config = StorageConfig {
+            path: temp_dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let base_time = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let keys = vec!["key1".to_string()];
+
+        // persist the messages with offsets 1..=4, 10s apart, using the WAL of the reducer
+        let (wal, _gc_wal) = create_wal_components(Some(&storage_config), WindowKind::Unaligned)
+            .await
+            .unwrap();
+        let wal = wal.unwrap();
+        let (tx, rx) = mpsc::channel(100);
+        let writer_handle = wal
+            .append_only_wal
+            .streaming_write(ReceiverStream::new(rx))
+            .await
+            .unwrap();
+        for i in 1..=4 {
+            let message = Message {
+                event_time: base_time + chrono::Duration::seconds(i * 10),
+                keys: Arc::from(keys.clone()),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                id: MessageID {
+                    vertex_name: "test-vertex".to_string().into(),
+                    offset: i.to_string().into(),
+                    index: 0,
+                },
+                ..Default::default()
+            };
+            tx.send(SegmentWriteMessage::WriteMessage { message })
+                .await
+                .unwrap();
+        }
+        drop(tx);
+        writer_handle.await.unwrap().unwrap();
+
+        // the snapshot saved before the restart covers the first 3 messages, of which the message
+        // at 20s is still pending in the window of the key
+        let state = UnalignedWindowManagerState {
+            offset: 3,
+            states: vec![KeyedWindowState {
+                keys: keys.clone(),
+                windows: vec![],
+                message_timestamps: vec![prost_timestamp_from_utc(
+                    base_time + chrono::Duration::seconds(20),
+                )],
+                gc_end_time: Some(prost_timestamp_from_utc(
+                    base_time + chrono::Duration::seconds(35),
+                )),
+            }],
+        };
+        write_snapshot(&storage_config.path.join(WINDOW_SNAPSHOT_FILE), &state).unwrap();
+
+        // on restart, the WAL is replayed by the compactor of the reducer
+        let (wal, _gc_wal) = create_wal_components(Some(&storage_config), WindowKind::Unaligned)
+            .await
+            .unwrap();
+        let wal = wal.unwrap();
+        let (replay_tx, mut replay_rx) = mpsc::channel(100);
+        let cln_token = CancellationToken::new();
+        let handle = wal
+            .compactor
+            .start_compaction_with_replay(replay_tx, Duration::from_secs(60), cln_token.clone())
+            .await
+            .unwrap();
+
+        let mut replayed_offsets = vec![];
+        while let Some(data) = replay_rx.recv().await {
+            let msg: WalMessage = data.try_into().unwrap();
+            let Offset::Int(offset) = msg.message.offset else {
+                panic!("expected int offset");
+            };
+            replayed_offsets.push(offset.offset);
+        }
+        cln_token.cancel();
+        handle.await.unwrap().unwrap();
+
+        // the processed messages are not replayed, the pending message and the message after the
+        // snapshot are replayed to rebuild the windows
+        assert_eq!(replayed_offsets, vec![2, 4]);
+    }
+}
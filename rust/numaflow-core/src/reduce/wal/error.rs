@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 use std::io;
+use std::path::PathBuf;
 
 /// The result of any operation on the WAL.
 pub(crate) type WalResult<T> = Result<T, Error>;
@@ -14,6 +15,15 @@ pub(crate) enum Error {
         /// Reason of the error.
         err: String,
     },
+    /// A record of the segment is corrupted or was partially written (torn).
+    Corrupted {
+        /// The segment file.
+        path: PathBuf,
+        /// Offset of the record in the segment, the records before it are good.
+        offset: u64,
+        /// Why the record could not be read.
+        reason: &'static str,
+    },
     /// IO Errors.
     Io(io::Error),
     /// Other errors.
@@ -26,6 +36,17 @@ impl Display for Error {
             Error::WriteEntry { id, err } => {
                 write!(f, "Error::WriteEntry {id} {err}")
             }
+            Error::Corrupted {
+                path,
+                offset,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "Error::Corrupted {} at offset {offset} {reason}",
+                    path.display()
+                )
+            }
             Error::Io(err) => {
                 write!(f, "Error::Io {err}")
             }
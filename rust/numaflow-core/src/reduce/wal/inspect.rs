@@ -0,0 +1,765 @@
+//! Offline inspection and repair of the WAL of a reduce vertex, to look inside the storage (e.g.,
+//! `/var/numaflow/pbq`) of a crashlooping pod. The segments are read with [ReplayWal] and compacted
+//! with [Compactor], the same way as the reduce vertex does on boot up. Every command writes one
+//! JSON object per line.
+//!
+//! The segments which are not frozen yet (the pod crashed while writing to them) are listed and
+//! dumped too, though they are never replayed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, SecondsFormat, Utc};
+use numaflow_pb::objects::wal::GcEvent;
+use serde::Serialize;
+use tokio_stream::StreamExt;
+
+use crate::config::components::reduce::{StorageConfig, WalRecoveryPolicy};
+use crate::error::{Error, Result};
+use crate::message::{Message, Offset};
+use crate::reduce::reducer::aligned::windower::fixed::FixedWindowManager;
+use crate::reduce::reducer::aligned::windower::sliding::SlidingWindowManager;
+use crate::reduce::wal::WalMessage;
+use crate::reduce::wal::error::Error as WalError;
+use crate::reduce::wal::segment::compactor::{Compactor, WindowKind};
+use crate::reduce::wal::segment::replay::{ReplayWal, SegmentEntry, sort_filenames};
+use crate::reduce::wal::segment::{GcEventEntry, WalType};
+use crate::reduce::wal::snapshot::WINDOW_SNAPSHOT_FILE;
+
+/// Extension of the in-progress segments, the frozen ones have `.frozen` appended to it.
+const SEGMENT_EXTENSION: &str = ".wal";
+const FROZEN_SEGMENT_EXTENSION: &str = ".wal.frozen";
+
+/// Separator of the keys, same as the one used by the compaction.
+const KEY_SEPARATOR: &str = ":";
+
+/// Windows of the reduce vertex, they decide how the GC events apply to the data.
+pub enum WalWindows {
+    /// Fixed windows, the GC events are per window.
+    Fixed { length: Duration },
+    /// Sliding windows, the GC events are per window.
+    Sliding { length: Duration, slide: Duration },
+    /// Session windows and accumulators, the GC events are per keys.
+    Unaligned,
+}
+
+impl WalWindows {
+    /// Creates the windows from the window type of the spec (`fixed`, `sliding`, `session` or
+    /// `accumulator`), and the length and slide durations (e.g., `60s`) for the aligned windows.
+    pub fn new(window: &str, length: Option<&str>, slide: Option<&str>) -> Result<Self> {
+        let duration = |name: &str, value: Option<&str>| -> Result<Duration> {
+            let value =
+                value.ok_or_else(|| Error::Config(format!("{name} is required for {window}")))?;
+            kube::core::Duration::from_str(value)
+                .map(Into::into)
+                .map_err(|e| Error::Config(format!("Invalid {name} '{value}': {e}")))
+        };
+        match window {
+            "fixed" => Ok(WalWindows::Fixed {
+                length: duration("length", length)?,
+            }),
+            "sliding" => Ok(WalWindows::Sliding {
+                length: duration("length", length)?,
+                slide: duration("slide", slide)?,
+            }),
+            "session" | "accumulator" => Ok(WalWindows::Unaligned),
+            _ => Err(Error::Config(format!(
+                "Unsupported window '{window}', supported windows are fixed, sliding, session and accumulator"
+            ))),
+        }
+    }
+
+    fn kind(&self) -> WindowKind {
+        match self {
+            WalWindows::Fixed { .. } | WalWindows::Sliding { .. } => WindowKind::Aligned,
+            WalWindows::Unaligned => WindowKind::Unaligned,
+        }
+    }
+}
+
+/// The inspection and repair commands.
+pub enum WalCommand {
+    /// Lists the segments with their sizes, number of records, time ranges and corruptions.
+    Segments,
+    /// Dumps all the records of the segments.
+    Dump,
+    /// Shows the windows which are still open, i.e., which have data that is not garbage collected.
+    OpenWindows(WalWindows),
+    /// Runs a compaction, i.e., deletes the garbage collected data, and lists the segments.
+    Compact(WalWindows),
+    /// Truncates the corrupted segments at their last good record.
+    Truncate,
+}
+
+/// Runs the command on the WAL at `path`, writing the results to `out`.
+pub async fn run(path: &Path, command: WalCommand, out: &mut impl Write) -> Result<()> {
+    match command {
+        WalCommand::Segments => list_segments(path, out).await,
+        WalCommand::Dump => dump(path, out).await,
+        WalCommand::OpenWindows(windows) => open_windows(path, &windows, out).await,
+        WalCommand::Compact(windows) => {
+            let storage_config = StorageConfig::default();
+            // corrupted segments have to be truncated explicitly before compacting them.
+            Compactor::new(
+                path.to_path_buf(),
+                windows.kind(),
+                storage_config.max_file_size_mb,
+                storage_config.flush_interval_ms,
+                storage_config.max_segment_age_secs,
+                WalRecoveryPolicy::Fail,
+            )
+            .await?
+            .with_snapshot(path.join(WINDOW_SNAPSHOT_FILE))
+            .compact_once()
+            .await?;
+            list_segments(path, out).await
+        }
+        WalCommand::Truncate => truncate(path, out).await,
+    }
+}
+
+/// A segment file of the WAL.
+struct Segment {
+    wal_type: WalType,
+    path: PathBuf,
+    frozen: bool,
+}
+
+impl Segment {
+    fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    fn size(&self) -> Result<u64> {
+        Ok(std::fs::metadata(&self.path)
+            .map_err(|e| Error::WAL(format!("{}: {e}", self.path.display())))?
+            .len())
+    }
+}
+
+/// A record of a segment which could not be read.
+#[derive(Serialize)]
+struct Corruption {
+    offset: u64,
+    reason: &'static str,
+}
+
+#[derive(Serialize)]
+struct SegmentInfo {
+    wal: &'static str,
+    file: String,
+    frozen: bool,
+    size: u64,
+    records: u64,
+    /// Earliest event time of the data, or earliest window start of the GC events.
+    min_time: Option<String>,
+    /// Latest event time of the data, or latest window end of the GC events.
+    max_time: Option<String>,
+    corrupted: Option<Corruption>,
+}
+
+#[derive(Serialize)]
+struct DataRecord {
+    wal: &'static str,
+    file: String,
+    offset: i64,
+    id: String,
+    keys: Vec<String>,
+    event_time: String,
+    watermark: Option<String>,
+    is_late: bool,
+    size: usize,
+}
+
+#[derive(Serialize)]
+struct GcRecord {
+    wal: &'static str,
+    file: String,
+    start_time: String,
+    end_time: String,
+    keys: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct OpenWindow {
+    /// Start of the window, or the earliest event time of the data of the keys for the unaligned
+    /// windows.
+    start_time: String,
+    /// End of the window, or the latest event time of the data of the keys for the unaligned
+    /// windows.
+    end_time: String,
+    keys: Option<Vec<String>>,
+    messages: u64,
+}
+
+#[derive(Serialize)]
+struct TruncatedSegment {
+    wal: &'static str,
+    file: String,
+    size: u64,
+    truncated_bytes: u64,
+}
+
+/// A decoded record of a segment.
+enum Record {
+    Data(Message),
+    Gc(GcEventEntry),
+}
+
+impl Record {
+    fn decode(wal_type: &WalType, data: Bytes) -> Result<Self> {
+        match wal_type {
+            WalType::Data | WalType::Compact => WalMessage::try_from(data)
+                .map(|wal_message| Record::Data(wal_message.into()))
+                .map_err(|e| Error::WAL(e.to_string())),
+            WalType::Gc => prost::Message::decode(data)
+                .map(|gc: GcEvent| Record::Gc(gc.into()))
+                .map_err(|e| Error::WAL(format!("Failed to decode GC event: {e}"))),
+        }
+    }
+
+    /// Time range of the record, the event time of the data or the window of the GC event.
+    fn time_range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        match self {
+            Record::Data(message) => (message.event_time, message.event_time),
+            Record::Gc(gc) => (gc.start_time, gc.end_time),
+        }
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn write_line(out: &mut impl Write, value: &impl Serialize) -> Result<()> {
+    serde_json::to_writer(&mut *out, value)
+        .map_err(|e| Error::WAL(format!("Failed to write the output: {e}")))?;
+    writeln!(out).map_err(|e| Error::WAL(format!("Failed to write the output: {e}")))
+}
+
+/// Lists the segments of all the WAL types, in the order they are replayed.
+fn segments(path: &Path) -> Result<Vec<Segment>> {
+    let entries = std::fs::read_dir(path)
+        .map_err(|e| Error::WAL(format!("Failed to read {}: {e}", path.display())))?;
+
+    let mut files = vec![];
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::WAL(e.to_string()))?;
+        if entry.path().is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    let mut segments = vec![];
+    for wal_type in [WalType::Compact, WalType::Data, WalType::Gc] {
+        let prefix = format!("{}_", wal_type.segment_prefix());
+        let wal_files = files
+            .iter()
+            .filter(|file| {
+                file.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| {
+                        name.starts_with(&prefix)
+                            && (name.ends_with(SEGMENT_EXTENSION)
+                                || name.ends_with(FROZEN_SEGMENT_EXTENSION))
+                    })
+            })
+            .cloned()
+            .collect();
+        for file in sort_filenames(wal_files) {
+            segments.push(Segment {
+                wal_type: wal_type.clone(),
+                frozen: file.to_string_lossy().ends_with(FROZEN_SEGMENT_EXTENSION),
+                path: file,
+            });
+        }
+    }
+    Ok(segments)
+}
+
+/// Reads the records of the segment with the recovery policy, and returns the corruption at which
+/// the read stopped if the policy is to fail.
+async fn read_segment(
+    segment: &Segment,
+    recovery_policy: WalRecoveryPolicy,
+    mut on_record: impl FnMut(Record) -> Result<()>,
+) -> Result<Option<Corruption>> {
+    let replay_wal = ReplayWal::new(
+        segment.wal_type.clone(),
+        segment.path.parent().unwrap_or(Path::new("")).to_path_buf(),
+        recovery_policy,
+    );
+    let (mut rx, handle) = replay_wal.streaming_read_segment(segment.path.clone());
+
+    // keep draining the stream on errors, the reader expects all the records to be consumed.
+    let mut result = Ok(());
+    while let Some(entry) = rx.next().await {
+        if let SegmentEntry::DataEntry { data, .. } = entry
+            && result.is_ok()
+        {
+            result = Record::decode(&segment.wal_type, data).and_then(&mut on_record);
+        }
+    }
+    result?;
+
+    match handle
+        .await
+        .map_err(|e| Error::WAL(format!("Segment reader failed: {e}")))?
+    {
+        Ok(()) => Ok(None),
+        Err(WalError::Corrupted { offset, reason, .. }) => Ok(Some(Corruption { offset, reason })),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn list_segments(path: &Path, out: &mut impl Write) -> Result<()> {
+    for segment in segments(path)? {
+        let mut records = 0;
+        let mut time_range: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+        let corrupted = read_segment(&segment, WalRecoveryPolicy::Fail, |record| {
+            let (start, end) = record.time_range();
+            records += 1;
+            time_range = Some(match time_range {
+                Some((min, max)) => (min.min(start), max.max(end)),
+                None => (start, end),
+            });
+            Ok(())
+        })
+        .await?;
+
+        write_line(
+            out,
+            &SegmentInfo {
+                wal: segment.wal_type.segment_prefix(),
+                file: segment.file_name(),
+                frozen: segment.frozen,
+                size: segment.size()?,
+                records,
+                min_time: time_range.map(|(min, _)| format_time(min)),
+                max_time: time_range.map(|(_, max)| format_time(max)),
+                corrupted,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+async fn dump(path: &Path, out: &mut impl Write) -> Result<()> {
+    for segment in segments(path)? {
+        let wal = segment.wal_type.segment_prefix();
+        let file = segment.file_name();
+        read_segment(&segment, WalRecoveryPolicy::Fail, |record| match record {
+            Record::Data(message) => write_line(
+                out,
+                &DataRecord {
+                    wal,
+                    file: file.clone(),
+                    offset: match &message.offset {
+                        Offset::Int(offset) => offset.offset,
+                        Offset::String(_) => -1,
+                    },
+                    id: message.id.to_string(),
+                    keys: message.keys.to_vec(),
+                    event_time: format_time(message.event_time),
+                    watermark: message.watermark.map(format_time),
+                    is_late: message.is_late,
+                    size: message.value.len(),
+                },
+            ),
+            Record::Gc(gc) => write_line(
+                out,
+                &GcRecord {
+                    wal,
+                    file: file.clone(),
+                    start_time: format_time(gc.start_time),
+                    end_time: format_time(gc.end_time),
+                    keys: gc.keys,
+                },
+            ),
+        })
+        .await?;
+    }
+    Ok(())
+}
+
+/// Garbage collected state of the WAL, the data before it has been processed.
+enum GcState {
+    Aligned(DateTime<Utc>),
+    Unaligned(HashMap<String, DateTime<Utc>>),
+}
+
+impl GcState {
+    /// Whether the message is yet to be processed, same as the retention of the compaction.
+    fn is_pending(&self, message: &Message) -> bool {
+        match self {
+            GcState::Aligned(end_time) => message.event_time >= *end_time,
+            GcState::Unaligned(end_times) => end_times
+                .get(&message.keys.join(KEY_SEPARATOR))
+                .is_none_or(|end_time| message.event_time >= *end_time),
+        }
+    }
+}
+
+async fn open_windows(path: &Path, windows: &WalWindows, out: &mut impl Write) -> Result<()> {
+    let segments = segments(path)?;
+
+    let mut gc_state = match windows.kind() {
+        WindowKind::Aligned => GcState::Aligned(DateTime::UNIX_EPOCH),
+        WindowKind::Unaligned => GcState::Unaligned(HashMap::new()),
+    };
+    for segment in segments
+        .iter()
+        .filter(|s| matches!(s.wal_type, WalType::Gc))
+    {
+        read_segment(segment, WalRecoveryPolicy::Fail, |record| {
+            let Record::Gc(gc) = record else {
+                return Ok(());
+            };
+            match &mut gc_state {
+                GcState::Aligned(end_time) => *end_time = (*end_time).max(gc.end_time),
+                GcState::Unaligned(end_times) => {
+                    if let Some(keys) = gc.keys {
+                        let end_time = end_times
+                            .entry(keys.join(KEY_SEPARATOR))
+                            .or_insert(gc.end_time);
+                        *end_time = (*end_time).max(gc.end_time);
+                    }
+                }
+            }
+            Ok(())
+        })
+        .await?;
+    }
+
+    let fixed = match windows {
+        WalWindows::Fixed { length } => Some(FixedWindowManager::new(*length)),
+        _ => None,
+    };
+    let sliding = match windows {
+        WalWindows::Sliding { length, slide } => {
+            Some(SlidingWindowManager::new(*length, *slide, None))
+        }
+        _ => None,
+    };
+
+    // open windows with the number of messages, keyed by (end, start) for aligned windows, and by
+    // keys for unaligned ones.
+    let mut aligned: BTreeMap<(DateTime<Utc>, DateTime<Utc>), u64> = BTreeMap::new();
+    let mut unaligned: BTreeMap<Vec<String>, (DateTime<Utc>, DateTime<Utc>, u64)> = BTreeMap::new();
+    for segment in segments
+        .iter()
+        .filter(|s| !matches!(s.wal_type, WalType::Gc))
+    {
+        read_segment(segment, WalRecoveryPolicy::Fail, |record| {
+            let Record::Data(message) = record else {
+                return Ok(());
+            };
+            if !gc_state.is_pending(&message) {
+                return Ok(());
+            }
+            let message_windows =
+                match (&fixed, &sliding) {
+                    (Some(manager), _) => vec![manager.message_window(&message)],
+                    (_, Some(manager)) => manager.create_windows(&message),
+                    (None, None) => {
+                        let (start, end, count) = unaligned
+                            .entry(message.keys.to_vec())
+                            .or_insert((message.event_time, message.event_time, 0));
+                        *start = (*start).min(message.event_time);
+                        *end = (*end).max(message.event_time);
+                        *count += 1;
+                        vec![]
+                    }
+                };
+            for window in message_windows {
+                *aligned
+                    .entry((window.end_time, window.start_time))
+                    .or_default() += 1;
+            }
+            Ok(())
+        })
+        .await?;
+    }
+
+    for ((end_time, start_time), messages) in aligned {
+        write_line(
+            out,
+            &OpenWindow {
+                start_time: format_time(start_time),
+                end_time: format_time(end_time),
+                keys: None,
+                messages,
+            },
+        )?;
+    }
+    for (keys, (start_time, end_time, messages)) in unaligned {
+        write_line(
+            out,
+            &OpenWindow {
+                start_time: format_time(start_time),
+                end_time: format_time(end_time),
+                keys: Some(keys),
+                messages,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+async fn truncate(path: &Path, out: &mut impl Write) -> Result<()> {
+    for segment in segments(path)? {
+        let size = segment.size()?;
+        read_segment(&segment, WalRecoveryPolicy::Truncate, |_| Ok(())).await?;
+        let truncated_size = segment.size()?;
+        if truncated_size < size {
+            write_line(
+                out,
+                &TruncatedSegment {
+                    wal: segment.wal_type.segment_prefix(),
+                    file: segment.file_name(),
+                    size: truncated_size,
+                    truncated_bytes: size - truncated_size,
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::TimeZone;
+    use serde_json::Value;
+    use tempfile::tempdir;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::message::{IntOffset, MessageID};
+    use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
+    use crate::shared::grpc::prost_timestamp_from_utc;
+
+    fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 4, 1, 1, 0, 0).unwrap()
+    }
+
+    /// Writes 30 messages one second apart, and a GC event of the first 10 seconds.
+    async fn write_wal(path: &Path) {
+        let data_wal = AppendOnlyWal::new(WalType::Data, path.to_path_buf(), 1, 1000, 300)
+            .await
+            .unwrap();
+        let (tx, rx) = mpsc::channel(100);
+        let handle = data_wal
+            .streaming_write(ReceiverStream::new(rx))
+            .await
+            .unwrap();
+        for i in 0..30 {
+            let message = Message {
+                event_time: start_time() + chrono::Duration::seconds(i),
+                keys: Arc::from(vec!["key".to_string()]),
+                value: Bytes::from(vec![1, 2, 3]),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                id: MessageID {
+                    vertex_name: "test-vertex".to_string().into(),
+                    offset: i.to_string().into(),
+                    index: 0,
+                },
+                ..Default::default()
+            };
+            tx.send(SegmentWriteMessage::WriteMessage { message })
+                .await
+                .unwrap();
+        }
+        drop(tx);
+        handle.await.unwrap().unwrap();
+
+        let gc_wal = AppendOnlyWal::new(WalType::Gc, path.to_path_buf(), 1, 1000, 300)
+            .await
+            .unwrap();
+        let (tx, rx) = mpsc::channel(10);
+        let handle = gc_wal
+            .streaming_write(ReceiverStream::new(rx))
+            .await
+            .unwrap();
+        let gc_event = GcEvent {
+            start_time: Some(prost_timestamp_from_utc(start_time())),
+            end_time: Some(prost_timestamp_from_utc(
+                start_time() + chrono::Duration::seconds(10),
+            )),
+            keys: vec!["key".to_string()],
+        };
+        tx.send(SegmentWriteMessage::WriteGcEvent {
+            data: Bytes::from(prost::Message::encode_to_vec(&gc_event)),
+        })
+        .await
+        .unwrap();
+        drop(tx);
+        handle.await.unwrap().unwrap();
+    }
+
+    async fn run_command(path: &Path, command: WalCommand) -> Vec<Value> {
+        let mut out = vec![];
+        run(path, command, &mut out).await.unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn fixed_windows() -> WalWindows {
+        WalWindows::new("fixed", Some("10s"), None).unwrap()
+    }
+
+    #[test]
+    fn test_wal_windows() {
+        assert!(matches!(
+            WalWindows::new("fixed", Some("60s"), None).unwrap(),
+            WalWindows::Fixed { length } if length == Duration::from_secs(60)
+        ));
+        assert!(matches!(
+            WalWindows::new("sliding", Some("1m"), Some("10s")).unwrap(),
+            WalWindows::Sliding { length, slide }
+                if length == Duration::from_secs(60) && slide == Duration::from_secs(10)
+        ));
+        assert!(matches!(
+            WalWindows::new("session", None, None).unwrap(),
+            WalWindows::Unaligned
+        ));
+        assert!(WalWindows::new("fixed", None, None).is_err());
+        assert!(WalWindows::new("sliding", Some("60s"), None).is_err());
+        assert!(WalWindows::new("fixed", Some("sixty"), None).is_err());
+        assert!(WalWindows::new("global", None, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_segments_and_dump() {
+        let dir = tempdir().unwrap();
+        write_wal(dir.path()).await;
+
+        let segments = run_command(dir.path(), WalCommand::Segments).await;
+        assert_eq!(segments.len(), 2);
+        let data = segments.first().unwrap();
+        assert_eq!(data["wal"], "data");
+        assert_eq!(data["frozen"], true);
+        assert_eq!(data["records"], 30);
+        assert_eq!(data["min_time"], "2025-04-01T01:00:00.000Z");
+        assert_eq!(data["max_time"], "2025-04-01T01:00:29.000Z");
+        assert_eq!(data["corrupted"], Value::Null);
+        let gc = segments.get(1).unwrap();
+        assert_eq!(gc["wal"], "gc");
+        assert_eq!(gc["records"], 1);
+        assert_eq!(gc["max_time"], "2025-04-01T01:00:10.000Z");
+
+        let records = run_command(dir.path(), WalCommand::Dump).await;
+        assert_eq!(records.len(), 31);
+        let first = records.first().unwrap();
+        assert_eq!(first["offset"], 0);
+        assert_eq!(first["keys"], serde_json::json!(["key"]));
+        assert_eq!(first["event_time"], "2025-04-01T01:00:00.000Z");
+        assert_eq!(first["size"], 3);
+        let gc = records.last().unwrap();
+        assert_eq!(gc["wal"], "gc");
+        assert_eq!(gc["start_time"], "2025-04-01T01:00:00.000Z");
+        assert_eq!(gc["end_time"], "2025-04-01T01:00:10.000Z");
+    }
+
+    #[tokio::test]
+    async fn test_open_windows() {
+        let dir = tempdir().unwrap();
+        write_wal(dir.path()).await;
+
+        // the first window has been garbage collected
+        let windows = run_command(dir.path(), WalCommand::OpenWindows(fixed_windows())).await;
+        let windows: Vec<_> = windows
+            .iter()
+            .map(|w| {
+                (
+                    w["start_time"].as_str().unwrap().to_string(),
+                    w["messages"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            windows,
+            vec![
+                ("2025-04-01T01:00:10.000Z".to_string(), 10),
+                ("2025-04-01T01:00:20.000Z".to_string(), 10),
+            ]
+        );
+
+        let windows = run_command(
+            dir.path(),
+            WalCommand::OpenWindows(WalWindows::new("session", None, None).unwrap()),
+        )
+        .await;
+        assert_eq!(windows.len(), 1);
+        let window = windows.first().unwrap();
+        assert_eq!(window["keys"], serde_json::json!(["key"]));
+        assert_eq!(window["start_time"], "2025-04-01T01:00:10.000Z");
+        assert_eq!(window["end_time"], "2025-04-01T01:00:29.000Z");
+        assert_eq!(window["messages"], 20);
+    }
+
+    #[tokio::test]
+    async fn test_compact() {
+        let dir = tempdir().unwrap();
+        write_wal(dir.path()).await;
+
+        let segments = run_command(dir.path(), WalCommand::Compact(fixed_windows())).await;
+        // the data segments are replaced by the compacted segment
+        assert!(segments.iter().all(|s| s["wal"] != "data"));
+        let compacted = segments.iter().find(|s| s["wal"] == "compaction").unwrap();
+        assert_eq!(compacted["wal"], "compaction");
+        assert_eq!(compacted["records"], 20);
+        assert_eq!(compacted["min_time"], "2025-04-01T01:00:10.000Z");
+    }
+
+    #[tokio::test]
+    async fn test_truncate() {
+        let dir = tempdir().unwrap();
+        write_wal(dir.path()).await;
+
+        let segment = segments(dir.path())
+            .unwrap()
+            .into_iter()
+            .find(|s| matches!(s.wal_type, WalType::Data))
+            .unwrap();
+        let size = segment.size().unwrap();
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&segment.path)
+            .unwrap();
+        file.set_len(size - 1).unwrap();
+
+        let segments = run_command(dir.path(), WalCommand::Segments).await;
+        let corrupted = &segments.first().unwrap()["corrupted"];
+        assert_eq!(corrupted["reason"], "torn-record");
+        let offset = corrupted["offset"].as_u64().unwrap();
+
+        let truncated = run_command(dir.path(), WalCommand::Truncate).await;
+        assert_eq!(truncated.len(), 1);
+        let truncated = truncated.first().unwrap();
+        assert_eq!(truncated["size"], offset);
+        assert_eq!(truncated["truncated_bytes"], size - 1 - offset);
+
+        let segments = run_command(dir.path(), WalCommand::Segments).await;
+        let data = segments.first().unwrap();
+        assert_eq!(data["records"], 29);
+        assert_eq!(data["corrupted"], Value::Null);
+        assert!(
+            run_command(dir.path(), WalCommand::Truncate)
+                .await
+                .is_empty()
+        );
+    }
+}
@@ -31,6 +31,16 @@ pub(crate) mod append;
 /// A compactor to truncate processed WAL segments. It can be both Data WAL and GC WAL.
 pub(crate) mod compactor;
 
+/// Set in the upper half of the length prefix of the records which are followed by the CRC32
+/// checksum of the data, i.e., `<u64(RECORD_MAGIC << 32 | data_len)><u32(crc32)><[u8;data_len]>`.
+/// Segments written before the checksums were added have `<u64(data_len)><[u8;data_len]>` records
+/// (upper half is zero), which are still replayed but can only be checked for truncation. A corrupted
+/// byte in the upper half is neither the magic nor zero, so it is detected.
+pub(crate) const RECORD_MAGIC: u64 = 0x4E57_414C; // "NWAL"
+
+/// Size of the header of a record with checksum, the length prefix and the CRC32.
+pub(crate) const RECORD_HEADER_SIZE: u64 = 12;
+
 /// WAL is made of three types, the Data, GC, and Compaction WAL.
 #[derive(Debug, Clone)]
 pub(crate) enum WalType {
@@ -114,6 +124,7 @@ impl From<GcEventEntry> for GcEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::components::reduce::WalRecoveryPolicy;
     use crate::message::{IntOffset, Message, MessageID, Offset};
     use crate::reduce::wal::segment::WalType;
     use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
@@ -211,9 +222,16 @@ mod tests {
         handle.await.unwrap().unwrap();
 
         // Create and run compactor
-        let compactor = Compactor::new(test_path.clone(), WindowKind::Aligned, 1, 1000, 300)
-            .await
-            .unwrap();
+        let compactor = Compactor::new(
+            test_path.clone(),
+            WindowKind::Aligned,
+            1,
+            1000,
+            300,
+            WalRecoveryPolicy::default(),
+        )
+        .await
+        .unwrap();
 
         let (replay_tx, _replay_rx) = tokio::sync::mpsc::channel(1000);
         let cln_token = CancellationToken::new();
@@ -228,7 +246,8 @@ mod tests {
         handle.await.unwrap().unwrap();
 
         // Verify compacted data
-        let compaction_replay_wal = ReplayWal::new(WalType::Compact, test_path);
+        let compaction_replay_wal =
+            ReplayWal::new(WalType::Compact, test_path, WalRecoveryPolicy::default());
         let (mut rx, handle) = compaction_replay_wal.streaming_read().unwrap();
 
         let mut remaining_message_count = 0;
@@ -366,6 +385,7 @@ mod tests {
             1,    // 20MB
             1000, // 1s flush interval
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await
         .unwrap();
@@ -383,7 +403,8 @@ mod tests {
         handle.await.unwrap().unwrap();
 
         // Verify compacted data
-        let compaction_wal = ReplayWal::new(WalType::Compact, test_path);
+        let compaction_wal =
+            ReplayWal::new(WalType::Compact, test_path, WalRecoveryPolicy::default());
         let (mut rx, handle) = compaction_wal.streaming_read().unwrap();
 
         let mut remaining_message_count = 0;
@@ -1,6 +1,6 @@
 use crate::message::Message;
 use crate::reduce::wal::error::WalResult;
-use crate::reduce::wal::segment::WalType;
+use crate::reduce::wal::segment::{RECORD_HEADER_SIZE, RECORD_MAGIC, WalType};
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use std::path::Path;
@@ -183,7 +183,8 @@ impl SegmentWriteActor {
     }
 
     /// Writes the data to the Segment.
-    /// The writes are in this format `<u64(data_len)><[u8;data_len]>`.
+    /// The writes are in this format `<u64(RECORD_MAGIC << 32 | data_len)><u32(crc32)><[u8;data_len]>`,
+    /// so that corrupted and torn records can be detected during replay.
     /// ### CANCEL SAFETY:
     /// This is not Cancel Safe. We can make it cancel safe by removing the buffering and instead of
     /// `write_all`, we should use `write`.
@@ -209,13 +210,20 @@ impl SegmentWriteActor {
             self.rotate_file(true).await?;
         }
 
-        let data_len = data.len() as u64;
+        let data_len = u32::try_from(data.len())
+            .map_err(|_| format!("WAL record of {} bytes is too large", data.len()))?
+            as u64;
 
         // TODO: this will have data loss if disk is full, we need to fix this late to be CANCEL SAFE.
-        self.current_file_buf.write_u64_le(data_len).await?;
+        self.current_file_buf
+            .write_u64_le(RECORD_MAGIC << 32 | data_len)
+            .await?;
+        self.current_file_buf
+            .write_u32_le(crc32fast::hash(&data))
+            .await?;
         self.current_file_buf.write_all(&data).await?;
 
-        self.current_size += data_len;
+        self.current_size += RECORD_HEADER_SIZE + data_len;
 
         Ok(())
     }
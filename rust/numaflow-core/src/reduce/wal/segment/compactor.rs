@@ -12,12 +12,16 @@
 //! #### Unaligned kind
 //! 1. Replay all the GC events and store the max end time for every key combination(map[key] = max end time)
 //! 2. Replay all the data events and only retain the messages with event time > max end time for that key
+//! 3. If the [WindowSnapshot] of the windows is present, also drop the messages which the snapshot
+//!    knows to be processed, even if their GC events have not been persisted yet.
 
+use crate::config::components::reduce::WalRecoveryPolicy;
 use crate::reduce::wal::error::WalResult;
 use crate::reduce::wal::segment::GcEventEntry;
 use crate::reduce::wal::segment::WalType;
 use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
 use crate::reduce::wal::segment::replay::{ReplayWal, SegmentEntry};
+use crate::reduce::wal::snapshot::WindowSnapshot;
 use crate::shared::grpc::utc_from_timestamp;
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
@@ -57,6 +61,8 @@ pub(crate) struct Compactor {
     kind: WindowKind,
     /// join handle for the compaction writer task
     writer_task_handle: JoinHandle<WalResult<()>>,
+    /// Path of the [WindowSnapshot] used as GC boundary for the Unaligned kind.
+    snapshot_path: Option<PathBuf>,
 }
 
 const WAL_KEY_SEPERATOR: &str = ":";
@@ -69,10 +75,11 @@ impl Compactor {
         max_file_size_mb: u64,
         flush_interval_ms: u64,
         max_segment_age_secs: u64,
+        recovery_policy: WalRecoveryPolicy,
     ) -> WalResult<Self> {
-        let segment_wal = ReplayWal::new(WalType::Data, path.clone());
-        let gc_wal = ReplayWal::new(WalType::Gc, path.clone());
-        let compaction_ro_wal = ReplayWal::new(WalType::Compact, path.clone());
+        let segment_wal = ReplayWal::new(WalType::Data, path.clone(), recovery_policy);
+        let gc_wal = ReplayWal::new(WalType::Gc, path.clone(), recovery_policy);
+        let compaction_ro_wal = ReplayWal::new(WalType::Compact, path.clone(), recovery_policy);
         let compaction_ao_wal = AppendOnlyWal::new(
             WalType::Compact,
             path.clone(),
@@ -94,9 +101,16 @@ impl Compactor {
             compaction_ao_tx,
             kind,
             writer_task_handle: handle,
+            snapshot_path: None,
         })
     }
 
+    /// Uses the [WindowSnapshot] at the given path as GC boundary while compacting Unaligned WALs.
+    pub(crate) fn with_snapshot(mut self, path: PathBuf) -> Self {
+        self.snapshot_path = Some(path);
+        self
+    }
+
     /// Starts the compaction process with replay and periodic execution.
     ///
     /// # Parameters
@@ -138,6 +152,18 @@ impl Compactor {
         }))
     }
 
+    /// Runs a single compaction without replay, and waits for the compacted data to be written. It
+    /// is used to compact the WAL offline.
+    pub(crate) async fn compact_once(self) -> WalResult<()> {
+        self.compact(None).await?;
+
+        // drop the compactor append wal tx to signal the writer task to shut down
+        drop(self.compaction_ao_tx);
+        self.writer_task_handle
+            .await
+            .map_err(|e| format!("Compaction writer failed: {e}"))?
+    }
+
     /// Compact first needs to get all the GC files and build a compaction map. This map will have
     /// the oldest data before which all can be deleted.
     async fn compact(&self, replay_tx: Option<Sender<Bytes>>) -> WalResult<()> {
@@ -200,7 +226,12 @@ impl Compactor {
 
         info!(oldest_time_map = ?oldest_time_map, "Event time map till which the data has been processed");
 
-        let compact = UnalignedCompaction(oldest_time_map);
+        let snapshot = match &self.snapshot_path {
+            Some(path) => WindowSnapshot::load(path)?,
+            None => None,
+        };
+
+        let compact = UnalignedCompaction(oldest_time_map, snapshot);
 
         // Compact the compaction_ro_wal
         self.process_wal_stream(&self.compaction_ro_wal, &compact, replay_tx.clone())
@@ -241,9 +272,7 @@ impl Compactor {
                     let msg: isb::ReadMessage = prost::Message::decode(data.clone())
                         .map_err(|e| format!("Failed to decode message: {e}"))?;
 
-                    if should_retain
-                        .should_retain_message(&msg.message.expect("Message should be present"))?
-                    {
+                    if should_retain.should_retain_message(&msg)? {
                         // Send the message to the compaction WAL
                         // No message handle needed for compaction writes
                         wal_tx
@@ -398,7 +427,7 @@ impl Compactor {
 /// ShouldRetain trait defines a method to determine whether a message should be retained.
 trait ShouldRetain {
     /// Determines whether a message should be retained based on its event time in the message
-    fn should_retain_message(&self, msg: &isb::Message) -> WalResult<bool>;
+    fn should_retain_message(&self, msg: &isb::ReadMessage) -> WalResult<bool>;
 }
 
 /// AlignedCompaction holds the oldest time below which all data has been processed. The event time
@@ -406,12 +435,17 @@ trait ShouldRetain {
 struct AlignedCompaction(DateTime<Utc>);
 
 /// UnalignedCompaction holds the oldest time below which all data has been processed. The event time
-/// will be different for each Key.
-struct UnalignedCompaction(HashMap<String, DateTime<Utc>>);
+/// will be different for each Key. The snapshot of the windows, if present, tells which messages have
+/// been processed beyond the persisted GC events.
+struct UnalignedCompaction(HashMap<String, DateTime<Utc>>, Option<WindowSnapshot>);
 
 impl ShouldRetain for AlignedCompaction {
     /// Determines whether a message should be retained based on its event time.
-    fn should_retain_message(&self, msg: &isb::Message) -> WalResult<bool> {
+    fn should_retain_message(&self, read_msg: &isb::ReadMessage) -> WalResult<bool> {
+        let msg = read_msg
+            .message
+            .as_ref()
+            .expect("Message should be present");
         // Extract the event time from the message
         let event_time = msg
             .header
@@ -433,7 +467,11 @@ impl ShouldRetain for AlignedCompaction {
 
 impl ShouldRetain for UnalignedCompaction {
     /// Determines whether an unaligned message should be retained based on its event time and keys.
-    fn should_retain_message(&self, msg: &isb::Message) -> WalResult<bool> {
+    fn should_retain_message(&self, read_msg: &isb::ReadMessage) -> WalResult<bool> {
+        let msg = read_msg
+            .message
+            .as_ref()
+            .expect("Message should be present");
         // Extract the event time from the message
         let event_time = msg
             .header
@@ -453,6 +491,13 @@ impl ShouldRetain for UnalignedCompaction {
             .map(|header| header.keys.clone())
             .unwrap_or_default();
 
+        // Drop the message if the snapshot knows it has been processed
+        if let Some(snapshot) = &self.1
+            && snapshot.is_processed(read_msg.read_offset, &keys, event_time)
+        {
+            return Ok(false);
+        }
+
         // Join the keys with the separator
         let key = keys.join(WAL_KEY_SEPERATOR);
 
@@ -474,9 +519,11 @@ mod tests {
     use super::*;
     use crate::message::{IntOffset, Message, MessageID, Offset};
     use crate::reduce::wal::WalMessage;
+    use crate::reduce::wal::snapshot::{WINDOW_SNAPSHOT_FILE, write_snapshot};
     use crate::shared::grpc::prost_timestamp_from_utc;
     use bytes::Bytes;
     use chrono::TimeZone;
+    use numaflow_pb::objects::wal::{KeyedWindowState, UnalignedWindowManagerState};
     use std::fs;
     use std::sync::Arc;
     use tempfile::tempdir;
@@ -501,6 +548,7 @@ mod tests {
             100,  // max_file_size_mb
             1000, // flush_interval_ms
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await?;
 
@@ -592,6 +640,7 @@ mod tests {
             100,  // max_file_size_mb
             1000, // flush_interval_ms
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await?;
 
@@ -800,6 +849,7 @@ mod tests {
             1,    // 1MB
             1000, // 1s flush interval
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await
         .unwrap();
@@ -810,7 +860,8 @@ mod tests {
         compactor.writer_task_handle.await.unwrap().unwrap();
 
         // Verify compacted data
-        let compaction_wal = ReplayWal::new(WalType::Compact, test_path);
+        let compaction_wal =
+            ReplayWal::new(WalType::Compact, test_path, WalRecoveryPolicy::default());
         let (mut rx, handle) = compaction_wal.streaming_read().unwrap();
         let mut replayed_event_times = vec![];
 
@@ -978,6 +1029,7 @@ mod tests {
             100,  // max_file_size_mb
             1000, // flush_interval_ms
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await?;
 
@@ -1229,6 +1281,7 @@ mod tests {
             100,  // max_file_size_mb
             1000, // flush_interval_ms
             300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
         )
         .await?;
 
@@ -1291,4 +1344,87 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_compact_unaligned_with_snapshot() -> WalResult<()> {
+        let temp_dir = tempdir()?;
+        let path = temp_dir.path().to_path_buf();
+        let base_time = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let keys = vec!["key1".to_string()];
+
+        // Write the messages with offsets 1..=4, 10s apart
+        let segment_wal = AppendOnlyWal::new(WalType::Data, path.clone(), 100, 1000, 300).await?;
+        let (tx, rx) = mpsc::channel(100);
+        let writer_handle = segment_wal.streaming_write(ReceiverStream::new(rx)).await?;
+        for i in 1..=4 {
+            let message = Message {
+                event_time: base_time + chrono::Duration::seconds(i * 10),
+                keys: Arc::from(keys.clone()),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                id: MessageID {
+                    vertex_name: "test-vertex".to_string().into(),
+                    offset: i.to_string().into(),
+                    index: 0,
+                },
+                ..Default::default()
+            };
+            tx.send(SegmentWriteMessage::WriteMessage { message })
+                .await
+                .map_err(|e| format!("Failed to send data: {e}"))?;
+        }
+        drop(tx);
+        writer_handle
+            .await
+            .map_err(|e| format!("Writer failed: {e}"))??;
+
+        // The snapshot covers the first 3 messages, the message at 20s is still pending. No GC
+        // events have been persisted.
+        let state = UnalignedWindowManagerState {
+            offset: 3,
+            states: vec![KeyedWindowState {
+                keys: keys.clone(),
+                windows: vec![],
+                message_timestamps: vec![prost_timestamp_from_utc(
+                    base_time + chrono::Duration::seconds(20),
+                )],
+                gc_end_time: Some(prost_timestamp_from_utc(
+                    base_time + chrono::Duration::seconds(35),
+                )),
+            }],
+        };
+        write_snapshot(&path.join(WINDOW_SNAPSHOT_FILE), &state)?;
+
+        let compactor = Compactor::new(
+            path.clone(),
+            WindowKind::Unaligned,
+            100,  // max_file_size_mb
+            1000, // flush_interval_ms
+            300,  // max_segment_age_secs
+            WalRecoveryPolicy::default(),
+        )
+        .await?
+        .with_snapshot(path.join(WINDOW_SNAPSHOT_FILE));
+
+        let (replay_tx, mut replay_rx) = mpsc::channel(100);
+        let cln_token = CancellationToken::new();
+        let handle = compactor
+            .start_compaction_with_replay(replay_tx, Duration::from_secs(60), cln_token.clone())
+            .await?;
+        cln_token.cancel();
+        handle
+            .await
+            .map_err(|e| format!("Compaction failed: {e}"))??;
+
+        let mut replayed_offsets = vec![];
+        while let Ok(data) = replay_rx.try_recv() {
+            let msg: isb::ReadMessage = prost::Message::decode(data)
+                .map_err(|e| format!("Failed to decode message: {e}"))?;
+            replayed_offsets.push(msg.read_offset);
+        }
+
+        // the pending message and the message after the snapshot are retained
+        assert_eq!(replayed_offsets, vec![2, 4]);
+
+        Ok(())
+    }
 }
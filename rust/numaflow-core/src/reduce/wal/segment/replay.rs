@@ -1,11 +1,12 @@
-use crate::reduce::wal::error::WalResult;
-use crate::reduce::wal::segment::WalType;
+use crate::config::components::reduce::WalRecoveryPolicy;
+use crate::metrics::{pipeline_metrics, wal_metrics_labels};
+use crate::reduce::wal::error::{Error, WalResult};
+use crate::reduce::wal::segment::{RECORD_HEADER_SIZE, RECORD_MAGIC, WalType};
 use bytes::Bytes;
 use std::cmp::Ordering;
 use std::fs;
-use std::io;
 use std::path::PathBuf;
-use tokio::fs::OpenOptions;
+use tokio::fs::{File, OpenOptions};
 use tokio::task::JoinHandle;
 use tokio::{
     io::{AsyncReadExt, BufReader},
@@ -13,7 +14,19 @@ use tokio::{
     task,
 };
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::{debug, info};
+use tracing::{debug, error, info};
+
+/// Size of the length prefix of a record.
+const RECORD_LEN_SIZE: u64 = 8;
+
+/// The record was only partially written, or its length prefix is corrupted.
+const TORN_RECORD: &str = "torn-record";
+
+/// The length prefix of the record has neither the magic nor the legacy (zero) upper half.
+const INVALID_PREFIX: &str = "invalid-prefix";
+
+/// The data of the record does not match its checksum.
+const CHECKSUM_MISMATCH: &str = "checksum-mismatch";
 
 /// Segment Entry as recorded in the WAL.
 #[derive(Debug)]
@@ -34,14 +47,21 @@ pub(in crate::reduce) enum SegmentEntry {
 pub(in crate::reduce) struct ReplayWal {
     wal_type: WalType,
     base_path: PathBuf,
+    /// What to do with the corrupted and torn records.
+    recovery_policy: WalRecoveryPolicy,
 }
 
 impl ReplayWal {
     /// Creates a new Replayer for the WAL.
-    pub(in crate::reduce) fn new(wal_type: WalType, base_path: PathBuf) -> Self {
+    pub(in crate::reduce) fn new(
+        wal_type: WalType,
+        base_path: PathBuf,
+        recovery_policy: WalRecoveryPolicy,
+    ) -> Self {
         Self {
             wal_type,
             base_path,
+            recovery_policy,
         }
     }
 
@@ -61,7 +81,8 @@ impl ReplayWal {
             info!("Starting WAL replay...");
             for file_path in files {
                 info!(file = %file_path.display(), "Replaying");
-                Self::read_segment(&file_path, tx.clone()).await?;
+                Self::read_segment(&self.wal_type, &file_path, self.recovery_policy, tx.clone())
+                    .await?;
 
                 tx.send(SegmentEntry::CmdFileSwitch {
                     filename: file_path,
@@ -77,37 +98,61 @@ impl ReplayWal {
         Ok((ReceiverStream::new(rx), handle))
     }
 
-    /// Read the segment file in-order and write to the channel.
-    async fn read_segment(path: &PathBuf, tx: Sender<SegmentEntry>) -> WalResult<()> {
+    /// Reads a single segment file, frozen or not, and streams its entries. Unlike
+    /// [Self::streaming_read], there is no [SegmentEntry::CmdFileSwitch] at the end. It is used to
+    /// inspect the segments offline.
+    pub(in crate::reduce) fn streaming_read_segment(
+        &self,
+        path: PathBuf,
+    ) -> (ReceiverStream<SegmentEntry>, JoinHandle<WalResult<()>>) {
+        let (tx, rx) = mpsc::channel::<SegmentEntry>(128);
+        let wal_type = self.wal_type.clone();
+        let recovery_policy = self.recovery_policy;
+        let handle =
+            task::spawn(
+                async move { Self::read_segment(&wal_type, &path, recovery_policy, tx).await },
+            );
+        (ReceiverStream::new(rx), handle)
+    }
+
+    /// Read the segment file in-order and write to the channel. On a corrupted or torn record, the
+    /// segment is either truncated at the last good record or the replay fails, based on the
+    /// [WalRecoveryPolicy].
+    async fn read_segment(
+        wal_type: &WalType,
+        path: &PathBuf,
+        recovery_policy: WalRecoveryPolicy,
+        tx: Sender<SegmentEntry>,
+    ) -> WalResult<()> {
         let file = OpenOptions::new().read(true).open(path).await?;
+        let file_len = file.metadata().await?.len();
 
         let mut reader = BufReader::new(file);
 
-        // we read data_len first and then move our offset up till the len
-        // refresher: each entry in our file is <u64(data_len)><[u8;data_len]>
-        loop {
-            // read len first
-            let data_len_result = reader.read_u64_le().await;
-            let data_len = match data_len_result {
-                Ok(len) => len,
-                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
-                Err(e) => return Err(format!("expected to read_u64 but couldn't {e}").into()),
+        // offset till which the records are good, the records are read only if the file has enough
+        // bytes left for them, so that a corrupted length does not make us read (or allocate) garbage.
+        let mut offset = 0;
+        while offset < file_len {
+            let (record_len, data) = match read_record(&mut reader, file_len - offset).await? {
+                Ok(record) => record,
+                Err(reason) => {
+                    return Self::recover(
+                        wal_type,
+                        path,
+                        recovery_policy,
+                        offset,
+                        file_len,
+                        reason,
+                    )
+                    .await;
+                }
             };
-
-            // make sure we have data for that len
-            let mut buffer = vec![0; data_len as usize];
-
-            // this is a critical error, we should be able to read data of len data_len
-            if let Err(e) = reader.read_exact(&mut buffer).await {
-                return Err(
-                    format!("expected to read {data_len}, but couldn't read_exact {e}").into(),
-                );
-            }
+            offset += record_len;
 
             // send each line
             tx.send(SegmentEntry::DataEntry {
-                size: data_len,
-                data: Bytes::from(buffer),
+                size: data.len() as u64,
+                data,
             })
             .await
             .expect("rx dropped while replaying");
@@ -115,11 +160,101 @@ impl ReplayWal {
 
         Ok(())
     }
+
+    /// Recovers the segment from the corrupted record at `offset`, everything before it has already
+    /// been replayed.
+    async fn recover(
+        wal_type: &WalType,
+        path: &PathBuf,
+        recovery_policy: WalRecoveryPolicy,
+        offset: u64,
+        file_len: u64,
+        reason: &'static str,
+    ) -> WalResult<()> {
+        pipeline_metrics()
+            .wal
+            .corrupted_records_total
+            .get_or_create(&wal_metrics_labels(wal_type.segment_prefix(), Some(reason)))
+            .inc();
+
+        match recovery_policy {
+            WalRecoveryPolicy::Fail => Err(Error::Corrupted {
+                path: path.clone(),
+                offset,
+                reason,
+            }),
+            WalRecoveryPolicy::Truncate => {
+                error!(
+                    file = %path.display(),
+                    offset,
+                    truncated_bytes = file_len - offset,
+                    reason,
+                    "Found a corrupted record in the WAL segment, truncating it at the last good record"
+                );
+                OpenOptions::new()
+                    .write(true)
+                    .open(path)
+                    .await?
+                    .set_len(offset)
+                    .await?;
+                pipeline_metrics()
+                    .wal
+                    .truncated_bytes_total
+                    .get_or_create(&wal_metrics_labels(wal_type.segment_prefix(), None))
+                    .inc_by(file_len - offset);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reads the next record of the segment, which has `remaining` bytes left. Returns the size of the
+/// record (including its header) and its data, or the reason why the record is corrupted.
+/// refresher: each entry is `<u64(RECORD_MAGIC << 32 | data_len)><u32(crc32)><[u8;data_len]>`,
+/// or `<u64(data_len)><[u8;data_len]>` for the segments written before the checksums were added.
+async fn read_record(
+    reader: &mut BufReader<File>,
+    remaining: u64,
+) -> WalResult<Result<(u64, Bytes), &'static str>> {
+    if remaining < RECORD_LEN_SIZE {
+        return Ok(Err(TORN_RECORD));
+    }
+    let len_prefix = reader.read_u64_le().await?;
+
+    let has_checksum = match len_prefix >> 32 {
+        RECORD_MAGIC => true,
+        0 => false,
+        _ => return Ok(Err(INVALID_PREFIX)),
+    };
+    let data_len = len_prefix & u64::from(u32::MAX);
+    let header_len = if has_checksum {
+        RECORD_HEADER_SIZE
+    } else {
+        RECORD_LEN_SIZE
+    };
+    if remaining < header_len || data_len > remaining - header_len {
+        return Ok(Err(TORN_RECORD));
+    }
+
+    let checksum = if has_checksum {
+        Some(reader.read_u32_le().await?)
+    } else {
+        None
+    };
+
+    let mut buffer = vec![0; data_len as usize];
+    reader.read_exact(&mut buffer).await?;
+
+    if checksum.is_some_and(|checksum| checksum != crc32fast::hash(&buffer)) {
+        return Ok(Err(CHECKSUM_MISMATCH));
+    }
+
+    Ok(Ok((header_len + data_len, Bytes::from(buffer))))
 }
 
 /// Sort the filenames based on the file name. It is first sorted based on the timestamp and on
 /// conflict sorted on the file-index.
-fn sort_filenames(mut files: Vec<PathBuf>) -> Vec<PathBuf> {
+pub(in crate::reduce) fn sort_filenames(mut files: Vec<PathBuf>) -> Vec<PathBuf> {
     files.sort_by(|a, b| {
         let parse = |s: &str| {
             let parts: Vec<&str> = s.split('_').collect();
@@ -180,9 +315,195 @@ fn list_files(wal_type: &WalType, base_path: PathBuf) -> Vec<PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::reduce::wal::error::Error;
     use crate::reduce::wal::segment::WalType;
+    use crate::reduce::wal::segment::append::{AppendOnlyWal, SegmentWriteMessage};
+    use rand::Rng;
     use std::fs::File;
+    use std::path::Path;
     use tempfile::tempdir;
+    use tokio_stream::StreamExt;
+
+    /// Records of different sizes to write to the segments.
+    fn test_records() -> Vec<Bytes> {
+        (0..10u8)
+            .map(|i| Bytes::from(vec![i; 10 + i as usize * 7]))
+            .collect()
+    }
+
+    /// Offsets at which each of the records start, followed by the end of the last record.
+    fn record_offsets(records: &[Bytes]) -> Vec<u64> {
+        let mut offsets = vec![0];
+        for record in records {
+            let last = *offsets.last().unwrap();
+            offsets.push(last + RECORD_HEADER_SIZE + record.len() as u64);
+        }
+        offsets
+    }
+
+    /// Writes the records to a single frozen data segment and returns its path.
+    async fn write_segment(base_path: &Path, records: &[Bytes]) -> PathBuf {
+        let wal = AppendOnlyWal::new(WalType::Data, base_path.to_path_buf(), 10, 100, 300)
+            .await
+            .unwrap();
+        let (tx, rx) = mpsc::channel(records.len());
+        let handle = wal.streaming_write(ReceiverStream::new(rx)).await.unwrap();
+        for data in records {
+            tx.send(SegmentWriteMessage::WriteGcEvent { data: data.clone() })
+                .await
+                .unwrap();
+        }
+        drop(tx);
+        handle.await.unwrap().unwrap();
+
+        let files = list_files(&WalType::Data, base_path.to_path_buf());
+        assert_eq!(files.len(), 1);
+        files.into_iter().next().unwrap()
+    }
+
+    /// Replays the data segments and returns the records.
+    async fn replay(base_path: &Path, recovery_policy: WalRecoveryPolicy) -> WalResult<Vec<Bytes>> {
+        let (mut rx, handle) =
+            ReplayWal::new(WalType::Data, base_path.to_path_buf(), recovery_policy)
+                .streaming_read()
+                .unwrap();
+        let mut records = vec![];
+        while let Some(entry) = rx.next().await {
+            if let SegmentEntry::DataEntry { data, .. } = entry {
+                records.push(data);
+            }
+        }
+        handle.await.unwrap().map(|_| records)
+    }
+
+    #[tokio::test]
+    async fn test_replay_records() {
+        let temp_dir = tempdir().unwrap();
+        let records = test_records();
+        let path = write_segment(temp_dir.path(), &records).await;
+        let file_len = fs::metadata(&path).unwrap().len();
+        assert_eq!(file_len, *record_offsets(&records).last().unwrap());
+
+        let replayed = replay(temp_dir.path(), WalRecoveryPolicy::Fail)
+            .await
+            .unwrap();
+        assert_eq!(replayed, records);
+    }
+
+    #[tokio::test]
+    async fn test_replay_records_without_checksum() {
+        // segments written before the checksums were added
+        let temp_dir = tempdir().unwrap();
+        let records = test_records();
+        let mut segment = vec![];
+        for record in &records {
+            segment.extend_from_slice(&(record.len() as u64).to_le_bytes());
+            segment.extend_from_slice(record);
+        }
+        fs::write(temp_dir.path().join("data_0_1.wal.frozen"), segment).unwrap();
+
+        let replayed = replay(temp_dir.path(), WalRecoveryPolicy::Fail)
+            .await
+            .unwrap();
+        assert_eq!(replayed, records);
+    }
+
+    #[tokio::test]
+    async fn test_replay_corrupted_records() {
+        let records = test_records();
+        let offsets = record_offsets(&records);
+        let mut rng = rand::rng();
+
+        for _ in 0..50 {
+            let temp_dir = tempdir().unwrap();
+            let path = write_segment(temp_dir.path(), &records).await;
+
+            // corrupt a byte at a random offset
+            let mut segment = fs::read(&path).unwrap();
+            let offset = rng.random_range(0..segment.len());
+            let byte = segment.get_mut(offset).unwrap();
+            *byte ^= rng.random_range(1..=u8::MAX);
+            fs::write(&path, &segment).unwrap();
+
+            // all the records before the corrupted one are replayed, and the segment is truncated
+            // at the start of the corrupted record.
+            let corrupted = offsets.iter().filter(|o| **o <= offset as u64).count() - 1;
+            let replayed = replay(temp_dir.path(), WalRecoveryPolicy::Truncate)
+                .await
+                .unwrap();
+            assert_eq!(
+                replayed,
+                records.get(..corrupted).unwrap(),
+                "corrupted offset {offset}"
+            );
+            assert_eq!(
+                fs::metadata(&path).unwrap().len(),
+                *offsets.get(corrupted).unwrap()
+            );
+
+            // the truncated segment replays cleanly
+            let replayed = replay(temp_dir.path(), WalRecoveryPolicy::Fail)
+                .await
+                .unwrap();
+            assert_eq!(replayed, records.get(..corrupted).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_torn_writes() {
+        let records = test_records();
+        let offsets = record_offsets(&records);
+        let mut rng = rand::rng();
+
+        for _ in 0..50 {
+            let temp_dir = tempdir().unwrap();
+            let path = write_segment(temp_dir.path(), &records).await;
+
+            // cut the segment at a random offset, like a crash in the middle of a write
+            let len = rng.random_range(0..*offsets.last().unwrap());
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_len(len)
+                .unwrap();
+
+            let complete = offsets.iter().filter(|o| **o <= len).count() - 1;
+            let replayed = replay(temp_dir.path(), WalRecoveryPolicy::Truncate)
+                .await
+                .unwrap();
+            assert_eq!(replayed, records.get(..complete).unwrap(), "torn at {len}");
+            assert_eq!(
+                fs::metadata(&path).unwrap().len(),
+                *offsets.get(complete).unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_corrupted_record_fail() {
+        let temp_dir = tempdir().unwrap();
+        let records = test_records();
+        let offsets = record_offsets(&records);
+        let path = write_segment(temp_dir.path(), &records).await;
+
+        // corrupt the data of the 6th record
+        let mut segment = fs::read(&path).unwrap();
+        let byte = segment
+            .get_mut(*offsets.get(5).unwrap() as usize + RECORD_HEADER_SIZE as usize)
+            .unwrap();
+        *byte = !*byte;
+        fs::write(&path, &segment).unwrap();
+
+        let result = replay(temp_dir.path(), WalRecoveryPolicy::Fail).await;
+        let Err(Error::Corrupted { offset, reason, .. }) = result else {
+            panic!("expected a corrupted record error, got {result:?}");
+        };
+        assert_eq!(offset, *offsets.get(5).unwrap());
+        assert_eq!(reason, CHECKSUM_MISMATCH);
+        // the segment is left as-is
+        assert_eq!(fs::metadata(&path).unwrap().len(), *offsets.last().unwrap());
+    }
 
     #[test]
     fn test_list_files() {
@@ -0,0 +1,253 @@
+//! Snapshot of the [UnalignedWindowManager] (session and accumulator windows), written periodically
+//! next to the segments. The snapshot holds the state of the windows of every key and the offset of
+//! the latest message assigned to the windows, i.e., the position in the WAL till which the snapshot
+//! is valid.
+//!
+//! The [Compactor] treats the snapshot as a GC boundary. A message at or before the snapshot offset
+//! has been processed if its event time is before the GC end time of its keys, and it is not pending
+//! in the windows of the keys (late messages can be assigned to the keys after they have been garbage
+//! collected). Such messages are deleted even if their GC events have not been persisted in a frozen
+//! GC segment yet. The messages after the snapshot offset are only compacted using the GC events.
+//!
+//! On restart, the snapshot is loaded into the [UnalignedWindowManager] to restore the GC end times
+//! of the keys, and the WAL is replayed by the [Compactor] using the same GC boundary: the messages
+//! at or before the offset are skipped unless they are pending in the windows. The state of the
+//! reduce function of the open windows lives in the UDF and is not part of the snapshot, hence the
+//! pending messages are still replayed to rebuild the windows.
+//!
+//! [UnalignedWindowManager]: crate::reduce::reducer::unaligned::windower::UnalignedWindowManager
+//! [Compactor]: crate::reduce::wal::segment::compactor::Compactor
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use numaflow_pb::objects::wal::UnalignedWindowManagerState;
+use prost::Message;
+use tracing::info;
+
+use crate::reduce::wal::error::WalResult;
+use crate::shared::grpc::utc_from_timestamp;
+
+const WAL_KEY_SEPARATOR: &str = ":";
+
+/// Name of the snapshot file in the WAL directory.
+pub(crate) const WINDOW_SNAPSHOT_FILE: &str = "window.snapshot";
+
+/// Where and how often the snapshot of the windows is saved.
+#[derive(Debug, Clone)]
+pub(crate) struct SnapshotConfig {
+    pub(crate) path: PathBuf,
+    pub(crate) interval: Duration,
+}
+
+/// State of the windows of a key in the snapshot.
+#[derive(Debug, Default)]
+struct KeyState {
+    /// End time till which the messages of the key have been processed.
+    gc_end_time: Option<DateTime<Utc>>,
+    /// Session windows of the key which are not garbage collected yet.
+    windows: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Event times of the pending messages of the accumulator window of the key.
+    message_timestamps: BTreeSet<DateTime<Utc>>,
+}
+
+impl KeyState {
+    /// Whether the message with the event time is waiting to be processed in the windows of the key.
+    fn is_pending(&self, event_time: DateTime<Utc>) -> bool {
+        self.message_timestamps.contains(&event_time)
+            || self
+                .windows
+                .iter()
+                .any(|(start, end)| event_time >= *start && event_time < *end)
+    }
+}
+
+/// Snapshot of the unaligned window manager loaded from the file.
+#[derive(Debug)]
+pub(crate) struct WindowSnapshot {
+    /// Offset of the latest message covered by the snapshot.
+    offset: i64,
+    /// State of the windows, keyed by the joined keys.
+    states: HashMap<String, KeyState>,
+}
+
+impl From<UnalignedWindowManagerState> for WindowSnapshot {
+    fn from(value: UnalignedWindowManagerState) -> Self {
+        let states = value
+            .states
+            .into_iter()
+            .map(|state| {
+                let key_state = KeyState {
+                    gc_end_time: state.gc_end_time.map(utc_from_timestamp),
+                    windows: state
+                        .windows
+                        .into_iter()
+                        .filter_map(|window| {
+                            Some((
+                                utc_from_timestamp(window.start_time?),
+                                utc_from_timestamp(window.end_time?),
+                            ))
+                        })
+                        .collect(),
+                    message_timestamps: state
+                        .message_timestamps
+                        .into_iter()
+                        .map(utc_from_timestamp)
+                        .collect(),
+                };
+                (state.keys.join(WAL_KEY_SEPARATOR), key_state)
+            })
+            .collect();
+
+        Self {
+            offset: value.offset,
+            states,
+        }
+    }
+}
+
+impl WindowSnapshot {
+    /// Loads the snapshot from the file, returns `None` if there is no snapshot.
+    pub(crate) fn load(path: &Path) -> WalResult<Option<Self>> {
+        Ok(load_snapshot(path)?.map(Self::from))
+    }
+
+    /// Whether the message read at the offset has been processed according to the snapshot, i.e.,
+    /// it can be garbage collected.
+    pub(crate) fn is_processed(
+        &self,
+        read_offset: i64,
+        keys: &[String],
+        event_time: DateTime<Utc>,
+    ) -> bool {
+        // the snapshot does not know about the messages after its offset
+        if read_offset > self.offset {
+            return false;
+        }
+
+        let Some(state) = self.states.get(&keys.join(WAL_KEY_SEPARATOR)) else {
+            return false;
+        };
+
+        state
+            .gc_end_time
+            .is_some_and(|gc_end_time| event_time < gc_end_time)
+            && !state.is_pending(event_time)
+    }
+}
+
+/// Reads the state of the windows from the snapshot file, returns `None` if there is no snapshot.
+pub(crate) fn load_snapshot(path: &Path) -> WalResult<Option<UnalignedWindowManagerState>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let buf = fs::read(path)?;
+    let state = UnalignedWindowManagerState::decode(buf.as_slice())
+        .map_err(|e| format!("Failed to decode window snapshot: {e}"))?;
+
+    Ok(Some(state))
+}
+
+/// Writes the snapshot to the file. The snapshot is written to a temporary file which is renamed,
+/// so that a crash while writing never leaves a partial snapshot behind.
+pub(crate) fn write_snapshot(path: &Path, state: &UnalignedWindowManagerState) -> WalResult<()> {
+    let tmp_path = path.with_extension("tmp");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(&state.encode_to_vec())?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, path)?;
+
+    info!(
+        path = %path.display(),
+        offset = state.offset,
+        keys = state.states.len(),
+        "Saved window snapshot"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use numaflow_pb::objects::wal::{KeyedWindowState, Window};
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::shared::grpc::prost_timestamp_from_utc;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn keys(key: &str) -> Vec<String> {
+        vec![key.to_string()]
+    }
+
+    #[test]
+    fn test_snapshot_is_processed() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("window.snapshot");
+        assert!(WindowSnapshot::load(&path).unwrap().is_none());
+
+        let state = UnalignedWindowManagerState {
+            offset: 100,
+            states: vec![
+                // accumulator with a late message pending before the GC end time
+                KeyedWindowState {
+                    keys: keys("acc"),
+                    windows: vec![],
+                    message_timestamps: vec![
+                        prost_timestamp_from_utc(time(5)),
+                        prost_timestamp_from_utc(time(20)),
+                    ],
+                    gc_end_time: Some(prost_timestamp_from_utc(time(10))),
+                },
+                // session with a window reopened before the GC end time
+                KeyedWindowState {
+                    keys: keys("session"),
+                    windows: vec![Window {
+                        start_time: Some(prost_timestamp_from_utc(time(2))),
+                        end_time: Some(prost_timestamp_from_utc(time(4))),
+                    }],
+                    message_timestamps: vec![],
+                    gc_end_time: Some(prost_timestamp_from_utc(time(10))),
+                },
+                // open window which has not been garbage collected yet
+                KeyedWindowState {
+                    keys: keys("open"),
+                    windows: vec![],
+                    message_timestamps: vec![prost_timestamp_from_utc(time(1))],
+                    gc_end_time: None,
+                },
+            ],
+        };
+        write_snapshot(&path, &state).unwrap();
+        assert!(!path.with_extension("tmp").exists());
+
+        let snapshot = WindowSnapshot::load(&path).unwrap().unwrap();
+
+        assert!(snapshot.is_processed(50, &keys("acc"), time(9)));
+        // pending messages of the accumulator
+        assert!(!snapshot.is_processed(50, &keys("acc"), time(5)));
+        assert!(!snapshot.is_processed(50, &keys("acc"), time(20)));
+        // after the GC end time
+        assert!(!snapshot.is_processed(50, &keys("acc"), time(10)));
+        // after the snapshot offset
+        assert!(!snapshot.is_processed(101, &keys("acc"), time(9)));
+
+        assert!(snapshot.is_processed(100, &keys("session"), time(1)));
+        assert!(snapshot.is_processed(100, &keys("session"), time(4)));
+        // in the open session window
+        assert!(!snapshot.is_processed(100, &keys("session"), time(3)));
+
+        assert!(!snapshot.is_processed(50, &keys("open"), time(0)));
+        assert!(!snapshot.is_processed(50, &keys("unknown"), time(0)));
+    }
+}
@@ -11,6 +11,9 @@ pub(crate) mod create_components;
 /// Shared methods for forwarding messages.
 pub(crate) mod forward;
 
+/// Segment files written by the file sink and read by the file source.
+pub(crate) mod file_segment;
+
 /// Test server framework: helpers for spinning up numaflow SDK gRPC servers in tests.
 #[cfg(test)]
 pub(crate) mod test_utils;
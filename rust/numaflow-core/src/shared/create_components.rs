@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use crate::config::components::reduce::UnalignedWindowType;
-use crate::config::components::sink::{SinkConfig, SinkType};
+use crate::config::components::sink::{BroadcastSinkConfig, OnOpenStrategy, SinkConfig, SinkType};
 use crate::config::components::source::{SourceConfig, SourceType};
 use crate::config::components::transformer::TransformerConfig;
 use crate::config::get_vertex_replica;
@@ -13,6 +13,7 @@ use crate::config::pipeline::{
 };
 use crate::error::Error;
 use crate::mapper::map::MapHandle;
+use crate::pipeline::isb::ISBService;
 use crate::reduce::reducer::WindowManager;
 use crate::reduce::reducer::aligned::user_defined::UserDefinedAlignedReduce;
 use crate::reduce::reducer::unaligned::user_defined::UserDefinedUnalignedReduce;
@@ -20,9 +21,13 @@ use crate::reduce::reducer::unaligned::user_defined::accumulator::UserDefinedAcc
 use crate::reduce::reducer::unaligned::user_defined::session::UserDefinedSessionReduce;
 use crate::shared::grpc;
 use crate::shared::grpc::{create_rpc_channel, wait_until_source_ready};
+use crate::sinker::sink::broadcast::{BroadcastSink, ChildSink};
+use crate::sinker::sink::file::FileSink;
+use crate::sinker::sink::object_store::ObjectStoreSink;
 use crate::sinker::sink::serve::ServingStore;
 use crate::sinker::sink::{SinkClientType, SinkWriter, SinkWriterBuilder};
 use crate::source::Source;
+use crate::source::file::new_file_source;
 use crate::source::generator::new_generator;
 use crate::source::http::CoreHttpSource;
 use crate::source::jetstream::new_jetstream_source;
@@ -37,8 +42,9 @@ use crate::typ::NumaflowTypeConfig;
 use crate::watermark::isb::ISBWatermarkHandle;
 use crate::watermark::source::SourceWatermarkHandle;
 use crate::{config, error, metrics, source};
-use async_nats::jetstream::Context;
+use numaflow_http::sink::HttpSink;
 use numaflow_models::models::{NatsAuth, Tls};
+use numaflow_nats::sink::NatsSink;
 use numaflow_nats::{TlsClientAuthCerts, TlsConfig};
 use numaflow_pb::clients::accumulator::accumulator_client::AccumulatorClient;
 use numaflow_pb::clients::map::map_client::MapClient;
@@ -47,6 +53,7 @@ use numaflow_pb::clients::sessionreduce::session_reduce_client::SessionReduceCli
 use numaflow_pb::clients::sink::sink_client::SinkClient;
 use numaflow_pb::clients::source::source_client::SourceClient;
 use numaflow_pb::clients::sourcetransformer::source_transform_client::SourceTransformClient;
+use numaflow_postgres::sink::PostgresSink;
 use numaflow_shared::server_info::{
     ContainerType, Protocol, ServerInfo, sdk_server_info, supports_nack,
 };
@@ -67,6 +74,20 @@ pub(crate) async fn create_sink_writer(
     cln_token: &CancellationToken,
     bypass_condition: Option<BypassConditions>,
 ) -> error::Result<SinkWriter> {
+    if let Some(circuit_breaker) = primary_sink
+        .retry_config
+        .as_ref()
+        .and_then(|retry_config| retry_config.sink_circuit_breaker.as_ref())
+        && circuit_breaker.on_open == OnOpenStrategy::Fallback
+        && fallback_sink.is_none()
+    {
+        return Err(Error::Config(
+            "circuit breaker of the sink routes to the fallback sink while open, \
+            but no fallback sink is configured"
+                .to_string(),
+        ));
+    }
+
     let mut sink_writer_builder =
         append_primary_sink_client(batch_size, read_timeout, primary_sink, cln_token).await?;
 
@@ -156,14 +177,111 @@ async fn append_primary_sink_client(
                 SinkClientType::Pulsar(Box::new(pulsar_sink)),
             )
         }
+        SinkType::Nats(nats_sink_config) => {
+            let nats_sink = NatsSink::connect(*nats_sink_config).await?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::Nats(Box::new(nats_sink)),
+            )
+        }
+        SinkType::Http(http_sink_config) => {
+            let http_sink = HttpSink::new(*http_sink_config)?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::Http(Box::new(http_sink)),
+            )
+        }
+        SinkType::File(file_sink_config) => {
+            let file_sink = FileSink::new(file_sink_config).await?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::File(Box::new(file_sink)),
+            )
+        }
+        SinkType::ObjectStore(object_store_config) => {
+            let object_store_sink = ObjectStoreSink::new(*object_store_config).await?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::ObjectStore(Box::new(object_store_sink)),
+            )
+        }
+        SinkType::Postgres(postgres_sink_config) => {
+            let postgres_sink = PostgresSink::connect(*postgres_sink_config)
+                .await
+                .map_err(|e| Error::Sink(e.to_string()))?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::Postgres(Box::new(postgres_sink)),
+            )
+        }
+        SinkType::Broadcast(broadcast_config) => {
+            let broadcast_sink = create_broadcast_sink(broadcast_config).await?;
+            SinkWriterBuilder::new(
+                batch_size,
+                read_timeout,
+                SinkClientType::Broadcast(Box::new(broadcast_sink)),
+            )
+            .retry_config(primary_sink.retry_config.unwrap_or_default())
+        }
     })
 }
 
+/// Creates the broadcast sink with its builtin child sinks.
+async fn create_broadcast_sink(config: BroadcastSinkConfig) -> Result<BroadcastSink, Error> {
+    let mut children = Vec::with_capacity(config.sinks.len());
+    for (name, sink_type) in config.sinks {
+        let sink_client = match sink_type {
+            SinkType::Log(_) => SinkClientType::Log,
+            SinkType::Blackhole(_) => SinkClientType::Blackhole,
+            SinkType::Sqs(sqs_sink_config) => {
+                SinkClientType::Sqs(SqsSinkBuilder::new(sqs_sink_config).build().await?)
+            }
+            SinkType::Kafka(sink_config) => {
+                SinkClientType::Kafka(numaflow_kafka::sink::new_sink(*sink_config)?)
+            }
+            SinkType::Pulsar(pulsar_sink_config) => SinkClientType::Pulsar(Box::new(
+                numaflow_pulsar::sink::new_sink(*pulsar_sink_config).await?,
+            )),
+            SinkType::Nats(nats_sink_config) => {
+                SinkClientType::Nats(Box::new(NatsSink::connect(*nats_sink_config).await?))
+            }
+            SinkType::Http(http_sink_config) => {
+                SinkClientType::Http(Box::new(HttpSink::new(*http_sink_config)?))
+            }
+            SinkType::File(file_sink_config) => {
+                SinkClientType::File(Box::new(FileSink::new(file_sink_config).await?))
+            }
+            SinkType::ObjectStore(object_store_config) => SinkClientType::ObjectStore(Box::new(
+                ObjectStoreSink::new(*object_store_config).await?,
+            )),
+            SinkType::Postgres(postgres_sink_config) => SinkClientType::Postgres(Box::new(
+                PostgresSink::connect(*postgres_sink_config)
+                    .await
+                    .map_err(|e| Error::Sink(e.to_string()))?,
+            )),
+            other => {
+                return Err(Error::Config(format!(
+                    "{} sink cannot be a child of the broadcast sink",
+                    other.name()
+                )));
+            }
+        };
+        children.push((name, ChildSink::try_from(sink_client)?));
+    }
+    Ok(BroadcastSink::new(children, config.ack))
+}
+
 async fn append_fallback_sink_client(
     cln_token: &CancellationToken,
     sink_writer_builder: SinkWriterBuilder,
     fb_sink: SinkConfig,
 ) -> Result<SinkWriterBuilder, Error> {
+    let sink_writer_builder = sink_writer_builder.dlq_headers(fb_sink.dlq_headers);
     Ok(match fb_sink.sink_type.clone() {
         SinkType::Log(_) => sink_writer_builder.fb_sink_client(SinkClientType::Log),
         SinkType::Serve => sink_writer_builder.fb_sink_client(SinkClientType::Serve),
@@ -208,6 +326,33 @@ async fn append_fallback_sink_client(
             let pulsar_sink = numaflow_pulsar::sink::new_sink(*pulsar_sink_config).await?;
             sink_writer_builder.fb_sink_client(SinkClientType::Pulsar(Box::new(pulsar_sink)))
         }
+        SinkType::Nats(nats_sink_config) => {
+            let nats_sink = NatsSink::connect(*nats_sink_config).await?;
+            sink_writer_builder.fb_sink_client(SinkClientType::Nats(Box::new(nats_sink)))
+        }
+        SinkType::Http(http_sink_config) => {
+            let http_sink = HttpSink::new(*http_sink_config)?;
+            sink_writer_builder.fb_sink_client(SinkClientType::Http(Box::new(http_sink)))
+        }
+        SinkType::File(file_sink_config) => {
+            let file_sink = FileSink::new(file_sink_config).await?;
+            sink_writer_builder.fb_sink_client(SinkClientType::File(Box::new(file_sink)))
+        }
+        SinkType::ObjectStore(object_store_config) => {
+            let object_store_sink = ObjectStoreSink::new(*object_store_config).await?;
+            sink_writer_builder
+                .fb_sink_client(SinkClientType::ObjectStore(Box::new(object_store_sink)))
+        }
+        SinkType::Postgres(postgres_sink_config) => {
+            let postgres_sink = PostgresSink::connect(*postgres_sink_config)
+                .await
+                .map_err(|e| Error::Sink(e.to_string()))?;
+            sink_writer_builder.fb_sink_client(SinkClientType::Postgres(Box::new(postgres_sink)))
+        }
+        SinkType::Broadcast(broadcast_config) => {
+            let broadcast_sink = create_broadcast_sink(broadcast_config).await?;
+            sink_writer_builder.fb_sink_client(SinkClientType::Broadcast(Box::new(broadcast_sink)))
+        }
     })
 }
 
@@ -263,6 +408,35 @@ async fn append_ons_sink_client(
             sink_writer_builder
                 .on_success_sink_client(SinkClientType::Pulsar(Box::new(pulsar_sink)))
         }
+        SinkType::Nats(nats_sink_config) => {
+            let nats_sink = NatsSink::connect(*nats_sink_config).await?;
+            sink_writer_builder.on_success_sink_client(SinkClientType::Nats(Box::new(nats_sink)))
+        }
+        SinkType::Http(http_sink_config) => {
+            let http_sink = HttpSink::new(*http_sink_config)?;
+            sink_writer_builder.on_success_sink_client(SinkClientType::Http(Box::new(http_sink)))
+        }
+        SinkType::File(file_sink_config) => {
+            let file_sink = FileSink::new(file_sink_config).await?;
+            sink_writer_builder.on_success_sink_client(SinkClientType::File(Box::new(file_sink)))
+        }
+        SinkType::ObjectStore(object_store_config) => {
+            let object_store_sink = ObjectStoreSink::new(*object_store_config).await?;
+            sink_writer_builder
+                .on_success_sink_client(SinkClientType::ObjectStore(Box::new(object_store_sink)))
+        }
+        SinkType::Postgres(postgres_sink_config) => {
+            let postgres_sink = PostgresSink::connect(*postgres_sink_config)
+                .await
+                .map_err(|e| Error::Sink(e.to_string()))?;
+            sink_writer_builder
+                .on_success_sink_client(SinkClientType::Postgres(Box::new(postgres_sink)))
+        }
+        SinkType::Broadcast(broadcast_config) => {
+            let broadcast_sink = create_broadcast_sink(broadcast_config).await?;
+            sink_writer_builder
+                .on_success_sink_client(SinkClientType::Broadcast(Box::new(broadcast_sink)))
+        }
     })
 }
 
@@ -541,6 +715,20 @@ pub async fn create_source<C: NumaflowTypeConfig>(
             )
             .await)
         }
+        SourceType::File(file_config) => {
+            let file_source =
+                new_file_source(file_config.clone(), batch_size, read_timeout).await?;
+            Ok(Source::new(
+                batch_size,
+                source::SourceType::File(file_source),
+                tracker,
+                source_config.read_ahead,
+                transformer,
+                watermark_handle,
+                rate_limiter,
+            )
+            .await)
+        }
 
         SourceType::UserDefined(user_defined_config) => {
             let (source_client, server_info) =
@@ -811,7 +999,7 @@ pub(crate) fn get_secret_from_volume(name: &str, key: &str) -> Result<String, St
 /// Creates an ISBWatermarkHandle if watermark is enabled in the configuration
 pub async fn create_edge_watermark_handle(
     config: &PipelineConfig,
-    js_context: &Context,
+    isb: &ISBService,
     cln_token: &CancellationToken,
     window_manager: Option<WindowManager>,
     tracker: Tracker,
@@ -824,7 +1012,7 @@ pub async fn create_edge_watermark_handle(
                 config.replica,
                 config.vertex_type,
                 2 * config.read_timeout,
-                js_context.clone(),
+                isb.clone(),
                 edge_config,
                 &config.to_vertex_config,
                 cln_token.clone(),
@@ -0,0 +1,353 @@
+//! Segment files shared by the file sink and the file source. A segment is written by a single sink
+//! replica under an in-progress name and renamed to its final name once it is rotated, so the file
+//! source only ever reads complete segments. Segment names start with the zero-padded creation
+//! timestamp, sorting them by name gives the order in which they were written.
+//!
+//! Records are either encoded as one JSON object per line ([SegmentFormat::Ndjson]), or as the ISB
+//! protobuf message prefixed with its length as `u64` little-endian ([SegmentFormat::Proto]), the
+//! same length prefix as the reduce WAL segments (without their checksums).
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use bytes::{Bytes, BytesMut};
+use chrono::{DateTime, Utc};
+use prost::Message as _;
+use serde::{Deserialize, Serialize};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+use crate::error::{Error, Result};
+use crate::message::Message;
+use crate::shared::grpc::utc_from_timestamp;
+
+/// Suffix of the segment which is currently being written to.
+pub(crate) const IN_PROGRESS_SUFFIX: &str = ".inprogress";
+
+/// Encoding of the records in a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum SegmentFormat {
+    /// One JSON object per line, the payload is base64 encoded.
+    #[default]
+    Ndjson,
+    /// Length-delimited ISB protobuf messages.
+    Proto,
+}
+
+impl TryFrom<&str> for SegmentFormat {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "ndjson" => Ok(SegmentFormat::Ndjson),
+            "proto" => Ok(SegmentFormat::Proto),
+            _ => Err(Error::Config(format!(
+                "Unsupported segment format '{value}', supported formats are ndjson and proto"
+            ))),
+        }
+    }
+}
+
+impl SegmentFormat {
+    /// Extension of the completed segments.
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            SegmentFormat::Ndjson => "ndjson",
+            SegmentFormat::Proto => "pb",
+        }
+    }
+
+    /// Final name of a segment, the in-progress segment has [IN_PROGRESS_SUFFIX] appended to it.
+    pub(crate) fn segment_name(
+        &self,
+        create_time: DateTime<Utc>,
+        replica: u16,
+        idx: usize,
+    ) -> String {
+        format!(
+            "{:020}_{replica}_{idx}.{}",
+            create_time.timestamp_micros(),
+            self.extension()
+        )
+    }
+
+    /// Whether the file is a completed segment of this format.
+    pub(crate) fn is_segment(&self, file_name: &str) -> bool {
+        file_name
+            .strip_suffix(self.extension())
+            .is_some_and(|name| name.ends_with('.'))
+    }
+
+    /// Encodes a message into a record of this format.
+    pub(crate) fn encode(&self, message: Message) -> Result<Bytes> {
+        match self {
+            SegmentFormat::Ndjson => {
+                let record = JsonRecord {
+                    id: message.id.to_string(),
+                    keys: message.keys.to_vec(),
+                    headers: message.headers.as_ref().clone(),
+                    event_time: message.event_time.timestamp_millis(),
+                    value: BASE64_STANDARD.encode(&message.value),
+                };
+                let mut line = serde_json::to_vec(&record)
+                    .map_err(|e| Error::Sink(format!("Encoding record: {e}")))?;
+                line.push(b'\n');
+                Ok(line.into())
+            }
+            SegmentFormat::Proto => {
+                let data: BytesMut = message.try_into()?;
+                let mut record = BytesMut::with_capacity(data.len() + 8);
+                record.extend_from_slice(&(data.len() as u64).to_le_bytes());
+                record.extend_from_slice(&data);
+                Ok(record.freeze())
+            }
+        }
+    }
+}
+
+/// A record in a segment.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SegmentRecord {
+    /// ID of the message when it was written to the segment.
+    pub(crate) id: String,
+    pub(crate) keys: Vec<String>,
+    pub(crate) headers: HashMap<String, String>,
+    pub(crate) event_time: DateTime<Utc>,
+    pub(crate) value: Bytes,
+}
+
+/// On disk representation of a [SegmentFormat::Ndjson] record.
+#[derive(Serialize, Deserialize)]
+struct JsonRecord {
+    id: String,
+    keys: Vec<String>,
+    headers: HashMap<String, String>,
+    /// Event time in epoch milliseconds.
+    #[serde(rename = "eventTime")]
+    event_time: i64,
+    /// Base64 encoded payload.
+    value: String,
+}
+
+/// Sequentially reads the records of a completed segment.
+pub(crate) struct SegmentReader {
+    format: SegmentFormat,
+    reader: BufReader<File>,
+    /// Size of the segment when it was opened.
+    size: u64,
+    /// Bytes left to read, the length prefixes of the records are checked against it so that a
+    /// corrupted segment can't make the reader allocate more than the size of the file.
+    remaining: u64,
+}
+
+impl SegmentReader {
+    pub(crate) async fn open(format: SegmentFormat, path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .await
+            .map_err(|e| Error::Source(format!("Opening segment {}: {e}", path.display())))?;
+        let remaining = file
+            .metadata()
+            .await
+            .map_err(|e| Error::Source(format!("Opening segment {}: {e}", path.display())))?
+            .len();
+        Ok(Self {
+            format,
+            reader: BufReader::new(file),
+            size: remaining,
+            remaining,
+        })
+    }
+
+    /// Number of bytes read from the segment so far.
+    fn position(&self) -> u64 {
+        self.size - self.remaining
+    }
+
+    /// Reads the next record, returns `None` at the end of the segment.
+    pub(crate) async fn next(&mut self) -> Result<Option<SegmentRecord>> {
+        match self.format {
+            SegmentFormat::Ndjson => self.next_json().await,
+            SegmentFormat::Proto => self.next_proto().await,
+        }
+    }
+
+    async fn next_json(&mut self) -> Result<Option<SegmentRecord>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = self
+                .reader
+                .read_line(&mut line)
+                .await
+                .map_err(|e| Error::Source(format!("Reading segment: {e}")))?;
+            if read == 0 {
+                return Ok(None);
+            }
+            self.remaining = self.remaining.saturating_sub(read as u64);
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let record: JsonRecord = serde_json::from_str(&line)
+            .map_err(|e| Error::Source(format!("Decoding segment record: {e}")))?;
+        let value = BASE64_STANDARD
+            .decode(record.value)
+            .map_err(|e| Error::Source(format!("Decoding segment record value: {e}")))?;
+        Ok(Some(SegmentRecord {
+            id: record.id,
+            keys: record.keys,
+            headers: record.headers,
+            event_time: DateTime::from_timestamp_millis(record.event_time).ok_or_else(|| {
+                Error::Source(format!("Invalid event time {}", record.event_time))
+            })?,
+            value: value.into(),
+        }))
+    }
+
+    async fn next_proto(&mut self) -> Result<Option<SegmentRecord>> {
+        let len = match self.reader.read_u64_le().await {
+            Ok(len) => len,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(Error::Source(format!("Reading segment: {e}"))),
+        };
+        self.remaining = self.remaining.saturating_sub(8);
+        if len > self.remaining {
+            return Err(Error::Source(format!(
+                "Segment record of {len} bytes exceeds the {} bytes left in the segment, the \
+                segment is corrupted",
+                self.remaining
+            )));
+        }
+        self.remaining -= len;
+        let mut data = vec![0; len as usize];
+        self.reader
+            .read_exact(&mut data)
+            .await
+            .map_err(|e| Error::Source(format!("Reading segment record: {e}")))?;
+
+        let proto_message = numaflow_pb::objects::isb::Message::decode(data.as_ref())
+            .map_err(|e| Error::Proto(e.to_string()))?;
+        let header = proto_message
+            .header
+            .ok_or(Error::Proto("Missing header".to_string()))?;
+        let body = proto_message
+            .body
+            .ok_or(Error::Proto("Missing body".to_string()))?;
+        let event_time = header
+            .message_info
+            .and_then(|info| info.event_time)
+            .map(utc_from_timestamp)
+            .ok_or(Error::Proto("Missing event time".to_string()))?;
+        Ok(Some(SegmentRecord {
+            id: header
+                .id
+                .map(|id| crate::message::MessageID::from(id).to_string())
+                .unwrap_or_default(),
+            keys: header.keys,
+            headers: header.headers,
+            event_time,
+            value: body.payload.into(),
+        }))
+    }
+}
+
+/// Returns the length of the segment till the end of its last complete record. The records after
+/// it can't be read, e.g., a record which was partially written when the writer crashed.
+pub(crate) async fn valid_length(format: SegmentFormat, path: &Path) -> Result<u64> {
+    let mut reader = SegmentReader::open(format, path).await?;
+    let mut length = 0;
+    while let Ok(Some(_)) = reader.next().await {
+        length = reader.position();
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset};
+    use std::sync::Arc;
+
+    fn test_message() -> Message {
+        Message {
+            keys: Arc::from(vec!["k1".to_string(), "k2".to_string()]),
+            value: Bytes::from_static(b"\x00binary\xffpayload"),
+            offset: Offset::Int(IntOffset::new(1, 0)),
+            event_time: DateTime::from_timestamp_millis(1_700_000_000_123).unwrap(),
+            id: MessageID {
+                vertex_name: "in".to_string().into(),
+                offset: "1".to_string().into(),
+                index: 0,
+            },
+            headers: Arc::new(HashMap::from([("h".to_string(), "v".to_string())])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_segment_name() {
+        let format = SegmentFormat::Proto;
+        let name = format.segment_name(
+            DateTime::from_timestamp_micros(1_700_000_000_000_000).unwrap(),
+            1,
+            2,
+        );
+        assert_eq!(name, "00001700000000000000_1_2.pb");
+        assert!(format.is_segment(&name));
+        assert!(!format.is_segment(&format!("{name}{IN_PROGRESS_SUFFIX}")));
+        assert!(!SegmentFormat::Ndjson.is_segment(&name));
+        assert!(!format.is_segment(".checkpoint.json"));
+    }
+
+    #[tokio::test]
+    async fn test_encode_decode() {
+        for format in [SegmentFormat::Ndjson, SegmentFormat::Proto] {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("segment");
+
+            let mut data = Vec::new();
+            for _ in 0..3 {
+                data.extend_from_slice(&format.encode(test_message()).unwrap());
+            }
+            tokio::fs::write(&path, data).await.unwrap();
+
+            let mut reader = SegmentReader::open(format, &path).await.unwrap();
+            for _ in 0..3 {
+                let record = reader.next().await.unwrap().unwrap();
+                let msg = test_message();
+                assert_eq!(record.id, msg.id.to_string());
+                assert_eq!(record.keys, msg.keys.to_vec());
+                assert_eq!(record.headers, *msg.headers);
+                assert_eq!(record.event_time, msg.event_time);
+                assert_eq!(record.value, msg.value);
+            }
+            assert!(reader.next().await.unwrap().is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_length_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segment");
+        let mut data = SegmentFormat::Proto
+            .encode(test_message())
+            .unwrap()
+            .to_vec();
+        // a length prefix far beyond the size of the segment
+        data.extend_from_slice(&(u64::MAX / 2).to_le_bytes());
+        data.extend_from_slice(b"garbage");
+        tokio::fs::write(&path, data).await.unwrap();
+
+        let mut reader = SegmentReader::open(SegmentFormat::Proto, &path)
+            .await
+            .unwrap();
+        assert!(reader.next().await.unwrap().is_some());
+        let Err(Error::Source(e)) = reader.next().await else {
+            panic!("Expected the corrupted record to be rejected");
+        };
+        assert!(e.contains("corrupted"), "{e}");
+    }
+}
@@ -20,6 +20,10 @@ pub(crate) mod sink;
 
 mod actor;
 
+mod circuit_breaker;
+
+mod dlq;
+
 mod builder;
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 use crate::Result;
-use crate::config::components::sink::{OnFailureStrategy, RetryConfig};
+use crate::config::components::sink::{OnFailureStrategy, OnOpenStrategy, RetryConfig};
 use crate::message::Message;
 use crate::metadata::Metadata;
+use crate::sinker::circuit_breaker::{CIRCUIT_OPEN_ERROR, CircuitBreaker, Permit};
+use crate::sinker::dlq::SinkFailure;
 use crate::sinker::sink::{ResponseStatusFromSink, Sink};
 use backoff::strategy::exponential::Exponential;
 use std::collections::HashMap;
@@ -9,7 +11,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc::Receiver;
 use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
-use tracing::warn;
+use tracing::{error, warn};
 
 /// Response from the sink actor containing categorized messages
 #[derive(Default)]
@@ -19,6 +21,9 @@ pub(super) struct SinkActorResponse {
     pub(super) serving: Vec<Message>,
     pub(super) dropped: Vec<Message>,
     pub(super) on_success: Vec<Message>,
+    /// failures of the messages in the sink keyed by message id, used to build the dead-letter
+    /// envelope of the fallback messages.
+    pub(super) failures: HashMap<String, SinkFailure>,
 }
 
 /// SinkActorMessage is a message that is sent to the SinkActor.
@@ -33,6 +38,7 @@ pub(super) struct SinkActor<T> {
     actor_messages: Receiver<SinkActorMessage>,
     sink: T,
     retry_config: RetryConfig,
+    circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl<T> SinkActor<T>
@@ -44,10 +50,15 @@ where
         sink: T,
         retry_config: RetryConfig,
     ) -> Self {
+        let circuit_breaker = retry_config
+            .sink_circuit_breaker
+            .clone()
+            .map(CircuitBreaker::new);
         Self {
             actor_messages,
             sink,
             retry_config,
+            circuit_breaker,
         }
     }
 
@@ -82,9 +93,45 @@ where
         let mut backoff_iter = backoff.into_iter();
         let mut retry_attempt = 0;
         let mut error_map = HashMap::new();
+        let mut failures: HashMap<String, SinkFailure> = HashMap::new();
 
         loop {
+            // while the circuit is open the batch is not written, it is either routed to the
+            // fallback sink or waits until the circuit is half-open.
+            if let Some(circuit_breaker) = self.circuit_breaker.as_mut()
+                && let Permit::Rejected(wait) = circuit_breaker.acquire()
+            {
+                match circuit_breaker.on_open() {
+                    OnOpenStrategy::Fallback => {
+                        for msg in &messages_to_retry {
+                            failures.entry(msg.id.to_string()).or_insert_with(|| {
+                                SinkFailure::new(CIRCUIT_OPEN_ERROR.to_string())
+                            });
+                        }
+                        fallback_messages.append(&mut messages_to_retry);
+                        break;
+                    }
+                    OnOpenStrategy::Pause => {
+                        tokio::select! {
+                            _ = tokio::time::sleep(wait) => continue,
+                            _ = cancel.cancelled() => {
+                                warn!("Cancellation received while the circuit is open");
+                                return Ok(SinkActorResponse {
+                                    failed: messages_to_retry,
+                                    fallback: fallback_messages,
+                                    serving: serving_messages,
+                                    dropped: dropped_messages,
+                                    on_success: on_success_messages,
+                                    failures,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+
             // send batch to sink
+            let attempted = messages_to_retry.len();
             let responses = self.sink.sink(messages_to_retry.clone()).await?;
 
             // Create a map of id to result
@@ -103,6 +150,12 @@ where
                         // Message successfully sunk, nothing more to do
                     }
                     Some(ResponseStatusFromSink::Failed(err_msg)) => {
+                        match failures.get_mut(&msg_id) {
+                            Some(failure) => failure.failed_again(err_msg.clone()),
+                            None => {
+                                failures.insert(msg_id, SinkFailure::new(err_msg.clone()));
+                            }
+                        }
                         *error_map.entry(err_msg).or_insert(0) += 1;
                         messages_to_retry.push(msg); // keep for retry
                     }
@@ -155,6 +208,11 @@ where
                 }
             }
 
+            if let Some(circuit_breaker) = self.circuit_breaker.as_mut() {
+                let failed = messages_to_retry.len();
+                circuit_breaker.record(attempted - failed, failed);
+            }
+
             if messages_to_retry.is_empty() {
                 // success path, all messages processed
                 break;
@@ -170,6 +228,7 @@ where
                     serving: serving_messages,
                     dropped: dropped_messages,
                     on_success: on_success_messages,
+                    failures,
                 });
             }
 
@@ -224,6 +283,7 @@ where
             serving: serving_messages,
             dropped: dropped_messages,
             on_success: on_success_messages,
+            failures,
         })
     }
 
@@ -238,5 +298,8 @@ where
         while let Some(msg) = self.actor_messages.recv().await {
             self.handle_message(msg).await;
         }
+        if let Err(e) = self.sink.close().await {
+            error!(?e, "Failed to close the sink");
+        }
     }
 }
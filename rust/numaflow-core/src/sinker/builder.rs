@@ -142,6 +142,7 @@ pub(crate) struct SinkWriterBuilder {
     on_success_sink_client: Option<SinkClientType>,
     serving_store: Option<ServingStore>,
     bypass_conditions: Option<BypassConditions>,
+    dlq_headers: bool,
 }
 
 impl SinkWriterBuilder {
@@ -159,6 +160,7 @@ impl SinkWriterBuilder {
             on_success_sink_client: None,
             serving_store: None,
             bypass_conditions: None,
+            dlq_headers: false,
         }
     }
 
@@ -187,11 +189,18 @@ impl SinkWriterBuilder {
         self
     }
 
+    /// Mirror the dead-letter envelope of the fallback messages into their headers.
+    pub(crate) fn dlq_headers(mut self, dlq_headers: bool) -> Self {
+        self.dlq_headers = dlq_headers;
+        self
+    }
+
     /// Build the SinkWriter, it also starts the SinkActor to handle messages.
     pub(crate) async fn build(self) -> Result<SinkWriter> {
         let (sink_handle, receiver) = mpsc::channel(self.batch_size);
         let mut health_check_builder = HealthCheckClientsBuilder::new();
         let retry_config = self.retry_config.clone();
+        let primary_sink = self.sink_client.name();
 
         // starting sinks
         match self.sink_client {
@@ -242,6 +251,42 @@ impl SinkWriterBuilder {
                     actor.run().await;
                 });
             }
+            SinkClientType::Nats(nats_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *nats_sink, retry_config);
+                    actor.run().await;
+                });
+            }
+            SinkClientType::Http(http_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *http_sink, retry_config);
+                    actor.run().await;
+                });
+            }
+            SinkClientType::File(file_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *file_sink, retry_config);
+                    actor.run().await;
+                });
+            }
+            SinkClientType::ObjectStore(object_store_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *object_store_sink, retry_config);
+                    actor.run().await;
+                });
+            }
+            SinkClientType::Postgres(postgres_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *postgres_sink, retry_config);
+                    actor.run().await;
+                });
+            }
+            SinkClientType::Broadcast(broadcast_sink) => {
+                tokio::spawn(async move {
+                    let actor = SinkActor::new(receiver, *broadcast_sink, retry_config);
+                    actor.run().await;
+                });
+            }
         };
 
         // start fallback sinks
@@ -296,6 +341,43 @@ impl SinkWriterBuilder {
                         actor.run().await;
                     });
                 }
+                SinkClientType::Nats(nats_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *nats_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Http(http_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *http_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::File(file_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *file_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::ObjectStore(object_store_sink) => {
+                    tokio::spawn(async move {
+                        let actor =
+                            SinkActor::new(fb_receiver, *object_store_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Postgres(postgres_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *postgres_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Broadcast(broadcast_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *broadcast_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
             };
             Some(fb_sender)
         } else {
@@ -355,6 +437,43 @@ impl SinkWriterBuilder {
                         actor.run().await;
                     });
                 }
+                SinkClientType::Nats(nats_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *nats_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Http(http_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *http_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::File(file_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *file_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::ObjectStore(object_store_sink) => {
+                    tokio::spawn(async move {
+                        let actor =
+                            SinkActor::new(fb_receiver, *object_store_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Postgres(postgres_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *postgres_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
+                SinkClientType::Broadcast(broadcast_sink) => {
+                    tokio::spawn(async move {
+                        let actor = SinkActor::new(fb_receiver, *broadcast_sink, fb_retry_config);
+                        actor.run().await;
+                    });
+                }
             };
             Some(os_sender)
         } else {
@@ -378,6 +497,8 @@ impl SinkWriterBuilder {
             self.serving_store,
             health_check_clients,
             self.bypass_conditions,
+            primary_sink,
+            self.dlq_headers,
         ))
     }
 }
@@ -0,0 +1,271 @@
+//! Circuit breaker around the primary sink, so that a sink that is hard-down is not hammered with
+//! every new batch burning the full retry budget.
+//!
+//! The circuit is **closed** while the sink is healthy and tracks the outcome of the writes over a
+//! sliding window. Once the ratio of the failed writes in the window crosses the threshold, the
+//! circuit **opens** and the writes are either paused or routed to the fallback sink (see
+//! [OnOpenStrategy]). After the open duration, the circuit is **half-open** and the next batch is
+//! written as a probe, it closes the circuit if all the messages are written and opens it again
+//! otherwise.
+//!
+//! The state transitions are exposed as metrics and are persisted as runtime events, so they show
+//! up on the `/runtime/errors` endpoint of the monitor.
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::time::Duration;
+
+use numaflow_monitor::runtime;
+use tokio::time::Instant;
+use tonic::Status;
+use tracing::{info, warn};
+
+use crate::config::components::sink::{CircuitBreakerConfig, OnOpenStrategy};
+use crate::config::pipeline::VERTEX_TYPE_SINK;
+use crate::config::{get_vertex_name, is_mono_vertex};
+use crate::metrics::{
+    monovertex_metrics, mvtx_forward_metric_labels, pipeline_metric_labels, pipeline_metrics,
+};
+
+/// Error recorded in the dead-letter envelope of the messages routed to the fallback sink while the
+/// circuit is open.
+pub(super) const CIRCUIT_OPEN_ERROR: &str = "circuit breaker of the sink is open";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum CircuitState {
+    Closed,
+    /// the circuit stays open until the given instant.
+    Open(Instant),
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// value of the state gauge.
+    fn gauge_value(&self) -> i64 {
+        match self {
+            CircuitState::Closed => 0,
+            CircuitState::HalfOpen => 1,
+            CircuitState::Open(_) => 2,
+        }
+    }
+}
+
+impl Display for CircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open(_) => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+/// Whether the sink can be written to.
+#[derive(Debug, PartialEq)]
+pub(super) enum Permit {
+    Allowed,
+    /// the circuit is open, the duration is the time left until it is half-open.
+    Rejected(Duration),
+}
+
+/// Outcome of the writes of a batch attempt.
+struct Outcome {
+    time: Instant,
+    succeeded: usize,
+    failed: usize,
+}
+
+pub(super) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: CircuitState,
+    /// outcomes of the writes within the window, only tracked while the circuit is closed.
+    window: VecDeque<Outcome>,
+}
+
+impl CircuitBreaker {
+    pub(super) fn new(config: CircuitBreakerConfig) -> Self {
+        let circuit_breaker = Self {
+            config,
+            state: CircuitState::Closed,
+            window: VecDeque::new(),
+        };
+        circuit_breaker.set_state_metric();
+        circuit_breaker
+    }
+
+    pub(super) fn on_open(&self) -> OnOpenStrategy {
+        self.config.on_open
+    }
+
+    /// Checks whether the next batch can be written, an open circuit becomes half-open once the
+    /// open duration has passed and the batch is written as the probe.
+    pub(super) fn acquire(&mut self) -> Permit {
+        match self.state {
+            CircuitState::Closed | CircuitState::HalfOpen => Permit::Allowed,
+            CircuitState::Open(until) => {
+                let now = Instant::now();
+                if now >= until {
+                    self.transition(CircuitState::HalfOpen, "probing the sink");
+                    Permit::Allowed
+                } else {
+                    Permit::Rejected(until - now)
+                }
+            }
+        }
+    }
+
+    /// Records the outcome of a batch attempt.
+    pub(super) fn record(&mut self, succeeded: usize, failed: usize) {
+        match self.state {
+            CircuitState::HalfOpen => {
+                if failed > 0 {
+                    self.open(format!("{failed} writes of the probe batch failed"));
+                } else {
+                    self.transition(CircuitState::Closed, "probe batch succeeded");
+                }
+            }
+            CircuitState::Closed => {
+                let now = Instant::now();
+                self.window.push_back(Outcome {
+                    time: now,
+                    succeeded,
+                    failed,
+                });
+                while let Some(outcome) = self.window.front()
+                    && now.duration_since(outcome.time) > self.config.window
+                {
+                    self.window.pop_front();
+                }
+
+                let (succeeded, failed) = self
+                    .window
+                    .iter()
+                    .fold((0, 0), |(s, f), o| (s + o.succeeded, f + o.failed));
+                let total = succeeded + failed;
+                if total >= self.config.min_writes
+                    && failed as f64 / total as f64 >= self.config.failure_ratio
+                {
+                    self.open(format!(
+                        "{failed} of {total} writes failed within {:?}",
+                        self.config.window
+                    ));
+                }
+            }
+            // nothing is written while the circuit is open
+            CircuitState::Open(_) => {}
+        }
+    }
+
+    fn open(&mut self, reason: String) {
+        let until = Instant::now() + self.config.open_duration;
+        self.transition(CircuitState::Open(until), &reason);
+    }
+
+    fn transition(&mut self, to: CircuitState, reason: &str) {
+        let from = std::mem::replace(&mut self.state, to);
+        self.window.clear();
+
+        let message = format!(
+            "circuit breaker of the sink of {} is {to} (was {from}), {reason}",
+            get_vertex_name()
+        );
+        match to {
+            CircuitState::Open(_) => warn!(on_open = %self.config.on_open, "{message}"),
+            _ => info!("{message}"),
+        }
+        runtime::persist_runtime_event(Status::unavailable(format!(
+            "SINK_CIRCUIT_BREAKER(numa): {message}"
+        )));
+
+        self.set_state_metric();
+        if is_mono_vertex() {
+            let mut labels = mvtx_forward_metric_labels().clone();
+            labels.push(("state".to_string(), to.to_string()));
+            monovertex_metrics()
+                .sink
+                .circuit_breaker_transitions_total
+                .get_or_create(&labels)
+                .inc();
+        } else {
+            let mut labels = pipeline_metric_labels(VERTEX_TYPE_SINK).clone();
+            labels.push(("state".to_string(), to.to_string()));
+            pipeline_metrics()
+                .sink_forwarder
+                .circuit_breaker_transitions_total
+                .get_or_create(&labels)
+                .inc();
+        }
+    }
+
+    fn set_state_metric(&self) {
+        if is_mono_vertex() {
+            monovertex_metrics()
+                .sink
+                .circuit_breaker_state
+                .get_or_create(mvtx_forward_metric_labels())
+                .set(self.state.gauge_value());
+        } else {
+            pipeline_metrics()
+                .sink_forwarder
+                .circuit_breaker_state
+                .get_or_create(pipeline_metric_labels(VERTEX_TYPE_SINK))
+                .set(self.state.gauge_value());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_ratio: 0.5,
+            min_writes: 10,
+            window: Duration::from_millis(100),
+            open_duration: Duration::from_millis(50),
+            on_open: OnOpenStrategy::Pause,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_transitions() {
+        let mut circuit_breaker = CircuitBreaker::new(config());
+
+        // below the minimum number of writes the ratio is not evaluated
+        circuit_breaker.record(0, 5);
+        assert_eq!(circuit_breaker.acquire(), Permit::Allowed);
+
+        // 5 of 10 writes failed within the window
+        circuit_breaker.record(5, 0);
+        assert!(matches!(circuit_breaker.state, CircuitState::Open(_)));
+        assert!(matches!(circuit_breaker.acquire(), Permit::Rejected(_)));
+
+        // half-open after the open duration, a failed probe opens it again
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(circuit_breaker.acquire(), Permit::Allowed);
+        assert_eq!(circuit_breaker.state, CircuitState::HalfOpen);
+        circuit_breaker.record(9, 1);
+        assert!(matches!(circuit_breaker.acquire(), Permit::Rejected(_)));
+
+        // a successful probe closes it
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(circuit_breaker.acquire(), Permit::Allowed);
+        circuit_breaker.record(10, 0);
+        assert_eq!(circuit_breaker.state, CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_window() {
+        let mut circuit_breaker = CircuitBreaker::new(config());
+
+        // the failures out of the window are not counted
+        circuit_breaker.record(0, 8);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        circuit_breaker.record(8, 2);
+        assert_eq!(circuit_breaker.state, CircuitState::Closed);
+
+        circuit_breaker.record(0, 8);
+        assert!(matches!(circuit_breaker.state, CircuitState::Open(_)));
+    }
+}
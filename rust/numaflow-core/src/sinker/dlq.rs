@@ -0,0 +1,258 @@
+//! Dead-letter envelope attached to the messages routed to the fallback sink, so that the messages
+//! in the fallback sink (DLQ) can be triaged without the logs of the pods. The envelope records why
+//! the primary sink did not write the message, it is stored in the system metadata of the message
+//! and optionally mirrored into its headers.
+//!
+//! The built-in sinks write the envelope as the [DLQ_HEADER] header (JSON), the user-defined sinks
+//! receive it in the system metadata under the [DLQ_METADATA_KEY] key.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use crate::config::get_vertex_name;
+use crate::message::Message;
+use crate::metadata::{KeyValueGroup, Metadata};
+
+/// Key of the envelope in the system metadata.
+pub(crate) const DLQ_METADATA_KEY: &str = "numaflow_dlq";
+
+/// Header with the JSON serialized envelope.
+pub(crate) const DLQ_HEADER: &str = "x-numaflow-dlq";
+
+/// Error of the messages the primary sink asked to route to the fallback sink.
+const FALLBACK_REQUESTED: &str = "fallback requested by the sink";
+
+const ERROR_KEY: &str = "error";
+const RETRY_ATTEMPTS_KEY: &str = "retry_attempts";
+const PRIMARY_SINK_KEY: &str = "primary_sink";
+const VERTEX_KEY: &str = "vertex";
+const FIRST_FAILURE_TIME_KEY: &str = "first_failure_time";
+const LAST_FAILURE_TIME_KEY: &str = "last_failure_time";
+
+/// Failures of a message in the primary sink, tracked across the retries of its batch.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SinkFailure {
+    /// error of the last failure.
+    pub(crate) error: String,
+    /// number of times the primary sink failed to write the message.
+    pub(crate) attempts: u16,
+    pub(crate) first_failure_time: DateTime<Utc>,
+    pub(crate) last_failure_time: DateTime<Utc>,
+}
+
+impl SinkFailure {
+    pub(crate) fn new(error: String) -> Self {
+        let now = Utc::now();
+        Self {
+            error,
+            attempts: 1,
+            first_failure_time: now,
+            last_failure_time: now,
+        }
+    }
+
+    /// records another failure of the message.
+    pub(crate) fn failed_again(&mut self, error: String) {
+        self.error = error;
+        self.attempts = self.attempts.saturating_add(1);
+        self.last_failure_time = Utc::now();
+    }
+}
+
+/// Why the primary sink did not write the message.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DlqEnvelope {
+    pub(crate) error: String,
+    pub(crate) retry_attempts: u16,
+    /// type of the primary sink, e.g., kafka or udsink.
+    pub(crate) primary_sink: String,
+    pub(crate) vertex: String,
+    pub(crate) first_failure_time: DateTime<Utc>,
+    pub(crate) last_failure_time: DateTime<Utc>,
+}
+
+impl DlqEnvelope {
+    /// Creates the envelope from the failures of the message, the messages without failures were
+    /// routed to the fallback sink by the primary sink itself.
+    pub(crate) fn new(primary_sink: &str, failure: Option<&SinkFailure>) -> Self {
+        let failure = failure
+            .cloned()
+            .unwrap_or_else(|| SinkFailure::new(FALLBACK_REQUESTED.to_string()));
+        Self {
+            error: failure.error,
+            // the first attempt is not a retry
+            retry_attempts: failure.attempts.saturating_sub(1),
+            primary_sink: primary_sink.to_string(),
+            vertex: get_vertex_name().to_string(),
+            first_failure_time: failure.first_failure_time,
+            last_failure_time: failure.last_failure_time,
+        }
+    }
+
+    /// Returns the envelope stored in the system metadata of the message, if any.
+    pub(crate) fn from_message(message: &Message) -> Option<Self> {
+        let group = message
+            .metadata
+            .as_ref()?
+            .sys_metadata
+            .get(DLQ_METADATA_KEY)?;
+        let get = |key: &str| {
+            group
+                .key_value
+                .get(key)
+                .map(|value| String::from_utf8_lossy(value).to_string())
+        };
+        let time = |key: &str| {
+            get(key)
+                .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+                .map(|time| time.with_timezone(&Utc))
+        };
+
+        Some(Self {
+            error: get(ERROR_KEY)?,
+            retry_attempts: get(RETRY_ATTEMPTS_KEY)?.parse().ok()?,
+            primary_sink: get(PRIMARY_SINK_KEY)?,
+            vertex: get(VERTEX_KEY)?,
+            first_failure_time: time(FIRST_FAILURE_TIME_KEY)?,
+            last_failure_time: time(LAST_FAILURE_TIME_KEY)?,
+        })
+    }
+
+    /// Serializes the envelope as JSON.
+    pub(crate) fn to_json(&self) -> String {
+        json!({
+            ERROR_KEY: self.error,
+            RETRY_ATTEMPTS_KEY: self.retry_attempts,
+            PRIMARY_SINK_KEY: self.primary_sink,
+            VERTEX_KEY: self.vertex,
+            FIRST_FAILURE_TIME_KEY: self.first_failure_time.to_rfc3339(),
+            LAST_FAILURE_TIME_KEY: self.last_failure_time.to_rfc3339(),
+        })
+        .to_string()
+    }
+
+    fn to_key_value_group(&self) -> KeyValueGroup {
+        let key_value = [
+            (ERROR_KEY, self.error.clone()),
+            (RETRY_ATTEMPTS_KEY, self.retry_attempts.to_string()),
+            (PRIMARY_SINK_KEY, self.primary_sink.clone()),
+            (VERTEX_KEY, self.vertex.clone()),
+            (FIRST_FAILURE_TIME_KEY, self.first_failure_time.to_rfc3339()),
+            (LAST_FAILURE_TIME_KEY, self.last_failure_time.to_rfc3339()),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), Bytes::from(value)))
+        .collect();
+        KeyValueGroup { key_value }
+    }
+
+    /// Attaches the envelope to the system metadata of the message, and to its headers if
+    /// `mirror_headers` is set.
+    pub(crate) fn attach(&self, message: &mut Message, mirror_headers: bool) {
+        let mut metadata = message
+            .metadata
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(Metadata::default);
+        metadata
+            .sys_metadata
+            .insert(DLQ_METADATA_KEY.to_string(), self.to_key_value_group());
+        message.metadata = Some(Arc::new(metadata));
+
+        if mirror_headers {
+            let mut headers = (*message.headers).clone();
+            headers.insert(DLQ_HEADER.to_string(), self.to_json());
+            message.headers = Arc::new(headers);
+        }
+    }
+}
+
+/// Attaches the envelope to the messages routed to the fallback sink, using the failures tracked
+/// by the primary sink (keyed by message id).
+pub(crate) fn attach_envelopes(
+    messages: &mut [Message],
+    failures: &HashMap<String, SinkFailure>,
+    primary_sink: &str,
+    mirror_headers: bool,
+) {
+    for message in messages {
+        DlqEnvelope::new(primary_sink, failures.get(&message.id.to_string()))
+            .attach(message, mirror_headers);
+    }
+}
+
+/// Header with the envelope of the message, used by the built-in sinks to write the envelope.
+pub(crate) fn dlq_header(message: &Message) -> Option<(String, String)> {
+    DlqEnvelope::from_message(message).map(|envelope| (DLQ_HEADER.to_string(), envelope.to_json()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessageID;
+
+    fn message(index: i32) -> Message {
+        Message {
+            value: "hello".into(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: "0".to_string().into(),
+                index,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_attach_envelopes() {
+        let mut failure = SinkFailure::new("connection refused".to_string());
+        failure.failed_again("broker unavailable".to_string());
+        failure.failed_again("broker unavailable".to_string());
+
+        let (failed, routed) = (message(0), message(1));
+        let failures = HashMap::from([(failed.id.to_string(), failure.clone())]);
+        let mut messages = vec![failed, routed];
+        attach_envelopes(&mut messages, &failures, "kafka", false);
+        let [failed, routed] = messages.as_slice() else {
+            unreachable!()
+        };
+
+        let envelope = DlqEnvelope::from_message(failed).unwrap();
+        assert_eq!(envelope.error, "broker unavailable");
+        assert_eq!(envelope.retry_attempts, 2);
+        assert_eq!(envelope.primary_sink, "kafka");
+        assert_eq!(
+            envelope.first_failure_time.timestamp_micros(),
+            failure.first_failure_time.timestamp_micros()
+        );
+        assert_eq!(
+            envelope.last_failure_time.timestamp_micros(),
+            failure.last_failure_time.timestamp_micros()
+        );
+        // headers are only mirrored when asked for
+        assert!(!failed.headers.contains_key(DLQ_HEADER));
+
+        // the message without failures was routed by the sink itself
+        let envelope = DlqEnvelope::from_message(routed).unwrap();
+        assert_eq!(envelope.error, FALLBACK_REQUESTED);
+        assert_eq!(envelope.retry_attempts, 0);
+    }
+
+    #[test]
+    fn test_attach_envelope_with_headers() {
+        let mut message = message(0);
+        let envelope = DlqEnvelope::new("udsink", Some(&SinkFailure::new("timeout".to_string())));
+        envelope.attach(&mut message, true);
+
+        let header: serde_json::Value =
+            serde_json::from_str(message.headers.get(DLQ_HEADER).unwrap()).unwrap();
+        assert_eq!(header.get("error").unwrap(), "timeout");
+        assert_eq!(header.get("retry_attempts").unwrap(), 0);
+        assert_eq!(header.get("primary_sink").unwrap(), "udsink");
+        assert_eq!(dlq_header(&message).unwrap().1, envelope.to_json());
+    }
+}
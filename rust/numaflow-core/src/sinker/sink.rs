@@ -8,10 +8,14 @@ use crate::metrics::{
     pipeline_drop_metric_labels, pipeline_metric_labels, pipeline_metrics,
 };
 use crate::sinker::actor::{SinkActorMessage, SinkActorResponse};
+use crate::sinker::dlq;
+use numaflow_http::sink::HttpSink;
 use numaflow_kafka::sink::KafkaSink;
+use numaflow_nats::sink::NatsSink;
 use numaflow_pb::clients::sink::Status::{Failure, Fallback, OnSuccess, Serve, Success};
 use numaflow_pb::clients::sink::sink_client::SinkClient;
 use numaflow_pb::clients::sink::sink_response;
+use numaflow_postgres::sink::PostgresSink;
 use numaflow_pulsar::sink::Sink as PulsarSink;
 use numaflow_sqs::sink::SqsSink;
 use serving::{DEFAULT_ID_HEADER, DEFAULT_POD_HASH_KEY};
@@ -46,7 +50,27 @@ pub(super) mod log;
 /// to write to the serving store.
 pub(crate) mod serve;
 
+/// [File] sink writes the messages to rotating segment files, which can be replayed by the file
+/// source.
+///
+/// [File]: https://numaflow.numaproj.io/user-guide/sinks/file/
+pub(crate) mod file;
+
+/// [Object Store] sink buffers the messages into NDJSON or Parquet files which are uploaded to an S3
+/// compatible store.
+///
+/// [Object Store]: https://numaflow.numaproj.io/user-guide/sinks/object-store/
+pub(crate) mod object_store;
+
+/// [Broadcast] sink writes each message to multiple builtin sinks.
+///
+/// [Broadcast]: https://numaflow.numaproj.io/user-guide/sinks/broadcast/
+pub(crate) mod broadcast;
+
+mod http;
 mod kafka;
+mod nats;
+mod postgres;
 mod pulsar;
 mod sqs;
 
@@ -64,6 +88,11 @@ pub(crate) mod user_defined;
 pub(crate) trait LocalSink {
     /// Write the messages to the Sink.
     async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>>;
+
+    /// Called once no more messages will be written, e.g. to complete the files being written.
+    fn close(&mut self) -> impl Future<Output = Result<()>> {
+        async { Ok(()) }
+    }
 }
 
 pub(crate) enum SinkClientType {
@@ -74,6 +103,33 @@ pub(crate) enum SinkClientType {
     Sqs(SqsSink),
     Kafka(KafkaSink),
     Pulsar(Box<PulsarSink>),
+    Nats(Box<NatsSink>),
+    Http(Box<HttpSink>),
+    File(Box<file::FileSink>),
+    ObjectStore(Box<object_store::ObjectStoreSink>),
+    Postgres(Box<PostgresSink>),
+    Broadcast(Box<broadcast::BroadcastSink>),
+}
+
+impl SinkClientType {
+    /// Name of the sink type, recorded in the dead-letter envelope of the fallback messages.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            SinkClientType::Log => "log",
+            SinkClientType::Blackhole => "blackhole",
+            SinkClientType::Serve => "serve",
+            SinkClientType::UserDefined(_) => "udsink",
+            SinkClientType::Sqs(_) => "sqs",
+            SinkClientType::Kafka(_) => "kafka",
+            SinkClientType::Pulsar(_) => "pulsar",
+            SinkClientType::Nats(_) => "nats",
+            SinkClientType::Http(_) => "http",
+            SinkClientType::File(_) => "file",
+            SinkClientType::ObjectStore(_) => "objectstore",
+            SinkClientType::Postgres(_) => "postgres",
+            SinkClientType::Broadcast(_) => "broadcast",
+        }
+    }
 }
 
 /// SinkWriter is a writer that writes messages to the Sink.
@@ -94,6 +150,10 @@ pub(crate) struct SinkWriter {
     serving_store: Option<ServingStore>,
     health_check_clients: HealthCheckClients,
     bypass_conditions: Option<BypassConditions>,
+    /// type of the primary sink, recorded in the dead-letter envelope.
+    primary_sink: &'static str,
+    /// mirror the dead-letter envelope into the headers of the fallback messages.
+    dlq_headers: bool,
 }
 
 impl SinkWriter {
@@ -108,6 +168,8 @@ impl SinkWriter {
         serving_store: Option<ServingStore>,
         health_check_clients: HealthCheckClients,
         bypass_conditions: Option<BypassConditions>,
+        primary_sink: &'static str,
+        dlq_headers: bool,
     ) -> Self {
         Self {
             batch_size,
@@ -120,6 +182,8 @@ impl SinkWriter {
             serving_store,
             health_check_clients,
             bypass_conditions,
+            primary_sink,
+            dlq_headers,
         }
     }
 
@@ -291,7 +355,7 @@ impl SinkWriter {
         let messages_size: usize = messages.iter().map(|msg| msg.value.len()).sum();
 
         // Invoke primary sink to write messages
-        let response = self
+        let mut response = self
             .write_to_primary_sink(messages, cln_token.clone())
             .await?;
 
@@ -314,6 +378,12 @@ impl SinkWriter {
 
         // If there are fallback messages, write them to the fallback sink
         if !response.fallback.is_empty() {
+            dlq::attach_envelopes(
+                &mut response.fallback,
+                &response.failures,
+                self.primary_sink,
+                self.dlq_headers,
+            );
             self.write_to_fallback(response.fallback, cln_token.clone())
                 .await?;
         }
@@ -1018,6 +1088,75 @@ mod tests {
         assert!(tracker.is_empty().await.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_circuit_breaker_fallback_write() {
+        use crate::config::components::sink::{CircuitBreakerConfig, OnOpenStrategy, RetryConfig};
+
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let sock_file = tmp_dir.path().join("sink.sock");
+        let server_info_file = tmp_dir.path().join("sink-server-info");
+
+        let server_info = server_info_file.clone();
+        let server_socket = sock_file.clone();
+
+        let _server_handle = tokio::spawn(async move {
+            sink::Server::new(SimpleSink)
+                .with_socket_file(server_socket)
+                .with_server_info_file(server_info)
+                .start_with_shutdown(shutdown_rx)
+                .await
+                .expect("failed to start sink server");
+        });
+
+        // wait for the server to start
+        sleep(Duration::from_millis(100)).await;
+
+        // the default retry strategy retries forever, the open circuit routes the failing
+        // messages to the fallback sink instead.
+        let mut sink_writer = SinkWriterBuilder::new(
+            10,
+            Duration::from_millis(100),
+            SinkClientType::UserDefined(SinkClient::new(
+                create_rpc_channel(sock_file).await.unwrap(),
+            )),
+        )
+        .retry_config(RetryConfig {
+            sink_circuit_breaker: Some(CircuitBreakerConfig {
+                min_writes: 5,
+                on_open: OnOpenStrategy::Fallback,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .fb_sink_client(SinkClientType::Log)
+        .build()
+        .await
+        .unwrap();
+
+        let messages: Vec<Message> = (0..10)
+            .map(|i| Message {
+                keys: Arc::from(vec!["error".to_string()]),
+                value: format!("message {}", i).as_bytes().to_vec().into(),
+                offset: Offset::Int(IntOffset::new(i, 0)),
+                id: MessageID {
+                    vertex_name: "vertex".to_string().into(),
+                    offset: format!("offset_{}", i).into(),
+                    index: i as i32,
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            sink_writer.write_to_sink(messages, CancellationToken::new()),
+        )
+        .await
+        .expect("open circuit should not retry the batch");
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_on_success_write() {
         let cln_token = CancellationToken::new();
@@ -0,0 +1,482 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::future::join_all;
+use numaflow_http::sink::HttpSink;
+use numaflow_kafka::sink::KafkaSink;
+use numaflow_nats::sink::NatsSink;
+use numaflow_postgres::sink::PostgresSink;
+use numaflow_pulsar::sink::Sink as PulsarSink;
+use numaflow_sqs::sink::SqsSink;
+use tokio::time::Instant;
+use tracing::warn;
+
+use super::blackhole::BlackholeSink;
+use super::file::FileSink;
+use super::log::LogSink;
+use super::object_store::ObjectStoreSink;
+use super::{ResponseFromSink, ResponseStatusFromSink, Sink, SinkClientType};
+use crate::config::components::sink::BroadcastAck;
+use crate::config::is_mono_vertex;
+use crate::config::pipeline::VERTEX_TYPE_SINK;
+use crate::error::{Error, Result};
+use crate::message::Message;
+use crate::metrics::{
+    monovertex_metrics, mvtx_forward_metric_labels, pipeline_metric_labels, pipeline_metrics,
+};
+
+/// Builtin sink the broadcast sink writes to.
+pub(crate) enum ChildSink {
+    Log(LogSink),
+    Blackhole(BlackholeSink),
+    Sqs(SqsSink),
+    Kafka(KafkaSink),
+    Pulsar(Box<PulsarSink>),
+    Nats(Box<NatsSink>),
+    Http(Box<HttpSink>),
+    File(Box<FileSink>),
+    ObjectStore(Box<ObjectStoreSink>),
+    Postgres(Box<PostgresSink>),
+}
+
+impl TryFrom<SinkClientType> for ChildSink {
+    type Error = Error;
+
+    fn try_from(sink_client: SinkClientType) -> Result<Self> {
+        Ok(match sink_client {
+            SinkClientType::Log => ChildSink::Log(LogSink),
+            SinkClientType::Blackhole => ChildSink::Blackhole(BlackholeSink),
+            SinkClientType::Sqs(sink) => ChildSink::Sqs(sink),
+            SinkClientType::Kafka(sink) => ChildSink::Kafka(sink),
+            SinkClientType::Pulsar(sink) => ChildSink::Pulsar(sink),
+            SinkClientType::Nats(sink) => ChildSink::Nats(sink),
+            SinkClientType::Http(sink) => ChildSink::Http(sink),
+            SinkClientType::File(sink) => ChildSink::File(sink),
+            SinkClientType::ObjectStore(sink) => ChildSink::ObjectStore(sink),
+            SinkClientType::Postgres(sink) => ChildSink::Postgres(sink),
+            other => {
+                return Err(Error::Config(format!(
+                    "{} sink cannot be broadcast to",
+                    other.name()
+                )));
+            }
+        })
+    }
+}
+
+impl Sink for ChildSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        match self {
+            ChildSink::Log(sink) => sink.sink(messages).await,
+            ChildSink::Blackhole(sink) => sink.sink(messages).await,
+            ChildSink::Sqs(sink) => sink.sink(messages).await,
+            ChildSink::Kafka(sink) => sink.sink(messages).await,
+            ChildSink::Pulsar(sink) => sink.sink(messages).await,
+            ChildSink::Nats(sink) => sink.sink(messages).await,
+            ChildSink::Http(sink) => sink.sink(messages).await,
+            ChildSink::File(sink) => sink.sink(messages).await,
+            ChildSink::ObjectStore(sink) => sink.sink(messages).await,
+            ChildSink::Postgres(sink) => sink.sink(messages).await,
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        match self {
+            ChildSink::Log(sink) => sink.close().await,
+            ChildSink::Blackhole(sink) => sink.close().await,
+            ChildSink::Sqs(sink) => sink.close().await,
+            ChildSink::Kafka(sink) => sink.close().await,
+            ChildSink::Pulsar(sink) => sink.close().await,
+            ChildSink::Nats(sink) => sink.close().await,
+            ChildSink::Http(sink) => sink.close().await,
+            ChildSink::File(sink) => sink.close().await,
+            ChildSink::ObjectStore(sink) => sink.close().await,
+            ChildSink::Postgres(sink) => sink.close().await,
+        }
+    }
+}
+
+struct Child<S> {
+    name: String,
+    sink: S,
+}
+
+/// Writes each batch to all its children concurrently and decides the status of each message from
+/// the outcome of the children as per the [BroadcastAck].
+///
+/// The children which are done with a message are remembered, so that the retries of the
+/// [SinkActor](crate::sinker::actor::SinkActor) only go to the children which failed to write it.
+/// A child is done with a message once it returns any status other than failed. The statuses
+/// other than success (e.g., fallback or on-success) are the status of the message once it is
+/// written as per the [BroadcastAck], so that the message is not dropped.
+pub(crate) struct BroadcastSink<S = ChildSink> {
+    children: Vec<Child<S>>,
+    ack: BroadcastAck,
+    /// per message id, the status of each child which is done with the message.
+    written: HashMap<String, Vec<Option<ResponseStatusFromSink>>>,
+}
+
+impl<S> BroadcastSink<S> {
+    pub(crate) fn new(children: Vec<(String, S)>, ack: BroadcastAck) -> Self {
+        Self {
+            children: children
+                .into_iter()
+                .map(|(name, sink)| Child { name, sink })
+                .collect(),
+            ack,
+            written: HashMap::new(),
+        }
+    }
+
+    /// Whether the message is written, given the number of children which have written it.
+    fn is_written(&self, written: usize) -> bool {
+        match self.ack {
+            BroadcastAck::All => written == self.children.len(),
+            BroadcastAck::Quorum => written > self.children.len() / 2,
+            BroadcastAck::BestEffort => true,
+        }
+    }
+}
+
+impl<S> Sink for BroadcastSink<S>
+where
+    S: Sink + Send,
+{
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        // the messages which are not retried are done, forget their outcome
+        let ids: HashSet<String> = messages.iter().map(|msg| msg.id.to_string()).collect();
+        self.written.retain(|id, _| ids.contains(id));
+
+        let num_children = self.children.len();
+        let written = &self.written;
+        let writes = self.children.iter_mut().enumerate().map(|(index, child)| {
+            // only the messages the child has not written yet
+            let pending: Vec<Message> = messages
+                .iter()
+                .filter(|msg| !is_done(written, &msg.id.to_string(), index))
+                .cloned()
+                .collect();
+            async move {
+                if pending.is_empty() {
+                    return (index, Ok(vec![]), 0);
+                }
+                let count = pending.len();
+                let start = Instant::now();
+                let result = child.sink.sink(pending).await;
+                send_child_metrics(&child.name, &result, count, start);
+                (index, result, count)
+            }
+        });
+        let results = join_all(writes).await;
+
+        // errors of the children per message id
+        let mut errors: HashMap<String, Vec<String>> = HashMap::new();
+        for (index, result, _) in results {
+            let name = &self.children.get(index).expect("child exists").name;
+            match result {
+                Ok(responses) => {
+                    for response in responses {
+                        match response.status {
+                            ResponseStatusFromSink::Failed(err) => errors
+                                .entry(response.id)
+                                .or_default()
+                                .push(format!("{name}: {err}")),
+                            status => {
+                                if let Some(child) = self
+                                    .written
+                                    .entry(response.id)
+                                    .or_insert_with(|| {
+                                        std::iter::repeat_with(|| None).take(num_children).collect()
+                                    })
+                                    .get_mut(index)
+                                {
+                                    *child = Some(status);
+                                }
+                            }
+                        }
+                    }
+                }
+                // the whole write failed, none of the pending messages were written
+                Err(e) => {
+                    warn!(sink = %name, ?e, "Failed to write to the broadcast child sink");
+                    for msg in &messages {
+                        let id = msg.id.to_string();
+                        if !is_done(&self.written, &id, index) {
+                            errors.entry(id).or_default().push(format!("{name}: {e}"));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut responses = Vec::with_capacity(messages.len());
+        for msg in messages {
+            let id = msg.id.to_string();
+            let written = self
+                .written
+                .get(&id)
+                .map_or(0, |children| children.iter().flatten().count());
+            let status = if self.is_written(written) {
+                if let Some(errors) = errors.get(&id) {
+                    warn!(
+                        id,
+                        ?errors,
+                        "Ignoring the failures of the broadcast child sinks"
+                    );
+                }
+                broadcast_status(self.written.remove(&id).unwrap_or_default())
+            } else {
+                let errors = errors.remove(&id).unwrap_or_default();
+                ResponseStatusFromSink::Failed(format!(
+                    "written to {written} of {num_children} sinks, {}",
+                    errors.join("; ")
+                ))
+            };
+            responses.push(ResponseFromSink { id, status });
+        }
+        Ok(responses)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        for child in &mut self.children {
+            if let Err(e) = child.sink.close().await {
+                warn!(sink = %child.name, ?e, "Failed to close the broadcast child sink");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether the child is done with the message.
+fn is_done(
+    written: &HashMap<String, Vec<Option<ResponseStatusFromSink>>>,
+    id: &str,
+    index: usize,
+) -> bool {
+    written
+        .get(id)
+        .is_some_and(|children| children.get(index).is_some_and(Option::is_some))
+}
+
+/// Status of a written message from the statuses of the children, fallback takes precedence so
+/// that the message reaches the fallback sink, then the other statuses than success in the order
+/// of the children.
+fn broadcast_status(children: Vec<Option<ResponseStatusFromSink>>) -> ResponseStatusFromSink {
+    let mut statuses: Vec<ResponseStatusFromSink> = children
+        .into_iter()
+        .flatten()
+        .filter(|status| *status != ResponseStatusFromSink::Success)
+        .collect();
+    match statuses
+        .iter()
+        .position(|status| *status == ResponseStatusFromSink::Fallback)
+    {
+        Some(index) => statuses.swap_remove(index),
+        None if statuses.is_empty() => ResponseStatusFromSink::Success,
+        None => statuses.swap_remove(0),
+    }
+}
+
+/// Updates the per child metrics of the sink.
+fn send_child_metrics(
+    name: &str,
+    result: &Result<Vec<ResponseFromSink>>,
+    count: usize,
+    start: Instant,
+) {
+    let failed = match result {
+        Ok(responses) => responses
+            .iter()
+            .filter(|response| matches!(response.status, ResponseStatusFromSink::Failed(_)))
+            .count(),
+        Err(_) => count,
+    };
+    let written = count - failed;
+
+    let (metrics, mut labels) = if is_mono_vertex() {
+        (
+            &monovertex_metrics().sink.broadcast,
+            mvtx_forward_metric_labels().clone(),
+        )
+    } else {
+        (
+            &pipeline_metrics().sink_forwarder.broadcast,
+            pipeline_metric_labels(VERTEX_TYPE_SINK).clone(),
+        )
+    };
+    labels.push(("sink".to_string(), name.to_string()));
+    metrics
+        .write_total
+        .get_or_create(&labels)
+        .inc_by(written as u64);
+    if failed > 0 {
+        metrics
+            .write_errors_total
+            .get_or_create(&labels)
+            .inc_by(failed as u64);
+    }
+    metrics
+        .time
+        .get_or_create(&labels)
+        .observe(start.elapsed().as_micros() as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset};
+
+    /// Fails to write the messages with the given offsets, `fail_times` times each.
+    struct FlakySink {
+        failing: HashSet<String>,
+        fail_times: usize,
+        attempts: HashMap<String, usize>,
+        written: Vec<String>,
+        /// sends the messages to the fallback sink instead of writing them.
+        fallback: bool,
+    }
+
+    impl FlakySink {
+        fn new(failing: &[&str], fail_times: usize) -> Self {
+            Self {
+                failing: failing.iter().map(|s| s.to_string()).collect(),
+                fail_times,
+                attempts: HashMap::new(),
+                written: vec![],
+                fallback: false,
+            }
+        }
+
+        fn with_fallback(mut self) -> Self {
+            self.fallback = true;
+            self
+        }
+    }
+
+    impl Sink for FlakySink {
+        async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+            Ok(messages
+                .into_iter()
+                .map(|msg| {
+                    let id = msg.id.to_string();
+                    let offset = String::from_utf8_lossy(&msg.id.offset).to_string();
+                    let attempts = self.attempts.entry(offset.clone()).or_default();
+                    *attempts += 1;
+                    let status = if self.failing.contains(&offset) && *attempts <= self.fail_times {
+                        ResponseStatusFromSink::Failed("unavailable".to_string())
+                    } else if self.fallback {
+                        ResponseStatusFromSink::Fallback
+                    } else {
+                        self.written.push(offset);
+                        ResponseStatusFromSink::Success
+                    };
+                    ResponseFromSink { id, status }
+                })
+                .collect())
+        }
+    }
+
+    fn messages(count: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| Message {
+                keys: vec!["key".to_string()].into(),
+                value: format!("message {i}").into(),
+                offset: Offset::Int(IntOffset::new(i as i64, 0)),
+                id: MessageID {
+                    vertex_name: "vertex".to_string().into(),
+                    offset: i.to_string().into(),
+                    index: 0,
+                },
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn failed(responses: &[ResponseFromSink]) -> Vec<String> {
+        responses
+            .iter()
+            .filter(|response| matches!(response.status, ResponseStatusFromSink::Failed(_)))
+            .map(|response| response.id.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_all() {
+        let mut sink = BroadcastSink::new(
+            vec![
+                ("a".to_string(), FlakySink::new(&[], 0)),
+                ("b".to_string(), FlakySink::new(&["1"], 1)),
+            ],
+            BroadcastAck::All,
+        );
+        let batch = messages(3);
+        let responses = sink.sink(batch.clone()).await.unwrap();
+        assert_eq!(failed(&responses), vec!["vertex-1-0".to_string()]);
+        let [_, response, _] = responses.as_slice() else {
+            unreachable!()
+        };
+        assert_eq!(
+            response.status,
+            ResponseStatusFromSink::Failed("written to 1 of 2 sinks, b: unavailable".to_string())
+        );
+
+        // the retry only goes to the child which failed
+        let retry: Vec<Message> = batch.into_iter().skip(1).take(1).collect();
+        let responses = sink.sink(retry).await.unwrap();
+        assert!(failed(&responses).is_empty());
+        let [a, b] = sink.children.as_slice() else {
+            unreachable!()
+        };
+        assert_eq!(a.sink.written, vec!["0", "1", "2"]);
+        assert_eq!(b.sink.written, vec!["0", "2", "1"]);
+        assert!(sink.written.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_quorum_and_best_effort() {
+        let children = || {
+            vec![
+                ("a".to_string(), FlakySink::new(&["0"], 1)),
+                ("b".to_string(), FlakySink::new(&["0", "1"], 1)),
+                ("c".to_string(), FlakySink::new(&[], 0)),
+            ]
+        };
+
+        // message 0 is written to 2 of 3 sinks, message 1 to 2 of 3 sinks
+        let mut sink = BroadcastSink::new(children(), BroadcastAck::Quorum);
+        let responses = sink.sink(messages(2)).await.unwrap();
+        assert_eq!(failed(&responses), vec!["vertex-0-0".to_string()]);
+
+        let mut sink = BroadcastSink::new(children(), BroadcastAck::BestEffort);
+        let responses = sink.sink(messages(2)).await.unwrap();
+        assert!(failed(&responses).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_propagates_fallback() {
+        let mut sink = BroadcastSink::new(
+            vec![
+                ("a".to_string(), FlakySink::new(&["0"], 1)),
+                ("b".to_string(), FlakySink::new(&[], 0).with_fallback()),
+            ],
+            BroadcastAck::All,
+        );
+        let batch = messages(2);
+        let responses = sink.sink(batch.clone()).await.unwrap();
+        assert_eq!(failed(&responses), vec!["vertex-0-0".to_string()]);
+        assert_eq!(
+            responses.get(1).unwrap().status,
+            ResponseStatusFromSink::Fallback
+        );
+
+        // the retry only goes to the child which failed, the fallback of the other child is kept
+        let retry: Vec<Message> = batch.into_iter().take(1).collect();
+        let responses = sink.sink(retry).await.unwrap();
+        assert_eq!(
+            responses.first().unwrap().status,
+            ResponseStatusFromSink::Fallback
+        );
+        assert!(sink.written.is_empty());
+    }
+}
@@ -0,0 +1,466 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::Mutex;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, error, info, warn};
+
+use crate::config::components::sink::FileSinkConfig;
+use crate::config::get_vertex_replica;
+use crate::error::{Error, Result};
+use crate::message::Message;
+use crate::shared::file_segment::{IN_PROGRESS_SUFFIX, SegmentFormat, valid_length};
+use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
+
+/// The segment that is currently being written to.
+struct ActiveSegment {
+    /// Path of the segment while it is being written, i.e., with the [IN_PROGRESS_SUFFIX].
+    in_progress_path: PathBuf,
+    /// Path of the segment once it is rotated.
+    final_path: PathBuf,
+    writer: BufWriter<File>,
+    size: u64,
+    create_time: DateTime<Utc>,
+}
+
+/// The segments of the sink, shared with the task which rotates the segments once they are too
+/// old.
+struct Segments {
+    config: FileSinkConfig,
+    replica: u16,
+    /// Opened lazily on the first write after a rotation.
+    active: Option<ActiveSegment>,
+    /// The segment index since the last restart.
+    segment_index: usize,
+}
+
+/// File sink writes the messages to rotating segments in a local directory, see
+/// [crate::shared::file_segment]. Every batch is flushed and synced to the disk before it is
+/// acknowledged, and a rotated segment is synced along with its directory before it is considered
+/// complete. The segments are rotated by age even if no more messages are written.
+pub(crate) struct FileSink {
+    segments: Arc<Mutex<Segments>>,
+    /// stops the task rotating the old segments.
+    _rotator: DropGuard,
+}
+
+impl FileSink {
+    pub(crate) async fn new(config: FileSinkConfig) -> Result<Self> {
+        tokio::fs::create_dir_all(&config.path).await.map_err(|e| {
+            Error::Sink(format!(
+                "Creating file sink directory {}: {e}",
+                config.path.display()
+            ))
+        })?;
+        let replica = *get_vertex_replica();
+        Self::complete_leftover_segments(&config.path, config.format, replica).await?;
+        info!(path = %config.path.display(), format = ?config.format, "File sink created");
+
+        let check_interval = (config.max_segment_age / 4).max(Duration::from_millis(10));
+        let segments = Arc::new(Mutex::new(Segments {
+            config,
+            replica,
+            active: None,
+            segment_index: 0,
+        }));
+
+        let cancel = CancellationToken::new();
+        tokio::spawn({
+            let segments = Arc::clone(&segments);
+            let cancel = cancel.clone();
+            async move {
+                let mut interval = tokio::time::interval(check_interval);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            // the next write retries the rotation and fails the batch
+                            if let Err(e) = segments.lock().await.rotate_if_needed().await {
+                                error!(?e, "Failed to rotate the idle segment");
+                            }
+                        }
+                        _ = cancel.cancelled() => return,
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            segments,
+            _rotator: cancel.drop_guard(),
+        })
+    }
+
+    /// Completes the in-progress segments left behind by a previous run of this replica, e.g. if
+    /// the sink was not closed. The acknowledged messages have been synced, but a crash in the
+    /// middle of a batch can leave a partially written record behind, hence the segment is
+    /// truncated to its last complete record before it is completed. The messages of the partial
+    /// record were not acknowledged and are written again.
+    async fn complete_leftover_segments(
+        path: &Path,
+        format: SegmentFormat,
+        replica: u16,
+    ) -> Result<()> {
+        let mut entries = tokio::fs::read_dir(path)
+            .await
+            .map_err(|e| Error::Sink(format!("Reading file sink directory: {e}")))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::Sink(format!("Reading file sink directory: {e}")))?
+        {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some(final_name) = file_name.strip_suffix(IN_PROGRESS_SUFFIX) else {
+                continue;
+            };
+            // segments of the other replicas may still be written to
+            if final_name.split('_').nth(1) != Some(replica.to_string().as_str()) {
+                continue;
+            }
+            info!(
+                file_name,
+                "Completing in-progress segment of a previous run"
+            );
+            Self::truncate_partial_record(&entry.path(), format).await?;
+            tokio::fs::rename(entry.path(), path.join(final_name))
+                .await
+                .map_err(|e| Error::Sink(format!("Completing segment {file_name}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Truncates the segment to the end of its last complete record.
+    async fn truncate_partial_record(segment: &Path, format: SegmentFormat) -> Result<()> {
+        let valid_length = valid_length(format, segment).await?;
+        let file = OpenOptions::new()
+            .write(true)
+            .open(segment)
+            .await
+            .map_err(|e| Error::Sink(format!("Opening segment {}: {e}", segment.display())))?;
+        let length = file
+            .metadata()
+            .await
+            .map_err(|e| Error::Sink(format!("Opening segment {}: {e}", segment.display())))?
+            .len();
+        if valid_length == length {
+            return Ok(());
+        }
+
+        warn!(
+            path = %segment.display(),
+            length,
+            valid_length,
+            "Truncating partially written record of the segment"
+        );
+        file.set_len(valid_length)
+            .await
+            .map_err(|e| Error::Sink(format!("Truncating segment {}: {e}", segment.display())))?;
+        file.sync_all()
+            .await
+            .map_err(|e| Error::Sink(format!("Syncing segment {}: {e}", segment.display())))
+    }
+}
+
+impl Segments {
+    async fn open_segment(&mut self) -> Result<&mut ActiveSegment> {
+        if self.active.is_none() {
+            let create_time = Utc::now();
+            let final_name =
+                self.config
+                    .format
+                    .segment_name(create_time, self.replica, self.segment_index);
+            self.segment_index += 1;
+
+            let final_path = self.config.path.join(&final_name);
+            let in_progress_path = self
+                .config
+                .path
+                .join(format!("{final_name}{IN_PROGRESS_SUFFIX}"));
+            debug!(path = %in_progress_path.display(), "Opening new segment");
+            let file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(&in_progress_path)
+                .await
+                .map_err(|e| Error::Sink(format!("Opening segment {final_name}: {e}")))?;
+
+            self.active = Some(ActiveSegment {
+                in_progress_path,
+                final_path,
+                writer: BufWriter::new(file),
+                size: 0,
+                create_time,
+            });
+        }
+        Ok(self.active.as_mut().expect("segment was just opened"))
+    }
+
+    /// Rotates the active segment if it is too big or too old.
+    async fn rotate_if_needed(&mut self) -> Result<()> {
+        let Some(active) = &self.active else {
+            return Ok(());
+        };
+        let age = Utc::now()
+            .signed_duration_since(active.create_time)
+            .to_std()
+            .unwrap_or_default();
+        if active.size >= self.config.max_segment_size || age >= self.config.max_segment_age {
+            self.rotate().await?;
+        }
+        Ok(())
+    }
+
+    /// Syncs the active segment and renames it to its final name, the directory is synced too so
+    /// that the rename survives a crash.
+    async fn rotate(&mut self) -> Result<()> {
+        let Some(mut active) = self.active.take() else {
+            return Ok(());
+        };
+        active.sync().await?;
+        tokio::fs::rename(&active.in_progress_path, &active.final_path)
+            .await
+            .map_err(|e| Error::Sink(format!("Rotating segment: {e}")))?;
+        File::open(&self.config.path)
+            .await
+            .map_err(|e| Error::Sink(format!("Opening file sink directory: {e}")))?
+            .sync_all()
+            .await
+            .map_err(|e| Error::Sink(format!("Syncing file sink directory: {e}")))?;
+        info!(path = %active.final_path.display(), size = active.size, "Rotated segment");
+        Ok(())
+    }
+}
+
+impl ActiveSegment {
+    /// Flushes the buffered records and syncs them to the disk.
+    async fn sync(&mut self) -> Result<()> {
+        self.writer
+            .flush()
+            .await
+            .map_err(|e| Error::Sink(format!("Flushing segment: {e}")))?;
+        self.writer
+            .get_ref()
+            .sync_data()
+            .await
+            .map_err(|e| Error::Sink(format!("Syncing segment: {e}")))
+    }
+}
+
+impl Sink for FileSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        self.segments.lock().await.write(messages).await
+    }
+
+    /// Completes the active segment, the next run would otherwise complete it on startup.
+    async fn close(&mut self) -> Result<()> {
+        self.segments.lock().await.rotate().await
+    }
+}
+
+impl Segments {
+    /// Writes the messages to the active segment, rotating it as needed.
+    async fn write(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        let mut responses = Vec::with_capacity(messages.len());
+        for msg in messages {
+            let id = msg.id.to_string();
+            let record = match self.config.format.encode(msg) {
+                Ok(record) => record,
+                Err(e) => {
+                    responses.push(ResponseFromSink {
+                        id,
+                        status: ResponseStatusFromSink::Failed(e.to_string()),
+                    });
+                    continue;
+                }
+            };
+
+            self.rotate_if_needed().await?;
+            let active = self.open_segment().await?;
+            // a failed write leaves a partial record behind, hence it is treated as a critical
+            // error rather than retried.
+            active
+                .writer
+                .write_all(&record)
+                .await
+                .map_err(|e| Error::Sink(format!("Writing to segment: {e}")))?;
+            active.size += record.len() as u64;
+            responses.push(ResponseFromSink {
+                id,
+                status: ResponseStatusFromSink::Success,
+            });
+        }
+
+        if let Some(active) = self.active.as_mut() {
+            active.sync().await?;
+        }
+        // rotate right away instead of waiting for the next batch, so that the segment can be
+        // read as soon as possible.
+        self.rotate_if_needed().await?;
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset};
+    use crate::shared::file_segment::SegmentReader;
+    use bytes::Bytes;
+
+    fn test_message(i: i64) -> Message {
+        Message {
+            keys: Arc::from(vec![format!("key-{i}")]),
+            value: Bytes::from(format!("payload-{i}")),
+            offset: Offset::Int(IntOffset::new(i, 0)),
+            event_time: Utc::now(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: i.to_string().into(),
+                index: 0,
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn list_dir(path: &Path) -> Vec<String> {
+        let mut names = vec![];
+        let mut entries = tokio::fs::read_dir(path).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = FileSinkConfig {
+            path: dir.path().to_path_buf(),
+            format: SegmentFormat::Proto,
+            // rotate after every two messages
+            max_segment_size: 2 * SegmentFormat::Proto.encode(test_message(0)).unwrap().len()
+                as u64,
+            max_segment_age: Duration::from_secs(60),
+        };
+        let mut sink = FileSink::new(config).await.unwrap();
+
+        let responses = sink.sink((0..5).map(test_message).collect()).await.unwrap();
+        assert_eq!(responses.len(), 5);
+        assert!(
+            responses
+                .iter()
+                .all(|resp| resp.status == ResponseStatusFromSink::Success)
+        );
+
+        // two completed segments and the last message in the in-progress one
+        let names = list_dir(dir.path()).await;
+        assert_eq!(names.len(), 3);
+        assert!(names.get(2).unwrap().ends_with(IN_PROGRESS_SUFFIX));
+
+        // closing the sink completes the in-progress segment
+        sink.close().await.unwrap();
+        let names = list_dir(dir.path()).await;
+        assert_eq!(names.len(), 3);
+        assert!(
+            names
+                .iter()
+                .all(|name| SegmentFormat::Proto.is_segment(name))
+        );
+
+        let mut values = vec![];
+        for name in names {
+            let mut reader = SegmentReader::open(SegmentFormat::Proto, &dir.path().join(name))
+                .await
+                .unwrap();
+            while let Some(record) = reader.next().await.unwrap() {
+                values.push(record.value);
+            }
+        }
+        let expected: Vec<Bytes> = (0..5).map(|i| test_message(i).value).collect();
+        assert_eq!(values, expected);
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_rotates_idle_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut sink = FileSink::new(FileSinkConfig {
+            path: dir.path().to_path_buf(),
+            format: SegmentFormat::Ndjson,
+            max_segment_age: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        sink.sink(vec![test_message(1)]).await.unwrap();
+        let names = list_dir(dir.path()).await;
+        assert_eq!(names.len(), 1);
+        assert!(names.first().unwrap().ends_with(IN_PROGRESS_SUFFIX));
+
+        // no more messages are written, the segment is still completed once it is too old
+        tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                let names = list_dir(dir.path()).await;
+                if names.len() == 1 && SegmentFormat::Ndjson.is_segment(names.first().unwrap()) {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("idle segment was not rotated");
+
+        // the next message goes to a new segment
+        sink.sink(vec![test_message(2)]).await.unwrap();
+        assert_eq!(list_dir(dir.path()).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_completes_leftover_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let format = SegmentFormat::Ndjson;
+        let own = format.segment_name(Utc::now(), *get_vertex_replica(), 0);
+        let other = format.segment_name(Utc::now(), *get_vertex_replica() + 1, 0);
+        // a complete record followed by a partially written one
+        let record = format.encode(test_message(1)).unwrap();
+        let mut data = record.to_vec();
+        data.extend_from_slice(&format.encode(test_message(2)).unwrap()[..10]);
+        for name in [&own, &other] {
+            tokio::fs::write(
+                dir.path().join(format!("{name}{IN_PROGRESS_SUFFIX}")),
+                &data,
+            )
+            .await
+            .unwrap();
+        }
+
+        let _sink = FileSink::new(FileSinkConfig {
+            path: dir.path().to_path_buf(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let names = list_dir(dir.path()).await;
+        assert!(names.contains(&own));
+        assert!(names.contains(&format!("{other}{IN_PROGRESS_SUFFIX}")));
+
+        // the partial record is truncated, the segment of the other replica is left as is
+        let completed = tokio::fs::read(dir.path().join(&own)).await.unwrap();
+        assert_eq!(completed, record.to_vec());
+        let mut reader = SegmentReader::open(format, &dir.path().join(&own))
+            .await
+            .unwrap();
+        assert!(reader.next().await.unwrap().is_some());
+        assert!(reader.next().await.unwrap().is_none());
+        let other = tokio::fs::read(dir.path().join(format!("{other}{IN_PROGRESS_SUFFIX}")))
+            .await
+            .unwrap();
+        assert_eq!(other, data);
+    }
+}
@@ -0,0 +1,121 @@
+use numaflow_http::sink::{HttpSink, HttpSinkMessage, HttpSinkResponse};
+use tracing::warn;
+
+use crate::error::{Error, Result};
+use crate::message::Message;
+use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
+
+impl From<Message> for HttpSinkMessage {
+    fn from(msg: Message) -> Self {
+        Self {
+            id: msg.id.to_string(),
+            keys: msg.keys.to_vec(),
+            headers: msg.headers.as_ref().clone(),
+            event_time: msg.event_time,
+            payload: msg.value,
+        }
+    }
+}
+
+impl From<HttpSinkResponse> for ResponseFromSink {
+    fn from(resp: HttpSinkResponse) -> Self {
+        let status = match resp.status {
+            Ok(_) => ResponseStatusFromSink::Success,
+            Err(e) if e.is_retryable() => ResponseStatusFromSink::Failed(e.to_string()),
+            // the endpoint rejected the message. It is still a failed write rather than a
+            // fallback, since this sink may have no fallback sink or may itself be the fallback,
+            // the retry strategy's onFailure decides where it ends up.
+            Err(e) => {
+                warn!(id = resp.id, ?e, "Message rejected by the HTTP endpoint");
+                ResponseStatusFromSink::Failed(e.to_string())
+            }
+        };
+        ResponseFromSink {
+            id: resp.id,
+            status,
+        }
+    }
+}
+
+impl Sink for HttpSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        let http_messages: Vec<HttpSinkMessage> = messages.into_iter().map(Into::into).collect();
+        Ok(self
+            .sink_messages(http_messages)
+            .await
+            .map_err(|e| Error::Sink(e.to_string()))?
+            .into_iter()
+            .map(|resp| resp.into())
+            .collect::<Vec<ResponseFromSink>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset};
+    use axum::Router;
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use bytes::Bytes;
+    use chrono::Utc;
+    use numaflow_http::sink::HttpSinkConfig;
+    use std::sync::Arc;
+
+    fn test_message(i: i64, key: &str) -> Message {
+        Message {
+            keys: Arc::from(vec![key.to_string()]),
+            value: Bytes::from(format!("payload-{i}")),
+            offset: Offset::Int(IntOffset::new(i, 0)),
+            event_time: Utc::now(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: i.to_string().into(),
+                index: 0,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_sink_response_status() {
+        // responds based on the keys of the message
+        let app = Router::new().route(
+            "/",
+            post(|headers: axum::http::HeaderMap| async move {
+                match headers.get("x-numaflow-keys").and_then(|v| v.to_str().ok()) {
+                    Some("invalid") => StatusCode::BAD_REQUEST,
+                    Some("unavailable") => StatusCode::SERVICE_UNAVAILABLE,
+                    _ => StatusCode::OK,
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut sink = HttpSink::new(HttpSinkConfig {
+            url: format!("http://{addr}/"),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let messages = vec![
+            test_message(1, "ok"),
+            test_message(2, "invalid"),
+            test_message(3, "unavailable"),
+        ];
+        let ids: Vec<String> = messages.iter().map(|msg| msg.id.to_string()).collect();
+        let responses = sink.sink(messages).await.unwrap();
+        assert_eq!(responses.len(), 3);
+
+        let mut statuses: std::collections::HashMap<String, ResponseStatusFromSink> = responses
+            .into_iter()
+            .map(|resp| (resp.id, resp.status))
+            .collect();
+        let mut status_of = |i: usize| statuses.remove(ids.get(i).unwrap()).unwrap();
+        assert_eq!(status_of(0), ResponseStatusFromSink::Success);
+        assert!(matches!(status_of(1), ResponseStatusFromSink::Failed(e) if e.contains("400")));
+        assert!(matches!(status_of(2), ResponseStatusFromSink::Failed(_)));
+    }
+}
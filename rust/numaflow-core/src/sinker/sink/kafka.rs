@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 use crate::error::{Error, Result};
 use crate::message::Message;
+use crate::sinker::dlq;
 use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
 
 impl TryFrom<Message> for KafkaSinkMessage {
@@ -14,6 +15,8 @@ impl TryFrom<Message> for KafkaSinkMessage {
             .iter()
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect();
+        // the dead-letter envelope of the messages routed to the fallback sink
+        headers.extend(dlq::dlq_header(&msg));
 
         let id = msg.id.to_string();
         // Add all message keys to the Kafka headers
@@ -90,6 +93,7 @@ mod tests {
             tls: None,
             set_partition_key: false,
             kafka_raw_config: HashMap::new(),
+            transactional_id: None,
         };
         let mut sink = new_sink(config).expect("Failed to create KafkaSink");
         let mut messages = Vec::new();
@@ -1,3 +1,4 @@
+use crate::sinker::dlq;
 use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
 use crate::{error, message::Message};
 
@@ -11,6 +12,12 @@ impl Sink for LogSink {
             msg.headers.iter().for_each(|(k, v)| {
                 headers.push_str(&format!("{k}: {v}, "));
             });
+            // the dead-letter envelope, unless it is already mirrored into the headers
+            if let Some((k, v)) = dlq::dlq_header(&msg)
+                && !msg.headers.contains_key(&k)
+            {
+                headers.push_str(&format!("{k}: {v}, "));
+            }
 
             let log_line = format!(
                 "Payload - {} Keys - {} EventTime - {} Headers - {} ID - {}",
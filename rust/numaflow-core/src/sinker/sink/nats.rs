@@ -0,0 +1,136 @@
+use numaflow_nats::sink::{NatsSink, NatsSinkMessage, NatsSinkResponse};
+
+use crate::error::{Error, Result};
+use crate::message::Message;
+use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
+
+impl From<Message> for NatsSinkMessage {
+    fn from(msg: Message) -> Self {
+        Self {
+            // MessageID is unique per message and stable across redeliveries, which makes it a
+            // good candidate for the `Nats-Msg-Id` used by JetStream for de-duplication.
+            id: msg.id.to_string(),
+            keys: msg.keys.to_vec(),
+            headers: msg.headers.as_ref().clone(),
+            payload: msg.value,
+        }
+    }
+}
+
+impl From<NatsSinkResponse> for ResponseFromSink {
+    fn from(resp: NatsSinkResponse) -> Self {
+        match resp.status {
+            Ok(_) => ResponseFromSink {
+                id: resp.id,
+                status: ResponseStatusFromSink::Success,
+            },
+            Err(e) => ResponseFromSink {
+                id: resp.id,
+                status: ResponseStatusFromSink::Failed(e.to_string()),
+            },
+        }
+    }
+}
+
+impl Sink for NatsSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        let nats_messages: Vec<NatsSinkMessage> = messages.into_iter().map(Into::into).collect();
+        Ok(self
+            .sink_messages(nats_messages)
+            .await
+            .map_err(|e| Error::Sink(e.to_string()))?
+            .into_iter()
+            .map(|resp| resp.into())
+            .collect::<Vec<ResponseFromSink>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset};
+    use bytes::Bytes;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn test_message(i: i64) -> Message {
+        Message {
+            keys: Arc::from(vec![format!("key{i}")]),
+            value: Bytes::from(format!("payload-{i}")),
+            offset: Offset::Int(IntOffset::new(i, 0)),
+            event_time: Utc::now(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: i.to_string().into(),
+                index: 0,
+            },
+            headers: Arc::new(HashMap::from([(
+                "region".to_string(),
+                "us-west".to_string(),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_message_to_nats_sink_message() {
+        let msg = test_message(1);
+        let id = msg.id.to_string();
+        let nats_msg: NatsSinkMessage = msg.into();
+        assert_eq!(nats_msg.id, id);
+        assert_eq!(nats_msg.keys, vec!["key1".to_string()]);
+        assert_eq!(nats_msg.headers.get("region"), Some(&"us-west".to_string()));
+        assert_eq!(nats_msg.payload, Bytes::from("payload-1"));
+    }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_jetstream_sink() {
+        use async_nats::jetstream::stream;
+        use numaflow_nats::sink::NatsSinkConfig;
+        use std::time::Duration;
+
+        let stream_name = "test_core_jetstream_sink";
+        let client = async_nats::connect("localhost").await.unwrap();
+        let js = async_nats::jetstream::new(client);
+        let _ = js.delete_stream(stream_name).await;
+        let mut stream = js
+            .create_stream(stream::Config {
+                name: stream_name.to_string(),
+                subjects: vec![format!("{stream_name}.>")],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut sink = NatsSink::connect(NatsSinkConfig {
+            addr: "localhost".to_string(),
+            subject: format!("{stream_name}.{{{{header.region}}}}.{{{{key.0}}}}"),
+            auth: None,
+            tls: None,
+            jetstream: true,
+            ack_timeout: Duration::from_secs(5),
+        })
+        .await
+        .unwrap();
+
+        let messages: Vec<Message> = (0..5).map(test_message).collect();
+        let responses = sink.sink(messages.clone()).await.unwrap();
+        assert_eq!(responses.len(), 5);
+        for resp in responses {
+            assert_eq!(resp.status, ResponseStatusFromSink::Success);
+        }
+
+        // redelivery of the same messages is de-duplicated using the message id
+        let responses = sink.sink(messages).await.unwrap();
+        assert!(
+            responses
+                .iter()
+                .all(|resp| resp.status == ResponseStatusFromSink::Success)
+        );
+        assert_eq!(stream.info().await.unwrap().state.messages, 5);
+
+        js.delete_stream(stream_name).await.unwrap();
+    }
+}
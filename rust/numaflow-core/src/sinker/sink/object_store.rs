@@ -0,0 +1,373 @@
+//! Object store sink, the messages are buffered per partition into files which are uploaded once
+//! they are full or old enough.
+//!
+//! A message is reported as written ([ResponseStatusFromSink::Success]) as soon as it is buffered,
+//! i.e., before it is uploaded. Its ack handle is kept with the file though, so that the message is
+//! only acked once the file is durable in the store. If the upload fails, or the sink is dropped
+//! before the file could be uploaded, the messages are nak'ed and redelivered. The pending files
+//! are uploaded when the sink is closed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use backoff::retry::Retry;
+use backoff::strategy::fixed;
+use chrono::Utc;
+use numaflow_objectstore::ObjectStoreSinkConfig;
+use numaflow_objectstore::format::{Row, Schema};
+use numaflow_objectstore::store::ObjectStore;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{error, info, warn};
+
+use crate::config::{get_vertex_name, get_vertex_replica};
+use crate::error::{Error, Result};
+use crate::message::{AckHandle, Message};
+use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
+
+const UPLOAD_RETRY_INTERVAL_MS: u64 = 1000;
+const UPLOAD_RETRY_ATTEMPTS: usize = 5;
+
+/// A file which is being buffered.
+struct PendingFile {
+    rows: Vec<Row>,
+    size: usize,
+    /// ack handles of the messages in the file, they are released once the file is uploaded.
+    acks: Vec<Arc<AckHandle>>,
+    created: Instant,
+}
+
+impl PendingFile {
+    fn new() -> Self {
+        Self {
+            rows: vec![],
+            size: 0,
+            acks: vec![],
+            created: Instant::now(),
+        }
+    }
+}
+
+impl Drop for PendingFile {
+    fn drop(&mut self) {
+        // the ack handles are taken once the file is uploaded, the remaining messages are nak'ed.
+        for ack in &self.acks {
+            ack.is_failed.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// The files being buffered, shared with the task which uploads the files once they are too old.
+struct Files {
+    config: ObjectStoreSinkConfig,
+    schema: Schema,
+    store: ObjectStore,
+    /// pending files by partition.
+    files: HashMap<String, PendingFile>,
+    /// keeps the object keys written by the replica unique.
+    sequence: u64,
+}
+
+impl Files {
+    /// Adds the message to the file of its partition, and uploads the file if it is full.
+    async fn add(&mut self, message: &Message, row: Row) {
+        let partition = self.config.partition(message.event_time, &message.keys);
+        let file = self
+            .files
+            .entry(partition.clone())
+            .or_insert_with(PendingFile::new);
+        file.size += row.size;
+        file.rows.push(row);
+        file.acks.extend(message.ack_handle.clone());
+
+        if file.rows.len() >= self.config.max_file_messages
+            || file.size >= self.config.max_file_size
+        {
+            self.upload(partition).await;
+        }
+    }
+
+    /// Uploads the files which are older than the maximum age.
+    async fn upload_expired(&mut self) {
+        let expired: Vec<String> = self
+            .files
+            .iter()
+            .filter(|(_, file)| file.created.elapsed() >= self.config.max_file_age)
+            .map(|(partition, _)| partition.clone())
+            .collect();
+        for partition in expired {
+            self.upload(partition).await;
+        }
+    }
+
+    async fn upload_all(&mut self) {
+        let partitions: Vec<String> = self.files.keys().cloned().collect();
+        for partition in partitions {
+            self.upload(partition).await;
+        }
+    }
+
+    async fn upload(&mut self, partition: String) {
+        let Some(mut file) = self.files.remove(&partition) else {
+            return;
+        };
+        self.sequence += 1;
+        let key = format!(
+            "{partition}/{}-{}-{}-{}.{}",
+            get_vertex_name(),
+            get_vertex_replica(),
+            Utc::now().timestamp_millis(),
+            self.sequence,
+            self.config.format.extension()
+        );
+
+        let body = match self.schema.encode(self.config.format, &file.rows) {
+            Ok(body) => body,
+            Err(e) => {
+                error!(?e, %key, "Failed to encode the file, nak'ing its messages");
+                return;
+            }
+        };
+        let interval =
+            fixed::Interval::from_millis(UPLOAD_RETRY_INTERVAL_MS).take(UPLOAD_RETRY_ATTEMPTS);
+        let store = &self.store;
+        let result = Retry::new(
+            interval,
+            async || store.put(&key, body.clone()).await,
+            |e: &numaflow_objectstore::Error| {
+                warn!(?e, %key, "Failed to upload the file, retrying...");
+                true
+            },
+        )
+        .await;
+
+        match result {
+            Ok(()) => {
+                info!(
+                    location = self.store.location(&key),
+                    messages = file.rows.len(),
+                    "Uploaded the file"
+                );
+                // the messages are acked once their last reference is dropped.
+                file.acks.clear();
+            }
+            Err(e) => {
+                error!(?e, %key, "Failed to upload the file, nak'ing its messages");
+            }
+        }
+    }
+}
+
+/// Sink writing the messages to an object store, see the [module](self) docs.
+pub(crate) struct ObjectStoreSink {
+    files: Arc<Mutex<Files>>,
+    /// stops the task uploading the old files, which then uploads the remaining files.
+    _uploader: DropGuard,
+}
+
+impl ObjectStoreSink {
+    pub(crate) async fn new(config: ObjectStoreSinkConfig) -> Result<Self> {
+        config
+            .validate()
+            .map_err(|e| Error::Config(e.to_string()))?;
+        let schema =
+            Schema::new(config.columns.clone()).map_err(|e| Error::Config(e.to_string()))?;
+        let store = ObjectStore::connect(config.store.clone())
+            .await
+            .map_err(|e| Error::Sink(e.to_string()))?;
+
+        let check_interval = (config.max_file_age / 4).max(Duration::from_millis(10));
+        let files = Arc::new(Mutex::new(Files {
+            config,
+            schema,
+            store,
+            files: HashMap::new(),
+            sequence: 0,
+        }));
+
+        let cancel = CancellationToken::new();
+        tokio::spawn({
+            let files = Arc::clone(&files);
+            let cancel = cancel.clone();
+            async move {
+                let mut interval = tokio::time::interval(check_interval);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => files.lock().await.upload_expired().await,
+                        _ = cancel.cancelled() => {
+                            files.lock().await.upload_all().await;
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            files,
+            _uploader: cancel.drop_guard(),
+        })
+    }
+}
+
+impl Sink for ObjectStoreSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        let mut files = self.files.lock().await;
+        let mut responses = Vec::with_capacity(messages.len());
+        for message in messages {
+            let id = message.id.to_string();
+            let status = match files.schema.row(&message.value) {
+                Ok(row) => {
+                    files.add(&message, row).await;
+                    ResponseStatusFromSink::Success
+                }
+                // the payload doesn't match the columns. It is still a failed write, since this
+                // sink may have no fallback sink or may itself be the fallback, the retry
+                // strategy's onFailure decides where it ends up.
+                Err(e) => {
+                    warn!(id, ?e, "Message rejected by the object store sink");
+                    ResponseStatusFromSink::Failed(e.to_string())
+                }
+            };
+            responses.push(ResponseFromSink { id, status });
+        }
+        Ok(responses)
+    }
+
+    /// Uploads the pending files and waits for the uploads, so that their messages are acked
+    /// before the sink stops instead of being redelivered.
+    async fn close(&mut self) -> Result<()> {
+        self.files.lock().await.upload_all().await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bytes::Bytes;
+    use numaflow_objectstore::store::StoreConfig;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::message::{IntOffset, MessageID, Offset, ReadAck};
+
+    fn message(i: i64, value: &str) -> (Message, oneshot::Receiver<ReadAck>) {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let message = Message {
+            keys: Arc::from(vec!["key".to_string()]),
+            value: Bytes::from(value.to_string()),
+            offset: Offset::Int(IntOffset::new(i, 0)),
+            event_time: Utc::now(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: i.to_string().into(),
+                index: 0,
+            },
+            ack_handle: Some(Arc::new(AckHandle::new(ack_tx))),
+            ..Default::default()
+        };
+        (message, ack_rx)
+    }
+
+    /// Contents of the files in the directory, in the order they were written.
+    fn files(dir: &Path) -> Vec<String> {
+        let mut files = vec![];
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else {
+                    files.push(path);
+                }
+            }
+        }
+        files.sort_by_key(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            let sequence = name.rsplit('-').next().unwrap().to_string();
+            sequence.trim_end_matches(".ndjson").parse::<u64>().unwrap()
+        });
+        files
+            .into_iter()
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_object_store_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut sink = ObjectStoreSink::new(ObjectStoreSinkConfig {
+            store: StoreConfig::Local {
+                path: dir.path().to_path_buf(),
+            },
+            max_file_messages: 2,
+            max_file_age: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let (first, first_ack) = message(1, r#"{"n":1}"#);
+        let (second, second_ack) = message(2, r#"{"n":2}"#);
+        let (third, mut third_ack) = message(3, r#"{"n":3}"#);
+        let (invalid, _invalid_ack) = message(4, "not json");
+        let responses = sink
+            .sink(vec![first, second, third, invalid])
+            .await
+            .unwrap();
+        let statuses: Vec<ResponseStatusFromSink> =
+            responses.into_iter().map(|resp| resp.status).collect();
+        assert_eq!(
+            statuses[..3],
+            vec![
+                ResponseStatusFromSink::Success,
+                ResponseStatusFromSink::Success,
+                ResponseStatusFromSink::Success,
+            ]
+        );
+        assert!(matches!(statuses[3], ResponseStatusFromSink::Failed(_)));
+
+        // the first file is full and uploaded, the third message waits for the next one
+        assert_eq!(first_ack.await.unwrap(), ReadAck::Ack);
+        assert_eq!(second_ack.await.unwrap(), ReadAck::Ack);
+        assert!(third_ack.try_recv().is_err());
+        assert_eq!(files(dir.path()), vec!["{\"n\":1}\n{\"n\":2}\n"]);
+
+        // the file is uploaded once it is too old
+        assert_eq!(third_ack.await.unwrap(), ReadAck::Ack);
+        assert_eq!(
+            files(dir.path()),
+            vec!["{\"n\":1}\n{\"n\":2}\n", "{\"n\":3}\n"]
+        );
+
+        // the pending files are uploaded by the time close returns
+        let (fourth, mut fourth_ack) = message(5, r#"{"n":4}"#);
+        sink.sink(vec![fourth]).await.unwrap();
+        sink.close().await.unwrap();
+        assert_eq!(fourth_ack.try_recv().unwrap(), ReadAck::Ack);
+        assert_eq!(files(dir.path()).len(), 3);
+
+        // and when the sink is dropped without being closed
+        let (fifth, fifth_ack) = message(6, r#"{"n":5}"#);
+        sink.sink(vec![fifth]).await.unwrap();
+        drop(sink);
+        assert_eq!(fifth_ack.await.unwrap(), ReadAck::Ack);
+        assert_eq!(files(dir.path()).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_pending_file_nak() {
+        let (message, ack) = message(1, "{}");
+        let mut file = PendingFile::new();
+        file.acks.extend(message.ack_handle.clone());
+        drop(message);
+        drop(file);
+        assert_eq!(ack.await.unwrap(), ReadAck::Nak);
+    }
+}
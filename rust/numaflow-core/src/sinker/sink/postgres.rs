@@ -0,0 +1,86 @@
+use numaflow_postgres::sink::{PostgresSink, PostgresSinkMessage, PostgresSinkResponse};
+use tracing::warn;
+
+use crate::error::Result;
+use crate::message::Message;
+use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
+
+impl From<Message> for PostgresSinkMessage {
+    fn from(msg: Message) -> Self {
+        Self {
+            id: msg.id.to_string(),
+            keys: msg.keys.to_vec(),
+            payload: msg.value,
+        }
+    }
+}
+
+impl From<PostgresSinkResponse> for ResponseFromSink {
+    fn from(resp: PostgresSinkResponse) -> Self {
+        let status = match resp.status {
+            Ok(()) => ResponseStatusFromSink::Success,
+            // connection and server errors, the sink reconnects on the next write.
+            Err(e) if e.is_retryable() => ResponseStatusFromSink::Failed(e.to_string()),
+            // the row violates a constraint or doesn't fit the table. It is still a failed write,
+            // since this sink may have no fallback sink or may itself be the fallback, the retry
+            // strategy's onFailure decides where it ends up.
+            Err(e) => {
+                warn!(id = resp.id, ?e, "Message rejected by PostgreSQL");
+                ResponseStatusFromSink::Failed(e.to_string())
+            }
+        };
+        ResponseFromSink {
+            id: resp.id,
+            status,
+        }
+    }
+}
+
+impl Sink for PostgresSink {
+    async fn sink(&mut self, messages: Vec<Message>) -> Result<Vec<ResponseFromSink>> {
+        let messages: Vec<PostgresSinkMessage> = messages.into_iter().map(Into::into).collect();
+        Ok(self
+            .sink_messages(messages)
+            .await
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numaflow_postgres::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_postgres_sink_response_status() {
+        let status = |status| {
+            ResponseFromSink::from(PostgresSinkResponse {
+                id: "id".to_string(),
+                status,
+            })
+            .status
+        };
+
+        assert_eq!(status(Ok(())), ResponseStatusFromSink::Success);
+        assert!(matches!(
+            status(Err(Error::Database("connection closed".to_string()))),
+            ResponseStatusFromSink::Failed(_)
+        ));
+        assert!(matches!(
+            status(Err(Error::Rejected {
+                code: "23505".to_string(),
+                message: "duplicate key value violates unique constraint".to_string(),
+            })),
+            ResponseStatusFromSink::Failed(e) if e.contains("23505")
+        ));
+        assert!(matches!(
+            status(Err(Error::InvalidPayload(
+                "payload is not JSON".to_string()
+            ))),
+            ResponseStatusFromSink::Failed(_)
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 
 use crate::error::{Error, Result};
 use crate::message::Message;
+use crate::sinker::dlq;
 use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
 
 impl TryFrom<Message> for PulsarMessage {
@@ -16,6 +17,8 @@ impl TryFrom<Message> for PulsarMessage {
             .iter()
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect();
+        // the dead-letter envelope of the messages routed to the fallback sink
+        headers.extend(dlq::dlq_header(&msg));
 
         let id = msg.id.to_string();
         // Add all message keys to the Pulsar properties
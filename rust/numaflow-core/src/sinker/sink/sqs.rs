@@ -6,6 +6,7 @@ use numaflow_sqs::{
 use crate::error;
 use crate::error::Error;
 use crate::message::Message;
+use crate::sinker::dlq;
 use crate::sinker::sink::{ResponseFromSink, ResponseStatusFromSink, Sink};
 
 impl TryFrom<Message> for SqsSinkMessage {
@@ -14,6 +15,8 @@ impl TryFrom<Message> for SqsSinkMessage {
     fn try_from(msg: Message) -> crate::Result<Self> {
         let id = msg.id.to_string();
         let mut headers = (*msg.headers).clone();
+        // the dead-letter envelope of the messages routed to the fallback sink
+        headers.extend(dlq::dlq_header(&msg));
 
         if let Some(metadata) = &msg.metadata
             && let Some(sqs_meta) = metadata.user_metadata.get(SQS_METADATA_KEY)
@@ -4,6 +4,7 @@
 //! [Source]: https://numaflow.numaproj.io/user-guide/sources/overview/
 //! [Watermark]: https://numaflow.numaproj.io/core-concepts/watermarks/
 
+use crate::config::components::ratelimit::{KeySelector, RateLimitConfig};
 use crate::config::pipeline::VERTEX_TYPE_SOURCE;
 use crate::config::{get_vertex_name, is_mono_vertex};
 use crate::error::{Error, Result};
@@ -14,6 +15,7 @@ use crate::metrics::{
 };
 use crate::monovertex::bypass_router::MvtxBypassRouter;
 use crate::source::http::CoreHttpSource;
+use crate::source::throttle::KeyedThrottle;
 use crate::tracker::Tracker;
 use crate::{
     message::{Message, Offset},
@@ -27,7 +29,7 @@ use numaflow_nats::nats::NatsSource;
 use numaflow_pb::clients::source::source_client::SourceClient;
 use numaflow_pulsar::source::PulsarSource;
 use numaflow_sqs::source::SqsSource;
-use numaflow_throttling::RateLimiter;
+use numaflow_throttling::{IntoKeyed, KeyedRateLimiter, RateLimiter};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::sync::OwnedSemaphorePermit;
@@ -63,9 +65,17 @@ pub(crate) mod sqs;
 
 pub(crate) mod http;
 pub(crate) mod kafka;
+
+/// [File] is a builtin to replay the segments written by the file sink.
+///
+/// [File]: https://numaflow.numaproj.io/user-guide/sources/file/
+pub(crate) mod file;
 #[cfg(test)]
 pub(crate) mod test_utils;
 
+/// Per-key throttling of the messages read from the source.
+pub(crate) mod throttle;
+
 use crate::transformer::Transformer;
 use crate::watermark::source::SourceWatermarkHandle;
 
@@ -119,6 +129,7 @@ pub(crate) enum SourceType {
     Kafka(KafkaSource),
     Http(CoreHttpSource),
     Nats(NatsSource),
+    File(file::FileSource),
 }
 
 enum ActorMessage {
@@ -228,6 +239,9 @@ pub(crate) struct Source<C: crate::typ::NumaflowTypeConfig> {
     watermark_handle: Option<SourceWatermarkHandle>,
     health_checker: Option<SourceClient<Channel>>,
     rate_limiter: Option<C::RateLimiter>,
+    /// Per-key rate limiter, the read messages are throttled by their key instead of throttling
+    /// the read.
+    keyed_rate_limiter: Option<(KeySelector, <C::RateLimiter as IntoKeyed>::Keyed)>,
 }
 
 impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
@@ -293,6 +307,12 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
                 });
             }
             SourceType::Kafka(kafka) => {
+                if let Some(watermark_handle) = watermark_handle.clone() {
+                    tokio::spawn(kafka::track_partition_assignment(
+                        kafka.assigned_partitions(),
+                        watermark_handle,
+                    ));
+                }
                 tokio::spawn(async move {
                     let actor = SourceActor::new(receiver, kafka.clone(), kafka.clone(), kafka);
                     actor.run().await;
@@ -309,6 +329,17 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
                     actor.run().await;
                 });
             }
+            SourceType::File(file_source) => {
+                tokio::spawn(async move {
+                    let actor = SourceActor::new(
+                        receiver,
+                        file_source.clone(),
+                        file_source.clone(),
+                        file_source,
+                    );
+                    actor.run().await;
+                });
+            }
         };
 
         // initialize the active partitions in the watermark actor to indicate the partitions to which
@@ -329,9 +360,30 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
             watermark_handle,
             health_checker,
             rate_limiter,
+            keyed_rate_limiter: None,
         }
     }
 
+    /// Throttles the read messages by their key, if per-key rate limiting is configured. The
+    /// token buckets of the keys share the state of the rate limiter of the source.
+    pub(crate) fn with_keyed_rate_limiter(
+        mut self,
+        rate_limit_config: Option<&RateLimitConfig>,
+    ) -> Self {
+        let Some(rate_limit_config) = rate_limit_config else {
+            return self;
+        };
+        if let (Some(per_key), Some(keyed_config), Some(rate_limiter)) = (
+            &rate_limit_config.per_key,
+            crate::typ::build_keyed_config(rate_limit_config),
+            &self.rate_limiter,
+        ) {
+            self.keyed_rate_limiter =
+                Some((per_key.selector.clone(), rate_limiter.keyed(keyed_config)));
+        }
+        self
+    }
+
     /// read messages from the source by communicating with the read actor.
     async fn read(source_handle: mpsc::Sender<ActorMessage>) -> Option<Result<Vec<Message>>> {
         let (sender, receiver) = oneshot::channel();
@@ -417,6 +469,24 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
         ));
         let mvtx_labels = mvtx_forward_metric_labels();
 
+        // with per-key rate limiting, the read batches go through the throttle which forwards the
+        // messages downstream as their keys get tokens.
+        let (throttle_tx, throttle_handle) = match self.keyed_rate_limiter.clone() {
+            Some((selector, keyed_rate_limiter)) => {
+                let (throttle_tx, throttle_rx) = mpsc::channel(1);
+                let handle =
+                    KeyedThrottle::new(selector, keyed_rate_limiter, self.watermark_handle.clone())
+                        .start(
+                            throttle_rx,
+                            messages_tx.clone(),
+                            bypass_router.clone(),
+                            cln_token.clone(),
+                        );
+                (Some(throttle_tx), Some(handle))
+            }
+            None => (None, None),
+        };
+
         info!(?self.read_batch_size, "Started streaming source with batch size");
         let handle = tokio::spawn(async move {
             // this semaphore is used only if read-ahead is disabled. we hold this semaphore to
@@ -442,8 +512,10 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
                 // In source, we rate limit the `read` method invocations,
                 // and not the number of messages read. It just removes a single token per read.
                 // To throttle the number of messages read, make sure that `read_batch_size` is set to
-                // appropriate value.
-                if let Some(ref rate_limiter) = self.rate_limiter
+                // appropriate value. With per-key rate limiting, the messages are throttled after the
+                // read instead.
+                if self.keyed_rate_limiter.is_none()
+                    && let Some(ref rate_limiter) = self.rate_limiter
                     && rate_limiter
                         .acquire_n(Some(1), Some(Duration::from_secs(1)))
                         .await
@@ -561,6 +633,24 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
                     },
                 };
 
+                // the throttle publishes the watermark itself, so that the watermark doesn't pass
+                // the messages it defers.
+                if let Some(ref throttle_tx) = throttle_tx {
+                    // the throttle stops on cancellation, the batch read meanwhile is nacked so
+                    // that the source redelivers it.
+                    if let Err(mpsc::error::SendError(messages)) = throttle_tx.send(messages).await
+                    {
+                        warn!("Per-key throttle stopped, sending nack to the batch");
+                        for message in messages {
+                            if let Some(ack_handle) = &message.ack_handle {
+                                ack_handle.is_failed.store(true, Ordering::Relaxed);
+                            }
+                        }
+                        break;
+                    }
+                    continue;
+                }
+
                 if let Some(watermark_handle) = self.watermark_handle.as_mut() {
                     watermark_handle
                         .generate_and_publish_source_watermark(&messages)
@@ -575,23 +665,18 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
 
                 // write the messages to downstream.
                 for message in messages {
-                    let bypassed = if let Some(ref bypass_router) = bypass_router {
-                        bypass_router
-                            .try_bypass(message.clone())
-                            .await
-                            .expect("failed to send message to bypass channel")
-                    } else {
-                        false
-                    };
-
-                    if !bypassed {
-                        messages_tx
-                            .send(message)
-                            .await
-                            .expect("send should not fail");
-                    }
+                    forward(message, bypass_router.as_ref(), &messages_tx).await;
                 }
             }
+
+            // the throttle stops once the deferred messages are forwarded (or nacked on
+            // cancellation), so that they are acked before we wait for the inflight messages.
+            drop(throttle_tx);
+            if let Some(throttle_handle) = throttle_handle {
+                throttle_handle
+                    .await
+                    .map_err(|e| Error::Source(format!("Per-key throttle failed: {e}")))?;
+            }
             info!(status=?result, "Source stopped, waiting for inflight messages to be acked/nacked");
             // wait for all the ack tasks to be completed before stopping the source, since we give
             // a permit for each ack task all the permits should be released when the ack tasks are
@@ -603,7 +688,12 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
             info!("All inflight messages are acked/nacked. Source stopped.");
 
             // Shutdown rate limiter if configured
-            if let Some(ref rate_limiter) = self.rate_limiter {
+            if let Some((_, ref keyed_rate_limiter)) = self.keyed_rate_limiter {
+                keyed_rate_limiter
+                    .shutdown()
+                    .await
+                    .map_err(|e| Error::Source(format!("Failed to shutdown rate limiter: {e}")))?;
+            } else if let Some(ref rate_limiter) = self.rate_limiter {
                 rate_limiter
                     .shutdown()
                     .await
@@ -862,6 +952,29 @@ impl<C: crate::typ::NumaflowTypeConfig> Source<C> {
     }
 }
 
+/// Writes the message downstream, unless the bypass router routes it to a sink directly.
+async fn forward(
+    message: Message,
+    bypass_router: Option<&MvtxBypassRouter>,
+    messages_tx: &mpsc::Sender<Message>,
+) {
+    let bypassed = if let Some(bypass_router) = bypass_router {
+        bypass_router
+            .try_bypass(message.clone())
+            .await
+            .expect("failed to send message to bypass channel")
+    } else {
+        false
+    };
+
+    if !bypassed {
+        messages_tx
+            .send(message)
+            .await
+            .expect("send should not fail");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::shared::grpc::create_rpc_channel;
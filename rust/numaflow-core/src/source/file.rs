@@ -0,0 +1,604 @@
+//! File source replays the segments written by the file sink, see [crate::shared::file_segment].
+//! The completed segments in the directory are read in the order of their names, and new segments
+//! are picked up as they show up. The offset of a message is its segment and record index, the
+//! acknowledged records of every segment are persisted in a checkpoint file so that a restart only
+//! re-reads the records which were not acknowledged. The completed segments which are removed from
+//! the directory are pruned from the checkpoint.
+//!
+//! NOTE: every replica reads all the segments in the directory, the source should run with a
+//! single replica.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+
+use crate::config::components::source::FileSourceConfig;
+use crate::config::{get_vertex_name, get_vertex_replica};
+use crate::error::{Error, Result};
+use crate::message::{Message, MessageID, Offset, StringOffset};
+use crate::metadata::Metadata;
+use crate::shared::file_segment::{SegmentReader, SegmentRecord};
+use crate::source::{LagReader, SourceAcker, SourceReader};
+
+/// Persisted read progress of a segment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct SegmentCheckpoint {
+    /// All the records before this index have been acknowledged.
+    committed: u64,
+    /// Acknowledged records after `committed`.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    acked: BTreeSet<u64>,
+    /// All the records of the segment have been acknowledged.
+    done: bool,
+}
+
+/// Checkpoint file contents, the progress of every segment keyed by its name.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Checkpoint {
+    segments: BTreeMap<String, SegmentCheckpoint>,
+}
+
+/// Read progress of a segment in this run.
+#[derive(Debug, Default)]
+struct SegmentProgress {
+    checkpoint: SegmentCheckpoint,
+    /// Number of records in the segment, known once it has been read till the end.
+    total: Option<u64>,
+    /// The segment has been opened in this run.
+    opened: bool,
+}
+
+impl SegmentProgress {
+    /// Moves `committed` forward over the contiguously acknowledged records.
+    fn advance(&mut self) {
+        while self.checkpoint.acked.remove(&self.checkpoint.committed) {
+            self.checkpoint.committed += 1;
+        }
+        if self.total == Some(self.checkpoint.committed) {
+            self.checkpoint.done = true;
+        }
+    }
+}
+
+/// A message which has been read but not yet acknowledged.
+struct InflightRecord {
+    segment: String,
+    index: u64,
+    message: Message,
+}
+
+/// The segment which is currently being read.
+struct OpenSegment {
+    name: String,
+    reader: SegmentReader,
+    /// Index of the next record.
+    next_index: u64,
+}
+
+struct FileSourceState {
+    config: FileSourceConfig,
+    batch_size: usize,
+    read_timeout: Duration,
+    progress: BTreeMap<String, SegmentProgress>,
+    current: Option<OpenSegment>,
+    inflight: HashMap<Bytes, InflightRecord>,
+    /// Nacked messages which have to be read again.
+    redeliver: VecDeque<Message>,
+}
+
+/// File source, it is cloned to be used as the reader, acker and lag reader which share the state.
+#[derive(Clone)]
+pub(crate) struct FileSource {
+    state: Arc<Mutex<FileSourceState>>,
+}
+
+pub(crate) async fn new_file_source(
+    config: FileSourceConfig,
+    batch_size: usize,
+    read_timeout: Duration,
+) -> Result<FileSource> {
+    let checkpoint = match tokio::fs::read(&config.checkpoint_path).await {
+        Ok(data) => serde_json::from_slice::<Checkpoint>(&data).map_err(|e| {
+            Error::Source(format!(
+                "Decoding checkpoint {}: {e}",
+                config.checkpoint_path.display()
+            ))
+        })?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Checkpoint::default(),
+        Err(e) => {
+            return Err(Error::Source(format!(
+                "Reading checkpoint {}: {e}",
+                config.checkpoint_path.display()
+            )));
+        }
+    };
+    info!(
+        path = %config.path.display(),
+        checkpointed_segments = checkpoint.segments.len(),
+        "File source created"
+    );
+
+    let progress = checkpoint
+        .segments
+        .into_iter()
+        .map(|(name, checkpoint)| {
+            (
+                name,
+                SegmentProgress {
+                    checkpoint,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+
+    Ok(FileSource {
+        state: Arc::new(Mutex::new(FileSourceState {
+            config,
+            batch_size,
+            read_timeout,
+            progress,
+            current: None,
+            inflight: HashMap::new(),
+            redeliver: VecDeque::new(),
+        })),
+    })
+}
+
+impl FileSourceState {
+    async fn read(&mut self) -> Result<Vec<Message>> {
+        let mut messages = Vec::with_capacity(self.batch_size);
+        while messages.len() < self.batch_size {
+            let Some(message) = self.redeliver.pop_front() else {
+                break;
+            };
+            messages.push(message);
+        }
+
+        while messages.len() < self.batch_size {
+            if self.current.is_none() && !self.open_next_segment().await? {
+                break;
+            }
+            let current = self.current.as_mut().expect("segment is open");
+            let index = current.next_index;
+            let Some(record) = current.reader.next().await? else {
+                let name = current.name.clone();
+                debug!(segment = name, records = index, "Finished reading segment");
+                self.current = None;
+                let progress = self.progress.entry(name).or_default();
+                progress.total = Some(index);
+                progress.advance();
+                if progress.checkpoint.done {
+                    self.persist_checkpoint().await?;
+                }
+                continue;
+            };
+            current.next_index += 1;
+
+            let segment = current.name.clone();
+            let progress = self.progress.entry(segment.clone()).or_default();
+            // skip the records acknowledged before a restart
+            if index < progress.checkpoint.committed || progress.checkpoint.acked.contains(&index) {
+                continue;
+            }
+            let message = record_to_message(&segment, index, record);
+            let Offset::String(offset) = &message.offset else {
+                return Err(Error::Source(format!(
+                    "Expected string offset for file source. Got: {:?}",
+                    message.offset
+                )));
+            };
+            self.inflight.insert(
+                offset.offset.clone(),
+                InflightRecord {
+                    segment,
+                    index,
+                    message: message.clone(),
+                },
+            );
+            messages.push(message);
+        }
+        Ok(messages)
+    }
+
+    /// Opens the first completed segment, by name, which is neither done nor opened before.
+    /// Returns `false` if there is no such segment.
+    async fn open_next_segment(&mut self) -> Result<bool> {
+        let mut entries = tokio::fs::read_dir(&self.config.path)
+            .await
+            .map_err(|e| Error::Source(format!("Reading file source directory: {e}")))?;
+        let mut candidates = BTreeSet::new();
+        let mut present = HashSet::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::Source(format!("Reading file source directory: {e}")))?
+        {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !self.config.format.is_segment(&name) {
+                continue;
+            }
+            present.insert(name.clone());
+            if let Some(progress) = self.progress.get(&name)
+                && (progress.opened || progress.checkpoint.done)
+            {
+                continue;
+            }
+            candidates.insert(name);
+        }
+        // the completed segments which have been removed can't be read again, they are dropped
+        // from the checkpoint when it is persisted next.
+        self.progress
+            .retain(|name, progress| !progress.checkpoint.done || present.contains(name));
+
+        let Some(name) = candidates.pop_first() else {
+            return Ok(false);
+        };
+        info!(segment = name, "Reading segment");
+        let reader = SegmentReader::open(self.config.format, &self.config.path.join(&name)).await?;
+        self.progress.entry(name.clone()).or_default().opened = true;
+        self.current = Some(OpenSegment {
+            name,
+            reader,
+            next_index: 0,
+        });
+        Ok(true)
+    }
+
+    async fn ack(&mut self, offsets: Vec<Offset>) -> Result<()> {
+        for offset in offsets {
+            let record = self.take_inflight(offset)?;
+            let progress = self.progress.entry(record.segment).or_default();
+            progress.checkpoint.acked.insert(record.index);
+            progress.advance();
+        }
+        self.persist_checkpoint().await
+    }
+
+    async fn nack(&mut self, offsets: Vec<Offset>) -> Result<()> {
+        for offset in offsets {
+            let record = self.take_inflight(offset)?;
+            // the message is read again, and is tracked as inflight once more.
+            self.inflight.insert(
+                record.message.id.offset.clone(),
+                InflightRecord {
+                    segment: record.segment,
+                    index: record.index,
+                    message: record.message.clone(),
+                },
+            );
+            self.redeliver.push_back(record.message);
+        }
+        Ok(())
+    }
+
+    fn take_inflight(&mut self, offset: Offset) -> Result<InflightRecord> {
+        let Offset::String(offset) = offset else {
+            return Err(Error::Source(format!(
+                "Expected string offset for file source. Got: {offset:?}"
+            )));
+        };
+        self.inflight
+            .remove(&offset.offset)
+            .ok_or_else(|| Error::Source(format!("Unknown offset for file source: {offset}")))
+    }
+
+    /// Writes the checkpoint to a temporary file, syncs it and renames it, so that a crash never
+    /// leaves a partially written checkpoint behind.
+    async fn persist_checkpoint(&self) -> Result<()> {
+        let checkpoint = Checkpoint {
+            segments: self
+                .progress
+                .iter()
+                .map(|(name, progress)| (name.clone(), progress.checkpoint.clone()))
+                .collect(),
+        };
+        let data = serde_json::to_vec(&checkpoint)
+            .map_err(|e| Error::Source(format!("Encoding checkpoint: {e}")))?;
+        let tmp_path = self.config.checkpoint_path.with_extension("tmp");
+        let write = async {
+            let mut file = tokio::fs::File::create(&tmp_path).await?;
+            file.write_all(&data).await?;
+            file.sync_all().await?;
+            tokio::fs::rename(&tmp_path, &self.config.checkpoint_path).await?;
+            match self.config.checkpoint_path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => {
+                    tokio::fs::File::open(dir).await?.sync_all().await
+                }
+                _ => Ok(()),
+            }
+        };
+        write
+            .await
+            .map_err(|e| Error::Source(format!("Writing checkpoint: {e}")))
+    }
+}
+
+/// The offset is `<segment>:<record index>`, it is the same every time the segment is replayed.
+fn record_to_message(segment: &str, index: u64, record: SegmentRecord) -> Message {
+    let offset = Offset::String(StringOffset::new(
+        format!("{segment}:{index}"),
+        *get_vertex_replica(),
+    ));
+    Message {
+        typ: Default::default(),
+        keys: Arc::from(record.keys),
+        tags: None,
+        value: record.value,
+        offset: offset.clone(),
+        event_time: record.event_time,
+        watermark: None,
+        id: MessageID {
+            vertex_name: get_vertex_name().to_string().into(),
+            offset: format!("{segment}:{index}").into(),
+            index: 0,
+        },
+        headers: Arc::new(record.headers),
+        // Set default metadata so that metadata is always present.
+        metadata: Some(Arc::new(Metadata::default())),
+        is_late: false,
+        ack_handle: None,
+    }
+}
+
+impl SourceReader for FileSource {
+    fn name(&self) -> &'static str {
+        "File"
+    }
+
+    async fn read(&mut self) -> Option<Result<Vec<Message>>> {
+        let (result, read_timeout) = {
+            let mut state = self.state.lock().await;
+            (state.read().await, state.read_timeout)
+        };
+        // nothing new to read, wait a bit before looking for new segments
+        if let Ok(messages) = &result
+            && messages.is_empty()
+        {
+            tokio::time::sleep(read_timeout).await;
+        }
+        Some(result)
+    }
+
+    async fn partitions(&mut self) -> Result<Vec<u16>> {
+        Ok(vec![*get_vertex_replica()])
+    }
+}
+
+impl SourceAcker for FileSource {
+    async fn ack(&mut self, offsets: Vec<Offset>) -> Result<()> {
+        self.state.lock().await.ack(offsets).await
+    }
+
+    async fn nack(&mut self, offsets: Vec<Offset>) -> Result<()> {
+        self.state.lock().await.nack(offsets).await
+    }
+}
+
+impl LagReader for FileSource {
+    async fn pending(&mut self) -> Result<Option<usize>> {
+        // the number of records in the segments is not known without reading them
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::IntOffset;
+    use crate::shared::file_segment::SegmentFormat;
+    use chrono::DateTime;
+
+    fn test_message(i: i64) -> Message {
+        Message {
+            keys: Arc::from(vec![format!("key-{i}")]),
+            value: Bytes::from(format!("payload-{i}")),
+            offset: Offset::Int(IntOffset::new(i, 0)),
+            event_time: DateTime::from_timestamp_millis(1_700_000_000_000 + i).unwrap(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: i.to_string().into(),
+                index: 0,
+            },
+            headers: Arc::new(HashMap::from([("n".to_string(), i.to_string())])),
+            ..Default::default()
+        }
+    }
+
+    /// Writes a completed segment with the given messages.
+    async fn write_segment(dir: &std::path::Path, idx: usize, messages: std::ops::Range<i64>) {
+        let format = SegmentFormat::Ndjson;
+        let mut data = Vec::new();
+        for i in messages {
+            data.extend_from_slice(&format.encode(test_message(i)).unwrap());
+        }
+        let name = format.segment_name(
+            DateTime::from_timestamp_micros(1_700_000_000_000_000).unwrap()
+                + chrono::Duration::seconds(idx as i64),
+            0,
+            idx,
+        );
+        tokio::fs::write(dir.join(name), data).await.unwrap();
+    }
+
+    fn config(dir: &std::path::Path) -> FileSourceConfig {
+        FileSourceConfig {
+            path: dir.to_path_buf(),
+            format: SegmentFormat::Ndjson,
+            checkpoint_path: dir.join(".checkpoint.json"),
+        }
+    }
+
+    fn values(messages: &[Message]) -> Vec<Bytes> {
+        messages.iter().map(|msg| msg.value.clone()).collect()
+    }
+
+    fn expected(range: std::ops::Range<i64>) -> Vec<Bytes> {
+        range.map(|i| test_message(i).value).collect()
+    }
+
+    #[tokio::test]
+    async fn test_file_source_read_ack() {
+        let dir = tempfile::tempdir().unwrap();
+        write_segment(dir.path(), 0, 0..3).await;
+        write_segment(dir.path(), 1, 3..5).await;
+        // in-progress segments are not read
+        tokio::fs::write(
+            dir.path()
+                .join("00000000000000000000_0_9.ndjson.inprogress"),
+            b"x",
+        )
+        .await
+        .unwrap();
+
+        let mut source = new_file_source(config(dir.path()), 4, Duration::from_millis(10))
+            .await
+            .unwrap();
+        let first = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&first), expected(0..4));
+        let msg = first.first().unwrap();
+        assert_eq!(msg.keys.to_vec(), vec!["key-0".to_string()]);
+        assert_eq!(msg.headers.get("n"), Some(&"0".to_string()));
+        assert_eq!(msg.event_time, test_message(0).event_time);
+
+        let second = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&second), expected(4..5));
+        assert!(source.read().await.unwrap().unwrap().is_empty());
+
+        // ack everything except the second message
+        let offsets: Vec<Offset> = first
+            .iter()
+            .chain(second.iter())
+            .enumerate()
+            .filter(|(i, _)| *i != 1)
+            .map(|(_, msg)| msg.offset.clone())
+            .collect();
+        source.ack(offsets).await.unwrap();
+
+        let checkpoint: Checkpoint = serde_json::from_slice(
+            &tokio::fs::read(dir.path().join(".checkpoint.json"))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let segments: Vec<&SegmentCheckpoint> = checkpoint.segments.values().collect();
+        assert_eq!(
+            segments,
+            vec![
+                &SegmentCheckpoint {
+                    committed: 1,
+                    acked: BTreeSet::from([2]),
+                    done: false
+                },
+                &SegmentCheckpoint {
+                    committed: 2,
+                    acked: BTreeSet::new(),
+                    done: true
+                }
+            ]
+        );
+
+        // after a restart only the unacknowledged message is read again
+        let mut source = new_file_source(config(dir.path()), 4, Duration::from_millis(10))
+            .await
+            .unwrap();
+        let replayed = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&replayed), expected(1..2));
+        assert_eq!(
+            replayed.first().unwrap().offset,
+            first.get(1).unwrap().offset
+        );
+
+        // new segments are picked up
+        write_segment(dir.path(), 2, 5..6).await;
+        let new = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&new), expected(5..6));
+    }
+
+    #[tokio::test]
+    async fn test_file_source_nack() {
+        let dir = tempfile::tempdir().unwrap();
+        write_segment(dir.path(), 0, 0..3).await;
+
+        let mut source = new_file_source(config(dir.path()), 10, Duration::from_millis(10))
+            .await
+            .unwrap();
+        let messages = source.read().await.unwrap().unwrap();
+        assert_eq!(messages.len(), 3);
+
+        let nacked = messages.get(1).unwrap().offset.clone();
+        source.nack(vec![nacked.clone()]).await.unwrap();
+        let redelivered = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&redelivered), expected(1..2));
+
+        source
+            .ack(messages.iter().map(|msg| msg.offset.clone()).collect())
+            .await
+            .unwrap();
+        assert!(source.ack(vec![nacked]).await.is_err());
+        assert_eq!(source.pending().await.unwrap(), None);
+        assert!(
+            source
+                .state
+                .lock()
+                .await
+                .progress
+                .values()
+                .all(|p| p.checkpoint.done)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_source_prunes_removed_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        write_segment(dir.path(), 0, 0..2).await;
+        write_segment(dir.path(), 1, 2..3).await;
+
+        let mut source = new_file_source(config(dir.path()), 10, Duration::from_millis(10))
+            .await
+            .unwrap();
+        let messages = source.read().await.unwrap().unwrap();
+        let messages = [messages, source.read().await.unwrap().unwrap()].concat();
+        assert_eq!(values(&messages), expected(0..3));
+        source
+            .ack(messages.iter().map(|msg| msg.offset.clone()).collect())
+            .await
+            .unwrap();
+
+        // the completed first segment is removed, a new one is written
+        let first = source
+            .state
+            .lock()
+            .await
+            .progress
+            .keys()
+            .next()
+            .cloned()
+            .unwrap();
+        tokio::fs::remove_file(dir.path().join(&first))
+            .await
+            .unwrap();
+        write_segment(dir.path(), 2, 3..4).await;
+        let new = source.read().await.unwrap().unwrap();
+        assert_eq!(values(&new), expected(3..4));
+        source
+            .ack(new.iter().map(|msg| msg.offset.clone()).collect())
+            .await
+            .unwrap();
+
+        let checkpoint: Checkpoint = serde_json::from_slice(
+            &tokio::fs::read(dir.path().join(".checkpoint.json"))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(checkpoint.segments.len(), 2);
+        assert!(!checkpoint.segments.contains_key(&first));
+    }
+}
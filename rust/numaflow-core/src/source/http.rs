@@ -18,6 +18,10 @@ impl From<numaflow_http::Error> for crate::error::Error {
             Error::Server(_) | Error::ChannelSend(_) | Error::ChannelRecv(_) => {
                 Self::Source(format!("HTTP source: {value:?}"))
             }
+            Error::Sink(_)
+            | Error::Request(_)
+            | Error::Status { .. }
+            | Error::InvalidPayload(_) => Self::Sink(format!("HTTP sink: {value:?}")),
         }
     }
 }
@@ -1,8 +1,10 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use numaflow_kafka::source::{KafkaMessage, KafkaSource, KafkaSourceConfig};
+use tokio::sync::watch;
 use tracing::info;
 
 use crate::config::get_vertex_name;
@@ -10,6 +12,7 @@ use crate::error::Error;
 use crate::message::{Message, MessageID, Offset, StringOffset};
 use crate::metadata::Metadata;
 use crate::source;
+use crate::watermark::source::SourceWatermarkHandle;
 
 impl TryFrom<KafkaMessage> for Message {
     type Error = Error;
@@ -17,7 +20,7 @@ impl TryFrom<KafkaMessage> for Message {
     fn try_from(message: KafkaMessage) -> crate::Result<Self> {
         let offset = Offset::String(StringOffset::new(
             format!("{}:{}:{}", message.topic, message.partition, message.offset),
-            message.partition as u16,
+            message.partition_idx,
         ));
 
         // Use Kafka timestamp if available, otherwise fall back to current time
@@ -66,6 +69,7 @@ impl From<numaflow_kafka::Error> for Error {
                 "Failed to connect to Kafka server: {server} - {error}"
             )),
             numaflow_kafka::Error::NonRetryable(e) => Error::NonRetryable(e.to_string()),
+            numaflow_kafka::Error::Transaction(e) => Error::Source(e.to_string()),
             numaflow_kafka::Error::Other(e) => Error::Source(e),
         }
     }
@@ -80,6 +84,35 @@ pub(crate) async fn new_kafka_source(
     Ok(KafkaSource::connect(cfg, batch_size, timeout, cancel_token).await?)
 }
 
+/// Registers the watermark publishers of the partitions assigned to this replica and deregisters
+/// the revoked ones after every rebalance of the consumer group, so that every partition has its
+/// own watermark which follows the partition when it moves to another replica. Returns once the
+/// consumer is dropped.
+pub(crate) async fn track_partition_assignment(
+    mut assigned_rx: watch::Receiver<BTreeSet<u16>>,
+    watermark_handle: SourceWatermarkHandle,
+) {
+    let mut current: BTreeSet<u16> = BTreeSet::new();
+    loop {
+        let assigned: BTreeSet<u16> = assigned_rx.borrow_and_update().clone();
+        let revoked: Vec<u16> = current.difference(&assigned).copied().collect();
+        let added: Vec<u16> = assigned.difference(&current).copied().collect();
+        if !revoked.is_empty() {
+            info!(?revoked, "Kafka partitions revoked");
+            watermark_handle.revoke_partitions(revoked).await;
+        }
+        if !added.is_empty() {
+            info!(?added, "Kafka partitions assigned");
+            watermark_handle.assign_partitions(added).await;
+        }
+        current = assigned;
+
+        if assigned_rx.changed().await.is_err() {
+            break;
+        }
+    }
+}
+
 impl source::SourceReader for KafkaSource {
     fn name(&self) -> &'static str {
         "Kafka"
@@ -97,9 +130,16 @@ impl source::SourceReader for KafkaSource {
         }
     }
 
+    /// Only the partitions assigned to this consumer, the idle watermarks of the partitions owned
+    /// by the other replicas are published by them. Empty until the consumer joins the consumer
+    /// group while reading, the partitions are registered as they are assigned.
     async fn partitions(&mut self) -> crate::error::Result<Vec<u16>> {
-        let partitions = self.partitions_info().await?;
-        Ok(partitions.into_iter().map(|p| p as u16).collect())
+        Ok(self
+            .assigned_partitions()
+            .borrow()
+            .iter()
+            .copied()
+            .collect())
     }
 }
 
@@ -175,6 +215,8 @@ mod tests {
             topic: "test_topic".to_string(),
             value: Bytes::from("test_value"),
             partition: 1,
+            // partition 1 of the second topic of a multi-topic source
+            partition_idx: 1025,
             offset: 42,
             key: Some("test_key".to_string()),
             headers: {
@@ -189,8 +231,8 @@ mod tests {
 
         assert_eq!(message.value, Bytes::from("test_value"));
         // The offset format is "<topic>:<partition>:<offset>-<partition_idx>"
-        // where partition_idx is the Kafka partition number
-        assert_eq!(message.offset.to_string(), "test_topic:1:42-1");
+        // where partition_idx is the index of the partition across the topics
+        assert_eq!(message.offset.to_string(), "test_topic:1:42-1025");
         assert_eq!(message.headers.get("key"), Some(&"value".to_string()));
         // Verify that the event time is set from the Kafka timestamp
         assert_eq!(message.event_time.timestamp_millis(), 1640995200000);
@@ -202,6 +244,7 @@ mod tests {
             topic: "test_topic".to_string(),
             value: Bytes::from("test_value"),
             partition: 1,
+            partition_idx: 1,
             offset: 42,
             key: None,
             headers: HashMap::new(),
@@ -227,6 +270,7 @@ mod tests {
             topic: "test_topic".to_string(),
             value: Bytes::from("test_value"),
             partition: 1,
+            partition_idx: 1,
             offset: 42,
             key: None,
             headers: HashMap::new(),
@@ -282,11 +326,11 @@ mod tests {
         .await
         .unwrap();
 
-        assert_eq!(source.partitions().await.unwrap(), vec![0]);
-
         // Test SourceReader::read
         let messages = source.read().await.unwrap().unwrap();
         assert_eq!(messages.len(), 20, "Should read 20 messages in a batch");
+        // the partitions are assigned once the consumer joins the group
+        assert_eq!(source.partitions().await.unwrap(), vec![0]);
         assert_eq!(
             messages
                 .first()
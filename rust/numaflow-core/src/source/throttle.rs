@@ -0,0 +1,449 @@
+//! Per-key throttling of the messages read from the source. Unlike the vertex level rate limit,
+//! which pauses the whole read once the tokens are exhausted, only the messages of the keys which
+//! ran out of tokens are held back, while the messages of the other keys keep flowing downstream.
+//!
+//! The held back messages are retried until their key gets tokens again, and are forwarded in the
+//! order in which they were read. They are not acked until forwarded, so the source backpressures
+//! through the max ack pending like for any other inflight message.
+//!
+//! Since the messages are forwarded out of the read order, the throttle publishes the source
+//! watermark instead of the read loop, and holds the watermark of each partition at the event time
+//! of its oldest deferred message. Otherwise, a reduce downstream would close the windows of the
+//! deferred messages before they arrive and drop them as late.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::Ordering;
+
+use numaflow_throttling::KeyedRateLimiter;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::config::components::ratelimit::KeySelector;
+use crate::message::Message;
+use crate::monovertex::bypass_router::MvtxBypassRouter;
+use crate::watermark::source::SourceWatermarkHandle;
+
+/// How often the deferred messages are retried.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// KeyedThrottle forwards the messages whose key has tokens, and defers the rest.
+pub(crate) struct KeyedThrottle<K> {
+    selector: KeySelector,
+    limiter: K,
+    /// key -> messages waiting for a token of the key, in read order.
+    deferred: HashMap<String, VecDeque<Message>>,
+    watermark_handle: Option<SourceWatermarkHandle>,
+    /// partition -> event time at which its watermark is currently held.
+    holds: HashMap<u16, i64>,
+}
+
+impl<K: KeyedRateLimiter + 'static> KeyedThrottle<K> {
+    pub(crate) fn new(
+        selector: KeySelector,
+        limiter: K,
+        watermark_handle: Option<SourceWatermarkHandle>,
+    ) -> Self {
+        Self {
+            selector,
+            limiter,
+            deferred: HashMap::new(),
+            watermark_handle,
+            holds: HashMap::new(),
+        }
+    }
+
+    /// Starts the throttle, the batches received on `rx` are forwarded as their keys get tokens.
+    /// Once `rx` is closed the deferred messages are still forwarded, the task stops when there
+    /// are none left. On cancellation, the deferred messages are nacked.
+    pub(crate) fn start(
+        mut self,
+        mut rx: mpsc::Receiver<Vec<Message>>,
+        messages_tx: mpsc::Sender<Message>,
+        bypass_router: Option<MvtxBypassRouter>,
+        cln_token: CancellationToken,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut retry = tokio::time::interval(RETRY_INTERVAL);
+            retry.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut closed = false;
+
+            loop {
+                let mut messages = tokio::select! {
+                    _ = cln_token.cancelled() => break,
+                    messages = rx.recv(), if !closed => match messages {
+                        Some(messages) => self.admit(messages).await,
+                        None => {
+                            closed = true;
+                            vec![]
+                        }
+                    },
+                    _ = retry.tick(), if !self.deferred.is_empty() => self.release().await,
+                };
+
+                if let Some(watermark_handle) = self.watermark_handle.clone() {
+                    self.hold_watermark(self.deferred_event_times()).await;
+                    // a message which was not late when read is not late when released either,
+                    // since the watermark was held at its event time meanwhile.
+                    let watermark = watermark_handle.fetch_source_watermark().await;
+                    for message in messages.iter_mut() {
+                        message.is_late = message.event_time < watermark;
+                    }
+                }
+
+                for message in messages {
+                    super::forward(message, bypass_router.as_ref(), &messages_tx).await;
+                }
+
+                if closed && self.deferred.is_empty() {
+                    break;
+                }
+            }
+
+            self.nack_deferred();
+        })
+    }
+
+    /// Returns the messages which got a token, the rest are deferred until their key gets more
+    /// tokens. Keys which already have deferred messages get no tokens, so that the messages of
+    /// a key are always forwarded in order.
+    async fn admit(&mut self, messages: Vec<Message>) -> Vec<Message> {
+        if let Some(watermark_handle) = self.watermark_handle.clone() {
+            // which messages get deferred is only known once the tokens are acquired, so until
+            // then the watermark generated from the batch is held at its oldest message.
+            let mut holds = self.deferred_event_times();
+            for message in &messages {
+                merge_hold(&mut holds, message);
+            }
+            self.hold_watermark(holds).await;
+            watermark_handle
+                .generate_and_publish_source_watermark(&messages)
+                .await;
+        }
+
+        let messages: Vec<(String, Message)> = messages
+            .into_iter()
+            .map(|message| {
+                let key = self.selector.key(&message.keys, &message.headers);
+                (key, message)
+            })
+            .collect();
+
+        // key -> tokens acquired for the messages of the key in this batch
+        let mut tokens: HashMap<String, usize> = HashMap::new();
+        for (key, _) in &messages {
+            *tokens.entry(key.clone()).or_default() += 1;
+        }
+        for (key, n) in tokens.iter_mut() {
+            *n = if self.deferred.contains_key(key) {
+                0
+            } else {
+                self.limiter.acquire_n(key, Some(*n), None).await
+            };
+        }
+
+        let mut admitted = Vec::with_capacity(messages.len());
+        for (key, message) in messages {
+            match tokens.get_mut(&key) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    admitted.push(message);
+                }
+                _ => self.deferred.entry(key).or_default().push_back(message),
+            }
+        }
+        admitted
+    }
+
+    /// Returns the deferred messages whose key got tokens since they were deferred.
+    async fn release(&mut self) -> Vec<Message> {
+        let mut released = vec![];
+        for (key, deferred) in self.deferred.iter_mut() {
+            let n = self
+                .limiter
+                .acquire_n(key, Some(deferred.len()), None)
+                .await;
+            released.extend(deferred.drain(..n.min(deferred.len())));
+        }
+        self.deferred.retain(|_, deferred| !deferred.is_empty());
+        released
+    }
+
+    /// Event time of the oldest deferred message of each partition.
+    fn deferred_event_times(&self) -> HashMap<u16, i64> {
+        let mut holds = HashMap::new();
+        for message in self.deferred.values().flatten() {
+            merge_hold(&mut holds, message);
+        }
+        holds
+    }
+
+    /// Holds the source watermark of the partitions at the given event times, if they changed.
+    async fn hold_watermark(&mut self, holds: HashMap<u16, i64>) {
+        let Some(watermark_handle) = &self.watermark_handle else {
+            return;
+        };
+        if holds != self.holds {
+            watermark_handle.hold_source_watermark(holds.clone()).await;
+            self.holds = holds;
+        }
+    }
+
+    /// Nacks the deferred messages, so that the source redelivers them.
+    fn nack_deferred(&mut self) {
+        let count: usize = self.deferred.values().map(VecDeque::len).sum();
+        if count == 0 {
+            return;
+        }
+        warn!(
+            count,
+            "Nacking the messages deferred by the per-key rate limit"
+        );
+        for message in self.deferred.drain().flat_map(|(_, deferred)| deferred) {
+            if let Some(ack_handle) = &message.ack_handle {
+                ack_handle.is_failed.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Lowers the hold of the partition of the message to its event time.
+fn merge_hold(holds: &mut HashMap<u16, i64>, message: &Message) {
+    let event_time = message.event_time.timestamp_millis();
+    match holds.entry(message.offset.partition_idx()) {
+        Entry::Occupied(mut hold) => *hold.get_mut() = (*hold.get()).min(event_time),
+        Entry::Vacant(hold) => {
+            hold.insert(event_time);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use numaflow_throttling::{
+        IntoKeyed, KeyedConfig, Mode, RateLimit, TokenCalcBounds, WithoutState,
+    };
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::message::{AckHandle, IntOffset, MessageID, Offset, ReadAck};
+
+    fn message(key: &str, index: i64) -> (Message, oneshot::Receiver<ReadAck>) {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let message = Message {
+            keys: Arc::from(vec![key.to_string()]),
+            value: format!("{key}-{index}").into(),
+            offset: Offset::Int(IntOffset::new(index, 0)),
+            event_time: Utc::now(),
+            id: MessageID {
+                vertex_name: "vertex".to_string().into(),
+                offset: index.to_string().into(),
+                index: 0,
+            },
+            ack_handle: Some(Arc::new(AckHandle::new(ack_tx))),
+            ..Default::default()
+        };
+        (message, ack_rx)
+    }
+
+    fn throttle(
+        max: usize,
+        watermark_handle: Option<SourceWatermarkHandle>,
+    ) -> KeyedThrottle<impl KeyedRateLimiter + 'static> {
+        let bounds = TokenCalcBounds::new(max, max, Duration::from_secs(1), Mode::Relaxed);
+        let limiter = RateLimit::<WithoutState>::new(bounds.clone()).unwrap();
+        KeyedThrottle::new(
+            KeySelector::Keys,
+            limiter.keyed(KeyedConfig::new(bounds, 10)),
+            watermark_handle,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_admit_defers_exhausted_keys() {
+        let mut throttle = throttle(2, None);
+
+        let (messages, _acks): (Vec<_>, Vec<_>) = [("a", 0), ("a", 1), ("a", 2), ("b", 3)]
+            .into_iter()
+            .map(|(key, index)| message(key, index))
+            .unzip();
+
+        let admitted = throttle.admit(messages).await;
+        let values: Vec<_> = admitted.iter().map(|m| m.value.clone()).collect();
+        assert_eq!(values, vec!["a-0", "a-1", "b-3"]);
+        assert_eq!(throttle.deferred.get("a").map(VecDeque::len), Some(1));
+
+        // a key with deferred messages is not admitted, even if it has tokens
+        let (b, _b_ack) = message("b", 4);
+        let (a, _a_ack) = message("a", 5);
+        let admitted = throttle.admit(vec![b, a]).await;
+        let values: Vec<_> = admitted.iter().map(|m| m.value.clone()).collect();
+        assert_eq!(values, vec!["b-4"]);
+        assert_eq!(throttle.deferred.get("a").map(VecDeque::len), Some(2));
+
+        // the deferred messages have to be dropped before their ack receivers
+        drop(throttle);
+    }
+
+    #[tokio::test]
+    async fn test_deferred_messages_are_forwarded_in_order() {
+        let throttle = throttle(1, None);
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (messages_tx, mut messages_rx) = mpsc::channel(10);
+        let handle = throttle.start(input_rx, messages_tx, None, CancellationToken::new());
+
+        let (messages, _acks): (Vec<_>, Vec<_>) = (0..3).map(|index| message("a", index)).unzip();
+        input_tx.send(messages).await.unwrap();
+        drop(input_tx);
+
+        for index in 0..3 {
+            let message = messages_rx.recv().await.unwrap();
+            assert_eq!(message.value, format!("a-{index}"));
+        }
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_deferred_messages_are_nacked_on_cancellation() {
+        let throttle = throttle(1, None);
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (messages_tx, mut messages_rx) = mpsc::channel(10);
+        let cln_token = CancellationToken::new();
+        let handle = throttle.start(input_rx, messages_tx, None, cln_token.clone());
+
+        let (first, first_ack) = message("a", 0);
+        let (second, second_ack) = message("a", 1);
+        input_tx.send(vec![first, second]).await.unwrap();
+
+        let forwarded = messages_rx.recv().await.unwrap();
+        drop(forwarded);
+        assert_eq!(first_ack.await.unwrap(), ReadAck::Ack);
+
+        cln_token.cancel();
+        handle.await.unwrap();
+        assert_eq!(second_ack.await.unwrap(), ReadAck::Nak);
+    }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_deferred_key_holds_watermark() {
+        use async_nats::jetstream;
+        use async_nats::jetstream::kv::Config;
+        use chrono::DateTime;
+
+        use crate::config::pipeline::watermark::{BucketConfig, SourceWatermarkConfig};
+        use crate::pipeline::isb::ISBService;
+        use crate::watermark::wmb::WMB;
+
+        let client = async_nats::connect("localhost:4222").await.unwrap();
+        let js_context = jetstream::new(client);
+
+        let ot_bucket_name = "test_deferred_key_holds_watermark_OT";
+        let hb_bucket_name = "test_deferred_key_holds_watermark_PROCESSORS";
+        for bucket in [ot_bucket_name, hb_bucket_name] {
+            let _ = js_context.delete_key_value(bucket).await;
+            js_context
+                .create_key_value(Config {
+                    bucket: bucket.to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+        let ot_bucket = js_context.get_key_value(ot_bucket_name).await.unwrap();
+
+        let source_config = SourceWatermarkConfig {
+            max_delay: Default::default(),
+            strategy: Default::default(),
+            source_bucket_config: BucketConfig {
+                vertex: "source_vertex",
+                partitions: vec![0],
+                ot_bucket: ot_bucket_name,
+                hb_bucket: hb_bucket_name,
+                delay: None,
+            },
+            to_vertex_bucket_config: vec![],
+            idle_config: None,
+        };
+        let watermark_handle = SourceWatermarkHandle::new(
+            Duration::from_millis(100),
+            ISBService::JetStream(js_context.clone()),
+            Default::default(),
+            &source_config,
+            CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+        // waits until the published source watermark is the expected one
+        let published_watermark = |expected: i64| {
+            let ot_bucket = ot_bucket.clone();
+            async move {
+                tokio::time::timeout(Duration::from_secs(2), async {
+                    loop {
+                        if let Some(wmb) = ot_bucket.get("source-source_vertex-0").await.unwrap() {
+                            let wmb: WMB = wmb.try_into().unwrap();
+                            assert!(wmb.watermark <= expected, "watermark passed {expected}");
+                            if wmb.watermark == expected {
+                                return;
+                            }
+                        }
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                })
+                .await
+                .expect("watermark was not published");
+            }
+        };
+        let timed_message = |key: &str, index: i64, event_time: i64| {
+            let (mut message, ack) = message(key, index);
+            message.event_time = DateTime::from_timestamp_millis(event_time).unwrap();
+            (message, ack)
+        };
+
+        let throttle = throttle(1, Some(watermark_handle));
+        let (input_tx, input_rx) = mpsc::channel(10);
+        let (messages_tx, mut messages_rx) = mpsc::channel(10);
+        let handle = throttle.start(input_rx, messages_tx, None, CancellationToken::new());
+
+        // the second message of key a is deferred
+        let (messages, _acks): (Vec<_>, Vec<_>) =
+            [("a", 0, 10_000), ("a", 1, 20_000), ("b", 2, 30_000)]
+                .into_iter()
+                .map(|(key, index, event_time)| timed_message(key, index, event_time))
+                .unzip();
+        input_tx.send(messages).await.unwrap();
+        for expected in ["a-0", "b-2"] {
+            assert_eq!(messages_rx.recv().await.unwrap().value, expected);
+        }
+        published_watermark(10_000).await;
+
+        // the newer messages of the other keys don't move the watermark past the deferred one,
+        // which a reduce downstream would otherwise drop as late.
+        let (c, _c_ack) = timed_message("c", 3, 40_000);
+        input_tx.send(vec![c]).await.unwrap();
+        let forwarded = messages_rx.recv().await.unwrap();
+        assert_eq!(forwarded.value, "c-3");
+        assert!(!forwarded.is_late);
+        published_watermark(20_000).await;
+
+        // once the deferred message is forwarded, the hold is lifted
+        let forwarded = messages_rx.recv().await.unwrap();
+        assert_eq!(forwarded.value, "a-1");
+        assert!(!forwarded.is_late);
+        published_watermark(40_000).await;
+
+        drop(input_tx);
+        handle.await.unwrap();
+        for bucket in [ot_bucket_name, hb_bucket_name] {
+            js_context.delete_key_value(bucket).await.unwrap();
+        }
+    }
+}
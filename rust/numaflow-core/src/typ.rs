@@ -1,17 +1,21 @@
 //! Type configuration trait for Numaflow components.
 
 use crate::Result;
-use crate::config::components::ratelimit::RateLimitConfig;
+use crate::config::components::ratelimit::{KeyBounds, RateLimitConfig};
 use crate::error::Error;
-use crate::pipeline::isb::jetstream::js_reader::JetStreamReader;
-use crate::pipeline::isb::jetstream::js_writer::JetStreamWriter;
-use crate::pipeline::isb::{ISBReader, ISBWriter};
+use crate::pipeline::isb::jetstream::JetStreamFactory;
+use crate::pipeline::isb::{ISBFactory, ISBReader, ISBWriter};
+use async_nats::jetstream::Context;
+use numaflow_shared::kv::jetstream::JetstreamKVStore;
 use numaflow_throttling::state::OptimisticValidityUpdateSecs;
 use numaflow_throttling::state::store::in_memory_store::InMemoryStore;
+use numaflow_throttling::state::store::jetstream_store::JetStreamKVStore;
 use numaflow_throttling::state::store::redis_store::{RedisMode, RedisStore};
 use numaflow_throttling::{
-    GoBackNConfig, Mode, NoOpRateLimiter, RateLimit, RateLimiter, TokenCalcBounds, WithState,
+    GoBackNConfig, IntoKeyed, KeyedConfig, Mode, NoOpRateLimiter, RateLimit, RateLimiter,
+    TokenCalcBounds, WithState,
 };
+use std::marker::PhantomData;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
@@ -22,39 +26,58 @@ use tokio_util::sync::CancellationToken;
 /// while keeping the rest of the pipeline code generic.
 pub(crate) trait NumaflowTypeConfig: Send + Sync + Clone + 'static {
     /// The rate limiter type used for throttling
-    type RateLimiter: RateLimiter + Clone + Sync + 'static;
+    type RateLimiter: RateLimiter + IntoKeyed + Clone + Sync + 'static;
     /// The ISB reader type
     type ISBReader: ISBReader + 'static;
     /// The ISB writer type
     type ISBWriter: ISBWriter + 'static;
 }
 
+/// Type configuration with a Redis-backed rate limiter, over the ISB of the factory `F`.
 #[derive(Clone)]
-pub struct WithRedisRateLimiter {
+pub struct WithRedisRateLimiter<F = JetStreamFactory> {
     pub throttling_config: RateLimit<WithState<RedisStore>>,
+    _isb: PhantomData<F>,
 }
-impl NumaflowTypeConfig for WithRedisRateLimiter {
+impl<F: ISBFactory + Clone + 'static> NumaflowTypeConfig for WithRedisRateLimiter<F> {
     type RateLimiter = RateLimit<WithState<RedisStore>>;
-    type ISBReader = JetStreamReader;
-    type ISBWriter = JetStreamWriter;
+    type ISBReader = F::Reader;
+    type ISBWriter = F::Writer;
 }
 
+/// Type configuration with a JetStream KV-backed rate limiter, over the ISB of the factory `F`.
 #[derive(Clone)]
-pub struct WithInMemoryRateLimiter {
+pub struct WithJetStreamRateLimiter<F = JetStreamFactory> {
+    pub throttling_config: RateLimit<WithState<JetStreamKVStore>>,
+    _isb: PhantomData<F>,
+}
+impl<F: ISBFactory + Clone + 'static> NumaflowTypeConfig for WithJetStreamRateLimiter<F> {
+    type RateLimiter = RateLimit<WithState<JetStreamKVStore>>;
+    type ISBReader = F::Reader;
+    type ISBWriter = F::Writer;
+}
+
+/// Type configuration with an in-memory rate limiter, over the ISB of the factory `F`.
+#[derive(Clone)]
+pub struct WithInMemoryRateLimiter<F = JetStreamFactory> {
     pub throttling_config: RateLimit<WithState<InMemoryStore>>,
+    _isb: PhantomData<F>,
 }
-impl NumaflowTypeConfig for WithInMemoryRateLimiter {
+impl<F: ISBFactory + Clone + 'static> NumaflowTypeConfig for WithInMemoryRateLimiter<F> {
     type RateLimiter = RateLimit<WithState<InMemoryStore>>;
-    type ISBReader = JetStreamReader;
-    type ISBWriter = JetStreamWriter;
+    type ISBReader = F::Reader;
+    type ISBWriter = F::Writer;
 }
 
+/// Type configuration without rate limiting, over the ISB of the factory `F`.
 #[derive(Clone)]
-pub struct WithoutRateLimiter {}
-impl NumaflowTypeConfig for WithoutRateLimiter {
+pub struct WithoutRateLimiter<F = JetStreamFactory> {
+    _isb: PhantomData<F>,
+}
+impl<F: ISBFactory + Clone + 'static> NumaflowTypeConfig for WithoutRateLimiter<F> {
     type RateLimiter = NoOpRateLimiter;
-    type ISBReader = JetStreamReader;
-    type ISBWriter = JetStreamWriter;
+    type ISBReader = F::Reader;
+    type ISBWriter = F::Writer;
 }
 
 /// Build a Redis-backed rate limiter from rate limit config
@@ -92,6 +115,67 @@ pub async fn build_redis_rate_limiter_config(
     let limiter = build_redis_rate_limiter(rate_limit_config, cln_token).await?;
     Ok(WithRedisRateLimiter {
         throttling_config: limiter,
+        _isb: PhantomData,
+    })
+}
+
+/// Default KV bucket for the JetStream-backed rate limiter.
+const DEFAULT_RATE_LIMITER_BUCKET: &str = "numaflow-rate-limiter";
+
+/// Build a JetStream KV-backed rate limiter from rate limit config. The KV bucket is created in the
+/// ISB if it does not exist.
+pub async fn build_jetstream_rate_limiter(
+    rate_limit_config: &RateLimitConfig,
+    js_context: &Context,
+    cln_token: CancellationToken,
+) -> Result<RateLimit<WithState<JetStreamKVStore>>> {
+    let js_store_config = rate_limit_config
+        .store
+        .as_ref()
+        .and_then(|s| s.jet_stream_store.as_ref())
+        .ok_or_else(|| Error::Config("JetStream store config is required".to_string()))?;
+
+    let bucket: &'static str = match &js_store_config.bucket {
+        Some(bucket) => Box::leak(bucket.clone().into_boxed_str()),
+        None => DEFAULT_RATE_LIMITER_BUCKET,
+    };
+
+    let kv = match js_context.get_key_value(bucket).await {
+        Ok(kv) => kv,
+        Err(_) => js_context
+            .create_key_value(async_nats::jetstream::kv::Config {
+                bucket: bucket.to_string(),
+                history: 1,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| {
+                Error::Config(format!(
+                    "Failed to create rate limiter KV bucket {bucket}: {e}"
+                ))
+            })?,
+    };
+
+    let store = JetStreamKVStore::new(
+        rate_limit_config.key_prefix,
+        rate_limit_config.ttl,
+        JetstreamKVStore::new(kv, bucket),
+    );
+
+    let limiter = create_rate_limiter(rate_limit_config, store, cln_token).await?;
+    Ok(limiter)
+}
+
+/// Build a JetStream KV-backed rate limiter configuration.
+pub async fn build_jetstream_rate_limiter_config(
+    rate_limit_config: &RateLimitConfig,
+    js_context: &Context,
+    cln_token: CancellationToken,
+) -> Result<WithJetStreamRateLimiter> {
+    let limiter = build_jetstream_rate_limiter(rate_limit_config, js_context, cln_token).await?;
+    Ok(WithJetStreamRateLimiter {
+        throttling_config: limiter,
+        _isb: PhantomData,
     })
 }
 
@@ -114,6 +198,7 @@ pub async fn build_in_memory_rate_limiter_config(
     let limiter = build_in_memory_rate_limiter(rate_limit_config, cln_token).await?;
     Ok(WithInMemoryRateLimiter {
         throttling_config: limiter,
+        _isb: PhantomData,
     })
 }
 
@@ -126,17 +211,18 @@ pub fn should_use_redis_rate_limiter(rate_limit_config: &RateLimitConfig) -> boo
         .is_some()
 }
 
-/// Creates rate limiter for a given store and rate limit configuration.
-pub async fn create_rate_limiter<S>(
-    rate_limit_config: &RateLimitConfig,
-    store: S,
-    cancel_token: CancellationToken,
-) -> Result<RateLimit<WithState<S>>>
-where
-    S: numaflow_throttling::state::Store + Sync + 'static,
-{
-    // Determine rate-limiter mode based on configuration
-    let mode = if rate_limit_config
+/// Helper function to determine whether to use the JetStream KV-backed rate limiter.
+pub fn should_use_jetstream_rate_limiter(rate_limit_config: &RateLimitConfig) -> bool {
+    rate_limit_config
+        .store
+        .as_ref()
+        .and_then(|s| s.jet_stream_store.as_ref())
+        .is_some()
+}
+
+/// Determines the rate limiter mode based on the rate limit configuration.
+fn rate_limiter_mode(rate_limit_config: &RateLimitConfig) -> Mode {
+    if rate_limit_config
         .modes
         .as_ref()
         .and_then(|m| m.scheduled.as_ref())
@@ -199,7 +285,33 @@ where
         ))
     } else {
         Mode::Relaxed
-    };
+    }
+}
+
+/// Builds the configuration of the per-key token buckets, if per-key rate limiting is enabled.
+pub(crate) fn build_keyed_config(rate_limit_config: &RateLimitConfig) -> Option<KeyedConfig> {
+    let per_key = rate_limit_config.per_key.as_ref()?;
+    let mode = rate_limiter_mode(rate_limit_config);
+    let bounds =
+        |b: &KeyBounds| TokenCalcBounds::new(b.max, b.min, b.ramp_up_duration, mode.clone());
+
+    let keyed_config = per_key.overrides.iter().fold(
+        KeyedConfig::new(bounds(&per_key.bounds), per_key.max_keys),
+        |keyed_config, (key, key_bounds)| keyed_config.with_override(key, bounds(key_bounds)),
+    );
+    Some(keyed_config)
+}
+
+/// Creates rate limiter for a given store and rate limit configuration.
+pub async fn create_rate_limiter<S>(
+    rate_limit_config: &RateLimitConfig,
+    store: S,
+    cancel_token: CancellationToken,
+) -> Result<RateLimit<WithState<S>>>
+where
+    S: numaflow_throttling::state::Store + Sync + 'static,
+{
+    let mode = rate_limiter_mode(rate_limit_config);
 
     let bounds = TokenCalcBounds::new(
         rate_limit_config.max,
@@ -23,6 +23,7 @@ use chrono::{DateTime, Utc};
 
 use crate::config::pipeline::ToVertexConfig;
 use crate::config::pipeline::isb::Stream;
+use crate::pipeline::isb::ISBService;
 
 /// State of each partition in the ISB. It has the information required to identify whether the
 /// partition is idling or not.
@@ -32,6 +33,9 @@ struct IdleState {
     last_wm_published_time: DateTime<Utc>,
     /// This offset's WM will keep increasing as long as the [Stream] is idling.
     wmb_msg_offset: Option<i64>,
+    /// Whether the messages of the [Stream] are batched, which changes how the offsets are derived
+    /// from the stream sequence.
+    batched: bool,
 }
 
 impl Default for IdleState {
@@ -40,6 +44,7 @@ impl Default for IdleState {
             stream: Stream::default(),
             last_wm_published_time: Utc::now(),
             wmb_msg_offset: None,
+            batched: false,
         }
     }
 }
@@ -53,7 +58,7 @@ impl Default for IdleState {
 pub(crate) struct ISBIdleDetector {
     /// last published wm state per [Stream].
     last_published_wm_state: Arc<RwLock<HashMap<&'static str, Vec<IdleState>>>>,
-    js_context: async_nats::jetstream::Context,
+    isb: ISBService,
     /// X duration we wait before we start publishing idle WM.
     idle_timeout: Duration,
 }
@@ -63,7 +68,7 @@ impl ISBIdleDetector {
     pub(crate) async fn new(
         idle_timeout: Duration,
         to_vertex_configs: &[ToVertexConfig],
-        js_context: async_nats::jetstream::Context,
+        isb: ISBService,
     ) -> Self {
         let mut last_published_wm = HashMap::new();
 
@@ -77,6 +82,7 @@ impl ISBIdleDetector {
                     let stream = stream.clone();
                     IdleState {
                         stream,
+                        batched: config.writer_config.batching.is_some(),
                         ..Default::default()
                     }
                 })
@@ -88,7 +94,7 @@ impl ISBIdleDetector {
         ISBIdleDetector {
             idle_timeout,
             last_published_wm_state: Arc::new(RwLock::new(last_published_wm)),
-            js_context,
+            isb,
         }
     }
 
@@ -141,16 +147,13 @@ impl ISBIdleDetector {
         }
         .try_into()?;
 
-        let offset = self
-            .js_context
-            .publish(idle_state.stream.name, ctrl_msg_bytes.freeze())
+        self.isb
+            .publish_ctrl_message(
+                &idle_state.stream,
+                ctrl_msg_bytes.freeze(),
+                idle_state.batched,
+            )
             .await
-            .map_err(|e| crate::error::Error::Watermark(e.to_string()))?
-            .await
-            .map_err(|e| crate::error::Error::Watermark(e.to_string()))?
-            .sequence;
-
-        Ok(offset as i64)
     }
 
     /// Updates the idle stream's metadata, by setting the ctrl message offset and updates the last published time.
@@ -243,8 +246,12 @@ mod tests {
             ordered_processing_enabled: false,
         };
 
-        let mut manager =
-            ISBIdleDetector::new(Duration::from_millis(100), &[to_vertex_config], js_context).await;
+        let mut manager = ISBIdleDetector::new(
+            Duration::from_millis(100),
+            &[to_vertex_config],
+            ISBService::JetStream(js_context),
+        )
+        .await;
 
         manager.reset_idle(&stream).await;
 
@@ -291,8 +298,12 @@ mod tests {
             ordered_processing_enabled: false,
         };
 
-        let manager =
-            ISBIdleDetector::new(Duration::from_millis(100), &[to_vertex_config], js_context).await;
+        let manager = ISBIdleDetector::new(
+            Duration::from_millis(100),
+            &[to_vertex_config],
+            ISBService::JetStream(js_context),
+        )
+        .await;
 
         let offset = manager
             .fetch_idle_offset(&stream)
@@ -331,8 +342,12 @@ mod tests {
             ordered_processing_enabled: false,
         };
 
-        let mut manager =
-            ISBIdleDetector::new(Duration::from_millis(100), &[to_vertex_config], js_context).await;
+        let mut manager = ISBIdleDetector::new(
+            Duration::from_millis(100),
+            &[to_vertex_config],
+            ISBService::JetStream(js_context),
+        )
+        .await;
 
         let offset = manager
             .fetch_idle_offset(&stream)
@@ -371,8 +386,12 @@ mod tests {
             ordered_processing_enabled: false,
         };
 
-        let mut manager =
-            ISBIdleDetector::new(Duration::from_millis(10), &[to_vertex_config], js_context).await;
+        let mut manager = ISBIdleDetector::new(
+            Duration::from_millis(10),
+            &[to_vertex_config],
+            ISBService::JetStream(js_context),
+        )
+        .await;
 
         // Mark the stream as active first
         manager.reset_idle(&stream).await;
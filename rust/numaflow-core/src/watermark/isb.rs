@@ -18,6 +18,7 @@
 //! ```text
 //! (Write to ISB) -------> (Publish Watermark) ------> (Remove tracked Offset)
 //! ```
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -26,24 +27,37 @@ use tokio_util::sync::CancellationToken;
 use tracing::warn;
 
 use numaflow_shared::kv::KVStore;
-use numaflow_shared::kv::jetstream::JetstreamKVStore;
 
 use crate::config::pipeline::isb::Stream;
 use crate::config::pipeline::watermark::{BucketConfig, EdgeWatermarkConfig};
 use crate::config::pipeline::{ToVertexConfig, VertexType};
 use crate::error::Result;
 use crate::message::{IntOffset, Offset};
+use crate::pipeline::isb::ISBService;
 use crate::reduce::reducer::WindowManager;
 use crate::tracker::Tracker;
 use crate::watermark::idle::isb::ISBIdleDetector;
-use crate::watermark::isb::wm_fetcher::ISBWatermarkFetcher;
-use crate::watermark::isb::wm_publisher::ISBWatermarkPublisher;
+use crate::watermark::isb::wm_fetcher::{EdgeWatermarkDetails, ISBWatermarkFetcher};
+use crate::watermark::isb::wm_publisher::{ISBWatermarkPublisher, PublishedWatermark};
 use crate::watermark::processor::manager::ProcessorManager;
 use crate::watermark::wmb::{WMB, Watermark};
 
 pub(crate) mod wm_fetcher;
 pub(crate) mod wm_publisher;
 
+/// Interval at which the watermark metrics of the incoming partitions are published.
+const WATERMARK_METRICS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Detailed view of the watermark of the vertex, used to find out which upstream processor is
+/// holding back the watermark.
+#[derive(Debug, Serialize)]
+pub(crate) struct WatermarkDetails {
+    /// Processors of the incoming edges for every partition read by the vertex.
+    pub(crate) from_edges: Vec<EdgeWatermarkDetails>,
+    /// Watermarks published by the vertex for the outgoing edges.
+    pub(crate) to_edges: Vec<PublishedWatermark>,
+}
+
 /// Shared state for ISBWatermarkHandle.
 /// Contains all computation logic and data structures.
 struct ISBWatermarkState {
@@ -261,7 +275,7 @@ impl ISBWatermarkHandle {
         vertex_replica: u16,
         vertex_type: VertexType,
         idle_timeout: Duration,
-        js_context: async_nats::jetstream::Context,
+        isb: ISBService,
         config: &EdgeWatermarkConfig,
         to_vertex_configs: &[ToVertexConfig],
         cln_token: CancellationToken,
@@ -274,7 +288,7 @@ impl ISBWatermarkHandle {
         for from_bucket_config in &config.from_vertex_config {
             // Create KV stores for ProcessorManager
             let (ot_store, hb_store) =
-                Self::create_single_kv_stores(&js_context, from_bucket_config).await;
+                Self::create_single_kv_stores(&isb, from_bucket_config).await?;
 
             let processor_manager = ProcessorManager::new(
                 ot_store,
@@ -290,8 +304,7 @@ impl ISBWatermarkHandle {
             ISBWatermarkFetcher::new(processor_managers, &config.from_vertex_config).await?;
 
         // Create KV stores for the publisher
-        let (ot_stores, hb_stores) =
-            Self::create_kv_stores(&js_context, &config.to_vertex_config).await;
+        let (ot_stores, hb_stores) = Self::create_kv_stores(&isb, &config.to_vertex_config).await?;
 
         let processor_name = format!("{vertex_name}-{vertex_replica}");
         let publisher = ISBWatermarkPublisher::new(
@@ -302,8 +315,7 @@ impl ISBWatermarkHandle {
             false,
         );
 
-        let idle_manager =
-            ISBIdleDetector::new(idle_timeout, to_vertex_configs, js_context.clone()).await;
+        let idle_manager = ISBIdleDetector::new(idle_timeout, to_vertex_configs, isb).await;
 
         let state = Arc::new(Mutex::new(ISBWatermarkState::new(
             fetcher,
@@ -316,10 +328,12 @@ impl ISBWatermarkHandle {
 
         let isb_watermark_handle = Self { state };
 
-        // start a task to keep publishing idle watermarks every idle_timeout
+        // start a task to keep publishing idle watermarks every idle_timeout, and the watermark
+        // metrics every WATERMARK_METRICS_INTERVAL
         tokio::spawn({
             let isb_watermark_handle = isb_watermark_handle.clone();
             let mut interval_ticker = tokio::time::interval(idle_timeout);
+            let mut metrics_ticker = tokio::time::interval(WATERMARK_METRICS_INTERVAL);
             let cln_token = cln_token.clone();
             async move {
                 loop {
@@ -327,6 +341,9 @@ impl ISBWatermarkHandle {
                         _ = interval_ticker.tick() => {
                             isb_watermark_handle.publish_idle_watermark().await;
                         }
+                        _ = metrics_ticker.tick() => {
+                            isb_watermark_handle.publish_watermark_metrics().await;
+                        }
                         _ = cln_token.cancelled() => {
                             break;
                         }
@@ -363,6 +380,22 @@ impl ISBWatermarkHandle {
         state.fetch_head_watermark(from_vertex, partition_idx)
     }
 
+    /// Fetches the detailed view of the watermark of the incoming and outgoing edges. If
+    /// `from_vertex` is provided, only that incoming edge is returned.
+    pub(crate) async fn fetch_watermark_details(
+        &self,
+        from_vertex: Option<&str>,
+        partitions: &[u16],
+    ) -> WatermarkDetails {
+        let state = self.state.lock().await;
+        WatermarkDetails {
+            from_edges: state
+                .fetcher
+                .fetch_watermark_details(from_vertex, partitions),
+            to_edges: state.publisher.last_published(),
+        }
+    }
+
     /// Fetches the head idle WMB for the given partition. Returns the minimum idle WMB across all
     /// processors for the specified partition, but only if all active processors are idle for that
     /// partition.
@@ -391,59 +424,41 @@ impl ISBWatermarkHandle {
         state.publish_idle_watermark().await;
     }
 
+    /// publishes the lag, skew and progress metrics of the watermark of the incoming partitions.
+    async fn publish_watermark_metrics(&self) {
+        let mut state = self.state.lock().await;
+        state.fetcher.publish_metrics();
+    }
+
     /// Helper to create KV stores for a single bucket config.
     /// Returns (ot_store, hb_store) tuple.
     async fn create_single_kv_stores(
-        js_context: &async_nats::jetstream::Context,
+        isb: &ISBService,
         bucket_config: &BucketConfig,
-    ) -> (Arc<dyn KVStore>, Arc<dyn KVStore>) {
-        let ot_bucket = js_context
-            .get_key_value(bucket_config.ot_bucket)
-            .await
-            .expect("Failed to get OT bucket");
-        let ot_store: Arc<dyn KVStore> =
-            Arc::new(JetstreamKVStore::new(ot_bucket, bucket_config.ot_bucket));
-
-        let hb_bucket = js_context
-            .get_key_value(bucket_config.hb_bucket)
-            .await
-            .expect("Failed to get HB bucket");
-        let hb_store: Arc<dyn KVStore> =
-            Arc::new(JetstreamKVStore::new(hb_bucket, bucket_config.hb_bucket));
-
-        (ot_store, hb_store)
+    ) -> Result<(Arc<dyn KVStore>, Arc<dyn KVStore>)> {
+        let ot_store = isb.kv_store(bucket_config.ot_bucket).await?;
+        let hb_store = isb.kv_store(bucket_config.hb_bucket).await?;
+        Ok((ot_store, hb_store))
     }
 
-    /// Helper to create KV stores from bucket configs using the JetStream context.
+    /// Helper to create KV stores from bucket configs on the ISB service.
     /// Returns (ot_stores, hb_stores) tuple.
     async fn create_kv_stores(
-        js_context: &async_nats::jetstream::Context,
+        isb: &ISBService,
         bucket_configs: &[BucketConfig],
-    ) -> (
+    ) -> Result<(
         HashMap<&'static str, Arc<dyn KVStore>>,
         Vec<Arc<dyn KVStore>>,
-    ) {
+    )> {
         let mut ot_stores: HashMap<&'static str, Arc<dyn KVStore>> = HashMap::new();
         let mut hb_stores: Vec<Arc<dyn KVStore>> = Vec::new();
 
         for config in bucket_configs {
-            let ot_bucket = js_context
-                .get_key_value(config.ot_bucket)
-                .await
-                .expect("Failed to get OT bucket");
-            ot_stores.insert(
-                config.vertex,
-                Arc::new(JetstreamKVStore::new(ot_bucket, config.ot_bucket)),
-            );
-
-            let hb_bucket = js_context
-                .get_key_value(config.hb_bucket)
-                .await
-                .expect("Failed to get HB bucket");
-            hb_stores.push(Arc::new(JetstreamKVStore::new(hb_bucket, config.hb_bucket)));
+            ot_stores.insert(config.vertex, isb.kv_store(config.ot_bucket).await?);
+            hb_stores.push(isb.kv_store(config.hb_bucket).await?);
         }
 
-        (ot_stores, hb_stores)
+        Ok((ot_stores, hb_stores))
     }
 }
 
@@ -568,7 +583,7 @@ mod tests {
             0,
             VertexType::MapUDF,
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &edge_config,
             &[ToVertexConfig {
                 name: "to_vertex",
@@ -738,7 +753,7 @@ mod tests {
             0,
             VertexType::MapUDF,
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &edge_config,
             &[ToVertexConfig {
                 name: "from_vertex",
@@ -899,7 +914,7 @@ mod tests {
             0,
             VertexType::MapUDF,
             Duration::from_millis(10), // Set idle timeout to a very short duration
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &edge_config,
             &[ToVertexConfig {
                 name: "to_vertex",
@@ -1012,7 +1027,7 @@ mod tests {
             0,
             VertexType::MapUDF,
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &edge_config,
             &[ToVertexConfig {
                 name: "from_vertex",
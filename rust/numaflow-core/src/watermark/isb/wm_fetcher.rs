@@ -6,10 +6,14 @@
 //! last fetched watermark per partition and returns the smallest watermark among all the last fetched
 //! watermarks across the partitions this is to make sure the watermark is min across all the incoming
 //! partitions.
+use chrono::Utc;
+
 use crate::config::pipeline::watermark::BucketConfig;
 use crate::error::Result;
-use crate::watermark::processor::manager::ProcessorManager;
+use crate::metrics::{pipeline_metrics, watermark_metrics_labels};
+use crate::watermark::processor::manager::{ProcessorDetails, ProcessorManager};
 use crate::watermark::wmb::{WMB, Watermark};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use tracing::info;
@@ -17,6 +21,30 @@ use tracing::info;
 /// Interval for logging watermark summary
 const WATERMARK_LOG_INTERVAL: Duration = Duration::from_secs(60);
 
+/// Watermark of a partition of an incoming edge and the processors publishing to it.
+#[derive(Debug, Serialize)]
+pub(crate) struct PartitionWatermarkDetails {
+    pub(crate) partition: u16,
+    /// Minimum head watermark among the active processors.
+    pub(crate) head_watermark: i64,
+    /// Last watermark fetched for the messages read from the partition.
+    pub(crate) last_processed_watermark: i64,
+    pub(crate) processors: Vec<ProcessorDetails>,
+}
+
+/// Watermark of the partitions of an incoming edge.
+#[derive(Debug, Serialize)]
+pub(crate) struct EdgeWatermarkDetails {
+    pub(crate) from: String,
+    pub(crate) partitions: Vec<PartitionWatermarkDetails>,
+}
+
+/// Last watermark of a partition seen while publishing the metrics, and when it advanced.
+struct ReportedWatermark {
+    watermark: i64,
+    advanced_at: Instant,
+}
+
 /// ISBWatermarkFetcher is the watermark fetcher for the incoming edges.
 pub(crate) struct ISBWatermarkFetcher {
     /// A map of vertex to its ProcessorManager. Each key represents the incoming vertex, it will
@@ -27,6 +55,8 @@ pub(crate) struct ISBWatermarkFetcher {
     last_processed_wm: HashMap<&'static str, HashMap<u16, i64>>,
     /// Last time the watermark summary was logged
     last_log_time: Instant,
+    /// Watermark of each partition of the incoming edges when the metrics were last published.
+    reported_wm: HashMap<(&'static str, u16), ReportedWatermark>,
 }
 
 impl ISBWatermarkFetcher {
@@ -36,12 +66,20 @@ impl ISBWatermarkFetcher {
         bucket_configs: &[BucketConfig],
     ) -> Result<Self> {
         let mut last_processed_wm = HashMap::new();
+        let mut reported_wm = HashMap::new();
 
         // Create a ProcessorManager for each edge.
         for config in bucket_configs {
             let mut processed_wm = HashMap::<u16, i64>::new();
             for partition in config.partitions.iter() {
                 processed_wm.insert(*partition, -1);
+                reported_wm.insert(
+                    (config.vertex, *partition),
+                    ReportedWatermark {
+                        watermark: -1,
+                        advanced_at: Instant::now(),
+                    },
+                );
             }
             last_processed_wm.insert(config.vertex, processed_wm);
         }
@@ -50,6 +88,7 @@ impl ISBWatermarkFetcher {
             processor_managers,
             last_processed_wm,
             last_log_time: Instant::now(),
+            reported_wm,
         })
     }
 
@@ -140,6 +179,134 @@ impl ISBWatermarkFetcher {
         Watermark::from_timestamp_millis(epoch.unwrap_or(-1)).expect("failed to parse time")
     }
 
+    /// Returns the state of the processors of the incoming edges for the given partitions. If
+    /// `from_vertex` is provided, only that edge is returned.
+    pub(crate) fn fetch_watermark_details(
+        &self,
+        from_vertex: Option<&str>,
+        partitions: &[u16],
+    ) -> Vec<EdgeWatermarkDetails> {
+        let mut edges: Vec<EdgeWatermarkDetails> = self
+            .processor_managers
+            .iter()
+            .filter(|(edge, _)| from_vertex.is_none_or(|vertex| vertex == **edge))
+            .map(|(edge, processor_manager)| {
+                let partitions = partitions
+                    .iter()
+                    .map(|partition| {
+                        let min_watermark =
+                            Self::compute_processor_watermark(processor_manager, *partition);
+
+                        let processors = processor_manager
+                            .processors
+                            .read()
+                            .expect("failed to acquire lock");
+                        let mut processors: Vec<ProcessorDetails> = processors
+                            .values()
+                            .map(|processor| {
+                                let is_min = processor.is_active()
+                                    && processor.timelines.get(partition).is_some_and(|timeline| {
+                                        Some(timeline.get_head_watermark()) == min_watermark
+                                    });
+                                processor.details(*partition, is_min)
+                            })
+                            .collect();
+                        processors.sort_by(|a, b| a.name.cmp(&b.name));
+
+                        PartitionWatermarkDetails {
+                            partition: *partition,
+                            head_watermark: min_watermark.unwrap_or(-1),
+                            last_processed_watermark: self
+                                .last_processed_wm
+                                .get(edge)
+                                .and_then(|wms| wms.get(partition))
+                                .copied()
+                                .unwrap_or(-1),
+                            processors,
+                        }
+                    })
+                    .collect();
+
+                EdgeWatermarkDetails {
+                    from: edge.to_string(),
+                    partitions,
+                }
+            })
+            .collect();
+        edges.sort_by(|a, b| a.from.cmp(&b.from));
+        edges
+    }
+
+    /// Publishes the lag, the processor skew and the time since the watermark last advanced for
+    /// every partition of the incoming edges. Since the watermark is compared with the one seen in
+    /// the previous call, the time since the last advance is only as precise as the interval at
+    /// which this is invoked.
+    pub(crate) fn publish_metrics(&mut self) {
+        let metrics = &pipeline_metrics().watermark;
+        let now = Utc::now().timestamp_millis();
+
+        for (edge, partitions) in &self.last_processed_wm {
+            for (partition, watermark) in partitions {
+                let labels = watermark_metrics_labels(edge, *partition);
+
+                let reported =
+                    self.reported_wm
+                        .entry((*edge, *partition))
+                        .or_insert(ReportedWatermark {
+                            watermark: *watermark,
+                            advanced_at: Instant::now(),
+                        });
+                if *watermark > reported.watermark {
+                    reported.watermark = *watermark;
+                    reported.advanced_at = Instant::now();
+                }
+                metrics
+                    .since_last_advance_ms
+                    .get_or_create(&labels)
+                    .set(reported.advanced_at.elapsed().as_millis() as i64);
+
+                // no watermark has been fetched for the partition yet
+                if *watermark != -1 {
+                    let lag = (now - watermark).max(0);
+                    metrics.lag_ms.get_or_create(&labels).set(lag);
+                    metrics.lag.get_or_create(&labels).observe(lag as f64);
+                }
+
+                if let Some(skew) = self
+                    .processor_managers
+                    .get(edge)
+                    .and_then(|pm| Self::compute_processor_skew(pm, *partition))
+                {
+                    metrics.processor_skew_ms.get_or_create(&labels).set(skew);
+                }
+            }
+        }
+    }
+
+    /// Difference between the highest and the lowest head watermarks of the active processors for
+    /// the given partition.
+    fn compute_processor_skew(
+        processor_manager: &ProcessorManager,
+        partition_idx: u16,
+    ) -> Option<i64> {
+        let processors = processor_manager
+            .processors
+            .read()
+            .expect("failed to acquire lock");
+
+        let head_watermarks = processors
+            .values()
+            .filter(|processor| processor.is_active())
+            .filter_map(|processor| processor.timelines.get(&partition_idx))
+            .map(|timeline| timeline.get_head_watermark())
+            .filter(|head_watermark| *head_watermark != -1);
+
+        let (min, max) = head_watermarks.fold((i64::MAX, i64::MIN), |(min, max), wm| {
+            (min.min(wm), max.max(wm))
+        });
+        if min <= max { Some(max - min) } else { None }
+    }
+
     /// Helper method to compute the minimum watermark across all active processors
     /// for a given processor manager and partition.
     fn compute_processor_watermark(
@@ -1619,4 +1786,152 @@ mod tests {
         let watermark_all = fetcher.fetch_head_watermark(None, 0);
         assert_eq!(watermark_all.timestamp_millis(), 150);
     }
+
+    #[tokio::test]
+    async fn test_fetch_watermark_details() {
+        let wmb = |offset, watermark| WMB {
+            watermark,
+            offset,
+            idle: false,
+            partition: 0,
+        };
+
+        let mut processors = HashMap::new();
+        for (name, status, wmbs) in [
+            ("processor1", Status::Active, vec![wmb(1, 100), wmb(5, 300)]),
+            ("processor2", Status::Active, vec![wmb(2, 150), wmb(4, 200)]),
+            ("processor3", Status::InActive, vec![wmb(3, 50)]),
+        ] {
+            let mut processor = Processor::new(Bytes::from(name), status, &[0]);
+            processor.last_heartbeat = Some(1000);
+            let timeline = processor.timelines.get_mut(&0).unwrap();
+            for wmb in wmbs {
+                timeline.put(wmb);
+            }
+            processors.insert(processor.name.clone(), processor);
+        }
+
+        let mut processor_managers = HashMap::new();
+        processor_managers.insert(
+            "from_vtx",
+            ProcessorManager {
+                processors: Arc::new(RwLock::new(processors)),
+                handles: vec![],
+            },
+        );
+
+        let bucket_config = BucketConfig {
+            vertex: "from_vtx",
+            ot_bucket: "ot_bucket",
+            hb_bucket: "hb_bucket",
+            partitions: vec![0],
+            delay: None,
+        };
+
+        let mut fetcher = ISBWatermarkFetcher::new(processor_managers, &[bucket_config])
+            .await
+            .unwrap();
+        fetcher.fetch_watermark(6, 0);
+
+        let details = fetcher.fetch_watermark_details(None, &[0]);
+        assert_eq!(details.len(), 1);
+        let edge = details.first().unwrap();
+        assert_eq!(edge.from, "from_vtx");
+
+        let partition = edge.partitions.first().unwrap();
+        assert_eq!(partition.partition, 0);
+        // processor3 is not active, processor2 is holding back the watermark
+        assert_eq!(partition.head_watermark, 200);
+        assert_eq!(partition.last_processed_watermark, 50);
+
+        let names: Vec<&str> = partition
+            .processors
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["processor1", "processor2", "processor3"]);
+
+        let processor2 = partition.processors.get(1).unwrap();
+        assert!(processor2.is_min);
+        assert_eq!(processor2.status, Status::Active);
+        assert_eq!(processor2.last_heartbeat, Some(1000));
+        assert_eq!(processor2.head, Some(wmb(4, 200)));
+        assert_eq!(processor2.tail, Some(wmb(2, 150)));
+        assert!(!partition.processors.first().unwrap().is_min);
+        assert!(!partition.processors.get(2).unwrap().is_min);
+
+        // filter by the incoming edge
+        assert!(
+            fetcher
+                .fetch_watermark_details(Some("other_vtx"), &[0])
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_publish_metrics() {
+        let now = Utc::now().timestamp_millis();
+        let wmb = |offset, watermark| WMB {
+            watermark,
+            offset,
+            idle: false,
+            partition: 0,
+        };
+
+        let mut processors = HashMap::new();
+        for (name, status, wmb) in [
+            ("processor1", Status::Active, wmb(1, now - 60_000)),
+            ("processor2", Status::Active, wmb(2, now - 45_000)),
+            ("processor3", Status::InActive, wmb(3, now - 120_000)),
+        ] {
+            let mut processor = Processor::new(Bytes::from(name), status, &[0]);
+            processor.timelines.get_mut(&0).unwrap().put(wmb);
+            processors.insert(processor.name.clone(), processor);
+        }
+
+        let mut processor_managers = HashMap::new();
+        processor_managers.insert(
+            "metrics_vtx",
+            ProcessorManager {
+                processors: Arc::new(RwLock::new(processors)),
+                handles: vec![],
+            },
+        );
+
+        let bucket_config = BucketConfig {
+            vertex: "metrics_vtx",
+            ot_bucket: "ot_bucket",
+            hb_bucket: "hb_bucket",
+            partitions: vec![0, 1],
+            delay: None,
+        };
+
+        let mut fetcher = ISBWatermarkFetcher::new(processor_managers, &[bucket_config])
+            .await
+            .unwrap();
+        fetcher.publish_metrics();
+
+        let metrics = &pipeline_metrics().watermark;
+        let labels = watermark_metrics_labels("metrics_vtx", 0);
+        // no watermark has been fetched yet
+        assert_eq!(metrics.lag_ms.get_or_create(&labels).get(), 0);
+        // only the active processors are considered
+        assert_eq!(
+            metrics.processor_skew_ms.get_or_create(&labels).get(),
+            15_000
+        );
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        fetcher.fetch_watermark(10, 0);
+        fetcher.publish_metrics();
+
+        // the watermark advanced, the inactive processor3 is still considered while fetching
+        let lag = metrics.lag_ms.get_or_create(&labels).get();
+        assert!((120_000..130_000).contains(&lag), "lag {lag}");
+        assert!(metrics.since_last_advance_ms.get_or_create(&labels).get() < 20);
+
+        // the watermark of partition 1 never advanced
+        let labels = watermark_metrics_labels("metrics_vtx", 1);
+        assert!(metrics.since_last_advance_ms.get_or_create(&labels).get() >= 20);
+    }
 }
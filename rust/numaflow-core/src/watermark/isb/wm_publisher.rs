@@ -11,6 +11,7 @@ use std::time::{Instant, UNIX_EPOCH};
 use bytes::{Bytes, BytesMut};
 use numaflow_shared::kv::KVStore;
 use prost::Message;
+use serde::Serialize;
 use tracing::{debug, error, info, warn};
 
 use crate::config::pipeline::isb::Stream;
@@ -26,6 +27,8 @@ const DEFAULT_POD_HEARTBEAT_INTERVAL: u16 = 5;
 struct LastPublishedState {
     offset: i64,
     watermark: i64,
+    /// Whether the last watermark was published for an idle partition.
+    idle: bool,
     last_published_time: Instant,
     delay: Option<Duration>,
 }
@@ -35,6 +38,7 @@ impl Default for LastPublishedState {
         LastPublishedState {
             offset: -1,
             watermark: -1,
+            idle: false,
             last_published_time: Instant::now(),
             delay: None,
         }
@@ -52,6 +56,16 @@ impl LastPublishedState {
     }
 }
 
+/// Last watermark published by this processor for a partition of an outgoing edge.
+#[derive(Debug, Serialize)]
+pub(crate) struct PublishedWatermark {
+    pub(crate) to: String,
+    pub(crate) partition: u16,
+    pub(crate) offset: i64,
+    pub(crate) watermark: i64,
+    pub(crate) idle: bool,
+}
+
 /// ISBWatermarkPublisher is the watermark publisher for the outgoing edges.
 pub(crate) struct ISBWatermarkPublisher {
     /// name of the processor(node) that is publishing the watermark.
@@ -148,6 +162,57 @@ impl ISBWatermarkPublisher {
         }
     }
 
+    /// Restores the last published watermarks of this processor from the OT stores. It is used when
+    /// a processing entity moves from another pod (e.g., a source partition after a rebalance) so
+    /// that the new owner does not publish a watermark lower than what has been published before.
+    pub(crate) async fn restore_last_published(&mut self) {
+        for (vertex, ot_store) in self.ot_stores.iter() {
+            let wmb = match ot_store.get(&self.processor_name).await {
+                Ok(Some(bytes)) => match WMB::try_from(bytes) {
+                    Ok(wmb) => wmb,
+                    Err(e) => {
+                        warn!(?e, processor = ?self.processor_name, "Failed to decode wmb, not restoring");
+                        continue;
+                    }
+                },
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(?e, processor = ?self.processor_name, "Failed to read wmb from ot store, not restoring");
+                    continue;
+                }
+            };
+            if let Some(last_state) = self
+                .last_published_wm
+                .get_mut(vertex)
+                .and_then(|partitions| partitions.get_mut(&wmb.partition))
+            {
+                last_state.watermark = last_state.watermark.max(wmb.watermark);
+                info!(processor = ?self.processor_name, watermark = ?last_state.watermark, "Restored last published watermark");
+            }
+        }
+    }
+
+    /// Returns the last published watermark for every partition of the outgoing edges.
+    pub(crate) fn last_published(&self) -> Vec<PublishedWatermark> {
+        let mut published: Vec<PublishedWatermark> = self
+            .last_published_wm
+            .iter()
+            .flat_map(|(vertex, partitions)| {
+                partitions
+                    .iter()
+                    .map(|(partition, state)| PublishedWatermark {
+                        to: vertex.to_string(),
+                        partition: *partition,
+                        offset: state.offset,
+                        watermark: state.watermark,
+                        idle: state.idle,
+                    })
+            })
+            .collect();
+        published.sort_by(|a, b| (&a.to, a.partition).cmp(&(&b.to, b.partition)));
+        published
+    }
+
     /// publish_watermark publishes the watermark for the given offset and the stream.
     pub(crate) async fn publish_watermark(
         &mut self,
@@ -184,6 +249,7 @@ impl ISBWatermarkPublisher {
         // if watermark cannot be computed, still we should publish the last known valid WM for the latest offset
         if watermark == last_state.watermark || watermark == -1 {
             last_state.offset = last_state.offset.max(offset);
+            last_state.idle = idle;
             return;
         }
 
@@ -199,6 +265,7 @@ impl ISBWatermarkPublisher {
         // valid offset and watermark, we can update the state
         last_state.offset = offset;
         last_state.watermark = watermark;
+        last_state.idle = idle;
 
         // Update state but skip publishing if delay hasn't passed
         // (users can configure delay to reduce the number of writes to the ot bucket)
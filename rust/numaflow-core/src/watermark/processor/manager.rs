@@ -14,6 +14,7 @@ use futures::StreamExt;
 use numaflow_pb::objects::watermark::Heartbeat;
 use numaflow_shared::kv::{KVStore, KVWatchOp};
 use prost::Message as ProtoMessage;
+use serde::Serialize;
 use tracing::{debug, error, info, warn};
 
 use crate::config::pipeline::VertexType;
@@ -25,7 +26,8 @@ use crate::watermark::wmb::WMB;
 const DEFAULT_PROCESSOR_REFRESH_RATE: u16 = 5;
 
 /// Status of a processor.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub(crate) enum Status {
     InActive,
     Active,
@@ -41,6 +43,24 @@ pub(crate) struct Processor {
     pub(crate) status: Status,
     /// OffsetTimeline for each partition.
     pub(crate) timelines: HashMap<u16, OffsetTimeline>,
+    /// Last heartbeat (epoch seconds) received from the processor.
+    pub(crate) last_heartbeat: Option<i64>,
+}
+
+/// State of a processor for a partition, exposed for introspecting the watermark.
+#[derive(Debug, Serialize)]
+pub(crate) struct ProcessorDetails {
+    pub(crate) name: String,
+    pub(crate) status: Status,
+    /// Last heartbeat in epoch seconds.
+    pub(crate) last_heartbeat: Option<i64>,
+    /// Latest [WMB] of the [OffsetTimeline].
+    pub(crate) head: Option<WMB>,
+    /// Oldest [WMB] retained in the [OffsetTimeline].
+    pub(crate) tail: Option<WMB>,
+    /// Whether the head watermark of the processor is the minimum among the active processors, i.e.,
+    /// it is the one holding back the watermark of the partition.
+    pub(crate) is_min: bool,
 }
 
 impl Debug for Processor {
@@ -84,6 +104,22 @@ impl Processor {
             name,
             status,
             timelines,
+            last_heartbeat: None,
+        }
+    }
+
+    /// Returns the state of the processor for the given partition.
+    pub(crate) fn details(&self, partition: u16, is_min: bool) -> ProcessorDetails {
+        let timeline = self.timelines.get(&partition);
+        ProcessorDetails {
+            name: String::from_utf8_lossy(&self.name).to_string(),
+            status: self.status.clone(),
+            last_heartbeat: self.last_heartbeat,
+            head: timeline
+                .and_then(OffsetTimeline::get_head_wmb)
+                .filter(|wmb| wmb.watermark != -1),
+            tail: timeline.and_then(OffsetTimeline::get_tail_wmb),
+            is_min,
         }
     }
 
@@ -236,6 +272,9 @@ impl ProcessorManager {
                         .as_secs() as i64,
                 })
                 .heartbeat;
+            if let Some(processor) = processors.get_mut(&processor_name) {
+                processor.last_heartbeat = Some(hb);
+            }
             heartbeats.insert(processor_name, hb);
         }
 
@@ -405,9 +444,12 @@ impl ProcessorManager {
                         if !processor.is_active() {
                             processor.set_status(Status::Active);
                         }
+                        processor.last_heartbeat = Some(hb);
                     } else {
                         info!(processor = ?processor_name, "Processor not found, adding it");
-                        let processor = Processor::new(processor_name, Status::Active, &partitions);
+                        let mut processor =
+                            Processor::new(processor_name, Status::Active, &partitions);
+                        processor.last_heartbeat = Some(hb);
                         processors.insert(processor.name.clone(), processor);
                     }
                 }
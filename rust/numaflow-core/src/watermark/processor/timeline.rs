@@ -102,6 +102,15 @@ impl OffsetTimeline {
         self.watermarks.front().copied()
     }
 
+    /// Returns the oldest WMB in the timeline, None if nothing has been published yet.
+    pub(crate) fn get_tail_wmb(&self) -> Option<WMB> {
+        self.watermarks
+            .iter()
+            .rev()
+            .find(|w| w.watermark != -1)
+            .copied()
+    }
+
     /// GetEventTime returns the event time of the nearest WMB that has an offset less than the input offset.
     pub(crate) fn get_event_time(&self, input_offset: i64) -> i64 {
         self.watermarks
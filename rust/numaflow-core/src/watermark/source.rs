@@ -15,26 +15,25 @@
 //! [Source]: https://numaflow.numaproj.io/user-guide/sources/overview/
 //! [ISB]: https://numaflow.numaproj.io/core-concepts/inter-step-buffer/
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 use tracing::warn;
 
-use numaflow_shared::kv::KVStore;
-use numaflow_shared::kv::jetstream::JetstreamKVStore;
-
 use crate::config::pipeline::isb::Stream;
 use crate::config::pipeline::watermark::SourceWatermarkConfig;
 use crate::config::pipeline::{ToVertexConfig, VertexType};
 use crate::error::Result;
 use crate::message::{IntOffset, Message, Offset};
+use crate::pipeline::isb::ISBService;
 use crate::watermark::idle::isb::ISBIdleDetector;
 use crate::watermark::idle::source::SourceIdleDetector;
 use crate::watermark::processor::manager::ProcessorManager;
 use crate::watermark::source::source_wm_fetcher::SourceWatermarkFetcher;
 use crate::watermark::source::source_wm_publisher::SourceWatermarkPublisher;
+use crate::watermark::source::source_wm_strategy::SourceWatermarkGenerator;
 use crate::watermark::wmb::Watermark;
 
 /// fetcher for fetching the source watermark
@@ -43,14 +42,26 @@ pub(crate) mod source_wm_fetcher;
 /// publisher for publishing the source watermark
 pub(crate) mod source_wm_publisher;
 
+/// strategies for generating the source watermark from the read messages
+pub(crate) mod source_wm_strategy;
+
 /// Shared state for SourceWatermarkHandle.
 /// Contains all computation logic and data structures.
 struct SourceWatermarkState {
     publisher: SourceWatermarkPublisher,
     fetcher: SourceWatermarkFetcher,
+    generator: SourceWatermarkGenerator,
     isb_idle_manager: ISBIdleDetector,
     source_idle_manager: Option<SourceIdleDetector>,
     active_input_partitions: HashMap<u16, bool>,
+    /// Partitions which moved to another pod, the watermarks of their in-flight messages are not
+    /// published since the new owner publishes them.
+    revoked_partitions: HashSet<u16>,
+    /// Event time of the oldest message of each partition which was read but is not forwarded yet,
+    /// e.g., deferred by the per-key rate limit. The watermark of the partition doesn't pass it.
+    holds: HashMap<u16, i64>,
+    /// Watermarks generated above the hold of their partition, published once the hold is lifted.
+    held_watermarks: HashMap<u16, i64>,
 }
 
 impl SourceWatermarkState {
@@ -58,15 +69,35 @@ impl SourceWatermarkState {
     fn new(
         publisher: SourceWatermarkPublisher,
         fetcher: SourceWatermarkFetcher,
+        generator: SourceWatermarkGenerator,
         isb_idle_manager: ISBIdleDetector,
         source_idle_manager: Option<SourceIdleDetector>,
     ) -> Self {
         Self {
             publisher,
             fetcher,
+            generator,
             isb_idle_manager,
             source_idle_manager,
             active_input_partitions: HashMap::new(),
+            revoked_partitions: HashSet::new(),
+            holds: HashMap::new(),
+            held_watermarks: HashMap::new(),
+        }
+    }
+
+    /// Caps the watermark of the partition at its hold, the generated watermark is kept so that it
+    /// can be published once the hold is lifted.
+    fn hold(&mut self, partition: u16, watermark: i64) -> i64 {
+        match self.holds.get(&partition) {
+            Some(&hold) if hold < watermark => {
+                self.held_watermarks.insert(partition, watermark);
+                hold
+            }
+            _ => {
+                self.held_watermarks.remove(&partition);
+                watermark
+            }
         }
     }
 
@@ -75,31 +106,21 @@ impl SourceWatermarkState {
         &mut self,
         messages: Vec<Message>,
     ) -> Result<()> {
-        // we need to build a hash-map of the lowest event time for each partition
-        let partition_to_lowest_event_time =
-            messages.iter().fold(HashMap::new(), |mut acc, message| {
-                let partition_id = match &message.offset {
-                    Offset::Int(offset) => offset.partition_idx,
-                    Offset::String(offset) => offset.partition_idx,
-                };
-
-                let event_time = message.event_time.timestamp_millis();
-
-                let lowest_event_time = acc.entry(partition_id).or_insert(event_time);
-                if event_time < *lowest_event_time {
-                    *lowest_event_time = event_time;
-                }
-                acc
-            });
+        // generate the watermark of each partition using the configured strategy
+        let partition_to_watermark = self.generator.generate(&messages);
 
-        if partition_to_lowest_event_time.is_empty() {
+        if partition_to_watermark.is_empty() {
             return Ok(());
         }
 
         // Publish the watermark for each partition
-        for (partition, event_time) in partition_to_lowest_event_time {
+        for (partition, watermark) in partition_to_watermark {
+            if self.revoked_partitions.contains(&partition) {
+                continue;
+            }
+            let watermark = self.hold(partition, watermark);
             self.publisher
-                .publish_source_watermark(partition, event_time, false)
+                .publish_source_watermark(partition, watermark, false)
                 .await;
 
             // cache the active input partitions, we need it for publishing isb idle watermark
@@ -114,6 +135,22 @@ impl SourceWatermarkState {
         Ok(())
     }
 
+    /// Replaces the holds of the partitions, and publishes the watermarks which were held back by
+    /// the previous holds up to the new ones.
+    async fn hold_source_watermark(&mut self, holds: HashMap<u16, i64>) {
+        self.holds = holds;
+        let held_watermarks: Vec<(u16, i64)> = self.held_watermarks.drain().collect();
+        for (partition, watermark) in held_watermarks {
+            if self.revoked_partitions.contains(&partition) {
+                continue;
+            }
+            let watermark = self.hold(partition, watermark);
+            self.publisher
+                .publish_source_watermark(partition, watermark, false)
+                .await;
+        }
+    }
+
     /// Handles publishing source ISB watermark with computation
     async fn publish_source_isb_watermark(
         &mut self,
@@ -121,6 +158,10 @@ impl SourceWatermarkState {
         offset: IntOffset,
         input_partition: u16,
     ) -> Result<()> {
+        if self.revoked_partitions.contains(&input_partition) {
+            return Ok(());
+        }
+
         // Fetch the source watermark
         let watermark = self.fetcher.fetch_source_watermark();
 
@@ -142,7 +183,12 @@ impl SourceWatermarkState {
     }
 
     /// Handles publishing source idle watermark with computation
-    async fn publish_source_idle_watermark(&mut self, partitions: Vec<u16>) -> Result<()> {
+    async fn publish_source_idle_watermark(&mut self, mut partitions: Vec<u16>) -> Result<()> {
+        // a held partition is not idle, it still has messages to forward.
+        partitions.retain(|partition| {
+            !self.revoked_partitions.contains(partition) && !self.holds.contains_key(partition)
+        });
+
         // First check if source idle manager exists and if source is idling
         let is_source_idling = if let Some(source_idle_manager) = &self.source_idle_manager {
             source_idle_manager.is_source_idling()
@@ -208,6 +254,23 @@ impl SourceWatermarkState {
         Ok(())
     }
 
+    /// Registers the partitions assigned to this pod.
+    async fn assign_partitions(&mut self, partitions: Vec<u16>) {
+        for partition in partitions.iter() {
+            self.revoked_partitions.remove(partition);
+        }
+        self.publisher.register_partitions(&partitions).await;
+    }
+
+    /// Fences the partitions which moved to another pod and removes their publishers.
+    fn revoke_partitions(&mut self, partitions: Vec<u16>) {
+        for partition in partitions.iter() {
+            self.active_input_partitions.remove(partition);
+            self.revoked_partitions.insert(*partition);
+        }
+        self.publisher.deregister_partitions(&partitions);
+    }
+
     /// Handles publishing ISB idle watermark with computation
     async fn publish_isb_idle_watermark(&mut self) -> Result<()> {
         // if source is idling, we can avoid publishing the idle watermark since we publish
@@ -268,29 +331,14 @@ impl SourceWatermarkHandle {
     /// Creates a new SourceWatermarkHandle.
     pub(crate) async fn new(
         idle_timeout: Duration,
-        js_context: async_nats::jetstream::Context,
+        isb: ISBService,
         to_vertex_configs: &[ToVertexConfig],
         config: &SourceWatermarkConfig,
         cln_token: CancellationToken,
     ) -> Result<Self> {
         // Create KV stores for ProcessorManager
-        let ot_bucket = js_context
-            .get_key_value(config.source_bucket_config.ot_bucket)
-            .await
-            .expect("Failed to get OT bucket");
-        let ot_store: Arc<dyn KVStore> = Arc::new(JetstreamKVStore::new(
-            ot_bucket,
-            config.source_bucket_config.ot_bucket,
-        ));
-
-        let hb_bucket = js_context
-            .get_key_value(config.source_bucket_config.hb_bucket)
-            .await
-            .expect("Failed to get HB bucket");
-        let hb_store: Arc<dyn KVStore> = Arc::new(JetstreamKVStore::new(
-            hb_bucket,
-            config.source_bucket_config.hb_bucket,
-        ));
+        let ot_store = isb.kv_store(config.source_bucket_config.ot_bucket).await?;
+        let hb_store = isb.kv_store(config.source_bucket_config.hb_bucket).await?;
 
         let processor_manager = ProcessorManager::new(
             ot_store,
@@ -303,7 +351,7 @@ impl SourceWatermarkHandle {
 
         let fetcher = SourceWatermarkFetcher::new(processor_manager);
         let publisher = SourceWatermarkPublisher::new(
-            js_context.clone(),
+            isb.clone(),
             config.max_delay,
             config.source_bucket_config.clone(),
             config.to_vertex_bucket_config.clone(),
@@ -315,11 +363,15 @@ impl SourceWatermarkHandle {
             .as_ref()
             .map(|idle_config| SourceIdleDetector::new(idle_config.clone()));
 
-        let isb_idle_manager =
-            ISBIdleDetector::new(idle_timeout, to_vertex_configs, js_context.clone()).await;
+        let isb_idle_manager = ISBIdleDetector::new(idle_timeout, to_vertex_configs, isb).await;
 
-        let state =
-            SourceWatermarkState::new(publisher, fetcher, isb_idle_manager, source_idle_manager);
+        let state = SourceWatermarkState::new(
+            publisher,
+            fetcher,
+            SourceWatermarkGenerator::new(&config.strategy),
+            isb_idle_manager,
+            source_idle_manager,
+        );
 
         let source_watermark_handle = Self {
             state: Arc::new(Mutex::new(state)),
@@ -361,6 +413,14 @@ impl SourceWatermarkHandle {
         }
     }
 
+    /// Holds the watermark of the partitions at the given event times (in milliseconds), until the
+    /// next call. Used when the read messages are not forwarded right away, so that the watermark
+    /// doesn't pass them. The partitions without a hold are released.
+    pub(crate) async fn hold_source_watermark(&self, holds: HashMap<u16, i64>) {
+        let mut state = self.state.lock().await;
+        state.hold_source_watermark(holds).await;
+    }
+
     /// Publishes the watermark for the given input partition on to the ISB of the next vertex.
     pub(crate) async fn publish_source_isb_watermark(
         &self,
@@ -427,6 +487,21 @@ impl SourceWatermarkHandle {
         }
     }
 
+    /// Registers the watermark publishers of the partitions assigned to this pod, for sources
+    /// whose partitions move between the pods, e.g., after a Kafka consumer group rebalance.
+    pub(crate) async fn assign_partitions(&self, partitions: Vec<u16>) {
+        let mut state = self.state.lock().await;
+        state.assign_partitions(partitions).await;
+    }
+
+    /// Deregisters the watermark publishers of the partitions revoked from this pod. The watermarks
+    /// of their in-flight messages are not published anymore, so that a slow revoked partition
+    /// cannot interfere with the watermark published by its new owner.
+    pub(crate) async fn revoke_partitions(&self, partitions: Vec<u16>) {
+        let mut state = self.state.lock().await;
+        state.revoke_partitions(partitions);
+    }
+
     /// Initializes the active partitions by creating a publisher for each partition.
     pub(crate) async fn initialize_active_partitions(&self, partitions: Vec<u16>) {
         // Acquire lock, perform operation, and release immediately
@@ -467,6 +542,7 @@ mod tests {
 
         let source_config = SourceWatermarkConfig {
             max_delay: Default::default(),
+            strategy: Default::default(),
             source_bucket_config: BucketConfig {
                 vertex: "source_vertex",
                 partitions: vec![0], // partitions is always vec![0] for source
@@ -499,7 +575,7 @@ mod tests {
 
         let handle = SourceWatermarkHandle::new(
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Default::default(),
             &source_config,
             CancellationToken::new(),
@@ -598,6 +674,7 @@ mod tests {
 
         let source_config = SourceWatermarkConfig {
             max_delay: Default::default(),
+            strategy: Default::default(),
             source_bucket_config: BucketConfig {
                 vertex: "source_vertex",
                 partitions: vec![0, 1],
@@ -653,7 +730,7 @@ mod tests {
 
         let handle = SourceWatermarkHandle::new(
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &[ToVertexConfig {
                 name: "edge_vertex",
                 writer_config: BufferWriterConfig {
@@ -856,10 +933,11 @@ mod tests {
 
         let handle = SourceWatermarkHandle::new(
             Duration::from_millis(10),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             &to_vertex_configs,
             &SourceWatermarkConfig {
                 max_delay: Default::default(),
+                strategy: Default::default(),
                 source_bucket_config,
                 to_vertex_bucket_config: vec![to_vertex_bucket_config],
                 idle_config: Some(source_idle_config),
@@ -1054,6 +1132,7 @@ mod tests {
             &to_vertex_configs,
             &SourceWatermarkConfig {
                 max_delay: Default::default(),
+                strategy: Default::default(),
                 source_bucket_config,
                 to_vertex_bucket_config: vec![to_vertex_bucket_config],
                 idle_config: Some(source_idle_config),
@@ -1152,6 +1231,7 @@ mod tests {
 
         let source_config = SourceWatermarkConfig {
             max_delay: Default::default(),
+            strategy: Default::default(),
             source_bucket_config: BucketConfig {
                 vertex: "source_vertex",
                 partitions: vec![0],
@@ -1230,7 +1310,7 @@ mod tests {
 
         let handle = SourceWatermarkHandle::new(
             Duration::from_millis(100),
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Default::default(),
             &source_config,
             CancellationToken::new(),
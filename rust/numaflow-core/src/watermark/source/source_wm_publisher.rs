@@ -12,13 +12,13 @@ use tracing::info;
 use crate::config::pipeline::isb::Stream;
 use crate::config::pipeline::watermark::BucketConfig;
 use crate::error;
+use crate::pipeline::isb::ISBService;
 use crate::watermark::isb::wm_publisher::ISBWatermarkPublisher;
 use numaflow_shared::kv::KVStore;
-use numaflow_shared::kv::jetstream::JetstreamKVStore;
 
 /// SourcePublisher is the watermark publisher for the source vertex.
 pub(crate) struct SourceWatermarkPublisher {
-    js_context: async_nats::jetstream::Context,
+    isb: ISBService,
     max_delay: Duration,
     source_config: BucketConfig,
     to_vertex_configs: Vec<BucketConfig>,
@@ -28,13 +28,13 @@ pub(crate) struct SourceWatermarkPublisher {
 impl SourceWatermarkPublisher {
     /// Creates a new [SourceWatermarkPublisher].
     pub(crate) async fn new(
-        js_context: async_nats::jetstream::Context,
+        isb: ISBService,
         max_delay: Duration,
         source_config: BucketConfig,
         to_vertex_configs: Vec<BucketConfig>,
     ) -> error::Result<Self> {
         Ok(SourceWatermarkPublisher {
-            js_context,
+            isb,
             max_delay,
             source_config,
             to_vertex_configs,
@@ -42,10 +42,10 @@ impl SourceWatermarkPublisher {
         })
     }
 
-    /// Helper to create KV stores from bucket configs using the JetStream context.
+    /// Helper to create KV stores from bucket configs on the ISB service.
     /// Returns (ot_stores, hb_stores) tuple.
     async fn create_kv_stores(
-        js_context: &async_nats::jetstream::Context,
+        isb: &ISBService,
         bucket_configs: &[BucketConfig],
     ) -> (
         HashMap<&'static str, Arc<dyn KVStore>>,
@@ -55,42 +55,38 @@ impl SourceWatermarkPublisher {
         let mut hb_stores: Vec<Arc<dyn KVStore>> = Vec::new();
 
         for config in bucket_configs {
-            let ot_bucket = js_context
-                .get_key_value(config.ot_bucket)
+            let ot_store = isb
+                .kv_store(config.ot_bucket)
                 .await
                 .expect("Failed to get OT bucket");
-            ot_stores.insert(
-                config.vertex,
-                Arc::new(JetstreamKVStore::new(ot_bucket, config.ot_bucket)),
-            );
+            ot_stores.insert(config.vertex, ot_store);
 
-            let hb_bucket = js_context
-                .get_key_value(config.hb_bucket)
+            let hb_store = isb
+                .kv_store(config.hb_bucket)
                 .await
                 .expect("Failed to get HB bucket");
-            hb_stores.push(Arc::new(JetstreamKVStore::new(hb_bucket, config.hb_bucket)));
+            hb_stores.push(hb_store);
         }
 
         (ot_stores, hb_stores)
     }
 
-    /// Publishes the source watermark for the input partition. It internally uses edge publisher
-    /// with processor set to the input partition and source OT.
-    pub(crate) async fn publish_source_watermark(
-        &mut self,
-        partition: u16,
-        mut watermark: i64,
-        idle: bool,
-    ) {
-        // for source, we do partition-based watermark publishing rather than pod-based, hence
-        // the processing entity is the partition itself. We create a publisher for each partition
-        // and publish the watermark to it.
-        let processor_name = format!("source-{}-{}", self.source_config.vertex, partition);
-        // create a publisher if not exists
+    /// Name of the processor publishing the source watermark of the input partition.
+    fn source_processor_name(&self, partition: u16) -> String {
+        format!("source-{}-{}", self.source_config.vertex, partition)
+    }
+
+    /// Name of the processor publishing the ISB watermark of the input partition.
+    fn isb_processor_name(&self, partition: u16) -> String {
+        format!("{}-{}", self.source_config.vertex, partition)
+    }
+
+    /// Creates the publisher for the source watermark of the input partition if it does not exist.
+    async fn create_source_publisher(&mut self, partition: u16) -> &mut ISBWatermarkPublisher {
+        let processor_name = self.source_processor_name(partition);
         if !self.publishers.contains_key(&processor_name) {
             let (ot_stores, hb_stores) =
-                Self::create_kv_stores(&self.js_context, std::slice::from_ref(&self.source_config))
-                    .await;
+                Self::create_kv_stores(&self.isb, std::slice::from_ref(&self.source_config)).await;
 
             let publisher = ISBWatermarkPublisher::new(
                 processor_name.clone(),
@@ -104,20 +100,59 @@ impl SourceWatermarkPublisher {
             );
             self.publishers.insert(processor_name.clone(), publisher);
         }
+        self.publishers
+            .get_mut(&processor_name)
+            .expect("Publisher not found")
+    }
+
+    /// Creates the publisher for the ISB watermark of the input partition if it does not exist.
+    async fn create_isb_publisher(&mut self, partition: u16) -> &mut ISBWatermarkPublisher {
+        let processor_name = self.isb_processor_name(partition);
+        if !self.publishers.contains_key(&processor_name) {
+            info!(processor = ?processor_name, partition = ?partition,
+                "Creating new publisher for ISB"
+            );
+            let (ot_stores, hb_stores) =
+                Self::create_kv_stores(&self.isb, &self.to_vertex_configs).await;
+
+            let publisher = ISBWatermarkPublisher::new(
+                processor_name.clone(),
+                ot_stores,
+                hb_stores,
+                &self.to_vertex_configs,
+                true,
+            );
+            self.publishers.insert(processor_name.clone(), publisher);
+        }
+        self.publishers
+            .get_mut(&processor_name)
+            .expect("Publisher not found")
+    }
 
+    /// Publishes the source watermark for the input partition. It internally uses edge publisher
+    /// with processor set to the input partition and source OT.
+    pub(crate) async fn publish_source_watermark(
+        &mut self,
+        partition: u16,
+        mut watermark: i64,
+        idle: bool,
+    ) {
         // subtract the max delay from the watermark, since we are publishing from source itself
         // if the watermark is not idle.
         if !idle && watermark != -1 {
             watermark -= self.max_delay.as_millis() as i64
         };
 
-        self.publishers
-            .get_mut(&processor_name)
-            .expect("Publisher not found")
+        let vertex = self.source_config.vertex;
+        // for source, we do partition-based watermark publishing rather than pod-based, hence
+        // the processing entity is the partition itself. We create a publisher for each partition
+        // and publish the watermark to it.
+        self.create_source_publisher(partition)
+            .await
             .publish_watermark(
                 &Stream {
                     name: "source",
-                    vertex: self.source_config.vertex,
+                    vertex,
                     // in source, input partition is considered as a separate processor entity and this
                     // partition represents the isb partition.
                     // Since source has publish/fetch cycle, in the publish we have to associate the
@@ -143,29 +178,10 @@ impl SourceWatermarkPublisher {
         watermark: i64,
         idle: bool,
     ) {
-        let processor_name = format!("{}-{}", self.source_config.vertex, input_partition);
         // In source, since we do partition-based watermark publishing rather than pod-based, we
         // create a publisher for each partition and publish the watermark to it.
-        if !self.publishers.contains_key(&processor_name) {
-            info!(processor = ?processor_name, partition = ?input_partition,
-                "Creating new publisher for ISB"
-            );
-            let (ot_stores, hb_stores) =
-                Self::create_kv_stores(&self.js_context, &self.to_vertex_configs).await;
-
-            let publisher = ISBWatermarkPublisher::new(
-                processor_name.clone(),
-                ot_stores,
-                hb_stores,
-                &self.to_vertex_configs,
-                true,
-            );
-            self.publishers.insert(processor_name.clone(), publisher);
-        }
-
-        self.publishers
-            .get_mut(&processor_name)
-            .expect("Publisher not found")
+        self.create_isb_publisher(input_partition)
+            .await
             .publish_watermark(stream, offset, watermark, idle)
             .await;
     }
@@ -173,20 +189,35 @@ impl SourceWatermarkPublisher {
     /// Initializes the active partitions by creating a publisher for each partition.
     pub(crate) async fn initialize_active_partitions(&mut self, active_partitions: Vec<u16>) {
         for partition in active_partitions {
-            let processor_name = format!("{}-{}", self.source_config.vertex, partition);
-            if !self.publishers.contains_key(&processor_name) {
-                let (ot_stores, hb_stores) =
-                    Self::create_kv_stores(&self.js_context, &self.to_vertex_configs).await;
-
-                let publisher = ISBWatermarkPublisher::new(
-                    processor_name.clone(),
-                    ot_stores,
-                    hb_stores,
-                    &self.to_vertex_configs,
-                    true,
-                );
-                self.publishers.insert(processor_name.clone(), publisher);
-            }
+            self.create_isb_publisher(partition).await;
+        }
+    }
+
+    /// Registers the publishers of the partitions assigned to this pod, e.g., after a rebalance of
+    /// the Kafka consumer group. The partition may have been owned by another pod before, the last
+    /// published watermarks are restored so that the watermark of the partition does not regress.
+    pub(crate) async fn register_partitions(&mut self, partitions: &[u16]) {
+        for partition in partitions {
+            self.create_source_publisher(*partition)
+                .await
+                .restore_last_published()
+                .await;
+            self.create_isb_publisher(*partition)
+                .await
+                .restore_last_published()
+                .await;
+        }
+    }
+
+    /// Deregisters the publishers of the partitions which are no longer owned by this pod. Dropping
+    /// the publisher stops its heartbeats, so that only the new owner of the partition keeps the
+    /// processor alive.
+    pub(crate) fn deregister_partitions(&mut self, partitions: &[u16]) {
+        for partition in partitions {
+            info!(partition = ?partition, "Removing publishers of revoked partition");
+            self.publishers
+                .remove(&self.source_processor_name(*partition));
+            self.publishers.remove(&self.isb_processor_name(*partition));
         }
     }
 }
@@ -199,6 +230,7 @@ mod tests {
     use async_nats::jetstream::kv::Config;
 
     use crate::config::pipeline::isb::Stream;
+    use crate::pipeline::isb::ISBService;
     use crate::watermark::source::source_wm_publisher::{BucketConfig, SourceWatermarkPublisher};
     use crate::watermark::wmb::WMB;
 
@@ -239,7 +271,7 @@ mod tests {
             .unwrap();
 
         let mut source_publisher = SourceWatermarkPublisher::new(
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Duration::from_secs(0),
             source_config.clone(),
             vec![],
@@ -341,7 +373,7 @@ mod tests {
             .unwrap();
 
         let mut source_publisher = SourceWatermarkPublisher::new(
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Duration::from_secs(0),
             source_config.clone(),
             vec![edge_config.clone()],
@@ -431,7 +463,7 @@ mod tests {
             .unwrap();
 
         let mut source_publisher = SourceWatermarkPublisher::new(
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Duration::from_secs(0),
             source_config.clone(),
             vec![],
@@ -534,7 +566,7 @@ mod tests {
             .unwrap();
 
         let mut source_publisher = SourceWatermarkPublisher::new(
-            js_context.clone(),
+            ISBService::JetStream(js_context.clone()),
             Duration::from_secs(0),
             source_config.clone(),
             vec![edge_config.clone()],
@@ -587,4 +619,82 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_partition_moves_between_publishers() {
+        let client = async_nats::connect("localhost:4222").await.unwrap();
+        let js_context = jetstream::new(client);
+
+        let ot_bucket_name = "source_partition_move_OT";
+        let hb_bucket_name = "source_partition_move_PROCESSORS";
+
+        let source_config = BucketConfig {
+            vertex: "source_vertex",
+            partitions: vec![0],
+            ot_bucket: ot_bucket_name,
+            hb_bucket: hb_bucket_name,
+            delay: None,
+        };
+
+        for bucket in [ot_bucket_name, hb_bucket_name] {
+            js_context
+                .create_key_value(Config {
+                    bucket: bucket.to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        let ot_bucket = js_context.get_key_value(ot_bucket_name).await.unwrap();
+        let latest_watermark = || async {
+            let wmb: WMB = ot_bucket
+                .get("source-source_vertex-0")
+                .await
+                .unwrap()
+                .unwrap()
+                .try_into()
+                .unwrap();
+            wmb.watermark
+        };
+
+        // the partition is owned by the first pod
+        let mut first = SourceWatermarkPublisher::new(
+            ISBService::JetStream(js_context.clone()),
+            Duration::from_secs(0),
+            source_config.clone(),
+            vec![],
+        )
+        .await
+        .unwrap();
+        first.register_partitions(&[0]).await;
+        first.publish_source_watermark(0, 200, false).await;
+        assert_eq!(latest_watermark().await, 200);
+        first.deregister_partitions(&[0]);
+        assert!(first.publishers.is_empty());
+
+        // and moves to the second pod, which replays older messages
+        let mut second = SourceWatermarkPublisher::new(
+            ISBService::JetStream(js_context.clone()),
+            Duration::from_secs(0),
+            source_config,
+            vec![],
+        )
+        .await
+        .unwrap();
+        second.register_partitions(&[0]).await;
+        second.publish_source_watermark(0, 100, false).await;
+        assert_eq!(latest_watermark().await, 200);
+        second.publish_source_watermark(0, 300, false).await;
+        assert_eq!(latest_watermark().await, 300);
+
+        for bucket in [ot_bucket_name, hb_bucket_name] {
+            js_context
+                .delete_key_value(bucket.to_string())
+                .await
+                .unwrap();
+        }
+    }
 }
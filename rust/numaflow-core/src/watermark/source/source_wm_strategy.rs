@@ -0,0 +1,349 @@
+//! Generates the source watermark of the partitions from the messages read from them, using the
+//! configured [SourceWatermarkStrategy]. The generated watermarks are published through the
+//! [SourceWatermarkPublisher], which also subtracts the max delay and skips the watermarks which
+//! would regress.
+//!
+//! The heuristic strategies bound the out-of-orderness of the source, i.e., the watermark of a
+//! partition is the highest event time read from it minus a delay learned from how late the
+//! messages arrive. The messages behind the watermark are late messages.
+//!
+//! [SourceWatermarkPublisher]: crate::watermark::source::source_wm_publisher::SourceWatermarkPublisher
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::config::pipeline::watermark::SourceWatermarkStrategy;
+use crate::message::{Message, Offset};
+
+/// Maximum number of out-of-orderness samples kept by the percentile strategy, the oldest samples
+/// are evicted first.
+const MAX_PERCENTILE_SAMPLES: usize = 10_000;
+
+/// Generates the watermark of the partitions of the read messages.
+pub(crate) enum SourceWatermarkGenerator {
+    MinEventTime,
+    Percentile(PercentileGenerator),
+    Adaptive(AdaptiveGenerator),
+    Punctuation(PunctuationGenerator),
+}
+
+impl SourceWatermarkGenerator {
+    pub(crate) fn new(strategy: &SourceWatermarkStrategy) -> Self {
+        match strategy {
+            SourceWatermarkStrategy::MinEventTime => Self::MinEventTime,
+            SourceWatermarkStrategy::Percentile { percentile, window } => {
+                Self::Percentile(PercentileGenerator {
+                    percentile: *percentile,
+                    window: *window,
+                    max_event_times: HashMap::new(),
+                    samples: VecDeque::new(),
+                })
+            }
+            SourceWatermarkStrategy::Adaptive {
+                half_life,
+                max_delay,
+            } => Self::Adaptive(AdaptiveGenerator {
+                half_life: *half_life,
+                max_delay: max_delay.map(|d| d.as_millis() as f64),
+                partitions: HashMap::new(),
+            }),
+            SourceWatermarkStrategy::Punctuation { header } => {
+                Self::Punctuation(PunctuationGenerator {
+                    header: header.clone(),
+                })
+            }
+        }
+    }
+
+    /// Returns the watermark of every partition of the messages. The partitions which should not
+    /// advance their watermark (no punctuation) are left out.
+    pub(crate) fn generate(&mut self, messages: &[Message]) -> HashMap<u16, i64> {
+        match self {
+            Self::MinEventTime => min_event_times(messages),
+            Self::Percentile(generator) => generator.generate(messages, Instant::now()),
+            Self::Adaptive(generator) => generator.generate(messages, Instant::now()),
+            Self::Punctuation(generator) => generator.generate(messages),
+        }
+    }
+}
+
+fn partition_of(message: &Message) -> u16 {
+    match &message.offset {
+        Offset::Int(offset) => offset.partition_idx,
+        Offset::String(offset) => offset.partition_idx,
+    }
+}
+
+/// Lowest event time of the messages of each partition.
+fn min_event_times(messages: &[Message]) -> HashMap<u16, i64> {
+    messages.iter().fold(HashMap::new(), |mut acc, message| {
+        let event_time = message.event_time.timestamp_millis();
+        let lowest_event_time = acc.entry(partition_of(message)).or_insert(event_time);
+        if event_time < *lowest_event_time {
+            *lowest_event_time = event_time;
+        }
+        acc
+    })
+}
+
+/// Holds back the watermark by a percentile of how far behind the highest event time of their
+/// partition the messages read in the sliding window were.
+pub(crate) struct PercentileGenerator {
+    percentile: u8,
+    window: Duration,
+    /// Highest event time read from each partition.
+    max_event_times: HashMap<u16, i64>,
+    /// Out-of-orderness of the messages read in the window, with the time they were read.
+    samples: VecDeque<(Instant, i64)>,
+}
+
+impl PercentileGenerator {
+    fn generate(&mut self, messages: &[Message], now: Instant) -> HashMap<u16, i64> {
+        let mut partitions = Vec::new();
+        for message in messages {
+            let partition = partition_of(message);
+            let event_time = message.event_time.timestamp_millis();
+
+            let max_event_time = self.max_event_times.entry(partition).or_insert(event_time);
+            if !partitions.contains(&partition) {
+                partitions.push(partition);
+            }
+
+            self.samples
+                .push_back((now, (*max_event_time - event_time).max(0)));
+            *max_event_time = (*max_event_time).max(event_time);
+        }
+
+        while self.samples.len() > MAX_PERCENTILE_SAMPLES
+            || self
+                .samples
+                .front()
+                .is_some_and(|(read_at, _)| now.duration_since(*read_at) > self.window)
+        {
+            self.samples.pop_front();
+        }
+
+        let delay = self.delay();
+        partitions
+            .into_iter()
+            .filter_map(|partition| {
+                self.max_event_times
+                    .get(&partition)
+                    .map(|max_event_time| (partition, max_event_time - delay))
+            })
+            .collect()
+    }
+
+    /// The configured percentile of the out-of-orderness samples (nearest-rank).
+    fn delay(&self) -> i64 {
+        if self.samples.is_empty() {
+            return 0;
+        }
+        let mut samples: Vec<i64> = self.samples.iter().map(|(_, sample)| *sample).collect();
+        samples.sort_unstable();
+
+        let rank = (self.percentile as usize * samples.len()).div_ceil(100);
+        samples
+            .get(rank.saturating_sub(1))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Delay of a partition of the adaptive strategy.
+struct AdaptivePartition {
+    max_event_time: i64,
+    /// Delay in milliseconds, kept as float so that it decays smoothly.
+    delay: f64,
+    decayed_at: Instant,
+}
+
+/// Holds back the watermark of each partition by a delay which jumps to the lateness of the late
+/// messages of the partition, and halves every half life while the messages arrive in order.
+pub(crate) struct AdaptiveGenerator {
+    half_life: Duration,
+    /// Upper bound of the delay in milliseconds, so that a single very late message does not stall
+    /// the watermark.
+    max_delay: Option<f64>,
+    partitions: HashMap<u16, AdaptivePartition>,
+}
+
+impl AdaptiveGenerator {
+    fn generate(&mut self, messages: &[Message], now: Instant) -> HashMap<u16, i64> {
+        let mut watermarks = HashMap::new();
+        for message in messages {
+            let partition_idx = partition_of(message);
+            let event_time = message.event_time.timestamp_millis();
+
+            let partition =
+                self.partitions
+                    .entry(partition_idx)
+                    .or_insert_with(|| AdaptivePartition {
+                        max_event_time: event_time,
+                        delay: 0.0,
+                        decayed_at: now,
+                    });
+
+            // decay once per batch, before looking at the lateness of its messages
+            if !watermarks.contains_key(&partition_idx) {
+                let elapsed = now.duration_since(partition.decayed_at);
+                partition.delay *=
+                    0.5_f64.powf(elapsed.as_secs_f64() / self.half_life.as_secs_f64());
+                partition.decayed_at = now;
+            }
+
+            let lateness = (partition.max_event_time - event_time) as f64;
+            if lateness > partition.delay {
+                partition.delay = lateness;
+            }
+            if let Some(max_delay) = self.max_delay {
+                partition.delay = partition.delay.min(max_delay);
+            }
+            partition.max_event_time = partition.max_event_time.max(event_time);
+
+            watermarks.insert(
+                partition_idx,
+                partition.max_event_time - partition.delay.round() as i64,
+            );
+        }
+        watermarks
+    }
+}
+
+/// Advances the watermark of a partition only on the messages with the marker header, e.g., the
+/// end of a batch set by a user-defined source. The value of the header is the new watermark in
+/// epoch milliseconds, the event time of the message is used if it is not a number.
+pub(crate) struct PunctuationGenerator {
+    header: String,
+}
+
+impl PunctuationGenerator {
+    fn generate(&self, messages: &[Message]) -> HashMap<u16, i64> {
+        messages
+            .iter()
+            .filter_map(|message| {
+                let value = message.headers.get(&self.header)?;
+                let watermark = value
+                    .trim()
+                    .parse::<i64>()
+                    .unwrap_or(message.event_time.timestamp_millis());
+                Some((partition_of(message), watermark))
+            })
+            .fold(HashMap::new(), |mut acc, (partition, watermark)| {
+                let highest = acc.entry(partition).or_insert(watermark);
+                *highest = (*highest).max(watermark);
+                acc
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::message::IntOffset;
+
+    fn message(partition_idx: u16, event_time: i64) -> Message {
+        Message {
+            offset: Offset::Int(IntOffset {
+                offset: 0,
+                partition_idx,
+            }),
+            event_time: Utc.timestamp_millis_opt(event_time).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_min_event_time() {
+        let mut generator = SourceWatermarkGenerator::new(&SourceWatermarkStrategy::MinEventTime);
+        let watermarks = generator.generate(&[
+            message(0, 300),
+            message(0, 100),
+            message(1, 200),
+            message(0, 200),
+        ]);
+        assert_eq!(watermarks, HashMap::from([(0, 100), (1, 200)]));
+    }
+
+    #[test]
+    fn test_percentile() {
+        let mut generator = SourceWatermarkGenerator::new(&SourceWatermarkStrategy::Percentile {
+            percentile: 90,
+            window: Duration::from_secs(60),
+        });
+
+        // 9 in order messages and one 500ms late, the 90th percentile ignores the outlier
+        let mut messages: Vec<Message> = (1..=9).map(|i| message(0, i * 1000)).collect();
+        messages.push(message(0, 8500));
+        let watermarks = generator.generate(&messages);
+        assert_eq!(watermarks, HashMap::from([(0, 9000)]));
+
+        // with the 99th percentile the watermark is held back by the late message
+        let SourceWatermarkGenerator::Percentile(generator) = &mut generator else {
+            unreachable!()
+        };
+        generator.percentile = 99;
+        let now = Instant::now();
+        let watermarks = generator.generate(&[message(1, 5000)], now);
+        assert_eq!(watermarks, HashMap::from([(1, 4500)]));
+
+        // the samples expire after the window
+        generator.window = Duration::ZERO;
+        let watermarks = generator.generate(&[message(0, 10_000)], now + Duration::from_millis(1));
+        assert_eq!(watermarks, HashMap::from([(0, 10_000)]));
+    }
+
+    #[test]
+    fn test_adaptive() {
+        let SourceWatermarkGenerator::Adaptive(mut generator) =
+            SourceWatermarkGenerator::new(&SourceWatermarkStrategy::Adaptive {
+                half_life: Duration::from_secs(10),
+                max_delay: Some(Duration::from_secs(2)),
+            })
+        else {
+            unreachable!()
+        };
+
+        let now = Instant::now();
+        let watermarks = generator.generate(
+            &[message(0, 10_000), message(0, 9000), message(1, 5000)],
+            now,
+        );
+        // partition 0 is held back by the late message, partition 1 is in order
+        assert_eq!(watermarks, HashMap::from([(0, 9000), (1, 5000)]));
+
+        // the delay halves after the half life
+        let watermarks = generator.generate(&[message(0, 11_000)], now + Duration::from_secs(10));
+        assert_eq!(watermarks, HashMap::from([(0, 10_500)]));
+
+        // the delay is bounded by the max delay
+        let watermarks = generator.generate(&[message(1, 0)], now + Duration::from_secs(10));
+        assert_eq!(watermarks, HashMap::from([(1, 3000)]));
+    }
+
+    #[test]
+    fn test_punctuation() {
+        let mut generator = SourceWatermarkGenerator::new(&SourceWatermarkStrategy::Punctuation {
+            header: "wm".to_string(),
+        });
+
+        let marker = |partition_idx, event_time, value: &str| {
+            let mut message = message(partition_idx, event_time);
+            message.headers = Arc::new(HashMap::from([("wm".to_string(), value.to_string())]));
+            message
+        };
+
+        let watermarks = generator.generate(&[
+            message(0, 100),
+            marker(0, 200, "150"),
+            marker(0, 300, ""),
+            message(1, 400),
+        ]);
+        // partition 1 has no marker, its watermark does not advance
+        assert_eq!(watermarks, HashMap::from([(0, 300)]));
+    }
+}
@@ -1,11 +1,12 @@
 use bytes::{Bytes, BytesMut};
 use chrono::{DateTime, Utc};
 use prost::Message;
+use serde::Serialize;
 
 use crate::error::Error;
 
 /// WMB is the watermark message that is sent by the processor to the downstream.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
 #[allow(clippy::upper_case_acronyms)]
 pub(crate) struct WMB {
     pub(crate) idle: bool,
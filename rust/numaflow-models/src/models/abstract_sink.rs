@@ -20,10 +20,22 @@ limitations under the License.
 pub struct AbstractSink {
     #[serde(rename = "blackhole", skip_serializing_if = "Option::is_none")]
     pub blackhole: Option<Box<crate::models::Blackhole>>,
+    #[serde(rename = "file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<crate::models::FileSink>>,
+    #[serde(rename = "http", skip_serializing_if = "Option::is_none")]
+    pub http: Option<Box<crate::models::HttpSink>>,
+    #[serde(rename = "jetstream", skip_serializing_if = "Option::is_none")]
+    pub jetstream: Option<Box<crate::models::JetStreamSink>>,
     #[serde(rename = "kafka", skip_serializing_if = "Option::is_none")]
     pub kafka: Option<Box<crate::models::KafkaSink>>,
     #[serde(rename = "log", skip_serializing_if = "Option::is_none")]
     pub log: Option<Box<crate::models::Log>>,
+    #[serde(rename = "nats", skip_serializing_if = "Option::is_none")]
+    pub nats: Option<Box<crate::models::NatsSink>>,
+    #[serde(rename = "objectStore", skip_serializing_if = "Option::is_none")]
+    pub object_store: Option<Box<crate::models::ObjectStoreSink>>,
+    #[serde(rename = "postgres", skip_serializing_if = "Option::is_none")]
+    pub postgres: Option<Box<crate::models::PostgresSink>>,
     #[serde(rename = "pulsar", skip_serializing_if = "Option::is_none")]
     pub pulsar: Option<Box<crate::models::PulsarSink>>,
     #[serde(rename = "serve", skip_serializing_if = "Option::is_none")]
@@ -38,8 +50,14 @@ impl AbstractSink {
     pub fn new() -> AbstractSink {
         AbstractSink {
             blackhole: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
             log: None,
+            nats: None,
+            object_store: None,
+            postgres: None,
             pulsar: None,
             serve: None,
             sqs: None,
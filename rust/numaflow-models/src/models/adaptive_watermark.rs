@@ -0,0 +1,37 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// AdaptiveWatermark : AdaptiveWatermark holds back the watermark of every partition by a delay which grows to cover the late messages of the partition and decays while messages arrive in order.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveWatermark {
+    #[serde(rename = "halfLife", skip_serializing_if = "Option::is_none")]
+    pub half_life: Option<kube::core::Duration>,
+    #[serde(rename = "maxDelay", skip_serializing_if = "Option::is_none")]
+    pub max_delay: Option<kube::core::Duration>,
+}
+
+impl AdaptiveWatermark {
+    /// AdaptiveWatermark holds back the watermark of every partition by a delay which grows to cover the late messages of the partition and decays while messages arrive in order.
+    pub fn new() -> AdaptiveWatermark {
+        AdaptiveWatermark {
+            half_life: None,
+            max_delay: None,
+        }
+    }
+}
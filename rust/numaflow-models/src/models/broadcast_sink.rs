@@ -0,0 +1,36 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// BroadcastSink : BroadcastSink writes each message to all of its sinks concurrently.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BroadcastSink {
+    /// Ack specifies when a message is considered written. The possible values are: 1. \"all\": all the sinks have written it, 2. \"quorum\": a majority of the sinks have written it and 3. \"bestEffort\": the failures of the sinks are ignored. The default is all.
+    #[serde(rename = "ack", skip_serializing_if = "Option::is_none")]
+    pub ack: Option<String>,
+    /// Sinks to write the messages to, only builtin sinks are supported.
+    #[serde(rename = "sinks")]
+    pub sinks: Vec<crate::models::AbstractSink>,
+}
+
+impl BroadcastSink {
+    /// BroadcastSink writes each message to all of its sinks concurrently.
+    pub fn new(sinks: Vec<crate::models::AbstractSink>) -> BroadcastSink {
+        BroadcastSink { ack: None, sinks }
+    }
+}
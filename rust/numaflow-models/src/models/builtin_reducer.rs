@@ -0,0 +1,47 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// BuiltinReducer : BuiltinReducer is a reducer which runs within the numa container, so that no user-defined reduce container is required.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuiltinReducer {
+    /// Aggregation of the window, one of count, sum, min, max, distinct or quantiles.
+    #[serde(rename = "aggregation")]
+    pub aggregation: String,
+    /// Field of the JSON payload to aggregate, nested fields are separated by dots. Defaults to the whole payload.
+    #[serde(rename = "field", skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Precision of the HyperLogLog sketch used by distinct, between 4 and 16. Defaults to 14.
+    #[serde(rename = "precision", skip_serializing_if = "Option::is_none")]
+    pub precision: Option<i32>,
+    /// Quantiles computed by quantiles, e.g. \"0.5\", \"0.99\".
+    #[serde(rename = "quantiles", skip_serializing_if = "Option::is_none")]
+    pub quantiles: Option<Vec<String>>,
+}
+
+impl BuiltinReducer {
+    /// BuiltinReducer is a reducer which runs within the numa container, so that no user-defined reduce container is required.
+    pub fn new(aggregation: String) -> BuiltinReducer {
+        BuiltinReducer {
+            aggregation,
+            field: None,
+            precision: None,
+            quantiles: None,
+        }
+    }
+}
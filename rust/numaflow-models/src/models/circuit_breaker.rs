@@ -0,0 +1,49 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// CircuitBreaker : CircuitBreaker stops writing to a sink that keeps failing. It opens when the ratio of the failed writes over a sliding window crosses the threshold, and after the open duration a single probe batch is written (half-open) to decide whether to close it again.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreaker {
+    /// FailureRatio is the ratio of failed writes over the window (0 < ratio <= 1) that opens the circuit. Defaults to 0.5.
+    #[serde(rename = "failureRatio", skip_serializing_if = "Option::is_none")]
+    pub failure_ratio: Option<f64>,
+    /// MinWrites is the minimum number of writes in the window before the failure ratio is evaluated. Defaults to 20.
+    #[serde(rename = "minWrites", skip_serializing_if = "Option::is_none")]
+    pub min_writes: Option<i64>,
+    /// OnOpen specifies what to do with the messages while the circuit is open. The possible values are: 1. \"pause\": stop writing (and hence reading) until the circuit is half-open and 2. \"fallback\": route the messages to the fallback sink. The default action is to pause.
+    #[serde(rename = "onOpen", skip_serializing_if = "Option::is_none")]
+    pub on_open: Option<String>,
+    #[serde(rename = "openDuration", skip_serializing_if = "Option::is_none")]
+    pub open_duration: Option<kube::core::Duration>,
+    #[serde(rename = "window", skip_serializing_if = "Option::is_none")]
+    pub window: Option<kube::core::Duration>,
+}
+
+impl CircuitBreaker {
+    /// CircuitBreaker stops writing to a sink that keeps failing. It opens when the ratio of the failed writes over a sliding window crosses the threshold, and after the open duration a single probe batch is written (half-open) to decide whether to close it again.
+    pub fn new() -> CircuitBreaker {
+        CircuitBreaker {
+            failure_ratio: None,
+            min_writes: None,
+            on_open: None,
+            open_duration: None,
+            window: None,
+        }
+    }
+}
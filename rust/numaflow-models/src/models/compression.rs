@@ -20,6 +20,12 @@ limitations under the License.
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Compression {
+    /// DictionaryBucket is the name of the KV bucket holding the trained zstd dictionaries, keyed by their version. The dictionary with the highest version is used for compressing. Only supported with zstd.
+    #[serde(rename = "dictionaryBucket", skip_serializing_if = "Option::is_none")]
+    pub dictionary_bucket: Option<String>,
+    /// MinSize is the size in bytes below which the messages are written uncompressed.
+    #[serde(rename = "minSize", skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<i64>,
     /// Type is the type of compression to be used
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<String>,
@@ -28,6 +34,10 @@ pub struct Compression {
 impl Compression {
     /// Compression is the compression settings for the messages in the InterStepBuffer
     pub fn new() -> Compression {
-        Compression { r#type: None }
+        Compression {
+            dictionary_bucket: None,
+            min_size: None,
+            r#type: None,
+        }
     }
 }
@@ -0,0 +1,46 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// FileSink : FileSink writes the messages to rotating segment files in a local directory, they can be read back with the file source.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FileSink {
+    /// Format of the segments, \"ndjson\" (default) writes one JSON record per line, \"proto\" writes length-delimited protobuf records.
+    #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(rename = "maxSegmentAge", skip_serializing_if = "Option::is_none")]
+    pub max_segment_age: Option<kube::core::Duration>,
+    /// MaxSegmentSize is the maximum size of a segment in bytes before it is rotated, defaults to 64MiB.
+    #[serde(rename = "maxSegmentSize", skip_serializing_if = "Option::is_none")]
+    pub max_segment_size: Option<i64>,
+    /// Path of the directory to write the segments to.
+    #[serde(rename = "path")]
+    pub path: String,
+}
+
+impl FileSink {
+    /// FileSink writes the messages to rotating segment files in a local directory, they can be read back with the file source.
+    pub fn new(path: String) -> FileSink {
+        FileSink {
+            format: None,
+            max_segment_age: None,
+            max_segment_size: None,
+            path,
+        }
+    }
+}
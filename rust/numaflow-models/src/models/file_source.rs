@@ -0,0 +1,43 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// FileSource : FileSource reads the segment files written by the file sink from a local directory.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FileSource {
+    /// CheckpointPath is the file where the read progress is persisted, defaults to \".checkpoint.json\" in the segment directory.
+    #[serde(rename = "checkpointPath", skip_serializing_if = "Option::is_none")]
+    pub checkpoint_path: Option<String>,
+    /// Format of the segments, \"ndjson\" (default) or \"proto\". It has to match the format of the file sink which wrote the segments.
+    #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Path of the directory to read the segments from.
+    #[serde(rename = "path")]
+    pub path: String,
+}
+
+impl FileSource {
+    /// FileSource reads the segment files written by the file sink from a local directory.
+    pub fn new(path: String) -> FileSource {
+        FileSource {
+            checkpoint_path: None,
+            format: None,
+            path,
+        }
+    }
+}
@@ -22,10 +22,16 @@ limitations under the License.
 pub struct GroupBy {
     #[serde(rename = "allowedLateness", skip_serializing_if = "Option::is_none")]
     pub allowed_lateness: Option<kube::core::Duration>,
+    #[serde(rename = "builtin", skip_serializing_if = "Option::is_none")]
+    pub builtin: Option<Box<crate::models::BuiltinReducer>>,
     #[serde(rename = "keyed", skip_serializing_if = "Option::is_none")]
     pub keyed: Option<bool>,
+    #[serde(rename = "lateData", skip_serializing_if = "Option::is_none")]
+    pub late_data: Option<Box<crate::models::LateData>>,
     #[serde(rename = "storage", skip_serializing_if = "Option::is_none")]
     pub storage: Option<Box<crate::models::PbqStorage>>,
+    #[serde(rename = "trigger", skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<Box<crate::models::WindowTrigger>>,
     #[serde(rename = "window")]
     pub window: Box<crate::models::Window>,
 }
@@ -35,8 +41,11 @@ impl GroupBy {
     pub fn new(window: crate::models::Window) -> GroupBy {
         GroupBy {
             allowed_lateness: None,
+            builtin: None,
             keyed: None,
+            late_data: None,
             storage: None,
+            trigger: None,
             window: Box::new(window),
         }
     }
@@ -0,0 +1,61 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// HttpSink : HTTPSink sends the messages to an HTTP endpoint, either one request per message or as a batch in a single request.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HttpSink {
+    #[serde(rename = "auth", skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Box<crate::models::HttpSinkAuth>>,
+    /// Format of the request body. \"raw\" (default) sends one request per message with the payload as the body. \"json\" sends the whole batch as a JSON array, and \"ndjson\" sends the whole batch as newline delimited JSON, both require the payloads to be valid JSON.
+    #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// HeaderMapping maps the message header names to the request header names, only the mapped message headers are sent. It is only applied to the \"raw\" format.
+    #[serde(rename = "headerMapping", skip_serializing_if = "Option::is_none")]
+    pub header_mapping: Option<::std::collections::HashMap<String, String>>,
+    /// Headers are static headers added to every request.
+    #[serde(rename = "headers", skip_serializing_if = "Option::is_none")]
+    pub headers: Option<::std::collections::HashMap<String, String>>,
+    /// InsecureSkipVerify skips the verification of the server certificate.
+    #[serde(rename = "insecureSkipVerify", skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+    /// Method is the HTTP method of the requests, defaults to POST.
+    #[serde(rename = "method", skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(rename = "timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<kube::core::Duration>,
+    /// URL of the HTTP endpoint.
+    #[serde(rename = "url")]
+    pub url: String,
+}
+
+impl HttpSink {
+    /// HTTPSink sends the messages to an HTTP endpoint, either one request per message or as a batch in a single request.
+    pub fn new(url: String) -> HttpSink {
+        HttpSink {
+            auth: None,
+            format: None,
+            header_mapping: None,
+            headers: None,
+            insecure_skip_verify: None,
+            method: None,
+            timeout: None,
+            url,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// HttpSinkAuth : HTTPSinkAuth defines how to authenticate the requests of the HTTP sink, only one of them can be specified.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkAuth {
+    #[serde(rename = "basic", skip_serializing_if = "Option::is_none")]
+    pub basic: Option<Box<crate::models::BasicAuth>>,
+    #[serde(rename = "bearer", skip_serializing_if = "Option::is_none")]
+    pub bearer: Option<k8s_openapi::api::core::v1::SecretKeySelector>,
+}
+
+impl HttpSinkAuth {
+    /// HTTPSinkAuth defines how to authenticate the requests of the HTTP sink, only one of them can be specified.
+    pub fn new() -> HttpSinkAuth {
+        HttpSinkAuth {
+            basic: None,
+            bearer: None,
+        }
+    }
+}
@@ -20,6 +20,8 @@ limitations under the License.
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InterStepBuffer {
+    #[serde(rename = "batching", skip_serializing_if = "Option::is_none")]
+    pub batching: Option<Box<crate::models::IsbBatching>>,
     #[serde(rename = "compression", skip_serializing_if = "Option::is_none")]
     pub compression: Option<Box<crate::models::Compression>>,
 }
@@ -27,6 +29,9 @@ pub struct InterStepBuffer {
 impl InterStepBuffer {
     /// InterStepBuffer configuration specifically for the pipeline.
     pub fn new() -> InterStepBuffer {
-        InterStepBuffer { compression: None }
+        InterStepBuffer {
+            batching: None,
+            compression: None,
+        }
     }
 }
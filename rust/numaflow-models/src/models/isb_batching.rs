@@ -0,0 +1,38 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// IsbBatching : ISBBatching packs multiple messages into a single InterStepBuffer message, which is compressed as a whole.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IsbBatching {
+    #[serde(rename = "linger", skip_serializing_if = "Option::is_none")]
+    pub linger: Option<kube::core::Duration>,
+    /// MaxMessages is the maximum number of messages packed into a single InterStepBuffer message, defaults to 100.
+    #[serde(rename = "maxMessages", skip_serializing_if = "Option::is_none")]
+    pub max_messages: Option<i32>,
+}
+
+impl IsbBatching {
+    /// ISBBatching packs multiple messages into a single InterStepBuffer message, which is compressed as a whole.
+    pub fn new() -> IsbBatching {
+        IsbBatching {
+            linger: None,
+            max_messages: None,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// JetStreamSink : JetStreamSink publishes messages to a JetStream stream and waits for the publish acks.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JetStreamSink {
+    #[serde(rename = "ackTimeout", skip_serializing_if = "Option::is_none")]
+    pub ack_timeout: Option<kube::core::Duration>,
+    #[serde(rename = "auth", skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Box<crate::models::NatsAuth>>,
+    /// Subject to publish the messages to, it should be bound to a stream. It can be a template rendered per message, supported placeholders are `{{key.N}}` (N-th key of the message), `{{keys}}` (all the keys joined by \".\") and `{{header.NAME}}` (value of the header NAME).
+    #[serde(rename = "subject")]
+    pub subject: String,
+    #[serde(rename = "tls", skip_serializing_if = "Option::is_none")]
+    pub tls: Option<Box<crate::models::Tls>>,
+    /// URL to connect to NATS cluster, multiple urls could be separated by comma.
+    #[serde(rename = "url")]
+    pub url: String,
+}
+
+impl JetStreamSink {
+    /// JetStreamSink publishes messages to a JetStream stream and waits for the publish acks.
+    pub fn new(subject: String, url: String) -> JetStreamSink {
+        JetStreamSink {
+            ack_timeout: None,
+            auth: None,
+            subject,
+            tls: None,
+            url,
+        }
+    }
+}
@@ -31,6 +31,9 @@ pub struct KafkaSink {
     pub tls: Option<Box<crate::models::Tls>>,
     #[serde(rename = "topic")]
     pub topic: String,
+    /// Transactional enables the exactly-once mode of the sink. Every batch is written in a Kafka transaction, and the messages are acknowledged only after the transaction is committed. The transactional.id is derived from the pipeline, vertex and replica, so that a restarted replica fences off its previous incarnation. Consumers should use isolation.level=read_committed to not see the records of aborted transactions.
+    #[serde(rename = "transactional", skip_serializing_if = "Option::is_none")]
+    pub transactional: Option<bool>,
 }
 
 impl KafkaSink {
@@ -42,6 +45,7 @@ impl KafkaSink {
             set_key: None,
             tls: None,
             topic,
+            transactional: None,
         }
     }
 }
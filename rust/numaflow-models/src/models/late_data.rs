@@ -0,0 +1,33 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// LateData : LateData routes the messages which are too late for their window to the edges with the tag in their conditions, instead of dropping them.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LateData {
+    /// Tag of the late messages, it is used in the conditions of the edges which should receive them. Defaults to \"late\".
+    #[serde(rename = "tag", skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl LateData {
+    /// LateData routes the messages which are too late for their window to the edges with the tag in their conditions, instead of dropping them.
+    pub fn new() -> LateData {
+        LateData { tag: None }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// LocalObjectStore : LocalObjectStore stores the objects in a local directory, usually a mounted volume.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocalObjectStore {
+    /// Path of the directory to store the objects in.
+    #[serde(rename = "path")]
+    pub path: String,
+}
+
+impl LocalObjectStore {
+    /// LocalObjectStore stores the objects in a local directory, usually a mounted volume.
+    pub fn new(path: String) -> LocalObjectStore {
+        LocalObjectStore { path }
+    }
+}
@@ -6,6 +6,8 @@ pub mod abstract_vertex;
 pub use self::abstract_vertex::AbstractVertex;
 pub mod accumulator_window;
 pub use self::accumulator_window::AccumulatorWindow;
+pub mod adaptive_watermark;
+pub use self::adaptive_watermark::AdaptiveWatermark;
 pub mod authorization;
 pub use self::authorization::Authorization;
 pub mod aws_assume_role;
@@ -16,8 +18,14 @@ pub mod basic_auth;
 pub use self::basic_auth::BasicAuth;
 pub mod blackhole;
 pub use self::blackhole::Blackhole;
+pub mod broadcast_sink;
+pub use self::broadcast_sink::BroadcastSink;
 pub mod buffer_service_config;
 pub use self::buffer_service_config::BufferServiceConfig;
+pub mod builtin_reducer;
+pub use self::builtin_reducer::BuiltinReducer;
+pub mod circuit_breaker;
+pub use self::circuit_breaker::CircuitBreaker;
 pub mod combined_edge;
 pub use self::combined_edge::CombinedEdge;
 pub mod compression;
@@ -32,6 +40,10 @@ pub mod daemon_template;
 pub use self::daemon_template::DaemonTemplate;
 pub mod edge;
 pub use self::edge::Edge;
+pub mod file_sink;
+pub use self::file_sink::FileSink;
+pub mod file_source;
+pub use self::file_source::FileSource;
 pub mod fixed_window;
 pub use self::fixed_window::FixedWindow;
 pub mod forward_conditions;
@@ -60,6 +72,10 @@ pub mod group_by;
 pub use self::group_by::GroupBy;
 pub mod gssapi;
 pub use self::gssapi::Gssapi;
+pub mod http_sink;
+pub use self::http_sink::HttpSink;
+pub mod http_sink_auth;
+pub use self::http_sink_auth::HttpSinkAuth;
 pub mod http_source;
 pub use self::http_source::HttpSource;
 pub mod idle_source;
@@ -74,10 +90,14 @@ pub mod inter_step_buffer_service_spec;
 pub use self::inter_step_buffer_service_spec::InterStepBufferServiceSpec;
 pub mod inter_step_buffer_service_status;
 pub use self::inter_step_buffer_service_status::InterStepBufferServiceStatus;
+pub mod isb_batching;
+pub use self::isb_batching::IsbBatching;
 pub mod jet_stream_buffer_service;
 pub use self::jet_stream_buffer_service::JetStreamBufferService;
 pub mod jet_stream_config;
 pub use self::jet_stream_config::JetStreamConfig;
+pub mod jet_stream_sink;
+pub use self::jet_stream_sink::JetStreamSink;
 pub mod jet_stream_source;
 pub use self::jet_stream_source::JetStreamSource;
 pub mod job_template;
@@ -86,8 +106,12 @@ pub mod kafka_sink;
 pub use self::kafka_sink::KafkaSink;
 pub mod kafka_source;
 pub use self::kafka_source::KafkaSource;
+pub mod late_data;
+pub use self::late_data::LateData;
 pub mod lifecycle;
 pub use self::lifecycle::Lifecycle;
+pub mod local_object_store;
+pub use self::local_object_store::LocalObjectStore;
 pub mod log;
 pub use self::log::Log;
 pub mod metadata;
@@ -108,14 +132,22 @@ pub mod mono_vertex_status;
 pub use self::mono_vertex_status::MonoVertexStatus;
 pub mod nats_auth;
 pub use self::nats_auth::NatsAuth;
+pub mod nats_sink;
+pub use self::nats_sink::NatsSink;
 pub mod nats_source;
 pub use self::nats_source::NatsSource;
 pub mod no_store;
 pub use self::no_store::NoStore;
+pub mod object_store_column;
+pub use self::object_store_column::ObjectStoreColumn;
+pub mod object_store_sink;
+pub use self::object_store_sink::ObjectStoreSink;
 pub mod ordered;
 pub use self::ordered::Ordered;
 pub mod pbq_storage;
 pub use self::pbq_storage::PbqStorage;
+pub mod percentile_watermark;
+pub use self::percentile_watermark::PercentileWatermark;
 pub mod persistence_strategy;
 pub use self::persistence_strategy::PersistenceStrategy;
 pub mod pipeline;
@@ -130,6 +162,10 @@ pub mod pipeline_status;
 pub use self::pipeline_status::PipelineStatus;
 pub mod ports;
 pub use self::ports::Ports;
+pub mod postgres_column;
+pub use self::postgres_column::PostgresColumn;
+pub mod postgres_sink;
+pub use self::postgres_sink::PostgresSink;
 pub mod probe;
 pub use self::probe::Probe;
 pub mod pulsar_auth;
@@ -140,12 +176,20 @@ pub mod pulsar_sink;
 pub use self::pulsar_sink::PulsarSink;
 pub mod pulsar_source;
 pub use self::pulsar_source::PulsarSource;
+pub mod punctuation_watermark;
+pub use self::punctuation_watermark::PunctuationWatermark;
 pub mod rate_limit;
 pub use self::rate_limit::RateLimit;
+pub mod rate_limit_key_override;
+pub use self::rate_limit_key_override::RateLimitKeyOverride;
+pub mod rate_limit_per_key;
+pub use self::rate_limit_per_key::RateLimitPerKey;
 pub mod rate_limiter_go_back_n;
 pub use self::rate_limiter_go_back_n::RateLimiterGoBackN;
 pub mod rate_limiter_in_memory_store;
 pub use self::rate_limiter_in_memory_store::RateLimiterInMemoryStore;
+pub mod rate_limiter_jet_stream_store;
+pub use self::rate_limiter_jet_stream_store::RateLimiterJetStreamStore;
 pub mod rate_limiter_modes;
 pub use self::rate_limiter_modes::RateLimiterModes;
 pub mod rate_limiter_only_if_used;
@@ -160,12 +204,18 @@ pub mod rate_limiter_store;
 pub use self::rate_limiter_store::RateLimiterStore;
 pub mod redis_auth;
 pub use self::redis_auth::RedisAuth;
+pub mod redis_buffer_service;
+pub use self::redis_buffer_service::RedisBufferService;
+pub mod redis_config;
+pub use self::redis_config::RedisConfig;
 pub mod redis_sentinel_config;
 pub use self::redis_sentinel_config::RedisSentinelConfig;
 pub mod retry_strategy;
 pub use self::retry_strategy::RetryStrategy;
 pub mod rolling_update_strategy;
 pub use self::rolling_update_strategy::RollingUpdateStrategy;
+pub mod s3_object_store;
+pub use self::s3_object_store::S3ObjectStore;
 pub mod sasl;
 pub use self::sasl::Sasl;
 pub mod sasl_plain;
@@ -244,5 +294,9 @@ pub mod vertex_template;
 pub use self::vertex_template::VertexTemplate;
 pub mod watermark;
 pub use self::watermark::Watermark;
+pub mod watermark_strategy;
+pub use self::watermark_strategy::WatermarkStrategy;
 pub mod window;
 pub use self::window::Window;
+pub mod window_trigger;
+pub use self::window_trigger::WindowTrigger;
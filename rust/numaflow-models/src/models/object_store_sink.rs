@@ -0,0 +1,67 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// ObjectStoreSink : ObjectStoreSink buffers the messages into files which are uploaded to an object store, the files are partitioned by the event time window and optionally the keys of the messages.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObjectStoreSink {
+    /// Columns of the files, the values are read from the JSON payload of the messages. Required for the parquet format, for ndjson the payload is written as is if no columns are specified.
+    #[serde(rename = "columns", skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<crate::models::ObjectStoreColumn>>,
+    /// Format of the files, \"ndjson\" (default) or \"parquet\".
+    #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(rename = "local", skip_serializing_if = "Option::is_none")]
+    pub local: Option<Box<crate::models::LocalObjectStore>>,
+    #[serde(rename = "maxFileAge", skip_serializing_if = "Option::is_none")]
+    pub max_file_age: Option<kube::core::Duration>,
+    /// MaxFileMessages is the maximum number of messages in a file, defaults to 100000.
+    #[serde(rename = "maxFileMessages", skip_serializing_if = "Option::is_none")]
+    pub max_file_messages: Option<i64>,
+    /// MaxFileSize is the maximum size of the rows of a file in bytes, defaults to 64MiB.
+    #[serde(rename = "maxFileSize", skip_serializing_if = "Option::is_none")]
+    pub max_file_size: Option<i64>,
+    /// PartitionByKeys adds the keys of the messages to the path of the files.
+    #[serde(rename = "partitionByKeys", skip_serializing_if = "Option::is_none")]
+    pub partition_by_keys: Option<bool>,
+    #[serde(rename = "partitionWindow", skip_serializing_if = "Option::is_none")]
+    pub partition_window: Option<kube::core::Duration>,
+    /// Prefix of the object keys.
+    #[serde(rename = "prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "s3", skip_serializing_if = "Option::is_none")]
+    pub s3: Option<Box<crate::models::S3ObjectStore>>,
+}
+
+impl ObjectStoreSink {
+    /// ObjectStoreSink buffers the messages into files which are uploaded to an object store, the files are partitioned by the event time window and optionally the keys of the messages.
+    pub fn new() -> ObjectStoreSink {
+        ObjectStoreSink {
+            columns: None,
+            format: None,
+            local: None,
+            max_file_age: None,
+            max_file_messages: None,
+            max_file_size: None,
+            partition_by_keys: None,
+            partition_window: None,
+            prefix: None,
+            s3: None,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// PercentileWatermark : PercentileWatermark holds back the watermark by a percentile of the out-of-orderness of the messages observed over a sliding window.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PercentileWatermark {
+    /// Percentile of the out-of-orderness used as the delay of the watermark, between 1 and 100, defaults to 99.
+    #[serde(rename = "percentile", skip_serializing_if = "Option::is_none")]
+    pub percentile: Option<i32>,
+    #[serde(rename = "window", skip_serializing_if = "Option::is_none")]
+    pub window: Option<kube::core::Duration>,
+}
+
+impl PercentileWatermark {
+    /// PercentileWatermark holds back the watermark by a percentile of the out-of-orderness of the messages observed over a sliding window.
+    pub fn new() -> PercentileWatermark {
+        PercentileWatermark {
+            percentile: None,
+            window: None,
+        }
+    }
+}
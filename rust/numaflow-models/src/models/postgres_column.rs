@@ -0,0 +1,43 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// PostgresColumn : PostgresColumn is a column of the table written by the PostgreSQL sink, its value is read from the JSON payload or the keys of the message.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostgresColumn {
+    /// Key is the index of the key of the message the value is read from, instead of the payload.
+    #[serde(rename = "key", skip_serializing_if = "Option::is_none")]
+    pub key: Option<i32>,
+    /// Name of the column.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Path is the JSON pointer of the value in the payload, e.g. \"/user/id\", defaults to the name of the column.
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl PostgresColumn {
+    /// PostgresColumn is a column of the table written by the PostgreSQL sink, its value is read from the JSON payload or the keys of the message.
+    pub fn new(name: String) -> PostgresColumn {
+        PostgresColumn {
+            key: None,
+            name,
+            path: None,
+        }
+    }
+}
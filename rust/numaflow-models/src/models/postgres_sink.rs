@@ -0,0 +1,65 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// PostgresSink : PostgresSink writes the messages to a PostgreSQL table, each batch is written with a single INSERT ... ON CONFLICT statement in one transaction.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSink {
+    /// Columns of the table and where their values are read from.
+    #[serde(rename = "columns")]
+    pub columns: Vec<crate::models::PostgresColumn>,
+    /// ConflictColumns are the columns of the unique constraint the rows are upserted on.
+    #[serde(rename = "conflictColumns", skip_serializing_if = "Option::is_none")]
+    pub conflict_columns: Option<Vec<String>>,
+    #[serde(rename = "connectTimeout", skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<kube::core::Duration>,
+    /// IDColumn is the column the message ID is written to. Without conflict columns, the rows whose ID is already in the table are skipped, which makes the writes of the redelivered messages idempotent. The column needs a unique constraint.
+    #[serde(rename = "idColumn", skip_serializing_if = "Option::is_none")]
+    pub id_column: Option<String>,
+    /// OnConflict is what happens to a row whose conflict columns match an existing row, \"update\" (default) updates the other columns and \"nothing\" keeps the existing row.
+    #[serde(rename = "onConflict", skip_serializing_if = "Option::is_none")]
+    pub on_conflict: Option<String>,
+    #[serde(rename = "passwordSecret", skip_serializing_if = "Option::is_none")]
+    pub password_secret: Option<k8s_openapi::api::core::v1::SecretKeySelector>,
+    /// Table the rows are written to, optionally qualified by the schema, e.g. \"public.events\".
+    #[serde(rename = "table")]
+    pub table: String,
+    /// URL is the connection string of the database, e.g. \"postgres://user@host:5432/db\".
+    #[serde(rename = "url")]
+    pub url: String,
+}
+
+impl PostgresSink {
+    /// PostgresSink writes the messages to a PostgreSQL table, each batch is written with a single INSERT ... ON CONFLICT statement in one transaction.
+    pub fn new(
+        columns: Vec<crate::models::PostgresColumn>,
+        table: String,
+        url: String,
+    ) -> PostgresSink {
+        PostgresSink {
+            columns,
+            conflict_columns: None,
+            connect_timeout: None,
+            id_column: None,
+            on_conflict: None,
+            password_secret: None,
+            table,
+            url,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// PunctuationWatermark : PunctuationWatermark advances the watermark of a partition only when a message with the marker header is read.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PunctuationWatermark {
+    /// Header marking the messages which advance the watermark, its value is the new watermark in epoch milliseconds, or the event time of the message if empty. Defaults to \"x-numaflow-watermark\".
+    #[serde(rename = "header", skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+}
+
+impl PunctuationWatermark {
+    /// PunctuationWatermark advances the watermark of a partition only when a message with the marker header is read.
+    pub fn new() -> PunctuationWatermark {
+        PunctuationWatermark { header: None }
+    }
+}
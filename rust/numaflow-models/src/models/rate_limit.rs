@@ -26,6 +26,8 @@ pub struct RateLimit {
     pub min: Option<i64>,
     #[serde(rename = "modes", skip_serializing_if = "Option::is_none")]
     pub modes: Option<Box<crate::models::RateLimiterModes>>,
+    #[serde(rename = "perKey", skip_serializing_if = "Option::is_none")]
+    pub per_key: Option<Box<crate::models::RateLimitPerKey>>,
     #[serde(rename = "rampUpDuration", skip_serializing_if = "Option::is_none")]
     pub ramp_up_duration: Option<kube::core::Duration>,
     /// ResumedRampUp is used to enable the resume mode for rate limiting.  This, if true, will allow the processor to resume the ramp-up process from the last known state of the rate limiter, i.e., if the processor was allowed X tokens before shutting down, it will be allowed X tokens again after the processor restarts.  The resumed ramp-up process will be allowed until TTL time after the processor first deregisters with the rate limiter.
@@ -43,6 +45,7 @@ impl RateLimit {
             max: None,
             min: None,
             modes: None,
+            per_key: None,
             ramp_up_duration: None,
             resumed_ramp_up: None,
             store: None,
@@ -0,0 +1,46 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// RateLimitKeyOverride : RateLimitKeyOverride overrides the rate limit of a specific key.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitKeyOverride {
+    /// Key the override applies to.
+    #[serde(rename = "key")]
+    pub key: String,
+    /// Max is the maximum TPS of the key. Defaults to the max of the rate limit.
+    #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
+    pub max: Option<i64>,
+    /// Minimum TPS of the key during initial bootup. Defaults to the min of the rate limit.
+    #[serde(rename = "min", skip_serializing_if = "Option::is_none")]
+    pub min: Option<i64>,
+    #[serde(rename = "rampUpDuration", skip_serializing_if = "Option::is_none")]
+    pub ramp_up_duration: Option<kube::core::Duration>,
+}
+
+impl RateLimitKeyOverride {
+    /// RateLimitKeyOverride overrides the rate limit of a specific key.
+    pub fn new(key: String) -> RateLimitKeyOverride {
+        RateLimitKeyOverride {
+            key,
+            max: None,
+            min: None,
+            ramp_up_duration: None,
+        }
+    }
+}
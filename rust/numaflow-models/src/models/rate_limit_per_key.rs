@@ -0,0 +1,43 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// RateLimitPerKey : RateLimitPerKey enables a separate rate limit per key (e.g., per tenant) instead of one for the whole vertex.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitPerKey {
+    /// Header to derive the key from. Defaults to the keys of the message.
+    #[serde(rename = "header", skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// MaxKeys is the maximum number of keys to keep a rate limit for, the least recently used key is evicted beyond that. Defaults to 1000.
+    #[serde(rename = "maxKeys", skip_serializing_if = "Option::is_none")]
+    pub max_keys: Option<i64>,
+    /// Overrides of the rate limit of specific keys.
+    #[serde(rename = "overrides", skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<crate::models::RateLimitKeyOverride>>,
+}
+
+impl RateLimitPerKey {
+    /// RateLimitPerKey enables a separate rate limit per key (e.g., per tenant) instead of one for the whole vertex.
+    pub fn new() -> RateLimitPerKey {
+        RateLimitPerKey {
+            header: None,
+            max_keys: None,
+            overrides: None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// RateLimiterJetStreamStore : RateLimiterJetStreamStore uses a JetStream Key-Value bucket of the InterStepBufferService as the store.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimiterJetStreamStore {
+    /// Name of the KV bucket to keep the state of the rate limiter in, created if it does not exist. Defaults to \"numaflow-rate-limiter\".
+    #[serde(rename = "bucket", skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+}
+
+impl RateLimiterJetStreamStore {
+    /// RateLimiterJetStreamStore uses a JetStream Key-Value bucket of the InterStepBufferService as the store.
+    pub fn new() -> RateLimiterJetStreamStore {
+        RateLimiterJetStreamStore { bucket: None }
+    }
+}
@@ -20,6 +20,8 @@ limitations under the License.
 pub struct RateLimiterStore {
     #[serde(rename = "inMemoryStore", skip_serializing_if = "Option::is_none")]
     pub in_memory_store: Option<Box<crate::models::RateLimiterInMemoryStore>>,
+    #[serde(rename = "jetStreamStore", skip_serializing_if = "Option::is_none")]
+    pub jet_stream_store: Option<Box<crate::models::RateLimiterJetStreamStore>>,
     #[serde(rename = "redisStore", skip_serializing_if = "Option::is_none")]
     pub redis_store: Option<Box<crate::models::RateLimiterRedisStore>>,
 }
@@ -28,6 +30,7 @@ impl RateLimiterStore {
     pub fn new() -> RateLimiterStore {
         RateLimiterStore {
             in_memory_store: None,
+            jet_stream_store: None,
             redis_store: None,
         }
     }
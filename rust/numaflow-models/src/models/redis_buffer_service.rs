@@ -0,0 +1,38 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// RedisBufferService : RedisBufferService is an external Redis server used as the inter-step buffer. It is not managed by the controller, the streams are created by the vertices on demand.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RedisBufferService {
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<k8s_openapi::api::core::v1::SecretKeySelector>,
+    /// Redis URL, e.g. \"redis://redis.default.svc:6379\"
+    #[serde(rename = "url")]
+    pub url: String,
+}
+
+impl RedisBufferService {
+    /// RedisBufferService is an external Redis server used as the inter-step buffer. It is not managed by the controller, the streams are created by the vertices on demand.
+    pub fn new(url: String) -> RedisBufferService {
+        RedisBufferService {
+            password: None,
+            url,
+        }
+    }
+}
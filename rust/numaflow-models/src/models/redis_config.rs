@@ -0,0 +1,35 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RedisConfig {
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<k8s_openapi::api::core::v1::SecretKeySelector>,
+    /// Redis URL
+    #[serde(rename = "url", skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl RedisConfig {
+    pub fn new() -> RedisConfig {
+        RedisConfig {
+            password: None,
+            url: None,
+        }
+    }
+}
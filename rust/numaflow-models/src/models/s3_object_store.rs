@@ -0,0 +1,50 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// S3ObjectStore : S3ObjectStore is an S3 compatible object store.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct S3ObjectStore {
+    #[serde(rename = "assumeRole", skip_serializing_if = "Option::is_none")]
+    pub assume_role: Option<Box<crate::models::AwsAssumeRole>>,
+    /// AWSRegion is the AWS Region of the bucket.
+    #[serde(rename = "awsRegion")]
+    pub aws_region: String,
+    /// Bucket is the name of the bucket.
+    #[serde(rename = "bucket")]
+    pub bucket: String,
+    /// EndpointURL overrides the endpoint of the store, e.g. for MinIO.
+    #[serde(rename = "endpointUrl", skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+    /// ForcePathStyle addresses the bucket in the path of the URL instead of the host, required by most S3 compatible stores.
+    #[serde(rename = "forcePathStyle", skip_serializing_if = "Option::is_none")]
+    pub force_path_style: Option<bool>,
+}
+
+impl S3ObjectStore {
+    /// S3ObjectStore is an S3 compatible object store.
+    pub fn new(aws_region: String, bucket: String) -> S3ObjectStore {
+        S3ObjectStore {
+            assume_role: None,
+            aws_region,
+            bucket,
+            endpoint_url: None,
+            force_path_style: None,
+        }
+    }
+}
@@ -20,14 +20,31 @@ limitations under the License.
 pub struct Sink {
     #[serde(rename = "blackhole", skip_serializing_if = "Option::is_none")]
     pub blackhole: Option<Box<crate::models::Blackhole>>,
+    #[serde(rename = "broadcast", skip_serializing_if = "Option::is_none")]
+    pub broadcast: Option<Box<crate::models::BroadcastSink>>,
+    /// DLQHeaders mirrors the dead-letter envelope of the messages routed to the fallback sink into their headers. The envelope is always added to the system metadata of the messages.
+    #[serde(rename = "dlqHeaders", skip_serializing_if = "Option::is_none")]
+    pub dlq_headers: Option<bool>,
     #[serde(rename = "fallback", skip_serializing_if = "Option::is_none")]
     pub fallback: Option<Box<crate::models::AbstractSink>>,
+    #[serde(rename = "file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<Box<crate::models::FileSink>>,
+    #[serde(rename = "http", skip_serializing_if = "Option::is_none")]
+    pub http: Option<Box<crate::models::HttpSink>>,
+    #[serde(rename = "jetstream", skip_serializing_if = "Option::is_none")]
+    pub jetstream: Option<Box<crate::models::JetStreamSink>>,
     #[serde(rename = "kafka", skip_serializing_if = "Option::is_none")]
     pub kafka: Option<Box<crate::models::KafkaSink>>,
     #[serde(rename = "log", skip_serializing_if = "Option::is_none")]
     pub log: Option<Box<crate::models::Log>>,
+    #[serde(rename = "nats", skip_serializing_if = "Option::is_none")]
+    pub nats: Option<Box<crate::models::NatsSink>>,
+    #[serde(rename = "objectStore", skip_serializing_if = "Option::is_none")]
+    pub object_store: Option<Box<crate::models::ObjectStoreSink>>,
     #[serde(rename = "onSuccess", skip_serializing_if = "Option::is_none")]
     pub on_success: Option<Box<crate::models::AbstractSink>>,
+    #[serde(rename = "postgres", skip_serializing_if = "Option::is_none")]
+    pub postgres: Option<Box<crate::models::PostgresSink>>,
     #[serde(rename = "pulsar", skip_serializing_if = "Option::is_none")]
     pub pulsar: Option<Box<crate::models::PulsarSink>>,
     #[serde(rename = "retryStrategy", skip_serializing_if = "Option::is_none")]
@@ -44,10 +61,18 @@ impl Sink {
     pub fn new() -> Sink {
         Sink {
             blackhole: None,
+            broadcast: None,
+            dlq_headers: None,
             fallback: None,
+            file: None,
+            http: None,
+            jetstream: None,
             kafka: None,
             log: None,
+            nats: None,
+            object_store: None,
             on_success: None,
+            postgres: None,
             pulsar: None,
             retry_strategy: None,
             serve: None,
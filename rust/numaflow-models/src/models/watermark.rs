@@ -25,6 +25,8 @@ pub struct Watermark {
     pub idle_source: Option<Box<crate::models::IdleSource>>,
     #[serde(rename = "maxDelay", skip_serializing_if = "Option::is_none")]
     pub max_delay: Option<kube::core::Duration>,
+    #[serde(rename = "strategy", skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<Box<crate::models::WatermarkStrategy>>,
 }
 
 impl Watermark {
@@ -33,6 +35,7 @@ impl Watermark {
             disabled: None,
             idle_source: None,
             max_delay: None,
+            strategy: None,
         }
     }
 }
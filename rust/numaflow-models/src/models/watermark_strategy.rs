@@ -0,0 +1,40 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// WatermarkStrategy : WatermarkStrategy is the heuristic used by the source to compute the watermark from the event times of the messages. Only one of the strategies can be set, defaults to the minimum event time of the read messages.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WatermarkStrategy {
+    #[serde(rename = "adaptive", skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<Box<crate::models::AdaptiveWatermark>>,
+    #[serde(rename = "percentile", skip_serializing_if = "Option::is_none")]
+    pub percentile: Option<Box<crate::models::PercentileWatermark>>,
+    #[serde(rename = "punctuation", skip_serializing_if = "Option::is_none")]
+    pub punctuation: Option<Box<crate::models::PunctuationWatermark>>,
+}
+
+impl WatermarkStrategy {
+    /// WatermarkStrategy is the heuristic used by the source to compute the watermark from the event times of the messages. Only one of the strategies can be set, defaults to the minimum event time of the read messages.
+    pub fn new() -> WatermarkStrategy {
+        WatermarkStrategy {
+            adaptive: None,
+            percentile: None,
+            punctuation: None,
+        }
+    }
+}
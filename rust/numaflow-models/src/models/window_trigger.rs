@@ -0,0 +1,42 @@
+/*
+Copyright 2022 The Numaproj Authors.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// Code generated by Openapi Generator. DO NOT EDIT.
+
+/// WindowTrigger : WindowTrigger fires intermediate results (panes) of fixed and sliding windows before they are closed by the watermark.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WindowTrigger {
+    /// Count fires a pane of a key every time the key receives this number of messages.
+    #[serde(rename = "count", skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[serde(rename = "interval", skip_serializing_if = "Option::is_none")]
+    pub interval: Option<kube::core::Duration>,
+    /// LateFirings fires a pane of every key once the watermark passes the end of the window, and a pane for every late message until the window is closed after the allowed lateness. Requires allowedLateness.
+    #[serde(rename = "lateFirings", skip_serializing_if = "Option::is_none")]
+    pub late_firings: Option<bool>,
+}
+
+impl WindowTrigger {
+    /// WindowTrigger fires intermediate results (panes) of fixed and sliding windows before they are closed by the watermark.
+    pub fn new() -> WindowTrigger {
+        WindowTrigger {
+            count: None,
+            interval: None,
+            late_firings: None,
+        }
+    }
+}
@@ -144,11 +144,47 @@ pub fn persist_application_error(grpc_status: Status) {
     );
 }
 
+/// Persists a runtime event of the numa container, like a state transition of the sink circuit
+/// breaker, so that it shows up on the `/runtime/errors` endpoint. Unlike
+/// [persist_application_error], it can be called any number of times, the oldest entries are
+/// removed once the limit of files per container is reached.
+///
+/// The container name is extracted from the message like for the application errors.
+pub fn persist_runtime_event(grpc_status: Status) {
+    let config = RuntimeInfoConfig::default();
+    // several events can be persisted within a second, the milliseconds keep the file names unique
+    let file_suffix = format!("numa-{}", Utc::now().timestamp_subsec_millis());
+    if let Err(e) = persist_status_to_file(
+        config.app_error_path,
+        config.max_error_files_per_container,
+        grpc_status,
+        &file_suffix,
+    ) {
+        error!("Failed to persist runtime event: {e}");
+    }
+}
+
 pub(crate) fn persist_application_error_to_file(
     application_error_path: String,
     max_error_files_per_container: usize,
     grpc_status: Status,
 ) {
+    persist_status_to_file(
+        application_error_path,
+        max_error_files_per_container,
+        grpc_status,
+        "numa",
+    )
+    .expect("Failed to persist application error");
+}
+
+/// Writes the status as `<timestamp>-<file_suffix>.json` in the directory of its container.
+fn persist_status_to_file(
+    application_error_path: String,
+    max_error_files_per_container: usize,
+    grpc_status: Status,
+    file_suffix: &str,
+) -> Result<()> {
     // extract the type of container based on the error message
     let container_name = extract_container_name(grpc_status.message());
     // create a directory for the container if it doesn't exist with permissions to read, write, and execute for all
@@ -157,16 +193,22 @@ pub(crate) fn persist_application_error_to_file(
         let mut builder = fs::DirBuilder::new();
         builder.recursive(true);
         builder.mode(0o777);
-        builder
-            .create(&dir_path)
-            .expect("Failed to create application errors directory");
+        builder.create(&dir_path).map_err(|e| {
+            Error::File(format!(
+                "Failed to create application errors directory: {e:?}"
+            ))
+        })?;
     }
 
     // this is to check the number of files in the directory
     // additional check in place to process only files and ignore directories
     // ignore files starting with prefix `current`
     let mut files: Vec<_> = fs::read_dir(&dir_path)
-        .expect("Failed to read application errors directory")
+        .map_err(|e| {
+            Error::File(format!(
+                "Failed to read application errors directory: {e:?}"
+            ))
+        })?
         .filter_map(|entry| entry.ok())
         .filter(|entry| entry.path().is_file())
         .filter(|entry| {
@@ -212,18 +254,26 @@ pub(crate) fn persist_application_error_to_file(
     // this is to ensure that while reading we skip this file to avoid race condition
     let current_file_path = dir_path.join(CURRENT_FILE);
     // append numa to the file name to denote files created by numa container
-    let file_name = format!("{timestamp}-numa.json");
+    let file_name = format!("{timestamp}-{file_suffix}.json");
     let final_file_path = dir_path.join(&file_name);
 
-    let mut current_file =
-        File::create(&current_file_path).expect("Failed to create current application errors file");
-    current_file
-        .write_all(json_str.as_bytes())
-        .expect("Failed to write to current application error file");
+    let mut current_file = File::create(&current_file_path).map_err(|e| {
+        Error::File(format!(
+            "Failed to create current application errors file: {e:?}"
+        ))
+    })?;
+    current_file.write_all(json_str.as_bytes()).map_err(|e| {
+        Error::File(format!(
+            "Failed to write to current application error file: {e:?}"
+        ))
+    })?;
 
     // rename the current file to the final file name once write operation completes
-    fs::rename(&current_file_path, &final_file_path)
-        .expect("Failed to rename current file to final file name");
+    fs::rename(&current_file_path, &final_file_path).map_err(|e| {
+        Error::File(format!(
+            "Failed to rename current file to final file name: {e:?}"
+        ))
+    })
 }
 
 /// A structure used to represent API responses containing runtime error entries.
@@ -491,6 +541,39 @@ mod tests {
         assert!(file_name.ends_with(".json"));
     }
 
+    #[test]
+    fn test_persist_runtime_events() {
+        let temp_dir = tempdir().unwrap();
+        let application_error_path = temp_dir.path().to_str().unwrap().to_string();
+
+        // events persisted within the same second should not overwrite each other
+        for (index, state) in ["open", "half-open", "closed"].iter().enumerate() {
+            let grpc_status = Status::unavailable(format!(
+                "SINK_CIRCUIT_BREAKER(numa): circuit breaker is {state}"
+            ));
+            persist_status_to_file(
+                application_error_path.clone(),
+                5,
+                grpc_status,
+                &format!("numa-{index}"),
+            )
+            .unwrap();
+        }
+
+        let runtime = Runtime::new(Some(RuntimeInfoConfig {
+            app_error_path: application_error_path,
+            max_error_files_per_container: 5,
+        }));
+        let errors = runtime.get_application_errors().unwrap();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|error| error.container == "numa"));
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.message.ends_with("circuit breaker is half-open"))
+        );
+    }
+
     #[test]
     fn test_get_application_errors() {
         // Create a temporary directory
@@ -32,6 +32,32 @@ pub struct WindowManagerState {
     #[prost(int64, tag = "2")]
     pub max_deleted_window_end_time: i64,
 }
+/// KeyedWindowState is the state of the windows of a key of an unaligned window manager.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyedWindowState {
+    /// keys of the windows
+    #[prost(string, repeated, tag = "1")]
+    pub keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// active and closed session windows of the key which are not garbage collected yet
+    #[prost(message, repeated, tag = "2")]
+    pub windows: ::prost::alloc::vec::Vec<Window>,
+    /// event times of the messages of the accumulator window which are not processed yet
+    #[prost(message, repeated, tag = "3")]
+    pub message_timestamps: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+    /// end time till which the messages of the key have been processed (garbage collected)
+    #[prost(message, optional, tag = "4")]
+    pub gc_end_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// UnalignedWindowManagerState is the snapshot of the unaligned (session and accumulator) window manager
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnalignedWindowManagerState {
+    /// offset of the latest message assigned to the windows, the snapshot covers the messages till this offset
+    #[prost(int64, tag = "1")]
+    pub offset: i64,
+    /// state of the windows of every key
+    #[prost(message, repeated, tag = "2")]
+    pub states: ::prost::alloc::vec::Vec<KeyedWindowState>,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Footer {
     /// the latest event time in the current Segment
@@ -14,6 +14,7 @@ use std::error::Error as StdError;
 use std::pin::Pin;
 
 pub mod jetstream;
+pub mod redis;
 
 /// Error type for KV operations (boxed for object safety)
 pub type KVError = Box<dyn StdError + Send + Sync + 'static>;
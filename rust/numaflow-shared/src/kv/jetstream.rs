@@ -4,7 +4,7 @@
 
 use super::{KVEntry, KVError, KVStore, KVWatchOp, KVWatchStream};
 use crate::isb::jetstream::JetstreamWatcher;
-use async_nats::jetstream::kv::{Entry, Store};
+use async_nats::jetstream::kv::{CreateErrorKind, Entry, Store, UpdateErrorKind};
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::{Stream, StreamExt, TryStreamExt};
@@ -65,6 +65,48 @@ impl JetstreamKVStore {
     pub fn new(store: Store, name: &'static str) -> Self {
         Self { store, name }
     }
+
+    /// Get the value for a given key along with its revision, which can be passed to
+    /// [`Self::compare_and_swap`]. Deleted keys are returned as `None`.
+    pub async fn entry(&self, key: &str) -> Result<Option<(Bytes, u64)>, KVError> {
+        let entry = self
+            .store
+            .entry(key)
+            .await
+            .map_err(|e| Box::new(e) as KVError)?;
+
+        Ok(entry
+            .filter(|e| e.operation == async_nats::jetstream::kv::Operation::Put)
+            .map(|e| (e.value, e.revision)))
+    }
+
+    /// Write the value only if the key was not modified since `revision`, or, when `revision` is
+    /// `None`, only if the key does not exist. Returns `false` if the key was modified concurrently,
+    /// in which case the caller should read the entry again and retry.
+    ///
+    /// # Arguments
+    /// * `key` - The key to write
+    /// * `value` - The value to store
+    /// * `revision` - The revision of the key returned by [`Self::entry`]
+    pub async fn compare_and_swap(
+        &self,
+        key: &str,
+        value: Bytes,
+        revision: Option<u64>,
+    ) -> Result<bool, KVError> {
+        match revision {
+            None => match self.store.create(key, value).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind() == CreateErrorKind::AlreadyExists => Ok(false),
+                Err(e) => Err(Box::new(e) as KVError),
+            },
+            Some(revision) => match self.store.update(key, value, revision).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind() == UpdateErrorKind::WrongLastRevision => Ok(false),
+                Err(e) => Err(Box::new(e) as KVError),
+            },
+        }
+    }
 }
 
 #[async_trait]
@@ -287,6 +329,61 @@ mod tests {
         cleanup_test_kv(&js, bucket_name).await;
     }
 
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_jetstream_kv_store_compare_and_swap() {
+        let bucket_name = "test-kv-compare-and-swap";
+        let (js, store) = setup_test_kv(bucket_name).await;
+
+        let kv_store = JetstreamKVStore::new(store, bucket_name);
+        assert!(kv_store.entry("key").await.unwrap().is_none());
+
+        // create only succeeds if the key does not exist
+        assert!(
+            kv_store
+                .compare_and_swap("key", Bytes::from("v1"), None)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !kv_store
+                .compare_and_swap("key", Bytes::from("v2"), None)
+                .await
+                .unwrap()
+        );
+
+        let (value, revision) = kv_store.entry("key").await.unwrap().unwrap();
+        assert_eq!(value, Bytes::from("v1"));
+
+        // update only succeeds at the latest revision
+        assert!(
+            kv_store
+                .compare_and_swap("key", Bytes::from("v2"), Some(revision))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !kv_store
+                .compare_and_swap("key", Bytes::from("v3"), Some(revision))
+                .await
+                .unwrap()
+        );
+        let (value, _) = kv_store.entry("key").await.unwrap().unwrap();
+        assert_eq!(value, Bytes::from("v2"));
+
+        // deleted keys can be created again
+        kv_store.delete("key").await.unwrap();
+        assert!(kv_store.entry("key").await.unwrap().is_none());
+        assert!(
+            kv_store
+                .compare_and_swap("key", Bytes::from("v4"), None)
+                .await
+                .unwrap()
+        );
+
+        cleanup_test_kv(&js, bucket_name).await;
+    }
+
     #[test]
     fn test_kv_entry_struct() {
         let entry = KVEntry {
@@ -0,0 +1,303 @@
+//! Redis implementation of the KV store traits.
+//!
+//! A bucket is a Redis hash holding the key-value pairs. Every change of the bucket is also
+//! appended to a Redis Stream named `<bucket>:watch` in the same transaction, which is what
+//! [`KVStore::watch`] reads. The stream is capped at about [`WATCH_STREAM_MAX_LEN`] entries, a
+//! watcher which falls behind by more than that misses the trimmed changes.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use redis::streams::{StreamId, StreamRangeReply, StreamReadOptions, StreamReadReply};
+use tracing::warn;
+
+use super::{KVEntry, KVError, KVStore, KVWatchOp, KVWatchStream};
+
+/// Approximate number of changes kept in the watch stream of a bucket.
+const WATCH_STREAM_MAX_LEN: usize = 1000;
+/// How long a watcher blocks waiting for new changes before asking again.
+const WATCH_BLOCK: Duration = Duration::from_secs(5);
+/// How long a watcher waits before reading again after a failed read.
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+/// Maximum number of changes read at once by a watcher.
+const WATCH_BATCH_SIZE: usize = 100;
+
+/// Redis KV Store implementation.
+///
+/// Use this as `Arc<dyn KVStore>` for dynamic dispatch.
+pub struct RedisKVStore {
+    client: redis::Client,
+    conn: ConnectionManager,
+    name: &'static str,
+    watch_stream: String,
+}
+
+impl RedisKVStore {
+    /// Create a new RedisKVStore for the bucket, the hash of the bucket is created by the first
+    /// write.
+    ///
+    /// # Arguments
+    /// * `client` - The Redis client, the watchers get their own connections from it
+    /// * `name` - The name of the bucket
+    pub async fn new(client: redis::Client, name: &'static str) -> Result<Self, KVError> {
+        let conn = client
+            .get_connection_manager()
+            .await
+            .map_err(|e| Box::new(e) as KVError)?;
+        Ok(Self {
+            client,
+            conn,
+            name,
+            watch_stream: format!("{name}:watch"),
+        })
+    }
+
+    /// Appends the change to the watch stream of the bucket as part of the pipeline.
+    fn record_change(&self, pipe: &mut redis::Pipeline, op: &str, key: &str, value: &[u8]) {
+        pipe.cmd("XADD")
+            .arg(&self.watch_stream)
+            .arg("MAXLEN")
+            .arg("~")
+            .arg(WATCH_STREAM_MAX_LEN)
+            .arg("*")
+            .arg("op")
+            .arg(op)
+            .arg("key")
+            .arg(key)
+            .arg("value")
+            .arg(value)
+            .ignore();
+    }
+}
+
+#[async_trait]
+impl KVStore for RedisKVStore {
+    async fn keys(&self) -> Result<Vec<String>, KVError> {
+        self.conn
+            .clone()
+            .hkeys(self.name)
+            .await
+            .map_err(|e| Box::new(e) as KVError)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), KVError> {
+        let mut pipe = redis::pipe();
+        pipe.atomic().hdel(self.name, key).ignore();
+        self.record_change(&mut pipe, "delete", key, &[]);
+        pipe.query_async::<()>(&mut self.conn.clone())
+            .await
+            .map_err(|e| Box::new(e) as KVError)
+    }
+
+    async fn put(&self, key: &str, value: Bytes) -> Result<(), KVError> {
+        let mut pipe = redis::pipe();
+        pipe.atomic().hset(self.name, key, value.as_ref()).ignore();
+        self.record_change(&mut pipe, "put", key, &value);
+        pipe.query_async::<()>(&mut self.conn.clone())
+            .await
+            .map_err(|e| Box::new(e) as KVError)
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Bytes>, KVError> {
+        let value: Option<Vec<u8>> = self
+            .conn
+            .clone()
+            .hget(self.name, key)
+            .await
+            .map_err(|e| Box::new(e) as KVError)?;
+        Ok(value.map(Bytes::from))
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Only watching the new changes is supported, since the changes are not versioned.
+    async fn watch(&self, revision: Option<u64>) -> Result<KVWatchStream, KVError> {
+        if let Some(revision) = revision {
+            return Err(format!(
+                "Watching bucket {} from revision {revision} is not supported",
+                self.name
+            )
+            .into());
+        }
+
+        // a connection of its own, since the blocking reads would hold up the other commands
+        let mut conn = self
+            .client
+            .get_connection_manager()
+            .await
+            .map_err(|e| Box::new(e) as KVError)?;
+        let last: StreamRangeReply = conn
+            .xrevrange_count(&self.watch_stream, "+", "-", 1)
+            .await
+            .map_err(|e| Box::new(e) as KVError)?;
+        let last_id = last
+            .ids
+            .into_iter()
+            .next()
+            .map(|entry| entry.id)
+            .unwrap_or_else(|| "0-0".to_string());
+
+        let state = WatchState {
+            conn,
+            stream: self.watch_stream.clone(),
+            last_id,
+            changes: VecDeque::new(),
+        };
+        Ok(Box::pin(futures::stream::unfold(state, next_change)))
+    }
+}
+
+/// State of a watcher, the changes after `last_id` are yet to be read.
+struct WatchState {
+    conn: ConnectionManager,
+    stream: String,
+    last_id: String,
+    changes: VecDeque<KVEntry>,
+}
+
+/// Returns the next change of the bucket, the watcher keeps retrying when the reads fail since the
+/// connection is re-established by the connection manager.
+async fn next_change(mut state: WatchState) -> Option<(KVEntry, WatchState)> {
+    loop {
+        if let Some(change) = state.changes.pop_front() {
+            return Some((change, state));
+        }
+
+        let opts = StreamReadOptions::default()
+            .block(WATCH_BLOCK.as_millis() as usize)
+            .count(WATCH_BATCH_SIZE);
+        let reply: redis::RedisResult<Option<StreamReadReply>> = state
+            .conn
+            .xread_options(&[&state.stream], &[&state.last_id], &opts)
+            .await;
+        match reply {
+            Ok(reply) => {
+                for entry in reply
+                    .into_iter()
+                    .flat_map(|reply| reply.keys)
+                    .flat_map(|key| key.ids)
+                {
+                    state.last_id.clone_from(&entry.id);
+                    match decode_change(&entry) {
+                        Some(change) => state.changes.push_back(change),
+                        None => warn!(stream = state.stream, id = entry.id, "Invalid change"),
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(
+                    ?e,
+                    stream = state.stream,
+                    "Failed to read changes, retrying"
+                );
+                tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+            }
+        }
+    }
+}
+
+/// Converts an entry of the watch stream to a [KVEntry].
+fn decode_change(entry: &StreamId) -> Option<KVEntry> {
+    let operation = match entry.get::<String>("op")?.as_str() {
+        "put" => KVWatchOp::Put,
+        "delete" => KVWatchOp::Delete,
+        _ => return None,
+    };
+    Some(KVEntry {
+        key: entry.get("key")?,
+        value: Bytes::from(entry.get::<Vec<u8>>("value").unwrap_or_default()),
+        operation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn entry(fields: &[(&str, &str)]) -> StreamId {
+        StreamId {
+            id: "1700000000000-0".to_string(),
+            map: fields
+                .iter()
+                .map(|(field, value)| {
+                    (
+                        field.to_string(),
+                        redis::Value::BulkString(value.as_bytes().to_vec()),
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_decode_change() {
+        let change = decode_change(&entry(&[("op", "put"), ("key", "k"), ("value", "v")])).unwrap();
+        assert_eq!(change.key, "k");
+        assert_eq!(change.value, Bytes::from("v"));
+        assert_eq!(change.operation, KVWatchOp::Put);
+
+        let change =
+            decode_change(&entry(&[("op", "delete"), ("key", "k"), ("value", "")])).unwrap();
+        assert_eq!(change.operation, KVWatchOp::Delete);
+        assert!(change.value.is_empty());
+
+        assert!(decode_change(&entry(&[("op", "purge"), ("key", "k")])).is_none());
+        assert!(decode_change(&entry(&[("op", "put")])).is_none());
+    }
+
+    #[cfg(feature = "redis-tests")]
+    #[tokio::test]
+    async fn test_redis_kv_store() {
+        use futures::StreamExt;
+
+        let bucket = "test-redis-kv-store";
+        let client = redis::Client::open("redis://localhost:6379").unwrap();
+        let mut conn = client.get_connection_manager().await.unwrap();
+        let _: () = redis::cmd("DEL")
+            .arg(bucket)
+            .arg(format!("{bucket}:watch"))
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+
+        let store = RedisKVStore::new(client, bucket).await.unwrap();
+        store.put("key1", Bytes::from("value1")).await.unwrap();
+        assert!(store.watch(Some(1)).await.is_err());
+
+        // only the changes after the watch started are seen
+        let mut watch = store.watch(None).await.unwrap();
+        store.put("key2", Bytes::from("value2")).await.unwrap();
+        store.delete("key1").await.unwrap();
+
+        let change = watch.next().await.unwrap();
+        assert_eq!(change.key, "key2");
+        assert_eq!(change.value, Bytes::from("value2"));
+        assert_eq!(change.operation, KVWatchOp::Put);
+        let change = watch.next().await.unwrap();
+        assert_eq!(change.key, "key1");
+        assert_eq!(change.operation, KVWatchOp::Delete);
+
+        assert_eq!(store.keys().await.unwrap(), vec!["key2".to_string()]);
+        assert_eq!(
+            store.get("key2").await.unwrap(),
+            Some(Bytes::from("value2"))
+        );
+        assert_eq!(store.get("key1").await.unwrap(), None);
+        assert_eq!(store.name(), bucket);
+
+        let _: () = redis::cmd("DEL")
+            .arg(bucket)
+            .arg(format!("{bucket}:watch"))
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+    }
+}
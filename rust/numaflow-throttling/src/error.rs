@@ -10,6 +10,9 @@ pub enum Error {
     #[error("RedisStore Error - {0}")]
     RedisStore(String),
 
+    #[error("JetStreamStore Error - {0}")]
+    JetStreamStore(String),
+
     #[error("CancellationToken Cancelled")]
     Cancellation,
 
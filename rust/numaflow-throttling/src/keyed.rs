@@ -0,0 +1,346 @@
+//! Keyed rate limiting hands out tokens from a separate token bucket per key (e.g., per tenant), so
+//! that a single noisy key cannot starve the rest. The buckets of all the keys share the
+//! distributed state of the rate limiter they are created from, i.e., the pool size is still
+//! agreed upon per vertex through the [Store](crate::state::Store), and every bucket divides its
+//! tokens by the same pool size. Hence, every store supports keyed rate limiting.
+//!
+//! Only the most recently used `max_keys` buckets are kept, the least recently used one is
+//! evicted when a new key shows up. An evicted key starts over from its `min` tokens.
+
+use crate::{NoOpRateLimiter, RateLimit, RateLimiter, Result, TokenCalcBounds};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, AtomicUsize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// KeyedRateLimiter exposes the same methods as [RateLimiter], but for the token bucket of a given
+/// key.
+#[trait_variant::make(Send)]
+pub trait KeyedRateLimiter {
+    /// Acquire `n` tokens of the given key, see [RateLimiter::acquire_n].
+    async fn acquire_n(&self, key: &str, n: Option<usize>, timeout: Option<Duration>) -> usize;
+
+    /// Deposit the unused tokens of the given key, see [RateLimiter::deposit_unused].
+    async fn deposit_unused(&self, key: &str, n: usize);
+
+    /// Shutdown the rate limiter and clean up resources, see [RateLimiter::shutdown].
+    async fn shutdown(&self) -> Result<()>;
+}
+
+/// IntoKeyed creates a [KeyedRateLimiter] out of a rate limiter, sharing its state.
+pub trait IntoKeyed {
+    type Keyed: KeyedRateLimiter + Clone + Send + Sync + 'static;
+
+    /// Creates a [KeyedRateLimiter] with the given configuration.
+    fn keyed(&self, config: KeyedConfig) -> Self::Keyed;
+}
+
+/// Configuration of the token buckets of a [KeyedRateLimit].
+#[derive(Clone, Debug)]
+pub struct KeyedConfig {
+    /// Bounds of the keys without an override.
+    bounds: TokenCalcBounds,
+    /// key -> bounds of the key
+    overrides: HashMap<String, TokenCalcBounds>,
+    /// Maximum number of token buckets to keep.
+    max_keys: usize,
+}
+
+impl KeyedConfig {
+    /// Every key gets its own token bucket with the given `bounds`, and at most `max_keys` buckets
+    /// are kept.
+    pub fn new(bounds: TokenCalcBounds, max_keys: usize) -> Self {
+        KeyedConfig {
+            bounds,
+            overrides: HashMap::new(),
+            max_keys: max_keys.max(1),
+        }
+    }
+
+    /// Overrides the bounds of the given key.
+    pub fn with_override(mut self, key: impl Into<String>, bounds: TokenCalcBounds) -> Self {
+        self.overrides.insert(key.into(), bounds);
+        self
+    }
+
+    fn bounds(&self, key: &str) -> &TokenCalcBounds {
+        self.overrides.get(key).unwrap_or(&self.bounds)
+    }
+}
+
+/// KeyedRateLimit keeps a [RateLimit] per key, all of them sharing the state `W`.
+#[derive(Clone)]
+pub struct KeyedRateLimit<W> {
+    config: Arc<KeyedConfig>,
+    buckets: Arc<Mutex<Buckets<W>>>,
+    state: W,
+}
+
+/// Least recently used cache of the token buckets.
+struct Buckets<W> {
+    /// key -> (bucket, last use)
+    entries: HashMap<String, (RateLimit<W>, u64)>,
+    /// last use -> key, to find the least recently used bucket.
+    recency: BTreeMap<u64, String>,
+    /// Incremented on every use.
+    clock: u64,
+}
+
+impl<W: Clone> Buckets<W> {
+    fn new() -> Self {
+        Buckets {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Returns the bucket of the key, creating it (and evicting the least recently used bucket if
+    /// there are already `max_keys` buckets) if it does not exist.
+    fn get_or_insert(&mut self, key: &str, config: &KeyedConfig, state: &W) -> RateLimit<W> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        if let Some((bucket, last_use)) = self.entries.get_mut(key) {
+            self.recency.remove(last_use);
+            *last_use = clock;
+            self.recency.insert(clock, key.to_string());
+            return bucket.clone();
+        }
+
+        if self.entries.len() >= config.max_keys
+            && let Some((_, evicted)) = self.recency.pop_first()
+        {
+            self.entries.remove(&evicted);
+        }
+
+        let bucket = RateLimit::bucket(config.bounds(key).clone(), state.clone());
+        self.entries
+            .insert(key.to_string(), (bucket.clone(), clock));
+        self.recency.insert(clock, key.to_string());
+        bucket
+    }
+
+    fn get(&self, key: &str) -> Option<RateLimit<W>> {
+        self.entries.get(key).map(|(bucket, _)| bucket.clone())
+    }
+}
+
+impl<W> RateLimit<W> {
+    /// Creates a token bucket which shares the given state, starting from the `min` tokens.
+    fn bucket(token_calc_bounds: TokenCalcBounds, state: W) -> Self {
+        let burst = token_calc_bounds.min;
+        RateLimit {
+            token_calc_bounds,
+            token: Arc::new(AtomicUsize::new(burst)),
+            max_ever_filled: Arc::new(Mutex::new(burst as f32)),
+            last_queried_epoch: Arc::new(AtomicU64::new(0)),
+            state,
+        }
+    }
+}
+
+impl<W: Clone> KeyedRateLimit<W> {
+    /// Number of token buckets currently kept.
+    pub fn len(&self) -> usize {
+        self.buckets.lock().unwrap().entries.len()
+    }
+
+    /// Returns true if no token bucket is kept.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn bucket(&self, key: &str) -> RateLimit<W> {
+        self.buckets
+            .lock()
+            .unwrap()
+            .get_or_insert(key, &self.config, &self.state)
+    }
+}
+
+impl<W: Clone + Send + Sync + 'static> IntoKeyed for RateLimit<W>
+where
+    RateLimit<W>: RateLimiter + Sync,
+{
+    type Keyed = KeyedRateLimit<W>;
+
+    fn keyed(&self, config: KeyedConfig) -> Self::Keyed {
+        KeyedRateLimit {
+            config: Arc::new(config),
+            buckets: Arc::new(Mutex::new(Buckets::new())),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<W: Clone + Send + Sync + 'static> KeyedRateLimiter for KeyedRateLimit<W>
+where
+    RateLimit<W>: RateLimiter + Sync,
+{
+    async fn acquire_n(&self, key: &str, n: Option<usize>, timeout: Option<Duration>) -> usize {
+        self.bucket(key).acquire_n(n, timeout).await
+    }
+
+    /// Deposits the unused tokens, a no-op if the bucket of the key was evicted in the meantime.
+    async fn deposit_unused(&self, key: &str, n: usize) {
+        let bucket = self.buckets.lock().unwrap().get(key);
+        if let Some(bucket) = bucket {
+            bucket.deposit_unused(n).await;
+        }
+    }
+
+    /// Shuts down the shared state through the bucket which ramped up the furthest, so that it is
+    /// what a restarted processor resumes from.
+    async fn shutdown(&self) -> Result<()> {
+        let bucket = self
+            .buckets
+            .lock()
+            .unwrap()
+            .entries
+            .values()
+            .map(|(bucket, _)| bucket)
+            .max_by(|a, b| {
+                let a = *a.max_ever_filled.lock().unwrap();
+                let b = *b.max_ever_filled.lock().unwrap();
+                a.total_cmp(&b)
+            })
+            .cloned();
+
+        let bucket = bucket
+            .unwrap_or_else(|| RateLimit::bucket(self.config.bounds.clone(), self.state.clone()));
+        bucket.shutdown().await
+    }
+}
+
+impl IntoKeyed for NoOpRateLimiter {
+    type Keyed = NoOpRateLimiter;
+
+    fn keyed(&self, _config: KeyedConfig) -> Self::Keyed {
+        NoOpRateLimiter
+    }
+}
+
+impl KeyedRateLimiter for NoOpRateLimiter {
+    async fn acquire_n(&self, _key: &str, n: Option<usize>, _timeout: Option<Duration>) -> usize {
+        n.unwrap_or(usize::MAX)
+    }
+
+    async fn deposit_unused(&self, _key: &str, _n: usize) {}
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::OptimisticValidityUpdateSecs;
+    use crate::state::store::in_memory_store::InMemoryStore;
+    use crate::{Mode, WithState, WithoutState};
+    use tokio_util::sync::CancellationToken;
+
+    fn bounds(max: usize, min: usize) -> TokenCalcBounds {
+        TokenCalcBounds::new(max, min, Duration::from_secs(1), Mode::Relaxed)
+    }
+
+    #[tokio::test]
+    async fn test_keys_have_separate_buckets() {
+        // start at the top of a second, so that the buckets are not refilled midway
+        crate::sleep_until_next_sec().await;
+        let rate_limiter = RateLimit::<WithoutState>::new(bounds(10, 10)).unwrap();
+        let keyed = rate_limiter.keyed(KeyedConfig::new(bounds(10, 10), 100));
+
+        // the noisy key exhausts its own tokens only
+        assert_eq!(keyed.acquire_n("noisy", None, None).await, 10);
+        assert_eq!(keyed.acquire_n("noisy", Some(1), None).await, 0);
+        assert_eq!(keyed.acquire_n("quiet", Some(5), None).await, 5);
+        assert_eq!(keyed.acquire_n("quiet", None, None).await, 5);
+        assert_eq!(keyed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_key_overrides() {
+        // start at the top of a second, so that the buckets are not refilled midway
+        crate::sleep_until_next_sec().await;
+        let rate_limiter = RateLimit::<WithoutState>::new(bounds(10, 10)).unwrap();
+        let keyed = rate_limiter.keyed(
+            KeyedConfig::new(bounds(10, 10), 100).with_override("premium", bounds(100, 100)),
+        );
+
+        assert_eq!(keyed.acquire_n("premium", None, None).await, 100);
+        assert_eq!(keyed.acquire_n("other", None, None).await, 10);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_key_is_evicted() {
+        // start at the top of a second, so that the buckets are not refilled midway
+        crate::sleep_until_next_sec().await;
+        let rate_limiter = RateLimit::<WithoutState>::new(bounds(10, 10)).unwrap();
+        let keyed = rate_limiter.keyed(KeyedConfig::new(bounds(10, 10), 2));
+
+        assert_eq!(keyed.acquire_n("a", None, None).await, 10);
+        assert_eq!(keyed.acquire_n("b", None, None).await, 10);
+        // use "a" again, so that "b" is the least recently used key
+        assert_eq!(keyed.acquire_n("a", None, None).await, 0);
+        assert_eq!(keyed.acquire_n("c", None, None).await, 10);
+        assert_eq!(keyed.len(), 2);
+
+        // "a" was kept, "b" starts over with a new bucket
+        assert_eq!(keyed.acquire_n("a", None, None).await, 0);
+        assert_eq!(keyed.acquire_n("b", None, None).await, 10);
+    }
+
+    #[tokio::test]
+    async fn test_deposit_unused() {
+        // start at the top of a second, so that the buckets are not refilled midway
+        crate::sleep_until_next_sec().await;
+        let rate_limiter = RateLimit::<WithoutState>::new(bounds(10, 10)).unwrap();
+        let keyed = rate_limiter.keyed(KeyedConfig::new(bounds(10, 10), 100));
+
+        assert_eq!(keyed.acquire_n("a", None, None).await, 10);
+        keyed.deposit_unused("a", 4).await;
+        // unknown keys are ignored
+        keyed.deposit_unused("b", 4).await;
+        assert_eq!(keyed.len(), 1);
+
+        assert_eq!(keyed.acquire_n("a", None, None).await, 4);
+    }
+
+    #[tokio::test]
+    async fn test_keyed_with_state_shares_pool_size() {
+        let store = InMemoryStore::new(180);
+        let cancel = CancellationToken::new();
+
+        let mut rate_limiters = vec![];
+        for processor_id in ["processor_a", "processor_b"] {
+            let store = store.clone();
+            let cancel = cancel.clone();
+            rate_limiters.push(tokio::spawn(async move {
+                RateLimit::<WithState<InMemoryStore>>::new(
+                    bounds(20, 20),
+                    store,
+                    processor_id,
+                    cancel,
+                    Duration::from_millis(100),
+                    OptimisticValidityUpdateSecs::default(),
+                    false,
+                )
+                .await
+                .unwrap()
+            }));
+        }
+        let rate_limiter = rate_limiters.pop().unwrap().await.unwrap();
+        let other = rate_limiters.pop().unwrap().await.unwrap();
+
+        let keyed = rate_limiter.keyed(KeyedConfig::new(bounds(20, 20), 100));
+        // the tokens of every key are divided among the 2 processors
+        assert_eq!(keyed.acquire_n("a", None, None).await, 10);
+        assert_eq!(keyed.acquire_n("b", None, None).await, 10);
+
+        keyed.shutdown().await.unwrap();
+        other.shutdown().await.unwrap();
+        cancel.cancel();
+    }
+}
@@ -16,6 +16,12 @@ pub(crate) use error::Result;
 /// holds distributed consensus state required to compute the available tokens.
 pub mod state;
 
+/// Keyed module contains the [KeyedRateLimiter] which keeps a token bucket per key, sharing the
+/// [RateLimiterState] of the rate limiter it is created from.
+pub mod keyed;
+
+pub use keyed::{IntoKeyed, KeyedConfig, KeyedRateLimit, KeyedRateLimiter};
+
 #[derive(Clone)]
 pub struct WithoutState;
 
@@ -2,6 +2,7 @@ use crate::state::Consensus;
 use tokio_util::sync::CancellationToken;
 
 pub mod in_memory_store;
+pub mod jetstream_store;
 pub mod redis_store;
 
 /// Store is the trait that defines the interface for the external store. It is
@@ -0,0 +1,393 @@
+//! JetStream KV implementation of the Store trait. Every pipeline already has a JetStream ISB, so
+//! this lets the processors of a vertex coordinate the rate limit without running Redis.
+//!
+//! The state of the pool is kept as a single JSON document under the key prefix, and every
+//! operation is a read-modify-write of the document which is only written if the revision of the
+//! key did not change in the meantime (compare-and-swap), and retried otherwise. The operations
+//! are the equivalent of the Lua scripts used by the [RedisStore](super::redis_store::RedisStore).
+
+use crate::error::Error;
+use crate::state::Consensus;
+use crate::state::store::Store;
+use numaflow_shared::kv::jetstream::JetstreamKVStore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+#[derive(Clone)]
+pub struct JetStreamKVStore {
+    /// Key of the pool state in the bucket, fixed for a given Vertex.
+    key: &'static str,
+    kv: Arc<JetstreamKVStore>,
+    stale_age: u64,
+}
+
+/// State of the pool shared by all the processors. The timestamps are in seconds since epoch.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PoolState {
+    /// processor_id -> last heartbeat
+    heartbeats: BTreeMap<String, u64>,
+    /// processor_id -> reported pool size
+    pool_sizes: BTreeMap<String, usize>,
+    /// processor_id -> (max_ever_filled, deregistration time)
+    prev_max_filled: BTreeMap<String, (f32, u64)>,
+}
+
+impl PoolState {
+    /// Adds the heartbeat of the processor, sets its pool size to the number of processors, and
+    /// returns it along with the max_ever_filled of the processor if it was recently deregistered.
+    /// Same as lua/register.lua.
+    fn register(&mut self, processor_id: &str, now: u64, stale_age: u64) -> (usize, f32) {
+        self.heartbeats.insert(processor_id.to_string(), now);
+        let pool_size = self.heartbeats.len();
+        self.pool_sizes.insert(processor_id.to_string(), pool_size);
+
+        let stale_threshold = now.saturating_sub(stale_age);
+        self.prev_max_filled
+            .retain(|_, (_, deregistered_at)| *deregistered_at >= stale_threshold);
+
+        let prev_max_filled = self
+            .prev_max_filled
+            .get(processor_id)
+            .map(|(max_filled, _)| *max_filled)
+            .unwrap_or_default();
+
+        (pool_size, prev_max_filled)
+    }
+
+    /// Removes the processor from the pool and stores its max_ever_filled. Same as
+    /// lua/deregister.lua.
+    fn deregister(&mut self, processor_id: &str, prev_max_filled: f32, now: u64) {
+        self.heartbeats.remove(processor_id);
+        self.pool_sizes.remove(processor_id);
+        self.prev_max_filled
+            .insert(processor_id.to_string(), (prev_max_filled, now));
+    }
+
+    /// Updates the heartbeat and the pool size of the processor, evicts the stale processors, and
+    /// checks whether all the active processors agree on the pool size. Same as
+    /// lua/sync_pool_size.lua.
+    fn sync_pool_size(
+        &mut self,
+        processor_id: &str,
+        pool_size: usize,
+        now: u64,
+        stale_age: u64,
+    ) -> Consensus {
+        self.heartbeats.insert(processor_id.to_string(), now);
+        self.pool_sizes.insert(processor_id.to_string(), pool_size);
+
+        let stale_threshold = now.saturating_sub(stale_age);
+        let stale: Vec<String> = self
+            .heartbeats
+            .iter()
+            .filter(|(_, heartbeat)| **heartbeat < stale_threshold)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in stale {
+            info!(processor_id = id, "Evicting stale processor");
+            self.heartbeats.remove(&id);
+            self.pool_sizes.remove(&id);
+        }
+
+        let max_pool_size = self.pool_sizes.values().max().copied().unwrap_or_default();
+        if self.heartbeats.is_empty() || self.heartbeats.len() != self.pool_sizes.len() {
+            return Consensus::Disagree(max_pool_size);
+        }
+
+        let min_pool_size = self.pool_sizes.values().min().copied().unwrap_or_default();
+        if min_pool_size == max_pool_size {
+            Consensus::Agree(min_pool_size)
+        } else {
+            Consensus::Disagree(max_pool_size)
+        }
+    }
+}
+
+impl JetStreamKVStore {
+    /// Creates a new store keeping the pool state under `key` in the given KV bucket. The key has
+    /// to be a valid NATS KV key, i.e., only `[-/_=.a-zA-Z0-9]`.
+    pub fn new(key: &'static str, stale_age: usize, kv: JetstreamKVStore) -> Self {
+        Self {
+            key,
+            kv: Arc::new(kv),
+            stale_age: stale_age as u64,
+        }
+    }
+
+    /// Applies `f` to the latest pool state and writes it back, retrying on concurrent updates
+    /// from the other processors until the write succeeds or the token is cancelled.
+    async fn update<T>(
+        &self,
+        cancel: &CancellationToken,
+        f: impl Fn(&mut PoolState, u64) -> T,
+    ) -> crate::Result<T> {
+        loop {
+            if cancel.is_cancelled() {
+                return Err(Error::Cancellation);
+            }
+
+            let entry = self
+                .kv
+                .entry(self.key)
+                .await
+                .map_err(|e| Error::JetStreamStore(format!("Failed to get pool state: {e}")))?;
+
+            let (mut state, revision) = match entry {
+                Some((value, revision)) => (
+                    serde_json::from_slice::<PoolState>(&value)
+                        .map_err(|e| Error::JetStreamStore(format!("Invalid pool state: {e}")))?,
+                    Some(revision),
+                ),
+                None => (PoolState::default(), None),
+            };
+
+            let result = f(&mut state, now());
+
+            let value = serde_json::to_vec(&state)
+                .map_err(|e| Error::JetStreamStore(format!("Failed to encode pool state: {e}")))?;
+            let swapped = self
+                .kv
+                .compare_and_swap(self.key, value.into(), revision)
+                .await
+                .map_err(|e| Error::JetStreamStore(format!("Failed to update pool state: {e}")))?;
+
+            if swapped {
+                return Ok(result);
+            }
+            debug!(key = self.key, "Pool state updated concurrently, retrying");
+        }
+    }
+}
+
+/// Current time in seconds since epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
+
+impl Store for JetStreamKVStore {
+    async fn register(
+        &self,
+        processor_id: &str,
+        cancel: CancellationToken,
+    ) -> crate::Result<(usize, f32)> {
+        info!("Registering processor: {processor_id}");
+        self.update(&cancel, |state, now| {
+            state.register(processor_id, now, self.stale_age)
+        })
+        .await
+    }
+
+    async fn deregister(
+        &self,
+        processor_id: &str,
+        prev_max_filled: f32,
+        cancel: CancellationToken,
+    ) -> crate::Result<()> {
+        info!("Deregistering processor: {processor_id}");
+        self.update(&cancel, |state, now| {
+            state.deregister(processor_id, prev_max_filled, now)
+        })
+        .await
+    }
+
+    async fn sync_pool_size(
+        &self,
+        processor_id: &str,
+        pool_size: usize,
+        cancel: CancellationToken,
+    ) -> crate::Result<Consensus> {
+        self.update(&cancel, |state, now| {
+            state.sync_pool_size(processor_id, pool_size, now, self.stale_age)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_assigns_pool_size() {
+        let mut state = PoolState::default();
+
+        assert_eq!(state.register("processor_a", 100, 180), (1, 0.0));
+        assert_eq!(state.register("processor_b", 100, 180), (2, 0.0));
+        // registering again only updates the heartbeat
+        assert_eq!(state.register("processor_a", 101, 180), (2, 0.0));
+    }
+
+    #[test]
+    fn test_sync_pool_size_consensus() {
+        let mut state = PoolState::default();
+        state.register("processor_a", 100, 180);
+        state.register("processor_b", 100, 180);
+
+        // processor_a was registered with pool size 1
+        let consensus = state.sync_pool_size("processor_b", 2, 101, 180);
+        assert!(matches!(consensus, Consensus::Disagree(2)));
+
+        let consensus = state.sync_pool_size("processor_a", 2, 101, 180);
+        assert!(matches!(consensus, Consensus::Agree(2)));
+
+        let consensus = state.sync_pool_size("processor_a", 3, 102, 180);
+        assert!(matches!(consensus, Consensus::Disagree(3)));
+    }
+
+    #[test]
+    fn test_sync_pool_size_evicts_stale_processors() {
+        let mut state = PoolState::default();
+        state.register("processor_a", 100, 10);
+        state.register("processor_b", 100, 10);
+        state.sync_pool_size("processor_a", 2, 100, 10);
+        state.sync_pool_size("processor_b", 2, 100, 10);
+
+        // processor_a stops sending heartbeats
+        let consensus = state.sync_pool_size("processor_b", 2, 110, 10);
+        assert!(matches!(consensus, Consensus::Agree(2)));
+
+        let consensus = state.sync_pool_size("processor_b", 2, 111, 10);
+        assert!(matches!(consensus, Consensus::Agree(2)));
+        assert!(!state.heartbeats.contains_key("processor_a"));
+        assert!(!state.pool_sizes.contains_key("processor_a"));
+
+        let consensus = state.sync_pool_size("processor_b", 1, 112, 10);
+        assert!(matches!(consensus, Consensus::Agree(1)));
+    }
+
+    #[test]
+    fn test_deregister_keeps_prev_max_filled() {
+        let mut state = PoolState::default();
+        state.register("processor_a", 100, 10);
+        state.register("processor_b", 100, 10);
+
+        state.deregister("processor_a", 10.0, 105);
+        assert!(!state.heartbeats.contains_key("processor_a"));
+        assert!(!state.pool_sizes.contains_key("processor_a"));
+
+        let consensus = state.sync_pool_size("processor_b", 1, 106, 10);
+        assert!(matches!(consensus, Consensus::Agree(1)));
+
+        // re-registered within the stale age
+        assert_eq!(state.register("processor_a", 110, 10), (2, 10.0));
+
+        // re-registered after the stale age
+        state.deregister("processor_a", 20.0, 110);
+        assert_eq!(state.register("processor_a", 121, 10), (2, 0.0));
+        assert!(state.prev_max_filled.is_empty());
+    }
+
+    #[cfg(feature = "nats-tests")]
+    mod nats {
+        use super::*;
+        use async_nats::jetstream;
+        use std::time::Duration;
+
+        async fn create_test_store(bucket: &'static str, stale_age: usize) -> JetStreamKVStore {
+            let client = async_nats::connect("localhost:4222").await.unwrap();
+            let js = jetstream::new(client);
+            let _ = js.delete_key_value(bucket).await;
+            let kv = js
+                .create_key_value(jetstream::kv::Config {
+                    bucket: bucket.to_string(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            JetStreamKVStore::new(
+                "test-pipeline-vertex-pl",
+                stale_age,
+                JetstreamKVStore::new(kv, bucket),
+            )
+        }
+
+        async fn cleanup_test_store(bucket: &str) {
+            let client = async_nats::connect("localhost:4222").await.unwrap();
+            let _ = jetstream::new(client).delete_key_value(bucket).await;
+        }
+
+        #[tokio::test]
+        async fn test_consensus_jetstream() {
+            let bucket = "test-rate-limiter-consensus";
+            let store = create_test_store(bucket, 180).await;
+            let cancel = CancellationToken::new();
+
+            let (pool_size, _) = store.register("processor_a", cancel.clone()).await.unwrap();
+            assert_eq!(pool_size, 1);
+            let (pool_size, _) = store.register("processor_b", cancel.clone()).await.unwrap();
+            assert_eq!(pool_size, 2);
+
+            let consensus = store
+                .sync_pool_size("processor_a", 2, cancel.clone())
+                .await
+                .unwrap();
+            assert!(matches!(consensus, Consensus::Agree(2)));
+
+            cleanup_test_store(bucket).await;
+        }
+
+        #[tokio::test]
+        async fn test_concurrent_register_jetstream() {
+            let bucket = "test-rate-limiter-concurrent-register";
+            let store = create_test_store(bucket, 180).await;
+            let cancel = CancellationToken::new();
+
+            let mut handles = vec![];
+            for i in 0..10 {
+                let store = store.clone();
+                let cancel = cancel.clone();
+                handles.push(tokio::spawn(async move {
+                    store
+                        .register(&format!("processor_{i}"), cancel)
+                        .await
+                        .unwrap()
+                        .0
+                }));
+            }
+
+            let mut pool_sizes = vec![];
+            for handle in handles {
+                pool_sizes.push(handle.await.unwrap());
+            }
+            pool_sizes.sort();
+            // every registration was applied on top of the previous one
+            assert_eq!(pool_sizes, (1..=10).collect::<Vec<_>>());
+
+            cleanup_test_store(bucket).await;
+        }
+
+        #[tokio::test]
+        async fn test_expired_stored_max_filled_at_deregister_jetstream() {
+            let bucket = "test-rate-limiter-expired-max-filled";
+            let store = create_test_store(bucket, 1).await;
+            let cancel = CancellationToken::new();
+
+            store.register("processor_a", cancel.clone()).await.unwrap();
+            store
+                .deregister("processor_a", 10.0, cancel.clone())
+                .await
+                .unwrap();
+
+            let (_, prev_max_filled) = store.register("processor_a", cancel.clone()).await.unwrap();
+            assert_eq!(prev_max_filled, 10.0);
+
+            store
+                .deregister("processor_a", 10.0, cancel.clone())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(2100)).await;
+
+            let (_, prev_max_filled) = store.register("processor_a", cancel.clone()).await.unwrap();
+            assert_eq!(prev_max_filled, 0.0);
+
+            cleanup_test_store(bucket).await;
+        }
+    }
+}
@@ -3,6 +3,9 @@ use clap::{Command, arg};
 /// SideInput Command Line Interface
 pub(crate) mod sideinput;
 
+/// WAL Command Line Interface
+pub(crate) mod wal;
+
 // The command argument "mvtx-daemon-server" matches the one passed to the daemon server container
 // at pkg/apis/numaflow/v1alpha1/mono_vertex_types.go GetDaemonDeploymentObj()
 // It's used to instruct the container to run the MonoVertex Daemon Server.
@@ -21,6 +24,7 @@ pub(super) fn root_cli() -> Command {
         .subcommand(add_processor_subcommand())
         .subcommand(add_mvtx_daemon_server_subcommand())
         .subcommand(sideinput::add_sideinput_subcommand())
+        .subcommand(wal::add_wal_subcommand())
 }
 
 fn add_processor_subcommand() -> Command {
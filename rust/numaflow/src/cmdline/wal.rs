@@ -0,0 +1,132 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use numaflow_core::wal::{WalCommand, WalWindows};
+use std::error::Error;
+use std::path::PathBuf;
+
+const PATH_WAL_MOUNT: &str = "/var/numaflow/pbq";
+
+pub(super) fn add_wal_subcommand() -> Command {
+    Command::new("wal")
+        .about("Inspect and repair the WAL of a Reduce vertex")
+        .subcommand_required(true)
+        .arg(
+            Arg::new("path")
+                .long("path")
+                .help("Directory of the WAL segments")
+                .global(true)
+                .action(ArgAction::Set)
+                .default_value(PATH_WAL_MOUNT)
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .subcommand(Command::new("segments").about("List the segments of the WAL"))
+        .subcommand(Command::new("dump").about("Dump the records of the WAL"))
+        .subcommand(
+            window_args(Command::new("open-windows"))
+                .about("Show the windows with data which is not garbage collected"),
+        )
+        .subcommand(
+            window_args(Command::new("compact"))
+                .about("Compact the WAL, deleting the garbage collected data"),
+        )
+        .subcommand(
+            Command::new("truncate")
+                .about("Truncate the corrupted segments at their last good record"),
+        )
+}
+
+fn window_args(command: Command) -> Command {
+    command
+        .arg(
+            Arg::new("window")
+                .long("window")
+                .help("Window type of the Reduce vertex")
+                .required(true)
+                .value_parser(["fixed", "sliding", "session", "accumulator"]),
+        )
+        .arg(
+            Arg::new("length")
+                .long("length")
+                .help("Length of the fixed and sliding windows, e.g. 60s")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("slide")
+                .long("slide")
+                .help("Slide of the sliding windows, e.g. 10s")
+                .action(ArgAction::Set),
+        )
+}
+
+fn windows(args: &ArgMatches) -> Result<WalWindows, Box<dyn Error>> {
+    let window = args
+        .get_one::<String>("window")
+        .expect("window is required");
+    Ok(WalWindows::new(
+        window,
+        args.get_one::<String>("length").map(String::as_str),
+        args.get_one::<String>("slide").map(String::as_str),
+    )?)
+}
+
+pub(crate) async fn run_wal(args: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let (command, args) = match args.subcommand() {
+        Some(("segments", args)) => (WalCommand::Segments, args),
+        Some(("dump", args)) => (WalCommand::Dump, args),
+        Some(("open-windows", args)) => (WalCommand::OpenWindows(windows(args)?), args),
+        Some(("compact", args)) => (WalCommand::Compact(windows(args)?), args),
+        Some(("truncate", args)) => (WalCommand::Truncate, args),
+        other => return Err(format!("Unknown wal {other:?} subcommand").into()),
+    };
+    let path = args
+        .get_one::<PathBuf>("path")
+        .expect("path has a default value");
+
+    let mut stdout = std::io::stdout().lock();
+    Ok(numaflow_core::wal::run(path, command, &mut stdout).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wal_subcmd_cli() {
+        add_wal_subcommand().debug_assert();
+
+        let matches = add_wal_subcommand()
+            .try_get_matches_from(vec![
+                "wal",
+                "open-windows",
+                "--window",
+                "sliding",
+                "--length",
+                "60s",
+                "--slide",
+                "10s",
+            ])
+            .unwrap();
+        let (name, args) = matches.subcommand().unwrap();
+        assert_eq!(name, "open-windows");
+        assert_eq!(
+            args.get_one::<PathBuf>("path").unwrap(),
+            &PathBuf::from(PATH_WAL_MOUNT)
+        );
+        assert!(matches!(windows(args).unwrap(), WalWindows::Sliding { .. }));
+
+        let matches = add_wal_subcommand()
+            .try_get_matches_from(vec!["wal", "segments", "--path", "/tmp/pbq"])
+            .unwrap();
+        let (_, args) = matches.subcommand().unwrap();
+        assert_eq!(
+            args.get_one::<PathBuf>("path").unwrap(),
+            &PathBuf::from("/tmp/pbq")
+        );
+
+        // the length is required for fixed windows
+        let matches = add_wal_subcommand()
+            .try_get_matches_from(vec!["wal", "compact", "--window", "fixed"])
+            .unwrap();
+        let (_, args) = matches.subcommand().unwrap();
+        assert!(windows(args).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use cmdline::sideinput;
+use cmdline::{sideinput, wal};
 use std::collections::HashMap;
 use std::env;
 use std::error::Error;
@@ -16,7 +16,10 @@ const VERSION_INFO: &str = env!("NUMAFLOW_VERSION_INFO");
 const ENV_MONO_VERTEX_NAME: &str = "NUMAFLOW_MONO_VERTEX_NAME";
 
 fn main() {
-    setup_tracing::register();
+    let cli_matches = cmdline::root_cli().get_matches();
+    // the output of the `wal` subcommand is written to stdout, so the logs go to stderr.
+    let logs_to_stderr = matches!(cli_matches.subcommand(), Some(("wal", _)));
+    setup_tracing::register(logs_to_stderr);
     // Setup the CryptoProvider (controls core cryptography used by rustls) for the process
     rustls::crypto::aws_lc_rs::default_provider()
         .install_default()
@@ -51,10 +54,8 @@ fn main() {
         "Numaflow runtime details"
     );
 
-    let cli = cmdline::root_cli();
-
     rt.block_on(async move {
-        if let Err(e) = run(cli).await {
+        if let Err(e) = run(cli_matches).await {
             error!("{e:?}");
             std::process::exit(1);
         }
@@ -63,9 +64,7 @@ fn main() {
     info!("Exited.");
 }
 
-async fn run(cli: clap::Command) -> Result<(), Box<dyn Error>> {
-    let cli_matches = cli.get_matches();
-
+async fn run(cli_matches: clap::ArgMatches) -> Result<(), Box<dyn Error>> {
     let cln_token = CancellationToken::new();
     let shutdown_cln_token = cln_token.clone();
 
@@ -95,9 +94,13 @@ async fn run(cli: clap::Command) -> Result<(), Box<dyn Error>> {
             let cfg: serving::Settings = vars.try_into().unwrap();
             serving::run(cfg).await?;
         }
-        Some(("processor", _)) => {
+        Some(("processor", args)) => {
             info!("Starting processing pipeline");
-            numaflow_core::run()
+            let isbsvc_type = args
+                .get_one::<String>("isbsvc-type")
+                .map(String::as_str)
+                .unwrap_or_default();
+            numaflow_core::run(isbsvc_type)
                 .await
                 .map_err(|e| format!("Error running core binary: {e:?}"))?;
         }
@@ -105,6 +108,9 @@ async fn run(cli: clap::Command) -> Result<(), Box<dyn Error>> {
             info!("Starting side input");
             sideinput::run_sideinput(args, cln_token).await?;
         }
+        Some(("wal", args)) => {
+            wal::run_wal(args).await?;
+        }
         Some((cmdline::CMD_ARG_MVTX_DAEMON_SERVER, _)) => {
             info!("Starting the MonoVertex daemon server");
             match env::var(ENV_MONO_VERTEX_NAME) {
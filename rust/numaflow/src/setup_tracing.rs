@@ -1,4 +1,5 @@
 use tracing::Level;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{Layer, filter::EnvFilter, fmt};
@@ -53,7 +54,8 @@ fn report_panic(panic_info: &PanicHookInfo<'_>) {
     };
 }
 
-pub fn register() {
+/// Registers the tracing subscriber, the logs are written to stdout unless `to_stderr` is set.
+pub fn register(to_stderr: bool) {
     // Set up the tracing subscriber. RUST_LOG can be used to set the log level.
     // The default log level is `info`. The `axum::rejection=trace` enables showing
     // rejections from built-in extractors at `TRACE` level.
@@ -68,12 +70,19 @@ pub fn register() {
         .with_default_directive(default_log_level.parse().unwrap_or(Level::INFO.into()))
         .from_env_lossy(); // Read RUST_LOG environment variable
 
+    let writer = if to_stderr {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+
     let layer = if debug_mode {
         // Text format
-        fmt::layer().boxed()
+        fmt::layer().with_writer(writer).boxed()
     } else {
         // JSON format, flattened
         fmt::layer()
+            .with_writer(writer)
             .with_ansi(false)
             .json()
             .flatten_event(true)
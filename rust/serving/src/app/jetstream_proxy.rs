@@ -1,6 +1,7 @@
 //! Serving proxy receives the serving request and writes the request to the ISB so the pipeline can
 //! process the request.
 
+use std::time::Duration;
 use std::{str::FromStr, sync::Arc};
 
 use super::{AppState, orchestrator, store::datastore::DataStore};
@@ -8,7 +9,9 @@ use super::{FetchQueryParams, Tid};
 use crate::Error;
 use crate::app::response::{ApiError, ServeResponse};
 use crate::app::store::cbstore::CallbackStore;
-use crate::config::{DEFAULT_POD_HASH_KEY, RequestType};
+use crate::config::{
+    DEFAULT_DEADLINE_HEADER, DEFAULT_POD_HASH_KEY, DEFAULT_TIMEOUT_HEADER, RequestType,
+};
 use crate::metrics::serving_metrics;
 use async_nats::jetstream::Context;
 use axum::Extension;
@@ -22,11 +25,12 @@ use axum::{
     response::{IntoResponse, Response},
     routing::{get, post},
 };
+use chrono::Utc;
 use serde_json::json;
 use tokio::time::Instant;
 use tokio_stream::{Stream, StreamExt};
 use tokio_util::sync::CancellationToken;
-use tracing::{Instrument, debug, error};
+use tracing::{Instrument, debug, error, warn};
 
 const NUMAFLOW_RESP_ARRAY_LEN: &str = "Numaflow-Array-Len";
 const NUMAFLOW_RESP_ARRAY_IDX_LEN: &str = "Numaflow-Array-Index-Len";
@@ -36,10 +40,82 @@ struct ProxyState<T, U> {
     stream: &'static str,
     tid_header: &'static str,
     pod_hash: &'static str,
+    /// timeout of the requests, it bounds the deadline of the sync requests.
+    request_timeout: Duration,
     orchestrator: orchestrator::OrchestratorState<T, U>,
     cancellation_token: CancellationToken,
 }
 
+/// Cancels the sync request in the orchestrator if the handler is dropped before the pipeline has
+/// processed the request, i.e., the client disconnected or the request timed out.
+struct CancelOnDrop<T, U>
+where
+    T: Clone + Send + Sync + DataStore + 'static,
+    U: Clone + Send + Sync + CallbackStore + 'static,
+{
+    orchestrator: Option<orchestrator::OrchestratorState<T, U>>,
+    id: String,
+}
+
+impl<T, U> CancelOnDrop<T, U>
+where
+    T: Clone + Send + Sync + DataStore + 'static,
+    U: Clone + Send + Sync + CallbackStore + 'static,
+{
+    fn new(orchestrator: orchestrator::OrchestratorState<T, U>, id: String) -> Self {
+        Self {
+            orchestrator: Some(orchestrator),
+            id,
+        }
+    }
+
+    /// the request has been processed, nothing to cancel.
+    fn disarm(mut self) {
+        self.orchestrator = None;
+    }
+}
+
+impl<T, U> Drop for CancelOnDrop<T, U>
+where
+    T: Clone + Send + Sync + DataStore + 'static,
+    U: Clone + Send + Sync + CallbackStore + 'static,
+{
+    fn drop(&mut self) {
+        let Some(mut orchestrator) = self.orchestrator.take() else {
+            return;
+        };
+        let id = std::mem::take(&mut self.id);
+        warn!(
+            ?id,
+            "Request was not processed before the client went away, cancelling"
+        );
+        tokio::spawn(async move {
+            if let Err(e) = orchestrator
+                .cancel(&id, "Client disconnected or deadline exceeded")
+                .await
+            {
+                error!(?id, error = ?e, "Failed to cancel the request");
+            }
+        });
+    }
+}
+
+/// Returns the deadline of a sync request in epoch milliseconds. It is the request timeout,
+/// shortened by the timeout (in seconds) the client has set in the [DEFAULT_TIMEOUT_HEADER].
+fn request_deadline(headers: &HeaderMap, request_timeout: Duration) -> (Duration, i64) {
+    let timeout = headers
+        .get(DEFAULT_TIMEOUT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map_or(request_timeout, |secs| {
+            Duration::from_secs(secs).min(request_timeout)
+        });
+    (
+        timeout,
+        Utc::now().timestamp_millis() + timeout.as_millis() as i64,
+    )
+}
+
 /// Expose a router for the serving proxy and its endpoints.
 pub(crate) async fn serving_proxy<
     T: Clone + Send + Sync + DataStore + 'static,
@@ -52,6 +128,7 @@ pub(crate) async fn serving_proxy<
         stream: state.settings.js_message_stream,
         tid_header: state.settings.tid_header,
         pod_hash: state.settings.pod_hash,
+        request_timeout: Duration::from_secs(state.settings.drain_timeout_secs),
         orchestrator: state.orchestrator_state,
         cancellation_token: state.cancellation_token,
     });
@@ -252,6 +329,9 @@ async fn sync_publish<
     }
     msg_headers.insert(proxy_state.tid_header, id.clone());
     msg_headers.insert(DEFAULT_POD_HASH_KEY, proxy_state.pod_hash);
+    // the vertices drop the message instead of processing it once nobody is waiting for the response
+    let (timeout, deadline) = request_deadline(&headers, proxy_state.request_timeout);
+    msg_headers.insert(DEFAULT_DEADLINE_HEADER, deadline.to_string());
 
     let processing_start = Instant::now();
     // Register the ID in the callback proxy state
@@ -296,7 +376,18 @@ async fn sync_publish<
         return Err(err);
     }
 
-    let processing_result = match notify.await {
+    // the request is cancelled if the handler is dropped (client disconnect or request timeout)
+    // or the deadline passes while waiting for the pipeline output.
+    let cancel_on_drop = CancelOnDrop::new(proxy_state.orchestrator.clone(), id.clone());
+    let Ok(notify_result) = tokio::time::timeout(timeout, notify).await else {
+        error!(?id, ?timeout, "Request deadline exceeded");
+        return Err(ApiError::RequestTimeout(
+            "Request deadline exceeded while waiting for the pipeline output".to_string(),
+        ));
+    };
+    cancel_on_drop.disarm();
+
+    let processing_result = match notify_result {
         Ok(processing_result) => {
             serving_metrics()
                 .processing_time
@@ -511,6 +602,29 @@ mod tests {
 
     const PIPELINE_SPEC_ENCODED: &str = "eyJ2ZXJ0aWNlcyI6W3sibmFtZSI6ImluIiwic291cmNlIjp7InNlcnZpbmciOnsiYXV0aCI6bnVsbCwic2VydmljZSI6dHJ1ZSwibXNnSURIZWFkZXJLZXkiOiJYLU51bWFmbG93LUlkIiwic3RvcmUiOnsidXJsIjoicmVkaXM6Ly9yZWRpczo2Mzc5In19fSwiY29udGFpbmVyVGVtcGxhdGUiOnsicmVzb3VyY2VzIjp7fSwiaW1hZ2VQdWxsUG9saWN5IjoiTmV2ZXIiLCJlbnYiOlt7Im5hbWUiOiJSVVNUX0xPRyIsInZhbHVlIjoiZGVidWcifV19LCJzY2FsZSI6eyJtaW4iOjF9LCJ1cGRhdGVTdHJhdGVneSI6eyJ0eXBlIjoiUm9sbGluZ1VwZGF0ZSIsInJvbGxpbmdVcGRhdGUiOnsibWF4VW5hdmFpbGFibGUiOiIyNSUifX19LHsibmFtZSI6InBsYW5uZXIiLCJ1ZGYiOnsiY29udGFpbmVyIjp7ImltYWdlIjoiYXNjaWk6MC4xIiwiYXJncyI6WyJwbGFubmVyIl0sInJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn0sImJ1aWx0aW4iOm51bGwsImdyb3VwQnkiOm51bGx9LCJjb250YWluZXJUZW1wbGF0ZSI6eyJyZXNvdXJjZXMiOnt9LCJpbWFnZVB1bGxQb2xpY3kiOiJOZXZlciJ9LCJzY2FsZSI6eyJtaW4iOjF9LCJ1cGRhdGVTdHJhdGVneSI6eyJ0eXBlIjoiUm9sbGluZ1VwZGF0ZSIsInJvbGxpbmdVcGRhdGUiOnsibWF4VW5hdmFpbGFibGUiOiIyNSUifX19LHsibmFtZSI6InRpZ2VyIiwidWRmIjp7ImNvbnRhaW5lciI6eyJpbWFnZSI6ImFzY2lpOjAuMSIsImFyZ3MiOlsidGlnZXIiXSwicmVzb3VyY2VzIjp7fSwiaW1hZ2VQdWxsUG9saWN5IjoiTmV2ZXIifSwiYnVpbHRpbiI6bnVsbCwiZ3JvdXBCeSI6bnVsbH0sImNvbnRhaW5lclRlbXBsYXRlIjp7InJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn0sInNjYWxlIjp7Im1pbiI6MX0sInVwZGF0ZVN0cmF0ZWd5Ijp7InR5cGUiOiJSb2xsaW5nVXBkYXRlIiwicm9sbGluZ1VwZGF0ZSI6eyJtYXhVbmF2YWlsYWJsZSI6IjI1JSJ9fX0seyJuYW1lIjoiZG9nIiwidWRmIjp7ImNvbnRhaW5lciI6eyJpbWFnZSI6ImFzY2lpOjAuMSIsImFyZ3MiOlsiZG9nIl0sInJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn0sImJ1aWx0aW4iOm51bGwsImdyb3VwQnkiOm51bGx9LCJjb250YWluZXJUZW1wbGF0ZSI6eyJyZXNvdXJjZXMiOnt9LCJpbWFnZVB1bGxQb2xpY3kiOiJOZXZlciJ9LCJzY2FsZSI6eyJtaW4iOjF9LCJ1cGRhdGVTdHJhdGVneSI6eyJ0eXBlIjoiUm9sbGluZ1VwZGF0ZSIsInJvbGxpbmdVcGRhdGUiOnsibWF4VW5hdmFpbGFibGUiOiIyNSUifX19LHsibmFtZSI6ImVsZXBoYW50IiwidWRmIjp7ImNvbnRhaW5lciI6eyJpbWFnZSI6ImFzY2lpOjAuMSIsImFyZ3MiOlsiZWxlcGhhbnQiXSwicmVzb3VyY2VzIjp7fSwiaW1hZ2VQdWxsUG9saWN5IjoiTmV2ZXIifSwiYnVpbHRpbiI6bnVsbCwiZ3JvdXBCeSI6bnVsbH0sImNvbnRhaW5lclRlbXBsYXRlIjp7InJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn0sInNjYWxlIjp7Im1pbiI6MX0sInVwZGF0ZVN0cmF0ZWd5Ijp7InR5cGUiOiJSb2xsaW5nVXBkYXRlIiwicm9sbGluZ1VwZGF0ZSI6eyJtYXhVbmF2YWlsYWJsZSI6IjI1JSJ9fX0seyJuYW1lIjoiYXNjaWlhcnQiLCJ1ZGYiOnsiY29udGFpbmVyIjp7ImltYWdlIjoiYXNjaWk6MC4xIiwiYXJncyI6WyJhc2NpaWFydCJdLCJyZXNvdXJjZXMiOnt9LCJpbWFnZVB1bGxQb2xpY3kiOiJOZXZlciJ9LCJidWlsdGluIjpudWxsLCJncm91cEJ5IjpudWxsfSwiY29udGFpbmVyVGVtcGxhdGUiOnsicmVzb3VyY2VzIjp7fSwiaW1hZ2VQdWxsUG9saWN5IjoiTmV2ZXIifSwic2NhbGUiOnsibWluIjoxfSwidXBkYXRlU3RyYXRlZ3kiOnsidHlwZSI6IlJvbGxpbmdVcGRhdGUiLCJyb2xsaW5nVXBkYXRlIjp7Im1heFVuYXZhaWxhYmxlIjoiMjUlIn19fSx7Im5hbWUiOiJzZXJ2ZS1zaW5rIiwic2luayI6eyJ1ZHNpbmsiOnsiY29udGFpbmVyIjp7ImltYWdlIjoic2VydmVzaW5rOjAuMSIsImVudiI6W3sibmFtZSI6Ik5VTUFGTE9XX0NBTExCQUNLX1VSTF9LRVkiLCJ2YWx1ZSI6IlgtTnVtYWZsb3ctQ2FsbGJhY2stVXJsIn0seyJuYW1lIjoiTlVNQUZMT1dfTVNHX0lEX0hFQURFUl9LRVkiLCJ2YWx1ZSI6IlgtTnVtYWZsb3ctSWQifV0sInJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn19LCJyZXRyeVN0cmF0ZWd5Ijp7fX0sImNvbnRhaW5lclRlbXBsYXRlIjp7InJlc291cmNlcyI6e30sImltYWdlUHVsbFBvbGljeSI6Ik5ldmVyIn0sInNjYWxlIjp7Im1pbiI6MX0sInVwZGF0ZVN0cmF0ZWd5Ijp7InR5cGUiOiJSb2xsaW5nVXBkYXRlIiwicm9sbGluZ1VwZGF0ZSI6eyJtYXhVbmF2YWlsYWJsZSI6IjI1JSJ9fX0seyJuYW1lIjoiZXJyb3Itc2luayIsInNpbmsiOnsidWRzaW5rIjp7ImNvbnRhaW5lciI6eyJpbWFnZSI6InNlcnZlc2luazowLjEiLCJlbnYiOlt7Im5hbWUiOiJOVU1BRkxPV19DQUxMQkFDS19VUkxfS0VZIiwidmFsdWUiOiJYLU51bWFmbG93LUNhbGxiYWNrLVVybCJ9LHsibmFtZSI6Ik5VTUFGTE9XX01TR19JRF9IRUFERVJfS0VZIiwidmFsdWUiOiJYLU51bWFmbG93LUlkIn1dLCJyZXNvdXJjZXMiOnt9LCJpbWFnZVB1bGxQb2xpY3kiOiJOZXZlciJ9fSwicmV0cnlTdHJhdGVneSI6e319LCJjb250YWluZXJUZW1wbGF0ZSI6eyJyZXNvdXJjZXMiOnt9LCJpbWFnZVB1bGxQb2xpY3kiOiJOZXZlciJ9LCJzY2FsZSI6eyJtaW4iOjF9LCJ1cGRhdGVTdHJhdGVneSI6eyJ0eXBlIjoiUm9sbGluZ1VwZGF0ZSIsInJvbGxpbmdVcGRhdGUiOnsibWF4VW5hdmFpbGFibGUiOiIyNSUifX19XSwiZWRnZXMiOlt7ImZyb20iOiJpbiIsInRvIjoicGxhbm5lciIsImNvbmRpdGlvbnMiOm51bGx9LHsiZnJvbSI6InBsYW5uZXIiLCJ0byI6ImFzY2lpYXJ0IiwiY29uZGl0aW9ucyI6eyJ0YWdzIjp7Im9wZXJhdG9yIjoib3IiLCJ2YWx1ZXMiOlsiYXNjaWlhcnQiXX19fSx7ImZyb20iOiJwbGFubmVyIiwidG8iOiJ0aWdlciIsImNvbmRpdGlvbnMiOnsidGFncyI6eyJvcGVyYXRvciI6Im9yIiwidmFsdWVzIjpbInRpZ2VyIl19fX0seyJmcm9tIjoicGxhbm5lciIsInRvIjoiZG9nIiwiY29uZGl0aW9ucyI6eyJ0YWdzIjp7Im9wZXJhdG9yIjoib3IiLCJ2YWx1ZXMiOlsiZG9nIl19fX0seyJmcm9tIjoicGxhbm5lciIsInRvIjoiZWxlcGhhbnQiLCJjb25kaXRpb25zIjp7InRhZ3MiOnsib3BlcmF0b3IiOiJvciIsInZhbHVlcyI6WyJlbGVwaGFudCJdfX19LHsiZnJvbSI6InRpZ2VyIiwidG8iOiJzZXJ2ZS1zaW5rIiwiY29uZGl0aW9ucyI6bnVsbH0seyJmcm9tIjoiZG9nIiwidG8iOiJzZXJ2ZS1zaW5rIiwiY29uZGl0aW9ucyI6bnVsbH0seyJmcm9tIjoiZWxlcGhhbnQiLCJ0byI6InNlcnZlLXNpbmsiLCJjb25kaXRpb25zIjpudWxsfSx7ImZyb20iOiJhc2NpaWFydCIsInRvIjoic2VydmUtc2luayIsImNvbmRpdGlvbnMiOm51bGx9LHsiZnJvbSI6InBsYW5uZXIiLCJ0byI6ImVycm9yLXNpbmsiLCJjb25kaXRpb25zIjp7InRhZ3MiOnsib3BlcmF0b3IiOiJvciIsInZhbHVlcyI6WyJlcnJvciJdfX19XSwibGlmZWN5Y2xlIjp7fSwid2F0ZXJtYXJrIjp7fX0=";
 
+    #[test]
+    fn test_request_deadline() {
+        let request_timeout = Duration::from_secs(120);
+        let now = Utc::now().timestamp_millis();
+
+        // without the timeout header, the request timeout is used
+        let (timeout, deadline) = request_deadline(&HeaderMap::new(), request_timeout);
+        assert_eq!(timeout, request_timeout);
+        assert!(deadline >= now + 120_000);
+
+        // the client can shorten the deadline
+        let mut headers = HeaderMap::new();
+        headers.insert(DEFAULT_TIMEOUT_HEADER, HeaderValue::from_static("5"));
+        let (timeout, deadline) = request_deadline(&headers, request_timeout);
+        assert_eq!(timeout, Duration::from_secs(5));
+        assert!(deadline >= now + 5_000 && deadline < now + 120_000);
+
+        // but not extend it beyond the request timeout
+        headers.insert(DEFAULT_TIMEOUT_HEADER, HeaderValue::from_static("600"));
+        let (timeout, _) = request_deadline(&headers, request_timeout);
+        assert_eq!(timeout, request_timeout);
+    }
+
     #[cfg(feature = "nats-tests")]
     #[tokio::test]
     async fn test_async_publish() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -118,8 +118,9 @@ where
 
                     // send can only fail if the request was cancelled by the client or the handler task
                     // was terminated.
-                    tx.send(Ok(graph))
-                        .expect("receiver was dropped, request is cancelled");
+                    if tx.send(Ok(graph)).is_err() {
+                        warn!(id = ?msg_id, "Receiver was dropped, request is cancelled");
+                    }
                     break;
                 }
             }
@@ -158,6 +159,10 @@ where
                     self.datum_store.retrieve_data(id, Some(pod_hash)).await?,
                 ))
             }
+            ProcessingStatus::Cancelled { reason, .. } => {
+                warn!(?id, %reason, "Request was cancelled");
+                Err(Error::Status(format!("Request was cancelled: {reason}")))
+            }
         }
     }
 
@@ -273,6 +278,10 @@ where
                 error!(?error, "Request failed");
                 Err(Error::SubGraphGenerator(error))
             }
+            ProcessingStatus::Cancelled { reason, .. } => {
+                warn!(?reason, "Request was cancelled");
+                Err(Error::SubGraphGenerator(reason))
+            }
         }
     }
 
@@ -282,6 +291,23 @@ where
         Ok(())
     }
 
+    /// cancels the sync request whose client has disconnected or timed out. The request is marked
+    /// as cancelled and its callbacks are no longer watched, the vertices drop the message once its
+    /// deadline has passed.
+    pub(crate) async fn cancel(&mut self, id: &str, reason: &str) -> Result<(), Error> {
+        // the request could have completed while the client was going away
+        if !matches!(
+            self.status_tracker.status(id).await?,
+            ProcessingStatus::InProgress { .. }
+        ) {
+            return Ok(());
+        }
+        self.status_tracker.mark_as_cancelled(id, reason).await?;
+        self.callback_store.deregister(id).await?;
+        serving_metrics().request_cancelled_count.inc();
+        Ok(())
+    }
+
     /// discards the request from the status tracker and callback store. This is used when the
     /// request has not been accepted due to back pressure.
     pub(crate) async fn discard(&mut self, id: &str) -> Result<(), Error> {
@@ -24,8 +24,12 @@ const CALLBACK_KEY_PREFIX: &str = "cb";
 /// JetStream implementation of the callback store.
 #[derive(Clone)]
 pub(crate) struct JetStreamCallbackStore {
+    pod_hash: &'static str,
     callback_kv: Store,
     callback_senders: Arc<Mutex<HashMap<String, mpsc::Sender<Arc<Callback>>>>>,
+    /// pod hash of the pod which accepted the retried requests first, their callbacks are stored
+    /// under it.
+    previous_pod_hashes: Arc<Mutex<HashMap<String, String>>>,
     cln_token: CancellationToken,
 }
 
@@ -62,8 +66,10 @@ impl JetStreamCallbackStore {
         .await;
 
         Ok(Self {
+            pod_hash,
             callback_kv,
             callback_senders,
+            previous_pod_hashes: Arc::new(Mutex::new(HashMap::new())),
             cln_token,
         })
     }
@@ -265,6 +271,18 @@ impl JetStreamCallbackStore {
         info!(?id, "Finished streaming historical callbacks.");
     }
 
+    /// purges the callbacks of the request, they are only needed while the request is in progress.
+    /// The purge is best effort since the request has already been deregistered.
+    async fn purge_callbacks(&self, pod_hash: &str, id: &str) {
+        let subject = format!(
+            "{}{CALLBACK_KEY_PREFIX}.{pod_hash}.{id}.>",
+            self.callback_kv.prefix
+        );
+        if let Err(e) = self.callback_kv.stream.purge().filter(subject).await {
+            error!(?id, error = ?e, "Failed to purge the callbacks of the request");
+        }
+    }
+
     /// creates a kv watcher for the given key pattern and revision, and it keeps retrying until it's
     /// successful.
     async fn create_watcher(
@@ -316,6 +334,10 @@ impl super::CallbackStore for JetStreamCallbackStore {
             let previous_pod_hash = failed_pod_hash.clone();
             let cln_token = self.cln_token.clone();
 
+            self.previous_pod_hashes
+                .lock()
+                .await
+                .insert(id.to_string(), failed_pod_hash);
             let mut senders_guard = self.callback_senders.lock().await;
             senders_guard.insert(id.to_string(), tx);
             // Spawn a separate task for historical callbacks
@@ -337,12 +359,20 @@ impl super::CallbackStore for JetStreamCallbackStore {
         }
     }
 
-    /// deregister the request and remove the callback sender from the map.
+    /// deregister the request, remove the callback sender from the map and purge the callbacks of
+    /// the request from the kv store.
     async fn deregister(&mut self, id: &str) -> StoreResult<()> {
-        let mut senders_guard = self.callback_senders.lock().await;
-        if senders_guard.remove(id).is_none() {
+        if self.callback_senders.lock().await.remove(id).is_none() {
             error!(?id, "No active sender found during deregistration");
         }
+
+        let pod_hash = self
+            .previous_pod_hashes
+            .lock()
+            .await
+            .remove(id)
+            .unwrap_or_else(|| self.pod_hash.to_string());
+        self.purge_callbacks(&pod_hash, id).await;
         Ok(())
     }
 
@@ -455,6 +485,80 @@ mod tests {
         context.delete_key_value(serving_store).await.unwrap();
     }
 
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_deregister_purges_callbacks() {
+        let js_url = "localhost:4222";
+        let client = async_nats::connect(js_url).await.unwrap();
+        let context = jetstream::new(client);
+        let serving_store = "test_deregister_purges_callbacks";
+        let pod_hash = "0";
+
+        // Delete bucket so that re-running the test won't fail
+        let _ = context.delete_key_value(serving_store).await;
+
+        context
+            .create_key_value(Config {
+                bucket: serving_store.to_string(),
+                history: 15,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut store = JetStreamCallbackStore::new(
+            context.clone(),
+            pod_hash,
+            serving_store,
+            CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+        let id = "test_deregister_id";
+        let other_id = "test_deregister_other_id";
+        let mut stream = store.register_and_watch(id, None).await.unwrap();
+
+        for request_id in [id, other_id] {
+            let callback = Callback {
+                id: request_id.to_string(),
+                vertex: "test_vertex".to_string(),
+                cb_time: 12345,
+                from_vertex: "test_from_vertex".to_string(),
+                responses: vec![],
+            };
+            let key = format!(
+                "cb.{pod_hash}.{request_id}.input.{}",
+                Utc::now().timestamp()
+            );
+            store
+                .callback_kv
+                .put(key, Bytes::from(serde_json::to_vec(&callback).unwrap()))
+                .await
+                .unwrap();
+        }
+        assert_eq!(stream.next().await.unwrap().id, id);
+
+        store.deregister(id).await.unwrap();
+
+        // the callbacks of the deregistered request are purged, the others are kept
+        let keys: Vec<String> = store
+            .callback_kv
+            .keys()
+            .await
+            .unwrap()
+            .map(|key| key.unwrap())
+            .collect()
+            .await;
+        assert_eq!(keys.len(), 1);
+        assert!(keys.first().unwrap().contains(other_id));
+
+        // the stream ends after the deregistration
+        assert!(stream.next().await.is_none());
+
+        context.delete_key_value(serving_store).await.unwrap();
+    }
+
     #[cfg(feature = "nats-tests")]
     #[tokio::test]
     async fn test_watch_historical_callbacks() {
@@ -56,6 +56,10 @@ pub(crate) enum ProcessingStatus {
         error: String, // error message of the failed request
         pod_hash: String,
     },
+    /// Cancelled indicates that the client of a sync request disconnected or timed out before the
+    /// response was returned. The message may have been dropped by the vertices after its
+    /// deadline, so the request cannot be retried with the same id.
+    Cancelled { reason: String, pod_hash: String },
 }
 
 impl TryFrom<Bytes> for ProcessingStatus {
@@ -197,6 +201,9 @@ impl StatusTracker {
     /// * [ProcessingStatus::Failed]: Indicates the request failed due to client cancellation or a
     ///   failure in the serving pod (pod that accepted the request). In this case, the request can
     ///   be retried.
+    /// * [ProcessingStatus::Cancelled]: Indicates the client of the sync request went away before
+    ///   the response was returned. Its callbacks have been cleaned up and the message may have been
+    ///   dropped, so it has to be retried with a different ID.
     fn handle_existing_status(
         &self,
         id: &str,
@@ -234,6 +241,12 @@ impl StatusTracker {
                     previous_pod_hash: pod_hash,
                 })
             }
+            ProcessingStatus::Cancelled { reason, .. } => {
+                warn!(%id, %status_key, %reason, "Request was cancelled, cannot be retried.");
+                Err(Error::Other(format!(
+                    "Request {id} was cancelled ({reason}), retry with a different id"
+                )))
+            }
         }
     }
 
@@ -260,6 +273,17 @@ impl StatusTracker {
         self.update_status(id, failed_status).await
     }
 
+    /// Mark the sync request as cancelled in the status kv store, the client has disconnected or
+    /// timed out before the response was returned.
+    pub(crate) async fn mark_as_cancelled(&self, id: &str, reason: &str) -> Result<()> {
+        info!(id, replica_id = ?self.pod_hash, reason, "Marking request as cancelled");
+        let cancelled_status = ProcessingStatus::Cancelled {
+            reason: reason.to_string(),
+            pod_hash: self.pod_hash.to_string(),
+        };
+        self.update_status(id, cancelled_status).await
+    }
+
     /// Update the status of a request in the status kv store.
     async fn update_status(&self, id: &str, status: ProcessingStatus) -> Result<()> {
         let key = format!("{STATUS_KEY_PREFIX}.{id}");
@@ -507,6 +531,59 @@ mod tests {
         context.delete_key_value(status_bucket).await.unwrap();
     }
 
+    #[cfg(feature = "nats-tests")]
+    #[tokio::test]
+    async fn test_mark_as_cancelled() {
+        let js_url = "localhost:4222";
+        let client = async_nats::connect(js_url).await.unwrap();
+        let context = jetstream::new(client);
+        let status_bucket = "test_mark_cancelled";
+
+        // Clean up bucket
+        let _ = context.delete_key_value(status_bucket).await;
+
+        // Create bucket
+        context
+            .create_key_value(Config {
+                bucket: status_bucket.to_string(),
+                history: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut tracker = StatusTracker::new(context.clone(), status_bucket, "test_pod", None)
+            .await
+            .unwrap();
+
+        let request_id = "test_request";
+        tracker
+            .register(request_id, RequestType::Sync)
+            .await
+            .expect("Failed to register request");
+
+        tracker
+            .mark_as_cancelled(request_id, "Client disconnected")
+            .await
+            .expect("Failed to mark request as cancelled");
+
+        let status = tracker.status(request_id).await.unwrap();
+        assert_eq!(
+            status,
+            ProcessingStatus::Cancelled {
+                reason: "Client disconnected".to_string(),
+                pod_hash: "test_pod".to_string()
+            }
+        );
+
+        // a cancelled request cannot be retried with the same id
+        let result = tracker.register(request_id, RequestType::Sync).await;
+        assert!(matches!(result, Err(Error::Other(_))));
+
+        // Clean up
+        context.delete_key_value(status_bucket).await.unwrap();
+    }
+
     #[cfg(feature = "nats-tests")]
     #[tokio::test]
     async fn test_deregister_request() {
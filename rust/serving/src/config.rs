@@ -17,6 +17,11 @@ pub const ENV_MIN_PIPELINE_SPEC: &str = "NUMAFLOW_SERVING_MIN_PIPELINE_SPEC";
 pub const DEFAULT_ID_HEADER: &str = "X-Numaflow-Id";
 pub const DEFAULT_POD_HASH_KEY: &str = "X-Numaflow-Pod-Hash";
 pub const DEFAULT_CALLBACK_URL_HEADER_KEY: &str = "X-Numaflow-Callback-Url";
+/// Deadline (epoch milliseconds) of a sync request, the vertices drop the message instead of
+/// processing it once the deadline has passed.
+pub const DEFAULT_DEADLINE_HEADER: &str = "X-Numaflow-Deadline";
+/// Timeout (seconds) a client can set to shorten the deadline of a sync request.
+pub const DEFAULT_TIMEOUT_HEADER: &str = "X-Numaflow-Timeout";
 const DEFAULT_GRPC_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024; // 64 MB
 const DEFAULT_SERVING_STORE_SOCKET: &str = "/var/run/numaflow/serving.sock";
 const DEFAULT_SERVING_STORE_SERVER_INFO_FILE: &str = "/var/run/numaflow/serving-server-info";
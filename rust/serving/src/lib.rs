@@ -23,8 +23,9 @@ mod app;
 
 mod config;
 pub use {
-    config::DEFAULT_CALLBACK_URL_HEADER_KEY, config::DEFAULT_ID_HEADER,
-    config::DEFAULT_POD_HASH_KEY, config::ENV_MIN_PIPELINE_SPEC, config::Settings,
+    config::DEFAULT_CALLBACK_URL_HEADER_KEY, config::DEFAULT_DEADLINE_HEADER,
+    config::DEFAULT_ID_HEADER, config::DEFAULT_POD_HASH_KEY, config::ENV_MIN_PIPELINE_SPEC,
+    config::Settings,
 };
 
 mod error;
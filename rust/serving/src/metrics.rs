@@ -60,6 +60,7 @@ pub(crate) struct ServingMetrics {
     pub(crate) request_register_fail_count: Counter,
     pub(crate) request_register_duplicate_count: Counter,
     pub(crate) request_register_duration: Histogram,
+    pub(crate) request_cancelled_count: Counter,
 
     pub(crate) payload_save_duration: Histogram,
     pub(crate) datum_retrive_duration: Histogram,
@@ -79,6 +80,7 @@ impl ServingMetrics {
         let cb_store_register_duplicate_count = Counter::default();
 
         let cb_store_register_duration = Histogram::new(exponential_buckets(0.001, 2.0, 10));
+        let request_cancelled_count = Counter::default();
 
         let payload_save_duration = Histogram::new(exponential_buckets(0.001, 2.0, 10));
         let datum_retrive_duration = Histogram::new(exponential_buckets(0.001, 2.0, 10));
@@ -92,6 +94,7 @@ impl ServingMetrics {
             request_register_fail_count: cb_store_register_fail_count,
             request_register_duplicate_count: cb_store_register_duplicate_count,
             request_register_duration: cb_store_register_duration,
+            request_cancelled_count,
             payload_save_duration,
             datum_retrive_duration,
             processing_time,
@@ -134,6 +137,12 @@ impl ServingMetrics {
             metrics.request_register_duration.clone(),
         );
 
+        registry.register(
+            "REQUEST_CANCELLED",
+            "A Counter to keep track of the number of sync requests cancelled due to client disconnects or timeouts",
+            metrics.request_cancelled_count.clone(),
+        );
+
         registry.register(
             "PAYLOAD_JESTREAM_SAVE_DURATION",
             "A Histogram to keep track of the time it takes to save request payload to Jestream",